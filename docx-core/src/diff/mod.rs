@@ -0,0 +1,249 @@
+use serde::Serialize;
+use std::fmt;
+
+use crate::documents::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiffKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+// A block or table cell whose rendering differs. Paths such as `body[3].rows[1].cells[0]`
+// point into the new document, except for removed elements which only exist in the old one.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffEntry {
+    pub kind: DiffKind,
+    pub path: String,
+    pub node_type: RenderNodeType,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+// Differences between the renderings of two documents. Elements with the same text but
+// different formatting properties are reported as changed.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct DocumentDiff {
+    pub entries: Vec<DiffEntry>,
+}
+
+impl DocumentDiff {
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn removed(&mut self, path: String, old: &JsonRender) {
+        self.entries.push(DiffEntry {
+            kind: DiffKind::Removed,
+            path,
+            node_type: old.node_type,
+            before: Some(old.text.clone()),
+            after: None,
+        });
+    }
+
+    fn added(&mut self, path: String, new: &JsonRender) {
+        self.entries.push(DiffEntry {
+            kind: DiffKind::Added,
+            path,
+            node_type: new.node_type,
+            before: None,
+            after: Some(new.text.clone()),
+        });
+    }
+
+    fn changed(&mut self, path: String, old: &JsonRender, new: &JsonRender) {
+        // Tables of the same shape are compared cell by cell.
+        if old.node_type == RenderNodeType::Table
+            && new.node_type == RenderNodeType::Table
+            && same_shape(old, new)
+        {
+            for (r, (old_row, new_row)) in old.children.iter().zip(&new.children).enumerate() {
+                for (c, (old_cell, new_cell)) in
+                    old_row.children.iter().zip(&new_row.children).enumerate()
+                {
+                    if old_cell != new_cell {
+                        self.changed(
+                            format!("{}.rows[{}].cells[{}]", path, r, c),
+                            old_cell,
+                            new_cell,
+                        );
+                    }
+                }
+            }
+            return;
+        }
+        self.entries.push(DiffEntry {
+            kind: DiffKind::Changed,
+            path,
+            node_type: new.node_type,
+            before: Some(old.text.clone()),
+            after: Some(new.text.clone()),
+        });
+    }
+}
+
+fn same_shape(old: &JsonRender, new: &JsonRender) -> bool {
+    old.children.len() == new.children.len()
+        && old
+            .children
+            .iter()
+            .zip(&new.children)
+            .all(|(a, b)| a.children.len() == b.children.len())
+}
+
+enum Edit {
+    Keep,
+    Remove(usize),
+    Add(usize),
+}
+
+// Longest common subsequence of the blocks, as an edit script from `old` to `new`.
+fn edits(old: &[JsonRender], new: &[JsonRender]) -> Vec<Edit> {
+    let (n, m) = (old.len(), new.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut edits = vec![];
+    while i < n || j < m {
+        if i < n && j < m && old[i] == new[j] {
+            edits.push(Edit::Keep);
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            edits.push(Edit::Add(j));
+            j += 1;
+        } else {
+            edits.push(Edit::Remove(i));
+            i += 1;
+        }
+    }
+    edits
+}
+
+pub fn diff(old: &Docx, new: &Docx) -> DocumentDiff {
+    let old = old.render_ascii_json();
+    let new = new.render_ascii_json();
+    let mut diff = DocumentDiff::default();
+    let mut removed: Vec<usize> = vec![];
+    let mut added: Vec<usize> = vec![];
+    let flush = |diff: &mut DocumentDiff, removed: &mut Vec<usize>, added: &mut Vec<usize>| {
+        // Removals replaced by additions of the same kind are changes.
+        let mut a = 0;
+        for &r in removed.iter() {
+            match added.get(a) {
+                Some(&i) if old.children[r].node_type == new.children[i].node_type => {
+                    diff.changed(format!("body[{}]", i), &old.children[r], &new.children[i]);
+                    a += 1;
+                }
+                _ => diff.removed(format!("body[{}]", r), &old.children[r]),
+            }
+        }
+        for &i in &added[a..] {
+            diff.added(format!("body[{}]", i), &new.children[i]);
+        }
+        removed.clear();
+        added.clear();
+    };
+    for e in edits(&old.children, &new.children) {
+        match e {
+            Edit::Keep => flush(&mut diff, &mut removed, &mut added),
+            Edit::Remove(i) => removed.push(i),
+            Edit::Add(i) => added.push(i),
+        }
+    }
+    flush(&mut diff, &mut removed, &mut added);
+    diff
+}
+
+impl Docx {
+    pub fn diff(&self, other: &Docx) -> DocumentDiff {
+        diff(self, other)
+    }
+}
+
+// One `- path: before` and/or `+ path: after` line per entry, like a unified diff.
+impl fmt::Display for DocumentDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for e in &self.entries {
+            if let Some(before) = &e.before {
+                writeln!(f, "- {}: {}", e.path, before.replace('\n', "\\n"))?;
+            }
+            if let Some(after) = &e.after {
+                writeln!(f, "+ {}: {}", e.path, after.replace('\n', "\\n"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn p(text: &str) -> Paragraph {
+        Paragraph::new().add_run(Run::new().add_text(text))
+    }
+
+    fn table(cells: &[&str]) -> Table {
+        Table::new(vec![TableRow::new(
+            cells
+                .iter()
+                .map(|t| TableCell::new().add_paragraph(p(t)))
+                .collect(),
+        )])
+    }
+
+    #[test]
+    fn test_diff_documents() {
+        let old = Docx::new()
+            .add_paragraph(p("Title"))
+            .add_paragraph(p("Removed"))
+            .add_table(table(&["a", "b"]))
+            .add_paragraph(p("Old text"));
+        let new = Docx::new()
+            .add_paragraph(p("Title"))
+            .add_table(table(&["a", "c"]))
+            .add_paragraph(p("New text"))
+            .add_paragraph(p("Added"));
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.to_string(),
+            "- body[1]: Removed\n- body[1].rows[0].cells[1]: b\n+ body[1].rows[0].cells[1]: c\n- body[2]: Old text\n+ body[2]: New text\n+ body[3]: Added\n"
+        );
+        assert_eq!(diff.entries[1].kind, DiffKind::Changed);
+        assert_eq!(diff.entries[1].node_type, RenderNodeType::TableCell);
+        assert!(old.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn test_diff_formatting() {
+        let old = Docx::new().add_paragraph(p("Same"));
+        let new =
+            Docx::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Same").bold()));
+        assert_eq!(
+            diff(&old, &new).entries,
+            vec![DiffEntry {
+                kind: DiffKind::Changed,
+                path: "body[0]".to_owned(),
+                node_type: RenderNodeType::Paragraph,
+                before: Some("Same".to_owned()),
+                after: Some("Same".to_owned()),
+            }]
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use serde::Serialize;
+
+use super::*;
+
+// Part imported by the `AltChunk` of the body with the same `id`. `path` is relative to the
+// `word` folder, such as `afchunk1.mht`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AltChunkPart {
+    pub id: String,
+    pub path: String,
+    pub content_type: String,
+    #[serde(skip_serializing)]
+    pub data: Vec<u8>,
+}
+
+impl AltChunkPart {
+    pub fn new(id: impl Into<String>, path: impl Into<String>, data: Vec<u8>) -> AltChunkPart {
+        let path = path.into();
+        AltChunkPart {
+            id: id.into(),
+            content_type: alt_chunk_content_type(&path).to_owned(),
+            path,
+            data,
+        }
+    }
+}
+
+fn alt_chunk_content_type(path: &str) -> &'static str {
+    let ext = path.rsplit('.').next().unwrap_or_default();
+    match ext.to_ascii_lowercase().as_str() {
+        "mht" | "mhtml" => "message/rfc822",
+        "htm" | "html" => "text/html",
+        "xhtml" => "application/xhtml+xml",
+        "rtf" => "application/rtf",
+        "txt" => "text/plain",
+        "xml" => "application/xml",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        _ => "application/octet-stream",
+    }
+}
+
+impl Docx {
+    // Add a part imported at the end of the body.
+    pub fn add_alt_chunk(mut self, path: impl Into<String>, data: Vec<u8>) -> Docx {
+        let id = format!("AltChunkId{}", self.alt_chunks.len() + 1);
+        self.document = self.document.add_alt_chunk(AltChunk::new(&id));
+        self.alt_chunks.push(AltChunkPart::new(id, path, data));
+        self
+    }
+
+    pub fn alt_chunks(&self) -> &[AltChunkPart] {
+        &self.alt_chunks
+    }
+
+    // Replace the content of the part with the relationship id, keeping its path.
+    pub fn replace_alt_chunk(mut self, id: &str, data: Vec<u8>) -> Docx {
+        if let Some(part) = self.alt_chunks.iter_mut().find(|p| p.id == id) {
+            part.data = data;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_alt_chunk_content_type() {
+        assert_eq!(alt_chunk_content_type("afchunk1.mht"), "message/rfc822");
+        assert_eq!(alt_chunk_content_type("chunk.HTML"), "text/html");
+        assert_eq!(alt_chunk_content_type("chunk.rtf"), "application/rtf");
+        assert_eq!(alt_chunk_content_type("chunk"), "application/octet-stream");
+    }
+}
@@ -1,3 +1,55 @@
+use std::io::{self, Write};
+
+// Elements implement either `build_to` or `build`. `build_to` writes the XML into the writer
+// of the parent, so elements built by `XMLBuilder` stream their children without building them
+// into buffers of their own. `build` is kept for the elements which build into a buffer.
 pub trait BuildXML {
-    fn build(&self) -> Vec<u8>;
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&self.build())
+    }
+
+    fn build(&self) -> Vec<u8> {
+        let mut buf = vec![];
+        self.build_to(&mut buf).expect("should write to buf");
+        buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::documents::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    struct FailingWriter;
+
+    impl Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::Other, "disk full"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_build_to() {
+        let p = Paragraph::new().add_run(Run::new().add_text("Hello"));
+        let mut buf = vec![];
+        p.build_to(&mut buf).unwrap();
+        assert_eq!(buf, p.build());
+    }
+
+    #[test]
+    fn test_build_to_error() {
+        let table =
+            Table::new(vec![TableRow::new(vec![TableCell::new().add_paragraph(
+                Paragraph::new().add_run(Run::new().add_text("Hello")),
+            )])]);
+        let e = table.build_to(&mut FailingWriter).unwrap_err();
+        assert_eq!(e.to_string(), "disk full");
+    }
 }
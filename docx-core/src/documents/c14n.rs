@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+
+use xml::attribute::OwnedAttribute;
+use xml::name::OwnedName;
+use xml::reader::{EventReader, ParserConfig, XmlEvent};
+
+// Canonical XML 1.0 without comments (http://www.w3.org/TR/2001/REC-xml-c14n-20010315) of
+// the first element for which `select` is true and its descendants.
+pub(crate) fn canonicalize(
+    xml: &[u8],
+    select: impl Fn(&OwnedName, &[OwnedAttribute]) -> bool,
+) -> Option<Vec<u8>> {
+    let config = ParserConfig::new()
+        .trim_whitespace(false)
+        .whitespace_to_characters(true)
+        .cdata_to_characters(true)
+        .ignore_comments(true)
+        .coalesce_characters(true);
+    let parser = EventReader::new_with_config(xml, config);
+    let mut out = String::new();
+    // The namespaces rendered by the output ancestors of each open element.
+    let mut rendered: Vec<BTreeMap<String, String>> = vec![];
+    for e in parser {
+        match e.ok()? {
+            XmlEvent::StartElement {
+                name,
+                attributes,
+                namespace,
+            } => {
+                if rendered.is_empty() && !select(&name, &attributes) {
+                    continue;
+                }
+                let in_scope: BTreeMap<String, String> = namespace
+                    .0
+                    .iter()
+                    .filter(|(prefix, _)| *prefix != "xml" && *prefix != "xmlns")
+                    .map(|(prefix, uri)| (prefix.clone(), uri.clone()))
+                    .collect();
+                let parent = rendered.last().cloned().unwrap_or_default();
+
+                out.push('<');
+                push_name(&mut out, &name);
+                for (prefix, uri) in &in_scope {
+                    let inherited = parent.get(prefix).map(|p| p.as_str()).unwrap_or("");
+                    if uri == inherited {
+                        continue;
+                    }
+                    if prefix.is_empty() {
+                        out.push_str(" xmlns=\"");
+                    } else {
+                        out.push_str(" xmlns:");
+                        out.push_str(prefix);
+                        out.push_str("=\"");
+                    }
+                    push_escaped_attr(&mut out, uri);
+                    out.push('"');
+                }
+
+                let mut attributes = attributes;
+                attributes.sort_by(|a, b| {
+                    let ns = |a: &OwnedAttribute| a.name.namespace.clone().unwrap_or_default();
+                    (ns(a), &a.name.local_name).cmp(&(ns(b), &b.name.local_name))
+                });
+                for a in &attributes {
+                    out.push(' ');
+                    push_name(&mut out, &a.name);
+                    out.push_str("=\"");
+                    push_escaped_attr(&mut out, &a.value);
+                    out.push('"');
+                }
+                out.push('>');
+                rendered.push(in_scope);
+            }
+            XmlEvent::EndElement { name } => {
+                if rendered.is_empty() {
+                    continue;
+                }
+                out.push_str("</");
+                push_name(&mut out, &name);
+                out.push('>');
+                rendered.pop();
+                if rendered.is_empty() {
+                    return Some(out.into_bytes());
+                }
+            }
+            XmlEvent::Characters(text) if !rendered.is_empty() => {
+                for c in text.chars() {
+                    match c {
+                        '&' => out.push_str("&amp;"),
+                        '<' => out.push_str("&lt;"),
+                        '>' => out.push_str("&gt;"),
+                        '\r' => out.push_str("&#xD;"),
+                        c => out.push(c),
+                    }
+                }
+            }
+            XmlEvent::ProcessingInstruction { name, data } if !rendered.is_empty() => {
+                out.push_str("<?");
+                out.push_str(&name);
+                if let Some(data) = data {
+                    out.push(' ');
+                    out.push_str(&data);
+                }
+                out.push_str("?>");
+            }
+            XmlEvent::EndDocument => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn push_name(out: &mut String, name: &OwnedName) {
+    if let Some(prefix) = &name.prefix {
+        out.push_str(prefix);
+        out.push(':');
+    }
+    out.push_str(&name.local_name);
+}
+
+fn push_escaped_attr(out: &mut String, v: &str) {
+    for c in v.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' => out.push_str("&#x9;"),
+            '\n' => out.push_str("&#xA;"),
+            '\r' => out.push_str("&#xD;"),
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_canonicalize() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Signature xmlns="http://www.w3.org/2000/09/xmldsig#" Id="sig"><!-- comment -->
+  <Object Id="obj" b='1 &amp; 2' a="x"><Empty/><m:Value xmlns:m="urn:m">a &gt; b</m:Value></Object>
+</Signature>"#;
+        let c = canonicalize(xml.as_bytes(), |_, attrs| {
+            attrs
+                .iter()
+                .any(|a| a.name.local_name == "Id" && a.value == "obj")
+        })
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(c).unwrap(),
+            r#"<Object xmlns="http://www.w3.org/2000/09/xmldsig#" Id="obj" a="x" b="1 &amp; 2"><Empty></Empty><m:Value xmlns:m="urn:m">a &gt; b</m:Value></Object>"#
+        );
+        assert!(canonicalize(xml.as_bytes(), |name, _| name.local_name == "None").is_none());
+    }
+}
@@ -23,6 +23,14 @@ impl Comments {
         self.comments
     }
 
+    // Direct replies to the comment, in document order.
+    pub fn replies(&self, id: usize) -> Vec<&Comment> {
+        self.comments
+            .iter()
+            .filter(|c| c.parent_comment_id == Some(id))
+            .collect()
+    }
+
     pub(crate) fn add_comments(&mut self, comments: Vec<Comment>) {
         self.comments = comments;
     }
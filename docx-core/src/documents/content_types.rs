@@ -12,8 +12,8 @@ pub struct ContentTypes {
     types: BTreeMap<String, String>,
     web_extension_count: usize,
     custom_xml_count: usize,
-    header_count: usize,
-    footer_count: usize,
+    pub(crate) header_count: usize,
+    pub(crate) footer_count: usize,
 }
 
 impl ContentTypes {
@@ -26,6 +26,11 @@ impl ContentTypes {
         self
     }
 
+    // The content type overridden for the part, such as `/word/styles.xml`.
+    pub fn find_content_type(&self, part_name: &str) -> Option<&str> {
+        self.types.get(part_name).map(|t| t.as_str())
+    }
+
     pub fn set_default(mut self) -> ContentTypes {
         self.types.insert(
             "/_rels/.rels".to_owned(),
@@ -106,7 +111,7 @@ impl ContentTypes {
 
     pub fn add_custom_xml(mut self) -> Self {
         self.types.insert(
-            format!("/customXml/itemProps{}.xml", self.web_extension_count),
+            format!("/customXml/itemProps{}.xml", self.custom_xml_count),
             "application/vnd.openxmlformats-officedocument.customXmlProperties+xml".to_owned(),
         );
         self.custom_xml_count += 1;
@@ -138,6 +143,15 @@ impl ContentTypes {
         );
         self
     }
+
+    pub fn add_endnotes(mut self) -> Self {
+        self.types.insert(
+            "/word/endnotes.xml".to_owned(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.endnotes+xml"
+                .to_owned(),
+        );
+        self
+    }
 }
 
 impl Default for ContentTypes {
@@ -187,9 +201,18 @@ impl FromXML for ContentTypes {
             match e {
                 Ok(XmlEvent::StartElement { attributes, .. }) => {
                     if depth == 1 {
-                        let namespace = attributes[0].value.clone();
-                        let path = attributes[1].value.clone();
-                        s = s.add_content(path, namespace);
+                        // Word writes `PartName` first. `Default` elements have no part name.
+                        let attr = |name: &str| {
+                            attributes
+                                .iter()
+                                .find(|a| a.name.local_name == name)
+                                .map(|a| a.value.clone())
+                        };
+                        if let (Some(path), Some(namespace)) =
+                            (attr("PartName"), attr("ContentType"))
+                        {
+                            s = s.add_content(path, namespace);
+                        }
                     }
                     depth += 1;
                 }
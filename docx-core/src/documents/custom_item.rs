@@ -7,6 +7,12 @@ use std::str::FromStr;
 #[derive(Debug, Clone)]
 pub struct CustomItem(XmlDocument);
 
+impl CustomItem {
+    pub fn xml(&self) -> String {
+        self.0.to_string()
+    }
+}
+
 impl FromStr for CustomItem {
     type Err = ParseXmlError;
 
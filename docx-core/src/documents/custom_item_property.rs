@@ -3,14 +3,33 @@ use serde::Serialize;
 use crate::documents::BuildXML;
 use crate::xml_builder::*;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct CustomItemProperty {
     id: String,
+    schema_refs: Vec<String>,
 }
 
 impl CustomItemProperty {
     pub fn new(id: impl Into<String>) -> Self {
-        Self { id: id.into() }
+        Self {
+            id: id.into(),
+            schema_refs: vec![],
+        }
+    }
+
+    pub fn add_schema_ref(mut self, uri: impl Into<String>) -> Self {
+        self.schema_refs.push(uri.into());
+        self
+    }
+
+    // The data store item id, without braces, which content controls bind to.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn schema_refs(&self) -> &[String] {
+        &self.schema_refs
     }
 }
 
@@ -23,8 +42,35 @@ impl BuildXML for CustomItemProperty {
                 "http://schemas.openxmlformats.org/officeDocument/2006/customXml",
                 &format!("{{{}}}", self.id),
             )
-            .open_data_store_schema_refs()
-            .close();
-        b.close().build()
+            .open_data_store_schema_refs();
+        for uri in &self.schema_refs {
+            b = b.data_store_schema_ref(uri);
+        }
+        b.close().close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let b = CustomItemProperty::new("06AC5857-5C65-A94A-BCEC-37356A209BC3")
+            .add_schema_ref("https://example.com/invoice")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<ds:datastoreItem xmlns:ds="http://schemas.openxmlformats.org/officeDocument/2006/customXml" ds:itemID="{06AC5857-5C65-A94A-BCEC-37356A209BC3}">
+  <ds:schemaRefs>
+    <ds:schemaRef ds:uri="https://example.com/invoice" />
+  </ds:schemaRefs>
+</ds:datastoreItem>"#
+        );
     }
 }
@@ -7,6 +7,8 @@ use crate::xml_builder::*;
 #[serde(rename_all = "camelCase")]
 pub struct CustomItemRels {
     custom_item_count: usize,
+    // The number of the itemProps parts before those of this item.
+    props_offset: usize,
 }
 
 impl CustomItemRels {
@@ -18,6 +20,11 @@ impl CustomItemRels {
         self.custom_item_count += 1;
         self
     }
+
+    pub(crate) fn props_offset(mut self, offset: usize) -> Self {
+        self.props_offset = offset;
+        self
+    }
 }
 
 impl BuildXML for CustomItemRels {
@@ -32,7 +39,7 @@ impl BuildXML for CustomItemRels {
             b = b.relationship(
                 &format!("rId{}", id),
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXmlProps",
-                &format!("itemProps{}.xml", id),
+                &format!("itemProps{}.xml", self.props_offset + id),
             )
         }
 
@@ -0,0 +1,129 @@
+use serde::Serialize;
+
+use super::*;
+
+// A part which is not modeled by the crate, related from the main document. `path` is in the
+// package, such as `word/extensions/data.xml`, and `id` is the relationship id which elements
+// of the document refer to.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomPart {
+    pub id: String,
+    pub path: String,
+    pub content_type: String,
+    pub relationship_type: String,
+    #[serde(skip_serializing)]
+    pub data: Vec<u8>,
+}
+
+impl CustomPart {
+    // The relationship target, relative to the `word` folder of the main document.
+    pub(crate) fn target(&self) -> String {
+        match self.path.strip_prefix("word/") {
+            Some(p) => p.to_owned(),
+            None => format!("../{}", self.path),
+        }
+    }
+}
+
+impl Docx {
+    // Add a part with its relationship from the main document. The relationship id is
+    // `rIdPart` followed by the number of the part, so that it is known before the document
+    // is built. A part added again to the same path replaces the data and keeps its id.
+    pub fn add_part(
+        mut self,
+        path: impl Into<String>,
+        content_type: impl Into<String>,
+        relationship_type: impl Into<String>,
+        data: Vec<u8>,
+    ) -> Docx {
+        let path = path.into().trim_start_matches('/').to_owned();
+        let content_type = content_type.into();
+        let relationship_type = relationship_type.into();
+        if let Some(part) = self.custom_parts.iter_mut().find(|p| p.path == path) {
+            part.content_type = content_type;
+            part.relationship_type = relationship_type;
+            part.data = data;
+            return self;
+        }
+        let id = format!("rIdPart{}", self.custom_parts.len() + 1);
+        self.custom_parts.push(CustomPart {
+            id,
+            path,
+            content_type,
+            relationship_type,
+            data,
+        });
+        self
+    }
+
+    // The relationship id of the part added to the path.
+    pub fn part_id(&self, path: &str) -> Option<&str> {
+        let path = path.trim_start_matches('/');
+        self.custom_parts
+            .iter()
+            .find(|p| p.path == path)
+            .map(|p| p.id.as_str())
+    }
+
+    pub fn custom_parts(&self) -> &[CustomPart] {
+        &self.custom_parts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::io::Read;
+
+    #[test]
+    fn test_add_part() {
+        let docx = Docx::new()
+            .add_part(
+                "word/extensions/data.xml",
+                "application/vnd.example.data+xml",
+                "http://example.com/relationships/data",
+                b"<data />".to_vec(),
+            )
+            .add_part(
+                "/extra/note.txt",
+                "text/plain",
+                "http://example.com/relationships/note",
+                b"note".to_vec(),
+            )
+            .add_part(
+                "word/extensions/data.xml",
+                "application/vnd.example.data+xml",
+                "http://example.com/relationships/data",
+                b"<data>2</data>".to_vec(),
+            );
+        assert_eq!(docx.part_id("word/extensions/data.xml"), Some("rIdPart1"));
+        assert_eq!(docx.part_id("/extra/note.txt"), Some("rIdPart2"));
+        assert_eq!(docx.part_id("word/none.xml"), None);
+
+        let mut buf = std::io::Cursor::new(vec![]);
+        docx.build().pack(&mut buf).unwrap();
+        let mut zip = zip::ZipArchive::new(buf).unwrap();
+        let read = |zip: &mut zip::ZipArchive<_>, name: &str| {
+            let mut s = String::new();
+            zip.by_name(name).unwrap().read_to_string(&mut s).unwrap();
+            s
+        };
+        assert_eq!(read(&mut zip, "word/extensions/data.xml"), "<data>2</data>");
+        assert_eq!(read(&mut zip, "extra/note.txt"), "note");
+        let rels = read(&mut zip, "word/_rels/document.xml.rels");
+        assert!(rels.contains(
+            r#"<Relationship Id="rIdPart1" Type="http://example.com/relationships/data" Target="extensions/data.xml" />"#
+        ));
+        assert!(rels.contains(
+            r#"<Relationship Id="rIdPart2" Type="http://example.com/relationships/note" Target="../extra/note.txt" />"#
+        ));
+        let types = read(&mut zip, "[Content_Types].xml");
+        assert!(
+            types.contains(r#"<Override PartName="/extra/note.txt" ContentType="text/plain" />"#)
+        );
+    }
+}
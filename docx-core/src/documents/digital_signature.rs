@@ -0,0 +1,309 @@
+use std::io::{self, Seek, Write};
+
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+use crate::escape::escape;
+use crate::xml_builder::*;
+use crate::zipper::{self, Entry};
+
+pub const RSA_SHA256: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256";
+pub(crate) const SHA1_URI: &str = "http://www.w3.org/2000/09/xmldsig#sha1";
+pub(crate) const SHA256_URI: &str = "http://www.w3.org/2001/04/xmlenc#sha256";
+pub(crate) const C14N_URI: &str = "http://www.w3.org/TR/2001/REC-xml-c14n-20010315";
+pub(crate) const RELATIONSHIP_TRANSFORM: &str =
+    "http://schemas.openxmlformats.org/package/2006/RelationshipTransform";
+pub(crate) const SIGNATURE_ORIGIN_TYPE: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/origin";
+pub(crate) const SIGNATURE_TYPE: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/signature";
+
+const XMLDSIG_NS: &str = "http://www.w3.org/2000/09/xmldsig#";
+const OBJECT_TYPE: &str = "http://www.w3.org/2000/09/xmldsig#Object";
+const SIGNATURE_PROPERTIES_NS: &str =
+    "http://schemas.openxmlformats.org/package/2006/digital-signature";
+const ORIGIN_PATH: &str = "_xmlsignatures/origin.sigs";
+const SIGNATURE_PATH: &str = "_xmlsignatures/sig1.xml";
+
+// Signs packages with a key which may be kept outside of the process, such as in an HSM.
+pub trait SignatureProvider {
+    // The DER encoded X.509 certificate of the signing key.
+    fn certificate(&self) -> Vec<u8>;
+
+    fn signature_method(&self) -> &str {
+        RSA_SHA256
+    }
+
+    // Such as `2026-10-14T09:30:00Z`.
+    fn signing_time(&self) -> String;
+
+    // The signature value of the canonical `SignedInfo` element.
+    fn sign(&self, signed_info: &[u8]) -> io::Result<Vec<u8>>;
+}
+
+// Checks the signature values of signed packages, whose digests are checked by the reader.
+pub trait SignatureVerifier {
+    fn verify(
+        &self,
+        method: &str,
+        certificate: &[u8],
+        signed_info: &[u8],
+        signature: &[u8],
+    ) -> bool;
+}
+
+impl Docx {
+    // Pack the document with an OPC digital signature of all of its parts but
+    // `[Content_Types].xml`, which is never signed.
+    pub fn pack_signed<W>(
+        mut self,
+        w: W,
+        signer: &dyn SignatureProvider,
+    ) -> zip::result::ZipResult<()>
+    where
+        W: Write + Seek,
+    {
+        self.rels = self.rels.add_rel(SIGNATURE_ORIGIN_TYPE, ORIGIN_PATH);
+        self.content_type = self
+            .content_type
+            .add_content(
+                format!("/{}", ORIGIN_PATH),
+                "application/vnd.openxmlformats-package.digital-signature-origin",
+            )
+            .add_content(
+                format!("/{}", SIGNATURE_PATH),
+                "application/vnd.openxmlformats-package.digital-signature-xmlsignature+xml",
+            );
+        let content_types = self.content_type.clone();
+        let mut entries = zipper::entries(self.build());
+
+        let mut references = vec![];
+        for e in entries.iter() {
+            let (path, data) = match e {
+                Entry::File(path, data) if path != "[Content_Types].xml" => (path, data),
+                _ => continue,
+            };
+            let uri = format!(
+                "/{}?ContentType={}",
+                path,
+                part_content_type(&content_types, path)
+            );
+            // The relationships are signed through the relationship transform, so that the
+            // relationships of the signatures to come can be added.
+            let reference = if path.ends_with(".rels") {
+                let invalid =
+                    || io::Error::new(io::ErrorKind::InvalidData, "should read relationships");
+                let source_ids = relationship_ids(data).ok_or_else(invalid)?;
+                let subset = relationship_subset(data, &source_ids, &[]).ok_or_else(invalid)?;
+                PartReference {
+                    uri,
+                    source_ids: Some(source_ids),
+                    digest: sha256(&subset),
+                }
+            } else {
+                PartReference {
+                    uri,
+                    source_ids: None,
+                    digest: sha256(data),
+                }
+            };
+            references.push(reference);
+        }
+        let signature = sign_references(&references, signer)?;
+
+        let origin_rels = XMLBuilder::new()
+            .declaration(Some(true))
+            .open_relationships("http://schemas.openxmlformats.org/package/2006/relationships")
+            .relationship("rId1", SIGNATURE_TYPE, "sig1.xml")
+            .close()
+            .build();
+        entries.push(Entry::Directory("_xmlsignatures/".to_owned()));
+        entries.push(Entry::File(ORIGIN_PATH.to_owned(), vec![]));
+        entries.push(Entry::File(SIGNATURE_PATH.to_owned(), signature));
+        entries.push(Entry::Directory("_xmlsignatures/_rels/".to_owned()));
+        entries.push(Entry::File(
+            "_xmlsignatures/_rels/origin.sigs.rels".to_owned(),
+            origin_rels,
+        ));
+        zipper::zip_entries(w, entries, &PackOptions::default())?;
+        Ok(())
+    }
+}
+
+struct PartReference {
+    uri: String,
+    // The ids of the relationships signed by the relationship transform for `.rels` parts.
+    source_ids: Option<Vec<String>>,
+    digest: Vec<u8>,
+}
+
+// The ids of the relationships but the one to the signature origin, which is not signed.
+fn relationship_ids(rels: &[u8]) -> Option<Vec<String>> {
+    let mut ids = vec![];
+    for e in EventReader::new(rels) {
+        if let XmlEvent::StartElement {
+            name, attributes, ..
+        } = e.ok()?
+        {
+            let attr = |n: &str| {
+                attributes
+                    .iter()
+                    .find(|a| a.name.local_name == n)
+                    .map(|a| a.value.clone())
+                    .unwrap_or_default()
+            };
+            if name.local_name == "Relationship" && attr("Type") != SIGNATURE_ORIGIN_TYPE {
+                ids.push(attr("Id"));
+            }
+        }
+    }
+    Some(ids)
+}
+
+// The relationships selected by a relationship transform, sorted by their ids and
+// canonicalized.
+pub(crate) fn relationship_subset(
+    rels: &[u8],
+    ids: &[String],
+    types: &[String],
+) -> Option<Vec<u8>> {
+    let mut selected = vec![];
+    for e in EventReader::new(rels) {
+        if let XmlEvent::StartElement {
+            name, attributes, ..
+        } = e.ok()?
+        {
+            if name.local_name != "Relationship" {
+                continue;
+            }
+            let attr = |n: &str| {
+                attributes
+                    .iter()
+                    .find(|a| a.name.local_name == n)
+                    .map(|a| a.value.clone())
+            };
+            let id = attr("Id").unwrap_or_default();
+            let rel_type = attr("Type").unwrap_or_default();
+            if ids.contains(&id) || types.contains(&rel_type) {
+                let target = attr("Target").unwrap_or_default();
+                let mode = attr("TargetMode").unwrap_or_else(|| "Internal".to_owned());
+                selected.push((id, target, mode, rel_type));
+            }
+        }
+    }
+    selected.sort();
+    let mut xml = String::from(
+        r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+    );
+    for (id, target, mode, rel_type) in selected {
+        xml.push_str(&format!(
+            r#"<Relationship Id="{}" Target="{}" TargetMode="{}" Type="{}"></Relationship>"#,
+            escape(&id),
+            escape(&target),
+            escape(&mode),
+            escape(&rel_type)
+        ));
+    }
+    xml.push_str("</Relationships>");
+    canonicalize(xml.as_bytes(), |_, _| true)
+}
+
+// The content type of the part, overridden or by the defaults which `ContentTypes` writes.
+fn part_content_type(types: &ContentTypes, path: &str) -> String {
+    if let Some(t) = types.find_content_type(&format!("/{}", path)) {
+        return t.to_owned();
+    }
+    let ext = path
+        .rsplit_once('.')
+        .map(|(_, ext)| ext)
+        .unwrap_or_default();
+    match ext {
+        "rels" => "application/vnd.openxmlformats-package.relationships+xml",
+        "xml" => "application/xml",
+        "png" => "image/png",
+        "jpeg" => "image/jpeg",
+        "jpg" => "image/jpg",
+        "bmp" => "image/bmp",
+        "gif" => "image/gif",
+        _ => "application/octet-stream",
+    }
+    .to_owned()
+}
+
+fn signature_xml(
+    references: &[PartReference],
+    signer: &dyn SignatureProvider,
+    object_digest: &str,
+    signature_value: &str,
+) -> Vec<u8> {
+    let mut b = XMLBuilder::new()
+        .declaration(Some(true))
+        .open_signature(XMLDSIG_NS, "idPackageSignature")
+        .open_signed_info()
+        .canonicalization_method(C14N_URI)
+        .signature_method(signer.signature_method())
+        .open_object_reference("#idPackageObject", OBJECT_TYPE)
+        .digest_method(SHA256_URI)
+        .digest_value(object_digest)
+        .close()
+        .close()
+        .signature_value(signature_value)
+        .open_key_info()
+        .open_x509_data()
+        .x509_certificate(&base64::encode(signer.certificate()))
+        .close()
+        .close()
+        .open_signature_object("idPackageObject")
+        .open_manifest();
+    for r in references {
+        b = b.open_part_reference(&r.uri);
+        if let Some(source_ids) = &r.source_ids {
+            b = b.open_transforms().open_transform(RELATIONSHIP_TRANSFORM);
+            for id in source_ids {
+                b = b.relationship_reference(SIGNATURE_PROPERTIES_NS, id);
+            }
+            b = b.close().transform(C14N_URI).close();
+        }
+        b = b
+            .digest_method(SHA256_URI)
+            .digest_value(&base64::encode(&r.digest))
+            .close();
+    }
+    b.close()
+        .open_signature_properties()
+        .open_signature_property("idSignatureTime", "#idPackageSignature")
+        .open_signature_time(SIGNATURE_PROPERTIES_NS)
+        .signature_time_format("YYYY-MM-DDThh:mm:ssTZD")
+        .signature_time_value(&signer.signing_time())
+        .close()
+        .close()
+        .close()
+        .close()
+        .close()
+        .build()
+}
+
+fn sign_references(
+    references: &[PartReference],
+    signer: &dyn SignatureProvider,
+) -> io::Result<Vec<u8>> {
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "should canonicalize signature");
+    let draft = signature_xml(references, signer, "", "");
+    let object = canonicalize(&draft, |_, attrs| {
+        attrs
+            .iter()
+            .any(|a| a.name.local_name == "Id" && a.value == "idPackageObject")
+    })
+    .ok_or_else(invalid)?;
+    let object_digest = base64::encode(sha256(&object));
+    let draft = signature_xml(references, signer, &object_digest, "");
+    let signed_info =
+        canonicalize(&draft, |name, _| name.local_name == "SignedInfo").ok_or_else(invalid)?;
+    let signature_value = base64::encode(signer.sign(&signed_info)?);
+    Ok(signature_xml(
+        references,
+        signer,
+        &object_digest,
+        &signature_value,
+    ))
+}
@@ -0,0 +1,10 @@
+use std::sync::atomic::AtomicUsize;
+// Pictures and charts are written with the id 1.
+static DOC_PR_ID: AtomicUsize = AtomicUsize::new(2);
+
+// The id of the `wp:docPr` of a text box or a shape, unique in the document.
+pub fn generate_doc_pr_id() -> usize {
+    use std::sync::atomic::Ordering;
+
+    DOC_PR_ID.fetch_add(1, Ordering::Relaxed)
+}
@@ -5,7 +5,18 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AppProps {}
+pub struct AppProps {
+    pub template: Option<String>,
+    pub total_time: Option<usize>,
+    pub pages: Option<usize>,
+    pub words: Option<usize>,
+    pub characters: Option<usize>,
+    pub application: Option<String>,
+    pub paragraphs: Option<usize>,
+    pub company: Option<String>,
+    pub characters_with_spaces: Option<usize>,
+    pub app_version: Option<String>,
+}
 
 impl AppProps {
     pub fn new() -> AppProps {
@@ -15,17 +26,59 @@ impl AppProps {
 
 impl Default for AppProps {
     fn default() -> Self {
-        Self {}
+        Self {
+            template: None,
+            total_time: None,
+            pages: None,
+            words: None,
+            characters: None,
+            application: None,
+            paragraphs: None,
+            company: None,
+            characters_with_spaces: None,
+            app_version: None,
+        }
     }
 }
 
 impl BuildXML for AppProps {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        let base = b.declaration(Some(true)).open_properties(
+        let mut base = b.declaration(Some(true)).open_properties(
             "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties",
             "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
         );
+        let convert = |v: &usize| format!("{}", v);
+        if let Some(v) = self.template.as_ref() {
+            base = base.template(v);
+        }
+        if let Some(v) = self.total_time.as_ref().map(convert) {
+            base = base.total_time(&v);
+        }
+        if let Some(v) = self.pages.as_ref().map(convert) {
+            base = base.pages(&v);
+        }
+        if let Some(v) = self.words.as_ref().map(convert) {
+            base = base.words(&v);
+        }
+        if let Some(v) = self.characters.as_ref().map(convert) {
+            base = base.characters(&v);
+        }
+        if let Some(v) = self.application.as_ref() {
+            base = base.application(v);
+        }
+        if let Some(v) = self.paragraphs.as_ref().map(convert) {
+            base = base.paragraphs(&v);
+        }
+        if let Some(v) = self.company.as_ref() {
+            base = base.company(v);
+        }
+        if let Some(v) = self.characters_with_spaces.as_ref().map(convert) {
+            base = base.characters_with_spaces(&v);
+        }
+        if let Some(v) = self.app_version.as_ref() {
+            base = base.app_version(v);
+        }
         base.close().build()
     }
 }
@@ -48,4 +101,22 @@ mod tests {
 <Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes" />"#
         );
     }
+
+    #[test]
+    fn test_configured_doc_props_app_build() {
+        let mut c = AppProps::new();
+        c.application = Some("Microsoft Office Word".to_owned());
+        c.pages = Some(2);
+        c.company = Some("bokuweb".to_owned());
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+  <Pages>2</Pages>
+  <Application>Microsoft Office Word</Application>
+  <Company>bokuweb</Company>
+</Properties>"#
+        );
+    }
 }
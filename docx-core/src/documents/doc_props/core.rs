@@ -6,21 +6,21 @@ use crate::xml_builder::*;
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CoreProps {
-    config: CorePropsConfig,
+    pub config: CorePropsConfig,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CorePropsConfig {
-    created: Option<String>,
-    creator: Option<String>,
-    description: Option<String>,
-    language: Option<String>,
-    last_modified_by: Option<String>,
-    modified: Option<String>,
-    revision: Option<usize>,
-    subject: Option<String>,
-    title: Option<String>,
+    pub created: Option<String>,
+    pub creator: Option<String>,
+    pub description: Option<String>,
+    pub language: Option<String>,
+    pub last_modified_by: Option<String>,
+    pub modified: Option<String>,
+    pub revision: Option<usize>,
+    pub subject: Option<String>,
+    pub title: Option<String>,
 }
 
 impl Default for CorePropsConfig {
@@ -4,6 +4,7 @@ use serde::Serialize;
 use super::*;
 use crate::documents::BuildXML;
 use crate::xml_builder::*;
+use std::io::{self, Write};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -23,6 +24,8 @@ pub enum DocumentChild {
     CommentEnd(CommentRangeEnd),
     StructuredDataTag(Box<StructuredDataTag>),
     TableOfContents(Box<TableOfContents>),
+    AltChunk(AltChunk),
+    Unsupported(String),
 }
 
 impl Serialize for DocumentChild {
@@ -79,6 +82,18 @@ impl Serialize for DocumentChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            DocumentChild::AltChunk(ref r) => {
+                let mut t = serializer.serialize_struct("AltChunk", 2)?;
+                t.serialize_field("type", "altChunk")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            DocumentChild::Unsupported(ref r) => {
+                let mut t = serializer.serialize_struct("Unsupported", 2)?;
+                t.serialize_field("type", "unsupported")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
         }
     }
 }
@@ -174,11 +189,6 @@ impl Document {
         self
     }
 
-    pub(crate) fn first_header_without_title_pg(mut self, h: Header, rid: &str) -> Self {
-        self.section_property = self.section_property.first_header_without_title_pg(h, rid);
-        self
-    }
-
     pub fn even_header(mut self, h: Header, rid: &str) -> Self {
         self.section_property = self.section_property.even_header(h, rid);
         self
@@ -194,13 +204,13 @@ impl Document {
         self
     }
 
-    pub(crate) fn first_footer_without_title_pg(mut self, h: Footer, rid: &str) -> Self {
-        self.section_property = self.section_property.first_footer_without_title_pg(h, rid);
+    pub fn even_footer(mut self, h: Footer, rid: &str) -> Self {
+        self.section_property = self.section_property.even_footer(h, rid);
         self
     }
 
-    pub fn even_footer(mut self, h: Footer, rid: &str) -> Self {
-        self.section_property = self.section_property.even_footer(h, rid);
+    pub fn add_alt_chunk(mut self, c: AltChunk) -> Self {
+        self.children.push(DocumentChild::AltChunk(c));
         self
     }
 
@@ -236,23 +246,25 @@ impl Document {
 }
 
 impl BuildXML for DocumentChild {
-    fn build(&self) -> Vec<u8> {
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
         match self {
-            DocumentChild::Paragraph(v) => v.build(),
-            DocumentChild::Table(v) => v.build(),
-            DocumentChild::BookmarkStart(v) => v.build(),
-            DocumentChild::BookmarkEnd(v) => v.build(),
-            DocumentChild::CommentStart(v) => v.build(),
-            DocumentChild::CommentEnd(v) => v.build(),
-            DocumentChild::StructuredDataTag(v) => v.build(),
-            DocumentChild::TableOfContents(v) => v.build(),
+            DocumentChild::Paragraph(v) => v.build_to(w),
+            DocumentChild::Table(v) => v.build_to(w),
+            DocumentChild::BookmarkStart(v) => v.build_to(w),
+            DocumentChild::BookmarkEnd(v) => v.build_to(w),
+            DocumentChild::CommentStart(v) => v.build_to(w),
+            DocumentChild::CommentEnd(v) => v.build_to(w),
+            DocumentChild::StructuredDataTag(v) => v.build_to(w),
+            DocumentChild::TableOfContents(v) => v.build_to(w),
+            DocumentChild::AltChunk(v) => v.build_to(w),
+            DocumentChild::Unsupported(v) => w.write_all(v.as_bytes()),
         }
     }
 }
 
 impl BuildXML for Document {
-    fn build(&self) -> Vec<u8> {
-        XMLBuilder::new()
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        XMLBuilder::from_writer(w)
             .declaration(Some(true))
             .open_document()
             .open_body()
@@ -260,7 +272,8 @@ impl BuildXML for Document {
             .add_child(&self.section_property)
             .close()
             .close()
-            .build()
+            .into_inner()?;
+        Ok(())
     }
 }
 
@@ -10,8 +10,27 @@ pub struct DocumentRels {
     pub has_comments: bool,
     pub has_numberings: bool,
     pub has_footnotes: bool,
+    pub has_endnotes: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub has_web_settings: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub has_theme: bool,
     pub images: Vec<(String, String)>,
     pub hyperlinks: Vec<(String, String, String)>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub alt_chunks: Vec<(String, String)>,
+    // Ids, relationship types and targets of the parts added by `Docx::add_part`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub custom_parts: Vec<(String, String, String)>,
+    // Ids and targets of the charts, relative to the `word` folder.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub charts: Vec<(String, String)>,
+    // Path of the glossary document part, relative to the `word` folder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glossary_document: Option<String>,
+    // Path of the VBA project part, relative to the `word` folder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vba_project: Option<String>,
     pub custom_xml_count: usize,
     pub header_count: usize,
     pub footer_count: usize,
@@ -32,6 +51,11 @@ impl DocumentRels {
         self
     }
 
+    pub fn add_alt_chunk(mut self, id: impl Into<String>, path: impl Into<String>) -> Self {
+        self.alt_chunks.push((id.into(), escape(&path.into())));
+        self
+    }
+
     pub fn add_hyperlinks(
         mut self,
         id: impl Into<String>,
@@ -71,6 +95,22 @@ impl BuildXML for DocumentRels {
                 "commentsExtended.xml",
             );
 
+        if self.has_web_settings {
+            b = b.relationship(
+                "rId4",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/webSettings",
+                "webSettings.xml",
+            )
+        }
+
+        if self.has_theme {
+            b = b.relationship(
+                "rIdTheme",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme",
+                "theme/theme1.xml",
+            )
+        }
+
         if self.has_comments {
             b = b.relationship(
                 "rId6",
@@ -95,6 +135,14 @@ impl BuildXML for DocumentRels {
             )
         }
 
+        if self.has_endnotes {
+            b = b.relationship(
+                "rIdEndnotes",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/endnotes",
+                "endnotes.xml",
+            )
+        }
+
         for i in 0..self.header_count {
             b = b.relationship(
                 &create_header_rid(i + 1),
@@ -136,6 +184,42 @@ impl BuildXML for DocumentRels {
             )
         }
 
+        for (id, path) in self.alt_chunks.iter() {
+            b = b.relationship(
+                id,
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/aFChunk",
+                path,
+            )
+        }
+
+        for (id, r#type, target) in self.custom_parts.iter() {
+            b = b.relationship(id, r#type, &escape(target))
+        }
+
+        for (id, target) in self.charts.iter() {
+            b = b.relationship(
+                id,
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart",
+                &escape(target),
+            )
+        }
+
+        if let Some(path) = &self.glossary_document {
+            b = b.relationship(
+                "rIdGlossaryDocument",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/glossaryDocument",
+                &escape(path),
+            )
+        }
+
+        if let Some(path) = &self.vba_project {
+            b = b.relationship(
+                "rIdVbaProject",
+                "http://schemas.microsoft.com/office/2006/relationships/vbaProject",
+                &escape(path),
+            )
+        }
+
         b.close().build()
     }
 }
@@ -0,0 +1,551 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{Cursor, Read, Seek, Write};
+
+use xml::reader::{EventReader, XmlEvent};
+use zip::result::ZipResult;
+use zip::{ZipArchive, ZipWriter};
+
+use super::*;
+use crate::escape::escape;
+use crate::reader::ReaderError;
+use crate::zipper::{self, Entry};
+
+// A document opened to be modified. The package it was read from is kept, and only the parts
+// which the changes to `docx` touch are written again. The other parts, such as custom XML,
+// macros, embedded objects and parts docx-rs does not know, are copied byte for byte.
+#[derive(Debug, Clone)]
+pub struct EditableDocx {
+    pub docx: Docx,
+    original: Vec<u8>,
+    // The parts built from the document as it was read.
+    baseline: HashMap<String, Vec<u8>>,
+}
+
+// Parts which refer to each other by relationship ids and by the paths docx-rs gives them,
+// so they are written together when one of them changes.
+fn group(path: &str) -> Option<&'static str> {
+    let document = path == "word/document.xml"
+        || path == "word/_rels/document.xml.rels"
+        || path.starts_with("word/header")
+        || path.starts_with("word/footer")
+        || path.starts_with("word/_rels/header")
+        || path.starts_with("word/_rels/footer")
+        || path.starts_with("word/media/");
+    let font_table = path == "word/fontTable.xml"
+        || path == "word/_rels/fontTable.xml.rels"
+        || path.starts_with("word/fonts/");
+    if document {
+        Some("document")
+    } else if font_table {
+        Some("fontTable")
+    } else {
+        None
+    }
+}
+
+fn files(docx: Docx) -> Vec<(String, Vec<u8>)> {
+    zipper::entries(docx.build())
+        .into_iter()
+        .filter_map(|e| match e {
+            Entry::File(path, data) => Some((path, data)),
+            Entry::Directory(_) => None,
+        })
+        .collect()
+}
+
+impl EditableDocx {
+    pub fn open(buf: &[u8]) -> Result<EditableDocx, ReaderError> {
+        let docx = crate::read_docx(buf)?;
+        let baseline = files(docx.clone()).into_iter().collect();
+        Ok(EditableDocx {
+            docx,
+            original: buf.to_vec(),
+            baseline,
+        })
+    }
+
+    pub fn edit(mut self, f: impl FnOnce(Docx) -> Docx) -> EditableDocx {
+        self.docx = f(self.docx);
+        self
+    }
+
+    // The parts to be written again, in the order they are built.
+    fn rebuilt(&self) -> Vec<(String, Vec<u8>)> {
+        let current = files(self.docx.clone());
+        let changed: Vec<&str> = current
+            .iter()
+            .filter(|(path, data)| self.baseline.get(path) != Some(data))
+            .map(|(path, _)| path.as_str())
+            .collect();
+        let groups: HashSet<&str> = changed.iter().filter_map(|p| group(p)).collect();
+        let rebuilt: HashSet<String> = current
+            .iter()
+            .map(|(path, _)| path)
+            .filter(|p| {
+                changed.contains(&p.as_str()) || group(p).map_or(false, |g| groups.contains(g))
+            })
+            .cloned()
+            .collect();
+        current
+            .into_iter()
+            .filter(|(path, _)| rebuilt.contains(path))
+            .collect()
+    }
+
+    pub fn modified_parts(&self) -> Vec<String> {
+        self.rebuilt().into_iter().map(|(path, _)| path).collect()
+    }
+
+    pub fn pack<W>(&self, mut w: W) -> ZipResult<()>
+    where
+        W: Write + Seek,
+    {
+        let rebuilt = self.rebuilt();
+        if rebuilt.is_empty() {
+            w.write_all(&self.original)?;
+            return Ok(());
+        }
+        let mut archive = ZipArchive::new(Cursor::new(&self.original[..]))?;
+        let original_names: Vec<String> = archive.file_names().map(|n| n.to_owned()).collect();
+        let mut names: HashSet<&str> = original_names.iter().map(|n| n.as_str()).collect();
+        names.extend(rebuilt.iter().map(|(path, _)| path.as_str()));
+        let rebuilt_map: HashMap<&str, &[u8]> = rebuilt
+            .iter()
+            .map(|(path, data)| (path.as_str(), data.as_slice()))
+            .collect();
+
+        // The kept relationships whose ids are taken, by the part which refers to them.
+        let mut renames: HashMap<String, HashMap<String, String>> = HashMap::new();
+        for (path, data) in &rebuilt {
+            if let (Some(source), Ok(mut f)) = (source_part(path), archive.by_name(path)) {
+                let mut original = vec![];
+                f.read_to_end(&mut original)?;
+                let renamed = renamed_relationships(&original, data);
+                if !renamed.is_empty() {
+                    renames.insert(source, renamed);
+                }
+            }
+        }
+
+        let options = PackOptions::default();
+        let mut zip = ZipWriter::new(w);
+        let mut written = HashSet::new();
+        for i in 0..archive.len() {
+            let name = archive.by_index_raw(i)?.name().to_owned();
+            let renamed = renames.get(&name);
+            let rebuilt_data = rebuilt_map.get(name.as_str());
+            if rebuilt_data.is_none() && renamed.is_none() {
+                zip.raw_copy_file(archive.by_index_raw(i)?)?;
+                continue;
+            }
+            let mut original = vec![];
+            archive.by_index(i)?.read_to_end(&mut original)?;
+            let data = match rebuilt_data {
+                None => original,
+                Some(data) if name.ends_with(".rels") => {
+                    let renamed = source_part(&name).and_then(|s| renames.get(&s));
+                    merge_relationships(&original, data, renamed)
+                }
+                Some(data) if name == "[Content_Types].xml" => {
+                    merge_content_types(&original, data, &names)
+                }
+                Some(data) => data.to_vec(),
+            };
+            let data = match renamed {
+                Some(renamed) => rename_references(&data, renamed),
+                None => data,
+            };
+            zipper::start_file(&mut zip, name.as_str(), &options)?;
+            zip.write_all(&data)?;
+            written.insert(name);
+        }
+        for (path, data) in &rebuilt {
+            if !written.contains(path) {
+                zipper::start_file(&mut zip, path.as_str(), &options)?;
+                zip.write_all(data)?;
+            }
+        }
+        zip.finish()?;
+        Ok(())
+    }
+}
+
+type Attributes = Vec<(String, String)>;
+
+fn elements(xml: &[u8], local_name: &str) -> Vec<Attributes> {
+    EventReader::new(xml)
+        .into_iter()
+        .map_while(|e| e.ok())
+        .filter_map(|e| match e {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } if name.local_name == local_name => Some(
+                attributes
+                    .into_iter()
+                    .map(|a| (a.name.local_name, a.value))
+                    .collect(),
+            ),
+            _ => None,
+        })
+        .collect()
+}
+
+fn attribute<'a>(attributes: &'a Attributes, name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, v)| v.as_str())
+}
+
+fn element(local_name: &str, attributes: &Attributes) -> String {
+    let mut s = format!("<{}", local_name);
+    for (n, v) in attributes {
+        s.push_str(&format!(" {}=\"{}\"", n, escape(v)));
+    }
+    s.push_str(" />");
+    s
+}
+
+// Add the children at the end of the root element, which may be empty such as `<Types />`.
+fn append_children(xml: &[u8], root: &str, children: &str) -> Vec<u8> {
+    if children.is_empty() {
+        return xml.to_vec();
+    }
+    let xml = String::from_utf8_lossy(xml);
+    let end = format!("</{}>", root);
+    let xml = match xml.rfind(&end) {
+        Some(i) => format!("{}{}{}", &xml[..i], children, &xml[i..]),
+        None => match xml.trim_end().strip_suffix("/>") {
+            Some(start) => format!("{}>{}{}", start.trim_end(), children, end),
+            None => xml.into_owned(),
+        },
+    };
+    xml.into_bytes()
+}
+
+// The part whose relationships are in the `.rels` part, such as `word/document.xml` for
+// `word/_rels/document.xml.rels`. The package relationships have none.
+fn source_part(rels: &str) -> Option<String> {
+    let rels = rels.strip_suffix(".rels")?;
+    let (dir, name) = match rels.rsplit_once("_rels/") {
+        Some((dir, name)) if !name.is_empty() && !name.contains('/') => (dir, name),
+        _ => return None,
+    };
+    Some(format!("{}{}", dir, name))
+}
+
+// Relationships of the types docx-rs does not write, which are kept. docx-rs gives ids such as
+// `rId5` to its own relationships, so a kept relationship whose id is taken is given a new
+// one, which is returned by the original id.
+fn renamed_relationships(original: &[u8], rebuilt: &[u8]) -> HashMap<String, String> {
+    let rebuilt_rels = elements(rebuilt, "Relationship");
+    let original_rels = elements(original, "Relationship");
+    let types: HashSet<&str> = rebuilt_rels
+        .iter()
+        .filter_map(|r| attribute(r, "Type"))
+        .collect();
+    let mut ids: HashSet<String> = rebuilt_rels
+        .iter()
+        .chain(original_rels.iter())
+        .filter_map(|r| attribute(r, "Id"))
+        .map(|id| id.to_owned())
+        .collect();
+    let rebuilt_ids: HashSet<&str> = rebuilt_rels
+        .iter()
+        .filter_map(|r| attribute(r, "Id"))
+        .collect();
+    let mut renamed = HashMap::new();
+    let mut n = 1;
+    for r in &original_rels {
+        let id = attribute(r, "Id").unwrap_or_default();
+        let t = attribute(r, "Type").unwrap_or_default();
+        if types.contains(t) || !rebuilt_ids.contains(id) {
+            continue;
+        }
+        let new_id = loop {
+            let new_id = format!("rIdKept{}", n);
+            n += 1;
+            if !ids.contains(&new_id) {
+                break new_id;
+            }
+        };
+        ids.insert(new_id.clone());
+        renamed.insert(id.to_owned(), new_id);
+    }
+    renamed
+}
+
+// The relationships of the types docx-rs writes come from the rebuilt part. The others, which
+// refer to the parts docx-rs does not know, are kept, with the new ids of those renamed.
+fn merge_relationships(
+    original: &[u8],
+    rebuilt: &[u8],
+    renamed: Option<&HashMap<String, String>>,
+) -> Vec<u8> {
+    let types: HashSet<String> = elements(rebuilt, "Relationship")
+        .iter()
+        .filter_map(|r| attribute(r, "Type"))
+        .map(|t| t.to_owned())
+        .collect();
+    let kept: String = elements(original, "Relationship")
+        .into_iter()
+        .filter(|r| !types.contains(attribute(r, "Type").unwrap_or_default()))
+        .map(|mut r| {
+            for (name, v) in r.iter_mut() {
+                if name == "Id" {
+                    if let Some(new_id) = renamed.and_then(|m| m.get(v.as_str())) {
+                        *v = new_id.clone();
+                    }
+                }
+            }
+            element("Relationship", &r)
+        })
+        .collect();
+    append_children(rebuilt, "Relationships", &kept)
+}
+
+// Rewrite the ids of the renamed relationships in the `r:` attributes of a part, such as
+// `r:id="rId5"`.
+fn rename_references(xml: &[u8], renamed: &HashMap<String, String>) -> Vec<u8> {
+    let xml = String::from_utf8_lossy(xml);
+    let mut out = String::with_capacity(xml.len());
+    let mut rest: &str = &xml;
+    while let Some(i) = rest.find("r:") {
+        let (head, tail) = rest.split_at(i + 2);
+        out.push_str(head);
+        rest = tail;
+        if !head[..i].ends_with(|c: char| c.is_whitespace()) {
+            continue;
+        }
+        let name_end = tail
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-' || c == '.'))
+            .unwrap_or(tail.len());
+        let value = &tail[name_end..];
+        let quote = match value.strip_prefix('=').and_then(|v| v.chars().next()) {
+            Some(q) if q == '"' || q == '\'' => q,
+            _ => continue,
+        };
+        let value = &value[2..];
+        let end = match value.find(quote) {
+            Some(end) => end,
+            None => continue,
+        };
+        if let Some(new_id) = renamed.get(&value[..end]) {
+            out.push_str(&tail[..name_end]);
+            out.push('=');
+            out.push(quote);
+            out.push_str(new_id);
+            out.push(quote);
+            rest = &value[end + 1..];
+        }
+    }
+    out.push_str(rest);
+    out.into_bytes()
+}
+
+// The content types of the parts which are copied are kept.
+fn merge_content_types(original: &[u8], rebuilt: &[u8], names: &HashSet<&str>) -> Vec<u8> {
+    let extensions: HashSet<String> = elements(rebuilt, "Default")
+        .iter()
+        .filter_map(|d| attribute(d, "Extension"))
+        .map(|e| e.to_ascii_lowercase())
+        .collect();
+    let parts: HashSet<String> = elements(rebuilt, "Override")
+        .iter()
+        .filter_map(|o| attribute(o, "PartName"))
+        .map(|p| p.to_owned())
+        .collect();
+    let defaults = elements(original, "Default")
+        .into_iter()
+        .filter(|d| {
+            let ext = attribute(d, "Extension").unwrap_or_default();
+            !extensions.contains(&ext.to_ascii_lowercase())
+        })
+        .map(|d| element("Default", &d));
+    let overrides = elements(original, "Override")
+        .into_iter()
+        .filter(|o| {
+            let part = attribute(o, "PartName").unwrap_or_default();
+            !parts.contains(part) && names.contains(part.trim_start_matches('/'))
+        })
+        .map(|o| element("Override", &o));
+    let kept: String = defaults.chain(overrides).collect();
+    append_children(rebuilt, "Types", &kept)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    const UNKNOWN_TYPE: &str = "http://example.com/relationships/unknown";
+
+    fn part(buf: &[u8], name: &str) -> Vec<u8> {
+        let mut archive = ZipArchive::new(Cursor::new(buf)).unwrap();
+        let mut data = vec![];
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_end(&mut data)
+            .unwrap();
+        data
+    }
+
+    // A package with a part which docx-rs does not know, its relationship added with `rels`.
+    fn package_with(
+        rels: impl Fn(&[u8]) -> Vec<u8>,
+        document: impl Fn(&[u8]) -> Vec<u8>,
+    ) -> Vec<u8> {
+        let mut buf = Cursor::new(vec![]);
+        Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+            .build()
+            .pack(&mut buf)
+            .unwrap();
+        let buf = buf.into_inner();
+        let mut archive = ZipArchive::new(Cursor::new(&buf[..])).unwrap();
+        let mut zip = ZipWriter::new(Cursor::new(vec![]));
+        let options = zip::write::FileOptions::default();
+        for i in 0..archive.len() {
+            let mut f = archive.by_index(i).unwrap();
+            let name = f.name().to_owned();
+            let mut data = vec![];
+            f.read_to_end(&mut data).unwrap();
+            let data = match name.as_str() {
+                "word/_rels/document.xml.rels" => rels(&data),
+                "word/document.xml" => document(&data),
+                "[Content_Types].xml" => append_children(
+                    &data,
+                    "Types",
+                    r#"<Override PartName="/word/unknown.xml" ContentType="application/x-unknown+xml" />"#,
+                ),
+                _ => data,
+            };
+            if name.ends_with('/') {
+                zip.add_directory(name, options).unwrap();
+            } else {
+                zip.start_file(name, options).unwrap();
+                zip.write_all(&data).unwrap();
+            }
+        }
+        zip.start_file("word/unknown.xml", options).unwrap();
+        zip.write_all(b"<unknown>kept</unknown>").unwrap();
+        zip.finish().unwrap().into_inner()
+    }
+
+    fn package() -> Vec<u8> {
+        package_with(
+            |rels| {
+                append_children(
+                    rels,
+                    "Relationships",
+                    &format!(
+                        r#"<Relationship Id="rIdUnknown" Type="{}" Target="unknown.xml" />"#,
+                        UNKNOWN_TYPE
+                    ),
+                )
+            },
+            |document| document.to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_pack_unchanged() {
+        let original = package();
+        let editable = EditableDocx::open(&original).unwrap();
+        assert!(editable.modified_parts().is_empty());
+        let mut buf = Cursor::new(vec![]);
+        editable.pack(&mut buf).unwrap();
+        assert_eq!(buf.into_inner(), original);
+    }
+
+    #[test]
+    fn test_pack_edited() {
+        let original = package();
+        let editable = EditableDocx::open(&original).unwrap().edit(|docx| {
+            docx.add_paragraph(Paragraph::new().add_run(Run::new().add_text("World")))
+        });
+        let modified = editable.modified_parts();
+        assert!(modified.contains(&"word/document.xml".to_owned()));
+        assert!(!modified.contains(&"word/styles.xml".to_owned()));
+
+        let mut buf = Cursor::new(vec![]);
+        editable.pack(&mut buf).unwrap();
+        let buf = buf.into_inner();
+        assert_eq!(part(&buf, "word/unknown.xml"), b"<unknown>kept</unknown>");
+        assert_eq!(
+            part(&buf, "word/styles.xml"),
+            part(&original, "word/styles.xml")
+        );
+        let rels = String::from_utf8(part(&buf, "word/_rels/document.xml.rels")).unwrap();
+        assert!(rels.contains(UNKNOWN_TYPE));
+        assert_eq!(rels.matches("relationships/styles\"").count(), 1);
+        let content_types = String::from_utf8(part(&buf, "[Content_Types].xml")).unwrap();
+        assert!(content_types.contains("/word/unknown.xml"));
+
+        let read = crate::read_docx(&buf).unwrap();
+        assert_eq!(read.document.children.len(), 2);
+    }
+
+    #[test]
+    fn test_pack_renames_taken_relationship_ids() {
+        // The unknown part has the id of `commentsExtended.xml` in the rebuilt relationships.
+        let original = package_with(
+            |rels| {
+                let rels = String::from_utf8_lossy(rels).replace(r#"Id="rId5""#, r#"Id="rIdX""#);
+                append_children(
+                    rels.as_bytes(),
+                    "Relationships",
+                    &format!(
+                        r#"<Relationship Id="rId5" Type="{}" Target="unknown.xml" />"#,
+                        UNKNOWN_TYPE
+                    ),
+                )
+            },
+            |document| {
+                String::from_utf8_lossy(document)
+                    .replacen("</w:p>", r#"<w:subDoc r:id="rId5" /></w:p>"#, 1)
+                    .into_bytes()
+            },
+        );
+        let editable = EditableDocx::open(&original).unwrap().edit(|docx| {
+            docx.add_paragraph(Paragraph::new().add_run(Run::new().add_text("World")))
+        });
+        let mut buf = Cursor::new(vec![]);
+        editable.pack(&mut buf).unwrap();
+        let buf = buf.into_inner();
+
+        let rels = String::from_utf8(part(&buf, "word/_rels/document.xml.rels")).unwrap();
+        assert!(rels.contains(&format!(
+            r#"<Relationship Id="rIdKept1" Type="{}" Target="unknown.xml" />"#,
+            UNKNOWN_TYPE
+        )));
+        assert!(rels.contains(r#"<Relationship Id="rId5" Type="http://schemas.microsoft.com/office/2011/relationships/commentsExtended" Target="commentsExtended.xml" />"#));
+        assert_eq!(rels.matches(r#"Id="rId5""#).count(), 1);
+        let document = String::from_utf8(part(&buf, "word/document.xml")).unwrap();
+        assert!(document.contains(r#"r:id="rIdKept1" />"#));
+        assert!(!document.contains(r#"r:id="rId5""#));
+    }
+
+    #[test]
+    fn test_rename_references() {
+        let renamed: HashMap<String, String> = vec![("rId5".to_owned(), "rIdKept1".to_owned())]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            String::from_utf8(rename_references(
+                br#"<w:object r:id="rId5"><v:imagedata r:pict='rId5' o:title="rId5" /><w:t>r:id="rId5"</w:t></w:object>"#,
+                &renamed,
+            ))
+            .unwrap(),
+            r#"<w:object r:id="rIdKept1"><v:imagedata r:pict='rIdKept1' o:title="rId5" /><w:t>r:id="rId5"</w:t></w:object>"#
+        );
+        assert_eq!(
+            source_part("word/_rels/document.xml.rels").as_deref(),
+            Some("word/document.xml")
+        );
+        assert_eq!(source_part("_rels/.rels"), None);
+    }
+}
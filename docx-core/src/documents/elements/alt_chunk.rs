@@ -0,0 +1,43 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::escape::escape;
+use crate::xml_builder::*;
+
+// Place of the body where the application imports the content of another part, such as
+// an HTML or RTF file. `id` is the relationship id of `AltChunkPart`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct AltChunk {
+    pub id: String,
+}
+
+impl AltChunk {
+    pub fn new(id: impl Into<String>) -> AltChunk {
+        AltChunk { id: id.into() }
+    }
+}
+
+impl BuildXML for AltChunk {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        b.alt_chunk(&escape(&self.id)).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_alt_chunk() {
+        let b = AltChunk::new("AltChunkId1").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:altChunk r:id="AltChunkId1" />"#
+        );
+    }
+}
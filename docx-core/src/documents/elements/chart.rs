@@ -0,0 +1,84 @@
+use serde::Serialize;
+
+use crate::types::*;
+
+// Chart of a `c:chart` drawing. The data is read from the cached values of the chart part,
+// which Word keeps in sync with the embedded workbook.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Chart {
+    // Relationship id of the chart part.
+    pub id: String,
+    // unit is emu
+    pub size: (u32, u32),
+    // name and descr (alt text) of wp:docPr
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    // Type of the first chart of the plot area.
+    pub chart_type: Option<ChartType>,
+    pub title: Option<String>,
+    pub series: Vec<ChartSeries>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartSeries {
+    pub name: Option<String>,
+    // x values of scatter and bubble charts.
+    pub categories: Vec<String>,
+    // Blank cells have no value.
+    pub values: Vec<Option<f64>>,
+}
+
+impl Chart {
+    pub fn new(id: impl Into<String>) -> Chart {
+        Chart {
+            id: id.into(),
+            ..Default::default()
+        }
+    }
+
+    // unit is emu
+    pub fn size(mut self, w_emu: u32, h_emu: u32) -> Chart {
+        self.size = (w_emu, h_emu);
+        self
+    }
+
+    pub fn chart_type(mut self, t: ChartType) -> Chart {
+        self.chart_type = Some(t);
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Chart {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn add_series(mut self, s: ChartSeries) -> Chart {
+        self.series.push(s);
+        self
+    }
+}
+
+impl ChartSeries {
+    pub fn new() -> ChartSeries {
+        Default::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> ChartSeries {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn categories(mut self, categories: Vec<String>) -> ChartSeries {
+        self.categories = categories;
+        self
+    }
+
+    pub fn values(mut self, values: Vec<Option<f64>>) -> ChartSeries {
+        self.values = values;
+        self
+    }
+}
@@ -1,22 +1,66 @@
 use serde::{Deserialize, Serialize, Serializer};
 
-use crate::documents::BuildXML;
+use crate::documents::{tint_and_shade, BuildXML, Theme};
 use crate::xml_builder::*;
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Color {
-    val: String,
+    pub val: String,
+    #[serde(default)]
+    pub theme_color: Option<String>,
+    #[serde(default)]
+    pub theme_tint: Option<String>,
+    #[serde(default)]
+    pub theme_shade: Option<String>,
 }
 
 impl Color {
     pub fn new(val: impl Into<String>) -> Color {
-        Color { val: val.into() }
+        Color {
+            val: val.into(),
+            theme_color: None,
+            theme_tint: None,
+            theme_shade: None,
+        }
+    }
+
+    pub fn theme_color(mut self, theme_color: impl Into<String>) -> Color {
+        self.theme_color = Some(theme_color.into());
+        self
+    }
+
+    pub fn theme_tint(mut self, tint: impl Into<String>) -> Color {
+        self.theme_tint = Some(tint.into());
+        self
+    }
+
+    pub fn theme_shade(mut self, shade: impl Into<String>) -> Color {
+        self.theme_shade = Some(shade.into());
+        self
+    }
+
+    // The RGB value, with the theme color if the theme has it. `val` is only the fallback
+    // of the theme color.
+    pub fn resolve(&self, theme: &Theme) -> String {
+        match self.theme_color.as_ref().and_then(|c| theme.color(c)) {
+            Some(rgb) => {
+                tint_and_shade(rgb, self.theme_tint.as_deref(), self.theme_shade.as_deref())
+            }
+            None => self.val.clone(),
+        }
     }
 }
 
 impl BuildXML for Color {
     fn build(&self) -> Vec<u8> {
-        XMLBuilder::new().color(&self.val).build()
+        XMLBuilder::new()
+            .color_with_theme(
+                &self.val,
+                self.theme_color.as_ref(),
+                self.theme_tint.as_ref(),
+                self.theme_shade.as_ref(),
+            )
+            .build()
     }
 }
 
@@ -43,4 +87,20 @@ mod tests {
         let b = c.build();
         assert_eq!(str::from_utf8(&b).unwrap(), r#"<w:color w:val="FFFFFF" />"#);
     }
+
+    #[test]
+    fn test_theme_color() {
+        let c = Color::new("4472C4")
+            .theme_color("accent1")
+            .theme_shade("BF");
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:color w:val="4472C4" w:themeColor="accent1" w:themeShade="BF" />"#
+        );
+        let mut theme = Theme::default();
+        assert_eq!(c.resolve(&theme), "4472C4");
+        theme.color_scheme.accent1 = "5B9BD5".to_owned();
+        assert_eq!(c.resolve(&theme), "4474A0");
+    }
 }
@@ -0,0 +1,131 @@
+use serde::Serialize;
+
+// Colors of the theme as RGB hex values, such as `4472C4`. System colors are kept as
+// their last computed value.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ColorScheme {
+    pub name: String,
+    pub dk1: String,
+    pub lt1: String,
+    pub dk2: String,
+    pub lt2: String,
+    pub accent1: String,
+    pub accent2: String,
+    pub accent3: String,
+    pub accent4: String,
+    pub accent5: String,
+    pub accent6: String,
+    pub hlink: String,
+    pub fol_hlink: String,
+}
+
+impl ColorScheme {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn dk1(mut self, rgb: impl Into<String>) -> Self {
+        self.dk1 = rgb.into();
+        self
+    }
+
+    pub fn lt1(mut self, rgb: impl Into<String>) -> Self {
+        self.lt1 = rgb.into();
+        self
+    }
+
+    pub fn dk2(mut self, rgb: impl Into<String>) -> Self {
+        self.dk2 = rgb.into();
+        self
+    }
+
+    pub fn lt2(mut self, rgb: impl Into<String>) -> Self {
+        self.lt2 = rgb.into();
+        self
+    }
+
+    pub fn accent1(mut self, rgb: impl Into<String>) -> Self {
+        self.accent1 = rgb.into();
+        self
+    }
+
+    pub fn accent2(mut self, rgb: impl Into<String>) -> Self {
+        self.accent2 = rgb.into();
+        self
+    }
+
+    pub fn accent3(mut self, rgb: impl Into<String>) -> Self {
+        self.accent3 = rgb.into();
+        self
+    }
+
+    pub fn accent4(mut self, rgb: impl Into<String>) -> Self {
+        self.accent4 = rgb.into();
+        self
+    }
+
+    pub fn accent5(mut self, rgb: impl Into<String>) -> Self {
+        self.accent5 = rgb.into();
+        self
+    }
+
+    pub fn accent6(mut self, rgb: impl Into<String>) -> Self {
+        self.accent6 = rgb.into();
+        self
+    }
+
+    pub fn hlink(mut self, rgb: impl Into<String>) -> Self {
+        self.hlink = rgb.into();
+        self
+    }
+
+    pub fn fol_hlink(mut self, rgb: impl Into<String>) -> Self {
+        self.fol_hlink = rgb.into();
+        self
+    }
+
+    // The color of an `a:clrScheme` child such as `accent1`.
+    pub(crate) fn slot(&self, name: &str) -> Option<&str> {
+        match name {
+            "dk1" => Some(&self.dk1),
+            "lt1" => Some(&self.lt1),
+            "dk2" => Some(&self.dk2),
+            "lt2" => Some(&self.lt2),
+            "accent1" => Some(&self.accent1),
+            "accent2" => Some(&self.accent2),
+            "accent3" => Some(&self.accent3),
+            "accent4" => Some(&self.accent4),
+            "accent5" => Some(&self.accent5),
+            "accent6" => Some(&self.accent6),
+            "hlink" => Some(&self.hlink),
+            "folHlink" => Some(&self.fol_hlink),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn slot_mut(&mut self, name: &str) -> Option<&mut String> {
+        match name {
+            "dk1" => Some(&mut self.dk1),
+            "lt1" => Some(&mut self.lt1),
+            "dk2" => Some(&mut self.dk2),
+            "lt2" => Some(&mut self.lt2),
+            "accent1" => Some(&mut self.accent1),
+            "accent2" => Some(&mut self.accent2),
+            "accent3" => Some(&mut self.accent3),
+            "accent4" => Some(&mut self.accent4),
+            "accent5" => Some(&mut self.accent5),
+            "accent6" => Some(&mut self.accent6),
+            "hlink" => Some(&mut self.hlink),
+            "folHlink" => Some(&mut self.fol_hlink),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+// A column of a section with columns of different widths, i.e. `<w:col w:w="..." w:space="..." />`.
+// Sizes are in twips, `space` being the gap after the column.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Column {
+    pub width: usize,
+    pub space: usize,
+}
+
+impl Column {
+    pub fn new(width: usize) -> Column {
+        Column { width, space: 0 }
+    }
+
+    pub fn space(mut self, space: usize) -> Column {
+        self.space = space;
+        self
+    }
+}
@@ -12,6 +12,8 @@ pub struct Comment {
     pub date: String,
     pub children: Vec<CommentChild>,
     pub parent_comment_id: Option<usize>,
+    // Resolved, stored as `w15:done` in commentsExtended.xml.
+    pub done: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -50,6 +52,7 @@ impl Default for Comment {
             date: "1970-01-01T00:00:00Z".to_owned(),
             children: vec![],
             parent_comment_id: None,
+            done: false,
         }
     }
 }
@@ -87,6 +90,11 @@ impl Comment {
         self
     }
 
+    pub fn done(mut self) -> Comment {
+        self.done = true;
+        self
+    }
+
     pub fn id(&self) -> usize {
         self.id
     }
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompatSetting {
+    pub name: String,
+    pub uri: String,
+    pub val: String,
+}
+
+impl CompatSetting {
+    pub fn new(
+        name: impl Into<String>,
+        uri: impl Into<String>,
+        val: impl Into<String>,
+    ) -> CompatSetting {
+        CompatSetting {
+            name: name.into(),
+            uri: uri.into(),
+            val: val.into(),
+        }
+    }
+}
+
+impl BuildXML for CompatSetting {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        b.compat_setting(&self.name, &self.uri, &self.val).build()
+    }
+}
@@ -0,0 +1,167 @@
+use serde::Serialize;
+use std::str::FromStr;
+
+use crate::documents::*;
+use crate::escape::escape;
+use crate::types::*;
+use crate::xml_builder::*;
+use std::io::{self, Write};
+
+// A field made of `w:fldChar` begin, separate and end runs, such as HYPERLINK, TOC or
+// MERGEFIELD. `instruction` is the text of its `w:instrText`s, such as
+// ` MERGEFIELD Name \* MERGEFORMAT `, and `cached_result` what was shown for the field when it
+// was last updated. Fields in the result are nested fields.
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ComplexField {
+    pub instruction: String,
+    pub cached_result: Vec<ParagraphChild>,
+    // The properties of the runs of the field characters.
+    pub run_property: RunProperty,
+    pub dirty: bool,
+}
+
+impl ComplexField {
+    pub fn new(instruction: impl Into<String>) -> ComplexField {
+        ComplexField {
+            instruction: instruction.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn add_result_run(mut self, run: Run) -> Self {
+        self.cached_result.push(ParagraphChild::Run(Box::new(run)));
+        self
+    }
+
+    pub fn add_result_field(mut self, field: ComplexField) -> Self {
+        self.cached_result
+            .push(ParagraphChild::ComplexField(Box::new(field)));
+        self
+    }
+
+    pub fn run_property(mut self, p: RunProperty) -> Self {
+        self.run_property = p;
+        self
+    }
+
+    pub fn dirty(mut self) -> Self {
+        self.dirty = true;
+        self
+    }
+
+    // The name of the field such as `HYPERLINK`, in upper case.
+    pub fn field_type(&self) -> String {
+        self.instruction
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_ascii_uppercase()
+    }
+
+    // The instruction parsed for the fields which are supported, `InstrText::Unsupported`
+    // otherwise.
+    pub fn instr_text(&self) -> InstrText {
+        let instruction = self.instruction.trim();
+        let args = instruction
+            .split_once(' ')
+            .map(|(_, args)| args.trim())
+            .unwrap_or_default();
+        let instr = match self.field_type().as_str() {
+            "TOC" => InstrToC::from_str(args).ok().map(InstrText::TOC),
+            "TC" => InstrTC::from_str(args).ok().map(InstrText::TC),
+            "PAGE" => Some(InstrText::PAGE(InstrPAGE::new())),
+            "NUMPAGES" => Some(InstrText::NUMPAGES(InstrNUMPAGES::new())),
+            "PAGEREF" => InstrPAGEREF::from_str(args).ok().map(InstrText::PAGEREF),
+            "HYPERLINK" => InstrHyperlink::from_str(args)
+                .ok()
+                .map(InstrText::HYPERLINK),
+            _ => None,
+        };
+        instr.unwrap_or_else(|| InstrText::Unsupported(self.instruction.clone()))
+    }
+
+    // The text of the cached result.
+    pub fn result_text(&self) -> String {
+        Paragraph {
+            children: self.cached_result.clone(),
+            ..Default::default()
+        }
+        .raw_text()
+    }
+
+    fn field_char_run(&self, t: FieldCharType) -> Run {
+        Run {
+            run_property: self.run_property.clone(),
+            ..Default::default()
+        }
+        .add_field_char(t, t == FieldCharType::Begin && self.dirty)
+    }
+}
+
+impl BuildXML for ComplexField {
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        let begin = self
+            .field_char_run(FieldCharType::Begin)
+            .add_instr_text(InstrText::Unsupported(escape(&self.instruction)));
+        let mut b = XMLBuilder::from_writer(w).add_child(&begin);
+        if !self.cached_result.is_empty() {
+            b = b
+                .add_child(&self.field_char_run(FieldCharType::Separate))
+                .add_children(&self.cached_result);
+        }
+        b.add_child(&self.field_char_run(FieldCharType::End))
+            .into_inner()?;
+        Ok(())
+    }
+}
+
+impl BuildXML for Box<ComplexField> {
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        self.as_ref().build_to(w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_complex_field() {
+        let b = ComplexField::new(r#" HYPERLINK "https://example.com/?a&b" "#)
+            .add_result_run(Run::new().add_text("Example"))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:r><w:rPr /><w:fldChar w:fldCharType="begin" w:dirty="false" /><w:instrText> HYPERLINK &quot;https://example.com/?a&amp;b&quot; </w:instrText></w:r><w:r><w:rPr /><w:fldChar w:fldCharType="separate" w:dirty="false" /></w:r><w:r><w:rPr /><w:t xml:space="preserve">Example</w:t></w:r><w:r><w:rPr /><w:fldChar w:fldCharType="end" w:dirty="false" /></w:r>"#
+        );
+    }
+
+    #[test]
+    fn test_instr_text() {
+        let f = ComplexField::new(r#" HYPERLINK "https://example.com" \l "#);
+        assert_eq!(f.field_type(), "HYPERLINK");
+        assert_eq!(
+            f.instr_text(),
+            InstrText::HYPERLINK(InstrHyperlink {
+                target: "https://example.com".to_owned(),
+                anchor: true,
+            })
+        );
+        let f = ComplexField::new(r" PAGEREF _Toc1 \h ");
+        assert_eq!(
+            f.instr_text(),
+            InstrText::PAGEREF(InstrPAGEREF::new("_Toc1").hyperlink())
+        );
+        let f = ComplexField::new(" MERGEFIELD Name ");
+        assert_eq!(f.field_type(), "MERGEFIELD");
+        assert_eq!(
+            f.instr_text(),
+            InstrText::Unsupported(" MERGEFIELD Name ".to_owned())
+        );
+    }
+}
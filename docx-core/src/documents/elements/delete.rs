@@ -3,12 +3,16 @@ use serde::Serialize;
 
 use crate::xml_builder::*;
 use crate::{documents::*, escape};
+use std::io::{self, Write};
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
 pub struct Delete {
     pub author: String,
     pub date: String,
     pub children: Vec<DeleteChild>,
+    // Deleted by moving the content elsewhere, i.e. `w:moveFrom`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub moved: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -52,6 +56,7 @@ impl Default for Delete {
             author: "unnamed".to_owned(),
             date: "1970-01-01T00:00:00Z".to_owned(),
             children: vec![],
+            moved: false,
         }
     }
 }
@@ -92,15 +97,24 @@ impl Delete {
         self.date = date.into();
         self
     }
+
+    pub fn moved(mut self) -> Delete {
+        self.moved = true;
+        self
+    }
 }
 
 impl HistoryId for Delete {}
 
 impl BuildXML for Delete {
     #[allow(clippy::needless_borrow)]
-    fn build(&self) -> Vec<u8> {
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
         let id = self.generate();
-        let mut b = XMLBuilder::new().open_delete(&id, &self.author, &self.date);
+        let mut b = if self.moved {
+            XMLBuilder::from_writer(w).open_move_from(&id, &self.author, &self.date)
+        } else {
+            XMLBuilder::from_writer(w).open_delete(&id, &self.author, &self.date)
+        };
         for c in &self.children {
             match c {
                 DeleteChild::Run(t) => b = b.add_child(t),
@@ -108,7 +122,8 @@ impl BuildXML for Delete {
                 DeleteChild::CommentEnd(c) => b = b.add_child(c),
             }
         }
-        b.close().build()
+        b.close().into_inner()?;
+        Ok(())
     }
 }
 
@@ -7,7 +7,7 @@ use crate::xml_builder::*;
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DeleteText {
-    text: String,
+    pub text: String,
     preserve_space: bool,
 }
 
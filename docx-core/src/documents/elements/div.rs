@@ -1,13 +1,24 @@
 use serde::Serialize;
 
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+use super::ParagraphBorder;
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Div {
     pub id: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub block_quote: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub body_div: bool,
     pub margin_left: usize,
     pub margin_right: usize,
     pub margin_top: usize,
     pub margin_bottom: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub borders: Vec<ParagraphBorder>,
     pub divs_child: Vec<Div>,
 }
 
@@ -15,10 +26,13 @@ impl Default for Div {
     fn default() -> Self {
         Self {
             id: "".to_string(),
+            block_quote: false,
+            body_div: false,
             margin_left: 0,
             margin_right: 0,
             margin_top: 0,
             margin_bottom: 0,
+            borders: vec![],
             divs_child: vec![],
         }
     }
@@ -32,6 +46,16 @@ impl Div {
         }
     }
 
+    pub fn block_quote(mut self) -> Self {
+        self.block_quote = true;
+        self
+    }
+
+    pub fn body_div(mut self) -> Self {
+        self.body_div = true;
+        self
+    }
+
     pub fn margin_left(mut self, s: usize) -> Self {
         self.margin_left = s;
         self
@@ -52,8 +76,77 @@ impl Div {
         self
     }
 
+    // Borders are written in the order they are added, which should be top, left, bottom
+    // and right.
+    pub fn add_border(mut self, b: ParagraphBorder) -> Self {
+        self.borders.push(b);
+        self
+    }
+
     pub fn add_child(mut self, s: Div) -> Self {
         self.divs_child.push(s);
         self
     }
 }
+
+impl BuildXML for Div {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new().open_div(&self.id);
+        if self.block_quote {
+            b = b.block_quote();
+        }
+        if self.body_div {
+            b = b.body_div();
+        }
+        b = b
+            .div_margin_left(&self.margin_left.to_string())
+            .div_margin_right(&self.margin_right.to_string())
+            .div_margin_top(&self.margin_top.to_string())
+            .div_margin_bottom(&self.margin_bottom.to_string());
+        if !self.borders.is_empty() {
+            b = b.open_div_border().add_children(&self.borders).close();
+        }
+        if !self.divs_child.is_empty() {
+            b = b.open_divs_child().add_children(&self.divs_child).close();
+        }
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_div() {
+        let b = Div::new("1")
+            .body_div()
+            .margin_left(10)
+            .add_border(ParagraphBorder::new(ParagraphBorderPosition::Top).size(4))
+            .add_child(Div::new("2").block_quote())
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:div w:id="1">
+  <w:bodyDiv />
+  <w:marLeft w:val="10" />
+  <w:marRight w:val="0" />
+  <w:marTop w:val="0" />
+  <w:marBottom w:val="0" />
+  <w:divBdr><w:top w:val="single" w:space="0" w:sz="4" w:color="auto" /></w:divBdr>
+  <w:divsChild><w:div w:id="2">
+  <w:blockQuote />
+  <w:marLeft w:val="0" />
+  <w:marRight w:val="0" />
+  <w:marTop w:val="0" />
+  <w:marBottom w:val="0" />
+</w:div></w:divsChild>
+</w:div>"#
+        );
+    }
+}
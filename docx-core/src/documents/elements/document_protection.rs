@@ -0,0 +1,338 @@
+use serde::Serialize;
+
+use crate::documents::{sha1, sha256, sha512, BuildXML};
+use crate::types::*;
+use crate::xml_builder::*;
+
+// The spin count which Word uses when it protects a document.
+pub const DEFAULT_PROTECTION_SPIN_COUNT: u32 = 100_000;
+
+// The key of the legacy Word password hash, which is hashed again by the algorithm of the
+// protection (ECMA-376 Part 4, 2.15.1.28 documentProtection).
+const INITIAL_CODE_ARRAY: [u16; 15] = [
+    0xE1F0, 0x1D0F, 0xCC9C, 0x84C0, 0x110C, 0x0E10, 0xF1CE, 0x313E, 0x1872, 0xE139, 0xD40F, 0x84F9,
+    0x280C, 0xA96A, 0x4EC3,
+];
+
+const ENCRYPTION_MATRIX: [[u16; 7]; 15] = [
+    [0xAEFC, 0x4DD9, 0x9BB2, 0x2745, 0x4E8A, 0x9D14, 0x2A09],
+    [0x7B61, 0xF6C2, 0xFDA5, 0xEB6B, 0xC6F7, 0x9DCF, 0x2BBF],
+    [0x4563, 0x8AC6, 0x05AD, 0x0B5A, 0x16B4, 0x2D68, 0x5AD0],
+    [0x0375, 0x06EA, 0x0DD4, 0x1BA8, 0x3750, 0x6EA0, 0xDD40],
+    [0xD849, 0xA0B3, 0x5147, 0xA28E, 0x553D, 0xAA7A, 0x44D5],
+    [0x6F45, 0xDE8A, 0xAD35, 0x4A4B, 0x9496, 0x390D, 0x721A],
+    [0xEB23, 0xC667, 0x9CEF, 0x29FF, 0x53FE, 0xA7FC, 0x5FD9],
+    [0x47D3, 0x8FA6, 0x0F6D, 0x1EDA, 0x3DB4, 0x7B68, 0xF6D0],
+    [0xB861, 0x60E3, 0xC1C6, 0x93AD, 0x377B, 0x6EF6, 0xDDEC],
+    [0x45A0, 0x8B40, 0x06A1, 0x0D42, 0x1A84, 0x3508, 0x6A10],
+    [0xAA51, 0x4483, 0x8906, 0x022D, 0x045A, 0x08B4, 0x1168],
+    [0x76B4, 0xED68, 0xCAF1, 0x85C3, 0x1BA7, 0x374E, 0x6E9C],
+    [0x3730, 0x6E60, 0xDCC0, 0xA9A1, 0x4363, 0x86C6, 0x1DAD],
+    [0x3331, 0x6662, 0xCCC4, 0x89A9, 0x0373, 0x06E6, 0x0DCC],
+    [0x1021, 0x2042, 0x4084, 0x8108, 0x1231, 0x2462, 0x48C4],
+];
+
+fn legacy_password_key(password: &str) -> u32 {
+    // Up to 15 characters, each of them as its low byte, or its high byte if the low one is 0.
+    let bytes: Vec<u8> = password
+        .encode_utf16()
+        .take(15)
+        .map(|c| match (c & 0xFF) as u8 {
+            0 => (c >> 8) as u8,
+            low => low,
+        })
+        .collect();
+    if bytes.is_empty() {
+        return 0;
+    }
+    let mut high = INITIAL_CODE_ARRAY[bytes.len() - 1];
+    for (i, b) in bytes.iter().enumerate() {
+        let row = &ENCRYPTION_MATRIX[15 - bytes.len() + i];
+        for (bit, v) in row.iter().enumerate() {
+            if b & (1 << bit) != 0 {
+                high ^= v;
+            }
+        }
+    }
+    let rotate = |v: u16| ((v >> 14) & 1) | ((v << 1) & 0x7FFF);
+    let mut low = 0u16;
+    for b in bytes.iter().rev() {
+        low = rotate(low) ^ u16::from(*b);
+    }
+    low = rotate(low) ^ bytes.len() as u16 ^ 0xCE4B;
+    (u32::from(high) << 16) | u32::from(low)
+}
+
+fn hash_password(password: &str, algorithm: &str, salt: &[u8], spin_count: u32) -> Option<Vec<u8>> {
+    let digest: fn(&[u8]) -> Vec<u8> = match algorithm {
+        "SHA-1" => sha1,
+        "SHA-256" => sha256,
+        "SHA-512" => sha512,
+        _ => return None,
+    };
+    // The legacy key in reversed byte order as an upper case hex string, in UTF-16LE.
+    let mut key = String::new();
+    for b in legacy_password_key(password).to_le_bytes().iter() {
+        key.push_str(&format!("{:02X}", b));
+    }
+    let key: Vec<u8> = key.encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+    let mut hash = digest(&[salt, &key[..]].concat());
+    for i in 0..spin_count {
+        hash = digest(&[&hash[..], &i.to_le_bytes()[..]].concat());
+    }
+    Some(hash)
+}
+
+// Password hash of a protection. The legacy attributes such as `w:cryptAlgorithmSid` are
+// read into these, and written as the attributes of Word 2010 and later.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtectionHash {
+    pub algorithm_name: Option<String>,
+    pub hash_value: Option<String>,
+    pub salt_value: Option<String>,
+    pub spin_count: Option<u32>,
+}
+
+impl ProtectionHash {
+    pub fn new() -> ProtectionHash {
+        Default::default()
+    }
+
+    pub fn algorithm_name(mut self, v: impl Into<String>) -> Self {
+        self.algorithm_name = Some(v.into());
+        self
+    }
+
+    pub fn hash_value(mut self, v: impl Into<String>) -> Self {
+        self.hash_value = Some(v.into());
+        self
+    }
+
+    pub fn salt_value(mut self, v: impl Into<String>) -> Self {
+        self.salt_value = Some(v.into());
+        self
+    }
+
+    pub fn spin_count(mut self, v: u32) -> Self {
+        self.spin_count = Some(v);
+        self
+    }
+
+    // The SHA-512 hash of the password, as Word writes it.
+    pub fn from_password(password: &str, salt: &[u8], spin_count: u32) -> ProtectionHash {
+        let hash = hash_password(password, "SHA-512", salt, spin_count).unwrap_or_default();
+        ProtectionHash::new()
+            .algorithm_name("SHA-512")
+            .hash_value(base64::encode(hash))
+            .salt_value(base64::encode(salt))
+            .spin_count(spin_count)
+    }
+
+    // Whether the password removes the protection. Hashes of unknown algorithms never match.
+    pub fn matches(&self, password: &str) -> bool {
+        let (algorithm, expected) = match (&self.algorithm_name, &self.hash_value) {
+            (Some(a), Some(h)) => (a, h),
+            _ => return false,
+        };
+        let salt = self
+            .salt_value
+            .as_ref()
+            .and_then(|s| base64::decode(s).ok())
+            .unwrap_or_default();
+        let hash = hash_password(
+            password,
+            algorithm,
+            &salt,
+            self.spin_count.unwrap_or_default(),
+        );
+        match (hash, base64::decode(expected)) {
+            (Some(hash), Ok(expected)) => hash == expected,
+            _ => false,
+        }
+    }
+
+    // The Windows algorithm id of the legacy `w:cryptAlgorithmSid`.
+    pub(crate) fn algorithm_sid(&self) -> Option<&'static str> {
+        let sid = match self.algorithm_name.as_deref()? {
+            "MD2" => "1",
+            "MD4" => "2",
+            "MD5" => "3",
+            "SHA-1" => "4",
+            "SHA-256" => "12",
+            "SHA-384" => "13",
+            "SHA-512" => "14",
+            _ => return None,
+        };
+        Some(sid)
+    }
+}
+
+// The editing allowed in the document, which applications only restrict when `enforcement`
+// is set.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentProtection {
+    pub edit: DocumentProtectionType,
+    pub enforcement: bool,
+    pub hash: ProtectionHash,
+}
+
+impl DocumentProtection {
+    pub fn new(edit: DocumentProtectionType) -> DocumentProtection {
+        DocumentProtection {
+            edit,
+            enforcement: true,
+            hash: ProtectionHash::new(),
+        }
+    }
+
+    pub fn enforcement(mut self, enforcement: bool) -> Self {
+        self.enforcement = enforcement;
+        self
+    }
+
+    pub fn hash(mut self, hash: ProtectionHash) -> Self {
+        self.hash = hash;
+        self
+    }
+}
+
+impl BuildXML for DocumentProtection {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .document_protection(&self.edit.to_string(), self.enforcement, &self.hash)
+            .build()
+    }
+}
+
+// Password to save changes to the file. When `recommended` is set, the application also
+// suggests to open the file as read-only.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteProtection {
+    pub recommended: bool,
+    pub hash: ProtectionHash,
+}
+
+impl WriteProtection {
+    pub fn new() -> WriteProtection {
+        Default::default()
+    }
+
+    pub fn recommended(mut self) -> Self {
+        self.recommended = true;
+        self
+    }
+
+    pub fn hash(mut self, hash: ProtectionHash) -> Self {
+        self.hash = hash;
+        self
+    }
+}
+
+impl BuildXML for WriteProtection {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .write_protection(self.recommended, &self.hash)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_document_protection() {
+        let b = DocumentProtection::new(DocumentProtectionType::Forms)
+            .hash(
+                ProtectionHash::new()
+                    .algorithm_name("SHA-512")
+                    .hash_value("aGFzaA==")
+                    .salt_value("c2FsdA==")
+                    .spin_count(100000),
+            )
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:documentProtection w:edit="forms" w:enforcement="1" w:cryptProviderType="rsaAES" w:cryptAlgorithmClass="hash" w:cryptAlgorithmType="typeAny" w:cryptAlgorithmSid="14" w:cryptSpinCount="100000" w:hash="aGFzaA==" w:salt="c2FsdA==" w:algorithmName="SHA-512" w:hashValue="aGFzaA==" w:saltValue="c2FsdA==" w:spinCount="100000" />"#
+        );
+    }
+
+    #[test]
+    fn test_protection_hash_from_password() {
+        let salt = base64::decode("ZUdHcFk0bWZrYzFoSnBvRw==").unwrap();
+        let hash = ProtectionHash::from_password("docx-rs", &salt, 1000);
+        assert_eq!(hash.salt_value.as_deref(), Some("ZUdHcFk0bWZrYzFoSnBvRw=="));
+        assert!(hash.matches("docx-rs"));
+        assert!(!hash.matches("docx"));
+        let b = DocumentProtection::new(DocumentProtectionType::ReadOnly)
+            .hash(hash.clone())
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            format!(
+                r#"<w:documentProtection w:edit="readOnly" w:enforcement="1" w:cryptProviderType="rsaAES" w:cryptAlgorithmClass="hash" w:cryptAlgorithmType="typeAny" w:cryptAlgorithmSid="14" w:cryptSpinCount="1000" w:hash="{0}" w:salt="ZUdHcFk0bWZrYzFoSnBvRw==" w:algorithmName="SHA-512" w:hashValue="{0}" w:saltValue="ZUdHcFk0bWZrYzFoSnBvRw==" w:spinCount="1000" />"#,
+                hash.hash_value.unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_protection_hash_known_answer() {
+        // Hashed by another implementation of ECMA-376 Part 4, 2.15.1.28 from the key
+        // `7EEDCE64` of "Example".
+        let hash = ProtectionHash::new()
+            .algorithm_name("SHA-512")
+            .hash_value("fzbVq+BhUGmQ3dXoeRru/73mEbv3Aqa/qa6w+B/WMaRHF6e/QjC8XiP9z+FKL5GUGoxcl2R41T9IeQhppUtWig==")
+            .salt_value("ZUdHcFk0bWZrYzFoSnBvRw==")
+            .spin_count(100000);
+        assert!(hash.matches("Example"));
+        assert!(!hash.matches("example"));
+        let salt = base64::decode("ZUdHcFk0bWZrYzFoSnBvRw==").unwrap();
+        assert_eq!(
+            ProtectionHash::from_password("Example", &salt, 100000),
+            hash
+        );
+
+        let legacy = ProtectionHash::new()
+            .algorithm_name("SHA-1")
+            .hash_value("hLABOmuURU6Ch6ICTzjEuEgr46A=")
+            .salt_value("ZUdHcFk0bWZrYzFoSnBvRw==")
+            .spin_count(100000);
+        assert!(legacy.matches("Example"));
+    }
+
+    #[test]
+    fn test_legacy_password_key() {
+        // The example of ECMA-376 Part 4, 2.15.1.28.
+        assert_eq!(legacy_password_key("Example"), 0x64CEED7E);
+        assert_eq!(legacy_password_key("34579"), 0x0005CB00);
+        assert_eq!(legacy_password_key(""), 0);
+        // Only the first 15 characters are hashed.
+        assert_eq!(
+            legacy_password_key("0123456789abcdefgh"),
+            legacy_password_key("0123456789abcde")
+        );
+        // Characters whose low byte is 0 are hashed as their high byte.
+        assert_eq!(
+            legacy_password_key("a\u{100}"),
+            legacy_password_key("a\u{1}")
+        );
+        assert_ne!(legacy_password_key("ab"), legacy_password_key("ba"));
+    }
+
+    #[test]
+    fn test_write_protection() {
+        let b = WriteProtection::new().recommended().build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:writeProtection w:recommended="1" />"#
+        );
+    }
+}
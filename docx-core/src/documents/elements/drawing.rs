@@ -1,7 +1,8 @@
 use super::*;
 use serde::{ser::*, Serialize};
 
-use crate::documents::BuildXML;
+use crate::documents::{generate_doc_pr_id, BuildXML};
+use crate::escape::escape;
 use crate::types::*;
 use crate::xml_builder::*;
 
@@ -15,6 +16,8 @@ pub struct Drawing {
 pub enum DrawingData {
     Pic(Pic),
     TextBox(TextBox),
+    Chart(Chart),
+    Shape(DrawingShape),
 }
 
 impl Serialize for DrawingData {
@@ -35,6 +38,18 @@ impl Serialize for DrawingData {
                 t.serialize_field("data", text_box)?;
                 t.end()
             }
+            DrawingData::Chart(ref chart) => {
+                let mut t = serializer.serialize_struct("Chart", 2)?;
+                t.serialize_field("type", "chart")?;
+                t.serialize_field("data", chart)?;
+                t.end()
+            }
+            DrawingData::Shape(ref shape) => {
+                let mut t = serializer.serialize_struct("Shape", 2)?;
+                t.serialize_field("type", "shape")?;
+                t.serialize_field("data", shape)?;
+                t.end()
+            }
         }
     }
 }
@@ -53,6 +68,112 @@ impl Drawing {
         self.data = Some(DrawingData::TextBox(t));
         self
     }
+
+    pub fn shape(mut self, s: DrawingShape) -> Drawing {
+        self.data = Some(DrawingData::Shape(s));
+        self
+    }
+
+    pub fn chart(mut self, c: Chart) -> Drawing {
+        self.data = Some(DrawingData::Chart(c));
+        self
+    }
+}
+
+// Open `wp:inline` or `wp:anchor` with the position of the picture or the text box, and
+// write its size.
+macro_rules! open_placement {
+    ($b:ident, $p:ident, $behind_doc:expr) => {
+        if let DrawingPositionType::Inline { .. } = $p.position_type {
+            $b = $b.open_wp_inline(
+                &format!("{}", $p.dist_t),
+                &format!("{}", $p.dist_b),
+                &format!("{}", $p.dist_l),
+                &format!("{}", $p.dist_r),
+            )
+        } else {
+            $b = $b
+                .open_wp_anchor(
+                    &format!("{}", $p.dist_t),
+                    &format!("{}", $p.dist_b),
+                    &format!("{}", $p.dist_l),
+                    &format!("{}", $p.dist_r),
+                    if $p.simple_pos { "1" } else { "0" },
+                    if $p.allow_overlap { "1" } else { "0" },
+                    $behind_doc,
+                    "0",
+                    if $p.layout_in_cell { "1" } else { "0" },
+                    &format!("{}", $p.relative_height),
+                )
+                .simple_pos(
+                    &format!("{}", $p.simple_pos_x),
+                    &format!("{}", $p.simple_pos_y),
+                )
+                .open_position_h(&format!("{}", $p.relative_from_h));
+
+            match $p.position_h {
+                DrawingPosition::Offset(x) => {
+                    let x = format!("{}", x as u32);
+                    $b = $b.pos_offset(&x).close();
+                }
+                DrawingPosition::Align(x) => {
+                    $b = $b.align(&x.to_string()).close();
+                }
+            }
+
+            $b = $b.open_position_v(&format!("{}", $p.relative_from_v));
+
+            match $p.position_v {
+                DrawingPosition::Offset(y) => {
+                    let y = format!("{}", y as u32);
+                    $b = $b.pos_offset(&y).close();
+                }
+                DrawingPosition::Align(a) => {
+                    $b = $b.align(&a.to_string()).close();
+                }
+            }
+        }
+
+        let w = format!("{}", $p.size.0);
+        let h = format!("{}", $p.size.1);
+        $b = $b
+            // Please see 20.4.2.7 extent (Drawing Object Size)
+            // One inch equates to 914400 EMUs and a centimeter is 360000
+            .wp_extent(&w, &h)
+            .wp_effect_extent("0", "0", "0", "0");
+    };
+}
+
+// Write the wrapping and the properties of a text box or a shape, and open its graphic data.
+fn open_wps_graphic(
+    b: XMLBuilder,
+    name: &str,
+    position_type: &DrawingPositionType,
+    allow_overlap: bool,
+) -> XMLBuilder {
+    let b = if allow_overlap {
+        b.wrap_none()
+    } else if *position_type == DrawingPositionType::Anchor {
+        b.wrap_square("bothSides")
+    } else {
+        b
+    };
+    b.wp_doc_pr(&format!("{}", generate_doc_pr_id()), name)
+        .open_wp_c_nv_graphic_frame_pr()
+        .close()
+        .open_a_graphic("http://schemas.openxmlformats.org/drawingml/2006/main")
+        .open_a_graphic_data("http://schemas.microsoft.com/office/word/2010/wordprocessingShape")
+}
+
+// Rectangle of 21600 x 21600, the size of the wrapping polygon of a picture.
+fn wrap_polygon(b: XMLBuilder) -> XMLBuilder {
+    b.open_wrap_polygon("0")
+        .wrap_polygon_start("0", "0")
+        .wrap_polygon_line_to("0", "21600")
+        .wrap_polygon_line_to("21600", "21600")
+        .wrap_polygon_line_to("21600", "0")
+        .wrap_polygon_line_to("0", "0")
+        .close()
 }
 
 impl BuildXML for Box<Drawing> {
@@ -68,70 +189,31 @@ impl BuildXML for Drawing {
 
         match &self.data {
             Some(DrawingData::Pic(p)) => {
-                if let DrawingPositionType::Inline { .. } = p.position_type {
-                    b = b.open_wp_inline(
-                        &format!("{}", p.dist_t),
-                        &format!("{}", p.dist_b),
-                        &format!("{}", p.dist_l),
-                        &format!("{}", p.dist_r),
-                    )
-                } else {
-                    b = b
-                        .open_wp_anchor(
-                            &format!("{}", p.dist_t),
-                            &format!("{}", p.dist_b),
-                            &format!("{}", p.dist_l),
-                            &format!("{}", p.dist_r),
-                            "0",
-                            if p.simple_pos { "1" } else { "0" },
-                            "0",
-                            "0",
-                            if p.layout_in_cell { "1" } else { "0" },
-                            &format!("{}", p.relative_height),
-                        )
-                        .simple_pos(
-                            &format!("{}", p.simple_pos_x),
-                            &format!("{}", p.simple_pos_y),
-                        )
-                        .open_position_h(&format!("{}", p.relative_from_h));
-
-                    match p.position_h {
-                        DrawingPosition::Offset(x) => {
-                            let x = format!("{}", x as u32);
-                            b = b.pos_offset(&x).close();
-                        }
-                        DrawingPosition::Align(x) => {
-                            b = b.align(&x.to_string()).close();
-                        }
-                    }
-
-                    b = b.open_position_v(&format!("{}", p.relative_from_v));
-
-                    match p.position_v {
-                        DrawingPosition::Offset(y) => {
-                            let y = format!("{}", y as u32);
-                            b = b.pos_offset(&y).close();
+                open_placement!(b, p, if p.behind_doc { "1" } else { "0" });
+                if p.position_type == DrawingPositionType::Anchor {
+                    b = match p.wrap {
+                        Some(DrawingWrapType::None) => b.wrap_none(),
+                        Some(DrawingWrapType::Square) => b.wrap_square("bothSides"),
+                        Some(DrawingWrapType::TopAndBottom) => b.wrap_top_and_bottom(),
+                        // The wrap polygon is required, so the bounds of the picture are used.
+                        Some(DrawingWrapType::Tight) => {
+                            wrap_polygon(b.open_wrap_tight("bothSides")).close()
                         }
-                        DrawingPosition::Align(a) => {
-                            b = b.align(&a.to_string()).close();
+                        Some(DrawingWrapType::Through) => {
+                            wrap_polygon(b.open_wrap_through("bothSides")).close()
                         }
-                    }
-                }
-
-                let w = format!("{}", p.size.0);
-                let h = format!("{}", p.size.1);
-                b = b
-                    // Please see 20.4.2.7 extent (Drawing Object Size)
-                    // One inch equates to 914400 EMUs and a centimeter is 360000
-                    .wp_extent(&w, &h)
-                    .wp_effect_extent("0", "0", "0", "0");
-                if p.allow_overlap {
+                        None if p.allow_overlap => b.wrap_none(),
+                        None => b.wrap_square("bothSides"),
+                    };
+                } else if p.allow_overlap {
                     b = b.wrap_none();
-                } else if p.position_type == DrawingPositionType::Anchor {
-                    b = b.wrap_square("bothSides");
                 }
+                let name = escape(p.name.as_deref().unwrap_or("Figure"));
+                b = match &p.description {
+                    Some(d) => b.wp_doc_pr_with_descr("1", &name, &escape(d)),
+                    None => b.wp_doc_pr("1", &name),
+                };
                 b = b
-                    .wp_doc_pr("1", "Figure")
                     .open_wp_c_nv_graphic_frame_pr()
                     .a_graphic_frame_locks(
                         "http://schemas.openxmlformats.org/drawingml/2006/main",
@@ -144,7 +226,43 @@ impl BuildXML for Drawing {
                     .close()
                     .close();
             }
-            Some(DrawingData::TextBox(_t)) => unimplemented!("TODO: Support textBox writer"),
+            Some(DrawingData::TextBox(t)) => {
+                open_placement!(b, t, "0");
+                b = open_wps_graphic(b, "Text Box", &t.position_type, t.allow_overlap)
+                    .add_child(t)
+                    .close()
+                    .close();
+            }
+            Some(DrawingData::Shape(s)) => {
+                open_placement!(b, s, "0");
+                b = open_wps_graphic(b, "Shape", &s.position_type, s.allow_overlap)
+                    .add_child(s)
+                    .close()
+                    .close();
+            }
+            // Charts are written inline, with the part they were read from.
+            Some(DrawingData::Chart(c)) => {
+                let name = escape(c.name.as_deref().unwrap_or("Chart"));
+                b = b
+                    .open_wp_inline("0", "0", "0", "0")
+                    .wp_extent(&format!("{}", c.size.0), &format!("{}", c.size.1))
+                    .wp_effect_extent("0", "0", "0", "0");
+                b = match &c.description {
+                    Some(d) => b.wp_doc_pr_with_descr("1", &name, &escape(d)),
+                    None => b.wp_doc_pr("1", &name),
+                };
+                b = b
+                    .open_wp_c_nv_graphic_frame_pr()
+                    .close()
+                    .open_a_graphic("http://schemas.openxmlformats.org/drawingml/2006/main")
+                    .open_a_graphic_data("http://schemas.openxmlformats.org/drawingml/2006/chart")
+                    .c_chart(
+                        "http://schemas.openxmlformats.org/drawingml/2006/chart",
+                        &escape(&c.id),
+                    )
+                    .close()
+                    .close();
+            }
             None => {
                 unimplemented!()
             }
@@ -158,9 +276,28 @@ mod tests {
 
     use super::*;
     #[cfg(test)]
+    use crate::documents::Docx;
     use pretty_assertions::assert_eq;
     use std::str;
 
+    #[test]
+    fn test_doc_pr_ids_of_shapes() {
+        let docx = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_shape(DrawingShape::new(PresetShapeType::Rectangle)))
+                .add_run(Run::new().add_shape(DrawingShape::new(PresetShapeType::Ellipse))),
+        );
+        let document = String::from_utf8(docx.build().document).unwrap();
+        let ids: Vec<_> = document
+            .split("<wp:docPr id=\"")
+            .skip(1)
+            .map(|s| &s[..s.find('"').unwrap()])
+            .collect();
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+        assert!(!ids.contains(&"1"));
+    }
+
     #[test]
     fn test_drawing_build_with_pic() {
         use std::io::Read;
@@ -383,4 +520,33 @@ mod tests {
 </w:drawing>"#
         );
     }
+
+    #[test]
+    fn test_drawing_build_with_text_box() {
+        let t = TextBox::new()
+            .size(914400, 457200)
+            .floating()
+            .offset_x(100)
+            .offset_y(200)
+            .fill("FFFFFF")
+            .border("000000", 12700)
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Label")));
+        let b = Drawing::new().text_box(t).build();
+        let xml = str::from_utf8(&b).unwrap();
+        assert!(xml.contains(
+            r#"<wp:positionH relativeFrom="margin">
+      <wp:posOffset>100</wp:posOffset>"#
+        ));
+        assert!(xml.contains(r#"<wp:extent cx="914400" cy="457200" />"#));
+        assert!(xml.contains(
+            r#"<a:graphicData uri="http://schemas.microsoft.com/office/word/2010/wordprocessingShape"><wps:wsp>"#
+        ));
+        assert!(xml.contains(
+            r#"<a:solidFill>
+      <a:srgbClr val="FFFFFF" />
+    </a:solidFill>
+    <a:ln w="12700">"#
+        ));
+        assert!(xml.contains(r#"<w:txbxContent><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:t xml:space="preserve">Label</w:t></w:r></w:p></w:txbxContent>"#));
+    }
 }
@@ -0,0 +1,251 @@
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// A DrawingML shape of a preset geometry, such as a rectangle or an arrow. The position,
+// size and outline width are in emu, and the rotation is in degrees clockwise.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DrawingShape {
+    pub preset: PresetShapeType,
+    pub size: (u32, u32),
+    pub rotation: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill: Option<String>,
+    // The color and the width of the outline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outline: Option<(String, u32)>,
+    pub position_type: DrawingPositionType,
+    pub simple_pos: bool,
+    pub simple_pos_x: i32,
+    pub simple_pos_y: i32,
+    pub layout_in_cell: bool,
+    pub relative_height: u32,
+    pub allow_overlap: bool,
+    pub position_h: DrawingPosition,
+    pub position_v: DrawingPosition,
+    pub relative_from_h: RelativeFromHType,
+    pub relative_from_v: RelativeFromVType,
+    pub dist_t: i32,
+    pub dist_b: i32,
+    pub dist_l: i32,
+    pub dist_r: i32,
+}
+
+impl DrawingShape {
+    // A black outline of 0.75pt, without fill.
+    pub fn new(preset: PresetShapeType) -> Self {
+        Self {
+            preset,
+            size: (from_px(100), from_px(100)),
+            rotation: 0,
+            fill: None,
+            outline: Some(("000000".to_owned(), 9525)),
+            position_type: DrawingPositionType::Inline,
+            simple_pos: false,
+            simple_pos_x: 0,
+            simple_pos_y: 0,
+            layout_in_cell: false,
+            relative_height: 190500,
+            allow_overlap: false,
+            position_v: DrawingPosition::Offset(0),
+            position_h: DrawingPosition::Offset(0),
+            relative_from_h: RelativeFromHType::default(),
+            relative_from_v: RelativeFromVType::default(),
+            dist_t: 0,
+            dist_b: 0,
+            dist_l: 0,
+            dist_r: 0,
+        }
+    }
+
+    // unit is emu
+    pub fn size(mut self, w_emu: u32, h_emu: u32) -> Self {
+        self.size = (w_emu, h_emu);
+        self
+    }
+
+    pub fn rotate(mut self, deg: u16) -> Self {
+        self.rotation = deg % 360;
+        self
+    }
+
+    pub fn fill(mut self, color: impl Into<String>) -> Self {
+        self.fill = Some(color.into());
+        self
+    }
+
+    // unit is emu, e.g. 12700 for 1pt
+    pub fn outline(mut self, color: impl Into<String>, width: u32) -> Self {
+        self.outline = Some((color.into(), width));
+        self
+    }
+
+    pub fn no_outline(mut self) -> Self {
+        self.outline = None;
+        self
+    }
+
+    pub fn floating(mut self) -> Self {
+        self.position_type = DrawingPositionType::Anchor;
+        self
+    }
+
+    pub fn offset_x(mut self, x: i32) -> Self {
+        self.position_h = DrawingPosition::Offset(x);
+        self
+    }
+
+    pub fn offset_y(mut self, y: i32) -> Self {
+        self.position_v = DrawingPosition::Offset(y);
+        self
+    }
+
+    pub fn position_h(mut self, pos: DrawingPosition) -> Self {
+        self.position_h = pos;
+        self
+    }
+
+    pub fn position_v(mut self, pos: DrawingPosition) -> Self {
+        self.position_v = pos;
+        self
+    }
+
+    pub fn relative_from_h(mut self, t: RelativeFromHType) -> Self {
+        self.relative_from_h = t;
+        self
+    }
+
+    pub fn relative_from_v(mut self, t: RelativeFromVType) -> Self {
+        self.relative_from_v = t;
+        self
+    }
+
+    pub fn allow_overlap(mut self, v: bool) -> Self {
+        self.allow_overlap = v;
+        self
+    }
+
+    pub fn relative_height(mut self, v: u32) -> Self {
+        self.relative_height = v;
+        self
+    }
+}
+
+impl BuildXML for DrawingShape {
+    fn build(&self) -> Vec<u8> {
+        let w = format!("{}", self.size.0);
+        let h = format!("{}", self.size.1);
+        let b = XMLBuilder::new()
+            .open_wp_shape()
+            .wps_shape_c_nv_sp_pr()
+            .open_wps_sp_pr();
+        let b = if self.rotation == 0 {
+            b.open_a_xfrm()
+        } else {
+            // unit of rot is 1/60000 degree
+            b.open_a_xfrm_with_rot(&format!("{}", self.rotation as u32 * 60000))
+        };
+        let b = b
+            .a_off("0", "0")
+            .a_ext(&w, &h)
+            .close()
+            .open_a_prst_geom(&self.preset.to_string())
+            .a_av_lst()
+            .close();
+        let b = match &self.fill {
+            Some(fill) if !self.preset.is_line() => {
+                b.open_a_solid_fill().a_srgb_color(fill).close()
+            }
+            _ => b.a_no_fill(),
+        };
+        let b = match &self.outline {
+            Some((color, width)) => {
+                let b = b
+                    .open_a_line(&format!("{}", width))
+                    .open_a_solid_fill()
+                    .a_srgb_color(color)
+                    .close();
+                if self.preset == PresetShapeType::Arrow {
+                    b.a_tail_end("triangle").close()
+                } else {
+                    b.close()
+                }
+            }
+            None => b,
+        };
+        b.close().wps_body_pr().close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build_rectangle() {
+        let b = DrawingShape::new(PresetShapeType::Rectangle)
+            .size(914400, 457200)
+            .rotate(45)
+            .fill("FF0000")
+            .no_outline()
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<wps:wsp>
+  <wps:cNvSpPr />
+  <wps:spPr>
+    <a:xfrm rot="2700000">
+      <a:off x="0" y="0" />
+      <a:ext cx="914400" cy="457200" />
+    </a:xfrm>
+    <a:prstGeom prst="rect">
+      <a:avLst />
+    </a:prstGeom>
+    <a:solidFill>
+      <a:srgbClr val="FF0000" />
+    </a:solidFill>
+  </wps:spPr>
+  <wps:bodyPr />
+</wps:wsp>"#
+        );
+    }
+
+    #[test]
+    fn test_build_arrow() {
+        let b = DrawingShape::new(PresetShapeType::Arrow)
+            .size(914400, 0)
+            .fill("FF0000")
+            .outline("0000FF", 12700)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<wps:wsp>
+  <wps:cNvSpPr />
+  <wps:spPr>
+    <a:xfrm>
+      <a:off x="0" y="0" />
+      <a:ext cx="914400" cy="0" />
+    </a:xfrm>
+    <a:prstGeom prst="straightConnector1">
+      <a:avLst />
+    </a:prstGeom>
+    <a:noFill />
+    <a:ln w="12700">
+      <a:solidFill>
+        <a:srgbClr val="0000FF" />
+      </a:solidFill>
+      <a:tailEnd type="triangle" />
+    </a:ln>
+  </wps:spPr>
+  <wps:bodyPr />
+</wps:wsp>"#
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+
+use crate::documents::*;
+use crate::xml_builder::*;
+use endnote_id::generate_endnote_id;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Endnote {
+    pub id: usize,
+    pub content: Vec<EndnoteChild>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EndnoteChild {
+    Paragraph(Paragraph),
+    Table(Table),
+}
+
+impl Serialize for EndnoteChild {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            EndnoteChild::Paragraph(ref p) => {
+                let mut t = serializer.serialize_struct("Paragraph", 2)?;
+                t.serialize_field("type", "paragraph")?;
+                t.serialize_field("data", p)?;
+                t.end()
+            }
+            EndnoteChild::Table(ref c) => {
+                let mut t = serializer.serialize_struct("Table", 2)?;
+                t.serialize_field("type", "table")?;
+                t.serialize_field("data", c)?;
+                t.end()
+            }
+        }
+    }
+}
+
+impl BuildXML for EndnoteChild {
+    fn build(&self) -> Vec<u8> {
+        match self {
+            EndnoteChild::Paragraph(v) => v.build(),
+            EndnoteChild::Table(v) => v.build(),
+        }
+    }
+}
+
+impl Default for Endnote {
+    fn default() -> Self {
+        Endnote {
+            id: 1,
+            content: vec![],
+        }
+    }
+}
+
+impl Endnote {
+    pub fn new() -> Self {
+        Self {
+            id: generate_endnote_id(),
+            ..Default::default()
+        }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn add_content(&mut self, p: Paragraph) -> Self {
+        self.content.push(EndnoteChild::Paragraph(p));
+        self.clone()
+    }
+
+    pub fn add_table(&mut self, t: Table) -> Self {
+        self.content.push(EndnoteChild::Table(t));
+        self.clone()
+    }
+}
+
+impl From<&EndnoteReference> for Endnote {
+    fn from(reference: &EndnoteReference) -> Self {
+        Endnote {
+            id: reference.id,
+            content: reference.content.clone(),
+        }
+    }
+}
+
+impl BuildXML for Endnote {
+    fn build(&self) -> Vec<u8> {
+        // Like footnotes, endnotes need at least one paragraph.
+        let mut endnote = self.clone();
+        if self.content.is_empty() {
+            endnote.add_content(Paragraph::new());
+        }
+
+        XMLBuilder::new()
+            .open_endnote(&format!("{}", self.id))
+            .add_children(&endnote.content)
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_endnote_build_with_paragraph() {
+        let b = Endnote::new()
+            .add_content(Paragraph::new().add_run(Run::new().add_text("hello")))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:endnote w:id="1"><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr><w:r><w:rPr /><w:t xml:space="preserve">hello</w:t></w:r></w:p></w:endnote>"#
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::documents::BuildXML;
+use crate::{xml_builder::*, Endnote, EndnoteChild, Paragraph};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndnoteReference {
+    pub id: usize,
+    pub style: String,
+    pub content: Vec<EndnoteChild>,
+}
+
+impl EndnoteReference {
+    pub fn new(id: usize) -> Self {
+        EndnoteReference {
+            id,
+            style: "EndnoteReference".to_string(),
+            content: vec![],
+        }
+    }
+    /// Add endnote content as a Paragraph
+    pub fn endnote(&mut self, p: Paragraph) {
+        self.content.push(EndnoteChild::Paragraph(p))
+    }
+}
+
+impl From<Endnote> for EndnoteReference {
+    fn from(endnote: Endnote) -> Self {
+        EndnoteReference {
+            id: endnote.id,
+            style: "EndnoteReference".to_string(),
+            content: endnote.content,
+        }
+    }
+}
+
+impl BuildXML for EndnoteReference {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new().endnote_reference(self.id).build()
+    }
+}
+
+impl Serialize for EndnoteReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut t = serializer.serialize_struct("EndnoteReference", 1)?;
+        t.serialize_field("id", &self.id)?;
+        t.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_endnotereference_build() {
+        let b = EndnoteReference::new(1).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:endnoteReference w:id="1" />"#
+        );
+    }
+}
@@ -11,6 +11,8 @@ use crate::xml_builder::*;
 pub struct FieldChar {
     pub field_char_type: FieldCharType,
     pub dirty: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_field: Option<FormFieldData>,
 }
 
 impl FieldChar {
@@ -18,6 +20,7 @@ impl FieldChar {
         Self {
             field_char_type: t,
             dirty: false,
+            form_field: None,
         }
     }
 
@@ -25,16 +28,25 @@ impl FieldChar {
         self.dirty = true;
         self
     }
+
+    pub fn form_field(mut self, f: FormFieldData) -> Self {
+        self.form_field = Some(f);
+        self
+    }
 }
 
 impl BuildXML for FieldChar {
     fn build(&self) -> Vec<u8> {
-        XMLBuilder::new()
-            .field_character(
-                &format!("{}", self.field_char_type),
-                &format!("{}", &self.dirty),
-            )
-            .build()
+        let t = format!("{}", self.field_char_type);
+        let dirty = format!("{}", &self.dirty);
+        match self.form_field {
+            Some(ref f) => XMLBuilder::new()
+                .open_field_character(&t, &dirty)
+                .add_child(f)
+                .close()
+                .build(),
+            None => XMLBuilder::new().field_character(&t, &dirty).build(),
+        }
     }
 }
 
@@ -1,13 +1,14 @@
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, EmbeddedFont};
 use crate::types::*;
 use crate::xml_builder::*;
 
 #[derive(Debug)]
 pub struct Font<'a> {
-    name: &'a str,
+    pub(crate) name: &'a str,
     charset: &'a str,
     family: &'a str,
     pitch: FontPitchType,
+    pub(crate) embeds: Vec<&'a EmbeddedFont>,
 }
 
 impl<'a> Font<'a> {
@@ -17,19 +18,29 @@ impl<'a> Font<'a> {
             charset,
             family,
             pitch,
+            embeds: vec![],
         }
     }
+
+    pub fn embed(mut self, font: &'a EmbeddedFont) -> Self {
+        self.embeds.push(font);
+        self
+    }
 }
 
 impl<'a> BuildXML for Font<'a> {
     fn build(&self) -> Vec<u8> {
-        let b = XMLBuilder::new();
-        b.open_font(self.name)
+        let mut b = XMLBuilder::new()
+            .open_font(self.name)
             .charset(self.charset)
             .family(self.family)
-            .pitch(&self.pitch.to_string())
-            .close()
-            .build()
+            .pitch(&self.pitch.to_string());
+        let mut embeds = self.embeds.clone();
+        embeds.sort_by_key(|e| e.style);
+        for e in embeds {
+            b = b.embed_font(e.style, &e.id, e.font_key.as_deref());
+        }
+        b.close().build()
     }
 }
 
@@ -20,6 +20,34 @@ pub struct FontGroup {
     pub fonts: Vec<FontSchemeFont>,
 }
 
+impl FontGroup {
+    pub fn new(latin: impl Into<String>) -> Self {
+        Self {
+            latin: latin.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn ea(mut self, typeface: impl Into<String>) -> Self {
+        self.ea = typeface.into();
+        self
+    }
+
+    pub fn cs(mut self, typeface: impl Into<String>) -> Self {
+        self.cs = typeface.into();
+        self
+    }
+
+    // The typeface of a script such as `Jpan`, which is used instead of `ea` for the language.
+    pub fn add_font(mut self, script: impl Into<String>, typeface: impl Into<String>) -> Self {
+        self.fonts.push(FontSchemeFont {
+            script: script.into(),
+            typeface: typeface.into(),
+        });
+        self
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
 #[cfg_attr(feature = "wasm", ts(export))]
@@ -29,7 +57,6 @@ pub struct FontScheme {
     pub minor_font: FontGroup,
 }
 
-// For now reader only
 impl FontScheme {
     pub fn new() -> Self {
         Self::default()
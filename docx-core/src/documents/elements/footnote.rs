@@ -1,3 +1,4 @@
+use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 
 use crate::documents::*;
@@ -8,7 +9,44 @@ use footnote_id::generate_footnote_id;
 #[serde(rename_all = "camelCase")]
 pub struct Footnote {
     pub id: usize,
-    pub content: Vec<Paragraph>,
+    pub content: Vec<FootnoteChild>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FootnoteChild {
+    Paragraph(Paragraph),
+    Table(Table),
+}
+
+impl Serialize for FootnoteChild {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            FootnoteChild::Paragraph(ref p) => {
+                let mut t = serializer.serialize_struct("Paragraph", 2)?;
+                t.serialize_field("type", "paragraph")?;
+                t.serialize_field("data", p)?;
+                t.end()
+            }
+            FootnoteChild::Table(ref c) => {
+                let mut t = serializer.serialize_struct("Table", 2)?;
+                t.serialize_field("type", "table")?;
+                t.serialize_field("data", c)?;
+                t.end()
+            }
+        }
+    }
+}
+
+impl BuildXML for FootnoteChild {
+    fn build(&self) -> Vec<u8> {
+        match self {
+            FootnoteChild::Paragraph(v) => v.build(),
+            FootnoteChild::Table(v) => v.build(),
+        }
+    }
 }
 
 impl Default for Footnote {
@@ -33,7 +71,12 @@ impl Footnote {
     }
 
     pub fn add_content(&mut self, p: Paragraph) -> Self {
-        self.content.push(p);
+        self.content.push(FootnoteChild::Paragraph(p));
+        self.clone()
+    }
+
+    pub fn add_table(&mut self, t: Table) -> Self {
+        self.content.push(FootnoteChild::Table(t));
         self.clone()
     }
 }
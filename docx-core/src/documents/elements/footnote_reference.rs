@@ -1,14 +1,14 @@
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 use crate::documents::BuildXML;
-use crate::{xml_builder::*, Footnote, Paragraph};
+use crate::{xml_builder::*, Footnote, FootnoteChild, Paragraph};
 
 #[derive(Debug, Clone, PartialEq)]
 
 pub struct FootnoteReference {
     pub id: usize,
     pub style: String,
-    pub content: Vec<Paragraph>,
+    pub content: Vec<FootnoteChild>,
 }
 
 impl FootnoteReference {
@@ -21,7 +21,7 @@ impl FootnoteReference {
     }
     /// Add footnote content as a Paragraph
     pub fn footnote(&mut self, p: Paragraph) {
-        self.content.push(p)
+        self.content.push(FootnoteChild::Paragraph(p))
     }
 }
 impl From<Footnote> for FootnoteReference {
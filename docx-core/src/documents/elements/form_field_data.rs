@@ -0,0 +1,188 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::escape::escape;
+use crate::xml_builder::*;
+
+// Legacy form field types of `w:ffData`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum FormField {
+    #[serde(rename_all = "camelCase")]
+    CheckBox { checked: bool },
+    // `selected` is the index of the chosen entry.
+    #[serde(rename_all = "camelCase")]
+    DropDown {
+        entries: Vec<String>,
+        selected: usize,
+    },
+    // The entered text is the field result.
+    #[serde(rename_all = "camelCase")]
+    TextInput {
+        default: Option<String>,
+        max_length: Option<usize>,
+    },
+}
+
+// https://c-rex.net/projects/samples/ooxml/e1/Part4/OOXML_P4_DOCX_ffData_topic_ID0ET1XU.html
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct FormFieldData {
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help_text: Option<String>,
+    // Shown in the status bar while the field is focused.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_text: Option<String>,
+    pub field: FormField,
+}
+
+impl FormFieldData {
+    pub fn new(field: FormField) -> Self {
+        Self {
+            name: None,
+            help_text: None,
+            status_text: None,
+            field,
+        }
+    }
+
+    pub fn check_box(checked: bool) -> Self {
+        Self::new(FormField::CheckBox { checked })
+    }
+
+    pub fn drop_down(entries: Vec<String>, selected: usize) -> Self {
+        Self::new(FormField::DropDown { entries, selected })
+    }
+
+    pub fn text_input() -> Self {
+        Self::new(FormField::TextInput {
+            default: None,
+            max_length: None,
+        })
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn help_text(mut self, text: impl Into<String>) -> Self {
+        self.help_text = Some(text.into());
+        self
+    }
+
+    pub fn status_text(mut self, text: impl Into<String>) -> Self {
+        self.status_text = Some(text.into());
+        self
+    }
+}
+
+impl BuildXML for FormFieldData {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new().open_form_field_data();
+        if let Some(ref name) = self.name {
+            b = b.name(&escape(name));
+        }
+        if let Some(ref text) = self.help_text {
+            b = b.form_field_help_text("text", &escape(text));
+        }
+        if let Some(ref text) = self.status_text {
+            b = b.form_field_status_text("text", &escape(text));
+        }
+        b = match self.field {
+            FormField::CheckBox { checked } => b
+                .open_check_box()
+                .size_auto()
+                .form_field_default("0")
+                .checked(if checked { "1" } else { "0" })
+                .close(),
+            FormField::DropDown {
+                ref entries,
+                selected,
+            } => {
+                b = b.open_drop_down_list().drop_down_result(selected);
+                for e in entries {
+                    b = b.list_entry(&escape(e));
+                }
+                b.close()
+            }
+            FormField::TextInput {
+                ref default,
+                max_length,
+            } => {
+                b = b.open_text_input();
+                if let Some(ref default) = default {
+                    b = b.form_field_default(&escape(default));
+                }
+                if let Some(max_length) = max_length {
+                    b = b.max_length(max_length);
+                }
+                b.close()
+            }
+        };
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_check_box() {
+        let b = FormFieldData::check_box(true).name("Check1").build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:ffData>
+  <w:name w:val="Check1" />
+  <w:checkBox>
+    <w:sizeAuto />
+    <w:default w:val="0" />
+    <w:checked w:val="1" />
+  </w:checkBox>
+</w:ffData>"#
+        );
+    }
+
+    #[test]
+    fn test_drop_down() {
+        let b = FormFieldData::drop_down(vec!["A".to_owned(), "B".to_owned()], 1).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:ffData>
+  <w:ddList>
+    <w:result w:val="1" />
+    <w:listEntry w:val="A" />
+    <w:listEntry w:val="B" />
+  </w:ddList>
+</w:ffData>"#
+        );
+    }
+
+    #[test]
+    fn test_text_input_with_help() {
+        let b = FormFieldData::text_input()
+            .name("Text1")
+            .help_text("Your full name")
+            .status_text("Name")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:ffData>
+  <w:name w:val="Text1" />
+  <w:helpText w:type="text" w:val="Your full name" />
+  <w:statusText w:type="text" w:val="Name" />
+  <w:textInput />
+</w:ffData>"#
+        );
+    }
+}
@@ -5,7 +5,7 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct GridSpan {
-    val: usize,
+    pub val: usize,
 }
 
 impl GridSpan {
@@ -5,6 +5,7 @@ use crate::documents::BuildXML;
 use crate::escape::escape;
 use crate::types::*;
 use crate::{create_hyperlink_rid, generate_hyperlink_id, xml_builder::*};
+use std::io::{self, Write};
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(tag = "type")]
@@ -12,7 +13,7 @@ use crate::{create_hyperlink_rid, generate_hyperlink_id, xml_builder::*};
 pub enum HyperlinkData {
     External {
         rid: String,
-        // path is writer only
+        // Escaped target. Read hyperlinks have it once their relationship is resolved.
         #[serde(skip_serializing_if = "String::is_empty")]
         path: String,
     },
@@ -98,8 +99,8 @@ impl Hyperlink {
 }
 
 impl BuildXML for Hyperlink {
-    fn build(&self) -> Vec<u8> {
-        let mut b = XMLBuilder::new();
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        let mut b = XMLBuilder::from_writer(w);
         match self.link {
             HyperlinkData::Anchor { ref anchor } => {
                 b = b.open_hyperlink(
@@ -116,7 +117,8 @@ impl BuildXML for Hyperlink {
                 )
             }
         };
-        b.add_children(&self.children).close().build()
+        b.add_children(&self.children).close().into_inner()?;
+        Ok(())
     }
 }
 
@@ -5,6 +5,7 @@ use super::*;
 
 use crate::documents::{BuildXML, HistoryId, Run};
 use crate::{escape, xml_builder::*};
+use std::io::{self, Write};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum InsertChild {
@@ -15,12 +16,12 @@ pub enum InsertChild {
 }
 
 impl BuildXML for InsertChild {
-    fn build(&self) -> Vec<u8> {
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
         match self {
-            InsertChild::Run(v) => v.build(),
-            InsertChild::Delete(v) => v.build(),
-            InsertChild::CommentStart(v) => v.build(),
-            InsertChild::CommentEnd(v) => v.build(),
+            InsertChild::Run(v) => v.build_to(w),
+            InsertChild::Delete(v) => v.build_to(w),
+            InsertChild::CommentStart(v) => v.build_to(w),
+            InsertChild::CommentEnd(v) => v.build_to(w),
         }
     }
 }
@@ -64,6 +65,9 @@ pub struct Insert {
     pub children: Vec<InsertChild>,
     pub author: String,
     pub date: String,
+    // Inserted by moving the content from elsewhere, i.e. `w:moveTo`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub moved: bool,
 }
 
 impl Default for Insert {
@@ -72,6 +76,7 @@ impl Default for Insert {
             author: "unnamed".to_owned(),
             date: "1970-01-01T00:00:00Z".to_owned(),
             children: vec![],
+            moved: false,
         }
     }
 }
@@ -135,18 +140,26 @@ impl Insert {
         self.date = date.into();
         self
     }
+
+    pub fn moved(mut self) -> Insert {
+        self.moved = true;
+        self
+    }
 }
 
 impl HistoryId for Insert {}
 
 impl BuildXML for Insert {
     #[allow(clippy::needless_borrow)]
-    fn build(&self) -> Vec<u8> {
-        XMLBuilder::new()
-            .open_insert(&self.generate(), &self.author, &self.date)
-            .add_children(&self.children)
-            .close()
-            .build()
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        let b = XMLBuilder::from_writer(w);
+        let b = if self.moved {
+            b.open_move_to(&self.generate(), &self.author, &self.date)
+        } else {
+            b.open_insert(&self.generate(), &self.author, &self.date)
+        };
+        b.add_children(&self.children).close().into_inner()?;
+        Ok(())
     }
 }
 
@@ -166,4 +179,13 @@ mod tests {
             r#"<w:ins w:id="123" w:author="unnamed" w:date="1970-01-01T00:00:00Z"><w:r><w:rPr /></w:r></w:ins>"#
         );
     }
+
+    #[test]
+    fn test_move_to() {
+        let b = Insert::new(Run::new()).moved().build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:moveTo w:id="123" w:author="unnamed" w:date="1970-01-01T00:00:00Z"><w:r><w:rPr /></w:r></w:moveTo>"#
+        );
+    }
 }
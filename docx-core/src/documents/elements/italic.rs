@@ -5,7 +5,7 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct Italic {
-    val: bool,
+    pub val: bool,
 }
 
 impl Italic {
@@ -2,6 +2,7 @@ mod a_graphic;
 mod a_graphic_data;
 mod abstract_numbering;
 mod adjust_right_ind;
+mod alt_chunk;
 mod based_on;
 mod bold;
 mod bold_cs;
@@ -12,11 +13,16 @@ mod cant_split;
 mod caps;
 mod cell_margins;
 mod character_spacing;
+mod chart;
 mod color;
+mod color_scheme;
+mod column;
 mod comment;
 mod comment_extended;
 mod comment_range_end;
 mod comment_range_start;
+mod compat_setting;
+mod complex_field;
 mod data_binding;
 mod default_tab_stop;
 mod delete;
@@ -27,13 +33,18 @@ mod doc_defaults;
 mod doc_grid;
 mod doc_id;
 mod doc_var;
+mod document_protection;
 mod drawing;
+mod drawing_shape;
+mod endnote;
+mod endnote_reference;
 mod fld_char;
 mod font;
 mod font_scheme;
 mod footer_reference;
 mod footnote;
 mod footnote_reference;
+mod form_field_data;
 mod frame_property;
 mod grid_span;
 mod header_reference;
@@ -68,6 +79,7 @@ mod number_format;
 mod numbering;
 mod numbering_id;
 mod numbering_property;
+mod office_math;
 mod outline_lvl;
 mod page_margin;
 mod page_num;
@@ -95,6 +107,7 @@ mod spec_vanish;
 mod start;
 mod strike;
 mod structured_data_tag;
+mod structured_data_tag_control;
 mod structured_data_tag_property;
 mod style;
 mod sym;
@@ -103,14 +116,19 @@ mod sz_cs;
 mod tab;
 mod table;
 mod table_borders;
+mod table_caption;
 mod table_cell;
 mod table_cell_borders;
 mod table_cell_margins;
 mod table_cell_property;
+mod table_cell_spacing;
 mod table_cell_width;
+mod table_description;
 mod table_grid;
+mod table_header;
 mod table_indent;
 mod table_layout;
+mod table_look;
 mod table_of_contents;
 mod table_of_contents_item;
 mod table_position_property;
@@ -118,6 +136,7 @@ mod table_property;
 mod table_row;
 mod table_row_property;
 mod table_style;
+mod table_style_property;
 mod table_width;
 mod tabs;
 mod text;
@@ -140,6 +159,7 @@ pub use a_graphic::*;
 pub use a_graphic_data::*;
 pub use abstract_numbering::*;
 pub use adjust_right_ind::*;
+pub use alt_chunk::*;
 pub use based_on::*;
 pub use bold::*;
 pub use bold_cs::*;
@@ -150,11 +170,16 @@ pub use cant_split::*;
 pub use caps::*;
 pub use cell_margins::*;
 pub use character_spacing::*;
+pub use chart::*;
 pub use color::*;
+pub use color_scheme::*;
+pub use column::*;
 pub use comment::*;
 pub use comment_extended::*;
 pub use comment_range_end::*;
 pub use comment_range_start::*;
+pub use compat_setting::*;
+pub use complex_field::*;
 pub use data_binding::*;
 pub use default_tab_stop::*;
 pub use delete::*;
@@ -165,13 +190,18 @@ pub use doc_defaults::*;
 pub use doc_grid::*;
 pub use doc_id::*;
 pub use doc_var::*;
+pub use document_protection::*;
 pub use drawing::*;
+pub use drawing_shape::*;
+pub use endnote::*;
+pub use endnote_reference::*;
 pub use fld_char::*;
 pub use font::*;
 pub use font_scheme::*;
 pub use footer_reference::*;
 pub use footnote::*;
 pub use footnote_reference::*;
+pub use form_field_data::*;
 pub use frame_property::*;
 pub use grid_span::*;
 pub use header_reference::*;
@@ -206,6 +236,7 @@ pub use number_format::*;
 pub use numbering::*;
 pub use numbering_id::*;
 pub use numbering_property::*;
+pub use office_math::*;
 pub use outline_lvl::*;
 pub use page_margin::*;
 pub use page_num::*;
@@ -233,6 +264,7 @@ pub use spec_vanish::*;
 pub use start::*;
 pub use strike::*;
 pub use structured_data_tag::*;
+pub use structured_data_tag_control::*;
 pub use structured_data_tag_property::*;
 pub use style::*;
 pub use sym::*;
@@ -241,14 +273,19 @@ pub use sz_cs::*;
 pub use tab::*;
 pub use table::*;
 pub use table_borders::*;
+pub use table_caption::*;
 pub use table_cell::*;
 pub use table_cell_borders::*;
 pub use table_cell_margins::*;
 pub use table_cell_property::*;
+pub use table_cell_spacing::*;
 pub use table_cell_width::*;
+pub use table_description::*;
 pub use table_grid::*;
+pub use table_header::*;
 pub use table_indent::*;
 pub use table_layout::*;
+pub use table_look::*;
 pub use table_of_contents::*;
 pub use table_of_contents_item::*;
 pub use table_position_property::*;
@@ -256,6 +293,7 @@ pub use table_property::*;
 pub use table_row::*;
 pub use table_row_property::*;
 pub use table_style::*;
+pub use table_style_property::*;
 pub use table_width::*;
 pub use tabs::*;
 pub use text::*;
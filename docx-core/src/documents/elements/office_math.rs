@@ -0,0 +1,331 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::escape::escape;
+use crate::xml_builder::*;
+
+pub const MATH_NAMESPACE: &str = "http://schemas.openxmlformats.org/officeDocument/2006/math";
+
+/*
+22.1.2.77 oMath (Office Math)
+This element specifies an instance of mathematical text.
+*/
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OfficeMath {
+    pub children: Vec<MathNode>,
+    // Display math on its own line, i.e. in an m:oMathPara.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub display: bool,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+#[serde(rename_all = "camelCase")]
+pub enum MathNode {
+    // m:r, text is not escaped.
+    Run {
+        text: String,
+    },
+    // m:f
+    Fraction {
+        numerator: Vec<MathNode>,
+        denominator: Vec<MathNode>,
+    },
+    // m:sSup
+    SuperScript {
+        base: Vec<MathNode>,
+        sup: Vec<MathNode>,
+    },
+    // m:sSub
+    SubScript {
+        base: Vec<MathNode>,
+        sub: Vec<MathNode>,
+    },
+    // m:sSubSup
+    SubSuperScript {
+        base: Vec<MathNode>,
+        sub: Vec<MathNode>,
+        sup: Vec<MathNode>,
+    },
+    // m:rad, square root when degree is empty.
+    Radical {
+        degree: Vec<MathNode>,
+        base: Vec<MathNode>,
+    },
+    // m:nary, e.g. ∑ or ∫ with limits.
+    Nary {
+        operator: String,
+        sub: Vec<MathNode>,
+        sup: Vec<MathNode>,
+        base: Vec<MathNode>,
+    },
+    // m:d, e.g. (a, b)
+    Delimiter {
+        begin: String,
+        end: String,
+        separator: String,
+        elements: Vec<Vec<MathNode>>,
+    },
+    // m:m, rows of cells.
+    Matrix {
+        rows: Vec<Vec<Vec<MathNode>>>,
+    },
+}
+
+impl MathNode {
+    pub fn run(text: impl Into<String>) -> MathNode {
+        MathNode::Run { text: text.into() }
+    }
+
+    pub fn fraction(numerator: Vec<MathNode>, denominator: Vec<MathNode>) -> MathNode {
+        MathNode::Fraction {
+            numerator,
+            denominator,
+        }
+    }
+
+    pub fn super_script(base: Vec<MathNode>, sup: Vec<MathNode>) -> MathNode {
+        MathNode::SuperScript { base, sup }
+    }
+
+    pub fn sub_script(base: Vec<MathNode>, sub: Vec<MathNode>) -> MathNode {
+        MathNode::SubScript { base, sub }
+    }
+
+    pub fn sub_super_script(
+        base: Vec<MathNode>,
+        sub: Vec<MathNode>,
+        sup: Vec<MathNode>,
+    ) -> MathNode {
+        MathNode::SubSuperScript { base, sub, sup }
+    }
+
+    pub fn radical(degree: Vec<MathNode>, base: Vec<MathNode>) -> MathNode {
+        MathNode::Radical { degree, base }
+    }
+
+    pub fn nary(
+        operator: impl Into<String>,
+        sub: Vec<MathNode>,
+        sup: Vec<MathNode>,
+        base: Vec<MathNode>,
+    ) -> MathNode {
+        MathNode::Nary {
+            operator: operator.into(),
+            sub,
+            sup,
+            base,
+        }
+    }
+
+    pub fn delimiter(elements: Vec<Vec<MathNode>>) -> MathNode {
+        MathNode::Delimiter {
+            begin: "(".to_owned(),
+            end: ")".to_owned(),
+            separator: "|".to_owned(),
+            elements,
+        }
+    }
+
+    pub fn matrix(rows: Vec<Vec<Vec<MathNode>>>) -> MathNode {
+        MathNode::Matrix { rows }
+    }
+}
+
+impl OfficeMath {
+    pub fn new() -> OfficeMath {
+        Default::default()
+    }
+
+    pub fn add_node(mut self, node: MathNode) -> Self {
+        self.children.push(node);
+        self
+    }
+
+    pub fn display(mut self) -> Self {
+        self.display = true;
+        self
+    }
+}
+
+fn build_nodes(mut b: XMLBuilder, nodes: &[MathNode]) -> XMLBuilder {
+    for n in nodes {
+        b = build_node(b, n);
+    }
+    b
+}
+
+fn build_node(b: XMLBuilder, node: &MathNode) -> XMLBuilder {
+    match node {
+        MathNode::Run { text } => b.open_math_run().math_text(&escape(text)).close(),
+        MathNode::Fraction {
+            numerator,
+            denominator,
+        } => {
+            let b = build_nodes(b.open_math_fraction().open_math_numerator(), numerator).close();
+            build_nodes(b.open_math_denominator(), denominator)
+                .close()
+                .close()
+        }
+        MathNode::SuperScript { base, sup } => {
+            let b = build_nodes(b.open_math_super_script().open_math_base(), base).close();
+            build_nodes(b.open_math_sup(), sup).close().close()
+        }
+        MathNode::SubScript { base, sub } => {
+            let b = build_nodes(b.open_math_sub_script().open_math_base(), base).close();
+            build_nodes(b.open_math_sub(), sub).close().close()
+        }
+        MathNode::SubSuperScript { base, sub, sup } => {
+            let b = build_nodes(b.open_math_sub_super_script().open_math_base(), base).close();
+            let b = build_nodes(b.open_math_sub(), sub).close();
+            build_nodes(b.open_math_sup(), sup).close().close()
+        }
+        MathNode::Radical { degree, base } => {
+            let mut b = b.open_math_radical();
+            if degree.is_empty() {
+                b = b.open_math_radical_property().math_deg_hide("1").close();
+            }
+            let b = build_nodes(b.open_math_degree(), degree).close();
+            build_nodes(b.open_math_base(), base).close().close()
+        }
+        MathNode::Nary {
+            operator,
+            sub,
+            sup,
+            base,
+        } => {
+            let b = b
+                .open_math_nary()
+                .open_math_nary_property()
+                .math_chr(&escape(operator))
+                .close();
+            let b = build_nodes(b.open_math_sub(), sub).close();
+            let b = build_nodes(b.open_math_sup(), sup).close();
+            build_nodes(b.open_math_base(), base).close().close()
+        }
+        MathNode::Delimiter {
+            begin,
+            end,
+            separator,
+            elements,
+        } => {
+            let mut b = b
+                .open_math_delimiter()
+                .open_math_delimiter_property()
+                .math_beg_chr(&escape(begin))
+                .math_sep_chr(&escape(separator))
+                .math_end_chr(&escape(end))
+                .close();
+            for e in elements {
+                b = build_nodes(b.open_math_base(), e).close();
+            }
+            b.close()
+        }
+        MathNode::Matrix { rows } => {
+            let mut b = b.open_math_matrix();
+            for row in rows {
+                b = b.open_math_matrix_row();
+                for cell in row {
+                    b = build_nodes(b.open_math_base(), cell).close();
+                }
+                b = b.close();
+            }
+            b.close()
+        }
+    }
+}
+
+impl BuildXML for OfficeMath {
+    fn build(&self) -> Vec<u8> {
+        if self.display {
+            let b = XMLBuilder::new()
+                .open_math_paragraph(MATH_NAMESPACE)
+                .open_display_math();
+            build_nodes(b, &self.children).close().close().build()
+        } else {
+            let b = XMLBuilder::new().open_math(MATH_NAMESPACE);
+            build_nodes(b, &self.children).close().build()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build_fraction() {
+        let b = OfficeMath::new()
+            .add_node(MathNode::fraction(
+                vec![MathNode::run("a")],
+                vec![MathNode::run("b")],
+            ))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<m:oMath xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">
+  <m:f>
+    <m:num>
+      <m:r>
+        <m:t xml:space="preserve">a</m:t>
+      </m:r>
+    </m:num>
+    <m:den>
+      <m:r>
+        <m:t xml:space="preserve">b</m:t>
+      </m:r>
+    </m:den>
+  </m:f>
+</m:oMath>"#
+        );
+    }
+
+    #[test]
+    fn test_build_display_matrix() {
+        let b = OfficeMath::new()
+            .add_node(MathNode::matrix(vec![
+                vec![vec![MathNode::run("a")], vec![MathNode::run("b")]],
+                vec![vec![MathNode::run("c")], vec![MathNode::run("d")]],
+            ]))
+            .display()
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<m:oMathPara xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">
+  <m:oMath>
+    <m:m>
+      <m:mr>
+        <m:e>
+          <m:r>
+            <m:t xml:space="preserve">a</m:t>
+          </m:r>
+        </m:e>
+        <m:e>
+          <m:r>
+            <m:t xml:space="preserve">b</m:t>
+          </m:r>
+        </m:e>
+      </m:mr>
+      <m:mr>
+        <m:e>
+          <m:r>
+            <m:t xml:space="preserve">c</m:t>
+          </m:r>
+        </m:e>
+        <m:e>
+          <m:r>
+            <m:t xml:space="preserve">d</m:t>
+          </m:r>
+        </m:e>
+      </m:mr>
+    </m:m>
+  </m:oMath>
+</m:oMathPara>"#
+        );
+    }
+}
@@ -5,6 +5,7 @@ use super::*;
 use crate::documents::BuildXML;
 use crate::types::*;
 use crate::xml_builder::*;
+use std::io::{self, Write};
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -39,22 +40,28 @@ pub enum ParagraphChild {
     StructuredDataTag(Box<StructuredDataTag>),
     PageNum(Box<PageNum>),
     NumPages(Box<NumPages>),
+    OfficeMath(Box<OfficeMath>),
+    ComplexField(Box<ComplexField>),
+    Unsupported(String),
 }
 
 impl BuildXML for ParagraphChild {
-    fn build(&self) -> Vec<u8> {
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
         match self {
-            ParagraphChild::Run(v) => v.build(),
-            ParagraphChild::Insert(v) => v.build(),
-            ParagraphChild::Delete(v) => v.build(),
-            ParagraphChild::Hyperlink(v) => v.build(),
-            ParagraphChild::BookmarkStart(v) => v.build(),
-            ParagraphChild::BookmarkEnd(v) => v.build(),
-            ParagraphChild::CommentStart(v) => v.build(),
-            ParagraphChild::CommentEnd(v) => v.build(),
-            ParagraphChild::StructuredDataTag(v) => v.build(),
-            ParagraphChild::PageNum(v) => v.build(),
-            ParagraphChild::NumPages(v) => v.build(),
+            ParagraphChild::Run(v) => v.build_to(w),
+            ParagraphChild::Insert(v) => v.build_to(w),
+            ParagraphChild::Delete(v) => v.build_to(w),
+            ParagraphChild::Hyperlink(v) => v.build_to(w),
+            ParagraphChild::BookmarkStart(v) => v.build_to(w),
+            ParagraphChild::BookmarkEnd(v) => v.build_to(w),
+            ParagraphChild::CommentStart(v) => v.build_to(w),
+            ParagraphChild::CommentEnd(v) => v.build_to(w),
+            ParagraphChild::StructuredDataTag(v) => v.build_to(w),
+            ParagraphChild::PageNum(v) => v.build_to(w),
+            ParagraphChild::NumPages(v) => v.build_to(w),
+            ParagraphChild::OfficeMath(v) => v.build_to(w),
+            ParagraphChild::ComplexField(v) => v.build_to(w),
+            ParagraphChild::Unsupported(v) => w.write_all(v.as_bytes()),
         }
     }
 }
@@ -131,6 +138,24 @@ impl Serialize for ParagraphChild {
                 t.serialize_field("data", r)?;
                 t.end()
             }
+            ParagraphChild::OfficeMath(ref r) => {
+                let mut t = serializer.serialize_struct("OfficeMath", 2)?;
+                t.serialize_field("type", "officeMath")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::ComplexField(ref r) => {
+                let mut t = serializer.serialize_struct("ComplexField", 2)?;
+                t.serialize_field("type", "complexField")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
+            ParagraphChild::Unsupported(ref r) => {
+                let mut t = serializer.serialize_struct("Unsupported", 2)?;
+                t.serialize_field("type", "unsupported")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
         }
     }
 }
@@ -175,6 +200,12 @@ impl Paragraph {
         self
     }
 
+    pub fn add_complex_field(mut self, f: ComplexField) -> Self {
+        self.children
+            .push(ParagraphChild::ComplexField(Box::new(f)));
+        self
+    }
+
     pub fn add_structured_data_tag(mut self, t: StructuredDataTag) -> Self {
         self.children
             .push(ParagraphChild::StructuredDataTag(Box::new(t)));
@@ -236,6 +267,11 @@ impl Paragraph {
         self
     }
 
+    pub fn bidi(mut self, v: bool) -> Self {
+        self.property = self.property.bidi(v);
+        self
+    }
+
     pub fn keep_lines(mut self, v: bool) -> Self {
         self.property = self.property.keep_lines(v);
         self
@@ -373,6 +409,7 @@ impl Paragraph {
                         }
                     }
                 }
+                ParagraphChild::ComplexField(f) => s.push_str(&f.result_text()),
                 _ => {}
             }
         }
@@ -389,6 +426,11 @@ impl Paragraph {
         self
     }
 
+    pub fn add_math(mut self, m: OfficeMath) -> Self {
+        self.children.push(ParagraphChild::OfficeMath(Box::new(m)));
+        self
+    }
+
     // frameProperty
     pub fn wrap(mut self, wrap: impl Into<String>) -> Self {
         self.property.frame_property = Some(FrameProperty {
@@ -488,19 +530,20 @@ impl Paragraph {
 }
 
 impl BuildXML for Paragraph {
-    fn build(&self) -> Vec<u8> {
-        XMLBuilder::new()
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        XMLBuilder::from_writer(w)
             .open_paragraph(&self.id)
             .add_child(&self.property)
             .add_children(&self.children)
             .close()
-            .build()
+            .into_inner()?;
+        Ok(())
     }
 }
 
 impl BuildXML for Box<Paragraph> {
-    fn build(&self) -> Vec<u8> {
-        Paragraph::build(self)
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        Paragraph::build_to(self, w)
     }
 }
 
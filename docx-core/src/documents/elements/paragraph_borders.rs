@@ -88,12 +88,24 @@ impl BuildXML for ParagraphBorder {
             let space = self.space.to_string();
             let size = self.size.to_string();
             match self.position {
-                ParagraphBorderPosition::Top => base.paragraph_border_top(&val, &space, &size, &self.color),
-                ParagraphBorderPosition::Left => base.paragraph_border_left(&val, &space, &size, &self.color),
-                ParagraphBorderPosition::Bottom => base.paragraph_border_bottom(&val, &space, &size, &self.color),
-                ParagraphBorderPosition::Right => base.paragraph_border_right(&val, &space, &size, &self.color),
-                ParagraphBorderPosition::Between => base.paragraph_border_between(&val, &space, &size, &self.color),
-                ParagraphBorderPosition::Bar => base.paragraph_border_bar(&val, &space, &size, &self.color),
+                ParagraphBorderPosition::Top => {
+                    base.paragraph_border_top(&val, &space, &size, &self.color)
+                }
+                ParagraphBorderPosition::Left => {
+                    base.paragraph_border_left(&val, &space, &size, &self.color)
+                }
+                ParagraphBorderPosition::Bottom => {
+                    base.paragraph_border_bottom(&val, &space, &size, &self.color)
+                }
+                ParagraphBorderPosition::Right => {
+                    base.paragraph_border_right(&val, &space, &size, &self.color)
+                }
+                ParagraphBorderPosition::Between => {
+                    base.paragraph_border_between(&val, &space, &size, &self.color)
+                }
+                ParagraphBorderPosition::Bar => {
+                    base.paragraph_border_bar(&val, &space, &size, &self.color)
+                }
             }
         };
         base.build()
@@ -111,7 +123,6 @@ pub struct ParagraphBorders {
     bar: Option<ParagraphBorder>,
 }
 
-
 impl Default for ParagraphBorders {
     fn default() -> Self {
         ParagraphBorders {
@@ -168,10 +179,13 @@ impl ParagraphBorders {
 
     pub fn clear_all(mut self) -> Self {
         self.left = Some(ParagraphBorder::new(ParagraphBorderPosition::Left).val(BorderType::Nil));
-        self.right = Some(ParagraphBorder::new(ParagraphBorderPosition::Right).val(BorderType::Nil));
+        self.right =
+            Some(ParagraphBorder::new(ParagraphBorderPosition::Right).val(BorderType::Nil));
         self.top = Some(ParagraphBorder::new(ParagraphBorderPosition::Top).val(BorderType::Nil));
-        self.bottom = Some(ParagraphBorder::new(ParagraphBorderPosition::Bottom).val(BorderType::Nil));
-        self.between = Some(ParagraphBorder::new(ParagraphBorderPosition::Between).val(BorderType::Nil));
+        self.bottom =
+            Some(ParagraphBorder::new(ParagraphBorderPosition::Bottom).val(BorderType::Nil));
+        self.between =
+            Some(ParagraphBorder::new(ParagraphBorderPosition::Between).val(BorderType::Nil));
         self.bar = Some(ParagraphBorder::new(ParagraphBorderPosition::Bar).val(BorderType::Nil));
         self
     }
@@ -190,4 +204,4 @@ impl BuildXML for ParagraphBorders {
             .close()
             .build()
     }
-}
\ No newline at end of file
+}
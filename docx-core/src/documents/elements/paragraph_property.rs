@@ -45,6 +45,9 @@ pub struct ParagraphProperty {
     pub adjust_right_ind: Option<AdjustRightInd>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snap_to_grid: Option<bool>,
+    // Right-to-left paragraph.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bidi: Option<bool>,
     // read only
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) div_id: Option<String>,
@@ -111,6 +114,11 @@ impl ParagraphProperty {
         self
     }
 
+    pub fn bidi(mut self, v: bool) -> Self {
+        self.bidi = Some(v);
+        self
+    }
+
     pub fn keep_lines(mut self, v: bool) -> Self {
         self.keep_lines = Some(v);
         self
@@ -239,6 +247,12 @@ fn inner_build(p: &ParagraphProperty) -> Vec<u8> {
         }
     }
 
+    if let Some(v) = p.bidi {
+        if v {
+            b = b.bidi()
+        }
+    }
+
     if let Some(v) = p.widow_control {
         b = b.widow_control(if v { "1" } else { "0" })
     }
@@ -251,7 +265,7 @@ fn inner_build(p: &ParagraphProperty) -> Vec<u8> {
         b = b.close();
     }
 
-    b.close().build()
+    b.add_optional_child(&p.section_property).close().build()
 }
 
 impl BuildXML for ParagraphProperty {
@@ -312,6 +326,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bidi() {
+        let c = ParagraphProperty::new();
+        let b = c.bidi(true).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:pPr><w:rPr /><w:bidi />
+</w:pPr>"#
+        );
+    }
+
     #[test]
     fn test_outline_lvl() {
         let props = ParagraphProperty::new();
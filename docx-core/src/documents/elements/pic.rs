@@ -35,6 +35,12 @@ pub struct Pic {
     /// lower values shall indicate lower Z-order.
     pub relative_height: u32,
     pub allow_overlap: bool,
+    // Placed behind the text of the document.
+    pub behind_doc: bool,
+    // How the text wraps around a floating picture. `wrapNone` is written when unset and
+    // overlapping, `wrapSquare` otherwise.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wrap: Option<DrawingWrapType>,
     pub position_h: DrawingPosition,
     pub position_v: DrawingPosition,
     pub relative_from_h: RelativeFromHType,
@@ -47,6 +53,19 @@ pub struct Pic {
     pub dist_r: i32,
     // deg
     pub rot: u16,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub flip_h: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub flip_v: bool,
+    // The insets of a:srcRect (left, top, right, bottom) in 1/1000 of a percent of the image.
+    // Negative values extend the image with blank space.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crop: Option<(i32, i32, i32, i32)>,
+    // name and descr (alt text) of wp:docPr
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 impl Pic {
@@ -69,6 +88,8 @@ impl Pic {
             layout_in_cell: false,
             relative_height: 190500,
             allow_overlap: false,
+            behind_doc: false,
+            wrap: None,
             position_v: DrawingPosition::Offset(0),
             position_h: DrawingPosition::Offset(0),
             relative_from_h: RelativeFromHType::default(),
@@ -78,6 +99,11 @@ impl Pic {
             dist_l: 0,
             dist_r: 0,
             rot: 0,
+            flip_h: false,
+            flip_v: false,
+            crop: None,
+            name: None,
+            description: None,
         }
     }
 
@@ -93,6 +119,8 @@ impl Pic {
             layout_in_cell: false,
             relative_height: 190500,
             allow_overlap: false,
+            behind_doc: false,
+            wrap: None,
             position_v: DrawingPosition::Offset(0),
             position_h: DrawingPosition::Offset(0),
             relative_from_h: RelativeFromHType::default(),
@@ -102,9 +130,24 @@ impl Pic {
             dist_l: 0,
             dist_r: 0,
             rot: 0,
+            flip_h: false,
+            flip_v: false,
+            crop: None,
+            name: None,
+            description: None,
         }
     }
 
+    pub fn name(mut self, name: impl Into<String>) -> Pic {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Pic {
+        self.description = Some(description.into());
+        self
+    }
+
     pub fn id(mut self, id: impl Into<String>) -> Pic {
         self.id = id.into();
         self
@@ -122,6 +165,22 @@ impl Pic {
         self
     }
 
+    pub fn flip_h(mut self) -> Pic {
+        self.flip_h = true;
+        self
+    }
+
+    pub fn flip_v(mut self) -> Pic {
+        self.flip_v = true;
+        self
+    }
+
+    // unit is 1/1000 of a percent, e.g. 25000 crops a quarter of the image from that edge
+    pub fn crop(mut self, left: i32, top: i32, right: i32, bottom: i32) -> Pic {
+        self.crop = Some((left, top, right, bottom));
+        self
+    }
+
     pub fn floating(mut self) -> Pic {
         self.position_type = DrawingPositionType::Anchor;
         self
@@ -132,6 +191,16 @@ impl Pic {
         self
     }
 
+    pub fn behind_doc(mut self, v: bool) -> Pic {
+        self.behind_doc = v;
+        self
+    }
+
+    pub fn wrap(mut self, w: DrawingWrapType) -> Pic {
+        self.wrap = Some(w);
+        self
+    }
+
     pub fn offset_x(mut self, x: i32) -> Pic {
         self.position_h = DrawingPosition::Offset(x);
         self
@@ -207,13 +276,17 @@ impl BuildXML for Pic {
             .close()
             .open_blip_fill()
             .a_blip(&self.id)
-            .a_src_rect()
+            .a_src_rect(self.crop)
             .open_a_stretch()
             .a_fill_rect()
             .close()
             .close()
             .open_pic_sp_pr("auto")
-            .open_a_xfrm_with_rot(&format!("{}", (self.rot as u32) * 60 * 1000))
+            .open_a_xfrm_with_flip(
+                &format!("{}", (self.rot as u32) * 60 * 1000),
+                self.flip_h,
+                self.flip_v,
+            )
             .a_off("0", "0")
             .a_ext(&w, &h)
             .close()
@@ -270,4 +343,22 @@ mod tests {
 </pic:pic>"#
         );
     }
+
+    #[test]
+    fn test_pic_build_with_crop_and_flip() {
+        use std::io::Read;
+
+        let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+        let mut buf = Vec::new();
+        let _ = img.read_to_end(&mut buf).unwrap();
+        let b = Pic::new(&buf)
+            .crop(10000, 0, 5000, 25000)
+            .rotate(90)
+            .flip_h()
+            .flip_v()
+            .build();
+        let s = str::from_utf8(&b).unwrap();
+        assert!(s.contains(r#"<a:srcRect l="10000" r="5000" b="25000" />"#));
+        assert!(s.contains(r#"<a:xfrm rot="5400000" flipH="1" flipV="1">"#));
+    }
 }
@@ -5,6 +5,7 @@ use serde::Serialize;
 use crate::documents::BuildXML;
 use crate::types::*;
 use crate::xml_builder::*;
+use std::io::{self, Write};
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -40,7 +41,9 @@ pub enum RunChild {
     // For reader
     InstrTextString(String),
     FootnoteReference(FootnoteReference),
+    EndnoteReference(EndnoteReference),
     Shading(Shading),
+    Unsupported(String),
 }
 
 impl Serialize for RunChild {
@@ -132,12 +135,24 @@ impl Serialize for RunChild {
                 t.serialize_field("data", f)?;
                 t.end()
             }
+            RunChild::EndnoteReference(ref e) => {
+                let mut t = serializer.serialize_struct("EndnoteReference", 2)?;
+                t.serialize_field("type", "endnoteReference")?;
+                t.serialize_field("data", e)?;
+                t.end()
+            }
             RunChild::Shading(ref f) => {
                 let mut t = serializer.serialize_struct("Shading", 2)?;
                 t.serialize_field("type", "shading")?;
                 t.serialize_field("data", f)?;
                 t.end()
             }
+            RunChild::Unsupported(ref r) => {
+                let mut t = serializer.serialize_struct("Unsupported", 2)?;
+                t.serialize_field("type", "unsupported")?;
+                t.serialize_field("data", r)?;
+                t.end()
+            }
         }
     }
 }
@@ -207,6 +222,18 @@ impl Run {
         self
     }
 
+    pub fn add_text_box(mut self, t: TextBox) -> Run {
+        self.children
+            .push(RunChild::Drawing(Box::new(Drawing::new().text_box(t))));
+        self
+    }
+
+    pub fn add_shape(mut self, s: DrawingShape) -> Run {
+        self.children
+            .push(RunChild::Drawing(Box::new(Drawing::new().shape(s))));
+        self
+    }
+
     pub(crate) fn add_drawing(mut self, d: Drawing) -> Run {
         self.children.push(RunChild::Drawing(Box::new(d)));
         self
@@ -310,6 +337,13 @@ impl Run {
         self
     }
 
+    pub fn add_endnote_reference(mut self, endnote: Endnote) -> Run {
+        self.run_property = RunProperty::new().style("EndnoteReference");
+        self.children
+            .push(RunChild::EndnoteReference(endnote.into()));
+        self
+    }
+
     pub fn shading(mut self, shading: Shading) -> Run {
         self.run_property = self.run_property.shading(shading);
         self
@@ -317,8 +351,8 @@ impl Run {
 }
 
 impl BuildXML for Run {
-    fn build(&self) -> Vec<u8> {
-        let b = XMLBuilder::new();
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        let b = XMLBuilder::from_writer(w);
         let mut b = b.open_run().add_child(&self.run_property);
         for c in &self.children {
             match c {
@@ -328,9 +362,7 @@ impl BuildXML for Run {
                 RunChild::Tab(t) => b = b.add_child(t),
                 RunChild::Break(t) => b = b.add_child(t),
                 RunChild::Drawing(t) => b = b.add_child(t),
-                RunChild::Shape(_t) => {
-                    todo!("Support shape writer.")
-                }
+                RunChild::Shape(t) => b = b.add_child(t.as_ref()),
                 RunChild::CommentStart(c) => b = b.add_child(c),
                 RunChild::CommentEnd(c) => b = b.add_child(c),
                 RunChild::FieldChar(c) => b = b.add_child(c),
@@ -338,10 +370,13 @@ impl BuildXML for Run {
                 RunChild::DeleteInstrText(c) => b = b.add_child(c),
                 RunChild::InstrTextString(_) => unreachable!(),
                 RunChild::FootnoteReference(c) => b = b.add_child(c),
+                RunChild::EndnoteReference(c) => b = b.add_child(c),
                 RunChild::Shading(s) => b = b.add_child(s),
+                RunChild::Unsupported(v) => b = b.add_bytes(v.as_bytes()),
             }
         }
-        b.close().build()
+        b.close().into_inner()?;
+        Ok(())
     }
 }
 
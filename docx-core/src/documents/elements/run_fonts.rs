@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::documents::BuildXML;
+use crate::documents::{BuildXML, Theme};
 use crate::escape::escape;
 use crate::xml_builder::*;
 
@@ -95,6 +95,27 @@ impl RunFonts {
     }
 }
 
+impl RunFonts {
+    // Replace the theme fonts with the typefaces of the theme. Theme fonts take precedence
+    // over the explicit fonts, and are kept when the theme doesn't have them.
+    pub fn resolve(&self, theme: &Theme) -> RunFonts {
+        let mut f = self.clone();
+        let slots = [
+            (&mut f.ascii, &mut f.ascii_theme),
+            (&mut f.hi_ansi, &mut f.hi_ansi_theme),
+            (&mut f.east_asia, &mut f.east_asia_theme),
+            (&mut f.cs, &mut f.cs_theme),
+        ];
+        for (font, font_theme) in slots {
+            if let Some(t) = font_theme.as_ref().and_then(|t| theme.font(t)) {
+                *font = Some(escape(t));
+                *font_theme = None;
+            }
+        }
+        f
+    }
+}
+
 impl BuildXML for RunFonts {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
@@ -167,4 +188,20 @@ mod tests {
             r#"<w:rFonts w:eastAsia="&quot;Calibri&quot;,sans-serif" />"#,
         );
     }
+
+    #[test]
+    fn test_resolve_theme_fonts() {
+        let mut theme = Theme::default();
+        theme.font_schema.minor_font.latin = "Calibri".to_owned();
+        let c = RunFonts::new()
+            .ascii("Arial")
+            .ascii_theme("minorHAnsi")
+            .east_asia_theme("minorEastAsia");
+        assert_eq!(
+            c.resolve(&theme),
+            RunFonts::new()
+                .ascii("Calibri")
+                .east_asia_theme("minorEastAsia")
+        );
+    }
 }
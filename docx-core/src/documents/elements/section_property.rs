@@ -2,7 +2,7 @@ use super::*;
 use crate::documents::BuildXML;
 use crate::types::*;
 use crate::xml_builder::*;
-use crate::{Footer, Header};
+use crate::{create_footer_rid, create_header_rid, Footer, Header};
 
 use serde::Serialize;
 
@@ -13,6 +13,12 @@ pub struct SectionProperty {
     pub page_margin: PageMargin,
     pub columns: usize,
     pub space: usize,
+    // Unequal columns. `columns` and `space` are used when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub column_definitions: Vec<Column>,
+    // A line between the columns.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub column_separator: bool,
     pub title_pg: bool,
     pub text_direction: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -77,6 +83,23 @@ impl SectionProperty {
         self
     }
 
+    pub fn columns(mut self, columns: usize, space: usize) -> Self {
+        self.columns = columns;
+        self.space = space;
+        self
+    }
+
+    pub fn add_column(mut self, c: Column) -> Self {
+        self.column_definitions.push(c);
+        self.columns = self.column_definitions.len();
+        self
+    }
+
+    pub fn column_separator(mut self, v: bool) -> Self {
+        self.column_separator = v;
+        self
+    }
+
     pub fn title_pg(mut self) -> Self {
         self.title_pg = true;
         self
@@ -160,6 +183,53 @@ impl SectionProperty {
         footers
     }
 
+    pub(crate) fn get_headers_mut(&mut self) -> Vec<&mut Header> {
+        self.header
+            .iter_mut()
+            .chain(self.first_header.iter_mut())
+            .chain(self.even_header.iter_mut())
+            .collect()
+    }
+
+    pub(crate) fn get_footers_mut(&mut self) -> Vec<&mut Footer> {
+        self.footer
+            .iter_mut()
+            .chain(self.first_footer.iter_mut())
+            .chain(self.even_footer.iter_mut())
+            .collect()
+    }
+
+    // Point the references to `header{n}.xml` and `footer{n}.xml` in the order of
+    // `get_headers` and `get_footers`, continuing the numbering of previous sections.
+    pub(crate) fn update_header_footer_ids(&mut self, headers: &mut usize, footers: &mut usize) {
+        let mut header_id = |r: &mut Option<HeaderReference>, t: &str| {
+            *headers += 1;
+            *r = Some(HeaderReference::new(t, create_header_rid(*headers)));
+        };
+        if self.header.is_some() {
+            header_id(&mut self.header_reference, "default");
+        }
+        if self.first_header.is_some() {
+            header_id(&mut self.first_header_reference, "first");
+        }
+        if self.even_header.is_some() {
+            header_id(&mut self.even_header_reference, "even");
+        }
+        let mut footer_id = |r: &mut Option<FooterReference>, t: &str| {
+            *footers += 1;
+            *r = Some(FooterReference::new(t, create_footer_rid(*footers)));
+        };
+        if self.footer.is_some() {
+            footer_id(&mut self.footer_reference, "default");
+        }
+        if self.first_footer.is_some() {
+            footer_id(&mut self.first_footer_reference, "first");
+        }
+        if self.even_footer.is_some() {
+            footer_id(&mut self.even_footer_reference, "even");
+        }
+    }
+
     pub fn page_num_type(mut self, h: PageNumType) -> Self {
         self.page_num_type = Some(h);
         self
@@ -173,6 +243,8 @@ impl Default for SectionProperty {
             page_margin: PageMargin::new(),
             columns: 1,
             space: 425,
+            column_definitions: vec![],
+            column_separator: false,
             title_pg: false,
             text_direction: "lrTb".to_string(),
             doc_grid: None,
@@ -202,8 +274,19 @@ impl BuildXML for SectionProperty {
         b = b
             .open_section_property()
             .add_child(&self.page_size)
-            .add_child(&self.page_margin)
-            .columns(&format!("{}", &self.space), &format!("{}", &self.columns))
+            .add_child(&self.page_margin);
+        let (space, columns) = (format!("{}", &self.space), format!("{}", &self.columns));
+        b = if self.column_definitions.is_empty() && !self.column_separator {
+            b.columns(&space, &columns)
+        } else {
+            b.columns_with_definitions(
+                &space,
+                &columns,
+                self.column_separator,
+                &self.column_definitions,
+            )
+        };
+        b = b
             .add_optional_child(&self.doc_grid)
             .add_optional_child(&self.header_reference)
             .add_optional_child(&self.first_header_reference)
@@ -249,6 +332,23 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_section_property_column_definitions() {
+        let c = SectionProperty::new()
+            .column_separator(true)
+            .add_column(Column::new(4000).space(708))
+            .add_column(Column::new(5000));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:sectPr><w:pgSz w:w="11906" w:h="16838" /><w:pgMar w:top="1985" w:right="1701" w:bottom="1701" w:left="1701" w:header="851" w:footer="992" w:gutter="0" /><w:cols w:space="425" w:num="2" w:sep="1" w:equalWidth="0">
+    <w:col w:w="4000" w:space="708" />
+    <w:col w:w="5000" w:space="0" />
+  </w:cols>
+</w:sectPr>"#
+        )
+    }
+
     #[test]
     fn test_section_property_default() {
         let c = SectionProperty::new();
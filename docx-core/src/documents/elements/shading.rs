@@ -1,16 +1,23 @@
 use serde::Serialize;
 
-use crate::documents::BuildXML;
+use crate::documents::{tint_and_shade, BuildXML, Theme};
 use crate::types::*;
 use crate::xml_builder::*;
 
-// INFO: Theme is not supported now.
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Shading {
     pub shd_type: ShdType,
     pub color: String,
     pub fill: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_fill: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_fill_tint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_fill_shade: Option<String>,
 }
 
 impl Default for Shading {
@@ -19,6 +26,10 @@ impl Default for Shading {
             shd_type: ShdType::Clear,
             color: "auto".to_owned(),
             fill: "FFFFFF".to_owned(),
+            theme_color: None,
+            theme_fill: None,
+            theme_fill_tint: None,
+            theme_fill_shade: None,
         }
     }
 }
@@ -42,12 +53,78 @@ impl Shading {
         self.shd_type = shd_type;
         self
     }
+
+    pub fn theme_color(mut self, theme_color: impl Into<String>) -> Shading {
+        self.theme_color = Some(theme_color.into());
+        self
+    }
+
+    pub fn theme_fill(mut self, theme_fill: impl Into<String>) -> Shading {
+        self.theme_fill = Some(theme_fill.into());
+        self
+    }
+
+    pub fn theme_fill_tint(mut self, tint: impl Into<String>) -> Shading {
+        self.theme_fill_tint = Some(tint.into());
+        self
+    }
+
+    pub fn theme_fill_shade(mut self, shade: impl Into<String>) -> Shading {
+        self.theme_fill_shade = Some(shade.into());
+        self
+    }
+
+    // The RGB value of the fill, with the theme fill if the theme has it.
+    pub fn resolve_fill(&self, theme: &Theme) -> String {
+        match self.theme_fill.as_ref().and_then(|c| theme.color(c)) {
+            Some(rgb) => tint_and_shade(
+                rgb,
+                self.theme_fill_tint.as_deref(),
+                self.theme_fill_shade.as_deref(),
+            ),
+            None => self.fill.clone(),
+        }
+    }
 }
 
 impl BuildXML for Shading {
     fn build(&self) -> Vec<u8> {
         XMLBuilder::new()
-            .shd(&self.shd_type.to_string(), &self.color, &self.fill)
+            .shd(
+                &self.shd_type.to_string(),
+                &self.color,
+                &self.fill,
+                self.theme_color.as_ref(),
+                self.theme_fill.as_ref(),
+                self.theme_fill_tint.as_ref(),
+                self.theme_fill_shade.as_ref(),
+            )
             .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_theme_fill() {
+        let s = Shading::new()
+            .fill("DEEAF6")
+            .theme_fill("accent1")
+            .theme_fill_tint("33");
+        let b = s.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:shd w:val="clear" w:color="auto" w:fill="DEEAF6" w:themeFill="accent1" w:themeFillTint="33" />"#
+        );
+        let mut theme = Theme::default();
+        assert_eq!(s.resolve_fill(&theme), "DEEAF6");
+        theme.color_scheme.accent1 = "5B9BD5".to_owned();
+        assert_eq!(s.resolve_fill(&theme), "DEEBF7");
+    }
+}
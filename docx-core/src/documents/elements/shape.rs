@@ -1,5 +1,8 @@
+use crate::documents::BuildXML;
 use serde::Serialize;
 
+use super::*;
+
 #[derive(Serialize, Debug, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
 #[cfg_attr(feature = "wasm", ts(export))]
@@ -9,6 +12,17 @@ pub struct Shape {
     pub style: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_data: Option<ImageData>,
+    // `v:textbox` content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "wasm", ts(skip))]
+    pub text_box: Option<TextBoxContent>,
+    // Shapes of a `v:group`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Shape>,
+    // The `w:pict` as it was read, to write it back.
+    #[serde(skip)]
+    #[cfg_attr(feature = "wasm", ts(skip))]
+    pub(crate) raw: Option<String>,
 }
 // Experimental, For now reader only.
 
@@ -34,13 +48,42 @@ impl Shape {
         self.image_data = Some(ImageData { id: id.into() });
         self
     }
+
+    pub fn text_box(mut self, content: TextBoxContent) -> Self {
+        self.text_box = Some(content);
+        self
+    }
+
+    pub fn add_child(mut self, shape: Shape) -> Self {
+        self.children.push(shape);
+        self
+    }
+
+    // Text boxes of the shape and of the shapes it groups, in document order.
+    pub fn text_boxes(&self) -> Vec<&TextBoxContent> {
+        let mut text_boxes: Vec<&TextBoxContent> = self.text_box.iter().collect();
+        for c in &self.children {
+            text_boxes.extend(c.text_boxes());
+        }
+        text_boxes
+    }
+
+    // Relationship ids of the images of the shape and of the shapes it groups.
+    pub fn image_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.image_data.iter().map(|i| i.id.as_str()).collect();
+        for c in &self.children {
+            ids.extend(c.image_ids());
+        }
+        ids
+    }
 }
 
-// impl BuildXML for Shape {
-//     fn build(&self) -> Vec<u8> {
-//         let b = XMLBuilder::new();
-//         let mut b = b.open_pict();
-//         b = b.add_child(t),
-//         b.close().build()
-//     }
-// }
+// Only the shapes which have been read are written, as they were read.
+impl BuildXML for Shape {
+    fn build(&self) -> Vec<u8> {
+        match &self.raw {
+            Some(raw) => raw.as_bytes().to_vec(),
+            None => vec![],
+        }
+    }
+}
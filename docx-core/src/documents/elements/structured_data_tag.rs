@@ -3,8 +3,9 @@ use serde::Serialize;
 
 use super::*;
 use crate::documents::BuildXML;
-// use crate::types::*;
+use crate::types::*;
 use crate::xml_builder::*;
+use std::io::{self, Write};
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -37,16 +38,16 @@ pub enum StructuredDataTagChild {
 }
 
 impl BuildXML for StructuredDataTagChild {
-    fn build(&self) -> Vec<u8> {
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
         match self {
-            StructuredDataTagChild::Run(v) => v.build(),
-            StructuredDataTagChild::Paragraph(v) => v.build(),
-            StructuredDataTagChild::Table(v) => v.build(),
-            StructuredDataTagChild::BookmarkStart(v) => v.build(),
-            StructuredDataTagChild::BookmarkEnd(v) => v.build(),
-            StructuredDataTagChild::CommentStart(v) => v.build(),
-            StructuredDataTagChild::CommentEnd(v) => v.build(),
-            StructuredDataTagChild::StructuredDataTag(v) => v.build(),
+            StructuredDataTagChild::Run(v) => v.build_to(w),
+            StructuredDataTagChild::Paragraph(v) => v.build_to(w),
+            StructuredDataTagChild::Table(v) => v.build_to(w),
+            StructuredDataTagChild::BookmarkStart(v) => v.build_to(w),
+            StructuredDataTagChild::BookmarkEnd(v) => v.build_to(w),
+            StructuredDataTagChild::CommentStart(v) => v.build_to(w),
+            StructuredDataTagChild::CommentEnd(v) => v.build_to(w),
+            StructuredDataTagChild::StructuredDataTag(v) => v.build_to(w),
         }
     }
 }
@@ -148,27 +149,91 @@ impl StructuredDataTag {
         self
     }
 
-    fn inner_build(&self) -> Vec<u8> {
-        XMLBuilder::new()
+    pub fn tag(mut self, v: impl Into<String>) -> Self {
+        self.property = self.property.tag(v);
+        self
+    }
+
+    pub fn lock(mut self, v: StructuredDataTagLockType) -> Self {
+        self.property = self.property.lock(v);
+        self
+    }
+
+    pub fn placeholder(mut self, doc_part: impl Into<String>) -> Self {
+        self.property = self.property.placeholder(doc_part);
+        self
+    }
+
+    pub fn showing_placeholder(mut self) -> Self {
+        self.property = self.property.showing_placeholder();
+        self
+    }
+
+    pub fn control(mut self, c: StructuredDataTagControl) -> Self {
+        self.property = self.property.control(c);
+        self
+    }
+
+    // Replace the content with the text, keeping the formatting of the first paragraph and run.
+    // Block-level tags get a single paragraph, inline ones a single run.
+    pub fn fill_text(&mut self, text: &str) {
+        let first_run = |children: &[ParagraphChild]| {
+            children.iter().find_map(|c| match c {
+                ParagraphChild::Run(r) => Some(r.run_property.clone()),
+                _ => None,
+            })
+        };
+        let mut paragraph = None;
+        let mut run_property = None;
+        for c in &self.children {
+            match c {
+                StructuredDataTagChild::Paragraph(p) if paragraph.is_none() => {
+                    run_property = first_run(&p.children);
+                    paragraph = Some(p.property.clone());
+                }
+                StructuredDataTagChild::Run(r) if run_property.is_none() => {
+                    run_property = Some(r.run_property.clone());
+                }
+                _ => {}
+            }
+        }
+        let mut run = Run::new().add_text(text);
+        if let Some(p) = run_property {
+            run.run_property = p;
+        }
+        self.children = match paragraph {
+            Some(property) => {
+                let mut p = Paragraph::new().add_run(run);
+                p.property = property;
+                vec![StructuredDataTagChild::Paragraph(Box::new(p))]
+            }
+            None => vec![StructuredDataTagChild::Run(Box::new(run))],
+        };
+        self.property.showing_placeholder = false;
+    }
+
+    fn inner_build<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        XMLBuilder::from_writer(w)
             .open_structured_tag()
             .add_child(&self.property)
             .open_structured_tag_content()
             .add_children(&self.children)
             .close()
             .close()
-            .build()
+            .into_inner()?;
+        Ok(())
     }
 }
 
 impl BuildXML for StructuredDataTag {
-    fn build(&self) -> Vec<u8> {
-        self.inner_build()
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        self.inner_build(w)
     }
 }
 
 impl BuildXML for Box<StructuredDataTag> {
-    fn build(&self) -> Vec<u8> {
-        self.inner_build()
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        self.inner_build(w)
     }
 }
 
@@ -192,4 +257,56 @@ mod tests {
 </w:sdt>"#
         );
     }
+
+    #[test]
+    fn test_sdt_with_lock_and_placeholder() {
+        let b = StructuredDataTag::new()
+            .tag("name")
+            .lock(StructuredDataTagLockType::SdtLocked)
+            .placeholder("DefaultPlaceholder_1")
+            .add_run(Run::new().add_text("Name"))
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:sdt><w:sdtPr><w:rPr /><w:tag w:val="name" />
+  <w:lock w:val="sdtLocked" />
+  <w:placeholder>
+    <w:docPart w:val="DefaultPlaceholder_1" />
+  </w:placeholder>
+</w:sdtPr><w:sdtContent><w:r><w:rPr /><w:t xml:space="preserve">Name</w:t></w:r></w:sdtContent>
+</w:sdt>"#
+        );
+    }
+
+    #[test]
+    fn test_fill_text() {
+        let mut tag = StructuredDataTag::new()
+            .showing_placeholder()
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Title")
+                    .add_run(Run::new().add_text("Click ").bold())
+                    .add_run(Run::new().add_text("here")),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("to enter text")));
+        tag.fill_text("Filled");
+        assert_eq!(
+            tag.children,
+            vec![StructuredDataTagChild::Paragraph(Box::new(
+                Paragraph::new()
+                    .style("Title")
+                    .add_run(Run::new().add_text("Filled").bold())
+            ))]
+        );
+        assert!(!tag.property.showing_placeholder);
+
+        let mut tag = StructuredDataTag::new().add_run(Run::new().add_text("Name").italic());
+        tag.fill_text("Alice");
+        assert_eq!(
+            tag.children,
+            vec![StructuredDataTagChild::Run(Box::new(
+                Run::new().add_text("Alice").italic()
+            ))]
+        );
+    }
 }
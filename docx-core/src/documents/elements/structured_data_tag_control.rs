@@ -0,0 +1,87 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::escape::escape;
+use crate::xml_builder::*;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct StructuredDataTagListItem {
+    pub display_text: String,
+    pub value: String,
+}
+
+impl StructuredDataTagListItem {
+    pub fn new(display_text: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            display_text: display_text.into(),
+            value: value.into(),
+        }
+    }
+}
+
+// Form control of a content control, e.g. `w14:checkbox` or `w:dropDownList`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum StructuredDataTagControl {
+    CheckBox {
+        checked: bool,
+    },
+    DropDownList {
+        items: Vec<StructuredDataTagListItem>,
+    },
+    ComboBox {
+        items: Vec<StructuredDataTagListItem>,
+    },
+}
+
+impl BuildXML for StructuredDataTagControl {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        let (b, items) = match self {
+            StructuredDataTagControl::CheckBox { checked } => {
+                return b
+                    .open_sdt_check_box()
+                    .sdt_checked(if *checked { "1" } else { "0" })
+                    .close()
+                    .build();
+            }
+            StructuredDataTagControl::DropDownList { items } => {
+                (b.open_drop_down_list_control(), items)
+            }
+            StructuredDataTagControl::ComboBox { items } => (b.open_combo_box(), items),
+        };
+        let mut b = b;
+        for i in items {
+            b = b.list_item(&escape(&i.display_text), &escape(&i.value));
+        }
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_drop_down_list() {
+        let b = StructuredDataTagControl::DropDownList {
+            items: vec![StructuredDataTagListItem::new("Yes", "y")],
+        }
+        .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:dropDownList>
+  <w:listItem w:displayText="Yes" w:value="y" />
+</w:dropDownList>"#
+        );
+    }
+}
@@ -2,7 +2,8 @@ use serde::Serialize;
 
 use super::*;
 use crate::documents::BuildXML;
-// use crate::types::*;
+use crate::escape::escape;
+use crate::types::*;
 use crate::xml_builder::*;
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
@@ -11,6 +12,17 @@ pub struct StructuredDataTagProperty {
     pub run_property: RunProperty,
     pub data_binding: Option<DataBinding>,
     pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock: Option<StructuredDataTagLockType>,
+    // Name of the document part with the placeholder text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    // The content is the placeholder text, i.e. the control has not been filled in.
+    pub showing_placeholder: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub control: Option<StructuredDataTagControl>,
 }
 
 impl Default for StructuredDataTagProperty {
@@ -19,6 +31,11 @@ impl Default for StructuredDataTagProperty {
             run_property: RunProperty::new(),
             data_binding: None,
             alias: None,
+            tag: None,
+            lock: None,
+            placeholder: None,
+            showing_placeholder: false,
+            control: None,
         }
     }
 }
@@ -37,6 +54,31 @@ impl StructuredDataTagProperty {
         self.alias = Some(v.into());
         self
     }
+
+    pub fn tag(mut self, v: impl Into<String>) -> Self {
+        self.tag = Some(v.into());
+        self
+    }
+
+    pub fn lock(mut self, v: StructuredDataTagLockType) -> Self {
+        self.lock = Some(v);
+        self
+    }
+
+    pub fn placeholder(mut self, doc_part: impl Into<String>) -> Self {
+        self.placeholder = Some(doc_part.into());
+        self
+    }
+
+    pub fn showing_placeholder(mut self) -> Self {
+        self.showing_placeholder = true;
+        self
+    }
+
+    pub fn control(mut self, c: StructuredDataTagControl) -> Self {
+        self.control = Some(c);
+        self
+    }
 }
 
 impl BuildXML for StructuredDataTagProperty {
@@ -47,7 +89,27 @@ impl BuildXML for StructuredDataTagProperty {
             .add_optional_child(&self.data_binding);
 
         if let Some(ref alias) = self.alias {
-            b = b.alias(alias);
+            b = b.alias(&escape(alias));
+        }
+
+        if let Some(ref tag) = self.tag {
+            b = b.sdt_tag(&escape(tag));
+        }
+
+        if let Some(lock) = self.lock {
+            b = b.sdt_lock(&lock.to_string());
+        }
+
+        if let Some(ref doc_part) = self.placeholder {
+            b = b.open_sdt_placeholder().doc_part(&escape(doc_part)).close();
+        }
+
+        if self.showing_placeholder {
+            b = b.showing_placeholder();
+        }
+
+        if let Some(ref control) = self.control {
+            b = b.add_child(control);
         }
 
         b.close().build()
@@ -17,6 +17,8 @@ pub struct Style {
     pub paragraph_property: ParagraphProperty,
     pub table_property: TableProperty,
     pub table_cell_property: TableCellProperty,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub table_style_properties: Vec<TableStyleProperty>,
     pub based_on: Option<BasedOn>,
     pub next: Option<Next>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -36,6 +38,7 @@ impl Default for Style {
             paragraph_property: ppr,
             table_property: TableProperty::new(),
             table_cell_property: TableCellProperty::new(),
+            table_style_properties: vec![],
             based_on: None,
             next: None,
             link: None,
@@ -156,6 +159,11 @@ impl Style {
         self
     }
 
+    pub fn paragraph_property(mut self, p: ParagraphProperty) -> Self {
+        self.paragraph_property = p;
+        self
+    }
+
     pub fn table_property(mut self, p: TableProperty) -> Self {
         self.table_property = p;
         self
@@ -216,6 +224,35 @@ impl Style {
         self
     }
 
+    pub fn add_table_style_property(mut self, p: TableStyleProperty) -> Self {
+        self.table_style_properties.push(p);
+        self
+    }
+
+    // Add the conditional format of the part of the table, or update the one added before.
+    pub fn table_style_property(
+        mut self,
+        override_type: TableStyleOverrideType,
+        f: impl FnOnce(TableStyleProperty) -> TableStyleProperty,
+    ) -> Self {
+        match self
+            .table_style_properties
+            .iter_mut()
+            .find(|p| p.override_type == override_type)
+        {
+            Some(p) => *p = f(p.clone()),
+            None => self
+                .table_style_properties
+                .push(f(TableStyleProperty::new(override_type))),
+        }
+        self
+    }
+
+    pub fn table_cell_shading(mut self, s: Shading) -> Self {
+        self.table_cell_property = self.table_cell_property.shading(s);
+        self
+    }
+
     // frameProperty
     pub fn wrap(mut self, wrap: impl Into<String>) -> Self {
         self.paragraph_property.frame_property = Some(FrameProperty {
@@ -326,8 +363,11 @@ impl BuildXML for Style {
 
         if self.style_type == StyleType::Table {
             b = b
-                .add_child(&self.table_cell_property)
-                .add_child(&self.table_property);
+                .add_child(&self.table_property)
+                .add_child(&self.table_cell_property);
+            for p in &self.table_style_properties {
+                b = b.add_child(p);
+            }
         }
 
         if let Some(ref next) = self.next {
@@ -362,4 +402,21 @@ mod tests {
             r#"<w:style w:type="paragraph" w:styleId="Heading"><w:name w:val="Heading1" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:qFormat /></w:style>"#
         );
     }
+
+    #[test]
+    fn test_build_table_style() {
+        let c = Style::new("GridTable", StyleType::Table)
+            .name("Grid Table")
+            .table_property(TableProperty::without_borders())
+            .table_style_property(TableStyleOverrideType::FirstRow, |p| p.bold())
+            .table_style_property(TableStyleOverrideType::Band1Horz, |p| {
+                p.shading(Shading::new().fill("DDDDDD"))
+            })
+            .table_style_property(TableStyleOverrideType::FirstRow, |p| p.color("FF0000"));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:style w:type="table" w:styleId="GridTable"><w:name w:val="Grid Table" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:tblPr><w:tblW w:w="0" w:type="auto" /><w:jc w:val="left" /><w:tblBorders /></w:tblPr><w:tcPr /><w:tblStylePr w:type="firstRow"><w:pPr><w:rPr /></w:pPr><w:rPr><w:color w:val="FF0000" /><w:b /><w:bCs /></w:rPr></w:tblStylePr><w:tblStylePr w:type="band1Horz"><w:pPr><w:rPr /></w:pPr><w:rPr /><w:tcPr><w:shd w:val="clear" w:color="auto" w:fill="DDDDDD" /></w:tcPr></w:tblStylePr><w:qFormat /></w:style>"#
+        );
+    }
 }
@@ -5,6 +5,7 @@ use super::*;
 use crate::documents::BuildXML;
 use crate::types::*;
 use crate::xml_builder::*;
+use std::io::{self, Write};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -21,9 +22,9 @@ pub enum TableChild {
 }
 
 impl BuildXML for TableChild {
-    fn build(&self) -> Vec<u8> {
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
         match self {
-            TableChild::TableRow(v) => v.build(),
+            TableChild::TableRow(v) => v.build_to(w),
         }
     }
 }
@@ -55,6 +56,55 @@ impl Table {
         }
     }
 
+    // A table with a cell for each item of the rows, holding the paragraph or the table.
+    pub fn from_rows<T: Into<TableCellContent>>(rows: Vec<Vec<T>>) -> Table {
+        Table::new(
+            rows.into_iter()
+                .map(|cells| {
+                    TableRow::new(
+                        cells
+                            .into_iter()
+                            .map(|c| match c.into() {
+                                TableCellContent::Paragraph(p) => TableCell::new().add_paragraph(p),
+                                TableCellContent::Table(t) => TableCell::new().add_table(t),
+                                TableCellContent::StructuredDataTag(t) => {
+                                    TableCell::new().add_structured_data_tag(*t)
+                                }
+                                TableCellContent::TableOfContents(t) => {
+                                    TableCell::new().add_table_of_contents(*t)
+                                }
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    // A table with a paragraph of the text in each cell.
+    pub fn from_text_rows<const N: usize>(rows: &[[&str; N]]) -> Table {
+        Table::from_rows(rows.iter().map(|r| r.to_vec()).collect())
+    }
+
+    // Repeat the first row on each page and make its text bold.
+    pub fn header_row(mut self) -> Table {
+        if let Some(TableChild::TableRow(row)) = self.rows.first_mut() {
+            row.property = row.property.clone().header_row();
+            for TableRowChild::TableCell(cell) in &mut row.cells {
+                for c in &mut cell.children {
+                    if let TableCellContent::Paragraph(p) = c {
+                        for child in &mut p.children {
+                            if let ParagraphChild::Run(run) = child {
+                                run.run_property = run.run_property.clone().bold();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        self
+    }
+
     pub fn add_row(mut self, row: TableRow) -> Table {
         self.rows.push(TableChild::TableRow(row));
         self
@@ -65,6 +115,40 @@ impl Table {
         self
     }
 
+    // Set the grid, the widths of the cells and the layout of the table from the columns, so
+    // that they agree with each other. The table has the fixed layout when all the columns
+    // are fixed, and a cell spanning an auto column is fitted to the content.
+    pub fn columns(mut self, columns: &[ColumnSpec]) -> Table {
+        self.grid = columns.iter().map(|c| c.width()).collect();
+        if columns.iter().all(|c| c.is_fixed()) {
+            let width = columns.iter().map(|c| c.width()).sum();
+            self.property = self
+                .property
+                .layout(TableLayoutType::Fixed)
+                .width(width, WidthType::Dxa);
+        } else {
+            self.property = self
+                .property
+                .layout(TableLayoutType::Autofit)
+                .width(0, WidthType::Auto);
+        }
+        for TableChild::TableRow(row) in &mut self.rows {
+            let mut col = row.property.grid_before.unwrap_or(0) as usize;
+            for TableRowChild::TableCell(cell) in &mut row.cells {
+                let span = cell.property.grid_span.as_ref().map(|g| g.val).unwrap_or(1);
+                let spanned = columns.iter().skip(col).take(span);
+                col += span;
+                let width = if spanned.clone().all(|c| c.is_fixed()) {
+                    TableCellWidth::new(spanned.map(|c| c.width()).sum(), WidthType::Dxa)
+                } else {
+                    TableCellWidth::new(0, WidthType::Auto)
+                };
+                cell.property.width = Some(width);
+            }
+        }
+        self
+    }
+
     pub fn indent(mut self, v: i32) -> Table {
         self.property = self.property.indent(v);
         self
@@ -76,7 +160,7 @@ impl Table {
     }
 
     pub fn style(mut self, s: impl Into<String>) -> Table {
-        self.property = self.property.style(s);
+        self.property = self.property.use_style(s);
         self
     }
 
@@ -100,6 +184,31 @@ impl Table {
         self
     }
 
+    pub fn caption(mut self, v: impl Into<String>) -> Self {
+        self.property = self.property.caption(v);
+        self
+    }
+
+    pub fn description(mut self, v: impl Into<String>) -> Self {
+        self.property = self.property.description(v);
+        self
+    }
+
+    pub fn look(mut self, look: TableLook) -> Self {
+        self.property = self.property.set_look(look);
+        self
+    }
+
+    pub fn cell_spacing(mut self, v: usize, t: WidthType) -> Self {
+        self.property = self.property.cell_spacing(v, t);
+        self
+    }
+
+    pub fn cell_margins(mut self, top: usize, left: usize, bottom: usize, right: usize) -> Self {
+        self.property = self.property.cell_margins(top, left, bottom, right);
+        self
+    }
+
     pub fn set_borders(mut self, borders: TableBorders) -> Self {
         self.property = self.property.set_borders(borders);
         self
@@ -119,23 +228,114 @@ impl Table {
         self.property = self.property.clear_all_border();
         self
     }
+
+    // Merge the cells of the rows from `start_row` to `end_row` and the grid columns from
+    // `start_col` to `end_col` into one cell. The cells of a row are joined with `w:gridSpan`
+    // and those of the following rows continue the first one with `w:vMerge`. The content
+    // of the merged cells is moved into the first cell, and the widths of the cells of a row
+    // are added up, as Word does.
+    pub fn merge_cells(
+        mut self,
+        start_row: usize,
+        start_col: usize,
+        end_row: usize,
+        end_col: usize,
+    ) -> Table {
+        if start_row > end_row || start_col > end_col {
+            return self;
+        }
+        let mut merged: Option<&mut TableCell> = None;
+        let mut moved = vec![];
+        for (i, TableChild::TableRow(row)) in self.rows.iter_mut().enumerate() {
+            if i < start_row || i > end_row {
+                continue;
+            }
+            let mut col = row.property.grid_before.unwrap_or(0) as usize;
+            let at = moved.len();
+            let mut cells = vec![];
+            let mut first = None;
+            let mut span = 0;
+            for TableRowChild::TableCell(mut cell) in row.cells.drain(..) {
+                let s = cell.property.grid_span.as_ref().map(|g| g.val).unwrap_or(1);
+                let overlaps = col <= end_col && col + s > start_col;
+                col += s;
+                if !overlaps {
+                    cells.push(TableRowChild::TableCell(cell));
+                    continue;
+                }
+                span += s;
+                match first {
+                    None => {
+                        first = Some(cells.len());
+                        cells.push(TableRowChild::TableCell(cell));
+                    }
+                    Some(first) => {
+                        let TableRowChild::TableCell(c) = &mut cells[first];
+                        if let (Some(w), Some(ref add)) =
+                            (&mut c.property.width, cell.property.width)
+                        {
+                            w.width += add.width;
+                        }
+                        moved.append(&mut cell.children);
+                    }
+                }
+            }
+            row.cells = cells;
+            let TableRowChild::TableCell(cell) = match first {
+                Some(first) => &mut row.cells[first],
+                None => continue,
+            };
+            cell.property.grid_span = if span > 1 {
+                Some(GridSpan::new(span))
+            } else {
+                None
+            };
+            cell.property.vertical_merge = if start_row == end_row {
+                None
+            } else if merged.is_none() {
+                Some(VMerge::new(VMergeType::Restart))
+            } else {
+                let children = std::mem::take(&mut cell.children);
+                moved.splice(at..at, children);
+                Some(VMerge::new(VMergeType::Continue))
+            };
+            if merged.is_none() {
+                merged = Some(cell);
+            }
+        }
+        if let Some(cell) = merged {
+            // Empty paragraphs are only there since a cell must have one.
+            cell.children.extend(
+                moved.into_iter().filter(
+                    |c| !matches!(c, TableCellContent::Paragraph(p) if p.children.is_empty()),
+                ),
+            );
+            cell.has_numbering = cell.children.iter().any(|c| match c {
+                TableCellContent::Paragraph(p) => p.has_numbering,
+                TableCellContent::Table(t) => t.has_numbering,
+                _ => false,
+            });
+        }
+        self
+    }
 }
 
 impl BuildXML for Table {
-    fn build(&self) -> Vec<u8> {
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
         let grid = TableGrid::new(self.grid.clone());
-        let b = XMLBuilder::new()
+        let b = XMLBuilder::from_writer(w)
             .open_table()
             .add_child(&self.property)
             .add_child(&grid)
             .add_children(&self.rows);
-        b.close().build()
+        b.close().into_inner()?;
+        Ok(())
     }
 }
 
 impl BuildXML for Box<Table> {
-    fn build(&self) -> Vec<u8> {
-        Table::build(self)
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        Table::build_to(self, w)
     }
 }
 
@@ -194,4 +394,119 @@ mod tests {
             r#"{"rows":[],"grid":[100,200,300],"hasNumbering":false,"property":{"width":{"width":0,"widthType":"auto"},"justification":"left","borders":{"top":{"borderType":"single","size":2,"color":"000000","position":"top","space":0},"left":{"borderType":"single","size":2,"color":"000000","position":"left","space":0},"bottom":{"borderType":"single","size":2,"color":"000000","position":"bottom","space":0},"right":{"borderType":"single","size":2,"color":"000000","position":"right","space":0},"insideH":{"borderType":"single","size":2,"color":"000000","position":"insideH","space":0},"insideV":{"borderType":"single","size":2,"color":"000000","position":"insideV","space":0}}}}"#
         );
     }
+
+    #[test]
+    fn test_table_style() {
+        let b = Table::new(vec![TableRow::new(vec![])])
+            .style("GridTable")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tbl><w:tblPr><w:tblW w:w="0" w:type="auto" /><w:jc w:val="left" /><w:tblBorders /><w:tblStyle w:val="GridTable" /><w:tblLook w:val="04A0" w:firstRow="1" w:lastRow="0" w:firstColumn="1" w:lastColumn="0" w:noHBand="0" w:noVBand="1" /></w:tblPr><w:tblGrid /><w:tr><w:trPr /></w:tr></w:tbl>"#
+        );
+    }
+
+    #[test]
+    fn test_merge_cells() {
+        let cell = |t: &str| {
+            TableCell::new()
+                .width(1000, WidthType::Dxa)
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text(t)))
+        };
+        let row = |t: [&str; 3]| TableRow::new(t.iter().map(|t| cell(t)).collect());
+        let t = Table::new(vec![
+            row(["a", "b", "c"]),
+            row(["d", "e", "f"]),
+            row(["g", "h", "i"]),
+        ])
+        .merge_cells(0, 0, 1, 1);
+        let text =
+            |t: &str| TableCellContent::Paragraph(Paragraph::new().add_run(Run::new().add_text(t)));
+        let mut merged = cell("a").width(2000, WidthType::Dxa);
+        merged
+            .children
+            .extend(vec![text("b"), text("d"), text("e")]);
+        assert_eq!(
+            t,
+            Table::new(vec![
+                TableRow::new(vec![
+                    merged.grid_span(2).vertical_merge(VMergeType::Restart),
+                    cell("c"),
+                ]),
+                TableRow::new(vec![
+                    TableCell::new()
+                        .width(2000, WidthType::Dxa)
+                        .grid_span(2)
+                        .vertical_merge(VMergeType::Continue),
+                    cell("f"),
+                ]),
+                row(["g", "h", "i"]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_from_text_rows() {
+        let t = Table::from_text_rows(&[["Name", "Age"], ["Alice", "30"]]).header_row();
+        let cell = |t: &str, bold: bool| {
+            let run = Run::new().add_text(t);
+            TableCell::new().add_paragraph(Paragraph::new().add_run(if bold {
+                run.bold()
+            } else {
+                run
+            }))
+        };
+        assert_eq!(
+            t,
+            Table::new(vec![
+                TableRow::new(vec![cell("Name", true), cell("Age", true)]).header_row(),
+                TableRow::new(vec![cell("Alice", false), cell("30", false)]),
+            ])
+        );
+        assert_eq!(
+            Table::from_rows(vec![vec!["Alice".to_owned(), "30".to_owned()]]),
+            Table::from_text_rows(&[["Alice", "30"]])
+        );
+    }
+
+    #[test]
+    fn test_columns() {
+        let rows = || {
+            vec![
+                TableRow::new(vec![TableCell::new().grid_span(2), TableCell::new()]),
+                TableRow::new(vec![TableCell::new(), TableCell::new(), TableCell::new()]),
+            ]
+        };
+        let t = Table::new(rows()).columns(&[
+            ColumnSpec::Fixed(1000),
+            ColumnSpec::Fixed(2000),
+            ColumnSpec::Auto(1500),
+        ]);
+        assert_eq!(
+            t,
+            Table::new(vec![
+                TableRow::new(vec![
+                    TableCell::new().grid_span(2).width(3000, WidthType::Dxa),
+                    TableCell::new().width(0, WidthType::Auto),
+                ]),
+                TableRow::new(vec![
+                    TableCell::new().width(1000, WidthType::Dxa),
+                    TableCell::new().width(2000, WidthType::Dxa),
+                    TableCell::new().width(0, WidthType::Auto),
+                ]),
+            ])
+            .set_grid(vec![1000, 2000, 1500])
+            .layout(TableLayoutType::Autofit)
+        );
+
+        let b = Table::without_borders(vec![TableRow::new(vec![TableCell::new()])])
+            .columns(&[ColumnSpec::Fixed(1200)])
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tbl><w:tblPr><w:tblW w:w="1200" w:type="dxa" /><w:jc w:val="left" /><w:tblBorders /><w:tblLayout w:type="fixed" /></w:tblPr><w:tblGrid>
+  <w:gridCol w:w="1200" w:type="dxa" />
+</w:tblGrid><w:tr><w:trPr /><w:tc><w:tcPr><w:tcW w:w="1200" w:type="dxa" /></w:tcPr><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr></w:p></w:tc></w:tr></w:tbl>"#
+        );
+    }
 }
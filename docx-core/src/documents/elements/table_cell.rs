@@ -5,6 +5,7 @@ use super::*;
 use crate::documents::BuildXML;
 use crate::types::*;
 use crate::xml_builder::*;
+use std::io::{self, Write};
 
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -56,6 +57,30 @@ impl Serialize for TableCellContent {
     }
 }
 
+impl From<Paragraph> for TableCellContent {
+    fn from(p: Paragraph) -> Self {
+        TableCellContent::Paragraph(p)
+    }
+}
+
+impl From<Table> for TableCellContent {
+    fn from(t: Table) -> Self {
+        TableCellContent::Table(t)
+    }
+}
+
+impl From<&str> for TableCellContent {
+    fn from(t: &str) -> Self {
+        TableCellContent::Paragraph(Paragraph::new().add_run(Run::new().add_text(t)))
+    }
+}
+
+impl From<String> for TableCellContent {
+    fn from(t: String) -> Self {
+        TableCellContent::from(t.as_str())
+    }
+}
+
 impl TableCell {
     pub fn new() -> TableCell {
         Default::default()
@@ -99,6 +124,18 @@ impl TableCell {
         self
     }
 
+    // The margins of the cell in twips, which override those of the table.
+    pub fn margins(mut self, top: usize, left: usize, bottom: usize, right: usize) -> TableCell {
+        self.property = self.property.margins(
+            CellMargins::new()
+                .margin_top(top, WidthType::Dxa)
+                .margin_left(left, WidthType::Dxa)
+                .margin_bottom(bottom, WidthType::Dxa)
+                .margin_right(right, WidthType::Dxa),
+        );
+        self
+    }
+
     pub fn vertical_align(mut self, t: VAlignType) -> TableCell {
         self.property = self.property.vertical_align(t);
         self
@@ -153,28 +190,26 @@ impl Default for TableCell {
 }
 
 impl BuildXML for TableCell {
-    fn build(&self) -> Vec<u8> {
-        let b = XMLBuilder::new();
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        let b = XMLBuilder::from_writer(w);
         let mut b = b.open_table_cell().add_child(&self.property);
         for c in &self.children {
             match c {
                 TableCellContent::Paragraph(p) => b = b.add_child(p),
-                TableCellContent::Table(t) => {
-                    b = b.add_child(t);
-                    // INFO: We need to add empty paragraph when parent cell includes only cell.
-                    if self.children.len() == 1 {
-                        b = b.add_child(&Paragraph::new())
-                    }
-                }
+                TableCellContent::Table(t) => b = b.add_child(t),
                 TableCellContent::StructuredDataTag(t) => b = b.add_child(t),
                 TableCellContent::TableOfContents(t) => b = b.add_child(t),
             }
         }
-        // INFO: We need to add empty paragraph when parent cell includes only cell.
-        if self.children.is_empty() {
+        // INFO: A cell must end with a paragraph, even when it is empty or ends with a table.
+        if matches!(
+            self.children.last(),
+            None | Some(TableCellContent::Table(_))
+        ) {
             b = b.add_child(&Paragraph::new())
         }
-        b.close().build()
+        b.close().into_inner()?;
+        Ok(())
     }
 }
 
@@ -195,6 +230,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cell_margins() {
+        let b = TableCell::new().margins(100, 120, 80, 140).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tc><w:tcPr><w:tcMar>
+  <w:top w:w="100" w:type="dxa" />
+  <w:left w:w="120" w:type="dxa" />
+  <w:bottom w:w="80" w:type="dxa" />
+  <w:right w:w="140" w:type="dxa" />
+</w:tcMar></w:tcPr><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr></w:p></w:tc>"#
+        );
+    }
+
     #[test]
     fn test_cell_add_p() {
         let b = TableCell::new()
@@ -206,6 +255,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cell_ends_with_table() {
+        let b = TableCell::new()
+            .add_paragraph(Paragraph::new())
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()])]))
+            .build();
+        assert!(str::from_utf8(&b).unwrap().ends_with(
+            r#"</w:tbl><w:p w14:paraId="12345678"><w:pPr><w:rPr /></w:pPr></w:p></w:tc>"#
+        ));
+    }
+
     #[test]
     fn test_cell_json() {
         let c = TableCell::new()
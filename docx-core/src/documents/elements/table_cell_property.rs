@@ -11,12 +11,12 @@ use crate::xml_builder::*;
 #[derive(Serialize, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct TableCellProperty {
-    width: Option<TableCellWidth>,
+    pub(crate) width: Option<TableCellWidth>,
     borders: Option<TableCellBorders>,
-    grid_span: Option<GridSpan>,
-    vertical_merge: Option<VMerge>,
+    pub(crate) grid_span: Option<GridSpan>,
+    pub(crate) vertical_merge: Option<VMerge>,
     vertical_align: Option<VAlign>,
-    text_direction: Option<TextDirection>,
+    pub(crate) text_direction: Option<TextDirection>,
     shading: Option<Shading>,
     #[serde(skip_serializing_if = "Option::is_none")]
     margins: Option<CellMargins>,
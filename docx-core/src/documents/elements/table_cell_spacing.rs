@@ -0,0 +1,46 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+// The space between adjacent cells and the table edges, of which Word draws the borders on both
+// sides.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableCellSpacing {
+    width: usize,
+    width_type: WidthType,
+}
+
+impl TableCellSpacing {
+    pub fn new(width: usize, width_type: WidthType) -> TableCellSpacing {
+        TableCellSpacing { width, width_type }
+    }
+}
+
+impl BuildXML for TableCellSpacing {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .table_cell_spacing(self.width as i32, self.width_type)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_table_cell_spacing() {
+        let b = TableCellSpacing::new(20, WidthType::Dxa).build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblCellSpacing w:w="20" w:type="dxa" />"#
+        );
+    }
+}
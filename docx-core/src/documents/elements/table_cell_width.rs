@@ -7,7 +7,7 @@ use crate::xml_builder::*;
 #[derive(Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TableCellWidth {
-    width: usize,
+    pub(crate) width: usize,
     width_type: WidthType,
 }
 
@@ -20,7 +20,7 @@ impl TableCellWidth {
 impl BuildXML for TableCellWidth {
     fn build(&self) -> Vec<u8> {
         XMLBuilder::new()
-            .table_cell_width(self.width as i32, WidthType::Dxa)
+            .table_cell_width(self.width as i32, self.width_type)
             .build()
     }
 }
@@ -0,0 +1,33 @@
+use serde::{Serialize, Serializer};
+
+use crate::documents::BuildXML;
+use crate::escape::escape;
+use crate::xml_builder::*;
+
+// The alternative text of the table for assistive technologies, a longer description of the table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableDescription {
+    pub(crate) val: String,
+}
+
+impl TableDescription {
+    pub fn new(val: impl Into<String>) -> TableDescription {
+        TableDescription { val: val.into() }
+    }
+}
+
+impl BuildXML for TableDescription {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        b.table_description(&escape(&self.val)).build()
+    }
+}
+
+impl Serialize for TableDescription {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.val)
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Serialize, Serializer};
+
+use crate::{xml_builder::XMLBuilder, BuildXML};
+
+// Repeat the row at the top of each page, as the header row of the table.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TableHeader {}
+
+impl BuildXML for TableHeader {
+    fn build(&self) -> Vec<u8> {
+        let b = XMLBuilder::new();
+        b.table_header().build()
+    }
+}
+
+impl Serialize for TableHeader {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str("tblHeader")
+    }
+}
@@ -20,7 +20,7 @@ impl TableIndent {
 impl BuildXML for TableIndent {
     fn build(&self) -> Vec<u8> {
         XMLBuilder::new()
-            .table_indent(self.width, WidthType::Dxa)
+            .table_indent(self.width, self.width_type)
             .build()
     }
 }
@@ -11,9 +11,7 @@ pub struct TableLayout {
 
 impl TableLayout {
     pub fn new(t: TableLayoutType) -> TableLayout {
-        TableLayout {
-            layout_type: t,
-        }
+        TableLayout { layout_type: t }
     }
 }
 
@@ -0,0 +1,125 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// The conditional formats of the table style which are applied, such as the header row and the
+// banded rows. Word writes both the attributes and the legacy `w:val` bitmask.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableLook {
+    pub first_row: bool,
+    pub last_row: bool,
+    pub first_column: bool,
+    pub last_column: bool,
+    pub no_h_band: bool,
+    pub no_v_band: bool,
+}
+
+const FIRST_ROW: u16 = 0x0020;
+const LAST_ROW: u16 = 0x0040;
+const FIRST_COLUMN: u16 = 0x0080;
+const LAST_COLUMN: u16 = 0x0100;
+const NO_H_BAND: u16 = 0x0200;
+const NO_V_BAND: u16 = 0x0400;
+
+// The look of new tables in Word, `04A0`.
+impl Default for TableLook {
+    fn default() -> Self {
+        TableLook {
+            first_row: true,
+            last_row: false,
+            first_column: true,
+            last_column: false,
+            no_h_band: false,
+            no_v_band: true,
+        }
+    }
+}
+
+impl TableLook {
+    pub fn new(
+        first_row: bool,
+        last_row: bool,
+        first_column: bool,
+        last_column: bool,
+        no_h_band: bool,
+        no_v_band: bool,
+    ) -> TableLook {
+        TableLook {
+            first_row,
+            last_row,
+            first_column,
+            last_column,
+            no_h_band,
+            no_v_band,
+        }
+    }
+
+    pub(crate) fn from_bitmask(v: u16) -> TableLook {
+        TableLook {
+            first_row: v & FIRST_ROW != 0,
+            last_row: v & LAST_ROW != 0,
+            first_column: v & FIRST_COLUMN != 0,
+            last_column: v & LAST_COLUMN != 0,
+            no_h_band: v & NO_H_BAND != 0,
+            no_v_band: v & NO_V_BAND != 0,
+        }
+    }
+
+    pub fn bitmask(&self) -> u16 {
+        [
+            (self.first_row, FIRST_ROW),
+            (self.last_row, LAST_ROW),
+            (self.first_column, FIRST_COLUMN),
+            (self.last_column, LAST_COLUMN),
+            (self.no_h_band, NO_H_BAND),
+            (self.no_v_band, NO_V_BAND),
+        ]
+        .iter()
+        .filter(|(on, _)| *on)
+        .fold(0, |v, (_, bit)| v | bit)
+    }
+}
+
+impl BuildXML for TableLook {
+    fn build(&self) -> Vec<u8> {
+        let flag = |v: bool| if v { "1" } else { "0" };
+        XMLBuilder::new()
+            .table_look(
+                &format!("{:04X}", self.bitmask()),
+                flag(self.first_row),
+                flag(self.last_row),
+                flag(self.first_column),
+                flag(self.last_column),
+                flag(self.no_h_band),
+                flag(self.no_v_band),
+            )
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_table_look() {
+        let b = TableLook::default().build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblLook w:val="04A0" w:firstRow="1" w:lastRow="0" w:firstColumn="1" w:lastColumn="0" w:noHBand="0" w:noVBand="1" />"#
+        );
+    }
+
+    #[test]
+    fn test_table_look_bitmask() {
+        let look = TableLook::new(true, true, false, false, true, false);
+        assert_eq!(look.bitmask(), 0x0260);
+        assert_eq!(TableLook::from_bitmask(0x0260), look);
+    }
+}
@@ -313,6 +313,29 @@ mod tests {
     use pretty_assertions::assert_eq;
     use std::str;
 
+    #[test]
+    fn test_auto_toc_in_nested_table() {
+        let heading = |text: &str| {
+            Paragraph::new()
+                .style("Heading1")
+                .add_run(Run::new().add_text(text))
+        };
+        let inner = Table::new(vec![TableRow::new(vec![
+            TableCell::new().add_paragraph(heading("Inner"))
+        ])]);
+        let xml = Docx::new()
+            .add_style(Style::new("Heading1", StyleType::Paragraph).name("Heading 1"))
+            .add_table_of_contents(TableOfContents::new().heading_styles_range(1, 3).auto())
+            .add_paragraph(heading("Outer"))
+            .add_table(Table::new(vec![TableRow::new(vec![
+                TableCell::new().add_table(inner)
+            ])]))
+            .build();
+        let document = str::from_utf8(&xml.document).unwrap();
+        assert_eq!(document.matches(r#"w:name="_Toc00000000""#).count(), 2);
+        assert_eq!(document.matches(">Inner</w:t>").count(), 2);
+    }
+
     #[test]
     fn test_toc() {
         let b = TableOfContents::new().heading_styles_range(1, 3).build();
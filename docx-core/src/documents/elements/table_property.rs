@@ -15,15 +15,23 @@ pub struct TableProperty {
     justification: Justification,
     borders: TableBorders,
     #[serde(skip_serializing_if = "Option::is_none")]
+    cell_spacing: Option<TableCellSpacing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     margins: Option<TableCellMargins>,
     #[serde(skip_serializing_if = "Option::is_none")]
     indent: Option<TableIndent>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    style: Option<TableStyle>,
+    pub(crate) style: Option<TableStyle>,
     #[serde(skip_serializing_if = "Option::is_none")]
     layout: Option<TableLayout>,
     #[serde(skip_serializing_if = "Option::is_none")]
     position: Option<TablePositionProperty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    look: Option<TableLook>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caption: Option<TableCaption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<TableDescription>,
 }
 
 impl Default for TableProperty {
@@ -32,11 +40,15 @@ impl Default for TableProperty {
             width: TableWidth::new(0, WidthType::Auto),
             justification: Justification::new("left"),
             borders: TableBorders::new(),
+            cell_spacing: None,
             margins: None,
             indent: None,
             style: None,
             layout: None,
             position: None,
+            look: None,
+            caption: None,
+            description: None,
         }
     }
 }
@@ -68,11 +80,22 @@ impl TableProperty {
         self
     }
 
+    pub fn cell_spacing(mut self, v: usize, t: WidthType) -> Self {
+        self.cell_spacing = Some(TableCellSpacing::new(v, t));
+        self
+    }
+
     pub fn set_margins(mut self, margins: TableCellMargins) -> Self {
         self.margins = Some(margins);
         self
     }
 
+    // The default margins of the cells in twips, in the order of `w:tblCellMar`.
+    pub fn cell_margins(mut self, top: usize, left: usize, bottom: usize, right: usize) -> Self {
+        self.margins = Some(TableCellMargins::new().margin(top, right, bottom, left));
+        self
+    }
+
     pub fn cell_margin_top(mut self, v: usize, t: WidthType) -> Self {
         if let Some(margins) = self.margins {
             self.margins = Some(margins.margin_top(v, t));
@@ -138,6 +161,18 @@ impl TableProperty {
         self
     }
 
+    // Refer to the table style without the default borders and with the default look of
+    // Word, so that the borders and the conditional formats of the style apply.
+    pub(crate) fn use_style(mut self, s: impl Into<String>) -> Self {
+        if self.borders == TableBorders::new() {
+            self.borders = TableBorders::with_empty();
+        }
+        if self.look.is_none() {
+            self.look = Some(TableLook::default());
+        }
+        self.style(s)
+    }
+
     pub fn layout(mut self, t: TableLayoutType) -> Self {
         self.layout = Some(TableLayout::new(t));
         self
@@ -147,6 +182,40 @@ impl TableProperty {
         self.position = Some(p);
         self
     }
+
+    pub fn look(
+        self,
+        first_row: bool,
+        last_row: bool,
+        first_column: bool,
+        last_column: bool,
+        no_h_band: bool,
+        no_v_band: bool,
+    ) -> Self {
+        self.set_look(TableLook::new(
+            first_row,
+            last_row,
+            first_column,
+            last_column,
+            no_h_band,
+            no_v_band,
+        ))
+    }
+
+    pub fn set_look(mut self, look: TableLook) -> Self {
+        self.look = Some(look);
+        self
+    }
+
+    pub fn caption(mut self, v: impl Into<String>) -> Self {
+        self.caption = Some(TableCaption::new(v));
+        self
+    }
+
+    pub fn description(mut self, v: impl Into<String>) -> Self {
+        self.description = Some(TableDescription::new(v));
+        self
+    }
 }
 
 impl BuildXML for TableProperty {
@@ -155,12 +224,16 @@ impl BuildXML for TableProperty {
             .open_table_property()
             .add_child(&self.width)
             .add_child(&self.justification)
+            .add_optional_child(&self.cell_spacing)
+            .add_optional_child(&self.indent)
             .add_child(&self.borders)
             .add_optional_child(&self.margins)
-            .add_optional_child(&self.indent)
             .add_optional_child(&self.style)
             .add_optional_child(&self.layout)
             .add_optional_child(&self.position)
+            .add_optional_child(&self.look)
+            .add_optional_child(&self.caption)
+            .add_optional_child(&self.description)
             .close()
             .build()
     }
@@ -184,6 +257,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cell_margins() {
+        let b = TableProperty::new().cell_margins(100, 120, 80, 140).build();
+        assert!(str::from_utf8(&b).unwrap().contains(
+            r#"<w:tblCellMar>
+  <w:top w:w="100" w:type="dxa" />
+  <w:left w:w="120" w:type="dxa" />
+  <w:bottom w:w="80" w:type="dxa" />
+  <w:right w:w="140" w:type="dxa" />
+</w:tblCellMar>"#
+        ));
+    }
+
+    #[test]
+    fn test_cell_spacing_and_indent() {
+        let b = TableProperty::new()
+            .cell_spacing(15, WidthType::Dxa)
+            .indent(200)
+            .build();
+        assert!(str::from_utf8(&b).unwrap().starts_with(
+            r#"<w:tblPr><w:tblW w:w="0" w:type="auto" /><w:jc w:val="left" /><w:tblCellSpacing w:w="15" w:type="dxa" /><w:tblInd w:w="200" w:type="dxa" /><w:tblBorders>"#
+        ));
+    }
+
+    #[test]
+    fn test_caption_and_description() {
+        let b = TableProperty::without_borders()
+            .caption("Sales")
+            .description("Sales by region & year")
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblPr><w:tblW w:w="0" w:type="auto" /><w:jc w:val="left" /><w:tblBorders /><w:tblCaption w:val="Sales" /><w:tblDescription w:val="Sales by region &amp; year" /></w:tblPr>"#
+        );
+    }
+
     #[test]
     fn test_table_property_json() {
         let p = TableProperty::new().indent(100);
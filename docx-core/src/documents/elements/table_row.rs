@@ -4,6 +4,7 @@ use serde::Serialize;
 use super::{Delete, Insert, TableCell, TableRowProperty};
 use crate::xml_builder::*;
 use crate::{documents::BuildXML, HeightRule};
+use std::io::{self, Write};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -19,9 +20,9 @@ pub enum TableRowChild {
 }
 
 impl BuildXML for TableRowChild {
-    fn build(&self) -> Vec<u8> {
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
         match self {
-            TableRowChild::TableCell(v) => v.build(),
+            TableRowChild::TableCell(v) => v.build_to(w),
         }
     }
 }
@@ -82,15 +83,21 @@ impl TableRow {
         self.property = self.property.cant_split();
         self
     }
+
+    pub fn header_row(mut self) -> TableRow {
+        self.property = self.property.header_row();
+        self
+    }
 }
 
 impl BuildXML for TableRow {
-    fn build(&self) -> Vec<u8> {
-        let b = XMLBuilder::new()
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        let b = XMLBuilder::from_writer(w)
             .open_table_row()
             .add_child(&self.property)
             .add_children(&self.cells);
-        b.close().build()
+        b.close().into_inner()?;
+        Ok(())
     }
 }
 
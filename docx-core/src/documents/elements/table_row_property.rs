@@ -9,7 +9,7 @@ use crate::{documents::BuildXML, HeightRule};
 pub struct TableRowProperty {
     grid_after: Option<u32>,
     width_after: Option<f32>,
-    grid_before: Option<u32>,
+    pub(crate) grid_before: Option<u32>,
     width_before: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     row_height: Option<f32>,
@@ -21,6 +21,8 @@ pub struct TableRowProperty {
     pub ins: Option<Insert>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cant_split: Option<CantSplit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_header: Option<TableHeader>,
 }
 
 impl TableRowProperty {
@@ -72,6 +74,11 @@ impl TableRowProperty {
         self.cant_split = Some(CantSplit::default());
         self
     }
+
+    pub fn header_row(mut self) -> Self {
+        self.table_header = Some(TableHeader::default());
+        self
+    }
 }
 
 impl BuildXML for TableRowProperty {
@@ -87,7 +94,7 @@ impl BuildXML for TableRowProperty {
                 &self.height_rule.unwrap_or_default().to_string(),
             )
         }
-        b.close().build()
+        b.add_optional_child(&self.table_header).close().build()
     }
 }
 
@@ -113,4 +120,13 @@ mod tests {
             r#"<w:trPr><w:cantSplit /></w:trPr>"#
         );
     }
+
+    #[test]
+    fn test_header_row() {
+        let b = TableRowProperty::new().cant_split().header_row().build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:trPr><w:cantSplit /><w:tblHeader /></w:trPr>"#
+        );
+    }
 }
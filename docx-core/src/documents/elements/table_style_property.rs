@@ -0,0 +1,125 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::types::*;
+use crate::xml_builder::*;
+
+use super::*;
+
+// A conditional format of a table style, such as the formatting of the first row.
+// The table and cell properties are optional since their defaults would override the
+// properties of the style, e.g. with the default borders.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableStyleProperty {
+    pub override_type: TableStyleOverrideType,
+    pub run_property: RunProperty,
+    pub paragraph_property: ParagraphProperty,
+    pub table_property: Option<TableProperty>,
+    pub table_cell_property: Option<TableCellProperty>,
+}
+
+impl TableStyleProperty {
+    pub fn new(override_type: TableStyleOverrideType) -> TableStyleProperty {
+        TableStyleProperty {
+            override_type,
+            run_property: RunProperty::new(),
+            paragraph_property: ParagraphProperty::new(),
+            table_property: None,
+            table_cell_property: None,
+        }
+    }
+
+    pub fn run_property(mut self, p: RunProperty) -> Self {
+        self.run_property = p;
+        self
+    }
+
+    pub fn paragraph_property(mut self, p: ParagraphProperty) -> Self {
+        self.paragraph_property = p;
+        self
+    }
+
+    pub fn table_property(mut self, p: TableProperty) -> Self {
+        self.table_property = Some(p);
+        self
+    }
+
+    pub fn table_cell_property(mut self, p: TableCellProperty) -> Self {
+        self.table_cell_property = Some(p);
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.run_property = self.run_property.bold();
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.run_property = self.run_property.italic();
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.run_property = self.run_property.color(color);
+        self
+    }
+
+    pub fn size(mut self, size: usize) -> Self {
+        self.run_property = self.run_property.size(size);
+        self
+    }
+
+    pub fn align(mut self, alignment_type: AlignmentType) -> Self {
+        self.paragraph_property = self.paragraph_property.align(alignment_type);
+        self
+    }
+
+    pub fn shading(mut self, s: Shading) -> Self {
+        self.table_cell_property = Some(self.table_cell_property.unwrap_or_default().shading(s));
+        self
+    }
+
+    pub fn set_cell_border(mut self, border: TableCellBorder) -> Self {
+        self.table_cell_property = Some(
+            self.table_cell_property
+                .unwrap_or_default()
+                .set_border(border),
+        );
+        self
+    }
+}
+
+impl BuildXML for TableStyleProperty {
+    fn build(&self) -> Vec<u8> {
+        XMLBuilder::new()
+            .open_table_style_property(&self.override_type.to_string())
+            .add_child(&self.paragraph_property)
+            .add_child(&self.run_property)
+            .add_optional_child(&self.table_property)
+            .add_optional_child(&self.table_cell_property)
+            .close()
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_build() {
+        let c = TableStyleProperty::new(TableStyleOverrideType::FirstRow)
+            .run_property(RunProperty::new().bold())
+            .table_cell_property(TableCellProperty::new().shading(Shading::new().fill("FF0000")));
+        let b = c.build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<w:tblStylePr w:type="firstRow"><w:pPr><w:rPr /></w:pPr><w:rPr><w:b /><w:bCs /></w:rPr><w:tcPr><w:shd w:val="clear" w:color="auto" w:fill="FF0000" /></w:tcPr></w:tblStylePr>"#
+        );
+    }
+}
@@ -2,6 +2,7 @@ use serde::Serialize;
 
 use crate::documents::*;
 use crate::types::*;
+use crate::xml_builder::*;
 
 #[derive(Debug, Clone, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -39,6 +40,12 @@ pub struct TextBox {
     pub dist_b: i32,
     pub dist_l: i32,
     pub dist_r: i32,
+    // The fill color, such as `FFFFFF`. The box is transparent without it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill: Option<String>,
+    // The color and the width in emu of the border.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border: Option<(String, u32)>,
 }
 
 impl Default for TextBox {
@@ -67,6 +74,8 @@ impl TextBox {
             dist_b: 0,
             dist_l: 0,
             dist_r: 0,
+            fill: None,
+            border: None,
         }
     }
 
@@ -140,39 +149,84 @@ impl TextBox {
         self.relative_height = v;
         self
     }
+
+    pub fn allow_overlap(mut self, v: bool) -> Self {
+        self.allow_overlap = v;
+        self
+    }
+
+    pub fn layout_in_cell(mut self, v: bool) -> Self {
+        self.layout_in_cell = v;
+        self
+    }
+
+    pub fn add_paragraph(mut self, p: Paragraph) -> Self {
+        self.children
+            .push(TextBoxContentChild::Paragraph(Box::new(p)));
+        self
+    }
+
+    pub fn add_table(mut self, t: Table) -> Self {
+        self.children.push(TextBoxContentChild::Table(Box::new(t)));
+        self
+    }
+
+    pub fn fill(mut self, color: impl Into<String>) -> Self {
+        self.fill = Some(color.into());
+        self
+    }
+
+    // unit is emu, e.g. 12700 for 1pt
+    pub fn border(mut self, color: impl Into<String>, width: u32) -> Self {
+        self.border = Some((color.into(), width));
+        self
+    }
 }
 
-/*
-impl BuildXML for Textbox {
+impl BuildXML for TextBox {
     fn build(&self) -> Vec<u8> {
-        let b = XMLBuilder::new();
         let w = format!("{}", self.size.0);
         let h = format!("{}", self.size.1);
-        b.open_pic("http://schemas.openxmlformats.org/drawingml/2006/picture")
-            .open_pic_nv_pic_pr()
-            .pic_c_nv_pr("0", "")
-            .open_pic_c_nv_pic_pr()
-            .a_pic_locks("1", "1")
-            .close()
-            .close()
-            .open_blip_fill()
-            .a_blip(&self.id)
-            .a_src_rect()
-            .open_a_stretch()
-            .a_fill_rect()
-            .close()
-            .close()
-            .open_pic_sp_pr("auto")
+        let b = XMLBuilder::new()
+            .open_wp_shape()
+            .wps_c_nv_sp_pr("1")
+            .open_wps_sp_pr()
             .open_a_xfrm()
             .a_off("0", "0")
             .a_ext(&w, &h)
             .close()
             .open_a_prst_geom("rect")
             .a_av_lst()
+            .close();
+        let b = match &self.fill {
+            Some(fill) => b.open_a_solid_fill().a_srgb_color(fill).close(),
+            None => b.a_no_fill(),
+        };
+        let mut b = match &self.border {
+            Some((color, width)) => b
+                .open_a_line(&format!("{}", width))
+                .open_a_solid_fill()
+                .a_srgb_color(color)
+                .close()
+                .close(),
+            None => b,
+        }
+        .close()
+        .open_wp_text_box()
+        .open_text_box_content();
+        for c in &self.children {
+            match c {
+                TextBoxContentChild::Paragraph(p) => b = b.add_child(p),
+                TextBoxContentChild::Table(t) => b = b.add_child(t),
+            }
+        }
+        // The default insets of Word, 0.1 inch on the sides and 0.05 inch on the top and bottom.
+        b.close()
             .close()
+            .open_wps_body_pr("square", "91440", "45720", "91440", "45720", "t")
+            .a_no_autofit()
             .close()
             .close()
             .build()
     }
 }
-*/
@@ -6,7 +6,7 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct TextDirection {
-    val: TextDirectionType,
+    pub(crate) val: TextDirectionType,
 }
 
 impl TextDirection {
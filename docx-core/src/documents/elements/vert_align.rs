@@ -6,7 +6,7 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct VertAlign {
-    val: VertAlignType,
+    pub val: VertAlignType,
 }
 
 impl VertAlign {
@@ -27,6 +27,6 @@ impl Serialize for VertAlign {
 impl BuildXML for VertAlign {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        b.vert_align(&self.val.to_string() ).build()
+        b.vert_align(&self.val.to_string()).build()
     }
 }
@@ -6,7 +6,7 @@ use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct VMerge {
-    val: VMergeType,
+    pub val: VMergeType,
 }
 
 impl VMerge {
@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+pub(crate) const CHART_CONTENT_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.drawingml.chart+xml";
+
+// Part of a chart as it was read, such as `word/charts/chart1.xml`, its relationships or the
+// workbook with the data of the chart. `path` is in the package.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartPart {
+    pub path: String,
+    pub content_type: Option<String>,
+    #[serde(skip_serializing)]
+    pub data: Vec<u8>,
+}
+
+// A chart of the document, written back from the parts it was read from. `id` is the
+// relationship id by which the drawings refer to it.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddedChart {
+    pub id: String,
+    // The chart part comes first.
+    pub parts: Vec<ChartPart>,
+}
+
+impl EmbeddedChart {
+    pub(crate) fn path(&self) -> Option<&str> {
+        self.parts.first().map(|p| p.path.as_str())
+    }
+
+    // The relationship target, relative to the `word` folder of the main document.
+    pub(crate) fn target(&self) -> Option<String> {
+        self.path().map(|path| match path.strip_prefix("word/") {
+            Some(p) => p.to_owned(),
+            None => format!("../{}", path),
+        })
+    }
+}
+
+// The content type of a part which is usually declared by the `Default` of its extension,
+// which is not read.
+pub(crate) fn chart_part_content_type(path: &str) -> Option<&'static str> {
+    let ext = path.rsplit('.').next().unwrap_or_default();
+    match ext.to_ascii_lowercase().as_str() {
+        "xlsx" => Some("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+        "xlsm" => Some("application/vnd.ms-excel.sheet.macroEnabled.12"),
+        "bin" => Some("application/vnd.openxmlformats-officedocument.oleObject"),
+        _ => None,
+    }
+}
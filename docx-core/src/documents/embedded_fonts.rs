@@ -0,0 +1,239 @@
+use serde::Serialize;
+
+use super::*;
+use crate::types::EmbeddedFontStyle;
+
+// A font embedded in the package, referred to by `id` from the font table. `path` is relative
+// to the `word` folder, such as `fonts/font1.odttf`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddedFont {
+    pub font_name: String,
+    pub style: EmbeddedFontStyle,
+    pub id: String,
+    // GUID such as `{6C3A5CB2-...}` which obfuscates the data. Unset when it is not obfuscated.
+    pub font_key: Option<String>,
+    pub path: String,
+    #[serde(skip_serializing)]
+    pub data: Vec<u8>,
+}
+
+impl EmbeddedFont {
+    pub fn new(
+        font_name: impl Into<String>,
+        style: EmbeddedFontStyle,
+        id: impl Into<String>,
+    ) -> EmbeddedFont {
+        EmbeddedFont {
+            font_name: font_name.into(),
+            style,
+            id: id.into(),
+            font_key: None,
+            path: String::new(),
+            data: vec![],
+        }
+    }
+
+    pub fn font_key(mut self, key: impl Into<String>) -> Self {
+        self.font_key = Some(key.into());
+        self
+    }
+
+    // The font file, de-obfuscated with the font key.
+    pub fn font_data(&self) -> Vec<u8> {
+        match &self.font_key {
+            Some(key) => obfuscate(&self.data, key),
+            None => self.data.clone(),
+        }
+    }
+
+    pub(crate) fn content_type(&self) -> &'static str {
+        if self.font_key.is_some() {
+            "application/vnd.openxmlformats-officedocument.obfuscatedFont"
+        } else {
+            "application/x-font-ttf"
+        }
+    }
+}
+
+// The first 32 bytes of an obfuscated font are XORed with the bytes of the GUID in reverse
+// order. Obfuscating twice restores the font.
+pub(crate) fn obfuscate(data: &[u8], font_key: &str) -> Vec<u8> {
+    let hex: Vec<u8> = font_key
+        .chars()
+        .filter_map(|c| c.to_digit(16))
+        .map(|d| d as u8)
+        .collect();
+    let mut data = data.to_vec();
+    if hex.len() != 32 {
+        return data;
+    }
+    let key: Vec<u8> = hex.chunks(2).rev().map(|d| d[0] << 4 | d[1]).collect();
+    for (i, b) in data.iter_mut().take(32).enumerate() {
+        *b ^= key[i % 16];
+    }
+    data
+}
+
+// A GUID such as `{6C3A5CB2-...}` from FNV-1a hashes of the font.
+fn font_key(name: &str, style: EmbeddedFontStyle, data: &[u8]) -> String {
+    let hash = |seed: u64| {
+        let mut h = 0xcbf2_9ce4_8422_2325 ^ seed;
+        let bytes = name.bytes().chain(style.to_string().into_bytes());
+        for b in bytes.chain(data.iter().copied()) {
+            h = (h ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3);
+        }
+        h
+    };
+    let hex = format!("{:016X}{:016X}", hash(0), hash(1));
+    format!(
+        "{{{}-{}-{}-{}-{}}}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+impl Docx {
+    // Embed a font file, which is obfuscated when the font has a key.
+    pub fn add_embedded_font(mut self, mut font: EmbeddedFont, font_data: &[u8]) -> Docx {
+        let ext = if font.font_key.is_some() {
+            "odttf"
+        } else {
+            "ttf"
+        };
+        // Fonts read from a document may be at any path, so skip those in use.
+        let mut n = self.font_table.embedded_fonts.len() + 1;
+        font.path = loop {
+            let path = format!("fonts/font{}.{}", n, ext);
+            if !self
+                .font_table
+                .embedded_fonts
+                .iter()
+                .any(|f| f.path == path)
+            {
+                break path;
+            }
+            n += 1;
+        };
+        font.data = match &font.font_key {
+            Some(key) => obfuscate(font_data, key),
+            None => font_data.to_vec(),
+        };
+        self.font_table.embedded_fonts.push(font);
+        self
+    }
+
+    // Embed a TrueType font for the styles it is used with, obfuscated as applications do, and
+    // keep it when the document is saved again. The keys are derived from the font, so the same
+    // font is written the same way.
+    pub fn embed_font(
+        mut self,
+        name: impl Into<String>,
+        ttf: &[u8],
+        variants: &[EmbeddedFontStyle],
+    ) -> Docx {
+        let name = name.into();
+        let variants = if variants.is_empty() {
+            &[EmbeddedFontStyle::Regular][..]
+        } else {
+            variants
+        };
+        for style in variants {
+            let mut n = self.font_table.embedded_fonts.len() + 1;
+            let id = loop {
+                let id = format!("rId{}", n);
+                if !self.font_table.embedded_fonts.iter().any(|f| f.id == id) {
+                    break id;
+                }
+                n += 1;
+            };
+            let key = font_key(&name, *style, ttf);
+            self = self.add_embedded_font(EmbeddedFont::new(&name, *style, id).font_key(key), ttf);
+        }
+        self.settings = self.settings.embed_true_type_fonts();
+        self
+    }
+
+    pub fn embedded_fonts(&self) -> &[EmbeddedFont] {
+        &self.font_table.embedded_fonts
+    }
+
+    pub fn embedded_font_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = vec![];
+        for f in &self.font_table.embedded_fonts {
+            if !names.contains(&f.font_name.as_str()) {
+                names.push(&f.font_name);
+            }
+        }
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_obfuscate() {
+        let key = "{00112233-4455-6677-8899-AABBCCDDEEFF}";
+        let font: Vec<u8> = (0..40).collect();
+        let obfuscated = obfuscate(&font, key);
+        assert_eq!(obfuscated[0], 0xff);
+        assert_eq!(obfuscated[1], 1 ^ 0xee);
+        assert_eq!(obfuscated[16], 16 ^ 0xff);
+        assert_eq!(&obfuscated[32..], &font[32..]);
+        let f = EmbeddedFont {
+            data: obfuscated,
+            ..EmbeddedFont::new("Font", EmbeddedFontStyle::Regular, "rId1").font_key(key)
+        };
+        assert_eq!(f.font_data(), font);
+    }
+
+    #[test]
+    fn test_embed_font() {
+        let font: Vec<u8> = (0..64).collect();
+        let docx = Docx::new().embed_font(
+            "Custom Sans",
+            &font,
+            &[EmbeddedFontStyle::Regular, EmbeddedFontStyle::Bold],
+        );
+        let fonts = docx.embedded_fonts();
+        assert_eq!(fonts.len(), 2);
+        assert_eq!(
+            (fonts[0].id.as_str(), fonts[1].id.as_str()),
+            ("rId1", "rId2")
+        );
+        assert_eq!(fonts[1].path, "fonts/font2.odttf");
+        let key = fonts[0].font_key.clone().unwrap();
+        assert_eq!(key.len(), 38);
+        assert_ne!(fonts[1].font_key.as_ref(), Some(&key));
+        assert_ne!(fonts[0].data, font);
+        assert_eq!(fonts[0].font_data(), font);
+        assert_eq!(docx.settings, Settings::new().embed_true_type_fonts());
+
+        // The same font is written with the same keys.
+        let again = Docx::new().embed_font("Custom Sans", &font, &[EmbeddedFontStyle::Regular]);
+        assert_eq!(again.embedded_fonts()[0].font_key, Some(key));
+    }
+
+    #[test]
+    fn test_embed_font_after_read_fonts() {
+        let mut read = EmbeddedFont::new("Read Sans", EmbeddedFontStyle::Regular, "rId1");
+        read.path = "fonts/font2.odttf".to_owned();
+        let mut docx = Docx::new();
+        docx.font_table.embedded_fonts.push(read);
+        let docx = docx.embed_font("Custom Sans", &[0; 64], &[EmbeddedFontStyle::Bold]);
+        let paths: Vec<&str> = docx
+            .embedded_fonts()
+            .iter()
+            .map(|f| f.path.as_str())
+            .collect();
+        assert_eq!(paths, vec!["fonts/font2.odttf", "fonts/font3.odttf"]);
+    }
+}
@@ -0,0 +1,199 @@
+use super::*;
+
+// Image of the media folder of a read document. `id` is the relationship id which `Pic::id`
+// of the pictures showing it refers to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddedImage {
+    pub id: String,
+    pub path: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+    // Size in EMU of the first picture showing the image, unset when none does.
+    pub size: Option<(u32, u32)>,
+}
+
+fn image_content_type(path: &str) -> &'static str {
+    let ext = path.rsplit('.').next().unwrap_or_default();
+    match ext.to_ascii_lowercase().as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" | "jpe" => "image/jpeg",
+        "gif" => "image/gif",
+        "bmp" => "image/bmp",
+        "tif" | "tiff" => "image/tiff",
+        "emf" => "image/x-emf",
+        "wmf" => "image/x-wmf",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        _ => "application/octet-stream",
+    }
+}
+
+#[derive(Default)]
+struct PicCollector<'a> {
+    pics: Vec<&'a Pic>,
+}
+
+impl<'a> PicCollector<'a> {
+    fn add_run(&mut self, run: &'a Run) {
+        for c in &run.children {
+            if let RunChild::Drawing(d) = c {
+                match &d.data {
+                    Some(DrawingData::Pic(pic)) => self.pics.push(pic),
+                    Some(DrawingData::TextBox(t)) => self.add_text_box(&t.children),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn add_text_box(&mut self, children: &'a [TextBoxContentChild]) {
+        for c in children {
+            match c {
+                TextBoxContentChild::Paragraph(p) => self.add_paragraph(p),
+                TextBoxContentChild::Table(t) => self.add_table(t),
+            }
+        }
+    }
+
+    fn add_paragraph_children(&mut self, children: &'a [ParagraphChild]) {
+        for c in children {
+            match c {
+                ParagraphChild::Run(r) => self.add_run(r),
+                ParagraphChild::Insert(i) => {
+                    for c in &i.children {
+                        if let InsertChild::Run(r) = c {
+                            self.add_run(r);
+                        }
+                    }
+                }
+                ParagraphChild::Delete(d) => {
+                    for c in &d.children {
+                        if let DeleteChild::Run(r) = c {
+                            self.add_run(r);
+                        }
+                    }
+                }
+                ParagraphChild::Hyperlink(h) => self.add_paragraph_children(&h.children),
+                ParagraphChild::ComplexField(f) => self.add_paragraph_children(&f.cached_result),
+                ParagraphChild::StructuredDataTag(t) => self.add_structured_data_tag(t),
+                _ => {}
+            }
+        }
+    }
+
+    fn add_paragraph(&mut self, p: &'a Paragraph) {
+        self.add_paragraph_children(&p.children);
+    }
+
+    fn add_table(&mut self, t: &'a Table) {
+        for TableChild::TableRow(row) in &t.rows {
+            for TableRowChild::TableCell(cell) in &row.cells {
+                for c in &cell.children {
+                    match c {
+                        TableCellContent::Paragraph(p) => self.add_paragraph(p),
+                        TableCellContent::Table(t) => self.add_table(t),
+                        TableCellContent::StructuredDataTag(t) => self.add_structured_data_tag(t),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    fn add_structured_data_tag(&mut self, t: &'a StructuredDataTag) {
+        for c in &t.children {
+            match c {
+                StructuredDataTagChild::Run(r) => self.add_run(r),
+                StructuredDataTagChild::Paragraph(p) => self.add_paragraph(p),
+                StructuredDataTagChild::Table(t) => self.add_table(t),
+                StructuredDataTagChild::StructuredDataTag(t) => self.add_structured_data_tag(t),
+                _ => {}
+            }
+        }
+    }
+
+    fn add_header(&mut self, h: &'a Header) {
+        for c in &h.children {
+            match c {
+                HeaderChild::Paragraph(p) => self.add_paragraph(p),
+                HeaderChild::Table(t) => self.add_table(t),
+                HeaderChild::StructuredDataTag(t) => self.add_structured_data_tag(t),
+            }
+        }
+    }
+
+    fn add_footer(&mut self, f: &'a Footer) {
+        for c in &f.children {
+            match c {
+                FooterChild::Paragraph(p) => self.add_paragraph(p),
+                FooterChild::Table(t) => self.add_table(t),
+                FooterChild::StructuredDataTag(t) => self.add_structured_data_tag(t),
+            }
+        }
+    }
+
+    fn add_section(&mut self, s: &'a SectionProperty) {
+        for h in s.get_headers() {
+            self.add_header(h);
+        }
+        for f in s.get_footers() {
+            self.add_footer(f);
+        }
+    }
+}
+
+impl Docx {
+    // Pictures of the body, headers and footers in document order.
+    fn pics(&self) -> Vec<&Pic> {
+        let mut c = PicCollector::default();
+        for child in &self.document.children {
+            match child {
+                DocumentChild::Paragraph(p) => {
+                    c.add_paragraph(p);
+                    if let Some(s) = &p.property.section_property {
+                        c.add_section(s);
+                    }
+                }
+                DocumentChild::Table(t) => c.add_table(t),
+                DocumentChild::StructuredDataTag(t) => c.add_structured_data_tag(t),
+                _ => {}
+            }
+        }
+        c.add_section(&self.document.section_property);
+        c.pics
+    }
+
+    // Images read from the media folder, with the extent of the pictures showing them.
+    pub fn images(&self) -> Vec<EmbeddedImage> {
+        let pics = self.pics();
+        self.images
+            .iter()
+            .map(|(id, path, image, _)| EmbeddedImage {
+                id: id.clone(),
+                path: path.clone(),
+                content_type: image_content_type(path).to_owned(),
+                data: image.0.clone(),
+                size: pics.iter().find(|p| &p.id == id).map(|p| p.size),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_image_content_type() {
+        assert_eq!(image_content_type("media/image1.PNG"), "image/png");
+        assert_eq!(image_content_type("media/image2.jpeg"), "image/jpeg");
+        assert_eq!(image_content_type("media/image3.emf"), "image/x-emf");
+        assert_eq!(
+            image_content_type("media/image4"),
+            "application/octet-stream"
+        );
+    }
+}
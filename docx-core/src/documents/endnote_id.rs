@@ -0,0 +1,18 @@
+#[cfg(not(test))]
+use std::sync::atomic::AtomicUsize;
+#[cfg(not(test))]
+static ENDNOTE_ID: AtomicUsize = AtomicUsize::new(1);
+
+#[cfg(not(test))]
+pub fn generate_endnote_id() -> usize {
+    use std::sync::atomic::Ordering;
+
+    let id = ENDNOTE_ID.load(Ordering::Relaxed);
+    ENDNOTE_ID.store(id.wrapping_add(1), Ordering::Relaxed);
+    id
+}
+
+#[cfg(test)]
+pub fn generate_endnote_id() -> usize {
+    1
+}
@@ -0,0 +1,44 @@
+use super::Endnote;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Endnotes {
+    pub(crate) endnotes: Vec<Endnote>,
+}
+
+impl Endnotes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn inner(&self) -> &[Endnote] {
+        &self.endnotes
+    }
+
+    // Endnotes with the id of an existing one replace it, as for footnotes.
+    pub(crate) fn add(&mut self, endnotes: Vec<Endnote>) {
+        for e in endnotes {
+            if let Some(existing) = self.endnotes.iter_mut().find(|x| x.id == e.id) {
+                if !e.content.is_empty() {
+                    *existing = e;
+                }
+            } else {
+                self.endnotes.push(e);
+            }
+        }
+    }
+}
+
+impl BuildXML for Endnotes {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new().declaration(Some(true)).open_endnotes();
+        for c in &self.endnotes {
+            b = b.add_child(c)
+        }
+        b.close().build()
+    }
+}
@@ -1,4 +1,4 @@
-use super::Font;
+use super::{EmbeddedFont, Font};
 use crate::documents::BuildXML;
 use crate::types::FontPitchType;
 use crate::xml_builder::*;
@@ -7,7 +7,10 @@ use serde::Serialize;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct FontTable {}
+pub struct FontTable {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embedded_fonts: Vec<EmbeddedFont>,
+}
 
 impl FontTable {
     pub fn new() -> FontTable {
@@ -17,23 +20,33 @@ impl FontTable {
 
 impl Default for FontTable {
     fn default() -> Self {
-        Self {}
+        Self {
+            embedded_fonts: vec![],
+        }
     }
 }
 
 impl BuildXML for FontTable {
     fn build(&self) -> Vec<u8> {
         let b = XMLBuilder::new();
-        let times = Font::new("Times New Roman", "00", "roman", FontPitchType::Variable);
-        let symbol = Font::new("Symbol", "02", "roman", FontPitchType::Variable);
-        let arial = Font::new("Arial", "00", "swiss", FontPitchType::Variable);
-        b.declaration(Some(true))
-            .open_fonts()
-            .add_child(&times)
-            .add_child(&symbol)
-            .add_child(&arial)
-            .close()
-            .build()
+        let mut fonts = vec![
+            Font::new("Times New Roman", "00", "roman", FontPitchType::Variable),
+            Font::new("Symbol", "02", "roman", FontPitchType::Variable),
+            Font::new("Arial", "00", "swiss", FontPitchType::Variable),
+        ];
+        for e in &self.embedded_fonts {
+            match fonts.iter_mut().find(|f| f.name == e.font_name) {
+                Some(f) => f.embeds.push(e),
+                // The metrics of embedded fonts are not kept, so leave them to the application.
+                None => fonts
+                    .push(Font::new(&e.font_name, "00", "auto", FontPitchType::Default).embed(e)),
+            }
+        }
+        let mut b = b.declaration(Some(true)).open_fonts();
+        for f in &fonts {
+            b = b.add_child(f);
+        }
+        b.close().build()
     }
 }
 
@@ -42,6 +55,7 @@ mod tests {
 
     use super::*;
     #[cfg(test)]
+    use crate::types::EmbeddedFontStyle;
     use pretty_assertions::assert_eq;
     use std::str;
 
@@ -67,4 +81,31 @@ mod tests {
 </w:font></w:fonts>"#
         );
     }
+
+    #[test]
+    fn test_embedded_fonts() {
+        let mut c = FontTable::new();
+        c.embedded_fonts = vec![
+            EmbeddedFont::new("Arial", EmbeddedFontStyle::Bold, "rId2")
+                .font_key("{00112233-4455-6677-8899-AABBCCDDEEFF}"),
+            EmbeddedFont::new("Arial", EmbeddedFontStyle::Regular, "rId1")
+                .font_key("{00112233-4455-6677-8899-AABBCCDDEEFF}"),
+            EmbeddedFont::new("Custom", EmbeddedFontStyle::Regular, "rId3"),
+        ];
+        let b = c.build();
+        assert!(str::from_utf8(&b).unwrap().ends_with(
+            r#"<w:font w:name="Arial">
+  <w:charset w:val="00" />
+  <w:family w:val="swiss" />
+  <w:pitch w:val="variable" />
+  <w:embedRegular r:id="rId1" w:fontKey="{00112233-4455-6677-8899-AABBCCDDEEFF}" />
+  <w:embedBold r:id="rId2" w:fontKey="{00112233-4455-6677-8899-AABBCCDDEEFF}" />
+</w:font><w:font w:name="Custom">
+  <w:charset w:val="00" />
+  <w:family w:val="auto" />
+  <w:pitch w:val="default" />
+  <w:embedRegular r:id="rId3" />
+</w:font></w:fonts>"#
+        ));
+    }
 }
@@ -0,0 +1,40 @@
+use serde::Serialize;
+
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FontTableRels {
+    pub fonts: Vec<(String, String)>,
+}
+
+impl FontTableRels {
+    pub fn new() -> FontTableRels {
+        Default::default()
+    }
+
+    pub fn add_font(mut self, id: impl Into<String>, path: impl Into<String>) -> Self {
+        self.fonts.push((id.into(), path.into()));
+        self
+    }
+}
+
+impl BuildXML for FontTableRels {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new();
+        b = b
+            .declaration(None)
+            .open_relationships("http://schemas.openxmlformats.org/package/2006/relationships");
+
+        for (id, path) in self.fonts.iter() {
+            b = b.relationship(
+                id,
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/font",
+                path,
+            )
+        }
+
+        b.close().build()
+    }
+}
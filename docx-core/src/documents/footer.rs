@@ -4,6 +4,7 @@ use serde::Serialize;
 use super::*;
 use crate::documents::BuildXML;
 use crate::xml_builder::*;
+use std::io::{self, Write};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -80,8 +81,8 @@ impl Serialize for FooterChild {
 }
 
 impl BuildXML for Footer {
-    fn build(&self) -> Vec<u8> {
-        let mut b = XMLBuilder::new();
+    fn build_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        let mut b = XMLBuilder::from_writer(w);
         b = b.declaration(Some(true)).open_footer();
 
         for c in &self.children {
@@ -91,7 +92,8 @@ impl BuildXML for Footer {
                 FooterChild::StructuredDataTag(t) => b = b.add_child(t),
             }
         }
-        b.close().build()
+        b.close().into_inner()?;
+        Ok(())
     }
 }
 
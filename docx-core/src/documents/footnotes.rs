@@ -15,8 +15,22 @@ impl Footnotes {
         Self::default()
     }
 
+    pub fn inner(&self) -> &[Footnote] {
+        &self.footnotes
+    }
+
+    // Footnotes with the id of an existing one replace it, e.g. read footnotes whose
+    // references are collected again on build. Empty references keep the existing content.
     pub(crate) fn add(&mut self, footnotes: Vec<Footnote>) {
-        self.footnotes.extend(footnotes)
+        for f in footnotes {
+            if let Some(existing) = self.footnotes.iter_mut().find(|e| e.id == f.id) {
+                if !f.content.is_empty() {
+                    *existing = f;
+                }
+            } else {
+                self.footnotes.push(f);
+            }
+        }
     }
 }
 
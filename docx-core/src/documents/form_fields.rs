@@ -0,0 +1,318 @@
+use super::*;
+use crate::escape::replace_escaped;
+use crate::types::*;
+
+// Legacy form field of the body with its current value: the result text of text inputs,
+// the selected entry of drop downs, and `1` or `0` for check boxes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormFieldValue {
+    pub data: FormFieldData,
+    pub value: String,
+}
+
+impl FormFieldValue {
+    fn new(data: FormFieldData, result: String) -> FormFieldValue {
+        let value = match &data.field {
+            FormField::CheckBox { checked } => if *checked { "1" } else { "0" }.to_owned(),
+            FormField::DropDown { entries, selected } => {
+                entries.get(*selected).cloned().unwrap_or_default()
+            }
+            FormField::TextInput { .. } => result,
+        };
+        FormFieldValue { data, value }
+    }
+}
+
+fn runs(p: &Paragraph) -> impl Iterator<Item = &Run> {
+    p.children.iter().filter_map(|c| match c {
+        ParagraphChild::Run(r) => Some(r.as_ref()),
+        _ => None,
+    })
+}
+
+fn runs_mut(p: &mut Paragraph) -> impl Iterator<Item = &mut Run> {
+    p.children.iter_mut().filter_map(|c| match c {
+        ParagraphChild::Run(r) => Some(r.as_mut()),
+        _ => None,
+    })
+}
+
+// The result of a field is the text between its `separate` and `end`. Fields nested in
+// the instructions or results of other fields are not form fields of the body.
+fn collect_fields(p: &Paragraph, fields: &mut Vec<FormFieldValue>) {
+    let mut depth = 0;
+    let mut current: Option<(FormFieldData, String)> = None;
+    let mut in_result = false;
+    for run in runs(p) {
+        for c in &run.children {
+            match c {
+                RunChild::FieldChar(f) => match f.field_char_type {
+                    FieldCharType::Begin => {
+                        depth += 1;
+                        if depth == 1 {
+                            current = f.form_field.clone().map(|d| (d, String::new()));
+                            in_result = false;
+                        }
+                    }
+                    FieldCharType::Separate => in_result = depth == 1,
+                    FieldCharType::End => {
+                        if depth == 1 {
+                            if let Some((data, result)) = current.take() {
+                                fields.push(FormFieldValue::new(data, result));
+                            }
+                            in_result = false;
+                        }
+                        depth -= 1;
+                    }
+                    FieldCharType::Unsupported => {}
+                },
+                RunChild::Text(t) if in_result => {
+                    if let Some((_, result)) = &mut current {
+                        result.push_str(&replace_escaped(&t.text));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// Check boxes are checked by `1` or `true`, and drop downs select the entry equal to the
+// value. Text inputs get the value as their only result text.
+fn fill_fields(p: &mut Paragraph, name: &str, value: &str) {
+    let mut depth = 0;
+    let mut filling = false;
+    let mut in_result = false;
+    let mut filled = false;
+    for run in runs_mut(p) {
+        let mut i = 0;
+        while i < run.children.len() {
+            match &mut run.children[i] {
+                RunChild::FieldChar(f) => match f.field_char_type {
+                    FieldCharType::Begin => {
+                        depth += 1;
+                        if depth == 1 {
+                            filling = false;
+                            in_result = false;
+                            if let Some(data) = &mut f.form_field {
+                                if data.name.as_deref() == Some(name) {
+                                    match &mut data.field {
+                                        FormField::CheckBox { checked } => {
+                                            *checked = value == "1" || value == "true";
+                                        }
+                                        FormField::DropDown { entries, selected } => {
+                                            if let Some(s) = entries.iter().position(|e| e == value)
+                                            {
+                                                *selected = s;
+                                            }
+                                        }
+                                        FormField::TextInput { .. } => {
+                                            filling = true;
+                                            filled = false;
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    FieldCharType::Separate => in_result = depth == 1,
+                    FieldCharType::End => {
+                        if depth == 1 && filling && !filled {
+                            run.children.insert(i, RunChild::Text(Text::new(value)));
+                            i += 1;
+                        }
+                        if depth == 1 {
+                            filling = false;
+                            in_result = false;
+                        }
+                        depth -= 1;
+                    }
+                    FieldCharType::Unsupported => {}
+                },
+                RunChild::Text(t) if filling && in_result => {
+                    if filled {
+                        run.children.remove(i);
+                        continue;
+                    }
+                    *t = Text::new(value);
+                    filled = true;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+}
+
+fn collect_fields_in_table(t: &Table, fields: &mut Vec<FormFieldValue>) {
+    for TableChild::TableRow(row) in &t.rows {
+        for TableRowChild::TableCell(cell) in &row.cells {
+            for c in &cell.children {
+                match c {
+                    TableCellContent::Paragraph(p) => collect_fields(p, fields),
+                    TableCellContent::Table(t) => collect_fields_in_table(t, fields),
+                    TableCellContent::StructuredDataTag(t) => collect_fields_in_tag(t, fields),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn collect_fields_in_tag(t: &StructuredDataTag, fields: &mut Vec<FormFieldValue>) {
+    for c in &t.children {
+        match c {
+            StructuredDataTagChild::Paragraph(p) => collect_fields(p, fields),
+            StructuredDataTagChild::Table(t) => collect_fields_in_table(t, fields),
+            StructuredDataTagChild::StructuredDataTag(t) => collect_fields_in_tag(t, fields),
+            _ => {}
+        }
+    }
+}
+
+fn fill_fields_in_table(t: &mut Table, name: &str, value: &str) {
+    for TableChild::TableRow(row) in &mut t.rows {
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            for c in &mut cell.children {
+                match c {
+                    TableCellContent::Paragraph(p) => fill_fields(p, name, value),
+                    TableCellContent::Table(t) => fill_fields_in_table(t, name, value),
+                    TableCellContent::StructuredDataTag(t) => fill_fields_in_tag(t, name, value),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn fill_fields_in_tag(t: &mut StructuredDataTag, name: &str, value: &str) {
+    for c in &mut t.children {
+        match c {
+            StructuredDataTagChild::Paragraph(p) => fill_fields(p, name, value),
+            StructuredDataTagChild::Table(t) => fill_fields_in_table(t, name, value),
+            StructuredDataTagChild::StructuredDataTag(t) => fill_fields_in_tag(t, name, value),
+            _ => {}
+        }
+    }
+}
+
+impl Docx {
+    // Legacy form fields of the body in document order.
+    pub fn form_fields(&self) -> Vec<FormFieldValue> {
+        let mut fields = vec![];
+        for child in &self.document.children {
+            match child {
+                DocumentChild::Paragraph(p) => collect_fields(p, &mut fields),
+                DocumentChild::Table(t) => collect_fields_in_table(t, &mut fields),
+                DocumentChild::StructuredDataTag(t) => collect_fields_in_tag(t, &mut fields),
+                _ => {}
+            }
+        }
+        fields
+    }
+
+    // Set the value of the legacy form fields named `name`, see `FormFieldValue`.
+    pub fn fill_form_field(mut self, name: &str, value: &str) -> Self {
+        for child in &mut self.document.children {
+            match child {
+                DocumentChild::Paragraph(p) => fill_fields(p, name, value),
+                DocumentChild::Table(t) => fill_fields_in_table(t, name, value),
+                DocumentChild::StructuredDataTag(t) => fill_fields_in_tag(t, name, value),
+                _ => {}
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn field_char(t: FieldCharType, data: Option<FormFieldData>) -> Run {
+        let mut c = FieldChar::new(t);
+        c.form_field = data;
+        let mut run = Run::new();
+        run.children.push(RunChild::FieldChar(c));
+        run
+    }
+
+    fn form_field(data: FormFieldData, instr: &str, result: &[&str]) -> Vec<Run> {
+        let mut runs = vec![
+            field_char(FieldCharType::Begin, Some(data)),
+            Run::new().add_instr_text(InstrText::Unsupported(instr.to_owned())),
+            field_char(FieldCharType::Separate, None),
+        ];
+        runs.extend(result.iter().map(|t| Run::new().add_text(*t)));
+        runs.push(field_char(FieldCharType::End, None));
+        runs
+    }
+
+    fn paragraph(runs: Vec<Run>) -> Paragraph {
+        runs.into_iter()
+            .fold(Paragraph::new(), |p, run| p.add_run(run))
+    }
+
+    #[test]
+    fn test_form_fields() {
+        let name = FormFieldData::text_input()
+            .name("Name")
+            .help_text("Full name");
+        let agree = FormFieldData::check_box(false).name("Agree");
+        let sizes = vec!["S".to_owned(), "M".to_owned(), "L".to_owned()];
+        let size = FormFieldData::drop_down(sizes, 1).name("Size");
+        let docx = Docx::new()
+            .add_paragraph(paragraph(form_field(
+                name.clone(),
+                "FORMTEXT",
+                &["Jane ", "Doe"],
+            )))
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()
+                .add_paragraph(paragraph(
+                    form_field(agree.clone(), "FORMCHECKBOX", &[])
+                        .into_iter()
+                        .chain(form_field(size.clone(), "FORMDROPDOWN", &[]))
+                        .collect(),
+                ))])]));
+        assert_eq!(
+            docx.form_fields(),
+            vec![
+                FormFieldValue {
+                    data: name,
+                    value: "Jane Doe".to_owned()
+                },
+                FormFieldValue {
+                    data: agree,
+                    value: "0".to_owned()
+                },
+                FormFieldValue {
+                    data: size,
+                    value: "M".to_owned()
+                },
+            ]
+        );
+
+        let docx = docx
+            .fill_form_field("Name", "A & B")
+            .fill_form_field("Agree", "1")
+            .fill_form_field("Size", "L");
+        let values: Vec<String> = docx.form_fields().into_iter().map(|f| f.value).collect();
+        assert_eq!(values, vec!["A & B", "1", "L"]);
+        match &docx.document.children[0] {
+            DocumentChild::Paragraph(p) => assert_eq!(p.raw_text(), "A &amp; B"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_fill_empty_text_input() {
+        let data = FormFieldData::text_input().name("Text1");
+        let docx = Docx::new()
+            .add_paragraph(paragraph(form_field(data, "FORMTEXT", &[])))
+            .fill_form_field("Text1", "filled");
+        assert_eq!(docx.form_fields()[0].value, "filled");
+    }
+}
@@ -0,0 +1,78 @@
+use serde::Serialize;
+
+use super::*;
+
+// Entry of the glossary document, such as a Quick Part or an AutoText entry. `children` is
+// the content inserted by the entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildingBlock {
+    pub name: String,
+    pub gallery: Option<String>,
+    pub category: Option<String>,
+    pub description: Option<String>,
+    pub guid: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub behaviors: Vec<String>,
+    pub children: Vec<DocumentChild>,
+}
+
+impl BuildingBlock {
+    pub fn new(name: impl Into<String>) -> BuildingBlock {
+        BuildingBlock {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+}
+
+// Part of the glossary document as it was read. `path` is in the package, such as
+// `word/glossary/styles.xml`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlossaryPart {
+    pub path: String,
+    pub content_type: Option<String>,
+    #[serde(skip_serializing)]
+    pub data: Vec<u8>,
+}
+
+// The glossary document with its own styles and numberings. It is written back from the
+// parts it was read from, so edits of the building blocks are not written.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GlossaryDocument {
+    pub building_blocks: Vec<BuildingBlock>,
+    pub styles: Option<Styles>,
+    pub numberings: Option<Numberings>,
+    // The glossary document part comes first.
+    pub parts: Vec<GlossaryPart>,
+}
+
+impl GlossaryDocument {
+    pub fn new() -> GlossaryDocument {
+        Default::default()
+    }
+
+    pub fn add_building_block(mut self, b: BuildingBlock) -> Self {
+        self.building_blocks.push(b);
+        self
+    }
+
+    pub fn find_building_block(&self, name: &str) -> Option<&BuildingBlock> {
+        self.building_blocks.iter().find(|b| b.name == name)
+    }
+
+    pub(crate) fn path(&self) -> Option<&str> {
+        self.parts.first().map(|p| p.path.as_str())
+    }
+}
+
+impl Docx {
+    pub fn building_blocks(&self) -> &[BuildingBlock] {
+        self.glossary_document
+            .as_ref()
+            .map(|g| &g.building_blocks[..])
+            .unwrap_or_default()
+    }
+}
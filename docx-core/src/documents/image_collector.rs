@@ -1,5 +1,8 @@
+use std::collections::HashMap;
+
+use crate::documents::sha256;
 use crate::{
-    DeleteChild, DrawingData, InsertChild, Paragraph, ParagraphChild, RunChild,
+    DeleteChild, DrawingData, InsertChild, Paragraph, ParagraphChild, RunChild, StructuredDataTag,
     StructuredDataTagChild, Table, TableCellContent, TableChild, TableRowChild, TocContent,
 };
 
@@ -30,7 +33,12 @@ pub(crate) fn collect_images_from_paragraph(
                             image_bufs.push((pic_id.clone(), b));
                             pic.id = pic_id;
                         } else {
-                            pic.id = buf.unwrap().0.clone();
+                            let id = buf.unwrap().0.clone();
+                            // The part may be another header which has not related the image yet.
+                            if !images.iter().any(|(i, _)| i == &id) {
+                                images.push((id.clone(), format!("media/{}.png", id)));
+                            }
+                            pic.id = id;
                         }
                     }
                 }
@@ -93,6 +101,14 @@ pub(crate) fn collect_images_from_paragraph(
                     }
                 }
             }
+        } else if let ParagraphChild::ComplexField(f) = child {
+            // Such as the pictures of INCLUDEPICTURE fields.
+            let mut result = Paragraph {
+                children: std::mem::take(&mut f.cached_result),
+                ..Default::default()
+            };
+            collect_images_from_paragraph(&mut result, images, image_bufs, id_prefix);
+            f.cached_result = result.children;
         }
     }
 }
@@ -153,3 +169,122 @@ pub(crate) fn collect_images_from_table(
         }
     }
 }
+
+pub(crate) fn collect_images_from_structured_data_tag(
+    tag: &mut StructuredDataTag,
+    images: &mut Vec<(String, String)>,
+    image_bufs: &mut Vec<(String, Vec<u8>)>,
+    id_prefix: Option<&str>,
+) {
+    for child in &mut tag.children {
+        if let StructuredDataTagChild::Paragraph(paragraph) = child {
+            collect_images_from_paragraph(paragraph, images, image_bufs, id_prefix);
+        }
+        if let StructuredDataTagChild::Table(table) = child {
+            collect_images_from_table(table, images, image_bufs, id_prefix);
+        }
+    }
+}
+
+// Relationship ids of the images of VML shapes, which refer to the images read with the
+// document instead of having their own data.
+pub(crate) fn collect_shape_image_ids_from_paragraph(paragraph: &Paragraph, ids: &mut Vec<String>) {
+    for child in &paragraph.children {
+        if let ParagraphChild::Run(run) = child {
+            for child in &run.children {
+                if let RunChild::Shape(shape) = child {
+                    for id in shape.image_ids() {
+                        if !ids.iter().any(|i| i == id) {
+                            ids.push(id.to_owned());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn collect_shape_image_ids_from_table(table: &Table, ids: &mut Vec<String>) {
+    for TableChild::TableRow(row) in &table.rows {
+        for TableRowChild::TableCell(cell) in &row.cells {
+            for content in &cell.children {
+                match content {
+                    TableCellContent::Paragraph(paragraph) => {
+                        collect_shape_image_ids_from_paragraph(paragraph, ids)
+                    }
+                    TableCellContent::Table(table) => {
+                        collect_shape_image_ids_from_table(table, ids)
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+// Keep one media part for each content, such as a logo in every header. The ids of the removed
+// parts are mapped to those of the kept ones, whose paths the relationships are pointed to.
+pub(crate) fn dedupe_media(image_bufs: &mut Vec<(String, Vec<u8>)>) -> HashMap<String, String> {
+    let mut kept: HashMap<Vec<u8>, String> = HashMap::new();
+    let mut replaced = HashMap::new();
+    image_bufs.retain(|(id, buf)| {
+        let digest = sha256(buf);
+        match kept.get(&digest) {
+            Some(kept_id) => {
+                if kept_id != id {
+                    replaced.insert(id.clone(), kept_id.clone());
+                }
+                false
+            }
+            None => {
+                kept.insert(digest, id.clone());
+                true
+            }
+        }
+    });
+    replaced
+}
+
+pub(crate) fn redirect_media(images: &mut [(String, String)], replaced: &HashMap<String, String>) {
+    for (_, path) in images.iter_mut() {
+        let id = path
+            .strip_prefix("media/")
+            .and_then(|p| p.strip_suffix(".png"));
+        if let Some(kept) = id.and_then(|id| replaced.get(id)) {
+            *path = format!("media/{}.png", kept);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_dedupe_media() {
+        let logo = std::fs::read("../images/cat_min.jpg").unwrap();
+        let pic = || Run::new().add_image(Pic::new(&logo));
+        let xml = Docx::new()
+            .header(Header::new().add_paragraph(Paragraph::new().add_run(pic())))
+            .first_header(Header::new().add_paragraph(Paragraph::new().add_run(pic())))
+            .footer(Footer::new().add_paragraph(Paragraph::new().add_run(pic())))
+            .add_paragraph(Paragraph::new().add_run(pic()))
+            .build();
+        assert_eq!(
+            xml.media
+                .iter()
+                .map(|(id, _)| id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["rIdImage123"]
+        );
+        let target = r#"Target="media/rIdImage123.png""#;
+        assert!(str::from_utf8(&xml.document_rels).unwrap().contains(target));
+        for rels in xml.header_rels.iter().chain(xml.footer_rels.iter()) {
+            assert!(str::from_utf8(rels).unwrap().contains(target));
+        }
+    }
+}
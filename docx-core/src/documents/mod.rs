@@ -1,23 +1,38 @@
 use std::{collections::HashMap, str::FromStr};
 
+mod alt_chunks;
 mod bookmark_id;
 mod build_xml;
+mod c14n;
 mod comments;
 mod comments_extended;
 mod content_types;
 mod custom_item;
 mod custom_item_property;
 mod custom_item_rels;
+mod custom_parts;
+mod digest;
+mod digital_signature;
+mod doc_pr_id;
 mod doc_props;
 mod document;
 mod document_rels;
+mod editable_docx;
 mod elements;
+mod embedded_charts;
+mod embedded_fonts;
+mod embedded_images;
+mod endnote_id;
+mod endnotes;
 mod font_table;
+mod font_table_rels;
 mod footer;
 mod footer_id;
 mod footer_rels;
 mod footnote_id;
 mod footnotes;
+mod form_fields;
+mod glossary_document;
 mod header;
 mod header_id;
 mod header_rels;
@@ -25,22 +40,35 @@ mod history_id;
 mod hyperlink_id;
 mod image_collector;
 mod numberings;
+#[cfg(feature = "tokio")]
+mod pack_async;
+mod pack_options;
+mod para_id_strategy;
 mod paragraph_id;
 mod paragraph_property_change_id;
 mod pic_id;
 mod preset_styles;
+mod pretty;
 mod rels;
+mod render;
 mod settings;
+mod statistics;
+mod strict;
 mod styles;
 mod taskpanes;
 mod taskpanes_rels;
 mod theme;
 mod toc_key;
+mod validation;
+mod vba_project;
 mod web_settings;
 mod webextension;
 mod xml_docx;
 
 pub(crate) use build_xml::BuildXML;
+pub(crate) use c14n::canonicalize;
+pub(crate) use digest::{sha1, sha256, sha512};
+pub(crate) use doc_pr_id::*;
 pub(crate) use history_id::HistoryId;
 pub(crate) use hyperlink_id::*;
 use image::ImageFormat;
@@ -48,6 +76,7 @@ pub(crate) use paragraph_id::*;
 pub(crate) use paragraph_property_change_id::ParagraphPropertyChangeId;
 pub(crate) use pic_id::*;
 
+pub use alt_chunks::*;
 pub use bookmark_id::*;
 pub use comments::*;
 pub use comments_extended::*;
@@ -55,33 +84,55 @@ pub use content_types::*;
 pub use custom_item::*;
 pub use custom_item_property::*;
 pub use custom_item_rels::*;
+pub use custom_parts::*;
+pub use digital_signature::*;
 pub use doc_props::*;
 pub use document::*;
 pub use document_rels::*;
+pub use editable_docx::*;
 pub use elements::*;
+pub use embedded_charts::*;
+pub use embedded_fonts::*;
+pub use embedded_images::*;
+pub use endnotes::*;
 pub use font_table::*;
+pub use font_table_rels::*;
 pub use footer::*;
 pub use footer_id::*;
 pub use footer_rels::*;
 pub use footnotes::*;
+pub use form_fields::*;
+pub use glossary_document::*;
 pub use header::*;
 pub use header_id::*;
 pub use header_rels::*;
 pub use numberings::*;
+pub use pack_options::*;
+pub use para_id_strategy::*;
 pub use rels::*;
+pub use render::*;
 pub use settings::*;
+pub use statistics::*;
 pub use styles::*;
 pub use taskpanes::*;
 pub use taskpanes_rels::*;
 pub use theme::*;
 pub use toc_key::*;
+pub use validation::*;
+pub use vba_project::*;
 pub use web_settings::*;
 pub use webextension::*;
 pub use xml_docx::*;
 
 use serde::{ser, Serialize};
 
-use self::image_collector::{collect_images_from_paragraph, collect_images_from_table};
+use crate::escape::escape;
+
+use self::image_collector::{
+    collect_images_from_paragraph, collect_images_from_structured_data_tag,
+    collect_images_from_table, collect_shape_image_ids_from_paragraph,
+    collect_shape_image_ids_from_table, dedupe_media, redirect_media,
+};
 
 #[derive(Debug, Clone)]
 pub struct Image(pub Vec<u8>);
@@ -134,13 +185,31 @@ pub struct Docx {
     pub custom_items: Vec<CustomItem>,
     pub custom_item_props: Vec<CustomItemProperty>,
     pub custom_item_rels: Vec<CustomItemRels>,
-    // reader only
     pub themes: Vec<Theme>,
     // reader only
     pub images: Vec<(String, String, Image, Png)>,
     // reader only
     pub hyperlinks: Vec<(String, String, String)>,
     pub footnotes: Footnotes,
+    pub endnotes: Endnotes,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub alt_chunks: Vec<AltChunkPart>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub custom_parts: Vec<CustomPart>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub charts: Vec<EmbeddedChart>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glossary_document: Option<GlossaryDocument>,
+    pub package_type: crate::types::PackageType,
+    pub conformance: crate::types::Conformance,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vba_project: Option<VbaProject>,
+    #[serde(skip)]
+    pub para_id_strategy: ParaIdStrategy,
+    #[serde(skip)]
+    pub pretty_xml: bool,
+    #[serde(skip)]
+    pub app_statistics: bool,
 }
 
 impl Default for Docx {
@@ -159,6 +228,7 @@ impl Default for Docx {
         let comments_extended = CommentsExtended::new();
         let web_settings = WebSettings::new();
         let footnotes = Footnotes::default();
+        let endnotes = Endnotes::default();
 
         Docx {
             content_type,
@@ -184,6 +254,17 @@ impl Default for Docx {
             images: vec![],
             hyperlinks: vec![],
             footnotes,
+            endnotes,
+            alt_chunks: vec![],
+            custom_parts: vec![],
+            charts: vec![],
+            glossary_document: None,
+            package_type: crate::types::PackageType::Document,
+            conformance: crate::types::Conformance::Transitional,
+            vba_project: None,
+            para_id_strategy: ParaIdStrategy::Keep,
+            pretty_xml: false,
+            app_statistics: true,
         }
     }
 }
@@ -246,15 +327,19 @@ impl Docx {
         path: impl Into<String>,
         buf: Vec<u8>,
     ) -> Self {
-        if let Ok(dimg) = image::load_from_memory(&buf) {
-            let mut png = std::io::Cursor::new(vec![]);
-            // For now only png supported
-            dimg.write_to(&mut png, ImageFormat::Png)
-                .expect("Unable to write dynamic image");
-
-            self.images
-                .push((id.into(), path.into(), Image(buf), Png(png.into_inner())));
-        }
+        // Images which can not be decoded, such as EMF, are kept without a PNG.
+        let png = match image::load_from_memory(&buf) {
+            Ok(dimg) => {
+                let mut png = std::io::Cursor::new(vec![]);
+                // For now only png supported
+                dimg.write_to(&mut png, ImageFormat::Png)
+                    .expect("Unable to write dynamic image");
+                png.into_inner()
+            }
+            Err(_) => vec![],
+        };
+        self.images
+            .push((id.into(), path.into(), Image(buf), Png(png)));
         self
     }
 
@@ -436,6 +521,18 @@ impl Docx {
         self
     }
 
+    pub fn core_props(&self) -> &CorePropsConfig {
+        &self.doc_props.core.config
+    }
+
+    pub fn app_props(&self) -> &AppProps {
+        &self.doc_props.app
+    }
+
+    pub fn custom_props(&self) -> &CustomProps {
+        &self.doc_props.custom
+    }
+
     pub fn doc_id(mut self, id: &str) -> Self {
         self.settings = self.settings.doc_id(id);
         self
@@ -446,6 +543,11 @@ impl Docx {
         self
     }
 
+    pub fn compatibility_mode(mut self, mode: crate::types::CompatibilityMode) -> Self {
+        self.settings = self.settings.compatibility_mode(mode);
+        self
+    }
+
     pub fn add_doc_var(mut self, name: &str, val: &str) -> Self {
         self.settings = self.settings.add_doc_var(name, val);
         self
@@ -500,27 +602,69 @@ impl Docx {
         self
     }
 
-    pub fn add_custom_item(mut self, id: &str, xml: &str) -> Self {
-        let x = CustomItem::from_str(xml).expect("should parse xml string");
+    pub fn add_custom_item(self, id: &str, xml: &str) -> Self {
+        self.add_custom_xml_part(xml, CustomItemProperty::new(id))
+    }
+
+    // Add `customXml/itemN.xml` with its data store properties, whose item id content
+    // controls bind to.
+    pub fn add_custom_xml_part(self, item_xml: &str, props: CustomItemProperty) -> Self {
+        let item = CustomItem::from_str(item_xml).expect("should parse xml string");
+        self.custom_xml_part(item, props)
+    }
+
+    pub(crate) fn custom_xml_part(mut self, item: CustomItem, props: CustomItemProperty) -> Self {
         self.content_type = self.content_type.add_custom_xml();
-        let rel = CustomItemRels::new().add_item();
-        self.custom_item_props.push(CustomItemProperty::new(id));
+        let rel = CustomItemRels::new()
+            .add_item()
+            .props_offset(self.custom_items.len());
+        self.custom_item_props.push(props);
         self.document_rels = self.document_rels.add_custom_item();
         self.custom_item_rels.push(rel);
-        self.custom_items.push(x);
+        self.custom_items.push(item);
         self
     }
 
+    // The custom XML parts in the order of `customXml/item1.xml`, `customXml/item2.xml`, ...
+    pub fn custom_xml_parts(&self) -> impl Iterator<Item = (&CustomItemProperty, &CustomItem)> {
+        self.custom_item_props.iter().zip(self.custom_items.iter())
+    }
+
     pub fn page_num_type(mut self, p: PageNumType) -> Self {
         self.document = self.document.page_num_type(p);
         self
     }
 
+    // Fill the content controls of the body whose `w:tag` is `tag` with the text.
+    pub fn fill_structured_data_tag(mut self, tag: &str, text: &str) -> Self {
+        for child in &mut self.document.children {
+            match child {
+                DocumentChild::Paragraph(paragraph) => fill_tags_in_paragraph(paragraph, tag, text),
+                DocumentChild::Table(table) => fill_tags_in_table(table, tag, text),
+                DocumentChild::StructuredDataTag(sdt) => fill_tags(sdt, tag, text),
+                _ => {}
+            }
+        }
+        self
+    }
+
+    pub fn pack_into<W>(self, w: W) -> zip::result::ZipResult<()>
+    where
+        W: std::io::Write + std::io::Seek,
+    {
+        self.build().pack(w)
+    }
+
+    pub fn pack_into_stream<W>(self, w: W) -> zip::result::ZipResult<()>
+    where
+        W: std::io::Write,
+    {
+        self.build().pack_stream(w)
+    }
+
     pub fn build(mut self) -> XMLDocx {
         self.reset();
 
-        self.update_dependencies();
-
         let tocs: Vec<(usize, Box<TableOfContents>)> = self
             .document
             .children
@@ -551,26 +695,53 @@ impl Docx {
             }
         }
 
-        let (images, mut images_bufs) = self.images_in_doc();
-        let (header_images, header_images_bufs) = self.images_in_header();
-        let (footer_images, footer_images_bufs) = self.images_in_footer();
+        // Comments extended refer to the ids of the paragraphs of the comments.
+        let _para_ids = self.assign_para_ids();
+        self.update_dependencies();
+
+        let (mut images, mut images_bufs) = self.images_in_doc();
+        let (mut header_images, header_images_bufs) = self.images_in_header();
+        let (mut footer_images, footer_images_bufs) = self.images_in_footer();
 
         images_bufs.extend(header_images_bufs);
         images_bufs.extend(footer_images_bufs);
 
-        let mut header_rels = vec![HeaderRels::new(); 3];
-        for (i, images) in header_images.iter().enumerate() {
-            if let Some(h) = header_rels.get_mut(i) {
-                h.set_images(images.to_owned());
+        // Images of VML shapes, which were read with the document.
+        for id in self.shape_image_ids() {
+            if images.iter().any(|(i, _)| i == &id) {
+                continue;
             }
-        }
-        let mut footer_rels = vec![FooterRels::new(); 3];
-        for (i, images) in footer_images.iter().enumerate() {
-            if let Some(f) = footer_rels.get_mut(i) {
-                f.set_images(images.to_owned());
+            if let Some((_, _, Image(data), Png(png))) = self.images.iter().find(|i| i.0 == id) {
+                // For now only png supported
+                let buf = if png.is_empty() { data } else { png };
+                images.push((id.clone(), format!("media/{}.png", id)));
+                images_bufs.push((id, buf.clone()));
             }
         }
 
+        let replaced = dedupe_media(&mut images_bufs);
+        redirect_media(&mut images, &replaced);
+        for images in header_images.iter_mut().chain(footer_images.iter_mut()) {
+            redirect_media(images, &replaced);
+        }
+
+        let header_rels: Vec<HeaderRels> = header_images
+            .into_iter()
+            .map(|images| {
+                let mut rels = HeaderRels::new();
+                rels.set_images(images);
+                rels
+            })
+            .collect();
+        let footer_rels: Vec<FooterRels> = footer_images
+            .into_iter()
+            .map(|images| {
+                let mut rels = FooterRels::new();
+                rels.set_images(images);
+                rels
+            })
+            .collect();
+
         let web_extensions = self.web_extensions.iter().map(|ext| ext.build()).collect();
         let custom_items = self.custom_items.iter().map(|xml| xml.build()).collect();
         let custom_item_props = self.custom_item_props.iter().map(|p| p.build()).collect();
@@ -581,22 +752,119 @@ impl Docx {
             .collect();
 
         self.document_rels.images = images;
+        self.document_rels.alt_chunks = vec![];
+        for part in &self.alt_chunks {
+            self.document_rels = self
+                .document_rels
+                .clone()
+                .add_alt_chunk(&part.id, &part.path);
+            self.content_type = self
+                .content_type
+                .clone()
+                .add_content(format!("/word/{}", part.path), &part.content_type);
+        }
 
-        let headers: Vec<Vec<u8>> = self
-            .document
-            .section_property
-            .get_headers()
-            .iter()
-            .map(|h| h.build())
-            .collect();
+        self.document_rels.custom_parts = vec![];
+        for part in &self.custom_parts {
+            self.document_rels.custom_parts.push((
+                part.id.clone(),
+                part.relationship_type.clone(),
+                part.target(),
+            ));
+            self.content_type = self
+                .content_type
+                .clone()
+                .add_content(format!("/{}", part.path), &part.content_type);
+        }
 
-        let footers: Vec<Vec<u8>> = self
-            .document
-            .section_property
-            .get_footers()
-            .iter()
-            .map(|h| h.build())
-            .collect();
+        self.document_rels.charts = vec![];
+        for chart in &self.charts {
+            if let Some(target) = chart.target() {
+                self.document_rels.charts.push((chart.id.clone(), target));
+            }
+            for part in &chart.parts {
+                if let Some(content_type) = &part.content_type {
+                    self.content_type = self
+                        .content_type
+                        .clone()
+                        .add_content(format!("/{}", part.path), content_type);
+                }
+            }
+        }
+
+        self.document_rels.glossary_document = None;
+        if let Some(glossary) = &self.glossary_document {
+            self.document_rels.glossary_document = glossary
+                .path()
+                .map(|p| p.strip_prefix("word/").unwrap_or(p).to_owned());
+            for part in &glossary.parts {
+                if let Some(content_type) = &part.content_type {
+                    self.content_type = self
+                        .content_type
+                        .clone()
+                        .add_content(format!("/{}", part.path), content_type);
+                }
+            }
+        }
+
+        self.content_type = self
+            .content_type
+            .clone()
+            .add_content("/word/document.xml", self.package_type.content_type());
+        // Macros are dropped from packages which can not have them.
+        if !self.package_type.is_macro_enabled() {
+            self.vba_project = None;
+        }
+        self.document_rels.vba_project = None;
+        if let Some(project) = &self.vba_project {
+            self.document_rels.vba_project = project
+                .path()
+                .map(|p| p.strip_prefix("word/").unwrap_or(p).to_owned());
+            for part in &project.parts {
+                if let Some(content_type) = &part.content_type {
+                    self.content_type = self
+                        .content_type
+                        .clone()
+                        .add_content(format!("/{}", part.path), content_type);
+                }
+            }
+        }
+
+        let mut font_table_rels = FontTableRels::new();
+        for f in &self.font_table.embedded_fonts {
+            font_table_rels = font_table_rels.add_font(&f.id, &f.path);
+            self.content_type = self
+                .content_type
+                .clone()
+                .add_content(format!("/word/{}", f.path), f.content_type());
+        }
+
+        // Web settings are only written when they have something to keep.
+        let has_web_settings = self.web_settings != WebSettings::default();
+        self.document_rels.has_web_settings = has_web_settings;
+        if has_web_settings {
+            self.content_type = self.content_type.clone().add_content(
+                "/word/webSettings.xml",
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.webSettings+xml",
+            );
+        }
+
+        if self.app_statistics {
+            self.update_app_statistics();
+        }
+
+        // Legacy compatibility options are not defined by the strict schemas.
+        if self.conformance.is_strict() {
+            self.settings = self.settings.without_compat_options();
+        }
+
+        self.update_header_footer_ids();
+        let mut headers: Vec<Vec<u8>> = vec![];
+        let mut footers: Vec<Vec<u8>> = vec![];
+        for section in self.sections_mut() {
+            headers.extend(section.get_headers().iter().map(|h| h.build()));
+            footers.extend(section.get_footers().iter().map(|f| f.build()));
+        }
 
         // Collect footnotes
         if self.collect_footnotes() {
@@ -605,7 +873,14 @@ impl Docx {
             self.document_rels.has_footnotes = true;
         }
 
-        XMLDocx {
+        // Collect endnotes
+        let has_endnotes = self.collect_endnotes();
+        if has_endnotes {
+            self.content_type = self.content_type.add_endnotes();
+            self.document_rels.has_endnotes = true;
+        }
+
+        let xml = XMLDocx {
             content_type: self.content_type.build(),
             rels: self.rels.build(),
             doc_props: self.doc_props.build(),
@@ -617,6 +892,28 @@ impl Docx {
             footer_rels: footer_rels.into_iter().map(|r| r.build()).collect(),
             settings: self.settings.build(),
             font_table: self.font_table.build(),
+            font_table_rels: if font_table_rels.fonts.is_empty() {
+                None
+            } else {
+                Some(font_table_rels.build())
+            },
+            web_settings: if has_web_settings {
+                Some(self.web_settings.build())
+            } else {
+                None
+            },
+            web_settings_rels: self.web_settings.rels.take(),
+            theme: if self.document_rels.has_theme {
+                self.themes.first().map(|t| t.build())
+            } else {
+                None
+            },
+            fonts: self
+                .font_table
+                .embedded_fonts
+                .into_iter()
+                .map(|f| (f.path, f.data))
+                .collect(),
             numberings: self.numberings.build(),
             media: images_bufs,
             headers,
@@ -628,7 +925,45 @@ impl Docx {
             custom_items,
             custom_item_rels,
             custom_item_props,
+            alt_chunks: self
+                .alt_chunks
+                .into_iter()
+                .map(|p| (p.path, p.data))
+                .collect(),
+            custom_parts: self
+                .custom_parts
+                .into_iter()
+                .map(|p| (p.path, p.data))
+                .collect(),
+            charts: self
+                .charts
+                .into_iter()
+                .flat_map(|c| c.parts.into_iter().map(|p| (p.path, p.data)))
+                .collect(),
+            glossary: self
+                .glossary_document
+                .map(|g| g.parts.into_iter().map(|p| (p.path, p.data)).collect())
+                .unwrap_or_default(),
+            vba_project: self
+                .vba_project
+                .map(|p| p.parts.into_iter().map(|p| (p.path, p.data)).collect())
+                .unwrap_or_default(),
             footnotes: self.footnotes.build(),
+            endnotes: if has_endnotes {
+                Some(self.endnotes.build())
+            } else {
+                None
+            },
+        };
+        let xml = if self.conformance.is_strict() {
+            xml.into_strict()
+        } else {
+            xml
+        };
+        if self.pretty_xml {
+            xml.into_pretty()
+        } else {
+            xml
         }
     }
 
@@ -908,7 +1243,68 @@ impl Docx {
         }
     }
 
+    // Link read footnotes and endnotes to their references.
+    pub(crate) fn store_notes(&mut self) {
+        let Docx {
+            document,
+            footnotes,
+            endnotes,
+            ..
+        } = self;
+        for child in &mut document.children {
+            match child {
+                DocumentChild::Paragraph(paragraph) => {
+                    store_notes_in_paragraph(paragraph, footnotes, endnotes)
+                }
+                DocumentChild::Table(table) => store_notes_in_table(table, footnotes, endnotes),
+                _ => {}
+            }
+        }
+    }
+
+    // Fill the charts of drawings with the chart parts they refer to.
+    pub(crate) fn store_charts(&mut self, charts: &HashMap<String, Chart>) {
+        for child in &mut self.document.children {
+            match child {
+                DocumentChild::Paragraph(paragraph) => {
+                    store_charts_in_runs(&mut paragraph.children, charts)
+                }
+                DocumentChild::Table(table) => store_charts_in_table(table, charts),
+                _ => {}
+            }
+        }
+    }
+
+    // Resolve the relationship ids of the hyperlinks read to their targets.
+    // reader only
+    pub(crate) fn store_hyperlinks(&mut self) {
+        let hyperlinks = &self.hyperlinks;
+        for child in &mut self.document.children {
+            match child {
+                DocumentChild::Paragraph(paragraph) => {
+                    store_hyperlinks_in_paragraph(paragraph, hyperlinks)
+                }
+                DocumentChild::Table(table) => store_hyperlinks_in_table(table, hyperlinks),
+                _ => {}
+            }
+        }
+    }
+
     // Traverse and collect images from document.
+    fn shape_image_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = vec![];
+        for child in &self.document.children {
+            match child {
+                DocumentChild::Paragraph(paragraph) => {
+                    collect_shape_image_ids_from_paragraph(paragraph, &mut ids);
+                }
+                DocumentChild::Table(table) => collect_shape_image_ids_from_table(table, &mut ids),
+                _ => {}
+            }
+        }
+        ids
+    }
+
     fn images_in_doc(&mut self) -> (Vec<ImageIdAndPath>, Vec<ImageIdAndBuf>) {
         let mut images: Vec<(String, String)> = vec![];
         let mut image_bufs: Vec<(String, Vec<u8>)> = vec![];
@@ -927,290 +1323,122 @@ impl Docx {
         (images, image_bufs)
     }
 
+    // Sections in document order, those ending at paragraphs first.
+    fn sections_mut(&mut self) -> Vec<&mut SectionProperty> {
+        let Document {
+            children,
+            section_property,
+            ..
+        } = &mut self.document;
+        let mut sections: Vec<&mut SectionProperty> = children
+            .iter_mut()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => p.property.section_property.as_mut(),
+                _ => None,
+            })
+            .collect();
+        sections.push(section_property);
+        sections
+    }
+
+    // Headers and footers are numbered across sections in the order they are written.
+    fn update_header_footer_ids(&mut self) {
+        let (mut headers, mut footers) = (0, 0);
+        for s in self.sections_mut() {
+            s.update_header_footer_ids(&mut headers, &mut footers);
+        }
+        self.document_rels.header_count = headers;
+        self.document_rels.footer_count = footers;
+        while self.content_type.header_count < headers {
+            self.content_type = self.content_type.clone().add_header();
+        }
+        while self.content_type.footer_count < footers {
+            self.content_type = self.content_type.clone().add_footer();
+        }
+    }
+
     fn images_in_header(&mut self) -> (Vec<Vec<ImageIdAndPath>>, Vec<ImageIdAndBuf>) {
-        let mut header_images: Vec<Vec<ImageIdAndPath>> = vec![vec![]; 3];
+        let mut header_images: Vec<Vec<ImageIdAndPath>> = vec![];
         let mut image_bufs: Vec<(String, Vec<u8>)> = vec![];
 
-        if let Some(header) = &mut self.document.section_property.header.as_mut() {
-            let mut images: Vec<ImageIdAndPath> = vec![];
-            for child in header.children.iter_mut() {
-                match child {
-                    HeaderChild::Paragraph(paragraph) => {
-                        collect_images_from_paragraph(
-                            paragraph,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("header"),
-                        );
-                    }
-                    HeaderChild::Table(table) => {
-                        collect_images_from_table(
-                            table,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("header"),
-                        );
-                    }
-                    HeaderChild::StructuredDataTag(tag) => {
-                        for child in tag.children.iter_mut() {
-                            if let StructuredDataTagChild::Paragraph(paragraph) = child {
-                                collect_images_from_paragraph(
-                                    paragraph,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
-                            if let StructuredDataTagChild::Table(table) = child {
-                                collect_images_from_table(
-                                    table,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
+        for section in self.sections_mut() {
+            for header in section.get_headers_mut() {
+                let mut images: Vec<ImageIdAndPath> = vec![];
+                for child in header.children.iter_mut() {
+                    match child {
+                        HeaderChild::Paragraph(paragraph) => {
+                            collect_images_from_paragraph(
+                                paragraph,
+                                &mut images,
+                                &mut image_bufs,
+                                Some("header"),
+                            );
                         }
-                    }
-                }
-            }
-            header_images[0] = images;
-        }
-
-        if let Some(header) = &mut self.document.section_property.first_header.as_mut() {
-            let mut images: Vec<ImageIdAndPath> = vec![];
-            for child in header.children.iter_mut() {
-                match child {
-                    HeaderChild::Paragraph(paragraph) => {
-                        collect_images_from_paragraph(
-                            paragraph,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("header"),
-                        );
-                    }
-                    HeaderChild::Table(table) => {
-                        collect_images_from_table(
-                            table,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("header"),
-                        );
-                    }
-                    HeaderChild::StructuredDataTag(tag) => {
-                        for child in tag.children.iter_mut() {
-                            if let StructuredDataTagChild::Paragraph(paragraph) = child {
-                                collect_images_from_paragraph(
-                                    paragraph,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
-                            if let StructuredDataTagChild::Table(table) = child {
-                                collect_images_from_table(
-                                    table,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
+                        HeaderChild::Table(table) => {
+                            collect_images_from_table(
+                                table,
+                                &mut images,
+                                &mut image_bufs,
+                                Some("header"),
+                            );
                         }
-                    }
-                }
-            }
-            header_images[1] = images;
-        }
-
-        if let Some(header) = &mut self.document.section_property.even_header.as_mut() {
-            let mut images: Vec<ImageIdAndPath> = vec![];
-            for child in header.children.iter_mut() {
-                match child {
-                    HeaderChild::Paragraph(paragraph) => {
-                        collect_images_from_paragraph(
-                            paragraph,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("header"),
-                        );
-                    }
-                    HeaderChild::Table(table) => {
-                        collect_images_from_table(
-                            table,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("header"),
-                        );
-                    }
-                    HeaderChild::StructuredDataTag(tag) => {
-                        for child in tag.children.iter_mut() {
-                            if let StructuredDataTagChild::Paragraph(paragraph) = child {
-                                collect_images_from_paragraph(
-                                    paragraph,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
-                            if let StructuredDataTagChild::Table(table) = child {
-                                collect_images_from_table(
-                                    table,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
+                        HeaderChild::StructuredDataTag(tag) => {
+                            collect_images_from_structured_data_tag(
+                                tag,
+                                &mut images,
+                                &mut image_bufs,
+                                Some("header"),
+                            );
                         }
                     }
                 }
+                header_images.push(images);
             }
-            header_images[2] = images;
         }
         (header_images, image_bufs)
     }
 
-    // Traverse and collect images from header.
     fn images_in_footer(&mut self) -> (Vec<Vec<ImageIdAndPath>>, Vec<ImageIdAndBuf>) {
-        let mut footer_images: Vec<Vec<ImageIdAndPath>> = vec![vec![]; 3];
+        let mut footer_images: Vec<Vec<ImageIdAndPath>> = vec![];
         let mut image_bufs: Vec<(String, Vec<u8>)> = vec![];
 
-        if let Some(footer) = &mut self.document.section_property.footer.as_mut() {
-            let mut images: Vec<ImageIdAndPath> = vec![];
-            for child in footer.children.iter_mut() {
-                match child {
-                    FooterChild::Paragraph(paragraph) => {
-                        collect_images_from_paragraph(
-                            paragraph,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("footer"),
-                        );
-                    }
-                    FooterChild::Table(table) => {
-                        collect_images_from_table(
-                            table,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("footer"),
-                        );
-                    }
-                    FooterChild::StructuredDataTag(tag) => {
-                        for child in tag.children.iter_mut() {
-                            if let StructuredDataTagChild::Paragraph(paragraph) = child {
-                                collect_images_from_paragraph(
-                                    paragraph,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
-                            if let StructuredDataTagChild::Table(table) = child {
-                                collect_images_from_table(
-                                    table,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
+        for section in self.sections_mut() {
+            for footer in section.get_footers_mut() {
+                let mut images: Vec<ImageIdAndPath> = vec![];
+                for child in footer.children.iter_mut() {
+                    match child {
+                        FooterChild::Paragraph(paragraph) => {
+                            collect_images_from_paragraph(
+                                paragraph,
+                                &mut images,
+                                &mut image_bufs,
+                                Some("footer"),
+                            );
                         }
-                    }
-                }
-            }
-            footer_images[0] = images;
-        }
-
-        if let Some(footer) = &mut self.document.section_property.first_footer.as_mut() {
-            let mut images: Vec<ImageIdAndPath> = vec![];
-            for child in footer.children.iter_mut() {
-                match child {
-                    FooterChild::Paragraph(paragraph) => {
-                        collect_images_from_paragraph(
-                            paragraph,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("footer"),
-                        );
-                    }
-                    FooterChild::Table(table) => {
-                        collect_images_from_table(
-                            table,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("footer"),
-                        );
-                    }
-                    FooterChild::StructuredDataTag(tag) => {
-                        for child in tag.children.iter_mut() {
-                            if let StructuredDataTagChild::Paragraph(paragraph) = child {
-                                collect_images_from_paragraph(
-                                    paragraph,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
-                            if let StructuredDataTagChild::Table(table) = child {
-                                collect_images_from_table(
-                                    table,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
+                        FooterChild::Table(table) => {
+                            collect_images_from_table(
+                                table,
+                                &mut images,
+                                &mut image_bufs,
+                                Some("footer"),
+                            );
                         }
-                    }
-                }
-            }
-            footer_images[1] = images;
-        }
-
-        if let Some(footer) = &mut self.document.section_property.even_footer.as_mut() {
-            let mut images: Vec<ImageIdAndPath> = vec![];
-            for child in footer.children.iter_mut() {
-                match child {
-                    FooterChild::Paragraph(paragraph) => {
-                        collect_images_from_paragraph(
-                            paragraph,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("footer"),
-                        );
-                    }
-                    FooterChild::Table(table) => {
-                        collect_images_from_table(
-                            table,
-                            &mut images,
-                            &mut image_bufs,
-                            Some("footer"),
-                        );
-                    }
-                    FooterChild::StructuredDataTag(tag) => {
-                        for child in tag.children.iter_mut() {
-                            if let StructuredDataTagChild::Paragraph(paragraph) = child {
-                                collect_images_from_paragraph(
-                                    paragraph,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
-                            if let StructuredDataTagChild::Table(table) = child {
-                                collect_images_from_table(
-                                    table,
-                                    &mut images,
-                                    &mut image_bufs,
-                                    Some("header"),
-                                );
-                            }
+                        FooterChild::StructuredDataTag(tag) => {
+                            collect_images_from_structured_data_tag(
+                                tag,
+                                &mut images,
+                                &mut image_bufs,
+                                Some("footer"),
+                            );
                         }
                     }
                 }
+                footer_images.push(images);
             }
-            footer_images[2] = images;
         }
         (footer_images, image_bufs)
     }
 
-    /// Collect footnotes from all Runs to the docx footnotes node.
     pub fn collect_footnotes(&mut self) -> bool {
         let footnotes: Vec<Footnote> = self
             .document
@@ -1232,9 +1460,39 @@ impl Docx {
             })
             .map(Into::<Footnote>::into)
             .collect();
-        let is_footnotes = !footnotes.is_empty();
         self.footnotes.add(footnotes);
-        is_footnotes
+        !self.footnotes.footnotes.is_empty()
+    }
+
+    // Numbering level of the paragraph, from its `numPr` or its style.
+    pub fn resolve_numbering(&self, p: &Paragraph) -> Option<ResolvedNumbering> {
+        let (id, level) = paragraph_numbering(p, Some(&self.styles))?;
+        self.numberings.resolve(id, level, &self.styles)
+    }
+
+    pub fn collect_endnotes(&mut self) -> bool {
+        let endnotes: Vec<Endnote> = self
+            .document
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                DocumentChild::Paragraph(paragraph) => Some(&paragraph.children),
+                _ => None,
+            })
+            .flat_map(|children| children.iter())
+            .filter_map(|para_child| match para_child {
+                ParagraphChild::Run(run) => Some(&run.children),
+                _ => None,
+            })
+            .flat_map(|children| children.iter())
+            .filter_map(|run_child| match run_child {
+                RunChild::EndnoteReference(endnote_ref) => Some(endnote_ref),
+                _ => None,
+            })
+            .map(Into::<Endnote>::into)
+            .collect();
+        self.endnotes.add(endnotes);
+        !self.endnotes.endnotes.is_empty()
     }
 }
 
@@ -1394,6 +1652,197 @@ fn store_comments_in_paragraph(paragraph: &mut Paragraph, comments: &[Comment])
     }
 }
 
+fn store_notes_in_runs(
+    children: &mut [ParagraphChild],
+    footnotes: &Footnotes,
+    endnotes: &Endnotes,
+) {
+    for child in children {
+        match child {
+            ParagraphChild::Run(run) => {
+                for c in &mut run.children {
+                    match c {
+                        RunChild::FootnoteReference(r) => {
+                            if let Some(f) = footnotes.footnotes.iter().find(|f| f.id == r.id) {
+                                r.content = f.content.clone();
+                            }
+                        }
+                        RunChild::EndnoteReference(r) => {
+                            if let Some(e) = endnotes.endnotes.iter().find(|e| e.id == r.id) {
+                                r.content = e.content.clone();
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            ParagraphChild::Hyperlink(link) => {
+                store_notes_in_runs(&mut link.children, footnotes, endnotes)
+            }
+            ParagraphChild::ComplexField(f) => {
+                store_notes_in_runs(&mut f.cached_result, footnotes, endnotes)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn store_notes_in_paragraph(paragraph: &mut Paragraph, footnotes: &Footnotes, endnotes: &Endnotes) {
+    store_notes_in_runs(&mut paragraph.children, footnotes, endnotes);
+}
+
+fn store_notes_in_table(table: &mut Table, footnotes: &Footnotes, endnotes: &Endnotes) {
+    for TableChild::TableRow(row) in &mut table.rows {
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            for content in &mut cell.children {
+                match content {
+                    TableCellContent::Paragraph(paragraph) => {
+                        store_notes_in_paragraph(paragraph, footnotes, endnotes)
+                    }
+                    TableCellContent::Table(table) => {
+                        store_notes_in_table(table, footnotes, endnotes)
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn store_chart_in_run(run: &mut Run, charts: &HashMap<String, Chart>) {
+    for c in &mut run.children {
+        if let RunChild::Drawing(d) = c {
+            if let Some(DrawingData::Chart(chart)) = &mut d.data {
+                if let Some(part) = charts.get(&chart.id) {
+                    chart.id = part.id.clone();
+                    chart.chart_type = part.chart_type;
+                    chart.title = part.title.clone();
+                    chart.series = part.series.clone();
+                }
+            }
+        }
+    }
+}
+
+fn store_charts_in_runs(children: &mut [ParagraphChild], charts: &HashMap<String, Chart>) {
+    for child in children {
+        match child {
+            ParagraphChild::Run(run) => store_chart_in_run(run, charts),
+            ParagraphChild::Insert(ins) => {
+                for c in &mut ins.children {
+                    if let InsertChild::Run(run) = c {
+                        store_chart_in_run(run, charts);
+                    }
+                }
+            }
+            ParagraphChild::Hyperlink(link) => store_charts_in_runs(&mut link.children, charts),
+            ParagraphChild::ComplexField(f) => store_charts_in_runs(&mut f.cached_result, charts),
+            _ => {}
+        }
+    }
+}
+
+fn fill_tags(sdt: &mut StructuredDataTag, tag: &str, text: &str) {
+    if sdt.property.tag.as_deref() == Some(tag) {
+        sdt.fill_text(text);
+        return;
+    }
+    for child in &mut sdt.children {
+        match child {
+            StructuredDataTagChild::Paragraph(paragraph) => {
+                fill_tags_in_paragraph(paragraph, tag, text)
+            }
+            StructuredDataTagChild::Table(table) => fill_tags_in_table(table, tag, text),
+            StructuredDataTagChild::StructuredDataTag(sdt) => fill_tags(sdt, tag, text),
+            _ => {}
+        }
+    }
+}
+
+fn fill_tags_in_paragraph(paragraph: &mut Paragraph, tag: &str, text: &str) {
+    for child in &mut paragraph.children {
+        if let ParagraphChild::StructuredDataTag(sdt) = child {
+            fill_tags(sdt, tag, text);
+        }
+    }
+}
+
+fn fill_tags_in_table(table: &mut Table, tag: &str, text: &str) {
+    for TableChild::TableRow(row) in &mut table.rows {
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            for content in &mut cell.children {
+                match content {
+                    TableCellContent::Paragraph(paragraph) => {
+                        fill_tags_in_paragraph(paragraph, tag, text)
+                    }
+                    TableCellContent::Table(table) => fill_tags_in_table(table, tag, text),
+                    TableCellContent::StructuredDataTag(sdt) => fill_tags(sdt, tag, text),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn store_hyperlinks_in_paragraph(
+    paragraph: &mut Paragraph,
+    hyperlinks: &[(String, String, String)],
+) {
+    for child in &mut paragraph.children {
+        if let ParagraphChild::Hyperlink(h) = child {
+            if let HyperlinkData::External { rid, path } = &h.link {
+                if !path.is_empty() {
+                    continue;
+                }
+                if let Some((_, target, mode)) = hyperlinks.iter().find(|(id, ..)| id == rid) {
+                    // Internal targets such as `#_Toc1` are bookmarks of the document.
+                    h.link = match target.strip_prefix('#') {
+                        Some(anchor) if mode != "External" => HyperlinkData::Anchor {
+                            anchor: anchor.to_owned(),
+                        },
+                        _ => HyperlinkData::External {
+                            rid: rid.clone(),
+                            path: escape(target),
+                        },
+                    };
+                }
+            }
+        }
+    }
+}
+
+fn store_hyperlinks_in_table(table: &mut Table, hyperlinks: &[(String, String, String)]) {
+    for TableChild::TableRow(row) in &mut table.rows {
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            for content in &mut cell.children {
+                match content {
+                    TableCellContent::Paragraph(paragraph) => {
+                        store_hyperlinks_in_paragraph(paragraph, hyperlinks)
+                    }
+                    TableCellContent::Table(table) => store_hyperlinks_in_table(table, hyperlinks),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn store_charts_in_table(table: &mut Table, charts: &HashMap<String, Chart>) {
+    for TableChild::TableRow(row) in &mut table.rows {
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            for content in &mut cell.children {
+                match content {
+                    TableCellContent::Paragraph(paragraph) => {
+                        store_charts_in_runs(&mut paragraph.children, charts)
+                    }
+                    TableCellContent::Table(table) => store_charts_in_table(table, charts),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
 fn store_comments_in_table(table: &mut Table, comments: &[Comment]) {
     for TableChild::TableRow(row) in &mut table.rows {
         for TableRowChild::TableCell(cell) in &mut row.cells {
@@ -1451,7 +1900,10 @@ fn push_comment_and_comment_extended(
         if let CommentChild::Paragraph(child) = child {
             let para_id = child.id.clone();
             comments.push(c.get_comment());
-            let comment_extended = CommentExtended::new(para_id);
+            let mut comment_extended = CommentExtended::new(para_id);
+            if comment.done {
+                comment_extended = comment_extended.done();
+            }
             if let Some(parent_comment_id) = comment.parent_comment_id {
                 if let Some(parent_para_id) = comment_map.get(&parent_comment_id) {
                     comments_extended
@@ -1487,71 +1939,23 @@ fn update_document_by_toc(
     }
 
     let (min, max) = toc.instr.heading_styles_range.unwrap_or((0, 9));
+    let ctx = TocContext {
+        heading_map: &heading_map,
+        style_map: &style_map,
+        min,
+        max,
+    };
 
     for child in document_children.into_iter() {
         match child {
-            DocumentChild::Paragraph(mut paragraph) => {
-                if let Some(heading_level) = paragraph
-                    .property
-                    .style
-                    .as_ref()
-                    .map(|p| p.val.to_string())
-                    .and_then(|sid| heading_map.get(&sid))
-                {
-                    if min <= *heading_level && max >= *heading_level {
-                        let toc_key = TocKey::generate();
-                        items.push(
-                            TableOfContentsItem::new()
-                                .text(paragraph.raw_text())
-                                .toc_key(&toc_key)
-                                .level(*heading_level),
-                        );
-                        paragraph =
-                            Box::new(paragraph.wrap_by_bookmark(generate_bookmark_id(), &toc_key));
-                    }
-
-                    if let Some((_min, _max)) = toc.instr.tc_field_level_range {
-                        // TODO: check tc field
-                    }
-                }
-
-                // Support \t option. Collect toc items if style id matched.
-                if let Some(level) = paragraph
-                    .property
-                    .style
-                    .as_ref()
-                    .and_then(|s| style_map.get(&s.val))
-                {
-                    if min <= *level && max >= *level {
-                        let toc_key = TocKey::generate();
-                        items.push(
-                            TableOfContentsItem::new()
-                                .text(paragraph.raw_text())
-                                .toc_key(&toc_key)
-                                .level(*level),
-                        );
-                        paragraph =
-                            Box::new(paragraph.wrap_by_bookmark(generate_bookmark_id(), &toc_key));
-                    }
-                }
-
-                children.push(DocumentChild::Paragraph(paragraph));
+            DocumentChild::Paragraph(paragraph) => {
+                children.push(DocumentChild::Paragraph(Box::new(
+                    ctx.paragraph(*paragraph, &mut items),
+                )));
             }
-            DocumentChild::Table(ref _table) => {
-                // TODO:
-                // for row in &table.rows {
-                //     for cell in &row.cells {
-                //         for content in &cell.children {
-                //             match content {
-                //                 TableCellContent::Paragraph(paragraph) => {}
-                //                 TableCellContent::Table(_) => {
-                //                     // TODO: Support table in table
-                //                 }
-                //             }
-                //         }
-                //     }
-                // }
-                children.push(child);
+            DocumentChild::Table(mut table) => {
+                ctx.table(&mut table, &mut items);
+                children.push(DocumentChild::Table(table));
             }
             _ => {
                 children.push(child);
@@ -1564,3 +1968,57 @@ fn update_document_by_toc(
     children[toc_index] = DocumentChild::TableOfContents(Box::new(toc));
     children
 }
+
+struct TocContext<'a> {
+    heading_map: &'a std::collections::HashMap<String, usize>,
+    style_map: &'a std::collections::HashMap<String, usize>,
+    min: usize,
+    max: usize,
+}
+
+impl<'a> TocContext<'a> {
+    // Wrap the paragraph by a bookmark which its item links to, if it is a heading in the range
+    // or has a style of the `\t` option.
+    fn paragraph(&self, paragraph: Paragraph, items: &mut Vec<TableOfContentsItem>) -> Paragraph {
+        let style = paragraph.property.style.as_ref().map(|s| s.val.as_str());
+        // TODO: check tc field of toc.instr.tc_field_level_range
+        let level = style
+            .and_then(|s| self.heading_map.get(s))
+            .filter(|l| self.min <= **l && self.max >= **l)
+            .or_else(|| {
+                style
+                    .and_then(|s| self.style_map.get(s))
+                    .filter(|l| self.min <= **l && self.max >= **l)
+            });
+        match level {
+            Some(level) => {
+                let toc_key = TocKey::generate();
+                items.push(
+                    TableOfContentsItem::new()
+                        .text(paragraph.raw_text())
+                        .toc_key(&toc_key)
+                        .level(*level),
+                );
+                paragraph.wrap_by_bookmark(generate_bookmark_id(), &toc_key)
+            }
+            None => paragraph,
+        }
+    }
+
+    // Headings in cells, also of nested tables, are listed as Word does.
+    fn table(&self, table: &mut Table, items: &mut Vec<TableOfContentsItem>) {
+        for TableChild::TableRow(row) in &mut table.rows {
+            for TableRowChild::TableCell(cell) in &mut row.cells {
+                for content in &mut cell.children {
+                    match content {
+                        TableCellContent::Paragraph(p) => {
+                            *p = self.paragraph(std::mem::take(p), items)
+                        }
+                        TableCellContent::Table(t) => self.table(t, items),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -26,6 +26,103 @@ impl Numberings {
         self.numberings.push(n);
         self
     }
+
+    // Level `level` of the numbering instance `id`, see `find_level`.
+    pub fn resolve(&self, id: usize, level: usize, styles: &Styles) -> Option<ResolvedNumbering> {
+        let num = self.numberings.iter().find(|n| n.id == id)?;
+        let definition = self.find_level(id, level, Some(styles))?;
+        let start = num
+            .level_overrides
+            .iter()
+            .find(|o| o.level == level)
+            .and_then(|o| o.override_start)
+            .unwrap_or(definition.start.val);
+        Some(ResolvedNumbering {
+            id,
+            abstract_num_id: num.abstract_num_id,
+            level,
+            start,
+            definition: definition.clone(),
+        })
+    }
+
+    // `lvlOverride` takes precedence over the abstract numbering. Abstract numberings with
+    // `numStyleLink` use the levels of the numbering their numbering style refers to.
+    pub(crate) fn find_level(
+        &self,
+        id: usize,
+        level: usize,
+        styles: Option<&Styles>,
+    ) -> Option<&Level> {
+        let num = self.numberings.iter().find(|n| n.id == id)?;
+        if let Some(l) = num
+            .level_overrides
+            .iter()
+            .find(|o| o.level == level)
+            .and_then(|o| o.override_level.as_ref())
+        {
+            return Some(l);
+        }
+        let mut abs = self
+            .abstract_nums
+            .iter()
+            .find(|a| a.id == num.abstract_num_id)?;
+        if let Some(linked) = abs
+            .num_style_link
+            .as_ref()
+            .and_then(|link| self.style_numbering(link, styles?))
+        {
+            abs = linked;
+        }
+        abs.levels.iter().find(|l| l.level == level)
+    }
+
+    fn style_numbering(&self, style_id: &str, styles: &Styles) -> Option<&AbstractNumbering> {
+        let id = styles.numbering(style_id)?.0?;
+        let num = self.numberings.iter().find(|n| n.id == id)?;
+        self.abstract_nums
+            .iter()
+            .find(|a| a.id == num.abstract_num_id && a.num_style_link.is_none())
+    }
+}
+
+// Numbering level of a paragraph, with the definition of the level from numbering.xml.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedNumbering {
+    pub id: usize,
+    pub abstract_num_id: usize,
+    pub level: usize,
+    // Start value, `startOverride` applied.
+    pub start: usize,
+    pub definition: Level,
+}
+
+// numId and ilvl of the paragraph, from its `numPr` or else its style. numId 0 removes numbering.
+pub(crate) fn paragraph_numbering(
+    p: &Paragraph,
+    styles: Option<&Styles>,
+) -> Option<(usize, usize)> {
+    let direct = p.property.numbering_property.as_ref();
+    let style = p
+        .property
+        .style
+        .as_ref()
+        .zip(styles)
+        .and_then(|(s, styles)| styles.numbering(&s.val));
+    let id = direct
+        .and_then(|n| n.id.as_ref())
+        .map(|n| n.id)
+        .or_else(|| style.and_then(|(id, _)| id))?;
+    if id == 0 {
+        return None;
+    }
+    let level = direct
+        .and_then(|n| n.level.as_ref())
+        .map(|l| l.val)
+        .or_else(|| style.and_then(|(_, level)| level))
+        .unwrap_or(0);
+    Some((id, level))
 }
 
 impl Default for Numberings {
@@ -40,11 +137,16 @@ impl Default for Numberings {
 impl BuildXML for Numberings {
     fn build(&self) -> Vec<u8> {
         let mut b = XMLBuilder::new().declaration(Some(true)).open_numbering();
-        b = b.add_child(&create_default_numbering());
+        // Read documents may define numbering 1 themselves.
+        if !self.abstract_nums.iter().any(|n| n.id == 1) {
+            b = b.add_child(&create_default_numbering());
+        }
         for n in &self.abstract_nums {
             b = b.add_child(n);
         }
-        b = b.add_child(&Numbering::new(1, 1));
+        if !self.numberings.iter().any(|n| n.id == 1) {
+            b = b.add_child(&Numbering::new(1, 1));
+        }
         for n in &self.numberings {
             b = b.add_child(n);
         }
@@ -180,3 +282,69 @@ fn create_default_numbering() -> AbstractNumbering {
             ),
         )
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn level(level: usize, format: &str, text: &str) -> Level {
+        Level::new(
+            level,
+            Start::new(1),
+            NumberFormat::new(format),
+            LevelText::new(text),
+            LevelJc::new("left"),
+        )
+    }
+
+    #[test]
+    fn test_resolve_numbering() {
+        // Numbering 3 uses the levels of numbering 2 through the `OutlineList` numbering style.
+        let numberings = Numberings::new()
+            .add_abstract_numbering(
+                AbstractNumbering::new(2)
+                    .style_link("OutlineList")
+                    .add_level(level(0, "upperRoman", "%1."))
+                    .add_level(level(1, "lowerLetter", "%2)")),
+            )
+            .add_abstract_numbering(AbstractNumbering::new(3).num_style_link("OutlineList"))
+            .add_numbering(Numbering::new(2, 2))
+            .add_numbering(Numbering::new(3, 3).add_override(LevelOverride::new(1).start(4)));
+        let styles = Styles::new()
+            .add_style(
+                Style::new("OutlineList", StyleType::Numbering).paragraph_property(
+                    ParagraphProperty::new()
+                        .numbering_property(NumberingProperty::new().id(NumberingId::new(2))),
+                ),
+            )
+            .add_style(
+                Style::new("ListItem", StyleType::Paragraph).paragraph_property(
+                    ParagraphProperty::new().numbering(NumberingId::new(3), IndentLevel::new(1)),
+                ),
+            )
+            .add_style(Style::new("Item", StyleType::Paragraph).based_on("ListItem"));
+        let docx = Docx::new().numberings(numberings).styles(styles);
+
+        let resolved = docx
+            .resolve_numbering(&Paragraph::new().style("Item"))
+            .unwrap();
+        assert_eq!((resolved.id, resolved.level, resolved.start), (3, 1, 4));
+        assert_eq!(resolved.definition.format.val, "lowerLetter");
+        assert_eq!(resolved.definition.text.val, "%2)");
+
+        let direct = Paragraph::new()
+            .style("Item")
+            .numbering(NumberingId::new(2), IndentLevel::new(0));
+        let resolved = docx.resolve_numbering(&direct).unwrap();
+        assert_eq!((resolved.id, resolved.level, resolved.start), (2, 0, 1));
+        assert_eq!(resolved.definition.format.val, "upperRoman");
+
+        let removed = Paragraph::new()
+            .style("Item")
+            .numbering(NumberingId::new(0), IndentLevel::new(0));
+        assert_eq!(docx.resolve_numbering(&removed), None);
+    }
+}
@@ -0,0 +1,59 @@
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use super::*;
+
+impl Docx {
+    // Pack into an async sink, such as the body of a response. The package is built in memory
+    // and then written, so the sink does not need to seek.
+    pub async fn pack_async<W>(self, w: W) -> zip::result::ZipResult<()>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        self.pack_async_with_options(w, &PackOptions::default())
+            .await
+    }
+
+    pub async fn pack_async_with_options<W>(
+        self,
+        mut w: W,
+        options: &PackOptions,
+    ) -> zip::result::ZipResult<()>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut buf = vec![];
+        self.build().pack_stream_with_options(&mut buf, options)?;
+        w.write_all(&buf).await?;
+        w.flush().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_pack_async() {
+        let docx = || {
+            Docx::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+                .header(Header::new().add_paragraph(Paragraph::new()))
+        };
+        let mut packed = vec![];
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(docx().pack_async(&mut packed))
+            .unwrap();
+        let mut expected = std::io::Cursor::new(vec![]);
+        docx().pack_into(&mut expected).unwrap();
+        assert_eq!(packed, expected.into_inner());
+
+        let read = crate::read_docx(&packed).unwrap();
+        assert_eq!(read.document.children.len(), 1);
+    }
+}
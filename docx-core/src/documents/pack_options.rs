@@ -0,0 +1,134 @@
+use zip::write::FileOptions;
+use zip::CompressionMethod;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZipCompression {
+    #[default]
+    Stored,
+    // Deflate with a level from 0 to 9.
+    Deflated(u8),
+}
+
+impl ZipCompression {
+    fn file_options(&self) -> FileOptions {
+        let options = FileOptions::default().unix_permissions(0o755);
+        match self {
+            ZipCompression::Stored => options.compression_method(CompressionMethod::Stored),
+            ZipCompression::Deflated(level) => options
+                .compression_method(CompressionMethod::Deflated)
+                .compression_level(Some(i32::from(*level.min(&9)))),
+        }
+    }
+}
+
+// How `XMLDocx::pack_with_options` compresses the parts. A part given by its path overrides
+// its extension, which overrides the compression of the package, so media which are
+// compressed already such as `png` can be stored while the XML is deflated.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PackOptions {
+    pub compression: ZipCompression,
+    pub extensions: Vec<(String, ZipCompression)>,
+    pub parts: Vec<(String, ZipCompression)>,
+}
+
+impl PackOptions {
+    pub fn new() -> PackOptions {
+        Default::default()
+    }
+
+    pub fn compression(mut self, c: ZipCompression) -> Self {
+        self.compression = c;
+        self
+    }
+
+    pub fn deflate(self, level: u8) -> Self {
+        self.compression(ZipCompression::Deflated(level))
+    }
+
+    pub fn stored(self) -> Self {
+        self.compression(ZipCompression::Stored)
+    }
+
+    // Such as `png`, without the dot.
+    pub fn extension_compression(mut self, ext: impl Into<String>, c: ZipCompression) -> Self {
+        self.extensions.push((ext.into().to_ascii_lowercase(), c));
+        self
+    }
+
+    // Such as `word/document.xml`.
+    pub fn part_compression(mut self, path: impl Into<String>, c: ZipCompression) -> Self {
+        self.parts.push((path.into(), c));
+        self
+    }
+
+    pub(crate) fn compression_of(&self, path: &str) -> ZipCompression {
+        if let Some((_, c)) = self.parts.iter().rev().find(|(p, _)| p == path) {
+            return *c;
+        }
+        let ext = path
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_ascii_lowercase())
+            .unwrap_or_default();
+        if let Some((_, c)) = self.extensions.iter().rev().find(|(e, _)| e == &ext) {
+            return *c;
+        }
+        self.compression
+    }
+
+    pub(crate) fn file_options(&self, path: &str) -> FileOptions {
+        self.compression_of(path).file_options()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_compression_of() {
+        let o = PackOptions::new()
+            .deflate(6)
+            .extension_compression("PNG", ZipCompression::Stored)
+            .part_compression("word/media/logo.png", ZipCompression::Deflated(9));
+        assert_eq!(
+            o.compression_of("word/document.xml"),
+            ZipCompression::Deflated(6)
+        );
+        assert_eq!(
+            o.compression_of("word/media/rId1.png"),
+            ZipCompression::Stored
+        );
+        assert_eq!(
+            o.compression_of("word/media/logo.png"),
+            ZipCompression::Deflated(9)
+        );
+        assert_eq!(
+            PackOptions::new().compression_of("[Content_Types].xml"),
+            ZipCompression::Stored
+        );
+    }
+
+    #[test]
+    fn test_pack_with_options() {
+        let docx = crate::Docx::new().add_paragraph(
+            crate::Paragraph::new().add_run(crate::Run::new().add_text("Hello ".repeat(100))),
+        );
+        let options = PackOptions::new()
+            .deflate(9)
+            .part_compression("word/styles.xml", ZipCompression::Stored);
+        let mut buf = std::io::Cursor::new(vec![]);
+        docx.build().pack_with_options(&mut buf, &options).unwrap();
+        let mut archive = zip::ZipArchive::new(buf).unwrap();
+        assert_eq!(
+            archive.by_name("word/document.xml").unwrap().compression(),
+            CompressionMethod::Deflated
+        );
+        assert_eq!(
+            archive.by_name("word/styles.xml").unwrap().compression(),
+            CompressionMethod::Stored
+        );
+    }
+}
@@ -0,0 +1,399 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use super::*;
+
+// How `Docx::build` assigns the `w14:paraId` of the paragraphs. With any strategy other than
+// `Keep`, the ids are assigned in document order, so two builds of the same content write
+// the same ids.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ParaIdStrategy {
+    // The ids the paragraphs were made or read with.
+    #[default]
+    Keep,
+    // `00000001`, `00000002`, ...
+    Sequential,
+    // Ids of a pseudo random sequence given by the seed.
+    Seeded(u64),
+    // Called with the index of the paragraph in document order.
+    Custom(ParaIdCallback),
+}
+
+impl ParaIdStrategy {
+    pub fn custom(callback: impl Fn(usize) -> String + Send + Sync + 'static) -> ParaIdStrategy {
+        ParaIdStrategy::Custom(ParaIdCallback(Arc::new(callback)))
+    }
+}
+
+#[derive(Clone)]
+pub struct ParaIdCallback(Arc<dyn Fn(usize) -> String + Send + Sync>);
+
+impl fmt::Debug for ParaIdCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ParaIdCallback")
+    }
+}
+
+impl PartialEq for ParaIdCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+struct ParaIdGenerator {
+    strategy: ParaIdStrategy,
+    count: usize,
+    state: u64,
+    used: HashSet<u32>,
+}
+
+thread_local! {
+    // The generator of the build in progress on this thread, which gives the ids of the
+    // paragraphs made while building, such as those of tables of contents.
+    static BUILD_PARA_IDS: RefCell<Option<ParaIdGenerator>> = RefCell::new(None);
+}
+
+// The id of a paragraph made while building with a strategy other than `Keep`.
+pub(crate) fn next_build_para_id() -> Option<String> {
+    BUILD_PARA_IDS.with(|g| g.borrow_mut().as_mut().map(|g| g.next()))
+}
+
+// Ends the build whose generator gives the ids of the paragraphs made while building.
+pub(crate) struct BuildParaIds;
+
+impl Drop for BuildParaIds {
+    fn drop(&mut self) {
+        BUILD_PARA_IDS.with(|g| g.borrow_mut().take());
+    }
+}
+
+impl ParaIdGenerator {
+    fn new(strategy: ParaIdStrategy) -> ParaIdGenerator {
+        let state = match strategy {
+            ParaIdStrategy::Seeded(seed) => seed,
+            _ => 0,
+        };
+        ParaIdGenerator {
+            strategy,
+            count: 0,
+            state,
+            used: HashSet::new(),
+        }
+    }
+
+    fn next(&mut self) -> String {
+        let index = self.count;
+        self.count += 1;
+        match &self.strategy {
+            ParaIdStrategy::Custom(f) => (f.0)(index),
+            ParaIdStrategy::Seeded(_) => loop {
+                // Ids are below 0x80000000 and not 0.
+                let id = (self.next_random() as u32) & 0x7fff_ffff;
+                if id != 0 && self.used.insert(id) {
+                    break format!("{:08x}", id);
+                }
+            },
+            _ => format!("{:08x}", index + 1),
+        }
+    }
+
+    // splitmix64
+    fn next_random(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl Docx {
+    pub fn para_ids(mut self, strategy: ParaIdStrategy) -> Docx {
+        self.para_id_strategy = strategy;
+        self
+    }
+
+    // The body first, then the headers and footers of the sections. Comments and notes are
+    // numbered where they are referenced. The paragraphs made until the returned value is
+    // dropped continue the sequence.
+    pub(crate) fn assign_para_ids(&mut self) -> Option<BuildParaIds> {
+        if self.para_id_strategy == ParaIdStrategy::Keep {
+            return None;
+        }
+        let mut g = ParaIdGenerator::new(self.para_id_strategy.clone());
+        for child in &mut self.document.children {
+            match child {
+                DocumentChild::Paragraph(p) => assign_in_paragraph(p, &mut g),
+                DocumentChild::Table(t) => assign_in_table(t, &mut g),
+                DocumentChild::StructuredDataTag(t) => assign_in_structured_data_tag(t, &mut g),
+                DocumentChild::TableOfContents(t) => assign_in_toc(t, &mut g),
+                DocumentChild::CommentStart(c) => assign_in_comment(&mut c.comment, &mut g),
+                _ => {}
+            }
+        }
+        for section in self.sections_mut() {
+            for header in section.get_headers_mut() {
+                for child in &mut header.children {
+                    match child {
+                        HeaderChild::Paragraph(p) => assign_in_paragraph(p, &mut g),
+                        HeaderChild::Table(t) => assign_in_table(t, &mut g),
+                        HeaderChild::StructuredDataTag(t) => {
+                            assign_in_structured_data_tag(t, &mut g)
+                        }
+                    }
+                }
+            }
+            for footer in section.get_footers_mut() {
+                for child in &mut footer.children {
+                    match child {
+                        FooterChild::Paragraph(p) => assign_in_paragraph(p, &mut g),
+                        FooterChild::Table(t) => assign_in_table(t, &mut g),
+                        FooterChild::StructuredDataTag(t) => {
+                            assign_in_structured_data_tag(t, &mut g)
+                        }
+                    }
+                }
+            }
+        }
+        BUILD_PARA_IDS.with(|b| *b.borrow_mut() = Some(g));
+        Some(BuildParaIds)
+    }
+}
+
+fn assign_in_paragraph(p: &mut Paragraph, g: &mut ParaIdGenerator) {
+    p.id = g.next();
+    assign_in_paragraph_children(&mut p.children, g);
+}
+
+fn assign_in_paragraph_children(children: &mut [ParagraphChild], g: &mut ParaIdGenerator) {
+    for child in children {
+        match child {
+            ParagraphChild::Run(r) => assign_in_run(r, g),
+            ParagraphChild::Insert(i) => {
+                for child in &mut i.children {
+                    match child {
+                        InsertChild::Run(r) => assign_in_run(r, g),
+                        InsertChild::Delete(d) => assign_in_delete(d, g),
+                        InsertChild::CommentStart(c) => assign_in_comment(&mut c.comment, g),
+                        InsertChild::CommentEnd(_) => {}
+                    }
+                }
+            }
+            ParagraphChild::Delete(d) => assign_in_delete(d, g),
+            ParagraphChild::Hyperlink(h) => assign_in_paragraph_children(&mut h.children, g),
+            ParagraphChild::CommentStart(c) => assign_in_comment(&mut c.comment, g),
+            ParagraphChild::StructuredDataTag(t) => assign_in_structured_data_tag(t, g),
+            ParagraphChild::ComplexField(f) => {
+                assign_in_paragraph_children(&mut f.cached_result, g)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn assign_in_delete(d: &mut Delete, g: &mut ParaIdGenerator) {
+    for child in &mut d.children {
+        match child {
+            DeleteChild::Run(r) => assign_in_run(r, g),
+            DeleteChild::CommentStart(c) => assign_in_comment(&mut c.comment, g),
+            DeleteChild::CommentEnd(_) => {}
+        }
+    }
+}
+
+fn assign_in_run(r: &mut Run, g: &mut ParaIdGenerator) {
+    for child in &mut r.children {
+        match child {
+            RunChild::CommentStart(c) => assign_in_comment(&mut c.comment, g),
+            RunChild::FootnoteReference(f) => {
+                for c in &mut f.content {
+                    match c {
+                        FootnoteChild::Paragraph(p) => assign_in_paragraph(p, g),
+                        FootnoteChild::Table(t) => assign_in_table(t, g),
+                    }
+                }
+            }
+            RunChild::EndnoteReference(e) => {
+                for c in &mut e.content {
+                    match c {
+                        EndnoteChild::Paragraph(p) => assign_in_paragraph(p, g),
+                        EndnoteChild::Table(t) => assign_in_table(t, g),
+                    }
+                }
+            }
+            RunChild::Drawing(d) => {
+                if let Some(DrawingData::TextBox(t)) = &mut d.data {
+                    assign_in_text_box(&mut t.children, g);
+                }
+            }
+            RunChild::Shape(s) => assign_in_shape(s, g),
+            _ => {}
+        }
+    }
+}
+
+fn assign_in_shape(s: &mut Shape, g: &mut ParaIdGenerator) {
+    if let Some(t) = &mut s.text_box {
+        assign_in_text_box(&mut t.children, g);
+    }
+    for s in &mut s.children {
+        assign_in_shape(s, g);
+    }
+}
+
+fn assign_in_text_box(children: &mut [TextBoxContentChild], g: &mut ParaIdGenerator) {
+    for child in children {
+        match child {
+            TextBoxContentChild::Paragraph(p) => assign_in_paragraph(p, g),
+            TextBoxContentChild::Table(t) => assign_in_table(t, g),
+        }
+    }
+}
+
+fn assign_in_comment(c: &mut Comment, g: &mut ParaIdGenerator) {
+    for child in &mut c.children {
+        match child {
+            CommentChild::Paragraph(p) => assign_in_paragraph(p, g),
+            CommentChild::Table(t) => assign_in_table(t, g),
+        }
+    }
+}
+
+fn assign_in_table(t: &mut Table, g: &mut ParaIdGenerator) {
+    for TableChild::TableRow(row) in &mut t.rows {
+        for TableRowChild::TableCell(cell) in &mut row.cells {
+            // The paragraph a cell has to end with is added here, so it is given an id too.
+            if matches!(
+                cell.children.last(),
+                None | Some(TableCellContent::Table(_))
+            ) {
+                cell.children
+                    .push(TableCellContent::Paragraph(Paragraph::new()));
+            }
+            for content in &mut cell.children {
+                match content {
+                    TableCellContent::Paragraph(p) => assign_in_paragraph(p, g),
+                    TableCellContent::Table(t) => assign_in_table(t, g),
+                    TableCellContent::StructuredDataTag(t) => assign_in_structured_data_tag(t, g),
+                    TableCellContent::TableOfContents(t) => assign_in_toc(t, g),
+                }
+            }
+        }
+    }
+}
+
+fn assign_in_structured_data_tag(t: &mut StructuredDataTag, g: &mut ParaIdGenerator) {
+    for child in &mut t.children {
+        match child {
+            StructuredDataTagChild::Run(r) => assign_in_run(r, g),
+            StructuredDataTagChild::Paragraph(p) => assign_in_paragraph(p, g),
+            StructuredDataTagChild::Table(t) => assign_in_table(t, g),
+            StructuredDataTagChild::CommentStart(c) => assign_in_comment(&mut c.comment, g),
+            StructuredDataTagChild::StructuredDataTag(t) => assign_in_structured_data_tag(t, g),
+            _ => {}
+        }
+    }
+}
+
+fn assign_in_toc(t: &mut TableOfContents, g: &mut ParaIdGenerator) {
+    for child in t
+        .before_contents
+        .iter_mut()
+        .chain(t.after_contents.iter_mut())
+    {
+        match child {
+            TocContent::Paragraph(p) => assign_in_paragraph(p, g),
+            TocContent::Table(t) => assign_in_table(t, g),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn ids(docx: &Docx) -> Vec<String> {
+        docx.document
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(p.id.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn docx(strategy: ParaIdStrategy) -> Docx {
+        let mut docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+            .add_paragraph(Paragraph::new().add_comment_start(
+                Comment::new(1).add_paragraph(Paragraph::new().add_run(Run::new())),
+            ))
+            .para_ids(strategy);
+        docx.assign_para_ids();
+        docx
+    }
+
+    #[test]
+    fn test_sequential_para_ids() {
+        let docx = docx(ParaIdStrategy::Sequential);
+        assert_eq!(ids(&docx), vec!["00000001", "00000002"]);
+        let comment_para_id = match &docx.document.children[1] {
+            DocumentChild::Paragraph(p) => match &p.children[0] {
+                ParagraphChild::CommentStart(c) => match &c.comment.children[0] {
+                    CommentChild::Paragraph(p) => p.id.clone(),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+        assert_eq!(comment_para_id, "00000003");
+    }
+
+    #[test]
+    fn test_seeded_para_ids() {
+        let a = ids(&docx(ParaIdStrategy::Seeded(42)));
+        assert_eq!(a, ids(&docx(ParaIdStrategy::Seeded(42))));
+        assert_ne!(a, ids(&docx(ParaIdStrategy::Seeded(7))));
+        assert!(a.iter().all(|id| id.len() == 8 && id.as_str() < "80000000"));
+    }
+
+    #[test]
+    fn test_custom_para_ids() {
+        let docx = docx(ParaIdStrategy::custom(|i| format!("{:08X}", 0x100 + i)));
+        assert_eq!(ids(&docx), vec!["00000100", "00000101"]);
+    }
+
+    #[test]
+    fn test_para_ids_of_paragraphs_made_while_building() {
+        for strategy in [ParaIdStrategy::Sequential, ParaIdStrategy::Seeded(42)] {
+            let docx = Docx::new()
+                .add_table_of_contents(
+                    TableOfContents::new()
+                        .heading_styles_range(1, 3)
+                        .add_item(TableOfContentsItem::new().text("One").level(1))
+                        .add_item(TableOfContentsItem::new().text("Two").level(1)),
+                )
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+                .para_ids(strategy)
+                .build();
+            let document = String::from_utf8(docx.document).unwrap();
+            let ids: Vec<&str> = document
+                .split(r#"w14:paraId=""#)
+                .skip(1)
+                .map(|s| &s[..8])
+                .collect();
+            let unique: HashSet<&str> = ids.iter().copied().collect();
+            assert!(ids.len() > 3);
+            assert_eq!(unique.len(), ids.len());
+        }
+        // The ids of the build are not given once it is done.
+        assert_eq!(Paragraph::new().id, "12345678");
+    }
+}
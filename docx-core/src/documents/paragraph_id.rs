@@ -7,6 +7,9 @@ static PARA_ID: AtomicUsize = AtomicUsize::new(1);
 pub fn generate_para_id() -> String {
     use std::sync::atomic::Ordering;
 
+    if let Some(id) = super::next_build_para_id() {
+        return id;
+    }
     let id = PARA_ID.load(Ordering::Relaxed);
     PARA_ID.store(id.wrapping_add(1), Ordering::Relaxed);
     format!("{:08x}", id)
@@ -22,7 +25,7 @@ pub fn reset_para_id() {
 
 #[cfg(test)]
 pub fn generate_para_id() -> String {
-    "12345678".to_owned()
+    super::next_build_para_id().unwrap_or_else(|| "12345678".to_owned())
 }
 
 #[cfg(test)]
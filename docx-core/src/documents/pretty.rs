@@ -0,0 +1,92 @@
+use xml::reader::ParserConfig;
+use xml::writer::EmitterConfig;
+
+use super::*;
+
+impl Docx {
+    // Write the XML parts indented and one element per line, so that generated documents can be
+    // compared. Text is kept as it is, so the document reads the same.
+    pub fn pretty_xml(mut self) -> Docx {
+        self.pretty_xml = true;
+        self
+    }
+}
+
+impl XMLDocx {
+    pub(crate) fn into_pretty(self) -> XMLDocx {
+        self.map_xml_parts(to_pretty)
+    }
+}
+
+pub(crate) fn to_pretty(xml: &[u8]) -> Vec<u8> {
+    pretty(xml).unwrap_or_else(|| xml.to_vec())
+}
+
+fn pretty(xml: &[u8]) -> Option<Vec<u8>> {
+    let reader = ParserConfig::new()
+        .whitespace_to_characters(true)
+        .cdata_to_characters(true)
+        .create_reader(xml);
+    let mut writer = EmitterConfig::new()
+        .perform_indent(true)
+        .write_document_declaration(false)
+        .create_writer(vec![]);
+    for e in reader {
+        let e = e.ok()?;
+        if let Some(e) = e.as_writer_event() {
+            writer.write(e).ok()?;
+        }
+    }
+    Some(writer.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_pretty() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:p xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:r><w:rPr><w:b /></w:rPr><w:t xml:space="preserve"> </w:t><w:t>Hello</w:t></w:r></w:p>"#;
+        assert_eq!(
+            str::from_utf8(&to_pretty(xml.as_bytes())).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:p xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:r>
+    <w:rPr>
+      <w:b />
+    </w:rPr>
+    <w:t xml:space="preserve"> </w:t>
+    <w:t>Hello</w:t>
+  </w:r>
+</w:p>"#
+        );
+    }
+
+    #[test]
+    fn test_build_pretty() {
+        let docx = || {
+            Docx::new().add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Hello "))
+                    .add_run(Run::new().add_text("World").bold()),
+            )
+        };
+        let xml = docx().pretty_xml().build();
+        let document = str::from_utf8(&xml.document).unwrap();
+        assert_eq!(document.matches("xmlns:w=").count(), 1);
+        assert!(document.contains("\n      <w:r>\n"));
+
+        let mut buf = std::io::Cursor::new(vec![]);
+        xml.pack(&mut buf).unwrap();
+        let read = crate::read_docx(&buf.into_inner()).unwrap();
+        let text = match &read.document.children[0] {
+            DocumentChild::Paragraph(p) => p.raw_text(),
+            _ => unreachable!(),
+        };
+        assert_eq!(text, "Hello World");
+    }
+}
@@ -0,0 +1,301 @@
+use super::table::{render_grid, GridCell};
+use super::*;
+
+// Terminal output with SGR escape sequences, e.g. `\x1b[1mbold\x1b[0m`.
+pub trait RenderAnsi {
+    fn render_ansi(&self, ctx: &mut RenderContext) -> String;
+}
+
+const RESET: &str = "\x1b[0m";
+
+// Apply the codes line by line so that table borders and padding stay unstyled.
+// Resets of styled children are followed by the codes again, so nesting is kept.
+fn styled(text: &str, codes: &[String]) -> String {
+    if codes.is_empty() {
+        return text.to_owned();
+    }
+    let prefix = format!("\x1b[{}m", codes.join(";"));
+    let restore = format!("{}{}", RESET, prefix);
+    text.split('\n')
+        .map(|l| {
+            if l.is_empty() {
+                l.to_owned()
+            } else {
+                format!("{}{}{}", prefix, l.replace(RESET, &restore), RESET)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// 24-bit foreground for `w:color` values such as `FF0000`.
+fn color_code(val: &str) -> Option<String> {
+    if val.len() != 6 {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(val.get(i..i + 2)?, 16).ok();
+    Some(format!(
+        "38;2;{};{};{}",
+        channel(0)?,
+        channel(2)?,
+        channel(4)?
+    ))
+}
+
+// Background for the named `w:highlight` colors.
+fn highlight_code(val: &str) -> Option<String> {
+    let code = match val {
+        "black" => 40,
+        "darkRed" => 41,
+        "darkGreen" => 42,
+        "darkYellow" => 43,
+        "darkBlue" => 44,
+        "darkMagenta" => 45,
+        "darkCyan" => 46,
+        "lightGray" => 47,
+        "darkGray" => 100,
+        "red" => 101,
+        "green" => 102,
+        "yellow" => 103,
+        "blue" => 104,
+        "magenta" => 105,
+        "cyan" => 106,
+        "white" => 107,
+        _ => return None,
+    };
+    Some(code.to_string())
+}
+
+fn run_codes(p: &RunProperty, ctx: &RenderContext) -> Vec<String> {
+    let mut codes = vec![];
+    if p.bold.as_ref().map(|b| b.val).unwrap_or_default() {
+        codes.push("1".to_owned());
+    }
+    if p.italic.as_ref().map(|i| i.val).unwrap_or_default() {
+        codes.push("3".to_owned());
+    }
+    if p.underline
+        .as_ref()
+        .map(|u| u.val != "none")
+        .unwrap_or_default()
+    {
+        codes.push("4".to_owned());
+    }
+    if p.strike.as_ref().map(|s| s.val).unwrap_or_default() {
+        codes.push("9".to_owned());
+    }
+    codes.extend(p.color.as_ref().and_then(|c| color_code(&ctx.color(c))));
+    codes.extend(p.highlight.as_ref().and_then(|h| highlight_code(&h.val)));
+    codes
+}
+
+fn render_paragraph_children_ansi(children: &[ParagraphChild], ctx: &mut RenderContext) -> String {
+    let mut s = String::new();
+    for c in children {
+        match c {
+            ParagraphChild::Run(r) => s.push_str(&r.render_ansi(ctx)),
+            ParagraphChild::Insert(i)
+                if ctx.options.track_changes != TrackChangesDisplay::Original =>
+            {
+                s.push_str(&i.render_ansi(ctx))
+            }
+            ParagraphChild::Delete(d)
+                if ctx.options.track_changes != TrackChangesDisplay::Final =>
+            {
+                s.push_str(&d.render_ansi(ctx))
+            }
+            ParagraphChild::Hyperlink(h) => s.push_str(&h.render_ansi(ctx)),
+            ParagraphChild::ComplexField(f) => {
+                s.push_str(&render_paragraph_children_ansi(&f.cached_result, ctx))
+            }
+            ParagraphChild::OfficeMath(m) => s.push_str(&m.to_unicode()),
+            _ => {}
+        }
+    }
+    s
+}
+
+impl RenderAnsi for Document {
+    fn render_ansi(&self, ctx: &mut RenderContext) -> String {
+        let blocks: Vec<String> = self
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(p.render_ansi(ctx)),
+                DocumentChild::Table(t) => Some(t.render_ansi(ctx)),
+                _ => None,
+            })
+            .collect();
+        blocks.join("\n")
+    }
+}
+
+impl RenderAnsi for Paragraph {
+    fn render_ansi(&self, ctx: &mut RenderContext) -> String {
+        let marker = ctx.list_marker(self);
+        let mut text = render_paragraph_children_ansi(&self.children, ctx);
+        // Headings are emphasized as a whole.
+        if ctx.heading_level(self).is_some() {
+            text = styled(&text, &["1".to_owned()]);
+        }
+        match marker {
+            Some(marker) => format!("{}{}", marker, text),
+            None => text,
+        }
+    }
+}
+
+impl RenderAnsi for Run {
+    fn render_ansi(&self, ctx: &mut RenderContext) -> String {
+        if self.run_property.vanish.is_some() && !ctx.options.include_hidden {
+            return String::new();
+        }
+        let mut text = String::new();
+        for c in &self.children {
+            match c {
+                RunChild::Text(t) => text.push_str(&replace_escaped(&t.text)),
+                RunChild::DeleteText(t) => text.push_str(&replace_escaped(&t.text)),
+                RunChild::Tab(_) => text.push('\t'),
+                RunChild::Break(_) => text.push('\n'),
+                _ => {}
+            }
+        }
+        styled(&text, &run_codes(&self.run_property, ctx))
+    }
+}
+
+impl RenderAnsi for Hyperlink {
+    fn render_ansi(&self, ctx: &mut RenderContext) -> String {
+        let content = render_paragraph_children_ansi(&self.children, ctx);
+        match ctx.hyperlink_url(&self.link) {
+            // Anchors point inside the document, so only show external targets.
+            Some(url) if !url.starts_with('#') => {
+                format!(
+                    "{} {}",
+                    content,
+                    styled(&format!("({})", url), &["2".to_owned()])
+                )
+            }
+            _ => content,
+        }
+    }
+}
+
+impl RenderAnsi for Insert {
+    fn render_ansi(&self, ctx: &mut RenderContext) -> String {
+        let mut s = String::new();
+        for c in &self.children {
+            if let InsertChild::Run(r) = c {
+                s.push_str(&r.render_ansi(ctx));
+            }
+        }
+        if ctx.options.track_changes == TrackChangesDisplay::Markup {
+            // Green for insertions.
+            s = styled(&s, &["32".to_owned()]);
+        }
+        s
+    }
+}
+
+impl RenderAnsi for Delete {
+    fn render_ansi(&self, ctx: &mut RenderContext) -> String {
+        let mut s = String::new();
+        for c in &self.children {
+            if let DeleteChild::Run(r) = c {
+                s.push_str(&r.render_ansi(ctx));
+            }
+        }
+        if ctx.options.track_changes == TrackChangesDisplay::Markup {
+            // Red strikethrough for deletions.
+            s = styled(&s, &["9".to_owned(), "31".to_owned()]);
+        }
+        s
+    }
+}
+
+impl RenderAnsi for Table {
+    fn render_ansi(&self, ctx: &mut RenderContext) -> String {
+        let rows: Vec<Vec<GridCell>> = self
+            .rows
+            .iter()
+            .map(|TableChild::TableRow(row)| {
+                row.cells
+                    .iter()
+                    .map(|TableRowChild::TableCell(cell)| {
+                        GridCell::new(cell, cell.render_ansi(ctx))
+                    })
+                    .collect()
+            })
+            .collect();
+        if rows.is_empty() {
+            return String::new();
+        }
+        render_grid(&rows, ctx.options.table_border).0
+    }
+}
+
+impl RenderAnsi for TableCell {
+    fn render_ansi(&self, ctx: &mut RenderContext) -> String {
+        let blocks: Vec<String> = self
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                TableCellContent::Paragraph(p) => Some(p.render_ansi(ctx)),
+                TableCellContent::Table(t) => Some(t.render_ansi(ctx)),
+                _ => None,
+            })
+            .collect();
+        blocks.join("\n").trim_end_matches('\n').to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_run_ansi() {
+        let r = Run::new()
+            .add_text("warn")
+            .bold()
+            .underline("single")
+            .color("FF8000")
+            .highlight("yellow");
+        assert_eq!(
+            r.render_ansi(&mut RenderContext::new()),
+            "\x1b[1;4;38;2;255;128;0;103mwarn\x1b[0m"
+        );
+        let r = Run::new().add_text("plain").color("auto");
+        assert_eq!(r.render_ansi(&mut RenderContext::new()), "plain");
+    }
+
+    #[test]
+    fn test_nested_ansi() {
+        assert_eq!(
+            styled("a\x1b[3mb\x1b[0mc", &["1".to_owned()]),
+            "\x1b[1ma\x1b[3mb\x1b[0m\x1b[1mc\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_docx_ansi() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("a "))
+                    .add_run(Run::new().add_text("b").italic()),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![
+                TableCell::new()
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text("x").bold())),
+                TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("yz"))),
+            ])]));
+        assert_eq!(
+            docx.render_ansi(),
+            "a \x1b[3mb\x1b[0m\n+---+----+\n| \x1b[1mx\x1b[0m | yz |\n+---+----+"
+        );
+    }
+}
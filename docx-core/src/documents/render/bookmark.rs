@@ -0,0 +1,73 @@
+use super::*;
+
+// Bookmarks are the targets of internal hyperlinks such as `#_Toc1`. They have no text
+// unless `bookmark_anchors` is set.
+impl Render for BookmarkStart {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let text = if ctx.options.bookmark_anchors {
+            format!("⟦#{}⟧", self.name)
+        } else {
+            String::new()
+        };
+        let mut render = JsonRender::new(RenderNodeType::Bookmark).text(text);
+        render.properties = serde_json::json!({ "id": self.id, "name": self.name });
+        render
+    }
+}
+
+// Add the character offset of bookmarks in the paragraph content, before list markers.
+pub(crate) fn set_bookmark_positions(children: &mut [JsonRender], offset: &mut usize) {
+    for c in children {
+        if c.node_type == RenderNodeType::Bookmark {
+            set_property(&mut c.properties, "position", (*offset).into());
+            *offset += c.text.chars().count();
+        } else if c.node_type != RenderNodeType::Run && !c.children.is_empty() {
+            set_bookmark_positions(&mut c.children, offset);
+        } else {
+            *offset += c.text.chars().count();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_render_bookmarks() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("See "))
+                    .add_hyperlink(
+                        Hyperlink::new("intro", HyperlinkType::Anchor)
+                            .add_run(Run::new().add_text("intro")),
+                    ),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("The "))
+                    .add_bookmark_start(1, "intro")
+                    .add_run(Run::new().add_text("intro"))
+                    .add_bookmark_end(1),
+            );
+        let r = docx.render_ascii_json();
+        assert_eq!(r.text, "See intro\nThe intro");
+        assert_eq!(
+            r.children[0].children[1].properties,
+            serde_json::json!({ "url": "#intro" })
+        );
+        let bookmark = &r.children[1].children[1];
+        assert_eq!(bookmark.node_type, RenderNodeType::Bookmark);
+        assert_eq!(
+            bookmark.properties,
+            serde_json::json!({ "id": 1, "name": "intro", "position": 4 })
+        );
+        let r = docx.render_with(&RenderOptions::new().bookmark_anchors(true));
+        assert_eq!(r.text, "See intro\nThe ⟦#intro⟧intro");
+    }
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+impl Render for CommentRangeStart {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let children: Vec<JsonRender> = self
+            .comment
+            .children
+            .iter()
+            .map(|c| match c {
+                CommentChild::Paragraph(p) => p.render_ascii_json(ctx),
+                CommentChild::Table(t) => t.render_ascii_json(ctx),
+            })
+            .collect();
+        // Keep the marker on one line so that it doesn't break the surrounding paragraph.
+        let body = children
+            .iter()
+            .map(|c| c.text.replace('\n', " "))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut render = JsonRender::new(RenderNodeType::Comment)
+            .text(format!("⟦comment #{}: {}⟧", self.id, body.trim()))
+            .children(children);
+        render.properties = serde_json::json!({
+            "id": self.id,
+            "author": self.comment.author,
+            "date": self.comment.date,
+            "parentCommentId": self.comment.parent_comment_id,
+        });
+        render
+    }
+}
+
+impl Render for CommentRangeEnd {
+    fn render_ascii_json(&self, _ctx: &mut RenderContext) -> JsonRender {
+        let mut render =
+            JsonRender::new(RenderNodeType::CommentEnd).text(format!("⟦/comment #{}⟧", self.id));
+        render.properties = serde_json::json!({ "id": self.id });
+        render
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_render_comment_markers() {
+        let p = Paragraph::new()
+            .add_run(Run::new().add_text("Hello "))
+            .add_comment_start(
+                Comment::new(3)
+                    .author("bokuweb")
+                    .date("2024-01-01T00:00:00Z")
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Typo?"))),
+            )
+            .add_run(Run::new().add_text("wrold"))
+            .add_comment_end(3);
+        assert_eq!(p.render_ascii(), "Hello wrold");
+
+        let r = p.render_with(&RenderOptions::new().include_comments(true));
+        assert_eq!(r.text, "Hello ⟦comment #3: Typo?⟧wrold⟦/comment #3⟧");
+        assert_eq!(
+            r.children[1].properties,
+            serde_json::json!({
+                "id": 3,
+                "author": "bokuweb",
+                "date": "2024-01-01T00:00:00Z",
+                "parentCommentId": null,
+            })
+        );
+    }
+}
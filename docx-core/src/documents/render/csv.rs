@@ -0,0 +1,112 @@
+use super::*;
+use crate::types::VMergeType;
+
+// RFC 4180 field. Quote only when needed to keep simple values readable.
+fn csv_field(value: &str) -> String {
+    if value.contains(|c| matches!(c, ',' | '"' | '\n' | '\r')) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+impl Table {
+    pub fn render_csv(&self) -> String {
+        self.render_csv_with(&mut RenderContext::new())
+    }
+
+    // Spanned and vertically merged cells are emitted as empty fields so that
+    // every record has the same number of columns.
+    pub(crate) fn render_csv_with(&self, ctx: &mut RenderContext) -> String {
+        let mut records: Vec<Vec<String>> = vec![];
+        for TableChild::TableRow(row) in &self.rows {
+            let mut fields = vec![];
+            for TableRowChild::TableCell(cell) in &row.cells {
+                let is_merged = cell
+                    .property
+                    .vertical_merge
+                    .as_ref()
+                    .map(|v| v.val == VMergeType::Continue)
+                    .unwrap_or_default();
+                if is_merged {
+                    fields.push(String::new());
+                } else {
+                    fields.push(cell.render_ascii_json(ctx).text);
+                }
+                let span = cell.property.grid_span.as_ref().map(|s| s.val).unwrap_or(1);
+                for _ in 1..span {
+                    fields.push(String::new());
+                }
+            }
+            records.push(fields);
+        }
+        let columns = records.iter().map(|r| r.len()).max().unwrap_or_default();
+        records
+            .iter()
+            .map(|r| {
+                (0..columns)
+                    .map(|i| csv_field(r.get(i).map(|f| f.as_str()).unwrap_or("")))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Docx {
+    // CSV of each top level table in document order.
+    pub fn render_tables_csv(&self) -> Vec<String> {
+        let mut ctx = self.render_context();
+        self.document
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Table(t) => Some(t.render_csv_with(&mut ctx)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn cell(text: &str) -> TableCell {
+        TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text(text)))
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let t = Table::new(vec![
+            TableRow::new(vec![cell("name"), cell("note"), cell("qty")]),
+            TableRow::new(vec![
+                cell("apple").vertical_merge(VMergeType::Restart),
+                cell("say \"hi\", ok"),
+                cell("1").add_paragraph(Paragraph::new().add_run(Run::new().add_text("2"))),
+            ]),
+            TableRow::new(vec![
+                cell("").vertical_merge(VMergeType::Continue),
+                cell("spanned").grid_span(2),
+            ]),
+        ]);
+        assert_eq!(
+            t.render_csv(),
+            "name,note,qty\napple,\"say \"\"hi\"\", ok\",\"1\n2\"\n,spanned,"
+        );
+    }
+
+    #[test]
+    fn test_render_tables_csv() {
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("intro")))
+            .add_table(Table::new(vec![TableRow::new(vec![cell("a"), cell("b")])]))
+            .add_table(Table::new(vec![TableRow::new(vec![cell("c")])]));
+        assert_eq!(docx.render_tables_csv(), vec!["a,b", "c"]);
+    }
+}
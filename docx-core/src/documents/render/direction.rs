@@ -0,0 +1,89 @@
+use crate::types::TextDirectionType;
+
+// Right-to-left text in visual order, line by line.
+pub(crate) fn reverse_lines(text: &str) -> String {
+    text.split('\n')
+        .map(|l| l.chars().rev().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Lay the lines of a cell out as columns, one character per row. Lines read top to bottom
+// with the first line on the right, or bottom to top with the first line on the left.
+pub(crate) fn transpose_lines(text: &str, bottom_to_top: bool) -> String {
+    let mut columns: Vec<Vec<char>> = text.split('\n').map(|l| l.chars().collect()).collect();
+    let height = columns.iter().map(|c| c.len()).max().unwrap_or(0);
+    if bottom_to_top {
+        for c in columns.iter_mut() {
+            c.reverse();
+            c.splice(0..0, std::iter::repeat(' ').take(height - c.len()));
+        }
+    } else {
+        columns.reverse();
+    }
+    (0..height)
+        .map(|row| {
+            columns
+                .iter()
+                .map(|c| c.get(row).copied().unwrap_or(' '))
+                .collect::<String>()
+                .trim_end()
+                .to_owned()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Whether vertical text reads bottom to top, or `None` for horizontal text.
+pub(crate) fn vertical_direction(d: &TextDirectionType) -> Option<bool> {
+    match d {
+        TextDirectionType::Tb
+        | TextDirectionType::TbV
+        | TextDirectionType::TbRl
+        | TextDirectionType::TbRlV => Some(false),
+        TextDirectionType::BtLr | TextDirectionType::Rl | TextDirectionType::RlV => Some(true),
+        TextDirectionType::Lr | TextDirectionType::LrV | TextDirectionType::LrTbV => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::documents::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_transpose_lines() {
+        assert_eq!(transpose_lines("abc\nde", false), "da\neb\n c");
+        assert_eq!(transpose_lines("abc\nde", true), "c\nbe\nad");
+    }
+
+    #[test]
+    fn test_render_directions() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .bidi(true)
+                    .add_run(Run::new().add_text("abc")),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()
+                .text_direction(TextDirectionType::TbRl)
+                .add_paragraph(
+                    Paragraph::new().add_run(Run::new().add_text("ab")),
+                )])]));
+        let r = docx.render_ascii_json();
+        assert_eq!(r.text, "abc\n+----+\n| ab |\n+----+");
+        assert_eq!(
+            r.children[0].properties,
+            serde_json::json!({ "direction": "rtl" })
+        );
+        assert_eq!(
+            r.children[1].children[0].children[0].properties,
+            serde_json::json!({ "textDirection": "tbRl" })
+        );
+        let r = docx.render_with(&RenderOptions::new().visual_text_direction(true));
+        assert_eq!(r.text, "cba\n+---+\n| a |\n| b |\n+---+");
+    }
+}
@@ -0,0 +1,202 @@
+use super::*;
+
+impl Render for Pic {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        // Prefer alt text over the object name.
+        let label = self
+            .description
+            .as_ref()
+            .filter(|d| !d.trim().is_empty())
+            .or_else(|| self.name.as_ref().filter(|n| !n.trim().is_empty()));
+        let text = match label {
+            Some(label) => format!("[image: {}]", label.trim()),
+            None => "[image]".to_owned(),
+        };
+        let mut render = JsonRender::new(RenderNodeType::Image).text(text);
+        // width and height are in EMU.
+        render.properties = serde_json::json!({
+            "rid": self.id,
+            "name": self.name,
+            "description": self.description,
+            "width": self.size.0,
+            "height": self.size.1,
+        });
+        ctx.visit(render, |v, r| v.visit_image(self, r))
+    }
+}
+
+impl Render for Chart {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let label = self
+            .title
+            .as_ref()
+            .or(self.description.as_ref())
+            .or(self.name.as_ref())
+            .filter(|l| !l.trim().is_empty());
+        let text = match label {
+            Some(label) => format!("[chart: {}]", label.trim()),
+            None => "[chart]".to_owned(),
+        };
+        let mut render = JsonRender::new(RenderNodeType::Chart).text(text);
+        render.properties = serde_json::json!({
+            "rid": self.id,
+            "chartType": self.chart_type,
+            "title": self.title,
+            "series": self.series,
+        });
+        ctx.visit(render, |v, r| v.visit_chart(self, r))
+    }
+}
+
+impl Render for Drawing {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        match &self.data {
+            Some(DrawingData::Pic(pic)) => pic.render_ascii_json(ctx),
+            Some(DrawingData::TextBox(t)) => render_text_box(&t.children, ctx),
+            Some(DrawingData::Chart(c)) => c.render_ascii_json(ctx),
+            // Shapes have no text to render.
+            Some(DrawingData::Shape(_)) | None => JsonRender::new(RenderNodeType::Image),
+        }
+    }
+}
+
+// Content of `wps:txbx` or `v:textbox`, or a placeholder when it goes to the appendix.
+pub(crate) fn render_text_box(
+    children: &[TextBoxContentChild],
+    ctx: &mut RenderContext,
+) -> JsonRender {
+    let children: Vec<JsonRender> = children
+        .iter()
+        .map(|c| match c {
+            TextBoxContentChild::Paragraph(p) => p.render_ascii_json(ctx),
+            TextBoxContentChild::Table(t) => t.render_ascii_json(ctx),
+        })
+        .collect();
+    let render = JsonRender::new(RenderNodeType::TextBox)
+        .text(join_text(&children, "\n"))
+        .children(children);
+    match ctx.options.text_box_display {
+        TextBoxDisplay::Inline => render,
+        TextBoxDisplay::Appendix => {
+            ctx.text_boxes.push(render);
+            let number = ctx.text_boxes.len();
+            let mut placeholder =
+                JsonRender::new(RenderNodeType::TextBox).text(format!("[text box {}]", number));
+            placeholder.properties = serde_json::json!({ "number": number });
+            placeholder
+        }
+    }
+}
+
+impl<'a> RenderContext<'a> {
+    // Text boxes section for the appendix, e.g. `Text boxes\n[1] content`.
+    pub(crate) fn render_text_boxes(&mut self) -> Option<JsonRender> {
+        if self.text_boxes.is_empty() {
+            return None;
+        }
+        let mut children = std::mem::take(&mut self.text_boxes);
+        for (i, t) in children.iter_mut().enumerate() {
+            t.text = format!("[{}] {}", i + 1, t.text);
+            t.properties = serde_json::json!({ "number": i + 1 });
+        }
+        Some(
+            JsonRender::new(RenderNodeType::TextBoxes)
+                .text(format!("Text boxes\n{}", join_text(&children, "\n")))
+                .children(children),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::ChartType;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_render_image_placeholder() {
+        let mut pic = Pic::with_empty()
+            .id("rId5")
+            .size(9525, 19050)
+            .name("Picture 1");
+        let p = Paragraph::new()
+            .add_run(Run::new().add_text("See "))
+            .add_run(Run::new().add_image(pic.clone()));
+        assert_eq!(p.render_ascii(), "See [image: Picture 1]");
+
+        pic = pic.description("Company logo");
+        let r = Run::new()
+            .add_image(pic)
+            .render_ascii_json(&mut RenderContext::new());
+        assert_eq!(r.text, "[image: Company logo]");
+        assert_eq!(
+            r.children[0].properties,
+            serde_json::json!({
+                "rid": "rId5",
+                "name": "Picture 1",
+                "description": "Company logo",
+                "width": 9525,
+                "height": 19050,
+            })
+        );
+    }
+
+    #[test]
+    fn test_render_chart() {
+        let chart = Chart::new("rId4").chart_type(ChartType::Pie).add_series(
+            ChartSeries::new()
+                .name("Share")
+                .categories(vec!["A".to_owned(), "B".to_owned()])
+                .values(vec![Some(0.25), Some(0.75)]),
+        );
+        let r = Run::new()
+            .add_drawing(Drawing::new().chart(chart.clone()))
+            .render_ascii_json(&mut RenderContext::new());
+        assert_eq!(r.text, "[chart]");
+        assert_eq!(
+            r.children[0].properties,
+            serde_json::json!({
+                "rid": "rId4",
+                "chartType": "pie",
+                "title": null,
+                "series": [{ "name": "Share", "categories": ["A", "B"], "values": [0.25, 0.75] }],
+            })
+        );
+        let r = chart
+            .title("Market")
+            .render_ascii_json(&mut RenderContext::new());
+        assert_eq!(r.text, "[chart: Market]");
+    }
+
+    #[test]
+    fn test_render_text_box() {
+        let mut text_box = TextBox::new();
+        text_box.children = vec![TextBoxContentChild::Paragraph(Box::new(
+            Paragraph::new().add_run(Run::new().add_text("Boxed")),
+        ))];
+        let shape = Shape::new().text_box(
+            TextBoxContent::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Legacy"))),
+        );
+        let mut run = Run::new().add_drawing(Drawing::new().text_box(text_box));
+        run.children.push(RunChild::Shape(Box::new(shape)));
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Intro")))
+            .add_paragraph(Paragraph::new().add_run(run));
+        assert_eq!(docx.render_ascii(), "Intro\nBoxedLegacy");
+
+        let r = docx.render_with(&RenderOptions::new().text_box_display(TextBoxDisplay::Appendix));
+        assert_eq!(
+            r.text,
+            "Intro\n[text box 1][text box 2]\nText boxes\n[1] Boxed\n[2] Legacy"
+        );
+        let appendix = r.children.last().unwrap();
+        assert_eq!(appendix.node_type, RenderNodeType::TextBoxes);
+        assert_eq!(
+            appendix.children[1].properties,
+            serde_json::json!({ "number": 2 })
+        );
+    }
+}
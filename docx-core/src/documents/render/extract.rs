@@ -0,0 +1,159 @@
+use super::*;
+
+// Part of the extracted text produced by one paragraph child.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextRange {
+    // Byte offsets into `ExtractedText::text`.
+    pub start: usize,
+    pub end: usize,
+    // Index in `Document::children`.
+    pub block: usize,
+    // (row, cell, paragraph) indices when the block is a table.
+    pub cell: Option<(usize, usize, usize)>,
+    // Index in `Paragraph::children`.
+    pub run: usize,
+}
+
+// Plain text of the body with the source of each range. Paragraphs are separated by `\n`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtractedText {
+    pub text: String,
+    pub ranges: Vec<TextRange>,
+}
+
+impl ExtractedText {
+    // Range containing the byte offset, e.g. of a search hit.
+    pub fn range_at(&self, offset: usize) -> Option<&TextRange> {
+        self.ranges
+            .iter()
+            .find(|r| r.start <= offset && offset < r.end)
+    }
+
+    fn push_paragraph(&mut self, p: &Paragraph, block: usize, cell: Option<(usize, usize, usize)>) {
+        for (run, c) in p.children.iter().enumerate() {
+            let text = child_text(c);
+            if text.is_empty() {
+                continue;
+            }
+            let start = self.text.len();
+            self.text.push_str(&text);
+            self.ranges.push(TextRange {
+                start,
+                end: self.text.len(),
+                block,
+                cell,
+                run,
+            });
+        }
+    }
+}
+
+fn run_text(run: &Run) -> String {
+    let mut s = String::new();
+    for c in &run.children {
+        match c {
+            RunChild::Text(t) => s.push_str(&replace_escaped(&t.text)),
+            RunChild::Tab(_) => s.push('\t'),
+            RunChild::Break(_) => s.push('\n'),
+            _ => {}
+        }
+    }
+    s
+}
+
+// Text after accepting all changes, so deletions are skipped.
+fn child_text(c: &ParagraphChild) -> String {
+    match c {
+        ParagraphChild::Run(r) => run_text(r),
+        ParagraphChild::Insert(i) => i
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                InsertChild::Run(r) => Some(run_text(r)),
+                _ => None,
+            })
+            .collect(),
+        ParagraphChild::Hyperlink(h) => h.children.iter().map(child_text).collect(),
+        ParagraphChild::ComplexField(f) => f.cached_result.iter().map(child_text).collect(),
+        _ => String::new(),
+    }
+}
+
+impl Docx {
+    pub fn extract_text(&self) -> ExtractedText {
+        let mut paragraphs = vec![];
+        for (block, c) in self.document.children.iter().enumerate() {
+            match c {
+                DocumentChild::Paragraph(p) => paragraphs.push((p.as_ref(), block, None)),
+                DocumentChild::Table(t) => {
+                    for (row, TableChild::TableRow(r)) in t.rows.iter().enumerate() {
+                        for (cell, TableRowChild::TableCell(c)) in r.cells.iter().enumerate() {
+                            let cell_paragraphs = c.children.iter().filter_map(|c| match c {
+                                TableCellContent::Paragraph(p) => Some(p),
+                                _ => None,
+                            });
+                            for (i, p) in cell_paragraphs.enumerate() {
+                                paragraphs.push((p, block, Some((row, cell, i))));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        let mut extracted = ExtractedText::default();
+        for (i, (p, block, cell)) in paragraphs.into_iter().enumerate() {
+            if i > 0 {
+                extracted.text.push('\n');
+            }
+            extracted.push_paragraph(p, block, cell);
+        }
+        extracted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_extract_text() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Hello "))
+                    .add_delete(Delete::new().add_run(Run::new().add_delete_text("old ")))
+                    .add_hyperlink(
+                        Hyperlink::new("https://example.com", HyperlinkType::External)
+                            .add_run(Run::new().add_text("world")),
+                    ),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![
+                TableCell::new(),
+                TableCell::new()
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text("cell"))),
+            ])]));
+        let extracted = docx.extract_text();
+        assert_eq!(extracted.text, "Hello world\ncell");
+        let hit = extracted.text.find("world").unwrap();
+        assert_eq!(
+            extracted.range_at(hit),
+            Some(&TextRange {
+                start: 6,
+                end: 11,
+                block: 0,
+                cell: None,
+                run: 2,
+            })
+        );
+        let hit = extracted.text.find("cell").unwrap();
+        let range = extracted.range_at(hit).unwrap();
+        assert_eq!((range.block, range.cell), (1, Some((0, 1, 0))));
+    }
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+// Fields are shown by their cached results.
+impl Render for ComplexField {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let children = render_paragraph_children(&self.cached_result, ctx);
+        let text = join_text(&children, "");
+        let mut render = JsonRender::new(RenderNodeType::Field)
+            .text(text)
+            .children(children);
+        render.properties = serde_json::json!({
+            "fieldType": self.field_type(),
+            "instruction": self.instruction.trim(),
+        });
+        render
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_render_field() {
+        let docx = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Dear "))
+                .add_complex_field(
+                    ComplexField::new(" MERGEFIELD Name ")
+                        .add_result_run(Run::new().add_text("«Name»")),
+                ),
+        );
+        let r = docx.render_ascii_json();
+        assert_eq!(r.text, "Dear «Name»");
+        let field = &r.children[0].children[1];
+        assert_eq!(field.node_type, RenderNodeType::Field);
+        assert_eq!(
+            field.properties,
+            serde_json::json!({ "fieldType": "MERGEFIELD", "instruction": "MERGEFIELD Name" })
+        );
+        assert_eq!(docx.render_markdown(), "Dear «Name»");
+    }
+}
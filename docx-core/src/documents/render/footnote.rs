@@ -0,0 +1,82 @@
+use super::*;
+
+impl<'a> RenderContext<'a> {
+    // Register the referenced footnote and return its display number.
+    pub(crate) fn add_footnote_reference(&mut self, reference: &FootnoteReference) -> usize {
+        self.footnote_references.push(reference.into());
+        self.footnote_references.len()
+    }
+
+    // Builder documents keep the content in the reference, read documents in the footnotes part.
+    fn footnote_content(&self, footnote: &Footnote) -> Vec<FootnoteChild> {
+        if !footnote.content.is_empty() {
+            return footnote.content.clone();
+        }
+        self.footnotes
+            .and_then(|f| f.footnotes.iter().find(|f| f.id == footnote.id))
+            .map(|f| f.content.clone())
+            .unwrap_or_default()
+    }
+
+    // Footnotes section for the references collected while rendering the body.
+    pub(crate) fn render_footnotes(&mut self) -> Option<JsonRender> {
+        if self.footnote_references.is_empty() {
+            return None;
+        }
+        let references = std::mem::take(&mut self.footnote_references);
+        let mut children = vec![];
+        for (i, footnote) in references.iter().enumerate() {
+            let paragraphs: Vec<JsonRender> = self
+                .footnote_content(footnote)
+                .iter()
+                .map(|c| match c {
+                    FootnoteChild::Paragraph(p) => p.render_ascii_json(self),
+                    FootnoteChild::Table(t) => t.render_ascii_json(self),
+                })
+                .collect();
+            let mut render = JsonRender::new(RenderNodeType::Footnote)
+                .text(format!("[{}] {}", i + 1, join_text(&paragraphs, "\n")))
+                .children(paragraphs);
+            render.properties = serde_json::json!({ "id": footnote.id, "number": i + 1 });
+            children.push(render);
+        }
+        Some(
+            JsonRender::new(RenderNodeType::Footnotes)
+                .text(format!("Footnotes\n{}", join_text(&children, "\n")))
+                .children(children),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_render_footnotes() {
+        let note = |text: &str| {
+            let mut f = Footnote::new();
+            f.add_content(Paragraph::new().add_run(Run::new().add_text(text)))
+        };
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Claim"))
+                    .add_run(Run::new().add_footnote_reference(note("Source A")))
+                    .add_run(Run::new().add_text(" and more"))
+                    .add_run(Run::new().add_footnote_reference(note("Source B"))),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("End")));
+        let r = docx.render_ascii_json();
+        assert_eq!(
+            r.text,
+            "Claim[1] and more[2]\nEnd\nFootnotes\n[1] Source A\n[2] Source B"
+        );
+        let footnotes = r.children.last().unwrap();
+        assert_eq!(footnotes.node_type, RenderNodeType::Footnotes);
+        assert_eq!(footnotes.children[1].text, "[2] Source B");
+    }
+}
@@ -0,0 +1,87 @@
+use super::*;
+
+pub(crate) fn check_box_text(checked: bool) -> &'static str {
+    if checked {
+        "[x]"
+    } else {
+        "[ ]"
+    }
+}
+
+// Current value of a legacy form field. Text inputs have their value in the field result,
+// so they only carry the definition.
+impl Render for FormFieldData {
+    fn render_ascii_json(&self, _ctx: &mut RenderContext) -> JsonRender {
+        let text = match &self.field {
+            FormField::CheckBox { checked } => check_box_text(*checked).to_owned(),
+            FormField::DropDown { entries, selected } => {
+                entries.get(*selected).cloned().unwrap_or_default()
+            }
+            FormField::TextInput { .. } => String::new(),
+        };
+        let mut render = JsonRender::new(RenderNodeType::FormField).text(text);
+        render.properties = serde_json::to_value(self).unwrap_or_default();
+        render
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn form_field(data: FormFieldData, instr: &str) -> Vec<Run> {
+        let mut begin = Run::new();
+        begin.children.push(RunChild::FieldChar(
+            FieldChar::new(FieldCharType::Begin).form_field(data),
+        ));
+        vec![
+            begin,
+            Run::new().add_instr_text(InstrText::Unsupported(instr.to_owned())),
+            Run::new().add_field_char(FieldCharType::End, false),
+        ]
+    }
+
+    #[test]
+    fn test_render_form_fields() {
+        let mut p = Paragraph::new().add_run(Run::new().add_text("Agree "));
+        for r in form_field(
+            FormFieldData::check_box(true).name("Check1"),
+            "FORMCHECKBOX",
+        ) {
+            p = p.add_run(r);
+        }
+        p = p.add_run(Run::new().add_text(" Size "));
+        let sizes = vec!["S".to_owned(), "M".to_owned(), "L".to_owned()];
+        for r in form_field(FormFieldData::drop_down(sizes, 2), "FORMDROPDOWN") {
+            p = p.add_run(r);
+        }
+        let r = p.render_ascii_json(&mut RenderContext::new());
+        assert_eq!(r.text, "Agree [x] Size L");
+        assert_eq!(
+            r.children[1].children[0].properties,
+            serde_json::json!({
+                "name": "Check1",
+                "field": { "type": "checkBox", "checked": true },
+            })
+        );
+    }
+
+    #[test]
+    fn test_render_sdt_check_box() {
+        let p = Paragraph::new().add_structured_data_tag(
+            StructuredDataTag::new()
+                .control(StructuredDataTagControl::CheckBox { checked: false })
+                .add_run(Run::new().add_text("☐")),
+        );
+        let r = p.render_ascii_json(&mut RenderContext::new());
+        assert_eq!(r.text, "[ ]");
+        assert_eq!(
+            r.children[0].properties["control"],
+            serde_json::json!({ "type": "checkBox", "checked": false })
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use super::*;
+
+impl Render for Header {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let children: Vec<JsonRender> = self
+            .children
+            .iter()
+            .map(|c| match c {
+                HeaderChild::Paragraph(p) => p.render_ascii_json(ctx),
+                HeaderChild::Table(t) => t.render_ascii_json(ctx),
+                HeaderChild::StructuredDataTag(t) => t.render_ascii_json(ctx),
+            })
+            .collect();
+        JsonRender::new(RenderNodeType::Header)
+            .text(join_text(&children, "\n"))
+            .children(children)
+    }
+}
+
+impl Render for Footer {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let children: Vec<JsonRender> = self
+            .children
+            .iter()
+            .map(|c| match c {
+                FooterChild::Paragraph(p) => p.render_ascii_json(ctx),
+                FooterChild::Table(t) => t.render_ascii_json(ctx),
+                FooterChild::StructuredDataTag(t) => t.render_ascii_json(ctx),
+            })
+            .collect();
+        JsonRender::new(RenderNodeType::Footer)
+            .text(join_text(&children, "\n"))
+            .children(children)
+    }
+}
+
+// Wrap the rendered part with delimiters, e.g. `--- header (first) ---`.
+fn delimited(mut render: JsonRender, name: &str, kind: &str) -> JsonRender {
+    render.text = format!(
+        "--- {} ({}) ---\n{}\n--- end {} ---",
+        name, kind, render.text, name
+    );
+    render.properties = serde_json::json!({ "type": kind });
+    render
+}
+
+// Rendered headers of the section in first, default, even order.
+pub(crate) fn render_headers(
+    section: &SectionProperty,
+    ctx: &mut RenderContext,
+) -> Vec<JsonRender> {
+    [
+        ("first", &section.first_header),
+        ("default", &section.header),
+        ("even", &section.even_header),
+    ]
+    .iter()
+    .filter_map(|(kind, h)| {
+        h.as_ref()
+            .map(|h| delimited(h.render_ascii_json(ctx), "header", kind))
+    })
+    .collect()
+}
+
+pub(crate) fn render_footers(
+    section: &SectionProperty,
+    ctx: &mut RenderContext,
+) -> Vec<JsonRender> {
+    [
+        ("first", &section.first_footer),
+        ("default", &section.footer),
+        ("even", &section.even_footer),
+    ]
+    .iter()
+    .filter_map(|(kind, f)| {
+        f.as_ref()
+            .map(|f| delimited(f.render_ascii_json(ctx), "footer", kind))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_render_headers_footers() {
+        let docx = Docx::new()
+            .header(
+                Header::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Head"))),
+            )
+            .first_header(
+                Header::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Cover"))),
+            )
+            .footer(
+                Footer::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Foot"))),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Body")));
+        assert_eq!(docx.render_ascii(), "Body");
+        let r = docx.render_with(&RenderOptions::new().include_headers_footers(true));
+        assert_eq!(
+            r.text,
+            "--- header (first) ---\nCover\n--- end header ---\n--- header (default) ---\nHead\n--- end header ---\nBody\n--- footer (default) ---\nFoot\n--- end footer ---"
+        );
+        assert_eq!(r.children[0].node_type, RenderNodeType::Header);
+        assert_eq!(
+            r.children[0].properties,
+            serde_json::json!({ "type": "first" })
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use super::*;
+use crate::types::VertAlignType;
+
+// Semantic HTML output for browser previews.
+pub trait RenderHtml {
+    fn render_html(&self, ctx: &mut RenderContext) -> String;
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_paragraph_children_html(children: &[ParagraphChild], ctx: &mut RenderContext) -> String {
+    let mut s = String::new();
+    for c in children {
+        match c {
+            ParagraphChild::Run(r) => s.push_str(&r.render_html(ctx)),
+            ParagraphChild::Insert(i) => s.push_str(&i.render_html(ctx)),
+            ParagraphChild::Hyperlink(h) => s.push_str(&h.render_html(ctx)),
+            ParagraphChild::ComplexField(f) => {
+                s.push_str(&render_paragraph_children_html(&f.cached_result, ctx))
+            }
+            _ => {}
+        }
+    }
+    s
+}
+
+impl RenderHtml for Document {
+    fn render_html(&self, ctx: &mut RenderContext) -> String {
+        let mut s = String::new();
+        // List tags opened for each numbering level, and whether an item of the level is open.
+        // Items are closed only when the next item of the same or a lower level starts, so that
+        // deeper lists are nested in the item above them.
+        let mut lists: Vec<(&str, bool)> = vec![];
+        for child in &self.children {
+            let list = match child {
+                DocumentChild::Paragraph(p) if ctx.heading_level(p).is_none() => ctx
+                    .numbering_level(p)
+                    .map(|(level, def)| (level, if is_bullet(def) { "ul" } else { "ol" })),
+                _ => None,
+            };
+            let depth = list.map(|(level, _)| level + 1).unwrap_or(0);
+            let tag = list.map(|(_, tag)| tag);
+            while let Some((open, item)) = lists.last().copied() {
+                if lists.len() > depth || (lists.len() == depth && Some(open) != tag) {
+                    if item {
+                        s.push_str("</li>\n");
+                    }
+                    s.push_str(&format!("</{}>\n", open));
+                    lists.pop();
+                } else {
+                    break;
+                }
+            }
+            if let Some((_, tag)) = list {
+                let open_levels = lists.len();
+                match lists.last_mut() {
+                    Some((_, item)) if *item && depth == open_levels => {
+                        s.push_str("</li>\n");
+                        *item = false;
+                    }
+                    _ => {}
+                }
+                while lists.len() < depth {
+                    if let Some((_, item)) = lists.last_mut() {
+                        // A skipped level still needs an item to hold the deeper list.
+                        if !*item {
+                            s.push_str("<li>");
+                            *item = true;
+                        }
+                        s.push('\n');
+                    }
+                    s.push_str(&format!("<{}>\n", tag));
+                    lists.push((tag, false));
+                }
+            }
+            match child {
+                DocumentChild::Paragraph(p) if list.is_some() => {
+                    s.push_str("<li>");
+                    s.push_str(&render_paragraph_children_html(&p.children, ctx));
+                    if let Some((_, item)) = lists.last_mut() {
+                        *item = true;
+                    }
+                }
+                DocumentChild::Paragraph(p) => {
+                    s.push_str(&p.render_html(ctx));
+                    s.push('\n');
+                }
+                DocumentChild::Table(t) => {
+                    s.push_str(&t.render_html(ctx));
+                    s.push('\n');
+                }
+                _ => continue,
+            };
+        }
+        while let Some((tag, item)) = lists.pop() {
+            if item {
+                s.push_str("</li>\n");
+            }
+            s.push_str(&format!("</{}>\n", tag));
+        }
+        s
+    }
+}
+
+impl RenderHtml for Paragraph {
+    fn render_html(&self, ctx: &mut RenderContext) -> String {
+        let content = render_paragraph_children_html(&self.children, ctx);
+        let tag = if let Some(level) = ctx.heading_level(self) {
+            format!("h{}", level.min(6))
+        } else if ctx.numbering_level(self).is_some() {
+            "li".to_owned()
+        } else {
+            "p".to_owned()
+        };
+        format!("<{}>{}</{}>", tag, content, tag)
+    }
+}
+
+impl RenderHtml for Run {
+    fn render_html(&self, _ctx: &mut RenderContext) -> String {
+        let mut s = String::new();
+        for c in &self.children {
+            match c {
+                RunChild::Text(t) => s.push_str(&escape_html(&replace_escaped(&t.text))),
+                RunChild::Tab(_) => s.push_str("&emsp;"),
+                RunChild::Break(_) => s.push_str("<br>"),
+                _ => {}
+            }
+        }
+        if s.is_empty() {
+            return s;
+        }
+        let p = &self.run_property;
+        let mut tags = vec![];
+        if p.bold.as_ref().map(|b| b.val).unwrap_or_default() {
+            tags.push("strong");
+        }
+        if p.italic.as_ref().map(|i| i.val).unwrap_or_default() {
+            tags.push("em");
+        }
+        if p.underline
+            .as_ref()
+            .map(|u| u.val != "none")
+            .unwrap_or_default()
+        {
+            tags.push("u");
+        }
+        if p.strike.as_ref().map(|s| s.val).unwrap_or_default() {
+            tags.push("s");
+        }
+        match p.vert_align.as_ref().map(|v| &v.val) {
+            Some(VertAlignType::SuperScript) => tags.push("sup"),
+            Some(VertAlignType::SubScript) => tags.push("sub"),
+            _ => {}
+        }
+        for tag in &tags {
+            s = format!("<{}>{}</{}>", tag, s, tag);
+        }
+        s
+    }
+}
+
+impl RenderHtml for Hyperlink {
+    fn render_html(&self, ctx: &mut RenderContext) -> String {
+        let content = render_paragraph_children_html(&self.children, ctx);
+        match ctx.hyperlink_url(&self.link) {
+            Some(url) => format!("<a href=\"{}\">{}</a>", escape_html(&url), content),
+            None => content,
+        }
+    }
+}
+
+impl RenderHtml for Insert {
+    fn render_html(&self, ctx: &mut RenderContext) -> String {
+        let mut s = String::new();
+        for c in &self.children {
+            if let InsertChild::Run(r) = c {
+                s.push_str(&r.render_html(ctx));
+            }
+        }
+        format!("<ins>{}</ins>", s)
+    }
+}
+
+impl RenderHtml for Table {
+    fn render_html(&self, ctx: &mut RenderContext) -> String {
+        let mut s = "<table>".to_owned();
+        for TableChild::TableRow(row) in &self.rows {
+            s.push_str(&row.render_html(ctx));
+        }
+        s.push_str("</table>");
+        s
+    }
+}
+
+impl RenderHtml for TableRow {
+    fn render_html(&self, ctx: &mut RenderContext) -> String {
+        let mut s = "<tr>".to_owned();
+        for TableRowChild::TableCell(cell) in &self.cells {
+            s.push_str(&cell.render_html(ctx));
+        }
+        s.push_str("</tr>");
+        s
+    }
+}
+
+impl RenderHtml for TableCell {
+    fn render_html(&self, ctx: &mut RenderContext) -> String {
+        let mut s = match self.property.grid_span.as_ref().map(|s| s.val) {
+            Some(span) if span > 1 => format!("<td colspan=\"{}\">", span),
+            _ => "<td>".to_owned(),
+        };
+        for c in &self.children {
+            match c {
+                TableCellContent::Paragraph(p) => s.push_str(&p.render_html(ctx)),
+                TableCellContent::Table(t) => s.push_str(&t.render_html(ctx)),
+                _ => {}
+            }
+        }
+        s.push_str("</td>");
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_run_html() {
+        let r = Run::new().add_text("a < b").bold().italic();
+        assert_eq!(
+            r.render_html(&mut RenderContext::new()),
+            "<em><strong>a &lt; b</strong></em>"
+        );
+    }
+
+    #[test]
+    fn test_paragraph_html() {
+        let p = Paragraph::new()
+            .add_run(Run::new().add_text("Go to "))
+            .add_hyperlink(
+                Hyperlink::new("https://example.com?a=1&b=2", HyperlinkType::External)
+                    .add_run(Run::new().add_text("site")),
+            )
+            .add_run(Run::new().add_break(BreakType::TextWrapping).add_text("x"));
+        assert_eq!(
+            p.render_html(&mut RenderContext::new()),
+            r#"<p>Go to <a href="https://example.com?a=1&amp;b=2">site</a><br>x</p>"#
+        );
+    }
+
+    #[test]
+    fn test_docx_html() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading1")
+                    .add_run(Run::new().add_text("Title")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("item"))
+                    .numbering(NumberingId::new(1), IndentLevel::new(0)),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("cell")))
+                .grid_span(2)])]));
+        assert_eq!(
+            docx.render_html(),
+            "<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"></head>\n<body>\n<h1>Title</h1>\n<ul>\n<li>item</li>\n</ul>\n<table><tr><td colspan=\"2\"><p>cell</p></td></tr></table>\n</body>\n</html>\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_list_html() {
+        let docx = Docx::new()
+            .add_abstract_numbering(
+                AbstractNumbering::new(1)
+                    .add_level(Level::new(
+                        0,
+                        Start::new(1),
+                        NumberFormat::new("decimal"),
+                        LevelText::new("%1."),
+                        LevelJc::new("left"),
+                    ))
+                    .add_level(Level::new(
+                        1,
+                        Start::new(1),
+                        NumberFormat::new("bullet"),
+                        LevelText::new("●"),
+                        LevelJc::new("left"),
+                    )),
+            )
+            .add_numbering(Numbering::new(2, 1))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("first"))
+                    .numbering(NumberingId::new(2), IndentLevel::new(0)),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("nested"))
+                    .numbering(NumberingId::new(2), IndentLevel::new(1)),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("second"))
+                    .numbering(NumberingId::new(2), IndentLevel::new(0)),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("end")));
+        let mut ctx = RenderContext::new().numberings(&docx.numberings);
+        assert_eq!(
+            docx.document.render_html(&mut ctx),
+            "<ol>\n<li>first\n<ul>\n<li>nested</li>\n</ul>\n</li>\n<li>second</li>\n</ol>\n<p>end</p>\n"
+        );
+    }
+}
@@ -0,0 +1,442 @@
+use super::*;
+
+// Word supports up to 9 levels (ilvl 0-8).
+const MAX_LEVELS: usize = 9;
+
+impl<'a> RenderContext<'a> {
+    // List marker with indentation of a numbered paragraph. This advances the counters,
+    // so call it once per paragraph in document order.
+    pub(crate) fn list_marker(&mut self, p: &Paragraph) -> Option<String> {
+        let (id, level) = self.numbering_id(p)?;
+        let level = level.min(MAX_LEVELS - 1);
+        let definition = self.level_definition(id, level);
+        let indent = "  ".repeat(level);
+        let starts: Vec<usize> = (0..MAX_LEVELS).map(|l| self.level_start(id, l)).collect();
+        let counters = self
+            .list_counters
+            .entry(id)
+            .or_insert_with(|| vec![0; MAX_LEVELS]);
+        counters[level] = if counters[level] == 0 {
+            starts[level]
+        } else {
+            counters[level] + 1
+        };
+        // Restart deeper levels.
+        for c in counters.iter_mut().skip(level + 1) {
+            *c = 0;
+        }
+        let counters = counters.clone();
+
+        if is_bullet(definition) {
+            let glyph = definition.map(|d| bullet_glyph(&d.text.val)).unwrap_or("•");
+            return Some(format!("{}{} ", indent, glyph));
+        }
+        let definition = definition?;
+        if definition.format.val == "none" {
+            return None;
+        }
+        // `%N` in lvlText refers to the current value of level N - 1.
+        let mut marker = definition.text.val.clone();
+        for l in (0..=level).rev() {
+            let placeholder = format!("%{}", l + 1);
+            if !marker.contains(&placeholder) {
+                continue;
+            }
+            let value = if counters[l] == 0 {
+                starts[l]
+            } else {
+                counters[l]
+            };
+            let format = self
+                .level_definition(id, l)
+                .map(|d| d.format.val.as_str())
+                .unwrap_or("decimal");
+            marker = marker.replace(&placeholder, &format_number(value, format));
+        }
+        Some(format!("{}{} ", indent, marker))
+    }
+
+    fn level_start(&self, id: usize, level: usize) -> usize {
+        let start_override = self.numberings.and_then(|numberings| {
+            numberings
+                .numberings
+                .iter()
+                .find(|n| n.id == id)?
+                .level_overrides
+                .iter()
+                .find(|o| o.level == level)?
+                .override_start
+        });
+        start_override
+            .or_else(|| self.level_definition(id, level).map(|d| d.start.val))
+            .unwrap_or(1)
+    }
+}
+
+// Bullets are often Symbol or Wingdings private use characters, which can't be displayed as text.
+fn bullet_glyph(text: &str) -> &str {
+    match text.chars().next() {
+        None => "•",
+        Some(c) if ('\u{F000}'..='\u{F0FF}').contains(&c) => "•",
+        _ => text,
+    }
+}
+
+pub(crate) fn format_number(n: usize, format: &str) -> String {
+    match format {
+        "lowerLetter" => alphabetic(n, LATIN),
+        "upperLetter" => alphabetic(n, LATIN).to_uppercase(),
+        "lowerRoman" => to_roman(n).to_lowercase(),
+        "upperRoman" => to_roman(n),
+        "decimalZero" if n < 10 => format!("0{}", n),
+        "ordinal" => format!("{}{}", n, ordinal_suffix(n)),
+        "cardinalText" => capitalize(&to_words(n)),
+        "ordinalText" => capitalize(&to_ordinal_words(n)),
+        "decimalFullWidth" | "decimalFullWidth2" => n
+            .to_string()
+            .chars()
+            .map(|c| char::from_u32(c as u32 - '0' as u32 + 0xFF10).unwrap_or(c))
+            .collect(),
+        "decimalEnclosedCircle" | "decimalEnclosedCircleChinese" => enclosed(n, 0x2460),
+        "decimalEnclosedParen" => enclosed(n, 0x2474),
+        "decimalEnclosedFullstop" => enclosed(n, 0x2488),
+        "ideographDigital" | "japaneseDigitalTenThousand" => digits(
+            n,
+            &['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'],
+        ),
+        "koreanDigital" => digits(
+            n,
+            &['영', '일', '이', '삼', '사', '오', '육', '칠', '팔', '구'],
+        ),
+        "japaneseCounting" => to_cjk_counting(n, false),
+        "chineseCounting"
+        | "chineseCountingThousand"
+        | "taiwaneseCounting"
+        | "taiwaneseCountingThousand" => to_cjk_counting(n, true),
+        "ideographTraditional" => cycle(
+            n,
+            &['甲', '乙', '丙', '丁', '戊', '己', '庚', '辛', '壬', '癸'],
+        ),
+        "ideographZodiac" => cycle(
+            n,
+            &[
+                '子', '丑', '寅', '卯', '辰', '巳', '午', '未', '申', '酉', '戌', '亥',
+            ],
+        ),
+        "aiueoFullWidth" => cycle(n, AIUEO),
+        "aiueo" => cycle(n, AIUEO_HALF_WIDTH),
+        "irohaFullWidth" => cycle(n, IROHA),
+        "iroha" => cycle(n, IROHA_HALF_WIDTH),
+        "ganada" => cycle(
+            n,
+            &[
+                '가', '나', '다', '라', '마', '바', '사', '아', '자', '차', '카', '타', '파', '하',
+            ],
+        ),
+        "chosung" => cycle(
+            n,
+            &[
+                'ㄱ', 'ㄴ', 'ㄷ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅅ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+            ],
+        ),
+        "russianLower" => alphabetic(n, RUSSIAN),
+        "russianUpper" => alphabetic(n, RUSSIAN).to_uppercase(),
+        // *, †, ‡, §, then doubled.
+        "chicago" => alphabetic(n, &['*', '†', '‡', '§']),
+        "none" => String::new(),
+        _ => n.to_string(),
+    }
+}
+
+const LATIN: &[char] = &[
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+
+// Letters Word uses for Russian lists, without ё, й, ъ, ы and ь.
+const RUSSIAN: &[char] = &[
+    'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з', 'и', 'к', 'л', 'м', 'н', 'о', 'п', 'р', 'с', 'т', 'у',
+    'ф', 'х', 'ц', 'ч', 'ш', 'щ', 'э', 'ю', 'я',
+];
+
+const AIUEO: &[char] = &[
+    'ア', 'イ', 'ウ', 'エ', 'オ', 'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ', 'ソ', 'タ',
+    'チ', 'ツ', 'テ', 'ト', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ', 'ホ', 'マ', 'ミ',
+    'ム', 'メ', 'モ', 'ヤ', 'ユ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', 'ヲ', 'ン',
+];
+
+const AIUEO_HALF_WIDTH: &[char] = &[
+    'ｱ', 'ｲ', 'ｳ', 'ｴ', 'ｵ', 'ｶ', 'ｷ', 'ｸ', 'ｹ', 'ｺ', 'ｻ', 'ｼ', 'ｽ', 'ｾ', 'ｿ', 'ﾀ', 'ﾁ', 'ﾂ', 'ﾃ',
+    'ﾄ', 'ﾅ', 'ﾆ', 'ﾇ', 'ﾈ', 'ﾉ', 'ﾊ', 'ﾋ', 'ﾌ', 'ﾍ', 'ﾎ', 'ﾏ', 'ﾐ', 'ﾑ', 'ﾒ', 'ﾓ', 'ﾔ', 'ﾕ', 'ﾖ',
+    'ﾗ', 'ﾘ', 'ﾙ', 'ﾚ', 'ﾛ', 'ﾜ', 'ｦ', 'ﾝ',
+];
+
+const IROHA: &[char] = &[
+    'イ', 'ロ', 'ハ', 'ニ', 'ホ', 'ヘ', 'ト', 'チ', 'リ', 'ヌ', 'ル', 'ヲ', 'ワ', 'カ', 'ヨ', 'タ',
+    'レ', 'ソ', 'ツ', 'ネ', 'ナ', 'ラ', 'ム', 'ウ', 'ヰ', 'ノ', 'オ', 'ク', 'ヤ', 'マ', 'ケ', 'フ',
+    'コ', 'エ', 'テ', 'ア', 'サ', 'キ', 'ユ', 'メ', 'ミ', 'シ', 'ヱ', 'ヒ', 'モ', 'セ', 'ス',
+];
+
+// ヰ and ヱ have no half-width forms.
+const IROHA_HALF_WIDTH: &[char] = &[
+    'ｲ', 'ﾛ', 'ﾊ', 'ﾆ', 'ﾎ', 'ﾍ', 'ﾄ', 'ﾁ', 'ﾘ', 'ﾇ', 'ﾙ', 'ｦ', 'ﾜ', 'ｶ', 'ﾖ', 'ﾀ', 'ﾚ', 'ｿ', 'ﾂ',
+    'ﾈ', 'ﾅ', 'ﾗ', 'ﾑ', 'ｳ', 'ヰ', 'ﾉ', 'ｵ', 'ｸ', 'ﾔ', 'ﾏ', 'ｹ', 'ﾌ', 'ｺ', 'ｴ', 'ﾃ', 'ｱ', 'ｻ', 'ｷ',
+    'ﾕ', 'ﾒ', 'ﾐ', 'ｼ', 'ヱ', 'ﾋ', 'ﾓ', 'ｾ', 'ｽ',
+];
+
+// a..z, then aa..zz, aaa..
+fn alphabetic(n: usize, letters: &[char]) -> String {
+    if n == 0 {
+        return n.to_string();
+    }
+    let c = letters[(n - 1) % letters.len()];
+    c.to_string().repeat((n - 1) / letters.len() + 1)
+}
+
+// Sequences restarting from the first symbol, e.g. the zodiac.
+fn cycle(n: usize, symbols: &[char]) -> String {
+    if n == 0 {
+        return n.to_string();
+    }
+    symbols[(n - 1) % symbols.len()].to_string()
+}
+
+fn digits(n: usize, symbols: &[char]) -> String {
+    n.to_string()
+        .chars()
+        .filter_map(|c| c.to_digit(10).map(|d| symbols[d as usize]))
+        .collect()
+}
+
+// ① to ⑳, larger numbers fall back to decimal.
+fn enclosed(n: usize, first: u32) -> String {
+    if (1..=20).contains(&n) {
+        if let Some(c) = char::from_u32(first + n as u32 - 1) {
+            return c.to_string();
+        }
+    }
+    n.to_string()
+}
+
+// 二十一 for 21. Chinese keeps the leading 一 of 百 and 千 and marks skipped digits with 零,
+// e.g. 一百零一, while Japanese writes 百一.
+fn to_cjk_counting(n: usize, chinese: bool) -> String {
+    const DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+    const UNITS: [(usize, char); 3] = [(1000, '千'), (100, '百'), (10, '十')];
+    if n == 0 || n >= 10000 {
+        return digits(n, &DIGITS);
+    }
+    let mut s = String::new();
+    let mut rest = n;
+    let mut zero = false;
+    for (value, unit) in UNITS.iter() {
+        let d = rest / value;
+        rest %= value;
+        if d == 0 {
+            zero = !s.is_empty();
+            continue;
+        }
+        if chinese && zero {
+            s.push('零');
+        }
+        zero = false;
+        if d > 1 || (chinese && n >= 100) {
+            s.push(DIGITS[d]);
+        }
+        s.push(*unit);
+    }
+    if rest > 0 {
+        if chinese && zero {
+            s.push('零');
+        }
+        s.push(DIGITS[rest]);
+    }
+    s
+}
+
+fn ordinal_suffix(n: usize) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+// English words as Word writes them, e.g. `one hundred twenty-one`.
+fn to_words(n: usize) -> String {
+    match n {
+        0..=19 => ONES[n].to_owned(),
+        20..=99 if n % 10 == 0 => TENS[n / 10].to_owned(),
+        20..=99 => format!("{}-{}", TENS[n / 10], ONES[n % 10]),
+        100..=999 if n % 100 == 0 => format!("{} hundred", ONES[n / 100]),
+        100..=999 => format!("{} hundred {}", ONES[n / 100], to_words(n % 100)),
+        1000..=999_999 if n % 1000 == 0 => format!("{} thousand", to_words(n / 1000)),
+        1000..=999_999 => format!("{} thousand {}", to_words(n / 1000), to_words(n % 1000)),
+        _ => n.to_string(),
+    }
+}
+
+fn to_ordinal_words(n: usize) -> String {
+    let words = to_words(n);
+    // Only the last word is ordinal, e.g. `twenty-first`.
+    let split = words.rfind(|c| c == ' ' || c == '-').map_or(0, |i| i + 1);
+    let (head, last) = words.split_at(split);
+    let last = match last {
+        "one" => "first".to_owned(),
+        "two" => "second".to_owned(),
+        "three" => "third".to_owned(),
+        "five" => "fifth".to_owned(),
+        "eight" => "eighth".to_owned(),
+        "nine" => "ninth".to_owned(),
+        "twelve" => "twelfth".to_owned(),
+        w if w.ends_with('y') => format!("{}ieth", &w[..w.len() - 1]),
+        w if w.chars().all(|c| c.is_ascii_digit()) => format!("{}{}", w, ordinal_suffix(n)),
+        w => format!("{}th", w),
+    };
+    format!("{}{}", head, last)
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn to_roman(mut n: usize) -> String {
+    if n == 0 {
+        return n.to_string();
+    }
+    let numerals = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut s = String::new();
+    for (value, numeral) in numerals.iter() {
+        while n >= *value {
+            s.push_str(numeral);
+            n -= value;
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn item(text: &str, level: usize) -> Paragraph {
+        Paragraph::new()
+            .add_run(Run::new().add_text(text))
+            .numbering(NumberingId::new(1), IndentLevel::new(level))
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(3, "decimal"), "3");
+        assert_eq!(format_number(28, "lowerLetter"), "bb");
+        assert_eq!(format_number(14, "upperRoman"), "XIV");
+        assert_eq!(format_number(4, "lowerRoman"), "iv");
+        assert_eq!(format_number(7, "decimalZero"), "07");
+        assert_eq!(format_number(12, "decimalZero"), "12");
+        assert_eq!(format_number(22, "ordinal"), "22nd");
+        assert_eq!(format_number(13, "ordinal"), "13th");
+        assert_eq!(format_number(21, "cardinalText"), "Twenty-one");
+        assert_eq!(format_number(42, "ordinalText"), "Forty-second");
+        assert_eq!(format_number(20, "ordinalText"), "Twentieth");
+    }
+
+    #[test]
+    fn test_format_cjk_number() {
+        assert_eq!(format_number(3, "decimalEnclosedCircle"), "③");
+        assert_eq!(format_number(12, "decimalFullWidth"), "１２");
+        assert_eq!(format_number(105, "ideographDigital"), "一〇五");
+        assert_eq!(format_number(21, "japaneseCounting"), "二十一");
+        assert_eq!(format_number(101, "japaneseCounting"), "百一");
+        assert_eq!(format_number(101, "chineseCounting"), "一百零一");
+        assert_eq!(format_number(1010, "chineseCounting"), "一千零一十");
+        assert_eq!(format_number(14, "chineseCounting"), "十四");
+        assert_eq!(format_number(11, "ideographTraditional"), "甲");
+        assert_eq!(format_number(3, "aiueoFullWidth"), "ウ");
+        assert_eq!(format_number(2, "ganada"), "나");
+        assert_eq!(format_number(3, "russianUpper"), "В");
+    }
+
+    #[test]
+    fn test_render_list_markers() {
+        let level = |l: usize, format: &str, text: &str| {
+            Level::new(
+                l,
+                Start::new(1),
+                NumberFormat::new(format),
+                LevelText::new(text),
+                LevelJc::new("left"),
+            )
+        };
+        let docx = Docx::new()
+            .add_abstract_numbering(
+                AbstractNumbering::new(1)
+                    .add_level(level(0, "decimal", "%1."))
+                    .add_level(level(1, "lowerLetter", "%1.%2."))
+                    .add_level(level(2, "lowerRoman", "%3)"))
+                    .add_level(level(3, "bullet", "\u{F0B7}")),
+            )
+            .add_numbering(Numbering::new(1, 1))
+            .add_paragraph(item("one", 0))
+            .add_paragraph(item("nested", 1))
+            .add_paragraph(item("deep", 2))
+            .add_paragraph(item("deeper", 2))
+            .add_paragraph(item("dot", 3))
+            .add_paragraph(item("nested again", 1))
+            .add_paragraph(item("two", 0))
+            .add_paragraph(item("restarted", 1));
+        assert_eq!(
+            docx.render_ascii(),
+            "1. one\n  1.a. nested\n    i) deep\n    ii) deeper\n      • dot\n  1.b. nested again\n2. two\n  2.a. restarted"
+        );
+    }
+}
@@ -0,0 +1,510 @@
+use super::*;
+
+// GitHub flavored markdown output.
+pub trait RenderMarkdown {
+    fn render_markdown(&self, ctx: &mut RenderContext) -> String;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Emphasis {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+}
+
+impl Emphasis {
+    fn from_run_property(p: &RunProperty) -> Emphasis {
+        Emphasis {
+            bold: p.bold.as_ref().map(|b| b.val).unwrap_or_default(),
+            italic: p.italic.as_ref().map(|i| i.val).unwrap_or_default(),
+            strike: p.strike.as_ref().map(|s| s.val).unwrap_or_default(),
+        }
+    }
+
+    fn open(&self) -> String {
+        let mut s = String::new();
+        if self.strike {
+            s.push_str("~~");
+        }
+        if self.bold {
+            s.push_str("**");
+        }
+        if self.italic {
+            s.push('*');
+        }
+        s
+    }
+
+    fn close(&self) -> String {
+        self.open().chars().rev().collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Inline {
+    Text(String, Emphasis),
+    Link(String, Vec<Inline>),
+    Break,
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut s = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '|' | '<') {
+            s.push('\\');
+        }
+        s.push(c);
+    }
+    s
+}
+
+// Escape a marker at the start of a line which would make it a heading, a quote, a list item
+// or a thematic break, such as `# Total` or `1. item`.
+fn escape_line_start(line: &str) -> String {
+    let indent = line.len() - line.trim_start_matches(|c| c == ' ' || c == '\t').len();
+    let (lead, rest) = line.split_at(indent);
+    let ends_marker = |s: &str| s.is_empty() || s.starts_with(|c| c == ' ' || c == '\t');
+    let mut chars = rest.chars();
+    let escape_at = match chars.next() {
+        Some('#') | Some('>') => Some(0),
+        Some('-') if ends_marker(chars.as_str()) || chars.as_str().starts_with('-') => Some(0),
+        Some('+') if ends_marker(chars.as_str()) => Some(0),
+        Some(c) if c.is_ascii_digit() => {
+            let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            let after = &rest[digits..];
+            if digits <= 9
+                && (after.starts_with('.') || after.starts_with(')'))
+                && ends_marker(&after[1..])
+            {
+                Some(digits)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+    match escape_at {
+        Some(at) => format!("{}{}\\{}", lead, &rest[..at], &rest[at..]),
+        None => line.to_owned(),
+    }
+}
+
+fn run_inlines(run: &Run) -> Vec<Inline> {
+    let emphasis = Emphasis::from_run_property(&run.run_property);
+    let mut inlines = vec![];
+    for c in &run.children {
+        match c {
+            RunChild::Text(t) => inlines.push(Inline::Text(replace_escaped(&t.text), emphasis)),
+            RunChild::Tab(_) => inlines.push(Inline::Text(" ".to_owned(), emphasis)),
+            RunChild::Break(_) => inlines.push(Inline::Break),
+            _ => {}
+        }
+    }
+    inlines
+}
+
+fn paragraph_inlines(children: &[ParagraphChild], ctx: &RenderContext) -> Vec<Inline> {
+    let mut inlines = vec![];
+    for c in children {
+        match c {
+            ParagraphChild::Run(r) => inlines.extend(run_inlines(r)),
+            ParagraphChild::Insert(i) => {
+                for c in &i.children {
+                    if let InsertChild::Run(r) = c {
+                        inlines.extend(run_inlines(r));
+                    }
+                }
+            }
+            ParagraphChild::Hyperlink(h) => {
+                let children = paragraph_inlines(&h.children, ctx);
+                if let Some(url) = ctx.hyperlink_url(&h.link) {
+                    inlines.push(Inline::Link(url, children));
+                } else {
+                    inlines.extend(children);
+                }
+            }
+            ParagraphChild::ComplexField(f) => {
+                inlines.extend(paragraph_inlines(&f.cached_result, ctx))
+            }
+            _ => {}
+        }
+    }
+    inlines
+}
+
+// Line breaks are returned as `\n` so that callers can choose hard breaks or `<br>`.
+fn inlines_to_markdown(inlines: &[Inline]) -> String {
+    let mut s = String::new();
+    // Merge adjacent texts with same emphasis to avoid `**a****b**`.
+    let mut pending: Option<(String, Emphasis)> = None;
+    let flush = |s: &mut String, pending: &mut Option<(String, Emphasis)>| {
+        if let Some((text, emphasis)) = pending.take() {
+            s.push_str(&emphasize(&text, emphasis));
+        }
+    };
+    for inline in inlines {
+        match inline {
+            Inline::Text(text, emphasis) => match pending {
+                Some((ref mut t, e)) if e == *emphasis => t.push_str(text),
+                _ => {
+                    flush(&mut s, &mut pending);
+                    pending = Some((text.clone(), *emphasis));
+                }
+            },
+            Inline::Link(url, children) => {
+                flush(&mut s, &mut pending);
+                s.push_str(&format!(
+                    "[{}]({})",
+                    inlines_to_markdown(children).replace('\n', " "),
+                    url.replace(' ', "%20")
+                ));
+            }
+            Inline::Break => {
+                flush(&mut s, &mut pending);
+                s.push('\n');
+            }
+        }
+    }
+    flush(&mut s, &mut pending);
+    s
+}
+
+// Emphasis markers must be adjacent to non whitespace, so keep surrounding spaces outside.
+fn emphasize(text: &str, emphasis: Emphasis) -> String {
+    let core = text.trim();
+    if core.is_empty() || emphasis == Emphasis::default() {
+        return escape_markdown(text);
+    }
+    let start = text.len() - text.trim_start().len();
+    let end = start + core.len();
+    format!(
+        "{}{}{}{}{}",
+        &text[..start],
+        emphasis.open(),
+        escape_markdown(core),
+        emphasis.close(),
+        &text[end..]
+    )
+}
+
+fn is_list_item(p: &Paragraph, ctx: &RenderContext) -> bool {
+    ctx.numbering_level(p).is_some() && ctx.heading_level(p).is_none()
+}
+
+impl RenderMarkdown for Document {
+    fn render_markdown(&self, ctx: &mut RenderContext) -> String {
+        let mut s = String::new();
+        let mut prev_is_list = false;
+        for child in &self.children {
+            let (block, is_list) = match child {
+                DocumentChild::Paragraph(p) => (p.render_markdown(ctx), is_list_item(p, ctx)),
+                DocumentChild::Table(t) => {
+                    (RenderMarkdown::render_markdown(t.as_ref(), ctx), false)
+                }
+                _ => continue,
+            };
+            if block.is_empty() {
+                continue;
+            }
+            if !s.is_empty() {
+                // Keep list items together as a tight list.
+                s.push_str(if prev_is_list && is_list {
+                    "\n"
+                } else {
+                    "\n\n"
+                });
+            }
+            s.push_str(&block);
+            prev_is_list = is_list;
+        }
+        s
+    }
+}
+
+impl RenderMarkdown for Paragraph {
+    fn render_markdown(&self, ctx: &mut RenderContext) -> String {
+        let text = inlines_to_markdown(&paragraph_inlines(&self.children, ctx));
+        if text.trim().is_empty() {
+            return String::new();
+        }
+        if let Some(level) = ctx.heading_level(self) {
+            return format!("{} {}", "#".repeat(level), text.replace('\n', " ").trim());
+        }
+        let text = text
+            .split('\n')
+            .map(escape_line_start)
+            .collect::<Vec<_>>()
+            .join("  \n");
+        if let Some((level, definition)) = ctx.numbering_level(self) {
+            let marker = if is_bullet(definition) { "-" } else { "1." };
+            // Three spaces are enough to nest under both `-` and `1.` markers.
+            return format!("{}{} {}", "   ".repeat(level), marker, text.trim_start());
+        }
+        text
+    }
+}
+
+impl RenderMarkdown for Run {
+    fn render_markdown(&self, _ctx: &mut RenderContext) -> String {
+        inlines_to_markdown(&run_inlines(self)).replace('\n', "  \n")
+    }
+}
+
+fn cell_markdown(cell: &TableCell, ctx: &mut RenderContext) -> String {
+    let mut lines = vec![];
+    for c in &cell.children {
+        match c {
+            TableCellContent::Paragraph(p) => {
+                lines.push(inlines_to_markdown(&paragraph_inlines(&p.children, ctx)))
+            }
+            TableCellContent::Table(t) => {
+                // Nested tables can't be expressed in GFM, flatten each row to one line.
+                for row in t.render_ascii_json(ctx).children {
+                    let cells: Vec<String> = row
+                        .children
+                        .iter()
+                        .map(|c| escape_markdown(&c.text.replace('\n', " ")))
+                        .collect();
+                    lines.push(cells.join(" \\| "));
+                }
+            }
+            _ => {}
+        }
+    }
+    lines.join("\n").trim().replace('\n', "<br>")
+}
+
+// Delimiter of a column, from the justification of the first paragraph of its cells.
+fn column_delimiter(alignment: Option<&str>) -> String {
+    match alignment {
+        Some("left") | Some("start") => ":---",
+        Some("center") => ":---:",
+        Some("right") | Some("end") => "---:",
+        _ => "---",
+    }
+    .to_owned()
+}
+
+fn cell_alignment(cell: &TableCell) -> Option<&str> {
+    cell.children.iter().find_map(|c| match c {
+        TableCellContent::Paragraph(p) => Some(p.property.alignment.as_ref()?.val.as_str()),
+        _ => None,
+    })
+}
+
+impl Table {
+    pub fn render_markdown(&self) -> String {
+        RenderMarkdown::render_markdown(self, &mut RenderContext::new())
+    }
+}
+
+impl RenderMarkdown for Table {
+    fn render_markdown(&self, ctx: &mut RenderContext) -> String {
+        let mut rows: Vec<Vec<String>> = vec![];
+        let mut alignments: Vec<Option<&str>> = vec![];
+        for TableChild::TableRow(row) in &self.rows {
+            let mut cells = vec![];
+            for TableRowChild::TableCell(cell) in &row.cells {
+                let column = cells.len();
+                if alignments.len() <= column {
+                    alignments.resize(column + 1, None);
+                }
+                if alignments[column].is_none() {
+                    alignments[column] = cell_alignment(cell);
+                }
+                cells.push(cell_markdown(cell, ctx));
+                // Keep column count for spanned cells.
+                let span = cell.property.grid_span.as_ref().map(|s| s.val).unwrap_or(1);
+                for _ in 1..span {
+                    cells.push(String::new());
+                }
+            }
+            rows.push(cells);
+        }
+        let columns = rows.iter().map(|r| r.len()).max().unwrap_or_default();
+        if columns == 0 {
+            return String::new();
+        }
+        let line = |cells: &[String]| {
+            let mut s = "|".to_owned();
+            for i in 0..columns {
+                s.push_str(&format!(
+                    " {} |",
+                    cells.get(i).map(|c| c.as_str()).unwrap_or("")
+                ));
+            }
+            s
+        };
+        let delimiters: Vec<String> = (0..columns)
+            .map(|i| column_delimiter(alignments.get(i).copied().flatten()))
+            .collect();
+        let mut lines = vec![line(&rows[0]), line(&delimiters)];
+        for r in &rows[1..] {
+            lines.push(line(r));
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn cell(text: &str) -> TableCell {
+        TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text(text)))
+    }
+
+    #[test]
+    fn test_paragraph_markdown() {
+        let p = Paragraph::new()
+            .add_run(Run::new().add_text("Hello "))
+            .add_run(Run::new().add_text("bold ").bold())
+            .add_run(Run::new().add_text("text").bold())
+            .add_run(Run::new().add_text(" and "))
+            .add_run(Run::new().add_text("italic").italic())
+            .add_run(Run::new().add_text(" "))
+            .add_run(Run::new().add_text("strike").strike())
+            .add_run(Run::new().add_text(" *x*"));
+        assert_eq!(
+            p.render_markdown(&mut RenderContext::new()),
+            "Hello **bold text** and *italic* ~~strike~~ \\*x\\*"
+        );
+    }
+
+    #[test]
+    fn test_escape_line_start_markdown() {
+        let render = |text: &str| {
+            Paragraph::new()
+                .add_run(Run::new().add_text(text))
+                .render_markdown(&mut RenderContext::new())
+        };
+        assert_eq!(render("# Total"), "\\# Total");
+        assert_eq!(render("> quoted"), "\\> quoted");
+        assert_eq!(render("- item"), "\\- item");
+        assert_eq!(render("+ item"), "\\+ item");
+        assert_eq!(render("* item"), "\\* item");
+        assert_eq!(render("---"), "\\---");
+        assert_eq!(render("1. item"), "1\\. item");
+        assert_eq!(render("12) item"), "12\\) item");
+        assert_eq!(render("2.5 kg"), "2.5 kg");
+        assert_eq!(render("a - b"), "a - b");
+        assert_eq!(render("<b>bold</b>"), "\\<b>bold\\</b>");
+
+        let p = Paragraph::new().add_run(
+            Run::new()
+                .add_text("a")
+                .add_break(BreakType::TextWrapping)
+                .add_text("# b"),
+        );
+        assert_eq!(p.render_markdown(&mut RenderContext::new()), "a  \n\\# b");
+    }
+
+    #[test]
+    fn test_heading_and_link_markdown() {
+        let styles =
+            Styles::new().add_style(Style::new("Heading1", StyleType::Paragraph).name("Heading 1"));
+        let mut ctx = RenderContext::new().styles(&styles);
+        let h = Paragraph::new()
+            .style("Heading1")
+            .add_run(Run::new().add_text("Title"));
+        assert_eq!(h.render_markdown(&mut ctx), "# Title");
+
+        let p = Paragraph::new().add_hyperlink(
+            Hyperlink::new("https://example.com", HyperlinkType::External)
+                .add_run(Run::new().add_text("link")),
+        );
+        assert_eq!(p.render_markdown(&mut ctx), "[link](https://example.com)");
+    }
+
+    #[test]
+    fn test_list_markdown() {
+        let docx = Docx::new()
+            .add_abstract_numbering(
+                AbstractNumbering::new(1)
+                    .add_level(Level::new(
+                        0,
+                        Start::new(1),
+                        NumberFormat::new("decimal"),
+                        LevelText::new("%1."),
+                        LevelJc::new("left"),
+                    ))
+                    .add_level(Level::new(
+                        1,
+                        Start::new(1),
+                        NumberFormat::new("bullet"),
+                        LevelText::new("●"),
+                        LevelJc::new("left"),
+                    )),
+            )
+            .add_numbering(Numbering::new(2, 1))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Items")))
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("first"))
+                    .numbering(NumberingId::new(2), IndentLevel::new(0)),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("nested"))
+                    .numbering(NumberingId::new(2), IndentLevel::new(1)),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("second"))
+                    .numbering(NumberingId::new(2), IndentLevel::new(0)),
+            );
+        assert_eq!(
+            docx.render_markdown(),
+            "Items\n\n1. first\n   - nested\n1. second"
+        );
+    }
+
+    #[test]
+    fn test_table_markdown() {
+        let t = Table::new(vec![
+            TableRow::new(vec![cell("a"), cell("b|c")]),
+            TableRow::new(vec![
+                cell("1").add_paragraph(Paragraph::new().add_run(Run::new().add_text("2")))
+            ]),
+        ]);
+        assert_eq!(
+            t.render_markdown(),
+            "| a | b\\|c |\n| --- | --- |\n| 1<br>2 |  |"
+        );
+    }
+
+    #[test]
+    fn test_table_markdown_alignment() {
+        let t = Table::new(vec![
+            TableRow::new(vec![cell("Item"), cell("Qty"), cell("Note")]),
+            TableRow::new(vec![
+                TableCell::new().add_paragraph(
+                    Paragraph::new()
+                        .align(AlignmentType::Left)
+                        .add_run(Run::new().add_text("Pen")),
+                ),
+                TableCell::new().add_paragraph(
+                    Paragraph::new()
+                        .align(AlignmentType::Right)
+                        .add_run(Run::new().add_text("2")),
+                ),
+                TableCell::new().add_paragraph(
+                    Paragraph::new().align(AlignmentType::Center).add_run(
+                        Run::new()
+                            .add_text("blue")
+                            .add_break(BreakType::TextWrapping)
+                            .add_text("ink"),
+                    ),
+                ),
+            ]),
+        ]);
+        assert_eq!(
+            t.render_markdown(),
+            "| Item | Qty | Note |\n| :--- | ---: | :---: |\n| Pen | 2 | blue<br>ink |"
+        );
+    }
+}
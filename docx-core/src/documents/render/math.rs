@@ -0,0 +1,427 @@
+use super::*;
+
+fn super_script_char(c: char) -> Option<char> {
+    let s = match c {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        '9' => '⁹',
+        '+' => '⁺',
+        '-' | '−' => '⁻',
+        '=' => '⁼',
+        '(' => '⁽',
+        ')' => '⁾',
+        'i' => 'ⁱ',
+        'n' => 'ⁿ',
+        _ => return None,
+    };
+    Some(s)
+}
+
+fn sub_script_char(c: char) -> Option<char> {
+    let s = match c {
+        '0' => '₀',
+        '1' => '₁',
+        '2' => '₂',
+        '3' => '₃',
+        '4' => '₄',
+        '5' => '₅',
+        '6' => '₆',
+        '7' => '₇',
+        '8' => '₈',
+        '9' => '₉',
+        '+' => '₊',
+        '-' | '−' => '₋',
+        '=' => '₌',
+        '(' => '₍',
+        ')' => '₎',
+        'a' => 'ₐ',
+        'e' => 'ₑ',
+        'i' => 'ᵢ',
+        'j' => 'ⱼ',
+        'k' => 'ₖ',
+        'n' => 'ₙ',
+        'x' => 'ₓ',
+        _ => return None,
+    };
+    Some(s)
+}
+
+// Parenthesize compound operands, e.g. `(a+b)/c`.
+fn group(text: &str) -> String {
+    if text.chars().count() <= 1 || text.chars().all(|c| c.is_alphanumeric()) {
+        text.to_owned()
+    } else {
+        format!("({})", text)
+    }
+}
+
+// `x²` when every character has a script form, `x^(…)` otherwise.
+fn script(text: &str, marker: char, map: fn(char) -> Option<char>) -> String {
+    match text.chars().map(map).collect::<Option<String>>() {
+        Some(s) => s,
+        None => format!("{}{}", marker, group(text)),
+    }
+}
+
+fn to_unicode(nodes: &[MathNode]) -> String {
+    nodes.iter().map(node_to_unicode).collect()
+}
+
+fn node_to_unicode(node: &MathNode) -> String {
+    match node {
+        MathNode::Run { text } => text.clone(),
+        MathNode::Fraction {
+            numerator,
+            denominator,
+        } => format!(
+            "{}/{}",
+            group(&to_unicode(numerator)),
+            group(&to_unicode(denominator))
+        ),
+        MathNode::SuperScript { base, sup } => format!(
+            "{}{}",
+            to_unicode(base),
+            script(&to_unicode(sup), '^', super_script_char)
+        ),
+        MathNode::SubScript { base, sub } => format!(
+            "{}{}",
+            to_unicode(base),
+            script(&to_unicode(sub), '_', sub_script_char)
+        ),
+        MathNode::SubSuperScript { base, sub, sup } => format!(
+            "{}{}{}",
+            to_unicode(base),
+            script(&to_unicode(sub), '_', sub_script_char),
+            script(&to_unicode(sup), '^', super_script_char)
+        ),
+        MathNode::Radical { degree, base } => {
+            let root = match to_unicode(degree).as_str() {
+                "" | "2" => "√".to_owned(),
+                "3" => "∛".to_owned(),
+                "4" => "∜".to_owned(),
+                d => format!("{}√", script(d, '^', super_script_char)),
+            };
+            format!("{}{}", root, group(&to_unicode(base)))
+        }
+        MathNode::Nary {
+            operator,
+            sub,
+            sup,
+            base,
+        } => {
+            let mut s = operator.clone();
+            let sub = to_unicode(sub);
+            if !sub.is_empty() {
+                s.push_str(&script(&sub, '_', sub_script_char));
+            }
+            let sup = to_unicode(sup);
+            if !sup.is_empty() {
+                s.push_str(&script(&sup, '^', super_script_char));
+            }
+            s.push_str(&to_unicode(base));
+            s
+        }
+        MathNode::Delimiter {
+            begin,
+            end,
+            separator,
+            elements,
+        } => {
+            let elements: Vec<String> = elements.iter().map(|e| to_unicode(e)).collect();
+            format!("{}{}{}", begin, elements.join(separator), end)
+        }
+        // Cells separated by spaces and rows by semicolons, e.g. `a b; c d`.
+        MathNode::Matrix { rows } => rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|c| to_unicode(c))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("; "),
+    }
+}
+
+fn latex_symbol(c: char) -> Option<&'static str> {
+    let s = match c {
+        'α' => "\\alpha",
+        'β' => "\\beta",
+        'γ' => "\\gamma",
+        'δ' => "\\delta",
+        'ε' => "\\epsilon",
+        'θ' => "\\theta",
+        'λ' => "\\lambda",
+        'μ' => "\\mu",
+        'π' => "\\pi",
+        'σ' => "\\sigma",
+        'φ' => "\\phi",
+        'ω' => "\\omega",
+        'Δ' => "\\Delta",
+        'Σ' => "\\Sigma",
+        'Ω' => "\\Omega",
+        '∞' => "\\infty",
+        '±' => "\\pm",
+        '×' => "\\times",
+        '÷' => "\\div",
+        '·' | '⋅' => "\\cdot",
+        '≤' => "\\leq",
+        '≥' => "\\geq",
+        '≠' => "\\neq",
+        '≈' => "\\approx",
+        '→' => "\\to",
+        '∈' => "\\in",
+        '∂' => "\\partial",
+        '∇' => "\\nabla",
+        '−' => "-",
+        _ => return None,
+    };
+    Some(s)
+}
+
+fn latex_text(text: &str) -> String {
+    let mut s = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match latex_symbol(c) {
+            Some(symbol) => {
+                s.push_str(symbol);
+                // Keep `\pi r` from becoming `\pir`.
+                if symbol.starts_with('\\') && chars.peek().map_or(false, |c| c.is_alphabetic()) {
+                    s.push(' ');
+                }
+            }
+            None => match c {
+                '{' | '}' | '%' | '#' | '&' | '$' | '_' => {
+                    s.push('\\');
+                    s.push(c);
+                }
+                _ => s.push(c),
+            },
+        }
+    }
+    s
+}
+
+fn latex_operator(operator: &str) -> String {
+    match operator {
+        "∑" => "\\sum".to_owned(),
+        "∏" => "\\prod".to_owned(),
+        "∐" => "\\coprod".to_owned(),
+        "∫" => "\\int".to_owned(),
+        "∬" => "\\iint".to_owned(),
+        "∭" => "\\iiint".to_owned(),
+        "∮" => "\\oint".to_owned(),
+        "⋃" => "\\bigcup".to_owned(),
+        "⋂" => "\\bigcap".to_owned(),
+        o => latex_text(o),
+    }
+}
+
+fn latex_delimiter(d: &str) -> String {
+    match d {
+        "" => ".".to_owned(),
+        "{" => "\\{".to_owned(),
+        "}" => "\\}".to_owned(),
+        "⟨" | "〈" => "\\langle".to_owned(),
+        "⟩" | "〉" => "\\rangle".to_owned(),
+        "‖" => "\\|".to_owned(),
+        d => d.to_owned(),
+    }
+}
+
+// Wrap the base of scripts in braces unless it is a single character.
+fn latex_base(nodes: &[MathNode]) -> String {
+    let base = to_latex(nodes);
+    if base.chars().count() <= 1 {
+        base
+    } else {
+        format!("{{{}}}", base)
+    }
+}
+
+fn to_latex(nodes: &[MathNode]) -> String {
+    nodes.iter().map(node_to_latex).collect()
+}
+
+fn node_to_latex(node: &MathNode) -> String {
+    match node {
+        MathNode::Run { text } => latex_text(text),
+        MathNode::Fraction {
+            numerator,
+            denominator,
+        } => format!(
+            "\\frac{{{}}}{{{}}}",
+            to_latex(numerator),
+            to_latex(denominator)
+        ),
+        MathNode::SuperScript { base, sup } => {
+            format!("{}^{{{}}}", latex_base(base), to_latex(sup))
+        }
+        MathNode::SubScript { base, sub } => {
+            format!("{}_{{{}}}", latex_base(base), to_latex(sub))
+        }
+        MathNode::SubSuperScript { base, sub, sup } => format!(
+            "{}_{{{}}}^{{{}}}",
+            latex_base(base),
+            to_latex(sub),
+            to_latex(sup)
+        ),
+        MathNode::Radical { degree, base } => {
+            if degree.is_empty() {
+                format!("\\sqrt{{{}}}", to_latex(base))
+            } else {
+                format!("\\sqrt[{}]{{{}}}", to_latex(degree), to_latex(base))
+            }
+        }
+        MathNode::Nary {
+            operator,
+            sub,
+            sup,
+            base,
+        } => {
+            let mut s = latex_operator(operator);
+            if !sub.is_empty() {
+                s.push_str(&format!("_{{{}}}", to_latex(sub)));
+            }
+            if !sup.is_empty() {
+                s.push_str(&format!("^{{{}}}", to_latex(sup)));
+            }
+            format!("{} {}", s, to_latex(base))
+        }
+        MathNode::Delimiter {
+            begin,
+            end,
+            separator,
+            elements,
+        } => {
+            let elements: Vec<String> = elements.iter().map(|e| to_latex(e)).collect();
+            format!(
+                "\\left{}{}\\right{}",
+                latex_delimiter(begin),
+                elements.join(&latex_text(separator)),
+                latex_delimiter(end)
+            )
+        }
+        MathNode::Matrix { rows } => {
+            let rows: Vec<String> = rows
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|c| to_latex(c))
+                        .collect::<Vec<_>>()
+                        .join(" & ")
+                })
+                .collect();
+            format!("\\begin{{matrix}}{}\\end{{matrix}}", rows.join(" \\\\ "))
+        }
+    }
+}
+
+impl OfficeMath {
+    // Unicode approximation such as `x² + √(y+1)`.
+    pub fn to_unicode(&self) -> String {
+        to_unicode(&self.children)
+    }
+
+    pub fn to_latex(&self) -> String {
+        to_latex(&self.children)
+    }
+}
+
+impl Render for OfficeMath {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let mut render = JsonRender::new(RenderNodeType::Math).text(self.to_unicode());
+        if ctx.options.math_latex {
+            render.properties = serde_json::json!({ "latex": self.to_latex() });
+        }
+        ctx.visit(render, |v, r| v.visit_math(self, r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn run(text: &str) -> Vec<MathNode> {
+        vec![MathNode::run(text)]
+    }
+
+    #[test]
+    fn test_render_math() {
+        let m = OfficeMath::new()
+            .add_node(MathNode::super_script(run("x"), run("2")))
+            .add_node(MathNode::run("+"))
+            .add_node(MathNode::fraction(run("a+b"), run("c")))
+            .add_node(MathNode::run("="))
+            .add_node(MathNode::radical(vec![], run("y+1")));
+        assert_eq!(m.to_unicode(), "x²+(a+b)/c=√(y+1)");
+        assert_eq!(m.to_latex(), "x^{2}+\\frac{a+b}{c}=\\sqrt{y+1}");
+    }
+
+    #[test]
+    fn test_render_math_operators() {
+        let m = OfficeMath::new()
+            .add_node(MathNode::nary(
+                "∑",
+                run("i=1"),
+                run("n"),
+                vec![MathNode::sub_script(run("x"), run("i"))],
+            ))
+            .add_node(MathNode::run("≤"))
+            .add_node(MathNode::delimiter(vec![run("πr"), run("k")]))
+            .add_node(MathNode::super_script(run("e"), run("-x/2")));
+        assert_eq!(m.to_unicode(), "∑ᵢ₌₁ⁿxᵢ≤(πr|k)e^(-x/2)");
+        assert_eq!(
+            m.to_latex(),
+            "\\sum_{i=1}^{n} x_{i}\\leq\\left(\\pi r|k\\right)e^{-x/2}"
+        );
+    }
+
+    #[test]
+    fn test_render_math_in_paragraph() {
+        let m = OfficeMath::new().add_node(MathNode::radical(run("3"), run("8")));
+        let p = Paragraph::new()
+            .add_run(Run::new().add_text("Root "))
+            .add_math(m);
+        let r = p.render_ascii_json(&mut RenderContext::new());
+        assert_eq!(r.text, "Root ∛8");
+        assert_eq!(r.children[1].node_type, RenderNodeType::Math);
+        assert_eq!(r.children[1].properties, serde_json::Value::Null);
+
+        let r = p.render_with(&RenderOptions::new().math_latex(true));
+        assert_eq!(
+            r.children[1].properties,
+            serde_json::json!({ "latex": "\\sqrt[3]{8}" })
+        );
+    }
+
+    #[test]
+    fn test_render_math_matrix() {
+        let m = OfficeMath::new().add_node(MathNode::Delimiter {
+            begin: "[".to_owned(),
+            end: "]".to_owned(),
+            separator: "|".to_owned(),
+            elements: vec![vec![MathNode::matrix(vec![
+                vec![run("1"), run("0")],
+                vec![run("0"), vec![MathNode::super_script(run("x"), run("2"))]],
+            ])]],
+        });
+        assert_eq!(m.to_unicode(), "[1 0; 0 x²]");
+        assert_eq!(
+            m.to_latex(),
+            "\\left[\\begin{matrix}1 & 0 \\\\ 0 & x^{2}\\end{matrix}\\right]"
+        );
+    }
+}
@@ -0,0 +1,1035 @@
+mod ansi;
+mod bookmark;
+mod comment;
+mod csv;
+mod direction;
+mod drawing;
+mod extract;
+mod field;
+mod footnote;
+mod form_field;
+mod header_footer;
+mod html;
+mod list;
+mod markdown;
+mod math;
+mod options;
+mod sdt;
+mod section;
+mod tab;
+mod table;
+mod toc;
+mod visitor;
+
+pub use ansi::*;
+pub use extract::*;
+pub use html::*;
+pub use markdown::*;
+pub use options::*;
+pub use visitor::*;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+
+use crate::documents::*;
+use crate::escape::replace_escaped;
+use crate::types::{BreakType, FieldCharType};
+use drawing::render_text_box;
+use header_footer::{render_footers, render_headers};
+use visitor::Visitor;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RenderNodeType {
+    Document,
+    Header,
+    Footer,
+    Paragraph,
+    Run,
+    Hyperlink,
+    Insert,
+    Delete,
+    Table,
+    TableRow,
+    TableCell,
+    Footnotes,
+    Footnote,
+    Comment,
+    CommentEnd,
+    Break,
+    Image,
+    Chart,
+    Math,
+    TextBox,
+    TextBoxes,
+    StructuredDataTag,
+    TableOfContents,
+    FormField,
+    Field,
+    Bookmark,
+    Section,
+}
+
+// Result of `Render::render_ascii_json`, serialized as
+// `{ "nodeType": "paragraph", "text": "...", "properties": null, "children": [] }`.
+// - nodeType: camelCased `RenderNodeType`, e.g. `tableRow`.
+// - text: plain text of the whole subtree.
+// - properties: node specific attributes such as `url` of hyperlinks, or null.
+// - children: rendered child nodes in document order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonRender {
+    pub node_type: RenderNodeType,
+    pub text: String,
+    #[serde(default)]
+    pub properties: serde_json::Value,
+    #[serde(default)]
+    pub children: Vec<JsonRender>,
+}
+
+impl JsonRender {
+    pub fn new(node_type: RenderNodeType) -> JsonRender {
+        JsonRender {
+            node_type,
+            text: String::new(),
+            properties: serde_json::Value::Null,
+            children: vec![],
+        }
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> JsonRender {
+        self.text = text.into();
+        self
+    }
+
+    pub fn children(mut self, children: Vec<JsonRender>) -> JsonRender {
+        self.children = children;
+        self
+    }
+}
+
+// Document level information shared by all renderers.
+#[derive(Debug, Clone, Default)]
+pub struct RenderContext<'a> {
+    heading_map: HashMap<String, usize>,
+    styles: Option<&'a Styles>,
+    numberings: Option<&'a Numberings>,
+    // Theme to resolve the theme colors with.
+    theme: Option<&'a Theme>,
+    // (rid, target, mode) of hyperlink relationships read from `document.xml.rels`.
+    hyperlinks: &'a [(String, String, String)],
+    // Current ordinals of each level per numId.
+    list_counters: HashMap<usize, Vec<usize>>,
+    options: RenderOptions,
+    footnotes: Option<&'a Footnotes>,
+    // Footnotes referenced so far, in reference order.
+    footnote_references: Vec<Footnote>,
+    // Number of tables being rendered around the current element.
+    table_depth: usize,
+    // Text boxes deferred to the appendix, in document order.
+    text_boxes: Vec<JsonRender>,
+    // Instructions of the fields open at the current paragraph, outermost first.
+    fields: Vec<String>,
+    // Cached entries of the current TOC field.
+    toc_entries: usize,
+    // (level, text) of the body headings, collected when `synthesize_toc` is set.
+    headings: Vec<(usize, String)>,
+    visitor: Visitor<'a>,
+}
+
+impl<'a> RenderContext<'a> {
+    pub fn new() -> RenderContext<'a> {
+        Default::default()
+    }
+
+    pub fn styles(mut self, styles: &'a Styles) -> Self {
+        self.heading_map = styles.create_heading_style_map();
+        self.styles = Some(styles);
+        self
+    }
+
+    pub fn numberings(mut self, numberings: &'a Numberings) -> Self {
+        self.numberings = Some(numberings);
+        self
+    }
+
+    pub fn theme(mut self, theme: &'a Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    // RGB of the color, or the theme color if the theme is known.
+    pub(crate) fn color(&self, c: &Color) -> String {
+        match self.theme {
+            Some(theme) => c.resolve(theme),
+            None => c.val.clone(),
+        }
+    }
+
+    pub fn options(mut self, options: RenderOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn footnotes(mut self, footnotes: &'a Footnotes) -> Self {
+        self.footnotes = Some(footnotes);
+        self
+    }
+
+    pub fn hyperlinks(mut self, hyperlinks: &'a [(String, String, String)]) -> Self {
+        self.hyperlinks = hyperlinks;
+        self
+    }
+
+    // Target of the link. Read documents only have the relationship id, so resolve it with rels.
+    pub(crate) fn hyperlink_url(&self, link: &HyperlinkData) -> Option<String> {
+        match link {
+            HyperlinkData::External { path, .. } if !path.is_empty() => Some(replace_escaped(path)),
+            HyperlinkData::External { rid, .. } => self
+                .hyperlinks
+                .iter()
+                .find(|(id, ..)| id == rid)
+                .map(|(_, target, _)| target.clone()),
+            HyperlinkData::Anchor { anchor } => Some(format!("#{}", anchor)),
+        }
+    }
+
+    // Heading level of the paragraph style. Without styles, fallback to `HeadingN` style ids.
+    pub(crate) fn heading_level(&self, p: &Paragraph) -> Option<usize> {
+        let style_id = &p.property.style.as_ref()?.val;
+        if let Some(level) = self.heading_map.get(style_id) {
+            return Some(*level);
+        }
+        style_id
+            .strip_prefix("Heading")
+            .and_then(|n| n.parse::<usize>().ok())
+            .filter(|n| (1..=9).contains(n))
+    }
+
+    // Resolve `numPr` of the paragraph or its style to the numbering level definition.
+    pub(crate) fn numbering_level(&self, p: &Paragraph) -> Option<(usize, Option<&'a Level>)> {
+        let (id, level) = self.numbering_id(p)?;
+        Some((level, self.level_definition(id, level)))
+    }
+
+    pub(crate) fn numbering_id(&self, p: &Paragraph) -> Option<(usize, usize)> {
+        paragraph_numbering(p, self.styles)
+    }
+
+    pub(crate) fn level_definition(&self, id: usize, level: usize) -> Option<&'a Level> {
+        self.numberings?.find_level(id, level, self.styles)
+    }
+}
+
+pub trait Render {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender;
+
+    fn render_ascii(&self) -> String {
+        self.render_ascii_json(&mut RenderContext::new()).text
+    }
+
+    fn render_with(&self, options: &RenderOptions) -> JsonRender {
+        self.render_ascii_json(&mut RenderContext::new().options(options.clone()))
+    }
+
+    // Write the ASCII rendering into `w`. Containers such as `Document` override this to
+    // write each block as soon as it's rendered.
+    fn render_ascii_to<W: io::Write>(&self, mut w: W) -> io::Result<()>
+    where
+        Self: Sized,
+    {
+        w.write_all(self.render_ascii().as_bytes())
+    }
+}
+
+impl Docx {
+    pub fn render_context(&self) -> RenderContext {
+        let ctx = RenderContext::new()
+            .styles(&self.styles)
+            .numberings(&self.numberings)
+            .hyperlinks(&self.hyperlinks)
+            .footnotes(&self.footnotes);
+        match self.themes.first() {
+            Some(theme) => ctx.theme(theme),
+            None => ctx,
+        }
+    }
+
+    pub fn render_ascii_json(&self) -> JsonRender {
+        self.document.render_ascii_json(&mut self.render_context())
+    }
+
+    pub fn render_ascii(&self) -> String {
+        self.render_ascii_json().text
+    }
+
+    pub fn render_ascii_to<W: io::Write>(&self, w: W) -> io::Result<()> {
+        self.document.write_ascii(&mut self.render_context(), w)
+    }
+
+    pub fn render_json_string(&self) -> String {
+        serde_json::to_string_pretty(&self.render_ascii_json()).unwrap()
+    }
+
+    pub fn render_with(&self, options: &RenderOptions) -> JsonRender {
+        self.document
+            .render_ascii_json(&mut self.render_context().options(options.clone()))
+    }
+
+    pub fn render_markdown(&self) -> String {
+        self.document.render_markdown(&mut self.render_context())
+    }
+
+    pub fn render_html(&self) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"></head>\n<body>\n{}</body>\n</html>\n",
+            self.document.render_html(&mut self.render_context())
+        )
+    }
+
+    pub fn render_ansi(&self) -> String {
+        self.document.render_ansi(&mut self.render_context())
+    }
+}
+
+// Numbering levels without definition are rendered as bullets.
+pub(crate) fn is_bullet(level: Option<&Level>) -> bool {
+    matches!(level.map(|l| l.format.val.as_str()), Some("bullet") | None)
+}
+
+pub(crate) fn join_text(children: &[JsonRender], sep: &str) -> String {
+    children
+        .iter()
+        .map(|c| c.text.as_str())
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+// Insert into the properties of a render, which are `null` until something is set.
+pub(crate) fn set_property(
+    properties: &mut serde_json::Value,
+    key: &str,
+    value: serde_json::Value,
+) {
+    if !properties.is_object() {
+        *properties = serde_json::json!({});
+    }
+    if let Some(map) = properties.as_object_mut() {
+        map.insert(key.to_owned(), value);
+    }
+}
+
+impl Document {
+    // Render top level blocks one by one in output order, so that callers can stream them.
+    fn render_blocks(
+        &self,
+        ctx: &mut RenderContext,
+        emit: &mut dyn FnMut(JsonRender) -> io::Result<()>,
+    ) -> io::Result<()> {
+        if ctx.options.synthesize_toc {
+            ctx.collect_headings(&self.children);
+        }
+        if ctx.options.include_headers_footers {
+            for h in render_headers(&self.section_property, ctx) {
+                emit(h)?;
+            }
+        }
+        let mut sections = 0;
+        for c in &self.children {
+            match c {
+                DocumentChild::Paragraph(p) => {
+                    emit(p.render_ascii_json(ctx))?;
+                    // Paragraphs with `sectPr` end a section.
+                    if let Some(section) = &p.property.section_property {
+                        emit(section::render_section(section, sections))?;
+                        sections += 1;
+                        if let Some(b) = section_break(section, ctx) {
+                            emit(b)?;
+                        }
+                    }
+                }
+                DocumentChild::Table(t) => emit(t.render_ascii_json(ctx))?,
+                DocumentChild::StructuredDataTag(t) => emit(t.render_ascii_json(ctx))?,
+                DocumentChild::TableOfContents(t) => emit(t.render_ascii_json(ctx))?,
+                _ => {}
+            }
+        }
+        emit(section::render_section(&self.section_property, sections))?;
+        // Footnotes are placed above the footers as on the page.
+        if let Some(footnotes) = ctx.render_footnotes() {
+            emit(footnotes)?;
+        }
+        if let Some(text_boxes) = ctx.render_text_boxes() {
+            emit(text_boxes)?;
+        }
+        if ctx.options.include_headers_footers {
+            for f in render_footers(&self.section_property, ctx) {
+                emit(f)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_ascii<W: io::Write>(
+        &self,
+        ctx: &mut RenderContext,
+        mut w: W,
+    ) -> io::Result<()> {
+        let mut first = true;
+        let separator = ctx
+            .options
+            .block_separator
+            .clone()
+            .unwrap_or_else(|| "\n".to_owned());
+        self.render_blocks(ctx, &mut |block| {
+            if block.node_type == RenderNodeType::Section {
+                return Ok(());
+            }
+            if !first {
+                w.write_all(separator.as_bytes())?;
+            }
+            first = false;
+            w.write_all(block.text.as_bytes())
+        })
+    }
+}
+
+impl Render for Document {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let mut children = vec![];
+        self.render_blocks(ctx, &mut |block| {
+            children.push(block);
+            Ok(())
+        })
+        .expect("collecting blocks should not fail");
+        JsonRender::new(RenderNodeType::Document)
+            .text(section::join_blocks(
+                &children,
+                ctx.options.block_separator.as_deref().unwrap_or("\n"),
+            ))
+            .children(children)
+    }
+
+    fn render_ascii_to<W: io::Write>(&self, w: W) -> io::Result<()> {
+        self.write_ascii(&mut RenderContext::new(), w)
+    }
+}
+
+impl Render for Paragraph {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let (in_toc, toc_closed) = ctx.scan_fields(self);
+        let marker = ctx.list_marker(self);
+        let mut children = render_paragraph_children(&self.children, ctx);
+        bookmark::set_bookmark_positions(&mut children, &mut 0);
+        let mut text = join_text(&children, "");
+        let mut properties = paragraph_properties(self, ctx);
+        if in_toc {
+            if let Some((entry, p)) = ctx.toc_entry(self, &text, toc_closed) {
+                text = entry;
+                if let serde_json::Value::Object(map) = p {
+                    for (k, v) in map {
+                        set_property(&mut properties, &k, v);
+                    }
+                }
+            }
+        }
+        if self.property.bidi == Some(true) {
+            set_property(&mut properties, "direction", "rtl".into());
+            if ctx.options.visual_text_direction {
+                text = direction::reverse_lines(&text);
+            }
+        }
+        let indent = marker.as_ref().map(|m| m.chars().count()).unwrap_or(0);
+        if let Some(marker) = marker {
+            text = format!("{}{}", marker, text);
+        }
+        if let Some(width) = ctx.options.tab_width {
+            text = tab::expand_tabs(&text, &tab::tab_stops(&self.property.tabs), width);
+        }
+        if let Some(width) = ctx.options.max_line_width {
+            text = wrap_text(&text, width, indent);
+        }
+        if let Some(marker) = &ctx.options.page_break_marker {
+            if self.property.page_break_before == Some(true) {
+                text = format!("{}\n{}", marker, text);
+            }
+        }
+        let mut render = JsonRender::new(RenderNodeType::Paragraph)
+            .text(text)
+            .children(children);
+        render.properties = properties;
+        ctx.visit(render, |v, r| v.visit_paragraph(self, r))
+    }
+}
+
+// Formatting set directly on the paragraph, `null` when there is none.
+fn paragraph_properties(p: &Paragraph, ctx: &RenderContext) -> serde_json::Value {
+    let mut properties = serde_json::Value::Null;
+    if let Some(style) = &p.property.style {
+        set_property(&mut properties, "styleId", style.val.clone().into());
+    }
+    if let Some(alignment) = &p.property.alignment {
+        set_property(&mut properties, "alignment", alignment.val.clone().into());
+    }
+    if let Some(level) = ctx.heading_level(p) {
+        set_property(&mut properties, "headingLevel", level.into());
+    }
+    if let Some(n) = &p.property.numbering_property {
+        if let (Some(id), Some(level)) = (&n.id, &n.level) {
+            set_property(
+                &mut properties,
+                "numbering",
+                serde_json::json!({ "id": id.id, "level": level.val }),
+            );
+        }
+    }
+    properties
+}
+
+// Sizes are in half points like `w:sz`.
+fn run_properties(p: &RunProperty, ctx: &RenderContext) -> serde_json::Value {
+    let mut properties = serde_json::Value::Null;
+    if let Some(style) = &p.style {
+        set_property(&mut properties, "styleId", style.val.clone().into());
+    }
+    if let Some(b) = &p.bold {
+        set_property(&mut properties, "bold", b.val.into());
+    }
+    if let Some(i) = &p.italic {
+        set_property(&mut properties, "italic", i.val.into());
+    }
+    if let Some(u) = &p.underline {
+        set_property(&mut properties, "underline", u.val.clone().into());
+    }
+    if let Some(s) = &p.strike {
+        set_property(&mut properties, "strike", s.val.into());
+    }
+    if let Some(sz) = &p.sz {
+        set_property(&mut properties, "size", sz.val.into());
+    }
+    if let Some(c) = &p.color {
+        set_property(&mut properties, "color", ctx.color(c).into());
+    }
+    if let Some(h) = &p.highlight {
+        set_property(&mut properties, "highlight", h.val.clone().into());
+    }
+    properties
+}
+
+fn section_break(section: &SectionProperty, ctx: &RenderContext) -> Option<JsonRender> {
+    let marker = ctx.options.section_break_marker.as_ref()?;
+    let mut render = JsonRender::new(RenderNodeType::Break).text(marker.clone());
+    render.properties = serde_json::json!({
+        "type": "section",
+        "sectionType": section.section_type.map(|t| t.to_string()),
+    });
+    Some(render)
+}
+
+fn render_paragraph_children(
+    children: &[ParagraphChild],
+    ctx: &mut RenderContext,
+) -> Vec<JsonRender> {
+    children
+        .iter()
+        .filter_map(|c| match c {
+            ParagraphChild::Run(r) => Some(r.render_ascii_json(ctx)),
+            ParagraphChild::Insert(i)
+                if ctx.options.track_changes != TrackChangesDisplay::Original =>
+            {
+                Some(i.render_ascii_json(ctx))
+            }
+            ParagraphChild::Delete(d)
+                if ctx.options.track_changes != TrackChangesDisplay::Final =>
+            {
+                Some(d.render_ascii_json(ctx))
+            }
+            ParagraphChild::Hyperlink(h) => Some(h.render_ascii_json(ctx)),
+            ParagraphChild::ComplexField(f) => Some(f.render_ascii_json(ctx)),
+            ParagraphChild::CommentStart(c) if ctx.options.include_comments => {
+                Some(c.render_ascii_json(ctx))
+            }
+            ParagraphChild::CommentEnd(c) if ctx.options.include_comments => {
+                Some(c.render_ascii_json(ctx))
+            }
+            ParagraphChild::OfficeMath(m) => Some(m.render_ascii_json(ctx)),
+            ParagraphChild::StructuredDataTag(t) => Some(t.render_ascii_json(ctx)),
+            ParagraphChild::BookmarkStart(b) => Some(b.render_ascii_json(ctx)),
+            _ => None,
+        })
+        .collect()
+}
+
+impl Render for Run {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let mut text = String::new();
+        let mut children = vec![];
+        if self.run_property.vanish.is_some() && !ctx.options.include_hidden {
+            return JsonRender::new(RenderNodeType::Run);
+        }
+        for c in &self.children {
+            match c {
+                RunChild::Text(t) => text.push_str(&replace_escaped(&t.text)),
+                RunChild::DeleteText(t) => text.push_str(&replace_escaped(&t.text)),
+                RunChild::Tab(_) => text.push('\t'),
+                RunChild::Break(b) => {
+                    let r = b.render_ascii_json(ctx);
+                    text.push_str(&r.text);
+                    children.push(r);
+                }
+                RunChild::Drawing(d) => {
+                    let r = d.render_ascii_json(ctx);
+                    if !r.text.is_empty() {
+                        text.push_str(&r.text);
+                        children.push(r);
+                    }
+                }
+                RunChild::Shape(s) => {
+                    for content in s.text_boxes() {
+                        let r = render_text_box(&content.children, ctx);
+                        text.push_str(&r.text);
+                        children.push(r);
+                    }
+                }
+                RunChild::FootnoteReference(f) => {
+                    text.push_str(&format!("[{}]", ctx.add_footnote_reference(f)))
+                }
+                RunChild::FieldChar(FieldChar {
+                    form_field: Some(f),
+                    ..
+                }) => {
+                    let r = f.render_ascii_json(ctx);
+                    text.push_str(&r.text);
+                    children.push(r);
+                }
+                _ => {}
+            }
+        }
+        let mut render = JsonRender::new(RenderNodeType::Run)
+            .text(text)
+            .children(children);
+        render.properties = run_properties(&self.run_property, ctx);
+        ctx.visit(render, |v, r| v.visit_run(self, r))
+    }
+}
+
+// Line breaks within a paragraph, as opposed to the separators between blocks.
+impl Render for Break {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let marker = match self.break_type {
+            BreakType::Page => ctx.options.page_break_marker.as_ref(),
+            BreakType::Column => ctx.options.column_break_marker.as_ref(),
+            _ => None,
+        };
+        let text = match marker {
+            Some(marker) => format!("\n{}\n", marker),
+            None => "\n".to_owned(),
+        };
+        let mut render = JsonRender::new(RenderNodeType::Break).text(text);
+        render.properties = serde_json::json!({ "type": self.break_type.to_string() });
+        render
+    }
+}
+
+impl Render for Hyperlink {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let children = render_paragraph_children(&self.children, ctx);
+        let mut text = join_text(&children, "");
+        let mut render = JsonRender::new(RenderNodeType::Hyperlink);
+        if let Some(url) = ctx.hyperlink_url(&self.link) {
+            // Anchors point inside the document, so only show external targets.
+            if !url.starts_with('#') && text.trim() != url {
+                match ctx.options.hyperlink_display {
+                    HyperlinkDisplay::TextWithUrl => text = format!("{} ({})", text, url),
+                    HyperlinkDisplay::Url => text = url.clone(),
+                    HyperlinkDisplay::Text => {}
+                }
+            }
+            render.properties = serde_json::json!({ "url": url });
+        }
+        let render = render.text(text).children(children);
+        ctx.visit(render, |v, r| v.visit_hyperlink(self, r))
+    }
+}
+
+impl Render for Insert {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let children: Vec<JsonRender> = self
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                InsertChild::Run(r) => Some(r.render_ascii_json(ctx)),
+                InsertChild::CommentStart(c) if ctx.options.include_comments => {
+                    Some(c.render_ascii_json(ctx))
+                }
+                InsertChild::CommentEnd(c) if ctx.options.include_comments => {
+                    Some(c.render_ascii_json(ctx))
+                }
+                // Insertions deleted later are in neither final nor original text.
+                InsertChild::Delete(d)
+                    if ctx.options.track_changes == TrackChangesDisplay::Markup =>
+                {
+                    Some(d.render_ascii_json(ctx))
+                }
+                _ => None,
+            })
+            .collect();
+        let mut text = join_text(&children, "");
+        if ctx.options.track_changes == TrackChangesDisplay::Markup && !text.is_empty() {
+            text = format!("{{+{}+}}", text);
+        }
+        let mut render = JsonRender::new(RenderNodeType::Insert)
+            .text(text)
+            .children(children);
+        render.properties = serde_json::json!({ "author": self.author, "date": self.date });
+        if self.moved {
+            set_property(&mut render.properties, "moved", true.into());
+        }
+        ctx.visit(render, |v, r| v.visit_insert(self, r))
+    }
+}
+
+impl Render for Delete {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let children: Vec<JsonRender> = self
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DeleteChild::Run(r) => Some(r.render_ascii_json(ctx)),
+                DeleteChild::CommentStart(c) if ctx.options.include_comments => {
+                    Some(c.render_ascii_json(ctx))
+                }
+                DeleteChild::CommentEnd(c) if ctx.options.include_comments => {
+                    Some(c.render_ascii_json(ctx))
+                }
+                _ => None,
+            })
+            .collect();
+        let mut text = join_text(&children, "");
+        if ctx.options.track_changes == TrackChangesDisplay::Markup && !text.is_empty() {
+            text = format!("{{-{}-}}", text);
+        }
+        let mut render = JsonRender::new(RenderNodeType::Delete)
+            .text(text)
+            .children(children);
+        render.properties = serde_json::json!({ "author": self.author, "date": self.date });
+        if self.moved {
+            set_property(&mut render.properties, "moved", true.into());
+        }
+        ctx.visit(render, |v, r| v.visit_delete(self, r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_render_paragraph() {
+        let p = Paragraph::new()
+            .add_run(Run::new().add_text("Hello").add_tab())
+            .add_insert(Insert::new(Run::new().add_text("inserted")))
+            .add_delete(Delete::new().add_run(Run::new().add_delete_text("deleted")))
+            .add_hyperlink(
+                Hyperlink::new("https://example.com", HyperlinkType::External)
+                    .add_run(Run::new().add_text(" & link")),
+            );
+        assert_eq!(
+            p.render_ascii(),
+            "Hello\tinserted & link (https://example.com)"
+        );
+    }
+
+    #[test]
+    fn test_render_paragraph_tabs() {
+        let p = Paragraph::new()
+            .add_tab(Tab::new().val(TabValueType::Right).pos(2160))
+            .add_run(Run::new().add_text("Qty").add_tab().add_text("12"));
+        assert_eq!(p.render_ascii(), "Qty\t12");
+        let options = RenderOptions::new().tab_width(4);
+        assert_eq!(p.render_with(&options).text, "Qty          12");
+        let p = Paragraph::new().add_run(Run::new().add_text("a").add_tab().add_text("b"));
+        assert_eq!(p.render_with(&options).text, "a   b");
+    }
+
+    #[test]
+    fn test_render_json_tree() {
+        let p = Paragraph::new().add_run(Run::new().add_text("Hello"));
+        let r = p.render_ascii_json(&mut RenderContext::new());
+        assert_eq!(r.node_type, RenderNodeType::Paragraph);
+        assert_eq!(r.properties, serde_json::Value::Null);
+        assert_eq!(
+            r.children,
+            vec![JsonRender::new(RenderNodeType::Run).text("Hello")]
+        );
+    }
+
+    #[test]
+    fn test_render_properties() {
+        let p = Paragraph::new()
+            .style("Heading1")
+            .align(AlignmentType::Center)
+            .add_run(Run::new().add_text("Title").bold().size(32))
+            .add_run(Run::new().add_text("!"));
+        let r = p.render_ascii_json(&mut RenderContext::new());
+        assert_eq!(
+            r.properties,
+            serde_json::json!({ "styleId": "Heading1", "alignment": "center", "headingLevel": 1 })
+        );
+        assert_eq!(
+            r.children[0].properties,
+            serde_json::json!({ "bold": true, "size": 32 })
+        );
+        assert_eq!(r.children[1].properties, serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_render_theme_color() {
+        let mut run = Run::new().add_text("Accent");
+        run.run_property.color = Some(Color::new("000000").theme_color("accent1"));
+        let p = Paragraph::new().add_run(run);
+        let r = p.render_ascii_json(&mut RenderContext::new());
+        assert_eq!(
+            r.children[0].properties,
+            serde_json::json!({ "color": "000000" })
+        );
+        let mut theme = Theme::default();
+        theme.color_scheme.accent1 = "4472C4".to_owned();
+        let r = p.render_ascii_json(&mut RenderContext::new().theme(&theme));
+        assert_eq!(
+            r.children[0].properties,
+            serde_json::json!({ "color": "4472C4" })
+        );
+    }
+
+    #[test]
+    fn test_render_json_string() {
+        let docx = Docx::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hi")));
+        let json = docx.render_json_string();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::json!({
+                "nodeType": "document",
+                "text": "Hi",
+                "properties": null,
+                "children": [{
+                    "nodeType": "paragraph",
+                    "text": "Hi",
+                    "properties": null,
+                    "children": [{
+                        "nodeType": "run",
+                        "text": "Hi",
+                        "properties": null,
+                        "children": [],
+                    }],
+                }, {
+                    "nodeType": "section",
+                    "text": "",
+                    "properties": {
+                        "index": 0,
+                        "pageSize": { "width": 11906, "height": 16838 },
+                        "orientation": "portrait",
+                        "margins": {
+                            "top": 1985,
+                            "left": 1701,
+                            "bottom": 1701,
+                            "right": 1701,
+                            "header": 851,
+                            "footer": 992,
+                            "gutter": 0,
+                        },
+                        "columns": 1,
+                        "sectionType": null,
+                    },
+                    "children": [],
+                }],
+            })
+        );
+        let restored: JsonRender = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, docx.render_ascii_json());
+        let minimal: JsonRender =
+            serde_json::from_str(r#"{ "nodeType": "tableRow", "text": "a" }"#).unwrap();
+        assert_eq!(minimal, JsonRender::new(RenderNodeType::TableRow).text("a"));
+    }
+
+    #[test]
+    fn test_render_ascii_to() {
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("one")))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("two")));
+        let mut buf = vec![];
+        docx.render_ascii_to(&mut buf).unwrap();
+        assert_eq!(String::from_utf8(buf).unwrap(), docx.render_ascii());
+
+        let mut buf = vec![];
+        Run::new()
+            .add_text("run")
+            .render_ascii_to(&mut buf)
+            .unwrap();
+        assert_eq!(buf, b"run");
+    }
+
+    #[test]
+    fn test_render_docx() {
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Title")))
+            .add_table(Table::new(vec![
+                TableRow::new(vec![
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("a"))),
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("b"))),
+                ]),
+                TableRow::new(vec![
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("c"))),
+                    TableCell::new()
+                        .add_paragraph(Paragraph::new().add_run(Run::new().add_text("d"))),
+                ]),
+            ]));
+        assert_eq!(
+            docx.render_ascii(),
+            "Title\n+---+---+\n| a | b |\n+---+---+\n| c | d |\n+---+---+"
+        );
+    }
+
+    #[test]
+    fn test_render_hyperlink_url() {
+        let hyperlinks = vec![(
+            "rId3".to_owned(),
+            "https://example.com/a".to_owned(),
+            "External".to_owned(),
+        )];
+        let mut ctx = RenderContext::new().hyperlinks(&hyperlinks);
+        let mut h =
+            Hyperlink::new("", HyperlinkType::External).add_run(Run::new().add_text("docs"));
+        h.link = HyperlinkData::External {
+            rid: "rId3".to_owned(),
+            path: String::new(),
+        };
+        let r = h.render_ascii_json(&mut ctx);
+        assert_eq!(r.text, "docs (https://example.com/a)");
+        assert_eq!(
+            r.properties,
+            serde_json::json!({ "url": "https://example.com/a" })
+        );
+
+        let anchor =
+            Hyperlink::new("_Toc1", HyperlinkType::Anchor).add_run(Run::new().add_text("Intro"));
+        let r = anchor.render_ascii_json(&mut ctx);
+        assert_eq!(r.text, "Intro");
+        assert_eq!(r.properties, serde_json::json!({ "url": "#_Toc1" }));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_render_with_options() {
+        let p = Paragraph::new()
+            .add_run(Run::new().add_text("visible "))
+            .add_run(Run::new().add_text("hidden ").vanish())
+            .add_delete(Delete::new().add_run(Run::new().add_delete_text("deleted ")))
+            .add_hyperlink(
+                Hyperlink::new("https://example.com", HyperlinkType::External)
+                    .add_run(Run::new().add_text("link")),
+            );
+        assert_eq!(
+            p.render_with(&RenderOptions::new()).text,
+            "visible link (https://example.com)"
+        );
+        let options = RenderOptions::new()
+            .include_hidden(true)
+            .include_deleted(true)
+            .hyperlink_display(HyperlinkDisplay::Text)
+            .max_line_width(16);
+        assert_eq!(p.render_with(&options).text, "visible hidden\ndeleted link");
+    }
+
+    #[test]
+    fn test_render_track_changes() {
+        let p = Paragraph::new()
+            .add_run(Run::new().add_text("The "))
+            .add_delete(Delete::new().add_run(Run::new().add_delete_text("old")))
+            .add_insert(Insert::new(Run::new().add_text("new")))
+            .add_run(Run::new().add_text(" text"));
+        let render = |display| {
+            p.render_with(&RenderOptions::new().track_changes(display))
+                .text
+        };
+        assert_eq!(render(TrackChangesDisplay::Final), "The new text");
+        assert_eq!(render(TrackChangesDisplay::Original), "The old text");
+        assert_eq!(
+            render(TrackChangesDisplay::Markup),
+            "The {-old-}{+new+} text"
+        );
+    }
+
+    #[test]
+    fn test_render_break_markers() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new().add_run(
+                    Run::new()
+                        .add_text("one")
+                        .add_break(BreakType::Page)
+                        .add_text("two")
+                        .add_break(BreakType::Column)
+                        .add_text("three"),
+                ),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("end of section"))
+                    .section_property(SectionProperty::new()),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("next"))
+                    .page_break_before(true),
+            );
+        assert_eq!(docx.render_ascii(), "one\ntwo\nthree\nend of section\nnext");
+        assert_eq!(
+            docx.render_with(&RenderOptions::new().break_markers()).text,
+            "one\n----- page break -----\ntwo\n----- column break -----\nthree\nend of section\n----- section break -----\n----- page break -----\nnext"
+        );
+    }
+
+    #[test]
+    fn test_render_line_breaks() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new().add_run(
+                    Run::new()
+                        .add_text("line")
+                        .add_break(BreakType::TextWrapping)
+                        .add_text("wrap"),
+                ),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("next")));
+        let r = docx.render_with(&RenderOptions::new().block_separator("\n\n"));
+        assert_eq!(r.text, "line\nwrap\n\nnext");
+        let b = &r.children[0].children[0].children[0];
+        assert_eq!(b.node_type, RenderNodeType::Break);
+        assert_eq!(b.properties, serde_json::json!({ "type": "textWrapping" }));
+    }
+
+    #[test]
+    fn test_heading_level() {
+        let styles =
+            Styles::new().add_style(Style::new("Title1", StyleType::Paragraph).name("Heading 2"));
+        let ctx = RenderContext::new().styles(&styles);
+        assert_eq!(
+            ctx.heading_level(&Paragraph::new().style("Title1")),
+            Some(2)
+        );
+        assert_eq!(
+            ctx.heading_level(&Paragraph::new().style("Heading3")),
+            Some(3)
+        );
+        assert_eq!(ctx.heading_level(&Paragraph::new().style("Normal")), None);
+    }
+}
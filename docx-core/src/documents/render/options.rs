@@ -0,0 +1,227 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderBorderStyle {
+    // +---+---+
+    #[default]
+    Ascii,
+    // ┌───┬───┐
+    Unicode,
+    // Columns are only separated by spaces.
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HyperlinkDisplay {
+    // text (https://example.com)
+    #[default]
+    TextWithUrl,
+    Text,
+    Url,
+}
+
+// How `w:ins` and `w:del` revisions are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrackChangesDisplay {
+    // Text after accepting all changes.
+    #[default]
+    Final,
+    // Text before the changes.
+    Original,
+    // {+inserted+} and {-deleted-}
+    Markup,
+}
+
+// Where the content of text boxes and shapes is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextBoxDisplay {
+    // In place of the anchoring run.
+    #[default]
+    Inline,
+    // `[text box 1]` in place, with the content listed after the body.
+    Appendix,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RenderOptions {
+    // Wrap paragraphs at word boundaries when set.
+    pub max_line_width: Option<usize>,
+    // Include runs hidden with `w:vanish`.
+    pub include_hidden: bool,
+    pub track_changes: TrackChangesDisplay,
+    // Surface comments as `⟦comment #1: text⟧` markers.
+    pub include_comments: bool,
+    // Render headers before and footers after the body.
+    pub include_headers_footers: bool,
+    pub table_border: RenderBorderStyle,
+    pub text_box_display: TextBoxDisplay,
+    pub hyperlink_display: HyperlinkDisplay,
+    // Marker lines for explicit breaks. Breaks are rendered as plain new lines when unset.
+    pub page_break_marker: Option<String>,
+    pub column_break_marker: Option<String>,
+    pub section_break_marker: Option<String>,
+    // Expand tabs to spaces using the paragraph tab stops, falling back to stops every
+    // `tab_width` columns. Tabs are kept as `\t` when unset.
+    pub tab_width: Option<usize>,
+    // Add the LaTeX source of math to the JSON properties.
+    pub math_latex: bool,
+    // Outline the headings in place of TOC fields without cached entries.
+    pub synthesize_toc: bool,
+    // Reverse right-to-left paragraphs and lay vertical cells out top to bottom. The
+    // direction is added to the JSON properties either way.
+    pub visual_text_direction: bool,
+    // Separator between top level blocks, `\n` when unset. Line breaks within paragraphs are
+    // always a single `\n`, so `\n\n` keeps them apart from paragraph boundaries.
+    pub block_separator: Option<String>,
+    // Show bookmarks as `⟦#name⟧` anchors. They are in the JSON tree either way.
+    pub bookmark_anchors: bool,
+}
+
+impl RenderOptions {
+    pub fn new() -> RenderOptions {
+        Default::default()
+    }
+
+    pub fn max_line_width(mut self, width: usize) -> Self {
+        self.max_line_width = Some(width);
+        self
+    }
+
+    pub fn include_hidden(mut self, include: bool) -> Self {
+        self.include_hidden = include;
+        self
+    }
+
+    pub fn track_changes(mut self, display: TrackChangesDisplay) -> Self {
+        self.track_changes = display;
+        self
+    }
+
+    // Kept from before `track_changes`. Deleted text is shown in the original text only.
+    #[deprecated(note = "Use `track_changes` with `TrackChangesDisplay::Original` or `Final`")]
+    pub fn include_deleted(self, include: bool) -> Self {
+        self.track_changes(if include {
+            TrackChangesDisplay::Original
+        } else {
+            TrackChangesDisplay::Final
+        })
+    }
+
+    pub fn include_comments(mut self, include: bool) -> Self {
+        self.include_comments = include;
+        self
+    }
+
+    pub fn include_headers_footers(mut self, include: bool) -> Self {
+        self.include_headers_footers = include;
+        self
+    }
+
+    pub fn page_break_marker(mut self, marker: impl Into<String>) -> Self {
+        self.page_break_marker = Some(marker.into());
+        self
+    }
+
+    pub fn column_break_marker(mut self, marker: impl Into<String>) -> Self {
+        self.column_break_marker = Some(marker.into());
+        self
+    }
+
+    pub fn section_break_marker(mut self, marker: impl Into<String>) -> Self {
+        self.section_break_marker = Some(marker.into());
+        self
+    }
+
+    // Default markers such as `----- page break -----` for all break kinds.
+    pub fn break_markers(self) -> Self {
+        self.page_break_marker("----- page break -----")
+            .column_break_marker("----- column break -----")
+            .section_break_marker("----- section break -----")
+    }
+
+    pub fn table_border(mut self, style: RenderBorderStyle) -> Self {
+        self.table_border = style;
+        self
+    }
+
+    pub fn text_box_display(mut self, display: TextBoxDisplay) -> Self {
+        self.text_box_display = display;
+        self
+    }
+
+    pub fn hyperlink_display(mut self, display: HyperlinkDisplay) -> Self {
+        self.hyperlink_display = display;
+        self
+    }
+
+    pub fn tab_width(mut self, width: usize) -> Self {
+        self.tab_width = Some(width);
+        self
+    }
+
+    pub fn math_latex(mut self, include: bool) -> Self {
+        self.math_latex = include;
+        self
+    }
+
+    pub fn synthesize_toc(mut self, synthesize: bool) -> Self {
+        self.synthesize_toc = synthesize;
+        self
+    }
+
+    pub fn visual_text_direction(mut self, visual: bool) -> Self {
+        self.visual_text_direction = visual;
+        self
+    }
+
+    pub fn block_separator(mut self, separator: impl Into<String>) -> Self {
+        self.block_separator = Some(separator.into());
+        self
+    }
+
+    pub fn bookmark_anchors(mut self, include: bool) -> Self {
+        self.bookmark_anchors = include;
+        self
+    }
+}
+
+// Greedy word wrap. Continuation lines are indented by `indent` columns,
+// and words longer than the width are kept as is.
+pub(crate) fn wrap_text(text: &str, width: usize, indent: usize) -> String {
+    let mut lines = vec![];
+    for line in text.split('\n') {
+        let mut current = String::new();
+        // Whether `current` has a word after the indentation.
+        let mut has_word = false;
+        for word in line.split(' ') {
+            if has_word && current.chars().count() + 1 + word.chars().count() > width {
+                lines.push(current);
+                current = " ".repeat(indent);
+            } else if has_word {
+                current.push(' ');
+            }
+            current.push_str(word);
+            has_word = true;
+        }
+        lines.push(current);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(
+            wrap_text("1. the quick brown fox jumps", 12, 3),
+            "1. the quick\n   brown fox\n   jumps"
+        );
+        assert_eq!(
+            wrap_text("extraordinary\nab cd", 5, 0),
+            "extraordinary\nab cd"
+        );
+    }
+}
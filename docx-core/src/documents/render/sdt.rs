@@ -0,0 +1,109 @@
+use super::*;
+
+// Content controls render their content. Runs of inline controls stay on the line, and
+// paragraphs and tables of block controls are separated by `\n`.
+impl Render for StructuredDataTag {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let mut text = String::new();
+        let mut children = vec![];
+        for c in &self.children {
+            let (child, block) = match c {
+                StructuredDataTagChild::Run(r) => (r.render_ascii_json(ctx), false),
+                StructuredDataTagChild::Paragraph(p) => (p.render_ascii_json(ctx), true),
+                StructuredDataTagChild::Table(t) => (t.render_ascii_json(ctx), true),
+                StructuredDataTagChild::StructuredDataTag(t) => {
+                    let block = t.children.iter().any(|c| {
+                        matches!(
+                            c,
+                            StructuredDataTagChild::Paragraph(_) | StructuredDataTagChild::Table(_)
+                        )
+                    });
+                    (t.render_ascii_json(ctx), block)
+                }
+                StructuredDataTagChild::CommentStart(c) if ctx.options.include_comments => {
+                    (c.render_ascii_json(ctx), false)
+                }
+                StructuredDataTagChild::CommentEnd(c) if ctx.options.include_comments => {
+                    (c.render_ascii_json(ctx), false)
+                }
+                _ => continue,
+            };
+            if block && !children.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&child.text);
+            children.push(child);
+        }
+        let property = &self.property;
+        // Check boxes show a glyph such as ☒ from a symbol font.
+        if let Some(StructuredDataTagControl::CheckBox { checked }) = property.control {
+            text = form_field::check_box_text(checked).to_owned();
+        }
+        let mut render = JsonRender::new(RenderNodeType::StructuredDataTag)
+            .text(text)
+            .children(children);
+        render.properties = serde_json::json!({
+            "tag": property.tag,
+            "alias": property.alias,
+            "showingPlaceholder": property.showing_placeholder,
+            "control": property.control,
+        });
+        ctx.visit(render, |v, r| v.visit_structured_data_tag(self, r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_render_block_sdt() {
+        let sdt = StructuredDataTag::new()
+            .alias("Customer")
+            .tag("customer")
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Alice")))
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()
+                .add_paragraph(
+                    Paragraph::new().add_run(Run::new().add_text("x")),
+                )])]));
+        let docx = Docx::new().add_structured_data_tag(sdt);
+        let r = docx.render_ascii_json();
+        let sdt = &r.children[0];
+        assert_eq!(sdt.node_type, RenderNodeType::StructuredDataTag);
+        assert_eq!(sdt.text, "Alice\n+---+\n| x |\n+---+");
+        assert_eq!(
+            sdt.properties,
+            serde_json::json!({
+                "tag": "customer",
+                "alias": "Customer",
+                "showingPlaceholder": false,
+                "control": null,
+            })
+        );
+    }
+
+    #[test]
+    fn test_render_inline_sdt() {
+        let p = Paragraph::new()
+            .add_run(Run::new().add_text("Name: "))
+            .add_structured_data_tag(
+                StructuredDataTag::new()
+                    .showing_placeholder()
+                    .add_run(Run::new().add_text("Click here")),
+            );
+        let r = p.render_ascii_json(&mut RenderContext::new());
+        assert_eq!(r.text, "Name: Click here");
+        assert_eq!(
+            r.children[1].properties,
+            serde_json::json!({
+                "tag": null,
+                "alias": null,
+                "showingPlaceholder": true,
+                "control": null,
+            })
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use super::*;
+use crate::types::PageOrientationType;
+
+// Page setup of a section, emitted after its last block. Sizes are in twips. Sections have
+// no text, so they are left out of the ASCII output.
+pub(crate) fn render_section(section: &SectionProperty, index: usize) -> JsonRender {
+    let size = &section.page_size;
+    let orientation = match size.orient {
+        Some(o) => o,
+        None if size.w > size.h => PageOrientationType::Landscape,
+        None => PageOrientationType::Portrait,
+    };
+    let mut render = JsonRender::new(RenderNodeType::Section);
+    render.properties = serde_json::json!({
+        "index": index,
+        "pageSize": { "width": size.w, "height": size.h },
+        "orientation": orientation.to_string(),
+        "margins": section.page_margin,
+        "columns": section.columns,
+        "sectionType": section.section_type.map(|t| t.to_string()),
+    });
+    render
+}
+
+pub(crate) fn join_blocks(blocks: &[JsonRender], sep: &str) -> String {
+    blocks
+        .iter()
+        .filter(|b| b.node_type != RenderNodeType::Section)
+        .map(|b| b.text.as_str())
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_render_sections() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Portrait"))
+                    .section_property(SectionProperty::new()),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Landscape")))
+            .page_size(16838, 11906);
+        let r = docx.render_ascii_json();
+        assert_eq!(r.text, "Portrait\nLandscape");
+        let sections: Vec<&JsonRender> = r
+            .children
+            .iter()
+            .filter(|c| c.node_type == RenderNodeType::Section)
+            .collect();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].properties["orientation"], "portrait");
+        assert_eq!(
+            sections[1].properties,
+            serde_json::json!({
+                "index": 1,
+                "pageSize": { "width": 16838, "height": 11906 },
+                "orientation": "landscape",
+                "margins": {
+                    "top": 1985,
+                    "left": 1701,
+                    "bottom": 1701,
+                    "right": 1701,
+                    "header": 851,
+                    "footer": 992,
+                    "gutter": 0,
+                },
+                "columns": 1,
+                "sectionType": null,
+            })
+        );
+    }
+}
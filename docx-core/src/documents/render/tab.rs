@@ -0,0 +1,104 @@
+use super::*;
+use crate::types::{TabLeaderType, TabValueType};
+
+// Monospace column width, 10 characters per inch.
+const TWIPS_PER_COLUMN: usize = 144;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TabStop {
+    column: usize,
+    val: TabValueType,
+    leader: char,
+}
+
+// Custom tab stops of the paragraph in column order. Cleared stops are dropped.
+pub(crate) fn tab_stops(tabs: &[Tab]) -> Vec<TabStop> {
+    let mut stops: Vec<TabStop> = tabs
+        .iter()
+        .filter(|t| t.val != Some(TabValueType::Clear))
+        .filter_map(|t| {
+            let leader = match t.leader {
+                Some(TabLeaderType::Dot) => '.',
+                Some(TabLeaderType::Hyphen) => '-',
+                Some(TabLeaderType::Underscore) | Some(TabLeaderType::Heavy) => '_',
+                Some(TabLeaderType::MiddleDot) => '·',
+                _ => ' ',
+            };
+            Some(TabStop {
+                column: t.pos? / TWIPS_PER_COLUMN,
+                val: t.val.unwrap_or(TabValueType::Left),
+                leader,
+            })
+        })
+        .collect();
+    stops.sort_by_key(|s| s.column);
+    stops
+}
+
+// Replace tabs with padding up to the next tab stop. Right, center and decimal stops
+// align the text up to the following tab, and positions past the last stop fall back to
+// every `default_width` columns.
+pub(crate) fn expand_tabs(text: &str, stops: &[TabStop], default_width: usize) -> String {
+    let default_width = default_width.max(1);
+    text.split('\n')
+        .map(|line| {
+            let mut segments = line.split('\t');
+            let mut s = segments.next().unwrap_or("").to_owned();
+            for segment in segments {
+                let column = s.chars().count();
+                let len = segment.chars().count();
+                let fill = match stops.iter().find(|t| t.column > column) {
+                    Some(stop) => {
+                        let start = match stop.val {
+                            TabValueType::Right | TabValueType::End => {
+                                stop.column.saturating_sub(len)
+                            }
+                            TabValueType::Center => stop.column.saturating_sub(len / 2),
+                            TabValueType::Decimal => {
+                                let point = segment.find('.').unwrap_or(segment.len());
+                                stop.column.saturating_sub(segment[..point].chars().count())
+                            }
+                            _ => stop.column,
+                        };
+                        let width = start.saturating_sub(column).max(1);
+                        stop.leader.to_string().repeat(width)
+                    }
+                    None => " ".repeat(default_width - column % default_width),
+                };
+                s.push_str(&fill);
+                s.push_str(segment);
+            }
+            s
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_expand_default_tabs() {
+        assert_eq!(expand_tabs("a\tbc\td\n\tx", &[], 4), "a   bc  d\n    x");
+    }
+
+    #[test]
+    fn test_expand_tab_stops() {
+        let stops = tab_stops(&[
+            Tab::new().pos(1440),
+            Tab::new()
+                .val(TabValueType::Right)
+                .leader(TabLeaderType::Dot)
+                .pos(2880),
+            Tab::new().val(TabValueType::Decimal).pos(4320),
+        ]);
+        assert_eq!(
+            expand_tabs("Name\tPage\t1.5\tend", &stops, 8),
+            "Name      Page...1.5       end"
+        );
+    }
+}
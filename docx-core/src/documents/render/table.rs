@@ -0,0 +1,548 @@
+use super::*;
+use crate::types::VMergeType;
+
+// Character count without ANSI escape sequences such as `\x1b[1m`.
+fn display_len(line: &str) -> usize {
+    let mut len = 0;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            len += 1;
+        }
+    }
+    len
+}
+
+// Display width of the widest line.
+fn text_width(text: &str) -> usize {
+    text.lines().map(display_len).max().unwrap_or(0)
+}
+
+fn pad(text: &str, width: usize) -> String {
+    let len = display_len(text);
+    format!("{}{}", text, " ".repeat(width.saturating_sub(len)))
+}
+
+// Rendered cell with its merge properties.
+pub(crate) struct GridCell {
+    text: String,
+    // Number of grid columns covered by `gridSpan`.
+    span: usize,
+    // `vMerge` continuation of the cell above.
+    continued: bool,
+}
+
+impl GridCell {
+    pub(crate) fn new(cell: &TableCell, text: String) -> GridCell {
+        GridCell {
+            text,
+            span: cell
+                .property
+                .grid_span
+                .as_ref()
+                .map(|s| s.val)
+                .unwrap_or(1)
+                .max(1),
+            continued: cell
+                .property
+                .vertical_merge
+                .as_ref()
+                .map(|v| v.val == VMergeType::Continue)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+struct Slot {
+    col: usize,
+    span: usize,
+    continued: bool,
+    // Part of vertically merged cells, whose lines flow through the merged rows.
+    merged: bool,
+    lines: Vec<String>,
+}
+
+// Whether a cell of the row starts at the column boundary.
+fn starts_at(row: &[Slot], col: usize, columns: usize) -> bool {
+    col == 0 || col == columns || row.iter().any(|s| s.col == col)
+}
+
+fn junction(style: RenderBorderStyle, up: bool, down: bool, left: bool, right: bool) -> char {
+    if style == RenderBorderStyle::Ascii {
+        return match (up || down, left || right) {
+            (true, true) => '+',
+            (true, false) => '|',
+            (false, true) => '-',
+            (false, false) => ' ',
+        };
+    }
+    match (up, down, left, right) {
+        (true, true, true, true) => '┼',
+        (true, true, true, false) => '┤',
+        (true, true, false, true) => '├',
+        (true, false, true, true) => '┴',
+        (false, true, true, true) => '┬',
+        (true, false, true, false) => '┘',
+        (true, false, false, true) => '└',
+        (false, true, true, false) => '┐',
+        (false, true, false, true) => '┌',
+        (_, _, false, false) if up || down => '│',
+        (false, false, _, _) if left || right => '─',
+        _ => ' ',
+    }
+}
+
+// Horizontal border between two rows, `None` for the top and bottom edges.
+// The border is left open below vertically merged cells.
+fn rule_line(
+    above: Option<&[Slot]>,
+    below: Option<&[Slot]>,
+    widths: &[usize],
+    style: RenderBorderStyle,
+) -> String {
+    let columns = widths.len();
+    let open: Vec<bool> = (0..columns)
+        .map(|c| {
+            below.map_or(false, |r| {
+                r.iter()
+                    .any(|s| s.continued && s.merged && s.col <= c && c < s.col + s.span)
+            })
+        })
+        .collect();
+    let line = if style == RenderBorderStyle::Ascii {
+        '-'
+    } else {
+        '─'
+    };
+    let mut s = String::new();
+    for c in 0..=columns {
+        let up = above.map_or(false, |r| starts_at(r, c, columns));
+        let down = below.map_or(false, |r| starts_at(r, c, columns));
+        let left = c > 0 && !open[c - 1];
+        let right = c < columns && !open[c];
+        s.push(junction(style, up, down, left, right));
+        if c < columns {
+            let fill = if open[c] { ' ' } else { line };
+            s.push_str(&fill.to_string().repeat(widths[c] + 2));
+        }
+    }
+    s
+}
+
+// Lay out rows of cells on a grid, returning the whole table and the lines of each row.
+// Spanned cells cover the width of their columns, and vertically merged cells flow their
+// lines through the merged rows.
+pub(crate) fn render_grid(
+    rows: &[Vec<GridCell>],
+    style: RenderBorderStyle,
+) -> (String, Vec<String>) {
+    let mut slots: Vec<Vec<Slot>> = rows
+        .iter()
+        .map(|r| {
+            let mut col = 0;
+            r.iter()
+                .map(|c| {
+                    let slot = Slot {
+                        col,
+                        span: c.span,
+                        continued: c.continued,
+                        merged: false,
+                        lines: c.text.lines().map(|l| l.to_owned()).collect(),
+                    };
+                    col += c.span;
+                    slot
+                })
+                .collect()
+        })
+        .collect();
+    let row_end = |r: &[Slot]| r.last().map(|s| s.col + s.span).unwrap_or(0);
+    let columns = slots.iter().map(|r| row_end(r)).max().unwrap_or(0);
+    for r in slots.iter_mut() {
+        for col in row_end(r)..columns {
+            r.push(Slot {
+                col,
+                span: 1,
+                continued: false,
+                merged: false,
+                lines: vec![],
+            });
+        }
+    }
+
+    // (first row, column, last row) of vertically merged cells.
+    let mut groups = vec![];
+    for (i, r) in slots.iter().enumerate() {
+        for s in r.iter().filter(|s| !s.continued) {
+            let mut last = i;
+            while slots
+                .get(last + 1)
+                .map_or(false, |n| n.iter().any(|n| n.continued && n.col == s.col))
+            {
+                last += 1;
+            }
+            if last > i {
+                groups.push((i, s.col, last));
+            }
+        }
+    }
+    for &(first, col, last) in &groups {
+        for r in &mut slots[first..=last] {
+            for s in r.iter_mut().filter(|s| s.col == col) {
+                s.merged = true;
+            }
+        }
+    }
+
+    let sep = if style == RenderBorderStyle::None {
+        2
+    } else {
+        3
+    };
+    let lines_width = |s: &Slot| s.lines.iter().map(|l| text_width(l)).max().unwrap_or(0);
+    let mut widths = vec![0; columns];
+    for s in slots.iter().flatten().filter(|s| s.span == 1) {
+        widths[s.col] = widths[s.col].max(lines_width(s));
+    }
+    for s in slots.iter().flatten().filter(|s| s.span > 1) {
+        let available = widths[s.col..s.col + s.span].iter().sum::<usize>() + sep * (s.span - 1);
+        let width = lines_width(s);
+        if width > available {
+            widths[s.col + s.span - 1] += width - available;
+        }
+    }
+
+    let mut heights: Vec<usize> = slots
+        .iter()
+        .map(|r| {
+            r.iter()
+                .filter(|s| !s.merged)
+                .map(|s| s.lines.len())
+                .max()
+                .unwrap_or(0)
+                .max(1)
+        })
+        .collect();
+    for &(first, col, last) in &groups {
+        let lines = match slots[first].iter_mut().find(|s| s.col == col) {
+            Some(s) => std::mem::take(&mut s.lines),
+            None => continue,
+        };
+        let available: usize = heights[first..=last].iter().sum();
+        if lines.len() > available {
+            heights[last] += lines.len() - available;
+        }
+        let mut offset = 0;
+        for (i, r) in slots.iter_mut().enumerate().take(last + 1).skip(first) {
+            let end = (offset + heights[i]).min(lines.len());
+            if let Some(s) = r.iter_mut().find(|s| s.col == col) {
+                s.lines = lines[offset.min(end)..end].to_vec();
+            }
+            offset = end;
+        }
+    }
+
+    let row_texts: Vec<String> = slots
+        .iter()
+        .zip(&heights)
+        .map(|(r, height)| {
+            (0..*height)
+                .map(|i| {
+                    let cells: Vec<String> = r
+                        .iter()
+                        .map(|s| {
+                            let width = widths[s.col..s.col + s.span].iter().sum::<usize>()
+                                + sep * (s.span - 1);
+                            pad(s.lines.get(i).map(|l| l.as_str()).unwrap_or(""), width)
+                        })
+                        .collect();
+                    match style {
+                        RenderBorderStyle::None => cells.join("  ").trim_end().to_owned(),
+                        RenderBorderStyle::Ascii => format!("| {} |", cells.join(" | ")),
+                        RenderBorderStyle::Unicode => format!("│ {} │", cells.join(" │ ")),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect();
+
+    if style == RenderBorderStyle::None {
+        return (row_texts.join("\n"), row_texts);
+    }
+    let mut lines = vec![rule_line(
+        None,
+        slots.first().map(|r| r.as_slice()),
+        &widths,
+        style,
+    )];
+    for (i, text) in row_texts.iter().enumerate() {
+        lines.push(text.clone());
+        lines.push(rule_line(
+            Some(&slots[i]),
+            slots.get(i + 1).map(|r| r.as_slice()),
+            &widths,
+            style,
+        ));
+    }
+    (lines.join("\n"), row_texts)
+}
+
+impl Render for Table {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        // Nesting depth, 0 for top level tables.
+        let depth = ctx.table_depth;
+        ctx.table_depth += 1;
+        let mut children: Vec<JsonRender> =
+            self.rows.iter().map(|r| r.render_ascii_json(ctx)).collect();
+        ctx.table_depth -= 1;
+        let mut render = JsonRender::new(RenderNodeType::Table);
+        render.properties = serde_json::json!({ "depth": depth });
+        if children.is_empty() {
+            return render;
+        }
+        let grid: Vec<Vec<GridCell>> = self
+            .rows
+            .iter()
+            .zip(&children)
+            .map(|(TableChild::TableRow(row), r)| grid_cells(row, r))
+            .collect();
+        let style = ctx.options.table_border;
+        let (mut text, rows) = render_grid(&grid, style);
+        // Re-align the rows to the table wide column widths.
+        for (row, row_text) in children.iter_mut().zip(rows) {
+            row.text = row_text;
+        }
+        // Without borders, indent nested grids so that they stand out from the parent cell.
+        if depth > 0 && style == RenderBorderStyle::None {
+            text = text
+                .lines()
+                .map(|l| format!("  {}", l))
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+        let render = render.text(text).children(children);
+        ctx.visit(render, |v, r| v.visit_table(self, r))
+    }
+}
+
+fn grid_cells(row: &TableRow, render: &JsonRender) -> Vec<GridCell> {
+    row.cells
+        .iter()
+        .zip(&render.children)
+        .map(|(TableRowChild::TableCell(c), r)| GridCell::new(c, r.text.clone()))
+        .collect()
+}
+
+impl Render for TableChild {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        match self {
+            TableChild::TableRow(r) => r.render_ascii_json(ctx),
+        }
+    }
+}
+
+impl Render for TableRow {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let children: Vec<JsonRender> = self
+            .cells
+            .iter()
+            .map(|c| c.render_ascii_json(ctx))
+            .collect();
+        let mut render = JsonRender::new(RenderNodeType::TableRow).children(children);
+        let (_, rows) = render_grid(&[grid_cells(self, &render)], ctx.options.table_border);
+        render.text = rows.into_iter().next().unwrap_or_default();
+        ctx.visit(render, |v, r| v.visit_table_row(self, r))
+    }
+}
+
+impl Render for TableRowChild {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        match self {
+            TableRowChild::TableCell(c) => c.render_ascii_json(ctx),
+        }
+    }
+}
+
+impl Render for TableCell {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let children: Vec<JsonRender> = self
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                TableCellContent::Paragraph(p) => Some(p.render_ascii_json(ctx)),
+                TableCellContent::Table(t) => Some(t.render_ascii_json(ctx)),
+                TableCellContent::StructuredDataTag(t) => Some(t.render_ascii_json(ctx)),
+                _ => None,
+            })
+            .collect();
+        // Word requires a paragraph after a nested table, which is usually empty.
+        let mut text = join_text(&children, "\n").trim_end_matches('\n').to_owned();
+        let mut properties = serde_json::Value::Null;
+        if let Some(d) = &self.property.text_direction {
+            set_property(&mut properties, "textDirection", d.val.to_string().into());
+            match direction::vertical_direction(&d.val) {
+                Some(bottom_to_top) if ctx.options.visual_text_direction => {
+                    text = direction::transpose_lines(&text, bottom_to_top);
+                }
+                _ => {}
+            }
+        }
+        let mut render = JsonRender::new(RenderNodeType::TableCell)
+            .text(text)
+            .children(children);
+        render.properties = properties;
+        ctx.visit(render, |v, r| v.visit_table_cell(self, r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn cell(text: &str) -> TableCell {
+        TableCell::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text(text)))
+    }
+
+    #[test]
+    fn test_render_row() {
+        let r = TableRow::new(vec![cell("a"), cell("bc")]);
+        assert_eq!(r.render_ascii(), "| a | bc |");
+    }
+
+    #[test]
+    fn test_render_table_border_style() {
+        let t = Table::new(vec![
+            TableRow::new(vec![cell("a"), cell("bc")]),
+            TableRow::new(vec![cell("def")]),
+        ]);
+        let options = RenderOptions::new().table_border(RenderBorderStyle::Unicode);
+        assert_eq!(
+            t.render_with(&options).text,
+            "┌─────┬────┐\n│ a   │ bc │\n├─────┼────┤\n│ def │    │\n└─────┴────┘"
+        );
+        let options = RenderOptions::new().table_border(RenderBorderStyle::None);
+        assert_eq!(t.render_with(&options).text, "a    bc\ndef");
+    }
+
+    #[test]
+    fn test_render_nested_table() {
+        let inner = Table::new(vec![TableRow::new(vec![cell("x"), cell("yy")])]);
+        let t = Table::new(vec![TableRow::new(vec![
+            cell("outer"),
+            TableCell::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("see")))
+                .add_table(inner)
+                .add_paragraph(Paragraph::new()),
+        ])]);
+        assert_eq!(
+            t.render_ascii(),
+            "+-------+------------+
+| outer | see        |
+|       | +---+----+ |
+|       | | x | yy | |
+|       | +---+----+ |
++-------+------------+"
+        );
+        let r = t.render_with(&RenderOptions::new().table_border(RenderBorderStyle::None));
+        assert_eq!(r.text, "outer  see\n         x  yy");
+        assert_eq!(
+            r.children[0].children[1].children[1].properties,
+            serde_json::json!({ "depth": 1 })
+        );
+    }
+
+    #[test]
+    fn test_render_aligned_table() {
+        let t = Table::new(vec![
+            TableRow::new(vec![cell("Name"), cell("Qty")]),
+            TableRow::new(vec![
+                cell("apple").add_paragraph(Paragraph::new().add_run(Run::new().add_text("red"))),
+                cell("10"),
+            ]),
+            TableRow::new(vec![cell("kiwi")]),
+        ]);
+        assert_eq!(
+            t.render_ascii(),
+            "+-------+-----+
+| Name  | Qty |
++-------+-----+
+| apple | 10  |
+| red   |     |
++-------+-----+
+| kiwi  |     |
++-------+-----+"
+        );
+    }
+
+    #[test]
+    fn test_render_grid_span() {
+        let t = Table::new(vec![
+            TableRow::new(vec![cell("A").grid_span(2), cell("B")]),
+            TableRow::new(vec![cell("x"), cell("y"), cell("zz")]),
+        ]);
+        assert_eq!(
+            t.render_ascii(),
+            "+-------+----+
+| A     | B  |
++---+---+----+
+| x | y | zz |
++---+---+----+"
+        );
+        let options = RenderOptions::new().table_border(RenderBorderStyle::Unicode);
+        assert_eq!(
+            t.render_with(&options).text,
+            "┌───────┬────┐
+│ A     │ B  │
+├───┬───┼────┤
+│ x │ y │ zz │
+└───┴───┴────┘"
+        );
+        let t = Table::new(vec![TableRow::new(vec![cell("wide title").grid_span(2)])]);
+        assert_eq!(
+            t.render_ascii(),
+            "+------------+\n| wide title |\n+------------+"
+        );
+    }
+
+    #[test]
+    fn test_render_vertical_merge() {
+        let t = Table::new(vec![
+            TableRow::new(vec![
+                cell("m1")
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text("m2")))
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text("m3")))
+                    .vertical_merge(VMergeType::Restart),
+                cell("a"),
+            ]),
+            TableRow::new(vec![
+                TableCell::new().vertical_merge(VMergeType::Continue),
+                cell("b"),
+            ]),
+        ]);
+        assert_eq!(
+            t.render_ascii(),
+            "+----+---+
+| m1 | a |
+|    +---+
+| m2 | b |
+| m3 |   |
++----+---+"
+        );
+        let options = RenderOptions::new().table_border(RenderBorderStyle::Unicode);
+        assert_eq!(
+            t.render_with(&options).text,
+            "┌────┬───┐
+│ m1 │ a │
+│    ├───┤
+│ m2 │ b │
+│ m3 │   │
+└────┴───┘"
+        );
+    }
+}
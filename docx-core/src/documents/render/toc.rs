@@ -0,0 +1,230 @@
+use super::*;
+
+// `Title ... 3` indented by two spaces per level.
+fn outline_line(level: usize, title: &str, page: Option<&str>) -> String {
+    let indent = "  ".repeat(level.max(1) - 1);
+    match page {
+        Some(page) => format!("{}{} ... {}", indent, title, page),
+        None => format!("{}{}", indent, title),
+    }
+}
+
+// Level of TOC entry styles such as `TOC2` or the writer's `ToC2`.
+fn toc_level(p: &Paragraph) -> usize {
+    p.property
+        .style
+        .as_ref()
+        .and_then(|s| {
+            let id = s.val.to_lowercase();
+            id.strip_prefix("toc")?.trim().parse::<usize>().ok()
+        })
+        .filter(|n| (1..=9).contains(n))
+        .unwrap_or(1)
+}
+
+fn field_runs<'b>(children: &'b [ParagraphChild], runs: &mut Vec<&'b Run>) {
+    for c in children {
+        match c {
+            ParagraphChild::Run(r) => runs.push(r),
+            ParagraphChild::Insert(i) => runs.extend(i.children.iter().filter_map(|c| match c {
+                InsertChild::Run(r) => Some(r.as_ref()),
+                _ => None,
+            })),
+            ParagraphChild::Hyperlink(h) => field_runs(&h.children, runs),
+            ParagraphChild::ComplexField(f) => field_runs(&f.cached_result, runs),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> RenderContext<'a> {
+    fn in_toc(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|instr| instr.split_whitespace().next() == Some("TOC"))
+    }
+
+    // Follow the fields of the paragraph. Returns whether the paragraph is (partly) in a TOC
+    // field, and whether the TOC field ends in it.
+    pub(crate) fn scan_fields(&mut self, p: &Paragraph) -> (bool, bool) {
+        let mut runs = vec![];
+        field_runs(&p.children, &mut runs);
+        let mut in_toc = self.in_toc();
+        let mut closed = false;
+        for run in runs {
+            for c in &run.children {
+                match c {
+                    RunChild::FieldChar(f) => match f.field_char_type {
+                        FieldCharType::Begin => self.fields.push(String::new()),
+                        FieldCharType::End => {
+                            let was_toc = self.in_toc();
+                            self.fields.pop();
+                            closed |= was_toc && !self.in_toc();
+                        }
+                        _ => {}
+                    },
+                    RunChild::InstrText(i) => {
+                        if let (InstrText::TOC(_), Some(instr)) =
+                            (i.as_ref(), self.fields.last_mut())
+                        {
+                            instr.push_str("TOC ");
+                        }
+                    }
+                    RunChild::InstrTextString(s) => {
+                        if let Some(instr) = self.fields.last_mut() {
+                            instr.push_str(s);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            in_toc |= self.in_toc();
+        }
+        // TOC fields read as a whole begin and end in the paragraph.
+        for c in &p.children {
+            if let ParagraphChild::ComplexField(f) = c {
+                if f.field_type() == "TOC" {
+                    in_toc = true;
+                    closed |= !self.in_toc();
+                }
+            }
+        }
+        (in_toc, closed)
+    }
+
+    // Headings of the body for TOC fields without cached results.
+    pub(crate) fn collect_headings(&mut self, children: &[DocumentChild]) {
+        self.headings = children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => {
+                    let level = self.heading_level(p)?;
+                    Some((level, replace_escaped(&p.raw_text())))
+                }
+                _ => None,
+            })
+            .collect();
+    }
+
+    fn heading_outline(&self) -> String {
+        self.headings
+            .iter()
+            .map(|(level, title)| outline_line(*level, title, None))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // Cached TOC entries, e.g. `Intro\t3`, become outline lines. A TOC field ending without
+    // entries is replaced with the heading outline when `synthesize_toc` is set.
+    pub(crate) fn toc_entry(
+        &mut self,
+        p: &Paragraph,
+        text: &str,
+        closed: bool,
+    ) -> Option<(String, serde_json::Value)> {
+        let mut entry = None;
+        if !text.trim().is_empty() {
+            self.toc_entries += 1;
+            let level = toc_level(p);
+            let (title, page) = match text.rsplit_once('\t') {
+                Some((title, page)) if !page.trim().is_empty() => (title, Some(page.trim())),
+                _ => (text, None),
+            };
+            entry = Some((
+                outline_line(level, title.trim(), page),
+                serde_json::json!({ "tocLevel": level, "page": page }),
+            ));
+        }
+        if closed {
+            let entries = std::mem::take(&mut self.toc_entries);
+            if entries == 0 && self.options.synthesize_toc {
+                entry = Some((
+                    self.heading_outline(),
+                    serde_json::json!({ "synthesized": true }),
+                ));
+            }
+        }
+        entry
+    }
+}
+
+impl Render for TableOfContents {
+    fn render_ascii_json(&self, ctx: &mut RenderContext) -> JsonRender {
+        let text = if !self.items.is_empty() {
+            self.items
+                .iter()
+                .map(|i| outline_line(i.level, &i.text, None))
+                .collect::<Vec<_>>()
+                .join("\n")
+        } else if ctx.options.synthesize_toc {
+            ctx.heading_outline()
+        } else {
+            String::new()
+        };
+        JsonRender::new(RenderNodeType::TableOfContents).text(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn entry(style: &str, title: &str, page: &str) -> Paragraph {
+        Paragraph::new().style(style).add_hyperlink(
+            Hyperlink::new("_Toc1", HyperlinkType::Anchor)
+                .add_run(Run::new().add_text(title))
+                .add_run(Run::new().add_tab())
+                .add_run(Run::new().add_text(page)),
+        )
+    }
+
+    #[test]
+    fn test_render_cached_toc() {
+        let toc = Paragraph::new()
+            .add_run(Run::new().add_field_char(FieldCharType::Begin, false))
+            .add_run(Run::new().add_instr_text(InstrText::TOC(InstrToC::new())))
+            .add_run(Run::new().add_field_char(FieldCharType::Separate, false));
+        let docx = Docx::new()
+            .add_paragraph(toc)
+            .add_paragraph(entry("TOC1", "Intro", "1"))
+            .add_paragraph(entry("TOC2", "Background", "2"))
+            .add_paragraph(
+                Paragraph::new().add_run(Run::new().add_field_char(FieldCharType::End, false)),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Body\tx")));
+        let r = docx.render_ascii_json();
+        assert_eq!(r.text, "\nIntro ... 1\n  Background ... 2\n\nBody\tx");
+        assert_eq!(
+            r.children[2].properties,
+            serde_json::json!({ "styleId": "TOC2", "tocLevel": 2, "page": "2" })
+        );
+    }
+
+    #[test]
+    fn test_synthesize_toc() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_field_char(FieldCharType::Begin, false))
+                    .add_run(Run::new().add_instr_text(InstrText::TOC(InstrToC::new())))
+                    .add_run(Run::new().add_field_char(FieldCharType::End, false)),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading1")
+                    .add_run(Run::new().add_text("Intro")),
+            )
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading2")
+                    .add_run(Run::new().add_text("Scope")),
+            );
+        assert_eq!(docx.render_ascii(), "\nIntro\nScope");
+        let r = docx.render_with(&RenderOptions::new().synthesize_toc(true));
+        assert_eq!(r.text, "Intro\n  Scope\nIntro\nScope");
+    }
+}
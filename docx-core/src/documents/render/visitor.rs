@@ -0,0 +1,177 @@
+use std::fmt;
+
+use super::*;
+
+// Hooks to customize the rendering of elements without reimplementing the renderer. Each
+// hook receives the element and its default rendering, and returns the rendering to use.
+// Containers are rendered from the results of their children, so replacing the text of a
+// run also changes the text of its paragraph.
+//
+//   struct Redact;
+//
+//   impl RenderVisitor for Redact {
+//       fn visit_run(&self, run: &Run, mut render: JsonRender) -> JsonRender {
+//           if run.run_property.highlight.is_some() {
+//               render.text = "█".repeat(render.text.chars().count());
+//           }
+//           render
+//       }
+//   }
+//
+//   let json = docx.render_with_visitor(&RenderOptions::new(), &Redact);
+pub trait RenderVisitor {
+    fn visit_paragraph(&self, _paragraph: &Paragraph, render: JsonRender) -> JsonRender {
+        render
+    }
+
+    fn visit_run(&self, _run: &Run, render: JsonRender) -> JsonRender {
+        render
+    }
+
+    fn visit_hyperlink(&self, _hyperlink: &Hyperlink, render: JsonRender) -> JsonRender {
+        render
+    }
+
+    fn visit_insert(&self, _insert: &Insert, render: JsonRender) -> JsonRender {
+        render
+    }
+
+    fn visit_delete(&self, _delete: &Delete, render: JsonRender) -> JsonRender {
+        render
+    }
+
+    fn visit_table(&self, _table: &Table, render: JsonRender) -> JsonRender {
+        render
+    }
+
+    fn visit_table_row(&self, _row: &TableRow, render: JsonRender) -> JsonRender {
+        render
+    }
+
+    fn visit_table_cell(&self, _cell: &TableCell, render: JsonRender) -> JsonRender {
+        render
+    }
+
+    fn visit_image(&self, _pic: &Pic, render: JsonRender) -> JsonRender {
+        render
+    }
+
+    fn visit_chart(&self, _chart: &Chart, render: JsonRender) -> JsonRender {
+        render
+    }
+
+    fn visit_math(&self, _math: &OfficeMath, render: JsonRender) -> JsonRender {
+        render
+    }
+
+    fn visit_structured_data_tag(
+        &self,
+        _sdt: &StructuredDataTag,
+        render: JsonRender,
+    ) -> JsonRender {
+        render
+    }
+}
+
+// `RenderContext` is `Debug`, which visitors don't have to be.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Visitor<'a>(Option<&'a dyn RenderVisitor>);
+
+impl<'a> fmt::Debug for Visitor<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(_) => write!(f, "Visitor(Some(..))"),
+            None => write!(f, "Visitor(None)"),
+        }
+    }
+}
+
+impl<'a> RenderContext<'a> {
+    pub fn visitor(mut self, visitor: &'a dyn RenderVisitor) -> Self {
+        self.visitor = Visitor(Some(visitor));
+        self
+    }
+
+    pub(crate) fn visit(
+        &self,
+        render: JsonRender,
+        hook: impl FnOnce(&dyn RenderVisitor, JsonRender) -> JsonRender,
+    ) -> JsonRender {
+        match self.visitor.0 {
+            Some(visitor) => hook(visitor, render),
+            None => render,
+        }
+    }
+}
+
+impl Docx {
+    pub fn render_with_visitor(
+        &self,
+        options: &RenderOptions,
+        visitor: &dyn RenderVisitor,
+    ) -> JsonRender {
+        self.document.render_ascii_json(
+            &mut self
+                .render_context()
+                .options(options.clone())
+                .visitor(visitor),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    struct Redact;
+
+    impl RenderVisitor for Redact {
+        fn visit_run(&self, run: &Run, mut render: JsonRender) -> JsonRender {
+            if run.run_property.highlight.is_some() {
+                render.text = "█".repeat(render.text.chars().count());
+            }
+            render
+        }
+
+        fn visit_image(&self, pic: &Pic, mut render: JsonRender) -> JsonRender {
+            render.text = format!("<img src=\"{}\">", pic.id);
+            render
+        }
+
+        fn visit_paragraph(&self, _p: &Paragraph, mut render: JsonRender) -> JsonRender {
+            render.properties = serde_json::json!({ "visited": true });
+            render
+        }
+    }
+
+    #[test]
+    fn test_render_with_visitor() {
+        let pic = Pic::with_empty().id("rIdImage1");
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("PIN "))
+                    .add_run(Run::new().add_text("1234").highlight("black")),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()
+                .add_paragraph(
+                    Paragraph::new().add_run(Run::new().add_image(pic)),
+                )])]));
+        let r = docx.render_with_visitor(&RenderOptions::new(), &Redact);
+        assert_eq!(
+            r.text,
+            "PIN ████\n+-----------------------+\n| <img src=\"rIdImage1\"> |\n+-----------------------+"
+        );
+        assert_eq!(
+            r.children[0].properties,
+            serde_json::json!({ "visited": true })
+        );
+        assert_eq!(
+            docx.render_ascii(),
+            "PIN 1234\n+---------+\n| [image] |\n+---------+"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use super::*;
 
 use crate::documents::BuildXML;
-use crate::types::CharacterSpacingValues;
+use crate::types::{CharacterSpacingValues, CompatibilityMode, DocumentProtectionType};
 use crate::xml_builder::*;
 
 use serde::Serialize;
@@ -17,6 +17,19 @@ pub struct Settings {
     adjust_line_height_in_table: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     character_spacing_control: Option<CharacterSpacingValues>,
+    track_revisions: bool,
+    // Flags of `w:compat` such as `spaceForUL`, in document order.
+    compat_options: Vec<String>,
+    compat_settings: Vec<CompatSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rsid_root: Option<String>,
+    rsids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    document_protection: Option<DocumentProtection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    write_protection: Option<WriteProtection>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    embed_true_type_fonts: bool,
 }
 
 impl Settings {
@@ -46,6 +59,68 @@ impl Settings {
 
     pub fn adjust_line_height_in_table(mut self) -> Self {
         self.adjust_line_height_in_table = true;
+        self.add_compat_option_before(ADJUST_LINE_HEIGHT_IN_TABLE, USE_FE_LAYOUT);
+        self
+    }
+
+    pub fn zoom(mut self, percent: usize) -> Self {
+        self.zoom = Zoom::new(percent);
+        self
+    }
+
+    pub fn track_revisions(mut self) -> Self {
+        self.track_revisions = true;
+        self
+    }
+
+    pub fn add_compat_option(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        if !self.compat_options.contains(&name) {
+            self.compat_options.push(name);
+        }
+        self
+    }
+
+    // Options such as `doNotExpandShiftReturn` and `useFELayout` are set for new documents.
+    pub fn remove_compat_option(mut self, name: &str) -> Self {
+        self.compat_options.retain(|o| o != name);
+        if name == ADJUST_LINE_HEIGHT_IN_TABLE {
+            self.adjust_line_height_in_table = false;
+        }
+        self
+    }
+
+    pub fn compatibility_mode(self, mode: CompatibilityMode) -> Self {
+        self.compat_setting(COMPATIBILITY_MODE, WORD_URI, mode.to_string())
+    }
+
+    // Settings with the same name are replaced.
+    pub fn compat_setting(
+        mut self,
+        name: impl Into<String>,
+        uri: impl Into<String>,
+        val: impl Into<String>,
+    ) -> Self {
+        let setting = CompatSetting::new(name, uri, val);
+        if let Some(s) = self
+            .compat_settings
+            .iter_mut()
+            .find(|s| s.name == setting.name)
+        {
+            *s = setting;
+        } else {
+            self.compat_settings.push(setting);
+        }
+        self
+    }
+
+    pub fn rsid_root(mut self, rsid: impl Into<String>) -> Self {
+        self.rsid_root = Some(rsid.into());
+        self
+    }
+
+    pub fn add_rsid(mut self, rsid: impl Into<String>) -> Self {
+        self.rsids.push(rsid.into());
         self
     }
 
@@ -53,6 +128,115 @@ impl Settings {
         self.character_spacing_control = Some(val);
         self
     }
+
+    // Keep the fonts embedded in the package when the document is saved by the application.
+    pub fn embed_true_type_fonts(mut self) -> Self {
+        self.embed_true_type_fonts = true;
+        self
+    }
+
+    pub fn document_protection(mut self, p: DocumentProtection) -> Self {
+        self.document_protection = Some(p);
+        self
+    }
+
+    pub fn write_protection(mut self, p: WriteProtection) -> Self {
+        self.write_protection = Some(p);
+        self
+    }
+
+    pub fn get_document_protection(&self) -> Option<&DocumentProtection> {
+        self.document_protection.as_ref()
+    }
+
+    pub fn get_write_protection(&self) -> Option<&WriteProtection> {
+        self.write_protection.as_ref()
+    }
+
+    pub fn get_compat_options(&self) -> &[String] {
+        &self.compat_options
+    }
+
+    pub fn get_compat_setting(&self, name: &str) -> Option<&str> {
+        self.compat_settings
+            .iter()
+            .find(|s| s.name == name)
+            .map(|s| s.val.as_str())
+    }
+
+    // Documents without the setting are laid out as by Word 2007 and earlier, so `None` is
+    // returned only for unknown values.
+    pub fn get_compatibility_mode(&self) -> Option<CompatibilityMode> {
+        match self.get_compat_setting(COMPATIBILITY_MODE) {
+            Some(mode) => mode.parse().ok(),
+            None => Some(CompatibilityMode::Word2007),
+        }
+    }
+
+    // Read documents replace the default compat options with their own.
+    pub(crate) fn clear_compat(mut self) -> Self {
+        self.compat_options = vec![];
+        self.compat_settings = vec![];
+        self
+    }
+
+    pub(crate) fn without_compat_options(mut self) -> Self {
+        self.compat_options = vec![];
+        self
+    }
+
+    // The compat options other than those of new documents.
+    pub(crate) fn legacy_compat_options(&self) -> Vec<&str> {
+        let defaults = default_compat_options();
+        self.compat_options
+            .iter()
+            .filter(|o| !defaults.contains(o) && o.as_str() != ADJUST_LINE_HEIGHT_IN_TABLE)
+            .map(|o| o.as_str())
+            .collect()
+    }
+
+    fn add_compat_option_before(&mut self, name: &str, next: &str) {
+        if self.compat_options.iter().any(|o| o == name) {
+            return;
+        }
+        match self.compat_options.iter().position(|o| o == next) {
+            Some(i) => self.compat_options.insert(i, name.to_owned()),
+            None => self.compat_options.push(name.to_owned()),
+        }
+    }
+}
+
+const ADJUST_LINE_HEIGHT_IN_TABLE: &str = "adjustLineHeightInTable";
+const USE_FE_LAYOUT: &str = "useFELayout";
+const COMPATIBILITY_MODE: &str = "compatibilityMode";
+const WORD_URI: &str = "http://schemas.microsoft.com/office/word";
+
+fn default_compat_options() -> Vec<String> {
+    [
+        "spaceForUL",
+        "balanceSingleByteDoubleByteWidth",
+        "doNotLeaveBackslashAlone",
+        "ulTrailSpace",
+        "doNotExpandShiftReturn",
+        USE_FE_LAYOUT,
+    ]
+    .iter()
+    .map(|o| o.to_string())
+    .collect()
+}
+
+fn default_compat_settings() -> Vec<CompatSetting> {
+    [
+        (COMPATIBILITY_MODE, "15"),
+        ("overrideTableStyleFontSizeAndJustification", "1"),
+        ("enableOpenTypeFeatures", "1"),
+        ("doNotFlipMirrorIndents", "1"),
+        ("differentiateMultirowTableHeaders", "1"),
+        ("useWord2013TrackBottomHyphenation", "0"),
+    ]
+    .iter()
+    .map(|(name, val)| CompatSetting::new(*name, WORD_URI, *val))
+    .collect()
 }
 
 impl Default for Settings {
@@ -65,6 +249,14 @@ impl Default for Settings {
             even_and_odd_headers: false,
             adjust_line_height_in_table: false,
             character_spacing_control: None,
+            track_revisions: false,
+            compat_options: default_compat_options(),
+            compat_settings: default_compat_settings(),
+            rsid_root: None,
+            rsids: vec![],
+            document_protection: None,
+            write_protection: None,
+            embed_true_type_fonts: false,
         }
     }
 }
@@ -75,57 +267,38 @@ impl BuildXML for Settings {
         let mut b = b
             .declaration(Some(true))
             .open_settings()
+            .add_optional_child(&self.write_protection)
             .add_child(&self.default_tab_stop)
-            .add_child(&self.zoom)
-            .open_compat()
-            .space_for_ul()
-            .balance_single_byte_double_byte_width()
-            .do_not_leave_backslash_alone()
-            .ul_trail_space()
-            .do_not_expand_shift_return();
-
-        if let Some(v) = self.character_spacing_control {
-            b = b.character_spacing_control(&v.to_string());
+            .add_child(&self.zoom);
+
+        if self.embed_true_type_fonts {
+            b = b.embed_true_type_fonts();
         }
 
-        if self.adjust_line_height_in_table {
-            b = b.adjust_line_height_table();
-        }
-
-        b = b
-            .use_fe_layout()
-            .compat_setting(
-                "compatibilityMode",
-                "http://schemas.microsoft.com/office/word",
-                "15",
-            )
-            .compat_setting(
-                "overrideTableStyleFontSizeAndJustification",
-                "http://schemas.microsoft.com/office/word",
-                "1",
-            )
-            .compat_setting(
-                "enableOpenTypeFeatures",
-                "http://schemas.microsoft.com/office/word",
-                "1",
-            )
-            .compat_setting(
-                "doNotFlipMirrorIndents",
-                "http://schemas.microsoft.com/office/word",
-                "1",
-            )
-            .compat_setting(
-                "differentiateMultirowTableHeaders",
-                "http://schemas.microsoft.com/office/word",
-                "1",
-            )
-            .compat_setting(
-                "useWord2013TrackBottomHyphenation",
-                "http://schemas.microsoft.com/office/word",
-                "0",
-            )
-            .close()
-            .add_optional_child(&self.doc_id);
+        if self.track_revisions {
+            b = b.track_revisions();
+        }
+
+        b = b.add_optional_child(&self.document_protection);
+
+        b = b.open_compat();
+        // characterSpacingControl goes before the table and layout flags.
+        let mut spacing = self.character_spacing_control;
+        for o in &self.compat_options {
+            if o == ADJUST_LINE_HEIGHT_IN_TABLE || o == USE_FE_LAYOUT {
+                if let Some(v) = spacing.take() {
+                    b = b.character_spacing_control(&v.to_string());
+                }
+            }
+            b = b.compat_option(o);
+        }
+        if let Some(v) = spacing {
+            b = b.character_spacing_control(&v.to_string());
+        }
+        for s in &self.compat_settings {
+            b = b.compat_setting(&s.name, &s.uri, &s.val);
+        }
+        b = b.close().add_optional_child(&self.doc_id);
 
         if !self.doc_vars.is_empty() {
             b = b.open_doc_vars();
@@ -135,6 +308,17 @@ impl BuildXML for Settings {
             b = b.close();
         }
 
+        if self.rsid_root.is_some() || !self.rsids.is_empty() {
+            b = b.open_rsids();
+            if let Some(root) = &self.rsid_root {
+                b = b.rsid_root(root);
+            }
+            for r in &self.rsids {
+                b = b.rsid(r);
+            }
+            b = b.close();
+        }
+
         if self.even_and_odd_headers {
             b = b.even_and_odd_headers();
         }
@@ -142,6 +326,53 @@ impl BuildXML for Settings {
     }
 }
 
+impl Docx {
+    // The editing allowed by the enforced document protection, if any.
+    pub fn edit_restriction(&self) -> Option<DocumentProtectionType> {
+        match self.settings.get_document_protection() {
+            Some(p) if p.enforcement && p.edit != DocumentProtectionType::None => Some(p.edit),
+            _ => None,
+        }
+    }
+
+    // Read-only by the document protection, or by a password to save changes.
+    pub fn is_read_only(&self) -> bool {
+        self.edit_restriction() == Some(DocumentProtectionType::ReadOnly)
+            || self
+                .settings
+                .get_write_protection()
+                .map_or(false, |p| p.hash.hash_value.is_some())
+    }
+
+    // Restrict the editing in Word to `edit`, which the password turns off.
+    pub fn protect(mut self, edit: DocumentProtectionType, password: Option<&str>) -> Docx {
+        let mut protection = DocumentProtection::new(edit);
+        if let Some(password) = password {
+            protection = protection.hash(ProtectionHash::from_password(
+                password,
+                &random_salt(),
+                DEFAULT_PROTECTION_SPIN_COUNT,
+            ));
+        }
+        self.settings = self.settings.document_protection(protection);
+        self
+    }
+}
+
+// The keys of `RandomState` are random for each process and change for each instance.
+fn random_salt() -> Vec<u8> {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    (0..2)
+        .flat_map(|i| {
+            let mut h = RandomState::new().build_hasher();
+            h.write_u8(i);
+            h.finish().to_le_bytes()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,4 +404,89 @@ mod tests {
 </w:settings>"#
         );
     }
+
+    #[test]
+    fn test_compat() {
+        let s = Settings::new()
+            .compatibility_mode(CompatibilityMode::Word2010)
+            .remove_compat_option("doNotExpandShiftReturn")
+            .remove_compat_option("useFELayout")
+            .add_compat_option("doNotUseHTMLParagraphAutoSpacing");
+        assert_eq!(
+            s.get_compatibility_mode(),
+            Some(CompatibilityMode::Word2010)
+        );
+        assert_eq!(
+            s.get_compat_options(),
+            &[
+                "spaceForUL",
+                "balanceSingleByteDoubleByteWidth",
+                "doNotLeaveBackslashAlone",
+                "ulTrailSpace",
+                "doNotUseHTMLParagraphAutoSpacing",
+            ]
+        );
+        let b = s.build();
+        assert!(str::from_utf8(&b).unwrap().contains(
+            r#"<w:compatSetting w:name="compatibilityMode" w:uri="http://schemas.microsoft.com/office/word" w:val="14" />"#
+        ));
+        assert_eq!(
+            Settings::new().clear_compat().get_compatibility_mode(),
+            Some(CompatibilityMode::Word2007)
+        );
+    }
+
+    #[test]
+    fn test_edit_restriction() {
+        let docx = Docx::new();
+        assert_eq!(docx.edit_restriction(), None);
+        assert!(!docx.is_read_only());
+
+        let mut docx = Docx::new();
+        docx.settings = Settings::new()
+            .document_protection(DocumentProtection::new(DocumentProtectionType::Forms));
+        assert_eq!(docx.edit_restriction(), Some(DocumentProtectionType::Forms));
+        assert!(!docx.is_read_only());
+
+        docx.settings = Settings::new().document_protection(
+            DocumentProtection::new(DocumentProtectionType::ReadOnly).enforcement(false),
+        );
+        assert_eq!(docx.edit_restriction(), None);
+
+        docx.settings = Settings::new().write_protection(
+            WriteProtection::new().hash(ProtectionHash::new().hash_value("aGFzaA==")),
+        );
+        assert!(docx.is_read_only());
+    }
+
+    #[test]
+    fn test_protect() {
+        let docx = Docx::new().protect(DocumentProtectionType::ReadOnly, Some("secret"));
+        let p = docx.settings.get_document_protection().unwrap();
+        assert_eq!(
+            docx.edit_restriction(),
+            Some(DocumentProtectionType::ReadOnly)
+        );
+        assert_eq!(p.hash.algorithm_name.as_deref(), Some("SHA-512"));
+        assert_eq!(p.hash.spin_count, Some(DEFAULT_PROTECTION_SPIN_COUNT));
+        assert!(p.hash.matches("secret"));
+        assert!(!p.hash.matches("Secret"));
+
+        let other = Docx::new().protect(DocumentProtectionType::ReadOnly, Some("secret"));
+        assert_ne!(
+            other
+                .settings
+                .get_document_protection()
+                .unwrap()
+                .hash
+                .salt_value,
+            p.hash.salt_value
+        );
+
+        let docx = Docx::new().protect(DocumentProtectionType::Comments, None);
+        let settings = str::from_utf8(&docx.settings.build()).unwrap().to_owned();
+        assert!(
+            settings.contains(r#"<w:documentProtection w:edit="comments" w:enforcement="1" />"#)
+        );
+    }
 }
@@ -0,0 +1,277 @@
+use serde::Serialize;
+
+use super::*;
+use crate::escape::replace_escaped;
+
+// Counts of the body as Word reports them in the word count dialog. Text boxes are counted,
+// deleted revisions are not, and empty paragraphs are skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Statistics {
+    pub words: usize,
+    // Characters without spaces.
+    pub characters: usize,
+    pub characters_with_spaces: usize,
+    pub paragraphs: usize,
+    pub tables: usize,
+    pub images: usize,
+}
+
+// Pages are estimated from the text with the usual font size of 10.5pt, half as wide as high,
+// and a line height of 1.2. All in twips.
+const ESTIMATED_CHAR_WIDTH: i64 = 105;
+const ESTIMATED_LINE_HEIGHT: i64 = 252;
+
+// East Asian characters are words on their own, like in Word.
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{AC00}'..='\u{D7AF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{FF66}'..='\u{FF9F}')
+}
+
+impl Statistics {
+    fn add_text(&mut self, text: &str) {
+        if text.trim().is_empty() {
+            return;
+        }
+        self.paragraphs += 1;
+        let mut in_word = false;
+        for c in text.chars() {
+            if c == '\n' {
+                in_word = false;
+                continue;
+            }
+            self.characters_with_spaces += 1;
+            if c.is_whitespace() {
+                in_word = false;
+                continue;
+            }
+            self.characters += 1;
+            if is_cjk(c) {
+                self.words += 1;
+                in_word = false;
+            } else if !in_word {
+                self.words += 1;
+                in_word = true;
+            }
+        }
+    }
+
+    fn add_paragraph(&mut self, p: &Paragraph) {
+        let mut text = String::new();
+        self.add_paragraph_children(&p.children, &mut text);
+        self.add_text(&text);
+    }
+
+    fn add_paragraph_children(&mut self, children: &[ParagraphChild], text: &mut String) {
+        for c in children {
+            match c {
+                ParagraphChild::Run(r) => self.add_run(r, text),
+                ParagraphChild::Insert(i) => {
+                    for c in &i.children {
+                        if let InsertChild::Run(r) = c {
+                            self.add_run(r, text);
+                        }
+                    }
+                }
+                ParagraphChild::Hyperlink(h) => self.add_paragraph_children(&h.children, text),
+                ParagraphChild::ComplexField(f) => {
+                    self.add_paragraph_children(&f.cached_result, text)
+                }
+                ParagraphChild::StructuredDataTag(t) => {
+                    for c in &t.children {
+                        if let StructuredDataTagChild::Run(r) = c {
+                            self.add_run(r, text);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn add_run(&mut self, run: &Run, text: &mut String) {
+        for c in &run.children {
+            match c {
+                RunChild::Text(t) => text.push_str(&replace_escaped(&t.text)),
+                RunChild::Sym(_) => text.push('□'),
+                RunChild::Tab(_) => text.push('\t'),
+                RunChild::Break(_) => text.push('\n'),
+                RunChild::Drawing(d) => match &d.data {
+                    Some(DrawingData::Pic(_)) => self.images += 1,
+                    Some(DrawingData::TextBox(t)) => self.add_text_box(&t.children),
+                    Some(DrawingData::Chart(_)) | Some(DrawingData::Shape(_)) | None => {}
+                },
+                RunChild::Shape(s) => {
+                    for content in s.text_boxes() {
+                        self.add_text_box(&content.children);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn add_text_box(&mut self, children: &[TextBoxContentChild]) {
+        for c in children {
+            match c {
+                TextBoxContentChild::Paragraph(p) => self.add_paragraph(p),
+                TextBoxContentChild::Table(t) => self.add_table(t),
+            }
+        }
+    }
+
+    fn add_table(&mut self, t: &Table) {
+        self.tables += 1;
+        for TableChild::TableRow(row) in &t.rows {
+            for TableRowChild::TableCell(cell) in &row.cells {
+                for c in &cell.children {
+                    match c {
+                        TableCellContent::Paragraph(p) => self.add_paragraph(p),
+                        TableCellContent::Table(t) => self.add_table(t),
+                        TableCellContent::StructuredDataTag(t) => self.add_structured_data_tag(t),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    fn add_structured_data_tag(&mut self, t: &StructuredDataTag) {
+        let mut text = String::new();
+        for c in &t.children {
+            match c {
+                StructuredDataTagChild::Paragraph(p) => self.add_paragraph(p),
+                StructuredDataTagChild::Table(t) => self.add_table(t),
+                StructuredDataTagChild::StructuredDataTag(t) => self.add_structured_data_tag(t),
+                StructuredDataTagChild::Run(r) => self.add_run(r, &mut text),
+                _ => {}
+            }
+        }
+        self.add_text(&text);
+    }
+}
+
+impl Docx {
+    pub fn statistics(&self) -> Statistics {
+        let mut s = Statistics::default();
+        for c in &self.document.children {
+            match c {
+                DocumentChild::Paragraph(p) => s.add_paragraph(p),
+                DocumentChild::Table(t) => s.add_table(t),
+                DocumentChild::StructuredDataTag(t) => s.add_structured_data_tag(t),
+                _ => {}
+            }
+        }
+        s
+    }
+
+    pub fn app_statistics(mut self, enabled: bool) -> Docx {
+        self.app_statistics = enabled;
+        self
+    }
+
+    // The counts of `docProps/app.xml`, and the application when it is not set.
+    pub(crate) fn update_app_statistics(&mut self) {
+        let s = self.statistics();
+        let app = &mut self.doc_props.app;
+        app.words = Some(s.words);
+        app.characters = Some(s.characters);
+        app.characters_with_spaces = Some(s.characters_with_spaces);
+        app.paragraphs = Some(s.paragraphs);
+        app.pages = Some(self.document.section_property.estimate_pages(&s));
+        if app.application.is_none() {
+            app.application = Some("docx-rs".to_owned());
+            app.app_version = Some(app_version());
+        }
+    }
+}
+
+impl SectionProperty {
+    fn estimate_pages(&self, s: &Statistics) -> usize {
+        let m = &self.page_margin;
+        let width = i64::from(self.page_size.w) - i64::from(m.left) - i64::from(m.right);
+        let height = i64::from(self.page_size.h) - i64::from(m.top) - i64::from(m.bottom);
+        let chars_per_line = (width / ESTIMATED_CHAR_WIDTH).max(1) as usize;
+        let lines_per_page = (height / ESTIMATED_LINE_HEIGHT).max(1) as usize;
+        let lines = s.paragraphs + s.characters_with_spaces / chars_per_line;
+        ((lines + lines_per_page - 1) / lines_per_page).max(1)
+    }
+}
+
+// `AppVersion` is written as `XX.YYYY`.
+fn app_version() -> String {
+    let mut v = env!("CARGO_PKG_VERSION")
+        .split('.')
+        .map(|n| n.parse::<usize>().unwrap_or_default());
+    let major = v.next().unwrap_or_default();
+    let minor = v.next().unwrap_or_default() * 100 + v.next().unwrap_or_default();
+    format!("{:02}.{:04}", major, minor)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_statistics() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Hel"))
+                    .add_run(Run::new().add_text("lo, world"))
+                    .add_delete(Delete::new().add_run(Run::new().add_delete_text(" removed"))),
+            )
+            .add_paragraph(Paragraph::new())
+            .add_table(Table::new(vec![TableRow::new(vec![
+                TableCell::new()
+                    .add_paragraph(Paragraph::new().add_run(Run::new().add_text("日本語"))),
+                TableCell::new().add_paragraph(
+                    Paragraph::new().add_run(Run::new().add_image(Pic::with_empty())),
+                ),
+            ])]));
+        assert_eq!(
+            docx.statistics(),
+            Statistics {
+                words: 5,
+                characters: 14,
+                characters_with_spaces: 15,
+                paragraphs: 2,
+                tables: 1,
+                images: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_app_statistics() {
+        let mut docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello, world")))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("a ".repeat(3000))));
+        docx.update_app_statistics();
+        let app = docx.app_props();
+        assert_eq!(app.words, Some(3002));
+        assert_eq!(app.characters, Some(3011));
+        assert_eq!(app.characters_with_spaces, Some(6012));
+        assert_eq!(app.paragraphs, Some(2));
+        assert_eq!(app.pages, Some(2));
+        assert_eq!(app.application, Some("docx-rs".to_owned()));
+        assert_eq!(app.app_version, Some(app_version()));
+        assert_eq!(app_version().len(), 7);
+
+        let xml = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+            .app_statistics(false)
+            .build();
+        assert!(!std::str::from_utf8(&xml.doc_props.app)
+            .unwrap()
+            .contains("<Words>"));
+    }
+}
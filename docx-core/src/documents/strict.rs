@@ -0,0 +1,264 @@
+use super::*;
+use crate::types::Conformance;
+
+const TRANSITIONAL_URI: &str = "http://schemas.openxmlformats.org/";
+const STRICT_URI: &str = "http://purl.oclc.org/ooxml/";
+
+// Namespaces and relationship types which are renamed, not only moved.
+const RENAMED_URIS: [(&str, &str); 4] = [
+    (
+        "officeDocument/2006/relationships/extended-properties",
+        "officeDocument/relationships/extendedProperties",
+    ),
+    (
+        "officeDocument/2006/relationships/custom-properties",
+        "officeDocument/relationships/customProperties",
+    ),
+    (
+        "officeDocument/2006/extended-properties",
+        "officeDocument/extendedProperties",
+    ),
+    (
+        "officeDocument/2006/custom-properties",
+        "officeDocument/customProperties",
+    ),
+];
+
+// The packaging and markup compatibility namespaces are the same in both classes.
+const MOVED_URIS: [(&str, &str); 3] = [
+    ("wordprocessingml/2006/main", "wordprocessingml/main"),
+    ("drawingml/2006/", "drawingml/"),
+    ("officeDocument/2006/", "officeDocument/"),
+];
+
+// Elements whose `w:left` and `w:right` children are `w:start` and `w:end` in strict.
+const SIDE_CONTAINERS: [&str; 4] = ["w:tblBorders", "w:tcBorders", "w:tblCellMar", "w:tcMar"];
+
+impl Docx {
+    pub fn conformance(mut self, c: Conformance) -> Docx {
+        self.conformance = c;
+        self
+    }
+}
+
+impl XMLDocx {
+    pub(crate) fn into_strict(self) -> XMLDocx {
+        self.map_xml_parts(to_strict)
+    }
+}
+
+pub(crate) fn to_strict(xml: &[u8]) -> Vec<u8> {
+    let xml = match std::str::from_utf8(xml) {
+        Ok(xml) => xml,
+        Err(_) => return xml.to_vec(),
+    };
+    let mut out = String::with_capacity(xml.len());
+    let mut rest = xml;
+    // The depth of the open elements of `SIDE_CONTAINERS`.
+    let mut sides = 0;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = match tag_end(rest) {
+            Some(end) => end,
+            None => break,
+        };
+        out.push_str(&strict_tag(&rest[..=end], &mut sides));
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    out.into_bytes()
+}
+
+// The index of the `>` closing the tag, skipping those in attribute values.
+fn tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in tag.char_indices() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn strict_uri(uri: &str) -> Option<String> {
+    let path = uri.strip_prefix(TRANSITIONAL_URI)?;
+    if let Some((_, to)) = RENAMED_URIS.iter().find(|(from, _)| *from == path) {
+        return Some(format!("{}{}", STRICT_URI, to));
+    }
+    MOVED_URIS.iter().find_map(|(from, to)| {
+        let rest = path.strip_prefix(from)?;
+        if from.ends_with('/') || rest.is_empty() {
+            Some(format!("{}{}{}", STRICT_URI, to, rest))
+        } else {
+            None
+        }
+    })
+}
+
+fn strict_side(name: &str) -> &str {
+    match name {
+        "w:left" => "w:start",
+        "w:right" => "w:end",
+        _ => name,
+    }
+}
+
+struct Attribute<'a> {
+    // The white space before the name.
+    space: &'a str,
+    name: &'a str,
+    quote: char,
+    value: &'a str,
+}
+
+fn strict_tag(tag: &str, sides: &mut usize) -> String {
+    if tag.starts_with("<?") || tag.starts_with("<!") {
+        return tag.to_owned();
+    }
+    if let Some(name) = tag.strip_prefix("</") {
+        let name = name.trim_end_matches('>').trim();
+        if SIDE_CONTAINERS.contains(&name) {
+            *sides = sides.saturating_sub(1);
+            return tag.to_owned();
+        }
+        if *sides > 0 {
+            return tag.replacen(name, strict_side(name), 1);
+        }
+        return tag.to_owned();
+    }
+    let body = &tag[1..];
+    let name_len = body
+        .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+        .unwrap_or(body.len());
+    let name = &body[..name_len];
+    let (attributes, tail) = match attributes(&body[name_len..]) {
+        Some(a) => a,
+        None => return tag.to_owned(),
+    };
+    let self_closing = tail.trim_start().starts_with('/');
+
+    let mut out = String::with_capacity(tag.len());
+    out.push('<');
+    out.push_str(if *sides > 0 { strict_side(name) } else { name });
+    for a in &attributes {
+        let mut attr_name = a.name;
+        let mut value = a.value.to_owned();
+        if a.name.starts_with("xmlns") || a.name == "Type" {
+            if let Some(uri) = strict_uri(a.value) {
+                value = uri;
+            }
+        }
+        if name == "w:ind" {
+            attr_name = match a.name {
+                "w:left" => "w:start",
+                "w:right" => "w:end",
+                "w:leftChars" => "w:startChars",
+                "w:rightChars" => "w:endChars",
+                n => n,
+            };
+        }
+        if (name == "w:jc" || name == "w:lvlJc") && a.name == "w:val" {
+            value = match a.value {
+                "left" => "start".to_owned(),
+                "right" => "end".to_owned(),
+                v => v.to_owned(),
+            };
+        }
+        out.push_str(a.space);
+        out.push_str(attr_name);
+        out.push('=');
+        out.push(a.quote);
+        out.push_str(&value);
+        out.push(a.quote);
+    }
+    if name == "w:document" && !attributes.iter().any(|a| a.name == "w:conformance") {
+        out.push_str(" w:conformance=\"strict\"");
+    }
+    out.push_str(tail);
+
+    if SIDE_CONTAINERS.contains(&name) && !self_closing {
+        *sides += 1;
+    }
+    out
+}
+
+// The attributes and what follows them, such as ` />`.
+fn attributes(mut s: &str) -> Option<(Vec<Attribute>, &str)> {
+    let mut attributes = vec![];
+    loop {
+        let trimmed = s.trim_start();
+        if trimmed.starts_with('/') || trimmed.starts_with('>') {
+            return Some((attributes, s));
+        }
+        let space = &s[..s.len() - trimmed.len()];
+        let eq = trimmed.find('=')?;
+        let name = trimmed[..eq].trim_end();
+        let after = trimmed[eq + 1..].trim_start();
+        let quote = after.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let len = after[1..].find(quote)?;
+        attributes.push(Attribute {
+            space,
+            name,
+            quote,
+            value: &after[1..1 + len],
+        });
+        s = &after[len + 2..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    fn strict(xml: &str) -> String {
+        String::from_utf8(to_strict(xml.as_bytes())).unwrap()
+    }
+
+    #[test]
+    fn test_strict_namespaces() {
+        assert_eq!(
+            strict(
+                r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006"><w:t>http://schemas.openxmlformats.org/drawingml/2006/main</w:t></w:document>"#
+            ),
+            r#"<w:document xmlns:w="http://purl.oclc.org/ooxml/wordprocessingml/main" xmlns:wp="http://purl.oclc.org/ooxml/drawingml/wordprocessingDrawing" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" w:conformance="strict"><w:t>http://schemas.openxmlformats.org/drawingml/2006/main</w:t></w:document>"#
+        );
+        assert_eq!(
+            strict(
+                r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties" Target="docProps/app.xml" /><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" />"#
+            ),
+            r#"<Relationship Id="rId1" Type="http://purl.oclc.org/ooxml/officeDocument/relationships/extendedProperties" Target="docProps/app.xml" /><Relationship Id="rId2" Type="http://purl.oclc.org/ooxml/officeDocument/relationships/image" Target="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" />"#
+        );
+    }
+
+    #[test]
+    fn test_strict_spellings() {
+        assert_eq!(
+            strict(
+                r#"<w:pPr><w:ind w:left="720" w:rightChars="100" w:hanging="360" /><w:jc w:val="right" /></w:pPr><w:tblPr><w:tblBorders><w:left w:val="single" /></w:tblBorders><w:tblCellMar><w:right w:w="55" w:type="dxa"></w:right></w:tblCellMar></w:tblPr><w:pBdr><w:left w:val="single" /></w:pBdr>"#
+            ),
+            r#"<w:pPr><w:ind w:start="720" w:endChars="100" w:hanging="360" /><w:jc w:val="end" /></w:pPr><w:tblPr><w:tblBorders><w:start w:val="single" /></w:tblBorders><w:tblCellMar><w:end w:w="55" w:type="dxa"></w:end></w:tblCellMar></w:tblPr><w:pBdr><w:left w:val="single" /></w:pBdr>"#
+        );
+    }
+
+    #[test]
+    fn test_build_strict() {
+        let xml = Docx::new()
+            .add_paragraph(Paragraph::new().align(crate::types::AlignmentType::Left))
+            .conformance(Conformance::Strict)
+            .build();
+        let document = str::from_utf8(&xml.document).unwrap();
+        assert!(document.contains("w:conformance=\"strict\""));
+        assert!(document.contains("<w:jc w:val=\"start\" />"));
+        assert!(!document.contains("schemas.openxmlformats.org/wordprocessingml"));
+        let settings = str::from_utf8(&xml.settings).unwrap();
+        assert!(!settings.contains("w:spaceForUL"));
+    }
+}
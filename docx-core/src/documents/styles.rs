@@ -9,7 +9,7 @@ use crate::xml_builder::*;
 #[serde(rename_all = "camelCase")]
 pub struct Styles {
     doc_defaults: DocDefaults,
-    styles: Vec<Style>,
+    pub(crate) styles: Vec<Style>,
 }
 
 impl Styles {
@@ -51,6 +51,40 @@ impl Styles {
         self.styles.iter().find(|s| s.style_id == id)
     }
 
+    // The style and the styles it is based on, from the style itself to the root of the
+    // `basedOn` chain. Properties found earlier in the chain take precedence.
+    pub fn based_on_chain(&self, style_id: &str) -> Vec<&Style> {
+        let mut chain: Vec<&Style> = vec![];
+        let mut style = self.find_style_by_id(style_id);
+        while let Some(s) = style {
+            // Stop at cyclic `basedOn`.
+            if chain.iter().any(|c| c.style_id == s.style_id) {
+                break;
+            }
+            chain.push(s);
+            style = s
+                .based_on
+                .as_ref()
+                .and_then(|b| self.find_style_by_id(&b.val));
+        }
+        chain
+    }
+
+    // numId and ilvl of the `numPr` of the style, inherited through `basedOn`.
+    pub(crate) fn numbering(&self, style_id: &str) -> Option<(Option<usize>, Option<usize>)> {
+        let (mut id, mut level) = (None, None);
+        for s in self.based_on_chain(style_id) {
+            if let Some(n) = &s.paragraph_property.numbering_property {
+                id = id.or_else(|| n.id.as_ref().map(|i| i.id));
+                level = level.or_else(|| n.level.as_ref().map(|l| l.val));
+            }
+        }
+        if id.is_none() && level.is_none() {
+            return None;
+        }
+        Some((id, level))
+    }
+
     pub fn create_heading_style_map(&self) -> std::collections::HashMap<String, usize> {
         self.styles
             .iter()
@@ -124,12 +158,12 @@ mod tests {
         let b = c.build();
         assert_eq!(
             str::from_utf8(&b).unwrap(),
-            r#"<w:styles xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" mc:Ignorable="w14 w15"><w:docDefaults><w:rPrDefault><w:rPr /></w:rPrDefault><w:pPrDefault><w:pPr><w:rPr /></w:pPr></w:pPrDefault></w:docDefaults><w:style w:type="paragraph" w:styleId="Normal"><w:name w:val="Normal" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:qFormat /></w:style><w:style w:type="table" w:styleId="Table"><w:name w:val="Table Style" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:tcPr /><w:tblPr><w:tblW w:w="0" w:type="auto" /><w:jc w:val="left" /><w:tblBorders><w:top w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:left w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:bottom w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:right w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:insideH w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:insideV w:val="single" w:sz="2" w:space="0" w:color="000000" /></w:tblBorders><w:tblCellMar>
+            r#"<w:styles xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml" mc:Ignorable="w14 w15"><w:docDefaults><w:rPrDefault><w:rPr /></w:rPrDefault><w:pPrDefault><w:pPr><w:rPr /></w:pPr></w:pPrDefault></w:docDefaults><w:style w:type="paragraph" w:styleId="Normal"><w:name w:val="Normal" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:qFormat /></w:style><w:style w:type="table" w:styleId="Table"><w:name w:val="Table Style" /><w:rPr /><w:pPr><w:rPr /></w:pPr><w:tblPr><w:tblW w:w="0" w:type="auto" /><w:jc w:val="left" /><w:tblBorders><w:top w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:left w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:bottom w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:right w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:insideH w:val="single" w:sz="2" w:space="0" w:color="000000" /><w:insideV w:val="single" w:sz="2" w:space="0" w:color="000000" /></w:tblBorders><w:tblCellMar>
   <w:top w:w="0" w:type="dxa" />
   <w:left w:w="108" w:type="dxa" />
   <w:bottom w:w="0" w:type="dxa" />
   <w:right w:w="108" w:type="dxa" />
-</w:tblCellMar></w:tblPr><w:qFormat /></w:style></w:styles>"#
+</w:tblCellMar></w:tblPr><w:tcPr /><w:qFormat /></w:style></w:styles>"#
         );
     }
 
@@ -141,4 +175,23 @@ mod tests {
         let b = c.create_heading_style_map();
         assert_eq!(b, m);
     }
+
+    #[test]
+    fn test_based_on_chain() {
+        let c = Styles::new()
+            .add_style(Style::new("Heading1", StyleType::Paragraph).based_on("Heading"))
+            .add_style(Style::new("Heading", StyleType::Paragraph).based_on("Normal"))
+            .add_style(Style::new("Normal", StyleType::Paragraph))
+            .add_style(Style::new("A", StyleType::Paragraph).based_on("B"))
+            .add_style(Style::new("B", StyleType::Paragraph).based_on("A"));
+        let ids = |id: &str| -> Vec<String> {
+            c.based_on_chain(id)
+                .iter()
+                .map(|s| s.style_id.clone())
+                .collect()
+        };
+        assert_eq!(ids("Heading1"), vec!["Heading1", "Heading", "Normal"]);
+        assert_eq!(ids("A"), vec!["A", "B"]);
+        assert!(ids("Missing").is_empty());
+    }
 }
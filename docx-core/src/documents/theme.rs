@@ -1,6 +1,24 @@
 use serde::Serialize;
 
 use super::*;
+use crate::documents::BuildXML;
+use crate::xml_builder::*;
+
+// The colors of Word's default theme, in the order of `a:clrScheme`.
+const OFFICE_COLORS: [(&str, &str); 12] = [
+    ("dk1", "000000"),
+    ("lt1", "FFFFFF"),
+    ("dk2", "44546A"),
+    ("lt2", "E7E6E6"),
+    ("accent1", "4472C4"),
+    ("accent2", "ED7D31"),
+    ("accent3", "A5A5A5"),
+    ("accent4", "FFC000"),
+    ("accent5", "5B9BD5"),
+    ("accent6", "70AD47"),
+    ("hlink", "0563C1"),
+    ("folHlink", "954F72"),
+];
 
 #[derive(Debug, Clone, PartialEq, Serialize, Default)]
 #[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
@@ -8,4 +26,273 @@ use super::*;
 #[serde(rename_all = "camelCase")]
 pub struct Theme {
     pub font_schema: FontScheme,
+    pub color_scheme: ColorScheme,
+}
+
+impl Theme {
+    // Word's default theme, whose colors and fonts are replaced by the builders.
+    pub fn new() -> Theme {
+        Theme::default()
+            .color_scheme(ColorScheme::new().name("Office"))
+            .major_font(FontGroup::new("Calibri Light"))
+            .minor_font(FontGroup::new("Calibri"))
+    }
+
+    // Slots which are not set keep the colors of Word's default theme.
+    pub fn color_scheme(mut self, mut c: ColorScheme) -> Self {
+        for (slot, rgb) in OFFICE_COLORS.iter() {
+            if let Some(color) = c.slot_mut(slot).filter(|color| color.is_empty()) {
+                *color = rgb.to_string();
+            }
+        }
+        self.color_scheme = c;
+        self
+    }
+
+    pub fn major_font(mut self, f: FontGroup) -> Self {
+        self.font_schema.major_font = f;
+        self
+    }
+
+    pub fn minor_font(mut self, f: FontGroup) -> Self {
+        self.font_schema.minor_font = f;
+        self
+    }
+
+    // RGB of `w:themeColor` and `w:themeFill` values such as `accent1`. The text and
+    // background colors are mapped to the dark and light colors as Word does by default.
+    pub fn color(&self, theme_color: &str) -> Option<&str> {
+        let c = &self.color_scheme;
+        let rgb = match theme_color {
+            "dark1" | "text1" => &c.dk1,
+            "light1" | "background1" => &c.lt1,
+            "dark2" | "text2" => &c.dk2,
+            "light2" | "background2" => &c.lt2,
+            "accent1" => &c.accent1,
+            "accent2" => &c.accent2,
+            "accent3" => &c.accent3,
+            "accent4" => &c.accent4,
+            "accent5" => &c.accent5,
+            "accent6" => &c.accent6,
+            "hyperlink" => &c.hlink,
+            "followedHyperlink" => &c.fol_hlink,
+            _ => return None,
+        };
+        Some(rgb.as_str()).filter(|rgb| !rgb.is_empty())
+    }
+
+    // Typeface of `w:asciiTheme` values such as `minorHAnsi`, or of DrawingML references
+    // such as `+mn-lt`.
+    pub fn font(&self, theme_font: &str) -> Option<&str> {
+        let s = &self.font_schema;
+        let typeface = match theme_font {
+            "majorAscii" | "majorHAnsi" | "+mj-lt" => &s.major_font.latin,
+            "majorEastAsia" | "+mj-ea" => &s.major_font.ea,
+            "majorBidi" | "+mj-cs" => &s.major_font.cs,
+            "minorAscii" | "minorHAnsi" | "+mn-lt" => &s.minor_font.latin,
+            "minorEastAsia" | "+mn-ea" => &s.minor_font.ea,
+            "minorBidi" | "+mn-cs" => &s.minor_font.cs,
+            _ => return None,
+        };
+        Some(typeface.as_str()).filter(|t| !t.is_empty())
+    }
+}
+
+impl Docx {
+    // Write the theme into `word/theme/theme1.xml` and use its minor fonts as the default fonts
+    // of the styles.
+    pub fn theme(mut self, t: Theme) -> Self {
+        self.themes = vec![t];
+        self.document_rels.has_theme = true;
+        self.content_type = self.content_type.add_content(
+            "/word/theme/theme1.xml",
+            "application/vnd.openxmlformats-officedocument.theme+xml",
+        );
+        self.default_fonts(
+            RunFonts::new()
+                .ascii_theme("minorHAnsi")
+                .hi_ansi_theme("minorHAnsi")
+                .east_asia_theme("minorEastAsia")
+                .cs_theme("minorBidi"),
+        )
+    }
+}
+
+fn build_font_group<W: std::io::Write>(b: XMLBuilder<W>, f: &FontGroup) -> XMLBuilder<W> {
+    let mut b = b.a_latin(&f.latin).a_ea(&f.ea).a_cs(&f.cs);
+    for font in &f.fonts {
+        b = b.a_font(&font.script, &font.typeface);
+    }
+    b
+}
+
+impl BuildXML for Theme {
+    fn build(&self) -> Vec<u8> {
+        let name = if self.color_scheme.name.is_empty() {
+            "Office"
+        } else {
+            &self.color_scheme.name
+        };
+        let mut b = XMLBuilder::new()
+            .declaration(Some(true))
+            .open_theme(name)
+            .open_theme_elements()
+            .open_color_scheme(name);
+        for (slot, default_rgb) in OFFICE_COLORS.iter() {
+            let rgb = self
+                .color_scheme
+                .slot(slot)
+                .filter(|rgb| !rgb.is_empty())
+                .unwrap_or(default_rgb);
+            b = b.open_color_slot(slot).a_srgb_color(rgb).close();
+        }
+        b = b.close().open_font_scheme(name).open_major_font();
+        b = build_font_group(b, &self.font_schema.major_font)
+            .close()
+            .open_minor_font();
+        b = build_font_group(b, &self.font_schema.minor_font)
+            .close()
+            .close();
+
+        // The fill, line and effect styles of shapes are plain, so that they follow the colors.
+        b = b.open_format_scheme(name).open_fill_style_list();
+        for _ in 0..3 {
+            b = b.open_a_solid_fill().a_scheme_color("phClr").close();
+        }
+        b = b.close().open_line_style_list();
+        for w in ["6350", "12700", "19050"].iter() {
+            b = b
+                .open_a_line(w)
+                .open_a_solid_fill()
+                .a_scheme_color("phClr")
+                .close()
+                .close();
+        }
+        b = b.close().open_effect_style_list();
+        for _ in 0..3 {
+            b = b.open_effect_style().a_effect_list().close();
+        }
+        b = b.close().open_background_fill_style_list();
+        for _ in 0..3 {
+            b = b.open_a_solid_fill().a_scheme_color("phClr").close();
+        }
+        b.close()
+            .close()
+            .close()
+            .a_object_defaults()
+            .a_extra_color_scheme_list()
+            .close()
+            .build()
+    }
+}
+
+// Apply `w:themeTint` or `w:themeShade`, hex values from `00` to `FF`, to a RGB color.
+// A tint mixes the color with white, and a shade with black.
+pub(crate) fn tint_and_shade(rgb: &str, tint: Option<&str>, shade: Option<&str>) -> String {
+    let ratio = |v: Option<&str>| {
+        v.and_then(|v| u8::from_str_radix(v, 16).ok())
+            .map(|v| v as f64 / 255.0)
+    };
+    let (tint, shade) = (ratio(tint), ratio(shade));
+    if rgb.len() != 6 || (tint.is_none() && shade.is_none()) {
+        return rgb.to_owned();
+    }
+    let mut res = String::new();
+    for i in 0..3 {
+        let c = match rgb
+            .get(i * 2..i * 2 + 2)
+            .and_then(|c| u8::from_str_radix(c, 16).ok())
+        {
+            Some(c) => c as f64,
+            None => return rgb.to_owned(),
+        };
+        let c = match tint {
+            Some(t) => c * t + 255.0 * (1.0 - t),
+            None => c,
+        };
+        let c = match shade {
+            Some(s) => c * s,
+            None => c,
+        };
+        res.push_str(&format!("{:02X}", c.round() as u8));
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_theme_color_and_font() {
+        let mut theme = Theme::default();
+        theme.color_scheme.dk1 = "000000".to_owned();
+        theme.color_scheme.accent1 = "4472C4".to_owned();
+        theme.font_schema.minor_font.latin = "Calibri".to_owned();
+        theme.font_schema.major_font.ea = "MS Gothic".to_owned();
+        assert_eq!(theme.color("text1"), Some("000000"));
+        assert_eq!(theme.color("accent1"), Some("4472C4"));
+        assert_eq!(theme.color("accent2"), None);
+        assert_eq!(theme.font("minorHAnsi"), Some("Calibri"));
+        assert_eq!(theme.font("+mn-lt"), Some("Calibri"));
+        assert_eq!(theme.font("majorEastAsia"), Some("MS Gothic"));
+        assert_eq!(theme.font("majorBidi"), None);
+    }
+
+    #[test]
+    fn test_build() {
+        let b = Theme::new()
+            .color_scheme(ColorScheme::new().name("Acme").accent1("E4002B"))
+            .major_font(FontGroup::new("Georgia").add_font("Jpan", "MS Mincho"))
+            .build();
+        let xml = str::from_utf8(&b).unwrap();
+        assert!(xml.contains(
+            r#"<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Acme">"#
+        ));
+        assert!(xml.contains(
+            r#"<a:accent1>
+        <a:srgbClr val="E4002B" />
+      </a:accent1>
+      <a:accent2>
+        <a:srgbClr val="ED7D31" />
+      </a:accent2>"#
+        ));
+        assert!(xml.contains(
+            r#"<a:majorFont>
+        <a:latin typeface="Georgia" />
+        <a:ea typeface="" />
+        <a:cs typeface="" />
+        <a:font script="Jpan" typeface="MS Mincho" />
+      </a:majorFont>"#
+        ));
+    }
+
+    #[test]
+    fn test_read_written_theme() {
+        let xml = Docx::new()
+            .theme(Theme::new().color_scheme(ColorScheme::new().accent1("E4002B")))
+            .build();
+        assert!(str::from_utf8(&xml.styles)
+            .unwrap()
+            .contains(r#"<w:rFonts w:asciiTheme="minorHAnsi" w:hAnsiTheme="minorHAnsi" w:cstheme="minorBidi" w:eastAsiaTheme="minorEastAsia" />"#));
+        let mut buf = std::io::Cursor::new(vec![]);
+        xml.pack(&mut buf).unwrap();
+        let docx = crate::read_docx(buf.get_ref()).unwrap();
+        let theme = &docx.themes[0];
+        assert_eq!(theme.color("accent1"), Some("E4002B"));
+        assert_eq!(theme.color("accent2"), Some("ED7D31"));
+        assert_eq!(theme.font("minorHAnsi"), Some("Calibri"));
+    }
+
+    #[test]
+    fn test_tint_and_shade() {
+        assert_eq!(tint_and_shade("4472C4", None, None), "4472C4");
+        assert_eq!(tint_and_shade("4472C4", Some("99"), None), "8FAADC");
+        assert_eq!(tint_and_shade("4472C4", None, Some("BF")), "335593");
+        assert_eq!(tint_and_shade("auto", Some("99"), None), "auto");
+    }
 }
@@ -0,0 +1,588 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use serde::Serialize;
+
+use super::*;
+
+// Word refuses bookmark names longer than this.
+const MAX_BOOKMARK_NAME_LENGTH: usize = 40;
+// Levels of a numbering definition are 0 to 8.
+const MAX_NUMBERING_LEVEL: usize = 8;
+// Font sizes are in half points, from 1pt to 1638pt.
+const FONT_SIZE_RANGE: (usize, usize) = (2, 3276);
+// Page sizes are in twips, up to 22 inches.
+const MAX_PAGE_SIZE: u32 = 31680;
+
+// Something which Word would reject or silently drop, although it is written as is.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ValidationIssue {
+    // A hyperlink or an image whose relationship is not in the package.
+    #[serde(rename_all = "camelCase")]
+    DanglingRelationship { id: String },
+    // A section referring to a header or footer which is not set.
+    #[serde(rename_all = "camelCase")]
+    MissingHeaderOrFooter { id: String },
+    #[serde(rename_all = "camelCase")]
+    MissingStyle { style_id: String },
+    #[serde(rename_all = "camelCase")]
+    MissingNumbering { id: usize },
+    #[serde(rename_all = "camelCase")]
+    MissingAbstractNumbering { id: usize },
+    #[serde(rename_all = "camelCase")]
+    DuplicateBookmark { name: String },
+    #[serde(rename_all = "camelCase")]
+    DuplicateBookmarkId { id: usize },
+    // A row without cells, given by its index in the table.
+    #[serde(rename_all = "camelCase")]
+    EmptyTableRow { row: usize },
+    #[serde(rename_all = "camelCase")]
+    OutOfRange { name: String, value: String },
+    // Something of the transitional schemas in a document written as strict.
+    #[serde(rename_all = "camelCase")]
+    TransitionalOnly { feature: String },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationIssue::DanglingRelationship { id } => {
+                write!(f, "Relationship {} is not found.", id)
+            }
+            ValidationIssue::MissingHeaderOrFooter { id } => {
+                write!(f, "Header or footer {} is not found.", id)
+            }
+            ValidationIssue::MissingStyle { style_id } => {
+                write!(f, "Style {} is not defined.", style_id)
+            }
+            ValidationIssue::MissingNumbering { id } => {
+                write!(f, "Numbering {} is not defined.", id)
+            }
+            ValidationIssue::MissingAbstractNumbering { id } => {
+                write!(f, "Abstract numbering {} is not defined.", id)
+            }
+            ValidationIssue::DuplicateBookmark { name } => {
+                write!(f, "Bookmark {} is duplicated.", name)
+            }
+            ValidationIssue::DuplicateBookmarkId { id } => {
+                write!(f, "Bookmark id {} is duplicated.", id)
+            }
+            ValidationIssue::EmptyTableRow { row } => {
+                write!(f, "Table row {} has no cells.", row)
+            }
+            ValidationIssue::OutOfRange { name, value } => {
+                write!(f, "{} {} is out of range.", name, value)
+            }
+            ValidationIssue::TransitionalOnly { feature } => {
+                write!(f, "{} is not allowed in strict documents.", feature)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationIssue {
+    // Whether Word refuses to open the document, rather than dropping what is wrong.
+    pub fn is_error(&self) -> bool {
+        matches!(
+            self,
+            ValidationIssue::DanglingRelationship { .. }
+                | ValidationIssue::MissingHeaderOrFooter { .. }
+                | ValidationIssue::MissingNumbering { .. }
+                | ValidationIssue::MissingAbstractNumbering { .. }
+                | ValidationIssue::DuplicateBookmarkId { .. }
+                | ValidationIssue::EmptyTableRow { .. }
+        )
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let issues: Vec<String> = self.issues.iter().map(|i| i.to_string()).collect();
+        write!(f, "{}", issues.join(" "))
+    }
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(|i| i.is_error())
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    fn add(&mut self, issue: ValidationIssue) {
+        if !self.issues.contains(&issue) {
+            self.issues.push(issue);
+        }
+    }
+}
+
+struct Validator<'a> {
+    docx: &'a Docx,
+    report: ValidationReport,
+    bookmarks: HashSet<String>,
+    bookmark_ids: HashSet<usize>,
+    // Hyperlinks and images of headers and footers have their own relationships, which are
+    // not kept, so they are only checked in the body.
+    in_body: bool,
+}
+
+impl<'a> Validator<'a> {
+    fn style(&mut self, id: &str) {
+        if self.docx.styles.find_style_by_id(id).is_none() {
+            self.report.add(ValidationIssue::MissingStyle {
+                style_id: id.to_owned(),
+            });
+        }
+    }
+
+    fn numbering(&mut self, n: &NumberingProperty) {
+        // `0` removes the numbering inherited from the style.
+        if let Some(id) = n.id.as_ref().map(|id| id.id).filter(|id| *id != 0) {
+            let numbering = self.docx.numberings.numberings.iter().find(|n| n.id == id);
+            match numbering {
+                Some(numbering) => {
+                    let abstract_id = numbering.abstract_num_id;
+                    let abstract_nums = &self.docx.numberings.abstract_nums;
+                    if !abstract_nums.iter().any(|a| a.id == abstract_id) {
+                        self.report
+                            .add(ValidationIssue::MissingAbstractNumbering { id: abstract_id });
+                    }
+                }
+                None => self.report.add(ValidationIssue::MissingNumbering { id }),
+            }
+        }
+        if let Some(level) = &n.level {
+            if level.val > MAX_NUMBERING_LEVEL {
+                self.out_of_range("numbering level", level.val);
+            }
+        }
+    }
+
+    fn out_of_range(&mut self, name: &str, value: impl ToString) {
+        self.report.add(ValidationIssue::OutOfRange {
+            name: name.to_owned(),
+            value: value.to_string(),
+        });
+    }
+
+    fn run_property(&mut self, p: &RunProperty) {
+        if let Some(s) = &p.style {
+            self.style(&s.val);
+        }
+        if let Some(sz) = &p.sz {
+            if sz.val < FONT_SIZE_RANGE.0 || sz.val > FONT_SIZE_RANGE.1 {
+                self.out_of_range("font size", sz.val);
+            }
+        }
+    }
+
+    fn paragraph_property(&mut self, p: &ParagraphProperty) {
+        self.run_property(&p.run_property);
+        if let Some(s) = &p.style {
+            self.style(&s.val);
+        }
+        if let Some(n) = &p.numbering_property {
+            self.numbering(n);
+        }
+        if let Some(s) = &p.section_property {
+            self.section(s);
+        }
+    }
+
+    fn section(&mut self, s: &SectionProperty) {
+        let headers = [
+            (&s.header_reference, s.header.is_some()),
+            (&s.first_header_reference, s.first_header.is_some()),
+            (&s.even_header_reference, s.even_header.is_some()),
+        ];
+        for (r, found) in headers {
+            if let Some(r) = r.as_ref().filter(|_| !found) {
+                self.report
+                    .add(ValidationIssue::MissingHeaderOrFooter { id: r.id.clone() });
+            }
+        }
+        let footers = [
+            (&s.footer_reference, s.footer.is_some()),
+            (&s.first_footer_reference, s.first_footer.is_some()),
+            (&s.even_footer_reference, s.even_footer.is_some()),
+        ];
+        for (r, found) in footers {
+            if let Some(r) = r.as_ref().filter(|_| !found) {
+                self.report
+                    .add(ValidationIssue::MissingHeaderOrFooter { id: r.id.clone() });
+            }
+        }
+        let size = &s.page_size;
+        for v in [size.w, size.h] {
+            if v == 0 || v > MAX_PAGE_SIZE {
+                self.out_of_range("page size", v);
+            }
+        }
+
+        self.in_body = false;
+        let headers = [&s.header, &s.first_header, &s.even_header];
+        for h in headers.iter().filter_map(|h| h.as_ref()) {
+            for c in &h.children {
+                match c {
+                    HeaderChild::Paragraph(p) => self.paragraph(p),
+                    HeaderChild::Table(t) => self.table(t),
+                    HeaderChild::StructuredDataTag(t) => self.structured_data_tag(t),
+                }
+            }
+        }
+        let footers = [&s.footer, &s.first_footer, &s.even_footer];
+        for f in footers.iter().filter_map(|f| f.as_ref()) {
+            for c in &f.children {
+                match c {
+                    FooterChild::Paragraph(p) => self.paragraph(p),
+                    FooterChild::Table(t) => self.table(t),
+                    FooterChild::StructuredDataTag(t) => self.structured_data_tag(t),
+                }
+            }
+        }
+        self.in_body = true;
+    }
+
+    fn bookmark(&mut self, b: &BookmarkStart) {
+        if !self.bookmarks.insert(b.name.clone()) {
+            self.report.add(ValidationIssue::DuplicateBookmark {
+                name: b.name.clone(),
+            });
+        }
+        if !self.bookmark_ids.insert(b.id) {
+            self.report
+                .add(ValidationIssue::DuplicateBookmarkId { id: b.id });
+        }
+        if b.name.chars().count() > MAX_BOOKMARK_NAME_LENGTH {
+            self.out_of_range("bookmark name length", b.name.chars().count());
+        }
+    }
+
+    fn paragraph(&mut self, p: &Paragraph) {
+        self.paragraph_property(&p.property);
+        self.paragraph_children(&p.children);
+    }
+
+    fn paragraph_children(&mut self, children: &[ParagraphChild]) {
+        for c in children {
+            match c {
+                ParagraphChild::Run(r) => self.run(r),
+                ParagraphChild::Insert(i) => {
+                    for c in &i.children {
+                        match c {
+                            InsertChild::Run(r) => self.run(r),
+                            InsertChild::Delete(d) => self.delete(d),
+                            _ => {}
+                        }
+                    }
+                }
+                ParagraphChild::Delete(d) => self.delete(d),
+                ParagraphChild::BookmarkStart(b) => self.bookmark(b),
+                ParagraphChild::Hyperlink(h) => {
+                    if let HyperlinkData::External { rid, path } = &h.link {
+                        let stored = self.docx.hyperlinks.iter().any(|(id, ..)| id == rid);
+                        if self.in_body && path.is_empty() && !stored {
+                            self.report
+                                .add(ValidationIssue::DanglingRelationship { id: rid.clone() });
+                        }
+                    }
+                    self.paragraph_children(&h.children);
+                }
+                ParagraphChild::ComplexField(f) => self.paragraph_children(&f.cached_result),
+                ParagraphChild::StructuredDataTag(t) => self.structured_data_tag(t),
+                _ => {}
+            }
+        }
+    }
+
+    fn delete(&mut self, d: &Delete) {
+        for c in &d.children {
+            if let DeleteChild::Run(r) = c {
+                self.run(r);
+            }
+        }
+    }
+
+    fn transitional_only(&mut self, feature: &str) {
+        if self.docx.conformance.is_strict() {
+            self.report.add(ValidationIssue::TransitionalOnly {
+                feature: feature.to_owned(),
+            });
+        }
+    }
+
+    fn run(&mut self, r: &Run) {
+        self.run_property(&r.run_property);
+        for c in &r.children {
+            if let RunChild::Shape(_) = c {
+                self.transitional_only("VML shape");
+            }
+            if let RunChild::Drawing(d) = c {
+                if let Some(DrawingData::Pic(pic)) = &d.data {
+                    // Images of read documents are kept apart from their pictures.
+                    let stored = self.docx.images.iter().any(|(id, ..)| id == &pic.id);
+                    if self.in_body && pic.image.is_empty() && !stored {
+                        self.report
+                            .add(ValidationIssue::DanglingRelationship { id: pic.id.clone() });
+                    }
+                }
+            }
+        }
+    }
+
+    fn table(&mut self, t: &Table) {
+        if let Some(s) = &t.property.style {
+            self.style(&s.val);
+        }
+        for (i, TableChild::TableRow(row)) in t.rows.iter().enumerate() {
+            if row.cells.is_empty() {
+                self.report.add(ValidationIssue::EmptyTableRow { row: i });
+            }
+            for TableRowChild::TableCell(cell) in &row.cells {
+                for c in &cell.children {
+                    match c {
+                        TableCellContent::Paragraph(p) => self.paragraph(p),
+                        TableCellContent::Table(t) => self.table(t),
+                        TableCellContent::StructuredDataTag(t) => self.structured_data_tag(t),
+                        TableCellContent::TableOfContents(_) => {}
+                    }
+                }
+            }
+        }
+    }
+
+    fn structured_data_tag(&mut self, t: &StructuredDataTag) {
+        for c in &t.children {
+            match c {
+                StructuredDataTagChild::Run(r) => self.run(r),
+                StructuredDataTagChild::Paragraph(p) => self.paragraph(p),
+                StructuredDataTagChild::Table(t) => self.table(t),
+                StructuredDataTagChild::BookmarkStart(b) => self.bookmark(b),
+                StructuredDataTagChild::StructuredDataTag(t) => self.structured_data_tag(t),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Docx {
+    // Check the references between parts and the values which Word does not accept. The
+    // document is written as is whatever the report says.
+    pub fn validate(&self) -> ValidationReport {
+        let mut v = Validator {
+            docx: self,
+            report: ValidationReport::default(),
+            bookmarks: HashSet::new(),
+            bookmark_ids: HashSet::new(),
+            in_body: true,
+        };
+        for o in self.settings.legacy_compat_options() {
+            v.transitional_only(&format!("compat option {}", o));
+        }
+        for style in &self.styles.styles {
+            if let Some(b) = &style.based_on {
+                v.style(&b.val);
+            }
+            v.run_property(&style.run_property);
+            v.paragraph_property(&style.paragraph_property);
+        }
+        for child in &self.document.children {
+            match child {
+                DocumentChild::Paragraph(p) => v.paragraph(p),
+                DocumentChild::Table(t) => v.table(t),
+                DocumentChild::BookmarkStart(b) => v.bookmark(b),
+                DocumentChild::StructuredDataTag(t) => v.structured_data_tag(t),
+                _ => {}
+            }
+        }
+        v.section(&self.document.section_property);
+        v.report
+    }
+
+    // Pack the document unless it has issues which make Word refuse it.
+    pub fn pack_validated<W>(self, w: W) -> Result<(), crate::DocxError>
+    where
+        W: std::io::Write + std::io::Seek,
+    {
+        let report = self.validate();
+        if report.has_errors() {
+            return Err(crate::DocxError::Invalid(ValidationReport {
+                issues: report.errors().cloned().collect(),
+            }));
+        }
+        self.pack_into(w)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_validate() {
+        let docx = Docx::new()
+            .add_style(Style::new("Heading1", StyleType::Paragraph).based_on("Missing"))
+            .add_paragraph(
+                Paragraph::new()
+                    .style("Heading1")
+                    .numbering(NumberingId::new(3), IndentLevel::new(9))
+                    .add_bookmark_start(1, "dup")
+                    .add_run(Run::new().add_text("a").size(0)),
+            )
+            .add_paragraph(Paragraph::new().add_bookmark_start(2, "dup"))
+            .add_table(
+                Table::new(vec![TableRow::new(vec![
+                    TableCell::new().add_paragraph(Paragraph::new().style("Unknown"))
+                ])])
+                .style("TableGrid"),
+            );
+        let report = docx.validate();
+        assert_eq!(
+            report.issues,
+            vec![
+                ValidationIssue::MissingStyle {
+                    style_id: "Missing".to_owned()
+                },
+                ValidationIssue::MissingNumbering { id: 3 },
+                ValidationIssue::OutOfRange {
+                    name: "numbering level".to_owned(),
+                    value: "9".to_owned()
+                },
+                ValidationIssue::OutOfRange {
+                    name: "font size".to_owned(),
+                    value: "0".to_owned()
+                },
+                ValidationIssue::DuplicateBookmark {
+                    name: "dup".to_owned()
+                },
+                ValidationIssue::MissingStyle {
+                    style_id: "TableGrid".to_owned()
+                },
+                ValidationIssue::MissingStyle {
+                    style_id: "Unknown".to_owned()
+                },
+            ]
+        );
+        assert_eq!(
+            report.issues[2].to_string(),
+            "numbering level 9 is out of range."
+        );
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn test_pack_validated() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_bookmark_start(1, "a")
+                    .add_bookmark_start(1, "b")
+                    .add_run(Run::new().add_text("a").size(0)),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![])]));
+        let mut buf = std::io::Cursor::new(vec![]);
+        let e = docx.pack_validated(&mut buf).unwrap_err();
+        let issues = match e {
+            crate::DocxError::Invalid(report) => report.issues,
+            e => panic!("unexpected error {}", e),
+        };
+        assert_eq!(
+            issues,
+            vec![
+                ValidationIssue::DuplicateBookmarkId { id: 1 },
+                ValidationIssue::EmptyTableRow { row: 0 },
+            ]
+        );
+        assert!(buf.into_inner().is_empty());
+        assert_eq!(
+            crate::DocxError::Invalid(ValidationReport { issues }).to_string(),
+            "The document is invalid. Bookmark id 1 is duplicated. Table row 0 has no cells."
+        );
+
+        let mut buf = std::io::Cursor::new(vec![]);
+        Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("a").size(0)))
+            .pack_validated(&mut buf)
+            .unwrap();
+        assert!(!buf.into_inner().is_empty());
+    }
+
+    #[test]
+    fn test_validate_strict() {
+        let mut run = Run::new();
+        run.children.push(RunChild::Shape(Box::new(Shape::new())));
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(run))
+            .settings(Settings::new().add_compat_option("doNotUseHTMLParagraphAutoSpacing"));
+        assert_eq!(docx.validate(), ValidationReport::default());
+
+        let issues = docx.conformance(Conformance::Strict).validate().issues;
+        assert_eq!(
+            issues,
+            vec![
+                ValidationIssue::TransitionalOnly {
+                    feature: "compat option doNotUseHTMLParagraphAutoSpacing".to_owned()
+                },
+                ValidationIssue::TransitionalOnly {
+                    feature: "VML shape".to_owned()
+                },
+            ]
+        );
+        assert_eq!(
+            issues[1].to_string(),
+            "VML shape is not allowed in strict documents."
+        );
+    }
+
+    #[test]
+    fn test_validate_read_document() {
+        let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+        let mut buf = Vec::new();
+        std::io::Read::read_to_end(&mut img, &mut buf).unwrap();
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_image(Pic::new(&buf)))
+                    .add_hyperlink(
+                        Hyperlink::new("https://example.com", HyperlinkType::External)
+                            .add_run(Run::new().add_text("link")),
+                    ),
+            )
+            .header(Header::new().add_paragraph(Paragraph::new()));
+        let mut out = std::io::Cursor::new(vec![]);
+        docx.build().pack(&mut out).unwrap();
+        let mut read = crate::read_docx(&out.into_inner()).unwrap();
+        assert_eq!(read.validate(), ValidationReport::default());
+
+        read.images.clear();
+        read.document.section_property.header = None;
+        let issues = read.validate().issues;
+        assert_eq!(issues.len(), 2);
+        assert!(matches!(
+            issues[0],
+            ValidationIssue::DanglingRelationship { .. }
+        ));
+        assert!(matches!(
+            issues[1],
+            ValidationIssue::MissingHeaderOrFooter { .. }
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+use serde::Serialize;
+
+use super::*;
+use crate::types::*;
+
+const VBA_PROJECT_CONTENT_TYPE: &str = "application/vnd.ms-office.vbaProject";
+
+// Part of the VBA project as it was read. `path` is in the package, such as
+// `word/vbaData.xml`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VbaProjectPart {
+    pub path: String,
+    pub content_type: Option<String>,
+    #[serde(skip_serializing)]
+    pub data: Vec<u8>,
+}
+
+// The macros of a macro-enabled package. Its parts are written back untouched.
+#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VbaProject {
+    // The `vbaProject.bin` part comes first.
+    pub parts: Vec<VbaProjectPart>,
+}
+
+impl VbaProject {
+    pub fn new(data: Vec<u8>) -> VbaProject {
+        VbaProject {
+            parts: vec![VbaProjectPart {
+                path: "word/vbaProject.bin".to_owned(),
+                content_type: Some(VBA_PROJECT_CONTENT_TYPE.to_owned()),
+                data,
+            }],
+        }
+    }
+
+    pub(crate) fn path(&self) -> Option<&str> {
+        self.parts.first().map(|p| p.path.as_str())
+    }
+}
+
+impl Docx {
+    pub fn package_type(mut self, t: PackageType) -> Docx {
+        self.package_type = t;
+        self
+    }
+
+    // The project is only written in macro-enabled packages.
+    pub fn vba_project(mut self, p: VbaProject) -> Docx {
+        self.vba_project = Some(p);
+        self
+    }
+}
@@ -1,21 +1,202 @@
 use serde::Serialize;
 
 use super::*;
+use crate::xml_builder::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WebSettings {
+    // The frame set as it was read, with the relationships of the part to the frames.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frameset: Option<String>,
+    #[serde(skip)]
+    pub(crate) rels: Option<Vec<u8>>,
     pub divs: Vec<Div>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub optimize_for_browser: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub rely_on_vml: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub allow_png: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub do_not_rely_on_css: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub do_not_save_as_single_file: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub do_not_organize_in_folder: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub do_not_use_long_file_names: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pixels_per_inch: Option<usize>,
+    // Such as `800x600`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_screen_size: Option<String>,
 }
 
 impl WebSettings {
     pub fn new() -> WebSettings {
         Default::default()
     }
+
+    pub fn add_div(mut self, d: Div) -> Self {
+        self.divs.push(d);
+        self
+    }
+
+    pub fn encoding(mut self, e: impl Into<String>) -> Self {
+        self.encoding = Some(e.into());
+        self
+    }
+
+    pub fn optimize_for_browser(mut self) -> Self {
+        self.optimize_for_browser = true;
+        self
+    }
+
+    pub fn rely_on_vml(mut self) -> Self {
+        self.rely_on_vml = true;
+        self
+    }
+
+    pub fn allow_png(mut self) -> Self {
+        self.allow_png = true;
+        self
+    }
+
+    pub fn do_not_rely_on_css(mut self) -> Self {
+        self.do_not_rely_on_css = true;
+        self
+    }
+
+    pub fn do_not_save_as_single_file(mut self) -> Self {
+        self.do_not_save_as_single_file = true;
+        self
+    }
+
+    pub fn do_not_organize_in_folder(mut self) -> Self {
+        self.do_not_organize_in_folder = true;
+        self
+    }
+
+    pub fn do_not_use_long_file_names(mut self) -> Self {
+        self.do_not_use_long_file_names = true;
+        self
+    }
+
+    pub fn pixels_per_inch(mut self, v: usize) -> Self {
+        self.pixels_per_inch = Some(v);
+        self
+    }
+
+    pub fn target_screen_size(mut self, v: impl Into<String>) -> Self {
+        self.target_screen_size = Some(v.into());
+        self
+    }
+
+    pub(crate) fn frameset(mut self, xml: String, rels: Option<Vec<u8>>) -> Self {
+        self.frameset = Some(xml);
+        self.rels = rels;
+        self
+    }
 }
 
 impl Default for WebSettings {
     fn default() -> Self {
-        Self { divs: vec![] }
+        Self {
+            frameset: None,
+            rels: None,
+            divs: vec![],
+            encoding: None,
+            optimize_for_browser: false,
+            rely_on_vml: false,
+            allow_png: false,
+            do_not_rely_on_css: false,
+            do_not_save_as_single_file: false,
+            do_not_organize_in_folder: false,
+            do_not_use_long_file_names: false,
+            pixels_per_inch: None,
+            target_screen_size: None,
+        }
+    }
+}
+
+impl BuildXML for WebSettings {
+    fn build(&self) -> Vec<u8> {
+        let mut b = XMLBuilder::new()
+            .declaration(Some(true))
+            .open_web_settings();
+        if let Some(frameset) = &self.frameset {
+            b = b.add_bytes(frameset.as_bytes());
+        }
+        if !self.divs.is_empty() {
+            b = b.open_divs().add_children(&self.divs).close();
+        }
+        if let Some(e) = &self.encoding {
+            b = b.encoding(e);
+        }
+        if self.optimize_for_browser {
+            b = b.optimize_for_browser();
+        }
+        if self.rely_on_vml {
+            b = b.rely_on_vml();
+        }
+        if self.allow_png {
+            b = b.allow_png();
+        }
+        if self.do_not_rely_on_css {
+            b = b.do_not_rely_on_css();
+        }
+        if self.do_not_save_as_single_file {
+            b = b.do_not_save_as_single_file();
+        }
+        if self.do_not_organize_in_folder {
+            b = b.do_not_organize_in_folder();
+        }
+        if self.do_not_use_long_file_names {
+            b = b.do_not_use_long_file_names();
+        }
+        if let Some(v) = self.pixels_per_inch {
+            b = b.pixels_per_inch(&v.to_string());
+        }
+        if let Some(v) = &self.target_screen_size {
+            b = b.target_screen_size(v);
+        }
+        b.close().build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::str;
+
+    #[test]
+    fn test_web_settings() {
+        let b = WebSettings::new()
+            .add_div(Div::new("1"))
+            .optimize_for_browser()
+            .allow_png()
+            .pixels_per_inch(96)
+            .build();
+        assert_eq!(
+            str::from_utf8(&b).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:webSettings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+  <w:divs><w:div w:id="1">
+  <w:marLeft w:val="0" />
+  <w:marRight w:val="0" />
+  <w:marTop w:val="0" />
+  <w:marBottom w:val="0" />
+</w:div></w:divs>
+  <w:optimizeForBrowser />
+  <w:allowPNG />
+  <w:pixelsPerInch w:val="96" />
+</w:webSettings>"#
+        );
     }
 }
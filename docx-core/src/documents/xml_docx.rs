@@ -1,4 +1,4 @@
-use super::XMLDocProps;
+use super::{PackOptions, XMLDocProps};
 
 use crate::zipper;
 use std::io::prelude::*;
@@ -15,6 +15,12 @@ pub struct XMLDocx {
     pub document_rels: Vec<u8>,
     pub settings: Vec<u8>,
     pub font_table: Vec<u8>,
+    pub font_table_rels: Option<Vec<u8>>,
+    pub web_settings: Option<Vec<u8>>,
+    pub web_settings_rels: Option<Vec<u8>>,
+    pub theme: Option<Vec<u8>>,
+    // Paths in the `word` folder and contents of the embedded fonts.
+    pub fonts: Vec<(String, Vec<u8>)>,
     pub numberings: Vec<u8>,
     pub media: Vec<(String, Vec<u8>)>,
     pub headers: Vec<Vec<u8>>,
@@ -28,7 +34,18 @@ pub struct XMLDocx {
     pub custom_items: Vec<Vec<u8>>,
     pub custom_item_rels: Vec<Vec<u8>>,
     pub custom_item_props: Vec<Vec<u8>>,
+    // Paths in the `word` folder and contents of the parts imported by `w:altChunk`.
+    pub alt_chunks: Vec<(String, Vec<u8>)>,
+    // Paths in the package and contents of the parts added by `Docx::add_part`.
+    pub custom_parts: Vec<(String, Vec<u8>)>,
+    // Paths in the package and contents of the parts of the charts.
+    pub charts: Vec<(String, Vec<u8>)>,
+    // Paths in the package and contents of the parts of the glossary document.
+    pub glossary: Vec<(String, Vec<u8>)>,
+    // Paths in the package and contents of the parts of the VBA project.
+    pub vba_project: Vec<(String, Vec<u8>)>,
     pub footnotes: Vec<u8>,
+    pub endnotes: Option<Vec<u8>>,
 }
 
 impl XMLDocx {
@@ -36,6 +53,117 @@ impl XMLDocx {
     where
         W: Write + Seek,
     {
-        zipper::zip(w, self)
+        self.pack_with_options(w, &PackOptions::default())
+    }
+
+    pub fn pack_with_options<W>(self, w: W, options: &PackOptions) -> zip::result::ZipResult<()>
+    where
+        W: Write + Seek,
+    {
+        zipper::zip(w, self, options)?;
+        Ok(())
+    }
+
+    // Packs into a sink which can not seek, such as a pipe or the body of a response.
+    pub fn pack_stream<W>(self, w: W) -> zip::result::ZipResult<()>
+    where
+        W: Write,
+    {
+        self.pack_stream_with_options(w, &PackOptions::default())
+    }
+
+    pub fn pack_stream_with_options<W>(
+        self,
+        w: W,
+        options: &PackOptions,
+    ) -> zip::result::ZipResult<()>
+    where
+        W: Write,
+    {
+        let w = zipper::zip(zipper::StreamWriter::new(w), self, options)?;
+        w.finish()?;
+        Ok(())
+    }
+
+    // Apply `f` to the XML parts built from the document. Custom XML, media and the parts which
+    // are kept as they were read are left as they are.
+    pub(crate) fn map_xml_parts(mut self, f: impl Fn(&[u8]) -> Vec<u8>) -> XMLDocx {
+        let parts = [
+            &mut self.content_type,
+            &mut self.rels,
+            &mut self.doc_props.app,
+            &mut self.doc_props.core,
+            &mut self.doc_props.custom,
+            &mut self.styles,
+            &mut self.document,
+            &mut self.comments,
+            &mut self.document_rels,
+            &mut self.settings,
+            &mut self.font_table,
+            &mut self.numberings,
+            &mut self.comments_extended,
+            &mut self.taskpanes_rels,
+            &mut self.footnotes,
+        ];
+        for part in parts {
+            *part = f(part);
+        }
+        let mut optional_parts = [
+            &mut self.font_table_rels,
+            &mut self.web_settings,
+            &mut self.web_settings_rels,
+            &mut self.theme,
+            &mut self.endnotes,
+        ];
+        for part in optional_parts.iter_mut().filter_map(|p| p.as_mut()) {
+            *part = f(part);
+        }
+        for part in self
+            .headers
+            .iter_mut()
+            .chain(self.header_rels.iter_mut())
+            .chain(self.footers.iter_mut())
+            .chain(self.footer_rels.iter_mut())
+            .chain(self.custom_item_rels.iter_mut())
+            .chain(self.custom_item_props.iter_mut())
+        {
+            *part = f(part);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    // Only `Write`, so the package can not be completed by seeking.
+    struct Pipe(Vec<u8>);
+
+    impl std::io::Write for Pipe {
+        fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+            self.0.write(data)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_pack_stream() {
+        let docx = || {
+            Docx::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+                .header(Header::new().add_paragraph(Paragraph::new()))
+        };
+        let mut cursor = std::io::Cursor::new(vec![]);
+        docx().pack_into(&mut cursor).unwrap();
+        let mut pipe = Pipe(vec![]);
+        docx().pack_into_stream(&mut pipe).unwrap();
+        assert_eq!(pipe.0, cursor.into_inner());
     }
 }
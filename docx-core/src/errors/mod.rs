@@ -8,6 +8,8 @@ pub enum DocxError {
     EmitterError(#[from] xml::writer::Error),
     #[error("Failed to zip XML documents.")]
     ZipError(#[from] zip::result::ZipError),
+    #[error("The document is invalid. {0}")]
+    Invalid(crate::ValidationReport),
     #[error("Unknown error")]
     Unknown,
 }
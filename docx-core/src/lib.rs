@@ -1,3 +1,4 @@
+mod diff;
 mod documents;
 mod errors;
 mod escape;
@@ -7,6 +8,7 @@ mod xml_builder;
 mod xml_json;
 mod zipper;
 
+pub use diff::*;
 pub use documents::*;
 pub use errors::*;
 pub use reader::*;
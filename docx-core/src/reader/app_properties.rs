@@ -0,0 +1,42 @@
+use std::io::Read;
+
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+impl FromXML for AppProps {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut props = AppProps::new();
+        let mut current = String::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement { name, .. }) => {
+                    current = name.local_name;
+                }
+                Ok(XmlEvent::Characters(c)) => match current.as_str() {
+                    "Template" => props.template = Some(c),
+                    "TotalTime" => props.total_time = c.parse().ok(),
+                    "Pages" => props.pages = c.parse().ok(),
+                    "Words" => props.words = c.parse().ok(),
+                    "Characters" => props.characters = c.parse().ok(),
+                    "Application" => props.application = Some(c),
+                    "Paragraphs" => props.paragraphs = c.parse().ok(),
+                    "Company" => props.company = Some(c),
+                    "CharactersWithSpaces" => props.characters_with_spaces = c.parse().ok(),
+                    "AppVersion" => props.app_version = Some(c),
+                    _ => {}
+                },
+                Ok(XmlEvent::EndElement { .. }) => {
+                    current.clear();
+                }
+                Ok(XmlEvent::EndDocument { .. }) => {
+                    return Ok(props);
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
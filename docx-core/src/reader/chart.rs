@@ -0,0 +1,156 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+use crate::types::ChartType;
+
+fn set_at<T: Clone + Default>(v: &mut Vec<T>, idx: usize, value: T) {
+    if v.len() <= idx {
+        v.resize(idx + 1, T::default());
+    }
+    v[idx] = value;
+}
+
+// Read a chart part (`c:chartSpace`). The relationship id and the name are set by the
+// drawing which refers to it.
+impl FromXML for Chart {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut chart = Chart::default();
+        // Local names of the open elements.
+        let mut stack: Vec<String> = vec![];
+        let mut series: Option<ChartSeries> = None;
+        let mut title: Option<String> = None;
+        let mut idx = 0;
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let parent = stack.last().map(|s| s.as_str());
+                    // Child of `c:ser`, such as `c:tx` for the name or `c:val` for the values.
+                    let section = stack
+                        .iter()
+                        .position(|s| s == "ser")
+                        .and_then(|i| stack.get(i + 1))
+                        .map(|s| s.as_str());
+                    match name.local_name.as_str() {
+                        "ser" => series = Some(ChartSeries::new()),
+                        "title" if parent == Some("chart") => title = Some(String::new()),
+                        "pt" => {
+                            idx = read(&attributes, "idx")
+                                .and_then(|v| usize::from_str(&v).ok())
+                                .unwrap_or(0);
+                        }
+                        "ptCount" => {
+                            let count = read(&attributes, "val")
+                                .and_then(|v| usize::from_str(&v).ok())
+                                .unwrap_or(0);
+                            if let Some(s) = series.as_mut() {
+                                match section {
+                                    Some("cat") | Some("xVal") if s.categories.len() < count => {
+                                        s.categories.resize(count, String::new())
+                                    }
+                                    Some("val") | Some("yVal") if s.values.len() < count => {
+                                        s.values.resize(count, None)
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        n if parent == Some("plotArea") && chart.chart_type.is_none() => {
+                            chart.chart_type = ChartType::from_str(n).ok();
+                        }
+                        _ => {}
+                    }
+                    stack.push(name.local_name);
+                }
+                Ok(XmlEvent::Characters(c)) => {
+                    let current = stack.last().map(|s| s.as_str());
+                    let section = stack
+                        .iter()
+                        .position(|s| s == "ser")
+                        .and_then(|i| stack.get(i + 1))
+                        .map(|s| s.as_str());
+                    if let Some(s) = series.as_mut() {
+                        if current == Some("v") {
+                            match section {
+                                Some("tx") => s.name = Some(c),
+                                Some("cat") | Some("xVal") => set_at(&mut s.categories, idx, c),
+                                Some("val") | Some("yVal") => {
+                                    set_at(&mut s.values, idx, f64::from_str(c.trim()).ok())
+                                }
+                                _ => {}
+                            }
+                        }
+                    } else if let Some(t) = title.as_mut() {
+                        if current == Some("t") || current == Some("v") {
+                            t.push_str(&c);
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndElement { .. }) => {
+                    let name = stack.pop().unwrap_or_default();
+                    let parent = stack.last().map(|s| s.as_str());
+                    match name.as_str() {
+                        "ser" => chart.series.extend(series.take()),
+                        "title" if parent == Some("chart") => {
+                            chart.title = title.take().filter(|t| !t.is_empty());
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndDocument { .. }) => return Ok(chart),
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_chart() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+  <c:chart>
+    <c:title><c:tx><c:rich><a:bodyPr /><a:p><a:r><a:t>Sales</a:t></a:r></a:p></c:rich></c:tx></c:title>
+    <c:plotArea>
+      <c:layout />
+      <c:barChart>
+        <c:barDir val="col" />
+        <c:ser>
+          <c:idx val="0" />
+          <c:tx><c:strRef><c:f>Sheet1!$B$1</c:f><c:strCache><c:ptCount val="1" /><c:pt idx="0"><c:v>2020</c:v></c:pt></c:strCache></c:strRef></c:tx>
+          <c:cat><c:strRef><c:f>Sheet1!$A$2:$A$4</c:f><c:strCache><c:ptCount val="3" /><c:pt idx="0"><c:v>Q1</c:v></c:pt><c:pt idx="1"><c:v>Q2</c:v></c:pt><c:pt idx="2"><c:v>Q3</c:v></c:pt></c:strCache></c:strRef></c:cat>
+          <c:val><c:numRef><c:f>Sheet1!$B$2:$B$4</c:f><c:numCache><c:formatCode>General</c:formatCode><c:ptCount val="3" /><c:pt idx="0"><c:v>4.3</c:v></c:pt><c:pt idx="2"><c:v>2</c:v></c:pt></c:numCache></c:numRef></c:val>
+        </c:ser>
+      </c:barChart>
+      <c:catAx><c:axId val="1" /><c:title><c:tx><c:rich><a:p><a:r><a:t>Quarter</a:t></a:r></a:p></c:rich></c:tx></c:title></c:catAx>
+    </c:plotArea>
+  </c:chart>
+</c:chartSpace>"#;
+        let c = Chart::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            c,
+            Chart::default()
+                .chart_type(ChartType::Bar)
+                .title("Sales")
+                .add_series(
+                    ChartSeries::new()
+                        .name("2020")
+                        .categories(vec!["Q1".to_owned(), "Q2".to_owned(), "Q3".to_owned()])
+                        .values(vec![Some(4.3), None, Some(2.0)])
+                )
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use crate::reader::*;
+
+impl ElementReader for ColorScheme {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut cs = ColorScheme::new();
+        if let Some(name) = read(attrs, "name") {
+            cs.name = name;
+        }
+        // The color slot, such as `accent1`, being read.
+        let mut slot: Option<String> = None;
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => match AXMLElement::from_str(&name.local_name).unwrap() {
+                    AXMLElement::SrgbClr => {
+                        if let (Some(s), Some(v)) = (&slot, read(&attributes, "val")) {
+                            if let Some(c) = cs.slot_mut(s) {
+                                *c = v;
+                            }
+                        }
+                    }
+                    AXMLElement::SysClr => {
+                        if let (Some(s), Some(v)) = (&slot, read(&attributes, "lastClr")) {
+                            if let Some(c) = cs.slot_mut(s) {
+                                *c = v;
+                            }
+                        }
+                    }
+                    _ => {
+                        if cs.slot_mut(&name.local_name).is_some() {
+                            slot = Some(name.local_name);
+                        }
+                    }
+                },
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    if let AXMLElement::ClrScheme = AXMLElement::from_str(&name.local_name).unwrap()
+                    {
+                        return Ok(cs);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use crate::documents::*;
+use crate::types::*;
+
+enum Piece {
+    Child(ParagraphChild),
+    // A field character with the properties of its run.
+    Char(FieldChar, RunProperty),
+}
+
+struct Frame {
+    field: ComplexField,
+    in_result: bool,
+}
+
+// Reconstruct the fields which begin and end in the paragraph from their field characters.
+// When some can not be, such as fields spanning paragraphs, form fields or fields nested in
+// instructions, the children are left as they were read.
+pub(crate) fn read_complex_fields(children: Vec<ParagraphChild>) -> Vec<ParagraphChild> {
+    let has_field = children.iter().any(|c| match c {
+        ParagraphChild::Run(r) => r.children.iter().any(
+            |c| matches!(c, RunChild::FieldChar(f) if f.field_char_type == FieldCharType::Begin),
+        ),
+        _ => false,
+    });
+    if !has_field {
+        return children;
+    }
+    build_fields(&children).unwrap_or(children)
+}
+
+// Runs are split at their field characters.
+fn pieces(children: &[ParagraphChild]) -> Vec<Piece> {
+    let mut pieces = vec![];
+    for c in children {
+        let run = match c {
+            ParagraphChild::Run(r)
+                if r.children
+                    .iter()
+                    .any(|c| matches!(c, RunChild::FieldChar(_))) =>
+            {
+                r
+            }
+            _ => {
+                pieces.push(Piece::Child(c.clone()));
+                continue;
+            }
+        };
+        let mut current = vec![];
+        for c in &run.children {
+            if let RunChild::FieldChar(f) = c {
+                if !current.is_empty() {
+                    pieces.push(Piece::Child(ParagraphChild::Run(Box::new(Run {
+                        run_property: run.run_property.clone(),
+                        children: std::mem::take(&mut current),
+                    }))));
+                }
+                pieces.push(Piece::Char(f.clone(), run.run_property.clone()));
+            } else {
+                current.push(c.clone());
+            }
+        }
+        if !current.is_empty() {
+            pieces.push(Piece::Child(ParagraphChild::Run(Box::new(Run {
+                run_property: run.run_property.clone(),
+                children: current,
+            }))));
+        }
+    }
+    pieces
+}
+
+fn build_fields(children: &[ParagraphChild]) -> Option<Vec<ParagraphChild>> {
+    let mut out = vec![];
+    let mut stack: Vec<Frame> = vec![];
+    for piece in pieces(children) {
+        match piece {
+            Piece::Char(f, run_property) => match f.field_char_type {
+                FieldCharType::Begin => {
+                    // Form fields are read by their field characters.
+                    if f.form_field.is_some() {
+                        return None;
+                    }
+                    if stack.last().map(|f| !f.in_result).unwrap_or_default() {
+                        return None;
+                    }
+                    stack.push(Frame {
+                        field: ComplexField {
+                            run_property,
+                            dirty: f.dirty,
+                            ..Default::default()
+                        },
+                        in_result: false,
+                    });
+                }
+                FieldCharType::Separate => stack.last_mut()?.in_result = true,
+                FieldCharType::End => {
+                    let field = ParagraphChild::ComplexField(Box::new(stack.pop()?.field));
+                    match stack.last_mut() {
+                        Some(parent) => parent.field.cached_result.push(field),
+                        None => out.push(field),
+                    }
+                }
+                _ => {}
+            },
+            Piece::Child(c) => match stack.last_mut() {
+                None => out.push(c),
+                Some(frame) if frame.in_result => frame.field.cached_result.push(c),
+                Some(frame) => frame.field.instruction.push_str(&instruction_text(&c)?),
+            },
+        }
+    }
+    if stack.is_empty() {
+        Some(out)
+    } else {
+        None
+    }
+}
+
+fn instruction_text(c: &ParagraphChild) -> Option<String> {
+    match c {
+        ParagraphChild::Run(r) => r
+            .children
+            .iter()
+            .map(|c| match c {
+                RunChild::InstrTextString(s) => Some(s.as_str()),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn instr(s: &str) -> Run {
+        let mut r = Run::new();
+        r.children.push(RunChild::InstrTextString(s.to_owned()));
+        r
+    }
+
+    fn begin() -> Run {
+        Run::new().add_field_char(FieldCharType::Begin, false)
+    }
+
+    fn separate() -> Run {
+        Run::new().add_field_char(FieldCharType::Separate, false)
+    }
+
+    fn end() -> Run {
+        Run::new().add_field_char(FieldCharType::End, false)
+    }
+
+    fn runs(runs: Vec<Run>) -> Vec<ParagraphChild> {
+        runs.into_iter()
+            .map(|r| ParagraphChild::Run(Box::new(r)))
+            .collect()
+    }
+
+    #[test]
+    fn test_read_complex_fields() {
+        let children = runs(vec![
+            Run::new().add_text("See "),
+            begin(),
+            instr(" HYPERLINK "),
+            instr("\"https://example.com\" "),
+            separate(),
+            Run::new().add_text("example"),
+            begin(),
+            instr(" PAGE "),
+            separate(),
+            Run::new().add_text("1"),
+            end(),
+            end(),
+        ]);
+        assert_eq!(
+            read_complex_fields(children),
+            vec![
+                ParagraphChild::Run(Box::new(Run::new().add_text("See "))),
+                ParagraphChild::ComplexField(Box::new(
+                    ComplexField::new(" HYPERLINK \"https://example.com\" ")
+                        .add_result_run(Run::new().add_text("example"))
+                        .add_result_field(
+                            ComplexField::new(" PAGE ").add_result_run(Run::new().add_text("1"))
+                        )
+                ))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_complex_fields_in_a_run() {
+        let mut run = begin();
+        run.children
+            .push(RunChild::InstrTextString(" NUMPAGES ".to_owned()));
+        let run = run
+            .add_field_char(FieldCharType::Separate, false)
+            .add_text("3")
+            .add_field_char(FieldCharType::End, false)
+            .add_text(" pages");
+        assert_eq!(
+            read_complex_fields(runs(vec![run])),
+            vec![
+                ParagraphChild::ComplexField(Box::new(
+                    ComplexField::new(" NUMPAGES ").add_result_run(Run::new().add_text("3"))
+                )),
+                ParagraphChild::Run(Box::new(Run::new().add_text(" pages"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_keep_open_fields() {
+        // The field ends in another paragraph.
+        let children = runs(vec![begin(), instr(" TOC \\o "), separate()]);
+        assert_eq!(read_complex_fields(children.clone()), children);
+        // A field nested in the instruction.
+        let children = runs(vec![
+            begin(),
+            instr(" IF "),
+            begin(),
+            instr(" MERGEFIELD Name "),
+            end(),
+            instr(" = \"\" "),
+            end(),
+        ]);
+        assert_eq!(read_complex_fields(children.clone()), children);
+    }
+}
@@ -0,0 +1,71 @@
+use std::io::Read;
+
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+impl FromXML for CoreProps {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut config = CorePropsConfig::new();
+        let mut current = String::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement { name, .. }) => {
+                    current = name.local_name;
+                }
+                Ok(XmlEvent::Characters(c)) => match current.as_str() {
+                    "created" => config.created = Some(c),
+                    "creator" => config.creator = Some(c),
+                    "description" => config.description = Some(c),
+                    "language" => config.language = Some(c),
+                    "lastModifiedBy" => config.last_modified_by = Some(c),
+                    "modified" => config.modified = Some(c),
+                    "revision" => config.revision = c.parse().ok(),
+                    "subject" => config.subject = Some(c),
+                    "title" => config.title = Some(c),
+                    _ => {}
+                },
+                Ok(XmlEvent::EndElement { .. }) => {
+                    current.clear();
+                }
+                Ok(XmlEvent::EndDocument { .. }) => {
+                    return Ok(CoreProps::new(config));
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_core_properties() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <dc:title>Report</dc:title>
+  <dc:creator>bokuweb</dc:creator>
+  <cp:lastModifiedBy>someone</cp:lastModifiedBy>
+  <cp:revision>3</cp:revision>
+  <dcterms:created xsi:type="dcterms:W3CDTF">2021-01-01T00:00:00Z</dcterms:created>
+  <dcterms:modified xsi:type="dcterms:W3CDTF">2021-02-01T00:00:00Z</dcterms:modified>
+</cp:coreProperties>"#;
+        let c = CoreProps::from_xml(xml.as_bytes()).unwrap();
+        let mut config = CorePropsConfig::new();
+        config.title = Some("Report".to_owned());
+        config.creator = Some("bokuweb".to_owned());
+        config.last_modified_by = Some("someone".to_owned());
+        config.revision = Some(3);
+        config.created = Some("2021-01-01T00:00:00Z".to_owned());
+        config.modified = Some("2021-02-01T00:00:00Z".to_owned());
+        assert_eq!(c, CoreProps::new(config));
+    }
+}
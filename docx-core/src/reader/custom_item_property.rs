@@ -0,0 +1,58 @@
+use std::io::Read;
+
+use crate::reader::*;
+use xml::reader::{EventReader, XmlEvent};
+
+impl FromXML for CustomItemProperty {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut parser = EventReader::new(reader);
+        let mut props = CustomItemProperty::default();
+        loop {
+            let e = parser.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => match name.local_name.as_str() {
+                    "datastoreItem" => {
+                        if let Some(a) = attributes.iter().find(|a| a.name.local_name == "itemID") {
+                            let id = a.value.trim_start_matches('{').trim_end_matches('}');
+                            props = CustomItemProperty::new(id);
+                        }
+                    }
+                    "schemaRef" => {
+                        if let Some(a) = attributes.iter().find(|a| a.name.local_name == "uri") {
+                            props = props.add_schema_ref(&a.value);
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(XmlEvent::EndDocument) => break,
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+        Ok(props)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_custom_item_property_from_xml() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
+<ds:datastoreItem ds:itemID="{06AC5857-5C65-A94A-BCEC-37356A209BC3}" xmlns:ds="http://schemas.openxmlformats.org/officeDocument/2006/customXml">
+  <ds:schemaRefs><ds:schemaRef ds:uri="https://example.com/invoice"/></ds:schemaRefs>
+</ds:datastoreItem>"#;
+        let p = CustomItemProperty::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            p,
+            CustomItemProperty::new("06AC5857-5C65-A94A-BCEC-37356A209BC3")
+                .add_schema_ref("https://example.com/invoice")
+        );
+    }
+}
@@ -44,7 +44,7 @@ impl ElementReader for Delete {
                 }
                 Ok(XmlEvent::EndElement { name, .. }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
-                    if e == XMLElement::Delete {
+                    if e == XMLElement::Delete || e == XMLElement::MoveFrom {
                         for attr in attrs {
                             let local_name = &attr.name.local_name;
                             if local_name == "author" {
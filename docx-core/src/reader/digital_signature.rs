@@ -0,0 +1,433 @@
+use std::io::{Cursor, Read, Seek};
+use std::path::Path;
+
+use xml::reader::{EventReader, XmlEvent};
+use zip::ZipArchive;
+
+use super::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignatureStatus {
+    Valid,
+    // The reference whose digest does not match, such as `/word/document.xml?ContentType=...`.
+    DigestMismatch(String),
+    InvalidSignatureValue,
+    // An algorithm or a transform which can not be checked.
+    Unsupported(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageSignature {
+    // Such as `_xmlsignatures/sig1.xml`.
+    pub part: String,
+    pub certificate: Vec<u8>,
+    pub signing_time: Option<String>,
+    pub status: SignatureStatus,
+}
+
+#[derive(Debug, Default)]
+struct Reference {
+    uri: String,
+    transforms: Vec<String>,
+    // The `SourceId` and `SourceType` selected by a relationship transform.
+    source_ids: Vec<String>,
+    source_types: Vec<String>,
+    digest_method: String,
+    digest_value: String,
+}
+
+#[derive(Debug, Default)]
+struct Signature {
+    canonicalization_method: String,
+    signature_method: String,
+    signed_references: Vec<Reference>,
+    manifest_references: Vec<Reference>,
+    signature_value: String,
+    certificate: String,
+    signing_time: Option<String>,
+}
+
+fn read_signature(xml: &[u8]) -> Result<Signature, ReaderError> {
+    let mut s = Signature::default();
+    let mut stack: Vec<String> = vec![];
+    let mut reference: Option<Reference> = None;
+    for e in EventReader::new(xml) {
+        match e {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                let attr = |n: &str| {
+                    attributes
+                        .iter()
+                        .find(|a| a.name.local_name == n)
+                        .map(|a| a.value.clone())
+                        .unwrap_or_default()
+                };
+                match name.local_name.as_str() {
+                    "CanonicalizationMethod" => s.canonicalization_method = attr("Algorithm"),
+                    "SignatureMethod" => s.signature_method = attr("Algorithm"),
+                    "Reference" => {
+                        reference = Some(Reference {
+                            uri: attr("URI"),
+                            ..Default::default()
+                        })
+                    }
+                    "Transform" => {
+                        if let Some(r) = reference.as_mut() {
+                            r.transforms.push(attr("Algorithm"));
+                        }
+                    }
+                    "RelationshipReference" => {
+                        if let Some(r) = reference.as_mut() {
+                            r.source_ids.push(attr("SourceId"));
+                        }
+                    }
+                    "RelationshipsGroupReference" => {
+                        if let Some(r) = reference.as_mut() {
+                            r.source_types.push(attr("SourceType"));
+                        }
+                    }
+                    "DigestMethod" => {
+                        if let Some(r) = reference.as_mut() {
+                            r.digest_method = attr("Algorithm");
+                        }
+                    }
+                    _ => {}
+                }
+                stack.push(name.local_name);
+            }
+            Ok(XmlEvent::Characters(c)) => match stack.last().map(|n| n.as_str()) {
+                Some("DigestValue") => {
+                    if let Some(r) = reference.as_mut() {
+                        r.digest_value.push_str(&c);
+                    }
+                }
+                Some("SignatureValue") => s.signature_value.push_str(&c),
+                Some("X509Certificate") if s.certificate.is_empty() => s.certificate = c,
+                Some("Value") if stack.iter().any(|n| n == "SignatureTime") => {
+                    s.signing_time = Some(c)
+                }
+                _ => {}
+            },
+            Ok(XmlEvent::EndElement { name }) => {
+                stack.pop();
+                if name.local_name == "Reference" {
+                    if let Some(r) = reference.take() {
+                        if stack.iter().any(|n| n == "Manifest") {
+                            s.manifest_references.push(r);
+                        } else if stack.iter().any(|n| n == "SignedInfo") {
+                            s.signed_references.push(r);
+                        }
+                    }
+                }
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+    Ok(s)
+}
+
+fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    base64::decode(s).ok()
+}
+
+fn is_c14n(method: &str) -> bool {
+    method == C14N_URI || method == format!("{}#WithComments", C14N_URI)
+}
+
+fn check_digest(r: &Reference, data: &[u8]) -> Result<(), SignatureStatus> {
+    let digest = match r.digest_method.as_str() {
+        SHA1_URI => sha1(data),
+        SHA256_URI => sha256(data),
+        m => return Err(SignatureStatus::Unsupported(m.to_owned())),
+    };
+    if decode_base64(&r.digest_value) == Some(digest) {
+        Ok(())
+    } else {
+        Err(SignatureStatus::DigestMismatch(r.uri.clone()))
+    }
+}
+
+fn check_signature<R: Read + Seek>(
+    xml: &[u8],
+    s: &Signature,
+    archive: &mut ZipArchive<R>,
+    verifier: &dyn SignatureVerifier,
+) -> Result<(), SignatureStatus> {
+    if !is_c14n(&s.canonicalization_method) {
+        return Err(SignatureStatus::Unsupported(
+            s.canonicalization_method.clone(),
+        ));
+    }
+    for r in &s.signed_references {
+        let id = r
+            .uri
+            .strip_prefix('#')
+            .ok_or_else(|| SignatureStatus::Unsupported(r.uri.clone()))?;
+        let element = canonicalize(xml, |_, attrs| {
+            attrs
+                .iter()
+                .any(|a| a.name.local_name == "Id" && a.value == id)
+        })
+        .ok_or_else(|| SignatureStatus::DigestMismatch(r.uri.clone()))?;
+        check_digest(r, &element)?;
+    }
+    for r in &s.manifest_references {
+        let path = r.uri.split('?').next().unwrap_or_default();
+        let path = path.trim_start_matches('/');
+        let mut data =
+            read_zip(archive, path).map_err(|_| SignatureStatus::DigestMismatch(r.uri.clone()))?;
+        for t in &r.transforms {
+            let transformed = if t == RELATIONSHIP_TRANSFORM {
+                relationship_subset(&data, &r.source_ids, &r.source_types)
+            } else if is_c14n(t) {
+                canonicalize(&data, |_, _| true)
+            } else {
+                return Err(SignatureStatus::Unsupported(t.clone()));
+            };
+            data = transformed.ok_or_else(|| SignatureStatus::DigestMismatch(r.uri.clone()))?;
+        }
+        check_digest(r, &data)?;
+    }
+    let signed_info = canonicalize(xml, |name, _| name.local_name == "SignedInfo")
+        .ok_or(SignatureStatus::InvalidSignatureValue)?;
+    let certificate = decode_base64(&s.certificate).unwrap_or_default();
+    let value = decode_base64(&s.signature_value).unwrap_or_default();
+    if verifier.verify(&s.signature_method, &certificate, &signed_info, &value) {
+        Ok(())
+    } else {
+        Err(SignatureStatus::InvalidSignatureValue)
+    }
+}
+
+// Check the OPC digital signatures of the package. The digests of the signed parts are
+// checked here and the signature values by `verifier`.
+pub fn verify_signatures(
+    buf: &[u8],
+    verifier: &dyn SignatureVerifier,
+) -> Result<Vec<PackageSignature>, ReaderError> {
+    let mut archive = ZipArchive::new(Cursor::new(buf))?;
+    let rels = rels::read_rels_xml(&read_zip(&mut archive, "_rels/.rels")?[..], "")?;
+    let origin = match rels
+        .get(SIGNATURE_ORIGIN_TYPE)
+        .and_then(|targets| targets.iter().next())
+    {
+        Some((_, origin, ..)) => origin.clone(),
+        None => return Ok(vec![]),
+    };
+    let origin_rels = rels::find_rels_filename(&origin)?;
+    let origin_rels = match read_zip(
+        &mut archive,
+        origin_rels.to_str().expect("should have origin rels"),
+    ) {
+        Ok(data) => data,
+        Err(_) => return Ok(vec![]),
+    };
+    let dir = origin.parent().unwrap_or_else(|| Path::new(""));
+    let origin_rels = rels::read_rels_xml(&origin_rels[..], dir)?;
+
+    let mut signatures = vec![];
+    for (_, path, ..) in origin_rels.get(SIGNATURE_TYPE).cloned().unwrap_or_default() {
+        let part = path
+            .to_str()
+            .expect("should have signature path")
+            .to_owned();
+        let xml = read_zip(&mut archive, &part)?;
+        let s = read_signature(&xml)?;
+        let status = match check_signature(&xml, &s, &mut archive, verifier) {
+            Ok(()) => SignatureStatus::Valid,
+            Err(status) => status,
+        };
+        signatures.push(PackageSignature {
+            part,
+            certificate: decode_base64(&s.certificate).unwrap_or_default(),
+            signing_time: s.signing_time,
+            status,
+        });
+    }
+    Ok(signatures)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+    use std::io;
+
+    // Signs with the SHA-256 of the certificate and the signed info, which stands in for a key.
+    struct TestKey;
+
+    impl SignatureProvider for TestKey {
+        fn certificate(&self) -> Vec<u8> {
+            b"certificate".to_vec()
+        }
+
+        fn signing_time(&self) -> String {
+            "2026-10-14T09:30:00Z".to_owned()
+        }
+
+        fn sign(&self, signed_info: &[u8]) -> io::Result<Vec<u8>> {
+            Ok(sha256(&[&self.certificate()[..], signed_info].concat()))
+        }
+    }
+
+    impl SignatureVerifier for TestKey {
+        fn verify(&self, method: &str, cert: &[u8], signed_info: &[u8], sig: &[u8]) -> bool {
+            method == RSA_SHA256 && sha256(&[cert, signed_info].concat()) == sig
+        }
+    }
+
+    fn signed() -> Vec<u8> {
+        let docx =
+            Docx::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("signed")));
+        let mut buf = Cursor::new(vec![]);
+        docx.pack_signed(&mut buf, &TestKey).unwrap();
+        buf.into_inner()
+    }
+
+    fn replace_part(buf: &[u8], name: &str, data: &[u8]) -> Vec<u8> {
+        let mut archive = ZipArchive::new(Cursor::new(buf)).unwrap();
+        let mut zip = zip::ZipWriter::new(Cursor::new(vec![]));
+        for i in 0..archive.len() {
+            let file = archive.by_index_raw(i).unwrap();
+            if file.name() == name {
+                drop(file);
+                zip.start_file(name, Default::default()).unwrap();
+                io::Write::write_all(&mut zip, data).unwrap();
+            } else {
+                zip.raw_copy_file(file).unwrap();
+            }
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_verify_signatures() {
+        let buf = signed();
+        assert_eq!(
+            verify_signatures(&buf, &TestKey).unwrap(),
+            vec![PackageSignature {
+                part: "_xmlsignatures/sig1.xml".to_owned(),
+                certificate: b"certificate".to_vec(),
+                signing_time: Some("2026-10-14T09:30:00Z".to_owned()),
+                status: SignatureStatus::Valid,
+            }]
+        );
+        assert!(read_docx(&buf).is_ok());
+        assert_eq!(
+            verify_signatures(&pack_unsigned(), &TestKey).unwrap(),
+            vec![]
+        );
+    }
+
+    fn pack_unsigned() -> Vec<u8> {
+        let mut buf = Cursor::new(vec![]);
+        Docx::new().build().pack(&mut buf).unwrap();
+        buf.into_inner()
+    }
+
+    #[test]
+    fn test_verify_tampered_signatures() {
+        let buf = signed();
+        let tampered = replace_part(&buf, "word/styles.xml", b"<w:styles />");
+        let status = verify_signatures(&tampered, &TestKey).unwrap()[0]
+            .status
+            .clone();
+        assert_eq!(
+            status,
+            SignatureStatus::DigestMismatch(
+                "/word/styles.xml?ContentType=application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"
+                    .to_owned()
+            )
+        );
+
+        let mut archive = ZipArchive::new(Cursor::new(&buf[..])).unwrap();
+        let sig = read_zip(&mut archive, "_xmlsignatures/sig1.xml").unwrap();
+        let sig = String::from_utf8(sig)
+            .unwrap()
+            .replace("2026-10-14", "2026-10-15");
+        let tampered = replace_part(&buf, "_xmlsignatures/sig1.xml", sig.as_bytes());
+        let status = verify_signatures(&tampered, &TestKey).unwrap()[0]
+            .status
+            .clone();
+        assert_eq!(
+            status,
+            SignatureStatus::DigestMismatch("#idPackageObject".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_verify_edited_relationships() {
+        let buf = signed();
+        let mut archive = ZipArchive::new(Cursor::new(&buf[..])).unwrap();
+        let rels = read_zip(&mut archive, "word/_rels/document.xml.rels").unwrap();
+        let rels = String::from_utf8(rels).unwrap();
+        let sig = read_zip(&mut archive, "_xmlsignatures/sig1.xml").unwrap();
+        let references = read_signature(&sig).unwrap().manifest_references;
+        let reference = |path: &str| references.iter().find(|r| r.uri.starts_with(path)).unwrap();
+        let document_rels = reference("/word/_rels/document.xml.rels?");
+        assert_eq!(
+            document_rels.transforms,
+            vec![RELATIONSHIP_TRANSFORM.to_owned(), C14N_URI.to_owned()]
+        );
+        assert_eq!(
+            document_rels.source_ids,
+            vec!["rId1", "rId2", "rId3", "rId5"]
+        );
+        // The relationship to the signature origin is not signed.
+        assert_eq!(
+            reference("/_rels/.rels?").source_ids,
+            vec!["rId1", "rId2", "rId3", "rId4"]
+        );
+
+        // The order and the formatting of the relationships are not signed.
+        let (head, tail) = rels.split_once("<Relationship ").unwrap();
+        let (first, rest) = tail.split_once("/>").unwrap();
+        let reordered = format!(
+            "{}\n  {}\n<Relationship {}/>{}",
+            head,
+            rest.trim_end_matches("</Relationships>"),
+            first,
+            "</Relationships>"
+        );
+        let edited = replace_part(&buf, "word/_rels/document.xml.rels", reordered.as_bytes());
+        assert_eq!(
+            verify_signatures(&edited, &TestKey).unwrap()[0].status,
+            SignatureStatus::Valid
+        );
+
+        let retargeted = rels.replace(r#"Target="styles.xml""#, r#"Target="other.xml""#);
+        let edited = replace_part(&buf, "word/_rels/document.xml.rels", retargeted.as_bytes());
+        assert_eq!(
+            verify_signatures(&edited, &TestKey).unwrap()[0].status,
+            SignatureStatus::DigestMismatch(
+                "/word/_rels/document.xml.rels?ContentType=application/vnd.openxmlformats-package.relationships+xml"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn test_relationship_subset() {
+        let rels = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId2" Type="urn:b" Target="b.xml"/>
+  <Relationship Id="rId1" Type="urn:a" Target="a.xml"/>
+  <Relationship Id="rId3" Type="urn:c" Target="http://example.com" TargetMode="External"/>
+</Relationships>"#;
+        let subset = relationship_subset(
+            rels.as_bytes(),
+            &["rId2".to_owned(), "rId1".to_owned()],
+            &["urn:c".to_owned()],
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(subset).unwrap(),
+            r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Target="a.xml" TargetMode="Internal" Type="urn:a"></Relationship><Relationship Id="rId2" Target="b.xml" TargetMode="Internal" Type="urn:b"></Relationship><Relationship Id="rId3" Target="http://example.com" TargetMode="External" Type="urn:c"></Relationship></Relationships>"#
+        );
+    }
+}
@@ -5,6 +5,7 @@ use xml::attribute::OwnedAttribute;
 use xml::reader::{EventReader, XmlEvent};
 
 use super::*;
+use crate::types::*;
 
 impl ElementReader for Div {
     fn read<R: Read>(
@@ -49,6 +50,52 @@ impl ElementReader for Div {
                                 }
                             }
                         }
+                        XMLElement::BlockQuote => {
+                            if read_bool(&attributes) {
+                                div = div.block_quote();
+                            }
+                        }
+                        XMLElement::BodyDiv => {
+                            if read_bool(&attributes) {
+                                div = div.body_div();
+                            }
+                        }
+                        XMLElement::DivBorder => loop {
+                            let e = r.next();
+                            match e {
+                                Ok(XmlEvent::StartElement {
+                                    attributes, name, ..
+                                }) => {
+                                    let position =
+                                        match XMLElement::from_str(&name.local_name).unwrap() {
+                                            XMLElement::Top => ParagraphBorderPosition::Top,
+                                            XMLElement::Left => ParagraphBorderPosition::Left,
+                                            XMLElement::Bottom => ParagraphBorderPosition::Bottom,
+                                            XMLElement::Right => ParagraphBorderPosition::Right,
+                                            _ => continue,
+                                        };
+                                    let attr = read_border(&attributes)?;
+                                    let mut border = ParagraphBorder::new(position)
+                                        .val(attr.border_type)
+                                        .color(attr.color);
+                                    if let Some(size) = attr.size {
+                                        border = border.size(size as usize);
+                                    }
+                                    if let Some(space) = attr.space {
+                                        border = border.space(space as usize);
+                                    }
+                                    div = div.add_border(border);
+                                }
+                                Ok(XmlEvent::EndElement { name, .. }) => {
+                                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                                    if let XMLElement::DivBorder = e {
+                                        break;
+                                    }
+                                }
+                                Err(_) => return Err(ReaderError::XMLReadError),
+                                _ => {}
+                            }
+                        },
                         XMLElement::DivsChild => loop {
                             let e = r.next();
                             match e {
@@ -8,72 +8,118 @@ use super::{Paragraph, Table};
 
 impl FromXML for Document {
     fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
-        let mut parser = EventReader::new(reader);
-        let mut doc = Self::default();
-        loop {
-            let e = parser.next();
-            match e {
-                Ok(XmlEvent::StartElement {
-                    attributes, name, ..
-                }) => {
-                    let e = XMLElement::from_str(&name.local_name).unwrap();
-                    match e {
-                        XMLElement::Paragraph => {
-                            let p = Paragraph::read(&mut parser, &attributes)?;
+        read_document(
+            reader,
+            &mut ReadContext::new(&ReadOptions::new()),
+            "word/document.xml",
+        )
+    }
+}
+
+// In lenient mode, blocks which fail to be read are skipped, and the blocks read so far are
+// kept when the XML itself is broken.
+pub(crate) fn read_document<R: Read>(
+    reader: R,
+    ctx: &mut ReadContext,
+    part: &str,
+) -> Result<Document, ReaderError> {
+    let mut parser = EventReader::new(reader);
+    let mut doc = Document::default();
+    let mut in_body = false;
+    loop {
+        let e = parser.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                attributes, name, ..
+            }) => {
+                let e = XMLElement::from_str(&name.local_name).unwrap();
+                match e {
+                    XMLElement::Paragraph => {
+                        let p = Paragraph::read(&mut parser, &attributes);
+                        if let Some(p) = ctx.recover(part, Some("p"), p)? {
                             doc = doc.add_paragraph(p);
-                            continue;
                         }
-                        XMLElement::Table => {
-                            let t = Table::read(&mut parser, &attributes)?;
+                        continue;
+                    }
+                    XMLElement::Table => {
+                        let t = Table::read(&mut parser, &attributes);
+                        if let Some(t) = ctx.recover(part, Some("tbl"), t)? {
                             doc = doc.add_table(t);
-                            continue;
                         }
-                        XMLElement::BookmarkStart => {
-                            let s = BookmarkStart::read(&mut parser, &attributes)?;
+                        continue;
+                    }
+                    XMLElement::BookmarkStart => {
+                        let s = BookmarkStart::read(&mut parser, &attributes);
+                        if let Some(s) = ctx.recover(part, Some("bookmarkStart"), s)? {
                             doc = doc.add_bookmark_start(s.id, s.name);
-                            continue;
                         }
-                        XMLElement::BookmarkEnd => {
-                            let e = BookmarkEnd::read(&mut parser, &attributes)?;
+                        continue;
+                    }
+                    XMLElement::BookmarkEnd => {
+                        let e = BookmarkEnd::read(&mut parser, &attributes);
+                        if let Some(e) = ctx.recover(part, Some("bookmarkEnd"), e)? {
                             doc = doc.add_bookmark_end(e.id);
-                            continue;
                         }
-                        XMLElement::CommentRangeStart => {
-                            if let Some(id) = read(&attributes, "id") {
-                                if let Ok(id) = usize::from_str(&id) {
-                                    let comment = Comment::new(id);
-                                    doc = doc.add_comment_start(comment);
-                                }
+                        continue;
+                    }
+                    XMLElement::CommentRangeStart => {
+                        if let Some(id) = read(&attributes, "id") {
+                            if let Ok(id) = usize::from_str(&id) {
+                                let comment = Comment::new(id);
+                                doc = doc.add_comment_start(comment);
                             }
-                            continue;
                         }
-                        XMLElement::CommentRangeEnd => {
-                            if let Some(id) = read(&attributes, "id") {
-                                if let Ok(id) = usize::from_str(&id) {
-                                    doc = doc.add_comment_end(id);
-                                }
+                        continue;
+                    }
+                    XMLElement::CommentRangeEnd => {
+                        if let Some(id) = read(&attributes, "id") {
+                            if let Ok(id) = usize::from_str(&id) {
+                                doc = doc.add_comment_end(id);
                             }
-                            continue;
                         }
-                        XMLElement::SectionProperty => {
-                            let e = SectionProperty::read(&mut parser, &attributes)?;
+                        continue;
+                    }
+                    XMLElement::SectionProperty => {
+                        let e = SectionProperty::read(&mut parser, &attributes);
+                        if let Some(e) = ctx.recover(part, Some("sectPr"), e)? {
                             doc = doc.default_section_property(e);
-                            continue;
                         }
-                        XMLElement::StructuredDataTag => {
-                            if let Ok(tag) = StructuredDataTag::read(&mut parser, &attributes) {
-                                doc = doc.add_structured_data_tag(tag);
-                            }
-                            continue;
+                        continue;
+                    }
+                    XMLElement::StructuredDataTag => {
+                        if let Ok(tag) = StructuredDataTag::read(&mut parser, &attributes) {
+                            doc = doc.add_structured_data_tag(tag);
+                        }
+                        continue;
+                    }
+                    XMLElement::AltChunk => {
+                        if let Some(id) = read(&attributes, "id") {
+                            doc = doc.add_alt_chunk(AltChunk::new(id));
                         }
-                        _ => {}
+                        continue;
                     }
+                    XMLElement::Document => {
+                        if let Some(c) = read(&attributes, "conformance") {
+                            ctx.conformance = c.parse().unwrap_or_default();
+                        }
+                    }
+                    XMLElement::Body => in_body = true,
+                    _ if in_body && unsupported::is_preserved(&name) => {
+                        let xml = unsupported::read_unsupported(&mut parser, &name, &attributes)?;
+                        doc.children.push(DocumentChild::Unsupported(xml));
+                        continue;
+                    }
+                    _ => {}
                 }
-                Ok(XmlEvent::EndDocument) => break,
-                Err(_) => return Err(ReaderError::XMLReadError),
-                _ => {}
             }
+            Ok(XmlEvent::EndDocument) => break,
+            Err(e) if ctx.lenient => {
+                ctx.warn(part, None, format!("Stopped at broken XML: {}", e));
+                break;
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
         }
-        Ok(doc)
     }
+    Ok(doc)
 }
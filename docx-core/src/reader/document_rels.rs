@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
-use std::io::Cursor;
+use std::io::{Read, Seek};
 use std::path::*;
 
 use document_rels::rels::find_rels_filename;
@@ -11,7 +11,7 @@ use super::*;
 
 pub type RId = String;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct ReadDocumentRels {
     rels: BTreeMap<String, BTreeSet<(RId, PathBuf, Option<String>)>>,
 }
@@ -24,8 +24,8 @@ impl ReadDocumentRels {
     }
 }
 
-pub fn read_document_rels(
-    archive: &mut zip::read::ZipArchive<Cursor<&[u8]>>,
+pub fn read_document_rels<R: Read + Seek>(
+    archive: &mut zip::read::ZipArchive<R>,
     main_path: impl AsRef<Path>,
 ) -> Result<ReadDocumentRels, ReaderError> {
     let dir = &main_path
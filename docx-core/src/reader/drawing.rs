@@ -11,30 +11,42 @@ use crate::{DrawingPositionType, RelativeFromHType, RelativeFromVType};
 
 use super::*;
 
+const CHART_NAMESPACE: &str = "http://schemas.openxmlformats.org/drawingml/2006/chart";
+
 fn read_position_h<R: Read>(
     r: &mut EventReader<R>,
     attrs: &[OwnedAttribute],
-) -> Result<(RelativeFromHType, i32), ReaderError> {
-    let mut offset: i32 = 0;
+) -> Result<(RelativeFromHType, DrawingPosition), ReaderError> {
+    let mut position = DrawingPosition::Offset(0);
     let mut relative_from_h = RelativeFromHType::default();
-
-    loop {
-        if let Some(h) = read(attrs, "relativeFrom") {
-            if let Ok(h) = RelativeFromHType::from_str(&h) {
-                relative_from_h = h;
-            }
+    if let Some(h) = read(attrs, "relativeFrom") {
+        if let Ok(h) = RelativeFromHType::from_str(&h) {
+            relative_from_h = h;
         }
+    }
+    let mut current = None;
+    loop {
         let e = r.next();
         match e {
-            Ok(XmlEvent::Characters(c)) => {
-                if let Ok(p) = f64::from_str(&c) {
-                    offset = p as i32;
-                }
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                current = Some(name.local_name);
             }
+            Ok(XmlEvent::Characters(c)) => match current.as_deref() {
+                Some("align") => {
+                    if let Ok(a) = PicAlign::from_str(c.trim()) {
+                        position = DrawingPosition::Align(a);
+                    }
+                }
+                _ => {
+                    if let Ok(p) = f64::from_str(&c) {
+                        position = DrawingPosition::Offset(p as i32);
+                    }
+                }
+            },
             Ok(XmlEvent::EndElement { name, .. }) => {
                 let e = WpXMLElement::from_str(&name.local_name).unwrap();
                 if e == WpXMLElement::PositionH {
-                    return Ok((relative_from_h, offset));
+                    return Ok((relative_from_h, position));
                 }
             }
             Err(_) => return Err(ReaderError::XMLReadError),
@@ -46,27 +58,37 @@ fn read_position_h<R: Read>(
 fn read_position_v<R: Read>(
     r: &mut EventReader<R>,
     attrs: &[OwnedAttribute],
-) -> Result<(RelativeFromVType, i32), ReaderError> {
-    let mut offset: i32 = 0;
+) -> Result<(RelativeFromVType, DrawingPosition), ReaderError> {
+    let mut position = DrawingPosition::Offset(0);
     let mut relative_from_v = RelativeFromVType::default();
-    loop {
-        if let Some(v) = read(attrs, "relativeFrom") {
-            if let Ok(v) = RelativeFromVType::from_str(&v) {
-                relative_from_v = v;
-            }
+    if let Some(v) = read(attrs, "relativeFrom") {
+        if let Ok(v) = RelativeFromVType::from_str(&v) {
+            relative_from_v = v;
         }
-
+    }
+    let mut current = None;
+    loop {
         let e = r.next();
         match e {
-            Ok(XmlEvent::Characters(c)) => {
-                if let Ok(p) = f64::from_str(&c) {
-                    offset = p as i32;
-                }
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                current = Some(name.local_name);
             }
+            Ok(XmlEvent::Characters(c)) => match current.as_deref() {
+                Some("align") => {
+                    if let Ok(a) = PicAlign::from_str(c.trim()) {
+                        position = DrawingPosition::Align(a);
+                    }
+                }
+                _ => {
+                    if let Ok(p) = f64::from_str(&c) {
+                        position = DrawingPosition::Offset(p as i32);
+                    }
+                }
+            },
             Ok(XmlEvent::EndElement { name, .. }) => {
                 let e = WpXMLElement::from_str(&name.local_name).unwrap();
                 if e == WpXMLElement::PositionV {
-                    return Ok((relative_from_v, offset));
+                    return Ok((relative_from_v, position));
                 }
             }
             Err(_) => return Err(ReaderError::XMLReadError),
@@ -126,15 +148,20 @@ impl ElementReader for Drawing {
         let mut simple_pos_y = 0;
         let mut layout_in_cell = true;
         let mut relative_height = 0;
-        let mut position_h = 0;
-        let mut position_v = 0;
+        let mut position_h = DrawingPosition::Offset(0);
+        let mut position_v = DrawingPosition::Offset(0);
         let mut relative_from_h = RelativeFromHType::default();
         let mut relative_from_v = RelativeFromVType::default();
         let mut allow_overlap = true;
+        let mut behind_doc = false;
+        let mut wrap = None;
+        let mut extent = None;
         let mut dist_t = 0;
         let mut dist_b = 0;
         let mut dist_l = 0;
         let mut dist_r = 0;
+        let mut doc_pr_name = None;
+        let mut doc_pr_descr = None;
 
         loop {
             let e = r.next();
@@ -187,6 +214,9 @@ impl ElementReader for Drawing {
                                         allow_overlap = false;
                                     }
                                 }
+                                if let Some(d) = read(&attributes, "behindDoc") {
+                                    behind_doc = !is_false(&d);
+                                }
                             }
                             WpXMLElement::Inline => {
                                 drawing_position_type = DrawingPositionType::Inline;
@@ -223,6 +253,26 @@ impl ElementReader for Drawing {
                                     }
                                 }
                             }
+                            WpXMLElement::Extent => {
+                                let cx =
+                                    read(&attributes, "cx").and_then(|v| u32::from_str(&v).ok());
+                                let cy =
+                                    read(&attributes, "cy").and_then(|v| u32::from_str(&v).ok());
+                                if let (Some(cx), Some(cy)) = (cx, cy) {
+                                    extent = Some((cx, cy));
+                                }
+                            }
+                            WpXMLElement::WrapNone
+                            | WpXMLElement::WrapSquare
+                            | WpXMLElement::WrapTight
+                            | WpXMLElement::WrapThrough
+                            | WpXMLElement::WrapTopAndBottom => {
+                                wrap = DrawingWrapType::from_str(&name.local_name).ok();
+                            }
+                            WpXMLElement::DocProperty => {
+                                doc_pr_name = read(&attributes, "name");
+                                doc_pr_descr = read(&attributes, "descr");
+                            }
                             WpXMLElement::PositionH => {
                                 if let Ok(p) = read_position_h(r, &attributes) {
                                     relative_from_h = p.0;
@@ -255,12 +305,32 @@ impl ElementReader for Drawing {
                             pic.dist_r = dist_r;
                             pic.relative_from_h = relative_from_h;
                             pic.relative_from_v = relative_from_v;
-                            pic.position_v = DrawingPosition::Offset(position_v);
-                            pic.position_h = DrawingPosition::Offset(position_h);
+                            pic.behind_doc = behind_doc;
+                            pic.wrap = wrap;
+                            if let Some(extent) = extent {
+                                pic.size = extent;
+                            }
+                            pic.position_v = position_v;
+                            pic.position_h = position_h;
+                            pic.name = doc_pr_name.clone();
+                            pic.description = doc_pr_descr.clone();
                             drawing = drawing.pic(pic);
                         }
                     }
 
+                    // c:
+                    if name.local_name == "chart"
+                        && name.namespace.as_deref() == Some(CHART_NAMESPACE)
+                    {
+                        let mut chart = Chart::new(read(&attributes, "id").unwrap_or_default());
+                        if let Some(extent) = extent {
+                            chart.size = extent;
+                        }
+                        chart.name = doc_pr_name.clone();
+                        chart.description = doc_pr_descr.clone();
+                        drawing = drawing.chart(chart);
+                    }
+
                     // wps:
                     if let Ok(WpsXMLElement::Txbx) = WpsXMLElement::from_str(&name.local_name) {
                         if let Ok(children) = read_textbox_content(r, &attributes) {
@@ -279,8 +349,11 @@ impl ElementReader for Drawing {
                             text_box.dist_r = dist_r;
                             text_box.relative_from_h = relative_from_h;
                             text_box.relative_from_v = relative_from_v;
-                            text_box.position_v = DrawingPosition::Offset(position_v);
-                            text_box.position_h = DrawingPosition::Offset(position_h);
+                            if let Some(extent) = extent {
+                                text_box.size = extent;
+                            }
+                            text_box.position_v = position_v;
+                            text_box.position_h = position_h;
                             text_box.children = children;
                             drawing = drawing.text_box(text_box);
                         }
@@ -298,3 +371,98 @@ impl ElementReader for Drawing {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_anchor() {
+        let c = r#"<w:drawing xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:pic="http://schemas.openxmlformats.org/drawingml/2006/picture" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+  <wp:anchor distT="0" distB="0" distL="114300" distR="114300" simplePos="0" relativeHeight="251658240" behindDoc="1" locked="0" layoutInCell="1" allowOverlap="0">
+    <wp:simplePos x="0" y="0" />
+    <wp:positionH relativeFrom="margin"><wp:align>center</wp:align></wp:positionH>
+    <wp:positionV relativeFrom="paragraph"><wp:posOffset>76200</wp:posOffset></wp:positionV>
+    <wp:extent cx="1905000" cy="952500" />
+    <wp:effectExtent l="0" t="0" r="0" b="0" />
+    <wp:wrapTopAndBottom />
+    <wp:docPr id="1" name="Picture 1" />
+    <a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/picture">
+      <pic:pic>
+        <pic:blipFill><a:blip r:embed="rId5" /></pic:blipFill>
+        <pic:spPr><a:xfrm rot="5400000"><a:off x="0" y="0" /><a:ext cx="1905000" cy="952500" /></a:xfrm><a:prstGeom prst="rect" /></pic:spPr>
+      </pic:pic>
+    </a:graphicData></a:graphic>
+  </wp:anchor>
+</w:drawing>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        // Skip to the drawing start.
+        if let Ok(XmlEvent::StartElement { .. }) = parser.next() {}
+        let pic = match Drawing::read(&mut parser, &[]).unwrap().data {
+            Some(DrawingData::Pic(pic)) => pic,
+            _ => unreachable!(),
+        };
+        assert_eq!(pic.position_type, DrawingPositionType::Anchor);
+        assert_eq!(pic.position_h, DrawingPosition::Align(PicAlign::Center));
+        assert_eq!(pic.position_v, DrawingPosition::Offset(76200));
+        assert_eq!(pic.relative_from_h, RelativeFromHType::Margin);
+        assert_eq!(pic.relative_from_v, RelativeFromVType::Paragraph);
+        assert_eq!(pic.size, (1905000, 952500));
+        assert_eq!(pic.wrap, Some(DrawingWrapType::TopAndBottom));
+        assert!(pic.behind_doc);
+        assert!(!pic.allow_overlap);
+        assert_eq!(pic.rot, 90);
+        assert_eq!(pic.dist_l, 114300);
+        assert_eq!(pic.id, "rId5");
+        assert_eq!(pic.name, Some("Picture 1".to_owned()));
+    }
+
+    #[test]
+    fn test_read_cropped_pic() {
+        let c = r#"<w:drawing xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:pic="http://schemas.openxmlformats.org/drawingml/2006/picture" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+  <wp:inline distT="0" distB="0" distL="0" distR="0">
+    <wp:extent cx="1905000" cy="952500" />
+    <wp:docPr id="1" name="Picture 1" />
+    <a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/picture">
+      <pic:pic>
+        <pic:blipFill><a:blip r:embed="rId5" /><a:srcRect l="10000" b="25000" /><a:stretch><a:fillRect /></a:stretch></pic:blipFill>
+        <pic:spPr><a:xfrm rot="10800000" flipH="1"><a:off x="0" y="0" /><a:ext cx="1905000" cy="952500" /></a:xfrm><a:prstGeom prst="rect" /></pic:spPr>
+      </pic:pic>
+    </a:graphicData></a:graphic>
+  </wp:inline>
+</w:drawing>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        // Skip to the drawing start.
+        if let Ok(XmlEvent::StartElement { .. }) = parser.next() {}
+        let pic = match Drawing::read(&mut parser, &[]).unwrap().data {
+            Some(DrawingData::Pic(pic)) => pic,
+            _ => unreachable!(),
+        };
+        assert_eq!(pic.crop, Some((10000, 0, 0, 25000)));
+        assert_eq!(pic.rot, 180);
+        assert!(pic.flip_h);
+        assert!(!pic.flip_v);
+    }
+
+    #[test]
+    fn test_read_written_text_box() {
+        let text_box = TextBox::new()
+            .size(914400, 457200)
+            .floating()
+            .offset_x(100)
+            .offset_y(200)
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Label")));
+        let b = Drawing::new().text_box(text_box.clone()).build();
+        let c = std::str::from_utf8(&b).unwrap().replacen(
+            "<w:drawing>",
+            r#"<w:drawing xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml">"#,
+            1,
+        );
+        let mut parser = EventReader::new(c.as_bytes());
+        let d = Drawing::read(&mut parser, &[]).unwrap();
+        assert_eq!(d, Drawing::new().text_box(text_box));
+    }
+}
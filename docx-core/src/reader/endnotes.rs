@@ -0,0 +1,44 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+impl FromXML for Endnotes {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut endnotes = Endnotes::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name)
+                        .expect("should convert to XMLElement");
+                    if let XMLElement::Endnote = e {
+                        // Separators have a type and a negative or zero id, and are not
+                        // referenced from the document.
+                        if read(&attributes, "type").is_some() {
+                            ignore::ignore_element(e, XMLElement::Endnote, &mut r);
+                        } else {
+                            endnotes.add(vec![Endnote::read(&mut r, &attributes)?]);
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::Endnotes {
+                        return Ok(endnotes);
+                    }
+                }
+                Ok(XmlEvent::EndDocument { .. }) => {
+                    return Ok(endnotes);
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
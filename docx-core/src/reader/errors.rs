@@ -22,6 +22,8 @@ pub enum ReaderError {
     DocumentNumberingsNotFoundError,
     #[error("Failed to find header or footer rels.")]
     HeaderOrFooterRelsNotFoundError,
+    #[error("Reading was cancelled.")]
+    Cancelled,
     #[error("Unknown error")]
     Unknown,
 }
@@ -0,0 +1,74 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+use crate::types::EmbeddedFontStyle;
+
+// Only the embedded fonts are read, since the writer generates the other fonts by itself.
+// Their paths and data are filled from the relationships of the font table.
+impl FromXML for FontTable {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut table = FontTable::new();
+        let mut font_name = String::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    let style = match e {
+                        XMLElement::Font => {
+                            font_name = read(&attributes, "name").unwrap_or_default();
+                            continue;
+                        }
+                        XMLElement::EmbedRegular => EmbeddedFontStyle::Regular,
+                        XMLElement::EmbedBold => EmbeddedFontStyle::Bold,
+                        XMLElement::EmbedItalic => EmbeddedFontStyle::Italic,
+                        XMLElement::EmbedBoldItalic => EmbeddedFontStyle::BoldItalic,
+                        _ => continue,
+                    };
+                    if let Some(id) = read(&attributes, "id") {
+                        let mut font = EmbeddedFont::new(&font_name, style, id);
+                        font.font_key = read(&attributes, "fontKey");
+                        table.embedded_fonts.push(font);
+                    }
+                }
+                Ok(XmlEvent::EndDocument { .. }) => return Ok(table),
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_font_table_from_xml() {
+        let xml = r#"<w:fonts xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+    <w:font w:name="Calibri"><w:charset w:val="00"/></w:font>
+    <w:font w:name="Custom Sans">
+        <w:embedRegular r:id="rId1" w:fontKey="{00112233-4455-6677-8899-AABBCCDDEEFF}"/>
+        <w:embedBold r:id="rId2" w:subsetted="1"/>
+    </w:font>
+</w:fonts>"#;
+        let t = FontTable::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            t.embedded_fonts,
+            vec![
+                EmbeddedFont::new("Custom Sans", EmbeddedFontStyle::Regular, "rId1")
+                    .font_key("{00112233-4455-6677-8899-AABBCCDDEEFF}"),
+                EmbeddedFont::new("Custom Sans", EmbeddedFontStyle::Bold, "rId2"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+impl ElementReader for Footnote {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let id = usize::from_str(&read(attrs, "id").expect("should footnote id exists."))?;
+        let mut footnote = Footnote {
+            id,
+            content: vec![],
+        };
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name)
+                        .expect("should convert to XMLElement");
+                    match e {
+                        XMLElement::Paragraph => {
+                            let p = Paragraph::read(r, &attributes)?;
+                            footnote.content.push(FootnoteChild::Paragraph(p));
+                        }
+                        XMLElement::Table => {
+                            let t = Table::read(r, &attributes)?;
+                            footnote.content.push(FootnoteChild::Table(t));
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::Footnote {
+                        return Ok(footnote);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+impl FromXML for Footnotes {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut r = EventReader::new(reader);
+        let mut footnotes = Footnotes::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name)
+                        .expect("should convert to XMLElement");
+                    if let XMLElement::Footnote = e {
+                        // Separators have a type and a negative or zero id, and are not
+                        // referenced from the document.
+                        if read(&attributes, "type").is_some() {
+                            ignore::ignore_element(e, XMLElement::Footnote, &mut r);
+                        } else {
+                            footnotes.add(vec![Footnote::read(&mut r, &attributes)?]);
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::Footnotes {
+                        return Ok(footnotes);
+                    }
+                }
+                Ok(XmlEvent::EndDocument { .. }) => {
+                    return Ok(footnotes);
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_footnotes_from_xml() {
+        let xml = r#"<w:footnotes xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:footnote w:type="separator" w:id="-1"><w:p><w:r><w:separator/></w:r></w:p></w:footnote>
+    <w:footnote w:type="continuationSeparator" w:id="0"><w:p><w:r><w:continuationSeparator/></w:r></w:p></w:footnote>
+    <w:footnote w:id="1"><w:p><w:r><w:t>Source</w:t></w:r></w:p></w:footnote>
+</w:footnotes>"#;
+        let f = Footnotes::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(f.inner().len(), 1);
+        assert_eq!(f.inner()[0].id, 1);
+        assert!(
+            matches!(&f.inner()[0].content[0], FootnoteChild::Paragraph(p) if p.raw_text() == "Source")
+        );
+    }
+
+    #[test]
+    fn test_footnotes_with_table_from_xml() {
+        let xml = r#"<w:footnotes xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:footnote w:id="1">
+        <w:p><w:r><w:t>Source</w:t></w:r></w:p>
+        <w:tbl><w:tr><w:tc><w:p><w:r><w:t>Cell</w:t></w:r></w:p></w:tc></w:tr></w:tbl>
+        <w:p />
+    </w:footnote>
+</w:footnotes>"#;
+        let f = Footnotes::from_xml(xml.as_bytes()).unwrap();
+        let content = &f.inner()[0].content;
+        assert_eq!(content.len(), 3);
+        match &content[1] {
+            FootnoteChild::Table(t) => assert_eq!(t.rows.len(), 1),
+            _ => panic!("should read the table"),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+impl ElementReader for FormFieldData {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        _attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut data = FormFieldData::text_input();
+        // Without a type element, treat the field as a text input.
+        let mut field = FormField::TextInput {
+            default: None,
+            max_length: None,
+        };
+        // `w:checked` overrides the default state of check boxes.
+        let mut checked = None;
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes,
+                    name: n,
+                    ..
+                }) => {
+                    let e = XMLElement::from_str(&n.local_name).unwrap();
+                    let val = read(&attributes, "val");
+                    match (e, &mut field) {
+                        (XMLElement::Name, _) => data.name = val,
+                        (XMLElement::HelpText, _) => data.help_text = val,
+                        (XMLElement::StatusText, _) => data.status_text = val,
+                        (XMLElement::CheckBox, _) => {
+                            field = FormField::CheckBox { checked: false };
+                        }
+                        (XMLElement::DropDownList, _) => {
+                            field = FormField::DropDown {
+                                entries: vec![],
+                                selected: 0,
+                            };
+                        }
+                        (XMLElement::Checked, _) => {
+                            checked = Some(val.map(|v| !is_false(&v)).unwrap_or(true));
+                        }
+                        (
+                            XMLElement::Default,
+                            FormField::CheckBox {
+                                checked: ref mut default,
+                            },
+                        ) => {
+                            *default = val.map(|v| !is_false(&v)).unwrap_or(false);
+                        }
+                        (XMLElement::Default, FormField::TextInput { default, .. }) => {
+                            *default = val;
+                        }
+                        (XMLElement::MaxLength, FormField::TextInput { max_length, .. }) => {
+                            *max_length = val.and_then(|v| usize::from_str(&v).ok());
+                        }
+                        (XMLElement::DropDownResult, FormField::DropDown { selected, .. }) => {
+                            *selected = val.and_then(|v| usize::from_str(&v).ok()).unwrap_or(0);
+                        }
+                        (XMLElement::ListEntry, FormField::DropDown { entries, .. }) => {
+                            entries.extend(val);
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name: n, .. }) => {
+                    let e = XMLElement::from_str(&n.local_name).unwrap();
+                    if e == XMLElement::FormFieldData {
+                        if let (FormField::CheckBox { checked: c }, Some(checked)) =
+                            (&mut field, checked)
+                        {
+                            *c = checked;
+                        }
+                        data.field = field;
+                        return Ok(data);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_form_field_data() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:r>
+    <w:fldChar w:fldCharType="begin">
+        <w:ffData>
+            <w:name w:val="Size"/>
+            <w:enabled/>
+            <w:helpText w:type="text" w:val="Pick a size"/>
+            <w:ddList>
+                <w:result w:val="1"/>
+                <w:listEntry w:val="S"/>
+                <w:listEntry w:val="M"/>
+            </w:ddList>
+        </w:ffData>
+    </w:fldChar>
+</w:r>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let run = Run::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            run.children,
+            vec![RunChild::FieldChar(
+                FieldChar::new(FieldCharType::Begin).form_field(
+                    FormFieldData::drop_down(vec!["S".to_owned(), "M".to_owned()], 1)
+                        .name("Size")
+                        .help_text("Pick a size")
+                )
+            )]
+        );
+    }
+}
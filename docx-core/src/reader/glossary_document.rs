@@ -0,0 +1,120 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+// Read the building blocks of the glossary document part. Its styles, numberings and other
+// parts are read by the caller.
+impl FromXML for GlossaryDocument {
+    fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
+        let mut parser = EventReader::new(reader);
+        let mut glossary = GlossaryDocument::new();
+        let mut block: Option<BuildingBlock> = None;
+        // `w:name` is both the name of the entry and of its category.
+        let mut in_category = false;
+        loop {
+            let e = parser.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::DocPart {
+                        block = Some(BuildingBlock::default());
+                        continue;
+                    }
+                    let b = match block.as_mut() {
+                        Some(b) => b,
+                        None => continue,
+                    };
+                    match e {
+                        XMLElement::Category => in_category = true,
+                        XMLElement::Name if in_category => b.category = read_val(&attributes),
+                        XMLElement::Name => b.name = read_val(&attributes).unwrap_or_default(),
+                        XMLElement::Gallery => b.gallery = read_val(&attributes),
+                        XMLElement::Description => b.description = read_val(&attributes),
+                        XMLElement::Guid => b.guid = read_val(&attributes),
+                        XMLElement::Behavior => {
+                            if let Some(v) = read_val(&attributes) {
+                                b.behaviors.push(v);
+                            }
+                        }
+                        XMLElement::Paragraph => {
+                            let p = Paragraph::read(&mut parser, &attributes)?;
+                            b.children.push(DocumentChild::Paragraph(Box::new(p)));
+                        }
+                        XMLElement::Table => {
+                            let t = Table::read(&mut parser, &attributes)?;
+                            b.children.push(DocumentChild::Table(Box::new(t)));
+                        }
+                        XMLElement::StructuredDataTag => {
+                            let t = StructuredDataTag::read(&mut parser, &attributes)?;
+                            b.children
+                                .push(DocumentChild::StructuredDataTag(Box::new(t)));
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    match e {
+                        XMLElement::Category => in_category = false,
+                        XMLElement::DocPart => {
+                            if let Some(b) = block.take() {
+                                glossary = glossary.add_building_block(b);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndDocument) => break,
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+        Ok(glossary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_glossary_document_from_xml() {
+        let xml = r#"<w:glossaryDocument xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:docParts>
+    <w:docPart>
+      <w:docPartPr>
+        <w:name w:val="Signature" />
+        <w:category><w:name w:val="General" /><w:gallery w:val="quickParts" /></w:category>
+        <w:behaviors><w:behavior w:val="content" /></w:behaviors>
+        <w:description w:val="Closing" />
+        <w:guid w:val="{6A1F3C8E-0000-0000-0000-000000000001}" />
+      </w:docPartPr>
+      <w:docPartBody><w:p><w:r><w:t>Best regards</w:t></w:r></w:p></w:docPartBody>
+    </w:docPart>
+  </w:docParts>
+</w:glossaryDocument>"#;
+        let g = GlossaryDocument::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            g.building_blocks,
+            vec![BuildingBlock {
+                name: "Signature".to_owned(),
+                gallery: Some("quickParts".to_owned()),
+                category: Some("General".to_owned()),
+                description: Some("Closing".to_owned()),
+                guid: Some("{6A1F3C8E-0000-0000-0000-000000000001}".to_owned()),
+                behaviors: vec!["content".to_owned()],
+                children: vec![DocumentChild::Paragraph(Box::new(
+                    Paragraph::new().add_run(Run::new().add_text("Best regards"))
+                ))],
+            }]
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
-use std::io::Cursor;
+use std::io::{Read, Seek};
 use std::path::*;
 
 use header_or_footer_rels::rels::find_rels_filename;
@@ -25,8 +25,8 @@ impl ReadHeaderOrFooterRels {
     }
 }
 
-pub fn read_header_or_footer_rels(
-    archive: &mut zip::read::ZipArchive<Cursor<&[u8]>>,
+pub fn read_header_or_footer_rels<R: Read + Seek>(
+    archive: &mut zip::read::ZipArchive<R>,
     header_or_footer_path: impl AsRef<Path>,
 ) -> Result<ReadHeaderOrFooterRels, ReaderError> {
     let dir = &header_or_footer_path
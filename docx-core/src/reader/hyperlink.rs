@@ -58,6 +58,18 @@ impl ElementReader for Hyperlink {
                             }
                             continue;
                         }
+                        XMLElement::MoveTo => {
+                            if let Ok(ins) = Insert::read(r, &attributes) {
+                                link = link.add_insert(ins.moved());
+                            }
+                            continue;
+                        }
+                        XMLElement::MoveFrom => {
+                            if let Ok(del) = Delete::read(r, &attributes) {
+                                link = link.add_delete(del.moved());
+                            }
+                            continue;
+                        }
                         XMLElement::BookmarkStart => {
                             if let Ok(s) = BookmarkStart::read(r, &attributes) {
                                 link = link.add_bookmark_start(s.id, s.name);
@@ -44,7 +44,7 @@ impl ElementReader for Insert {
                 }
                 Ok(XmlEvent::EndElement { name, .. }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
-                    if e == XMLElement::Insert {
+                    if e == XMLElement::Insert || e == XMLElement::MoveTo {
                         for attr in attrs {
                             let local_name = &attr.name.local_name;
                             if local_name == "author" {
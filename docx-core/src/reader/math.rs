@@ -0,0 +1,341 @@
+use std::collections::HashMap;
+use std::io::Read;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+// Children and `*Pr` values of a math structure such as `m:f` or `m:nary`.
+#[derive(Default)]
+struct MathParts {
+    props: HashMap<String, String>,
+    parts: Vec<(String, Vec<MathNode>)>,
+}
+
+impl MathParts {
+    fn take(&mut self, name: &str) -> Vec<MathNode> {
+        match self.parts.iter().position(|(n, _)| n == name) {
+            Some(i) => self.parts.remove(i).1,
+            None => vec![],
+        }
+    }
+
+    fn prop(&self, name: &str, default: &str) -> String {
+        self.props
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| default.to_owned())
+    }
+}
+
+impl ElementReader for OfficeMath {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        _attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        Ok(OfficeMath {
+            children: read_nodes(r)?,
+            display: false,
+        })
+    }
+}
+
+// Read the m:oMath of an m:oMathPara as display math.
+pub(crate) fn read_math_paragraph<R: Read>(
+    r: &mut EventReader<R>,
+) -> Result<Vec<OfficeMath>, ReaderError> {
+    let mut maths = vec![];
+    let mut depth = 0;
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                if name.local_name == "oMath" {
+                    maths.push(OfficeMath::read(r, &attributes)?.display());
+                } else {
+                    depth += 1;
+                }
+            }
+            Ok(XmlEvent::EndElement { .. }) => {
+                if depth == 0 {
+                    return Ok(maths);
+                }
+                depth -= 1;
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+// Rows of an m:m, each m:mr having one m:e per cell.
+fn read_matrix<R: Read>(r: &mut EventReader<R>) -> Result<Vec<Vec<Vec<MathNode>>>, ReaderError> {
+    let mut rows = vec![];
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                if name.local_name == "mr" {
+                    let p = read_parts(r)?;
+                    rows.push(
+                        p.parts
+                            .into_iter()
+                            .filter(|(n, _)| n == "e")
+                            .map(|(_, e)| e)
+                            .collect(),
+                    );
+                } else {
+                    read_props(r, &mut HashMap::new())?;
+                }
+            }
+            Ok(XmlEvent::EndElement { .. }) => return Ok(rows),
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+// Read nodes until the end of the current element. Unsupported containers are flattened.
+fn read_nodes<R: Read>(r: &mut EventReader<R>) -> Result<Vec<MathNode>, ReaderError> {
+    let mut nodes = vec![];
+    let mut depth = 0;
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement { name, .. }) => match name.local_name.as_str() {
+                "r" => nodes.push(MathNode::run(read_text(r)?)),
+                "f" => {
+                    let mut p = read_parts(r)?;
+                    nodes.push(MathNode::fraction(p.take("num"), p.take("den")));
+                }
+                "sSup" => {
+                    let mut p = read_parts(r)?;
+                    nodes.push(MathNode::super_script(p.take("e"), p.take("sup")));
+                }
+                "sSub" => {
+                    let mut p = read_parts(r)?;
+                    nodes.push(MathNode::sub_script(p.take("e"), p.take("sub")));
+                }
+                "sSubSup" => {
+                    let mut p = read_parts(r)?;
+                    nodes.push(MathNode::sub_super_script(
+                        p.take("e"),
+                        p.take("sub"),
+                        p.take("sup"),
+                    ));
+                }
+                "rad" => {
+                    let mut p = read_parts(r)?;
+                    nodes.push(MathNode::radical(p.take("deg"), p.take("e")));
+                }
+                "nary" => {
+                    let mut p = read_parts(r)?;
+                    // The operator defaults to an integral.
+                    let operator = p.prop("chr", "∫");
+                    nodes.push(MathNode::nary(
+                        operator,
+                        p.take("sub"),
+                        p.take("sup"),
+                        p.take("e"),
+                    ));
+                }
+                "d" => {
+                    let p = read_parts(r)?;
+                    let begin = p.prop("begChr", "(");
+                    let end = p.prop("endChr", ")");
+                    let separator = p.prop("sepChr", "|");
+                    let elements = p
+                        .parts
+                        .into_iter()
+                        .filter(|(n, _)| n == "e")
+                        .map(|(_, e)| e)
+                        .collect();
+                    nodes.push(MathNode::Delimiter {
+                        begin,
+                        end,
+                        separator,
+                        elements,
+                    });
+                }
+                "m" => nodes.push(MathNode::matrix(read_matrix(r)?)),
+                _ => depth += 1,
+            },
+            Ok(XmlEvent::EndElement { .. }) => {
+                if depth == 0 {
+                    return Ok(nodes);
+                }
+                depth -= 1;
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+fn read_parts<R: Read>(r: &mut EventReader<R>) -> Result<MathParts, ReaderError> {
+    let mut parts = MathParts::default();
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                if name.local_name.ends_with("Pr") {
+                    read_props(r, &mut parts.props)?;
+                } else {
+                    let nodes = read_nodes(r)?;
+                    parts.parts.push((name.local_name, nodes));
+                }
+            }
+            Ok(XmlEvent::EndElement { .. }) => return Ok(parts),
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+// Collect `m:val` of the property elements, e.g. `<m:chr m:val="∑"/>`.
+fn read_props<R: Read>(
+    r: &mut EventReader<R>,
+    props: &mut HashMap<String, String>,
+) -> Result<(), ReaderError> {
+    let mut depth = 0;
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                if let Some(val) = read(&attributes, "val") {
+                    props.insert(name.local_name, val);
+                }
+                depth += 1;
+            }
+            Ok(XmlEvent::EndElement { .. }) => {
+                if depth == 0 {
+                    return Ok(());
+                }
+                depth -= 1;
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+// Text of `m:t` in a math run.
+fn read_text<R: Read>(r: &mut EventReader<R>) -> Result<String, ReaderError> {
+    let mut text = String::new();
+    let mut in_text = false;
+    let mut depth = 0;
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                in_text = name.local_name == "t";
+                depth += 1;
+            }
+            Ok(XmlEvent::Characters(c)) | Ok(XmlEvent::Whitespace(c)) => {
+                if in_text {
+                    text.push_str(&c);
+                }
+            }
+            Ok(XmlEvent::EndElement { .. }) => {
+                in_text = false;
+                if depth == 0 {
+                    return Ok(text);
+                }
+                depth -= 1;
+            }
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_math() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">
+<w:p>
+    <m:oMath>
+        <m:nary>
+            <m:naryPr><m:chr m:val="∑"/><m:ctrlPr><w:rPr><w:i/></w:rPr></m:ctrlPr></m:naryPr>
+            <m:sub><m:r><m:t>i=1</m:t></m:r></m:sub>
+            <m:sup><m:r><m:t>n</m:t></m:r></m:sup>
+            <m:e>
+                <m:sSup>
+                    <m:e><m:r><m:rPr><m:sty m:val="p"/></m:rPr><m:t>x</m:t></m:r></m:e>
+                    <m:sup><m:r><m:t>2</m:t></m:r></m:sup>
+                </m:sSup>
+            </m:e>
+        </m:nary>
+        <m:func>
+            <m:fName><m:r><m:t>sin</m:t></m:r></m:fName>
+            <m:e><m:r><m:t>θ</m:t></m:r></m:e>
+        </m:func>
+    </m:oMath>
+</w:p>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let p = Paragraph::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            p.children,
+            vec![ParagraphChild::OfficeMath(Box::new(
+                OfficeMath::new()
+                    .add_node(MathNode::nary(
+                        "∑",
+                        vec![MathNode::run("i=1")],
+                        vec![MathNode::run("n")],
+                        vec![MathNode::super_script(
+                            vec![MathNode::run("x")],
+                            vec![MathNode::run("2")]
+                        )],
+                    ))
+                    .add_node(MathNode::run("sin"))
+                    .add_node(MathNode::run("θ"))
+            ))]
+        );
+    }
+
+    #[test]
+    fn test_read_math_paragraph() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">
+<w:p>
+    <m:oMathPara>
+        <m:oMathParaPr><m:jc m:val="center"/></m:oMathParaPr>
+        <m:oMath>
+            <m:r><m:t>A=</m:t></m:r>
+            <m:m>
+                <m:mPr><m:mcs><m:mc><m:mcPr><m:count m:val="2"/></m:mcPr></m:mc></m:mcs></m:mPr>
+                <m:mr><m:e><m:r><m:t>1</m:t></m:r></m:e><m:e><m:r><m:t>2</m:t></m:r></m:e></m:mr>
+                <m:mr><m:e><m:r><m:t>3</m:t></m:r></m:e><m:e><m:r><m:t>4</m:t></m:r></m:e></m:mr>
+            </m:m>
+        </m:oMath>
+    </m:oMathPara>
+</w:p>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let p = Paragraph::read(&mut parser, &[]).unwrap();
+        let cell = |t: &str| vec![MathNode::run(t)];
+        assert_eq!(
+            p.children,
+            vec![ParagraphChild::OfficeMath(Box::new(
+                OfficeMath::new()
+                    .add_node(MathNode::run("A="))
+                    .add_node(MathNode::matrix(vec![
+                        vec![cell("1"), cell("2")],
+                        vec![cell("3"), cell("4")],
+                    ]))
+                    .display()
+            ))]
+        );
+    }
+}
@@ -1,27 +1,41 @@
 mod a_graphic;
 mod a_graphic_data;
+mod app_properties;
 mod attributes;
 mod bookmark_end;
 mod bookmark_start;
 mod cell_margins;
+mod chart;
+mod color_scheme;
 mod comment;
 mod comment_extended;
 mod comments;
 mod comments_extended;
+mod complex_field;
+mod core_properties;
+mod custom_item_property;
 mod custom_properties;
 mod delete;
+mod digital_signature;
 mod div;
 mod doc_defaults;
 mod doc_grid;
 mod document;
 mod document_rels;
 mod drawing;
+mod endnote;
+mod endnotes;
 mod errors;
 mod font_group;
 mod font_scheme;
+mod font_table;
 mod footer;
+mod footnote;
+mod footnotes;
+mod form_field_data;
 mod frame_property;
 mod from_xml;
+mod glossary_document;
 mod header;
 mod header_or_footer_rels;
 mod hyperlink;
@@ -29,14 +43,19 @@ mod ignore;
 mod insert;
 mod level;
 mod level_override;
+mod math;
 mod mc_fallback;
 mod numbering_property;
 mod numberings;
+mod package;
 mod page_num_type;
 mod paragraph;
 mod paragraph_property;
 mod paragraph_property_change;
 mod pic;
+mod read_options;
+#[cfg(feature = "tokio")]
+mod read_async;
 mod read_zip;
 mod rels;
 mod run;
@@ -46,6 +65,7 @@ mod settings;
 mod shading;
 mod shape;
 mod structured_data_tag;
+mod structured_data_tag_property;
 mod style;
 mod styles;
 mod tab;
@@ -55,28 +75,42 @@ mod table_cell;
 mod table_cell_borders;
 mod table_cell_margins;
 mod table_cell_property;
-mod table_property;
+mod table_look;
 mod table_position_property;
+mod table_property;
 mod table_row;
+mod table_style_property;
 mod tabs;
 mod text_box_content;
+mod texts;
 mod theme;
+mod unsupported;
 mod web_settings;
 mod wp_anchor;
 mod wps_shape;
 mod wps_text_box;
 mod xml_element;
 
-use std::{collections::HashMap, io::Cursor, path::PathBuf};
+use std::{
+    collections::HashMap,
+    io::{Cursor, Read, Seek},
+    path::{Path, PathBuf},
+};
 
 use crate::documents::*;
 
 pub use attributes::*;
+pub use digital_signature::*;
 pub use document_rels::*;
 pub use errors::ReaderError;
 pub use from_xml::*;
 pub use mc_fallback::*;
+pub use package::*;
+pub use read_options::*;
+#[cfg(feature = "tokio")]
+pub use read_async::*;
 pub use read_zip::*;
+pub use texts::*;
 pub use xml_element::*;
 use zip::ZipArchive;
 
@@ -85,6 +119,10 @@ use self::header_or_footer_rels::{read_header_or_footer_rels, ReadHeaderOrFooter
 // 2006
 const DOC_RELATIONSHIP_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument";
+const CORE_PROPERTIES_TYPE: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties";
+const APP_PROPERTIES_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties";
 const CUSTOM_PROPERTIES_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties";
 const STYLE_RELATIONSHIP_TYPE: &str =
@@ -107,132 +145,497 @@ const IMAGE_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image";
 const HYPERLINK_TYPE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink";
+const FOOTNOTES_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footnotes";
+const ENDNOTES_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/endnotes";
+const CHART_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart";
+const FONT_TABLE_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/fontTable";
+const FONT_TYPE: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships/font";
+const ALT_CHUNK_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/aFChunk";
+const CUSTOM_XML_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXml";
+const CUSTOM_XML_PROPS_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXmlProps";
 // 2011
+const GLOSSARY_DOCUMENT_TYPE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/glossaryDocument";
 const COMMENTS_EXTENDED_TYPE: &str =
     "http://schemas.microsoft.com/office/2011/relationships/commentsExtended";
+const VBA_PROJECT_TYPE: &str = "http://schemas.microsoft.com/office/2006/relationships/vbaProject";
+
+// The Main Document part is usually at /word/document.xml.
+fn main_document_path(rels: &Rels) -> String {
+    rels.find_target(DOC_RELATIONSHIP_TYPE)
+        .map(|rel| rel.2.clone())
+        .unwrap_or_else(|| "word/document.xml".to_owned())
+}
+
+// Fill the paths and data of embedded fonts, dropping those whose parts are missing.
+fn read_embedded_fonts<R: Read + Seek>(
+    mut table: FontTable,
+    path: &Path,
+    archive: &mut ZipArchive<R>,
+    ctx: &mut ReadContext,
+) -> FontTable {
+    let rels = read_header_or_footer_rels(archive, path).unwrap_or_default();
+    let paths = rels.find_target_path(FONT_TYPE).unwrap_or_default();
+    table.embedded_fonts.retain_mut(|f| {
+        let path = paths.iter().find(|(id, ..)| id == &f.id);
+        let path = match path.and_then(|(_, p, _)| p.to_str()) {
+            Some(p) => p,
+            None => return false,
+        };
+        match ctx.read_zip(archive, path) {
+            Ok(data) => {
+                f.path = path.strip_prefix("word/").unwrap_or(path).to_owned();
+                f.data = data;
+                true
+            }
+            Err(_) => false,
+        }
+    });
+    table
+}
+
+// Read the glossary document with the parts in its folder, to write them back as they were.
+// The parts outside of the folder are those of the main document.
+fn read_glossary_document<R: Read + Seek>(
+    path: &Path,
+    archive: &mut ZipArchive<R>,
+    content_types: &ContentTypes,
+    ctx: &mut ReadContext,
+) -> Result<Option<GlossaryDocument>, ReaderError> {
+    let part = path.to_str().expect("should have glossary document");
+    let data = ctx.read_zip(archive, part);
+    let data = match ctx.recover(part, None, data)? {
+        Some(data) => data,
+        None => return Ok(None),
+    };
+    let mut glossary = match ctx.parse(part, &data, |d, _| GlossaryDocument::from_xml(d))? {
+        Some(glossary) => glossary,
+        None => return Ok(None),
+    };
+    let content_type = |p: &str| {
+        content_types
+            .find_content_type(&format!("/{}", p))
+            .map(|t| t.to_owned())
+    };
+    glossary.parts.push(GlossaryPart {
+        path: part.to_owned(),
+        content_type: content_type(part),
+        data,
+    });
 
-fn read_headers(
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let rels_path = rels::find_rels_filename(path)?;
+    let rels_path = rels_path.to_str().expect("should have glossary rels");
+    let rels_data = match ctx.read_zip(archive, rels_path) {
+        Ok(data) => data,
+        Err(_) => return Ok(Some(glossary)),
+    };
+    let rels = rels::read_rels_xml(&rels_data[..], dir)?;
+    glossary.parts.push(GlossaryPart {
+        path: rels_path.to_owned(),
+        content_type: None,
+        data: rels_data,
+    });
+    for (rel_type, targets) in rels {
+        for (_, target, mode) in targets {
+            if mode.as_deref() == Some("External") || !target.starts_with(dir) {
+                continue;
+            }
+            let target = target.to_str().expect("should have glossary part");
+            if let Ok(data) = ctx.read_zip(archive, target) {
+                if rel_type == STYLE_RELATIONSHIP_TYPE {
+                    glossary.styles = Styles::from_xml(&data[..]).ok();
+                } else if rel_type == NUMBERING_RELATIONSHIP_TYPE {
+                    glossary.numberings = Numberings::from_xml(&data[..]).ok();
+                }
+                glossary.parts.push(GlossaryPart {
+                    path: target.to_owned(),
+                    content_type: content_type(target),
+                    data,
+                });
+            }
+        }
+    }
+    Ok(Some(glossary))
+}
+
+// Read the VBA project with the parts related from it, such as `vbaData.xml`, to write them
+// back untouched.
+fn read_vba_project<R: Read + Seek>(
+    path: &Path,
+    archive: &mut ZipArchive<R>,
+    content_types: &ContentTypes,
+    ctx: &mut ReadContext,
+) -> Result<Option<VbaProject>, ReaderError> {
+    let part = path.to_str().expect("should have vba project");
+    let data = ctx.read_zip(archive, part);
+    let data = match ctx.recover(part, None, data)? {
+        Some(data) => data,
+        None => return Ok(None),
+    };
+    let content_type = |p: &str| {
+        content_types
+            .find_content_type(&format!("/{}", p))
+            .map(|t| t.to_owned())
+    };
+    let mut project = VbaProject::new(data);
+    project.parts[0].path = part.to_owned();
+    // Word declares the project by the `bin` default rather than an override.
+    if let Some(t) = content_type(part) {
+        project.parts[0].content_type = Some(t);
+    }
+
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let rels_path = rels::find_rels_filename(path)?;
+    let rels_path = rels_path.to_str().expect("should have vba project rels");
+    let rels_data = match ctx.read_zip(archive, rels_path) {
+        Ok(data) => data,
+        Err(_) => return Ok(Some(project)),
+    };
+    let rels = rels::read_rels_xml(&rels_data[..], dir)?;
+    project.parts.push(VbaProjectPart {
+        path: rels_path.to_owned(),
+        content_type: None,
+        data: rels_data,
+    });
+    for (_, targets) in rels {
+        for (_, target, mode) in targets {
+            if mode.as_deref() == Some("External") {
+                continue;
+            }
+            let target = target.to_str().expect("should have vba project part");
+            if let Ok(data) = ctx.read_zip(archive, target) {
+                project.parts.push(VbaProjectPart {
+                    path: target.to_owned(),
+                    content_type: content_type(target),
+                    data,
+                });
+            }
+        }
+    }
+    Ok(Some(project))
+}
+
+fn read_headers<R: Read + Seek>(
     rels: &ReadDocumentRels,
-    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    archive: &mut ZipArchive<R>,
+    ctx: &mut ReadContext,
 ) -> HashMap<RId, (Header, ReadHeaderOrFooterRels)> {
     let header_paths = rels.find_target_path(HEADER_TYPE);
     let headers: HashMap<RId, (Header, ReadHeaderOrFooterRels)> = header_paths
         .unwrap_or_default()
         .into_iter()
         .filter_map(|(rid, path, ..)| {
-            let data = read_zip(archive, path.to_str().expect("should have header path."));
-            if let Ok(d) = data {
-                if let Ok(h) = Header::from_xml(&d[..]) {
-                    let rels = read_header_or_footer_rels(archive, path).unwrap_or_default();
-                    return Some((rid, (h, rels)));
-                }
-            }
-            None
+            let part = path.to_str().expect("should have header path.");
+            let h = read_part(archive, part, ctx, |d, _| Header::from_xml(d)).ok()??;
+            let rels = read_header_or_footer_rels(archive, path).unwrap_or_default();
+            Some((rid, (h, rels)))
         })
         .collect();
     headers
 }
 
-fn read_footers(
+fn read_footers<R: Read + Seek>(
     rels: &ReadDocumentRels,
-    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    archive: &mut ZipArchive<R>,
+    ctx: &mut ReadContext,
 ) -> HashMap<RId, (Footer, ReadHeaderOrFooterRels)> {
     let footer_paths = rels.find_target_path(FOOTER_TYPE);
     let footers: HashMap<RId, (Footer, ReadHeaderOrFooterRels)> = footer_paths
         .unwrap_or_default()
         .into_iter()
         .filter_map(|(rid, path, ..)| {
-            let data = read_zip(archive, path.to_str().expect("should have footer path."));
-            if let Ok(d) = data {
-                if let Ok(h) = Footer::from_xml(&d[..]) {
-                    let rels = read_header_or_footer_rels(archive, path).unwrap_or_default();
-                    return Some((rid, (h, rels)));
-                }
-            }
-            None
+            let part = path.to_str().expect("should have footer path.");
+            let h = read_part(archive, part, ctx, |d, _| Footer::from_xml(d)).ok()??;
+            let rels = read_header_or_footer_rels(archive, path).unwrap_or_default();
+            Some((rid, (h, rels)))
         })
         .collect();
     footers
 }
 
-fn read_themes(rels: &ReadDocumentRels, archive: &mut ZipArchive<Cursor<&[u8]>>) -> Vec<Theme> {
+fn read_themes<R: Read + Seek>(
+    rels: &ReadDocumentRels,
+    archive: &mut ZipArchive<R>,
+    ctx: &mut ReadContext,
+) -> Vec<Theme> {
     let theme_paths = rels.find_target_path(THEME_TYPE);
     theme_paths
         .unwrap_or_default()
         .into_iter()
         .filter_map(|(_rid, path, ..)| {
-            let data = read_zip(archive, path.to_str().expect("should have footer path."));
-            if let Ok(d) = data {
-                if let Ok(h) = Theme::from_xml(&d[..]) {
-                    return Some(h);
-                }
-            }
-            None
+            let part = path.to_str().expect("should have theme path.");
+            read_part(archive, part, ctx, |d, _| Theme::from_xml(d)).ok()?
         })
         .collect()
 }
 
+// Resolve the `..` of targets such as `word/../customXml/item1.xml`.
+fn normalize_part_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for c in path.components() {
+        match c {
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            std::path::Component::CurDir => {}
+            c => normalized.push(c),
+        }
+    }
+    normalized
+}
+
+// Read the custom XML parts with their data store properties, skipping those which can not
+// be parsed.
+fn read_custom_xml_parts<R: Read + Seek>(
+    rels: &ReadDocumentRels,
+    archive: &mut ZipArchive<R>,
+    ctx: &mut ReadContext,
+) -> Vec<(CustomItem, CustomItemProperty)> {
+    let item_paths = rels.find_target_path(CUSTOM_XML_TYPE).unwrap_or_default();
+    let mut parts = vec![];
+    for (_, path, ..) in item_paths {
+        let path = normalize_part_path(&path);
+        let item_path = path.to_str().expect("should have custom xml path.");
+        let item = match ctx.read_zip(archive, item_path) {
+            Ok(data) => String::from_utf8_lossy(&data).parse::<CustomItem>(),
+            Err(_) => continue,
+        };
+        let item = match item {
+            Ok(item) => item,
+            Err(_) => continue,
+        };
+        let props = read_header_or_footer_rels(archive, &path)
+            .ok()
+            .and_then(|r| r.find_target_path(CUSTOM_XML_PROPS_TYPE))
+            .and_then(|paths| paths.into_iter().next())
+            .and_then(|(_, props_path, ..)| {
+                let part = props_path.to_str().expect("should have item props path.");
+                read_optional_part(archive, part, ctx, |d, _| CustomItemProperty::from_xml(d))
+                    .ok()?
+            });
+        let props = props.unwrap_or_default();
+        parts.push((item, props));
+    }
+    parts
+}
+
+// Read the charts with the parts related from them, such as the embedded workbook, to write
+// them back. The charts are given new relationship ids, which the rebuilt relationships of
+// the document do not use.
+fn read_charts<R: Read + Seek>(
+    rels: &ReadDocumentRels,
+    archive: &mut ZipArchive<R>,
+    content_types: &ContentTypes,
+    ctx: &mut ReadContext,
+) -> Result<(HashMap<RId, Chart>, Vec<EmbeddedChart>), ReaderError> {
+    let content_type = |p: &str| {
+        content_types
+            .find_content_type(&format!("/{}", p))
+            .or_else(|| chart_part_content_type(p))
+            .map(|t| t.to_owned())
+    };
+    let mut charts = HashMap::new();
+    let mut embedded = vec![];
+    for (rid, path, ..) in rels.find_target_path(CHART_TYPE).unwrap_or_default() {
+        let part = path.to_str().expect("should have chart path.");
+        let data = ctx.read_zip(archive, part);
+        let data = match ctx.recover(part, None, data)? {
+            Some(data) => data,
+            None => continue,
+        };
+        let mut chart = match ctx.parse(part, &data, |d, _| Chart::from_xml(d))? {
+            Some(chart) => chart,
+            None => continue,
+        };
+        chart.id = format!("rIdChart{}", embedded.len() + 1);
+        let mut e = EmbeddedChart {
+            id: chart.id.clone(),
+            parts: vec![ChartPart {
+                path: part.to_owned(),
+                content_type: content_type(part).or_else(|| Some(CHART_CONTENT_TYPE.to_owned())),
+                data,
+            }],
+        };
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        let rels_path = rels::find_rels_filename(&path)?;
+        let rels_path = rels_path.to_str().expect("should have chart rels");
+        if let Ok(rels_data) = ctx.read_zip(archive, rels_path) {
+            let chart_rels = rels::read_rels_xml(&rels_data[..], dir)?;
+            e.parts.push(ChartPart {
+                path: rels_path.to_owned(),
+                content_type: None,
+                data: rels_data,
+            });
+            for (_, targets) in chart_rels {
+                for (_, target, mode) in targets {
+                    if mode.as_deref() == Some("External") {
+                        continue;
+                    }
+                    let target = normalize_part_path(&target);
+                    let target = target.to_str().expect("should have chart part");
+                    if e.parts.iter().any(|p| p.path == target) {
+                        continue;
+                    }
+                    if let Ok(data) = ctx.read_zip(archive, target) {
+                        e.parts.push(ChartPart {
+                            path: target.to_owned(),
+                            content_type: content_type(target),
+                            data,
+                        });
+                    }
+                }
+            }
+        }
+        charts.insert(rid, chart);
+        embedded.push(e);
+    }
+    Ok((charts, embedded))
+}
+
+// Read and parse a part of the package. In lenient mode, a missing or broken part is reported
+// as a warning and skipped.
+fn read_part<R: Read + Seek, T>(
+    archive: &mut ZipArchive<R>,
+    part: &str,
+    ctx: &mut ReadContext,
+    parse: impl Fn(&[u8], &mut ReadContext) -> Result<T, ReaderError>,
+) -> Result<Option<T>, ReaderError> {
+    let data = ctx.read_zip(archive, part);
+    match ctx.recover(part, None, data)? {
+        Some(data) => ctx.parse(part, &data, parse),
+        None => Ok(None),
+    }
+}
+
+// Parse a part which may be missing from the package.
+fn read_optional_part<R: Read + Seek, T: Default>(
+    archive: &mut ZipArchive<R>,
+    part: &str,
+    ctx: &mut ReadContext,
+    parse: impl Fn(&[u8], &mut ReadContext) -> Result<T, ReaderError>,
+) -> Result<Option<T>, ReaderError> {
+    match ctx.read_zip(archive, part) {
+        Ok(data) => Ok(Some(ctx.parse(part, &data, parse)?.unwrap_or_default())),
+        Err(ReaderError::Cancelled) => Err(ReaderError::Cancelled),
+        Err(_) => Ok(None),
+    }
+}
+
 pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
+    read_docx_from_reader(Cursor::new(buf))
+}
+
+pub fn read_docx_with_options(
+    buf: &[u8],
+    options: &ReadOptions,
+) -> Result<(Docx, Vec<ReadWarning>), ReaderError> {
+    read_docx_from_reader_with_options(Cursor::new(buf), options)
+}
+
+// Read from a file or any other seekable source. Parts are read out of the archive one at a
+// time, so the package itself does not need to be in memory.
+pub fn read_docx_from_reader<R: Read + Seek>(reader: R) -> Result<Docx, ReaderError> {
+    read_docx_from_reader_with_options(reader, &ReadOptions::new()).map(|(docx, _)| docx)
+}
+
+pub fn read_docx_from_reader_with_options<R: Read + Seek>(
+    reader: R,
+    options: &ReadOptions,
+) -> Result<(Docx, Vec<ReadWarning>), ReaderError> {
+    let mut ctx = ReadContext::new(options);
     let mut docx = Docx::new();
-    let cur = Cursor::new(buf);
-    let mut archive = zip::ZipArchive::new(cur)?;
+    let mut archive = zip::ZipArchive::new(reader)?;
+    ctx.total_bytes = (0..archive.len())
+        .filter_map(|i| archive.by_index_raw(i).ok().map(|f| f.size() as usize))
+        .sum();
     // First, the content type for relationship parts and the Main Document part
     // (the only required part) must be defined (physically located at /[Content_Types].xml in the package)
-    let _content_types = {
-        let data = read_zip(&mut archive, "[Content_Types].xml")?;
-        ContentTypes::from_xml(&data[..])?
-    };
+    let content_types = read_part(&mut archive, "[Content_Types].xml", &mut ctx, |d, _| {
+        ContentTypes::from_xml(d)
+    })?
+    .unwrap_or_default();
 
     // Next, the single required relationship (the package-level relationship to the Main Document part)
     //  must be defined (physically located at /_rels/.rels in the package)
-    let rels = {
-        let data = read_zip(&mut archive, "_rels/.rels")?;
-        Rels::from_xml(&data[..])?
-    };
+    let rels = read_part(&mut archive, "_rels/.rels", &mut ctx, |d, _| {
+        Rels::from_xml(d)
+    })?
+    .unwrap_or_default();
 
     // Finally, the minimum content for the Main Document part must be defined
     // (physically located at /document.xml in the package):
-    let main_rel = rels
-        .find_target(DOC_RELATIONSHIP_TYPE)
-        .ok_or(ReaderError::DocumentNotFoundError);
+    let document_path = main_document_path(&rels);
+    if let Some(t) = content_types
+        .find_content_type(&format!("/{}", document_path))
+        .and_then(crate::types::PackageType::from_content_type)
+    {
+        docx.package_type = t;
+    }
 
-    let document_path = if let Ok(rel) = main_rel {
-        rel.2.clone()
-    } else {
-        "word/document.xml".to_owned()
-    };
+    if let Some(core_props) = rels.find_target(CORE_PROPERTIES_TYPE) {
+        let core = read_part(&mut archive, &core_props.2, &mut ctx, |d, _| {
+            CoreProps::from_xml(d)
+        });
+        if let Ok(Some(core)) = core {
+            docx.doc_props.core = core;
+        }
+    }
+
+    if let Some(app_props) = rels.find_target(APP_PROPERTIES_TYPE) {
+        let app = read_part(&mut archive, &app_props.2, &mut ctx, |d, _| {
+            AppProps::from_xml(d)
+        });
+        if let Ok(Some(app)) = app {
+            docx.doc_props.app = app;
+        }
+    }
 
     if let Some(custom_props) = rels.find_target(CUSTOM_PROPERTIES_TYPE) {
-        let data = read_zip(&mut archive, &custom_props.2);
-        if let Ok(data) = data {
-            if let Ok(custom) = CustomProps::from_xml(&data[..]) {
-                docx.doc_props.custom = custom;
-            }
+        let custom = read_part(&mut archive, &custom_props.2, &mut ctx, |d, _| {
+            CustomProps::from_xml(d)
+        });
+        if let Ok(Some(custom)) = custom {
+            docx.doc_props.custom = custom;
         }
     }
 
-    let rels = read_document_rels(&mut archive, &document_path)?;
+    let rels = read_document_rels(&mut archive, &document_path);
+    let rels = ctx
+        .recover(&document_path, Some("relationships"), rels)?
+        .unwrap_or_default();
 
-    let headers = read_headers(&rels, &mut archive);
-    let footers = read_footers(&rels, &mut archive);
+    let (headers, footers) = if ctx.skip_headers_footers {
+        (HashMap::new(), HashMap::new())
+    } else {
+        (
+            read_headers(&rels, &mut archive, &mut ctx),
+            read_footers(&rels, &mut archive, &mut ctx),
+        )
+    };
 
-    docx.themes = read_themes(&rels, &mut archive);
+    docx.themes = read_themes(&rels, &mut archive, &mut ctx);
 
     // Read commentsExtended
     let comments_extended_path = rels.find_target_path(COMMENTS_EXTENDED_TYPE);
     let comments_extended = if let Some(comments_extended_path) = comments_extended_path {
         if let Some((_, comments_extended_path, ..)) = comments_extended_path.get(0) {
-            let data = read_zip(
+            read_optional_part(
                 &mut archive,
                 comments_extended_path
                     .to_str()
                     .expect("should have comments extended."),
-            );
-            if let Ok(data) = data {
-                CommentsExtended::from_xml(&data[..])?
-            } else {
-                CommentsExtended::default()
-            }
+                &mut ctx,
+                |d, _| CommentsExtended::from_xml(d),
+            )?
+            .unwrap_or_default()
         } else {
             CommentsExtended::default()
         }
@@ -244,12 +647,14 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
     let comments_path = rels.find_target_path(COMMENTS_TYPE);
     let comments = if let Some(paths) = comments_path {
         if let Some((_, comments_path, ..)) = paths.get(0) {
-            let data = read_zip(
+            let comments = read_optional_part(
                 &mut archive,
                 comments_path.to_str().expect("should have comments."),
-            );
-            if let Ok(data) = data {
-                let mut comments = Comments::from_xml(&data[..])?.into_inner();
+                &mut ctx,
+                |d, _| Comments::from_xml(d),
+            )?;
+            if let Some(comments) = comments {
+                let mut comments = comments.into_inner();
                 for i in 0..comments.len() {
                     let c = &comments[i];
                     let extended = comments_extended.children.iter().find(|ex| {
@@ -262,6 +667,9 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
                         }
                         false
                     });
+                    if let Some(CommentExtended { done: true, .. }) = extended {
+                        comments[i].done = true;
+                    }
                     if let Some(CommentExtended {
                         parent_paragraph_id: Some(parent_paragraph_id),
                         ..
@@ -293,131 +701,103 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
     };
 
     let document = {
-        let data = read_zip(&mut archive, &document_path)?;
-        Document::from_xml(&data[..])?
+        let data = ctx.read_zip(&mut archive, &document_path)?;
+        ctx.parse(&document_path, &data, |d, ctx| {
+            document::read_document(d, ctx, &document_path)
+        })?
+        .unwrap_or_default()
     };
     docx = docx.document(document);
+    docx.conformance = ctx.conformance;
 
-    // assign headers
-    if let Some(h) = docx.document.section_property.header_reference.clone() {
-        if let Some((header, rels)) = headers.get(&h.id) {
-            docx.document = docx.document.header(header.clone(), &h.id);
-            let count = docx.document_rels.header_count + 1;
-            docx.document_rels.header_count = count;
-            docx.content_type = docx.content_type.add_header();
-            // Read media
-            let media = rels.find_target_path(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive);
-        }
-    }
-    if let Some(ref h) = docx
-        .document
-        .section_property
-        .first_header_reference
-        .clone()
-    {
-        if let Some((header, rels)) = headers.get(&h.id) {
-            docx.document = docx
-                .document
-                .first_header_without_title_pg(header.clone(), &h.id);
-            let count = docx.document_rels.header_count + 1;
-            docx.document_rels.header_count = count;
-            docx.content_type = docx.content_type.add_header();
-            // Read media
-            let media = rels.find_target_path(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive);
-        }
-    }
-    if let Some(ref h) = docx.document.section_property.even_header_reference.clone() {
-        if let Some((header, rels)) = headers.get(&h.id) {
-            docx.document = docx.document.even_header(header.clone(), &h.id);
-            let count = docx.document_rels.header_count + 1;
-            docx.document_rels.header_count = count;
-            docx.content_type = docx.content_type.add_header();
-
-            // Read media
-            let media = rels.find_target_path(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive);
+    // assign headers and footers of the last section and of sections ending at paragraphs.
+    let section = std::mem::take(&mut docx.document.section_property);
+    let (d, section) =
+        assign_headers_footers(docx, section, &headers, &footers, &mut archive, &mut ctx);
+    docx = d;
+    docx.document.section_property = section;
+    for i in 0..docx.document.children.len() {
+        let section = match &mut docx.document.children[i] {
+            DocumentChild::Paragraph(p) => p.property.section_property.take(),
+            _ => None,
+        };
+        if let Some(section) = section {
+            let (d, section) =
+                assign_headers_footers(docx, section, &headers, &footers, &mut archive, &mut ctx);
+            docx = d;
+            if let DocumentChild::Paragraph(p) = &mut docx.document.children[i] {
+                p.property.section_property = Some(section);
+            }
         }
     }
 
-    // assign footers
-    if let Some(f) = docx.document.section_property.footer_reference.clone() {
-        if let Some((footer, rels)) = footers.get(&f.id) {
-            docx.document = docx.document.footer(footer.clone(), &f.id);
-            let count = docx.document_rels.footer_count + 1;
-            docx.document_rels.footer_count = count;
-            docx.content_type = docx.content_type.add_footer();
-
-            // Read media
-            let media = rels.find_target_path(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive);
-        }
+    // store comments to paragraphs.
+    if !comments.inner().is_empty() {
+        docx.store_comments(comments.inner());
+        docx = docx.comments(comments);
+        docx = docx.comments_extended(comments_extended);
     }
 
-    if let Some(ref f) = docx
-        .document
-        .section_property
-        .first_footer_reference
-        .clone()
-    {
-        if let Some((footer, rels)) = footers.get(&f.id) {
-            docx.document = docx
-                .document
-                .first_footer_without_title_pg(footer.clone(), &f.id);
-            let count = docx.document_rels.footer_count + 1;
-            docx.document_rels.footer_count = count;
-            docx.content_type = docx.content_type.add_footer();
-
-            // Read media
-            let media = rels.find_target_path(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive);
+    // Read footnotes and endnotes, and link them to their references.
+    let footnotes_path = rels.find_target_path(FOOTNOTES_TYPE);
+    if let Some((_, footnotes_path, ..)) = footnotes_path.as_ref().and_then(|p| p.get(0)) {
+        if let Some(footnotes) = read_optional_part(
+            &mut archive,
+            footnotes_path.to_str().expect("should have footnotes."),
+            &mut ctx,
+            |d, _| Footnotes::from_xml(d),
+        )? {
+            docx.footnotes = footnotes;
         }
     }
-    if let Some(ref f) = docx.document.section_property.even_footer_reference.clone() {
-        if let Some((footer, rels)) = footers.get(&f.id) {
-            docx.document = docx.document.even_footer(footer.clone(), &f.id);
-            let count = docx.document_rels.footer_count + 1;
-            docx.document_rels.footer_count = count;
-            docx.content_type = docx.content_type.add_footer();
-
-            // Read media
-            let media = rels.find_target_path(IMAGE_TYPE);
-            docx = add_images(docx, media, &mut archive);
+    let endnotes_path = rels.find_target_path(ENDNOTES_TYPE);
+    if let Some((_, endnotes_path, ..)) = endnotes_path.as_ref().and_then(|p| p.get(0)) {
+        if let Some(endnotes) = read_optional_part(
+            &mut archive,
+            endnotes_path.to_str().expect("should have endnotes."),
+            &mut ctx,
+            |d, _| Endnotes::from_xml(d),
+        )? {
+            docx.endnotes = endnotes;
         }
     }
+    docx.store_notes();
 
-    // store comments to paragraphs.
-    if !comments.inner().is_empty() {
-        docx.store_comments(comments.inner());
-        docx = docx.comments(comments);
-        docx = docx.comments_extended(comments_extended);
+    // Read charts, and fill the drawings which refer to them.
+    let (charts, embedded_charts) = read_charts(&rels, &mut archive, &content_types, &mut ctx)?;
+    if !charts.is_empty() {
+        docx.store_charts(&charts);
     }
+    docx.charts = embedded_charts;
 
     // Read document relationships
     // Read styles
-    let style_path = rels.find_target_path(STYLE_RELATIONSHIP_TYPE);
+    let style_path = rels
+        .find_target_path(STYLE_RELATIONSHIP_TYPE)
+        .filter(|_| !ctx.skip_styles);
     if let Some(paths) = style_path {
         if let Some((_, style_path, ..)) = paths.get(0) {
-            let data = read_zip(
-                &mut archive,
-                style_path.to_str().expect("should have styles"),
-            )?;
-            let styles = Styles::from_xml(&data[..])?;
-            docx = docx.styles(styles);
+            let part = style_path.to_str().expect("should have styles");
+            if let Some(styles) =
+                read_part(&mut archive, part, &mut ctx, |d, _| Styles::from_xml(d))?
+            {
+                docx = docx.styles(styles);
+            }
         }
     }
 
     // Read numberings
-    let num_path = rels.find_target_path(NUMBERING_RELATIONSHIP_TYPE);
+    let num_path = rels
+        .find_target_path(NUMBERING_RELATIONSHIP_TYPE)
+        .filter(|_| !ctx.skip_numberings);
     if let Some(paths) = num_path {
         if let Some((_, num_path, ..)) = paths.get(0) {
-            let data = read_zip(
-                &mut archive,
-                num_path.to_str().expect("should have numberings"),
-            )?;
-            let nums = Numberings::from_xml(&data[..])?;
-            docx = docx.numberings(nums);
+            let part = num_path.to_str().expect("should have numberings");
+            if let Some(nums) =
+                read_part(&mut archive, part, &mut ctx, |d, _| Numberings::from_xml(d))?
+            {
+                docx = docx.numberings(nums);
+            }
         }
     }
 
@@ -425,12 +805,12 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
     let settings_path = rels.find_target_path(SETTINGS_TYPE);
     if let Some(paths) = settings_path {
         if let Some((_, settings_path, ..)) = paths.get(0) {
-            let data = read_zip(
-                &mut archive,
-                settings_path.to_str().expect("should have settings"),
-            )?;
-            let settings = Settings::from_xml(&data[..])?;
-            docx = docx.settings(settings);
+            let part = settings_path.to_str().expect("should have settings");
+            if let Some(settings) =
+                read_part(&mut archive, part, &mut ctx, |d, _| Settings::from_xml(d))?
+            {
+                docx = docx.settings(settings);
+            }
         }
     }
 
@@ -438,46 +818,1170 @@ pub fn read_docx(buf: &[u8]) -> Result<Docx, ReaderError> {
     let web_settings_path = rels.find_target_path(WEB_SETTINGS_TYPE);
     if let Some(paths) = web_settings_path {
         if let Some((_, web_settings_path, ..)) = paths.get(0) {
-            let data = read_zip(
-                &mut archive,
-                web_settings_path
-                    .to_str()
-                    .expect("should have web settings"),
-            )?;
-            let web_settings = WebSettings::from_xml(&data[..])?;
-            docx = docx.web_settings(web_settings);
+            let part = web_settings_path
+                .to_str()
+                .expect("should have web settings");
+            if let Some(mut web_settings) = read_part(&mut archive, part, &mut ctx, |d, _| {
+                WebSettings::from_xml(d)
+            })? {
+                // Frames refer to their documents through the relationships of the part.
+                if let Some(frameset) = web_settings.frameset.take() {
+                    let rels = rels::find_rels_filename(web_settings_path)?;
+                    let rels = rels.to_str().expect("should have web settings rels");
+                    let rels = ctx.read_zip(&mut archive, rels).ok();
+                    web_settings = web_settings.frameset(frameset, rels);
+                }
+                docx = docx.web_settings(web_settings);
+            }
         }
     }
     // Read media
     let media = rels.find_target_path(IMAGE_TYPE);
-    docx = add_images(docx, media, &mut archive);
+    docx = add_images(docx, media, &mut archive, &mut ctx);
+
+    // Read embedded fonts
+    if let Some((_, font_table_path, ..)) = rels
+        .find_target_path(FONT_TABLE_TYPE)
+        .as_ref()
+        .and_then(|p| p.get(0))
+    {
+        let part = font_table_path.to_str().expect("should have font table");
+        if let Some(mut table) =
+            read_part(&mut archive, part, &mut ctx, |d, _| FontTable::from_xml(d))?
+        {
+            if ctx.skip_media {
+                table.embedded_fonts.clear();
+                docx.font_table = table;
+            } else {
+                docx.font_table =
+                    read_embedded_fonts(table, font_table_path, &mut archive, &mut ctx);
+            }
+        }
+    }
+
+    // Read the custom XML parts, which content controls may bind to.
+    for (item, props) in read_custom_xml_parts(&rels, &mut archive, &mut ctx) {
+        docx = docx.custom_xml_part(item, props);
+    }
+
+    // Read the parts imported by altChunks
+    if let Some(paths) = rels.find_target_path(ALT_CHUNK_TYPE) {
+        for (id, path, ..) in paths {
+            let path = path.to_str().expect("should convert to str");
+            if let Ok(data) = ctx.read_zip(&mut archive, path) {
+                let path = path.strip_prefix("word/").unwrap_or(path);
+                docx.alt_chunks.push(AltChunkPart::new(id, path, data));
+            }
+        }
+    }
+
+    if let Some((_, glossary_path, ..)) = rels
+        .find_target_path(GLOSSARY_DOCUMENT_TYPE)
+        .and_then(|paths| paths.into_iter().next())
+    {
+        docx.glossary_document =
+            read_glossary_document(&glossary_path, &mut archive, &content_types, &mut ctx)?;
+    }
+
+    if let Some((_, vba_path, ..)) = rels
+        .find_target_path(VBA_PROJECT_TYPE)
+        .and_then(|paths| paths.into_iter().next())
+    {
+        docx.vba_project = read_vba_project(&vba_path, &mut archive, &content_types, &mut ctx)?;
+    }
 
     // Read hyperlinks
     let links = rels.find_target_path(HYPERLINK_TYPE);
     if let Some(paths) = links {
         for (id, target, mode) in paths {
-            if let Some(mode) = mode {
-                docx =
-                    docx.add_hyperlink(id, target.to_str().expect("should convert to str"), mode);
-            }
+            let mode = mode.unwrap_or_else(|| "Internal".to_owned());
+            docx = docx.add_hyperlink(id, target.to_str().expect("should convert to str"), mode);
         }
     }
+    docx.store_hyperlinks();
 
-    Ok(docx)
+    // Parts whose errors are ignored may have been the ones cancelled.
+    if ctx.cancelled {
+        return Err(ReaderError::Cancelled);
+    }
+    Ok((docx, ctx.warnings))
+}
+
+impl Docx {
+    pub fn read_with_options(
+        buf: &[u8],
+        options: &ReadOptions,
+    ) -> Result<(Docx, Vec<ReadWarning>), ReaderError> {
+        read_docx_with_options(buf, options)
+    }
+}
+
+fn assign_headers_footers<R: Read + Seek>(
+    mut docx: Docx,
+    mut section: SectionProperty,
+    headers: &HashMap<RId, (Header, ReadHeaderOrFooterRels)>,
+    footers: &HashMap<RId, (Footer, ReadHeaderOrFooterRels)>,
+    archive: &mut ZipArchive<R>,
+    ctx: &mut ReadContext,
+) -> (Docx, SectionProperty) {
+    let mut found_headers = vec![];
+    if let Some(h) = section.header_reference.clone() {
+        if let Some((header, rels)) = headers.get(&h.id) {
+            section = section.header(header.clone(), &h.id);
+            found_headers.push(rels);
+        }
+    }
+    if let Some(h) = section.first_header_reference.clone() {
+        if let Some((header, rels)) = headers.get(&h.id) {
+            section = section.first_header_without_title_pg(header.clone(), &h.id);
+            found_headers.push(rels);
+        }
+    }
+    if let Some(h) = section.even_header_reference.clone() {
+        if let Some((header, rels)) = headers.get(&h.id) {
+            section = section.even_header(header.clone(), &h.id);
+            found_headers.push(rels);
+        }
+    }
+    for rels in found_headers {
+        docx.document_rels.header_count += 1;
+        docx.content_type = docx.content_type.add_header();
+        // Read media
+        let media = rels.find_target_path(IMAGE_TYPE);
+        docx = add_images(docx, media, archive, ctx);
+    }
+
+    let mut found_footers = vec![];
+    if let Some(f) = section.footer_reference.clone() {
+        if let Some((footer, rels)) = footers.get(&f.id) {
+            section = section.footer(footer.clone(), &f.id);
+            found_footers.push(rels);
+        }
+    }
+    if let Some(f) = section.first_footer_reference.clone() {
+        if let Some((footer, rels)) = footers.get(&f.id) {
+            section = section.first_footer_without_title_pg(footer.clone(), &f.id);
+            found_footers.push(rels);
+        }
+    }
+    if let Some(f) = section.even_footer_reference.clone() {
+        if let Some((footer, rels)) = footers.get(&f.id) {
+            section = section.even_footer(footer.clone(), &f.id);
+            found_footers.push(rels);
+        }
+    }
+    for rels in found_footers {
+        docx.document_rels.footer_count += 1;
+        docx.content_type = docx.content_type.add_footer();
+        // Read media
+        let media = rels.find_target_path(IMAGE_TYPE);
+        docx = add_images(docx, media, archive, ctx);
+    }
+    (docx, section)
 }
 
-fn add_images(
+fn add_images<R: Read + Seek>(
     mut docx: Docx,
     media: Option<Vec<(RId, PathBuf, Option<String>)>>,
-    archive: &mut ZipArchive<Cursor<&[u8]>>,
+    archive: &mut ZipArchive<R>,
+    ctx: &mut ReadContext,
 ) -> Docx {
     // Read media
+    if ctx.skip_media {
+        return docx;
+    }
     if let Some(paths) = media {
         for (id, media, ..) in paths {
-            if let Ok(data) = read_zip(archive, media.to_str().expect("should have media")) {
+            if let Ok(data) = ctx.read_zip(archive, media.to_str().expect("should have media")) {
                 docx = docx.add_image(id, media.to_str().unwrap().to_string(), data);
             }
         }
     }
     docx
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn header(text: &str) -> Header {
+        Header::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text(text)))
+    }
+
+    fn footer(text: &str) -> Footer {
+        Footer::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text(text)))
+    }
+
+    fn pack(docx: Docx) -> Vec<u8> {
+        let mut buf = Cursor::new(vec![]);
+        docx.build().pack(&mut buf).unwrap();
+        buf.into_inner()
+    }
+
+    fn section_headers(s: &SectionProperty) -> Vec<String> {
+        s.get_headers()
+            .iter()
+            .map(|h| match &h.children[0] {
+                HeaderChild::Paragraph(p) => p.raw_text(),
+                _ => String::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_read_section_headers_footers() {
+        let first = SectionProperty::new()
+            .header(header("first section"), "rIdA")
+            .footer(footer("first footer"), "rIdB");
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("one"))
+                    .section_property(first),
+            )
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("two")))
+            .header(header("last section"));
+
+        let read = read_docx(&pack(docx)).unwrap();
+        let section = match &read.document.children[0] {
+            DocumentChild::Paragraph(p) => p.property.section_property.clone().unwrap(),
+            _ => unreachable!(),
+        };
+        assert_eq!(section_headers(&section), vec!["first section"]);
+        assert!(section.footer.is_some());
+        assert_eq!(
+            section_headers(&read.document.section_property),
+            vec!["last section"]
+        );
+        assert_eq!(read.document_rels.header_count, 2);
+
+        // The references are renumbered on write, so they still match the parts.
+        let reread = read_docx(&pack(read)).unwrap();
+        assert_eq!(
+            section_headers(&reread.document.section_property),
+            vec!["last section"]
+        );
+    }
+
+    #[test]
+    fn test_read_comment_replies() {
+        // Paragraph ids are fixed in tests, so give the comments distinct ones.
+        let comment = |id: usize, text: &str| {
+            Comment::new(id).author("bokuweb").add_paragraph(
+                Paragraph::new()
+                    .id(format!("0000000{}", id))
+                    .add_run(Run::new().add_text(text)),
+            )
+        };
+        let docx = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_comment_start(comment(1, "Question").done())
+                .add_comment_start(comment(2, "Answer").parent_comment_id(1))
+                .add_run(Run::new().add_text("Hello"))
+                .add_comment_end(2)
+                .add_comment_end(1),
+        );
+
+        let read = read_docx(&pack(docx)).unwrap();
+        let comments = read.comments.inner();
+        assert_eq!(comments.len(), 2);
+        assert!(comments[0].done);
+        assert_eq!(comments[0].parent_comment_id, None);
+        assert!(!comments[1].done);
+        assert_eq!(comments[1].parent_comment_id, Some(1));
+        let replies = read.comments.replies(1);
+        assert_eq!(replies.len(), 1);
+        assert_eq!(replies[0].id, 2);
+        assert!(read.comments.replies(2).is_empty());
+    }
+
+    #[test]
+    fn test_read_and_pack_table_in_notes() {
+        let table = || {
+            Table::new(vec![TableRow::new(vec![TableCell::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Cell")))])])
+        };
+        let mut footnote = Footnote::new();
+        let footnote = footnote
+            .add_content(Paragraph::new().add_run(Run::new().add_text("Footnote")))
+            .add_table(table());
+        let mut endnote = Endnote::new();
+        let endnote = endnote.add_table(table());
+        let docx = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_footnote_reference(footnote))
+                .add_run(Run::new().add_endnote_reference(endnote)),
+        );
+
+        // Read, packed again and read once more.
+        let read = read_docx(&pack(read_docx(&pack(docx)).unwrap())).unwrap();
+        let footnote = &read.footnotes.inner()[0];
+        assert_eq!(footnote.content.len(), 2);
+        assert!(matches!(&footnote.content[1], FootnoteChild::Table(t) if t.rows.len() == 1));
+        let endnote = &read.endnotes.inner()[0];
+        assert!(matches!(&endnote.content[0], EndnoteChild::Table(_)));
+    }
+
+    #[test]
+    fn test_read_footnotes_endnotes() {
+        let mut footnote = Footnote::new();
+        let footnote =
+            footnote.add_content(Paragraph::new().add_run(Run::new().add_text("Footnote")));
+        let mut endnote = Endnote::new();
+        let endnote = endnote.add_content(Paragraph::new().add_run(Run::new().add_text("Endnote")));
+        let docx = Docx::new().add_paragraph(
+            Paragraph::new()
+                .add_run(Run::new().add_text("Text"))
+                .add_run(Run::new().add_footnote_reference(footnote))
+                .add_run(Run::new().add_endnote_reference(endnote)),
+        );
+
+        let read = read_docx(&pack(docx)).unwrap();
+        let content = |docx: &Docx| match &docx.document.children[0] {
+            DocumentChild::Paragraph(p) => p
+                .children
+                .iter()
+                .filter_map(|c| match c {
+                    ParagraphChild::Run(r) => r.children.get(0),
+                    _ => None,
+                })
+                .filter_map(|c| match c {
+                    RunChild::FootnoteReference(f) => match &f.content[0] {
+                        FootnoteChild::Paragraph(p) => Some(p.raw_text()),
+                        FootnoteChild::Table(_) => None,
+                    },
+                    RunChild::EndnoteReference(e) => match &e.content[0] {
+                        EndnoteChild::Paragraph(p) => Some(p.raw_text()),
+                        EndnoteChild::Table(_) => None,
+                    },
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+            _ => unreachable!(),
+        };
+        assert_eq!(content(&read), vec!["Footnote", "Endnote"]);
+        assert_eq!(read.footnotes.inner().len(), 1);
+        assert_eq!(read.endnotes.inner().len(), 1);
+
+        let reread = read_docx(&pack(read)).unwrap();
+        assert_eq!(content(&reread), vec!["Footnote", "Endnote"]);
+    }
+
+    #[test]
+    fn test_read_doc_props() {
+        let mut docx = Docx::new()
+            .created_at("2021-01-01T00:00:00Z")
+            .updated_at("2021-02-01T00:00:00Z")
+            .custom_property("client", "bokuweb");
+        docx.doc_props.core.config.creator = Some("author".to_owned());
+        docx.doc_props.app.application = Some("docx-rs".to_owned());
+        docx.doc_props.app.pages = Some(1);
+
+        let read = read_docx(&pack(docx)).unwrap();
+        assert_eq!(read.core_props().creator, Some("author".to_owned()));
+        assert_eq!(
+            read.core_props().created,
+            Some("2021-01-01T00:00:00Z".to_owned())
+        );
+        assert_eq!(
+            read.core_props().modified,
+            Some("2021-02-01T00:00:00Z".to_owned())
+        );
+        assert_eq!(read.core_props().revision, Some(1));
+        assert_eq!(read.app_props().application, Some("docx-rs".to_owned()));
+        assert_eq!(read.app_props().pages, Some(1));
+        assert_eq!(
+            read.custom_props().properties.get("client"),
+            Some(&"bokuweb".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_read_from_reader() {
+        let docx =
+            Docx::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")));
+        let path = std::env::temp_dir().join("docx_rs_test_read_from_reader.docx");
+        std::fs::write(&path, pack(docx)).unwrap();
+        let file = std::fs::File::open(&path).unwrap();
+        let read = read_docx_from_reader(std::io::BufReader::new(file)).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        match &read.document.children[0] {
+            DocumentChild::Paragraph(p) => assert_eq!(p.raw_text(), "Hello"),
+            _ => unreachable!(),
+        }
+    }
+
+    // Copy the package, replacing, adding or removing parts.
+    fn replace_parts(buf: &[u8], parts: &[(&str, Option<&str>)]) -> Vec<u8> {
+        use std::io::Write;
+        let mut archive = zip::ZipArchive::new(Cursor::new(buf)).unwrap();
+        let mut out = zip::ZipWriter::new(Cursor::new(vec![]));
+        for (name, data) in parts {
+            if let (Some(data), Err(_)) = (data, archive.by_name(name)) {
+                out.start_file(*name, zip::write::FileOptions::default())
+                    .unwrap();
+                out.write_all(data.as_bytes()).unwrap();
+            }
+        }
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i).unwrap();
+            let name = file.name().to_owned();
+            let mut data = vec![];
+            file.read_to_end(&mut data).unwrap();
+            let data = match parts.iter().find(|(n, _)| *n == name) {
+                Some((_, Some(replaced))) => replaced.as_bytes().to_vec(),
+                Some((_, None)) => continue,
+                None => data,
+            };
+            out.start_file(name, zip::write::FileOptions::default())
+                .unwrap();
+            out.write_all(&data).unwrap();
+        }
+        out.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_read_vml_shapes() {
+        let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+        let mut image = Vec::new();
+        img.read_to_end(&mut image).unwrap();
+        let buf = pack(
+            Docx::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_image(Pic::new(&image)))),
+        );
+        let rels = part(&buf, "word/_rels/document.xml.rels");
+        let id = rels
+            .split("Id=\"")
+            .map(|s| s.split('"').next().unwrap())
+            .find(|id| id.starts_with("rIdImage"))
+            .unwrap();
+        let document = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:v="urn:schemas-microsoft-com:vml" xmlns:o="urn:schemas-microsoft-com:office:office" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+  <w:body>
+    <w:p><w:r><w:pict><v:shape style="width:100pt"><v:imagedata r:id="{}" o:title="" /></v:shape></w:pict></w:r></w:p>
+    <w:p><w:r><w:pict><v:rect><v:textbox><w:txbxContent><w:p><w:r><w:t>Legacy</w:t></w:r></w:p></w:txbxContent></v:textbox></v:rect></w:pict></w:r></w:p>
+  </w:body>
+</w:document>"#,
+            id
+        );
+        let buf = replace_parts(&buf, &[("word/document.xml", Some(&document))]);
+        let docx = read_docx(&buf).unwrap();
+        let shapes: Vec<&Shape> = docx
+            .document
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => match &p.children[0] {
+                    ParagraphChild::Run(r) => match &r.children[0] {
+                        RunChild::Shape(s) => Some(s.as_ref()),
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+        assert_eq!(shapes.len(), 2);
+        assert_eq!(shapes[0].image_ids(), vec![id]);
+        assert_eq!(docx.render_ascii_json().text, "\nLegacy");
+
+        // The shapes and the image they refer to are written back.
+        let buf = pack(docx);
+        assert!(part(&buf, "word/document.xml").contains("<v:textbox><w:txbxContent>"));
+        assert!(part(&buf, "word/_rels/document.xml.rels").contains(&format!(r#"Id="{}""#, id)));
+        let docx = read_docx(&buf).unwrap();
+        assert_eq!(docx.render_ascii_json().text, "\nLegacy");
+    }
+
+    #[test]
+    fn test_read_lenient() {
+        let document = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p w14:paraId="00000001"><w:r><w:t>first</w:t></w:r></w:p>
+    <w:p><w:r><w:t>broken</w:t><w:footnoteReference w:id="x" /></w:r></w:p>
+    <w:p><w:r><w:t>last</w:t></w:r></w:p>
+  </w:body>
+</w:document>"#;
+        let buf = replace_parts(
+            &pack(Docx::new()),
+            &[
+                ("word/document.xml", Some(document)),
+                ("word/styles.xml", None),
+            ],
+        );
+        assert!(read_docx(&buf).is_err());
+
+        let (docx, warnings) =
+            Docx::read_with_options(&buf, &ReadOptions::new().lenient(true)).unwrap();
+        let texts: Vec<String> = docx
+            .document
+            .children
+            .iter()
+            .filter_map(|c| match c {
+                DocumentChild::Paragraph(p) => Some(p.raw_text()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(texts, vec!["first", "last"]);
+        assert_eq!(
+            warnings,
+            vec![
+                ReadWarning {
+                    part: "word/document.xml".to_owned(),
+                    element: None,
+                    message: "Declared missing namespaces.".to_owned(),
+                },
+                ReadWarning {
+                    part: "word/document.xml".to_owned(),
+                    element: Some("p".to_owned()),
+                    message: "Failed to parse int.".to_owned(),
+                },
+                ReadWarning {
+                    part: "word/styles.xml".to_owned(),
+                    element: None,
+                    message: "Failed to read from zip.".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_chart() {
+        let document = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+  <w:body>
+    <w:tbl><w:tr><w:tc><w:p><w:r><w:drawing>
+      <wp:inline><wp:extent cx="5486400" cy="3200400" /><wp:docPr id="1" name="Chart 1" />
+        <a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/chart"><c:chart r:id="rId10" /></a:graphicData></a:graphic>
+      </wp:inline>
+    </w:drawing></w:r></w:p></w:tc></w:tr></w:tbl>
+  </w:body>
+</w:document>"#;
+        let chart = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart">
+  <c:chart><c:plotArea><c:lineChart><c:ser>
+    <c:tx><c:v>Visitors</c:v></c:tx>
+    <c:cat><c:numLit><c:ptCount val="2" /><c:pt idx="0"><c:v>2021</c:v></c:pt><c:pt idx="1"><c:v>2022</c:v></c:pt></c:numLit></c:cat>
+    <c:val><c:numLit><c:ptCount val="2" /><c:pt idx="0"><c:v>120</c:v></c:pt><c:pt idx="1"><c:v>150.5</c:v></c:pt></c:numLit></c:val>
+  </c:ser></c:lineChart></c:plotArea></c:chart>
+</c:chartSpace>"#;
+        let buf = pack(Docx::new());
+        let mut archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+        let rels = String::from_utf8(read_zip(&mut archive, "word/_rels/document.xml.rels").unwrap())
+            .unwrap()
+            .replace(
+                "</Relationships>",
+                r#"<Relationship Id="rId10" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart" Target="charts/chart1.xml" /></Relationships>"#,
+            );
+        let buf = replace_parts(
+            &buf,
+            &[
+                ("word/document.xml", Some(document)),
+                ("word/_rels/document.xml.rels", Some(&rels)),
+                ("word/charts/chart1.xml", Some(chart)),
+            ],
+        );
+        let docx = read_docx(&buf).unwrap();
+        let cell = match &docx.document.children[0] {
+            DocumentChild::Table(t) => {
+                let TableChild::TableRow(row) = &t.rows[0];
+                let TableRowChild::TableCell(cell) = &row.cells[0];
+                cell.clone()
+            }
+            _ => unreachable!(),
+        };
+        let drawing = match &cell.children[0] {
+            TableCellContent::Paragraph(p) => match &p.children[0] {
+                ParagraphChild::Run(run) => match &run.children[0] {
+                    RunChild::Drawing(d) => d.clone(),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+        let mut expected = Chart::new("rIdChart1")
+            .size(5486400, 3200400)
+            .chart_type(ChartType::Line)
+            .add_series(
+                ChartSeries::new()
+                    .name("Visitors")
+                    .categories(vec!["2021".to_owned(), "2022".to_owned()])
+                    .values(vec![Some(120.0), Some(150.5)]),
+            );
+        expected.name = Some("Chart 1".to_owned());
+        assert_eq!(drawing.data, Some(DrawingData::Chart(expected)));
+    }
+
+    #[test]
+    fn test_read_chart_and_pack() {
+        let document = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+  <w:body>
+    <w:p><w:r><w:drawing>
+      <wp:inline><wp:extent cx="5486400" cy="3200400" /><wp:docPr id="1" name="Chart 1" />
+        <a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/chart"><c:chart r:id="rId1" /></a:graphicData></a:graphic>
+      </wp:inline>
+    </w:drawing></w:r></w:p>
+  </w:body>
+</w:document>"#;
+        let chart = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+  <c:chart><c:plotArea><c:barChart><c:ser>
+    <c:val><c:numLit><c:ptCount val="1" /><c:pt idx="0"><c:v>3</c:v></c:pt></c:numLit></c:val>
+  </c:ser></c:barChart></c:plotArea></c:chart>
+  <c:externalData r:id="rId1" />
+</c:chartSpace>"#;
+        let chart_rels = r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/package" Target="../embeddings/Microsoft_Excel_Worksheet.xlsx" /></Relationships>"#;
+        let buf = pack(Docx::new());
+        let mut archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+        // The chart has the id of the styles in the rebuilt relationships.
+        let rels = String::from_utf8(read_zip(&mut archive, "word/_rels/document.xml.rels").unwrap())
+            .unwrap()
+            .replace(
+                r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml" />"#,
+                r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart" Target="charts/chart1.xml" />"#,
+            );
+        let buf = replace_parts(
+            &buf,
+            &[
+                ("word/document.xml", Some(document)),
+                ("word/_rels/document.xml.rels", Some(&rels)),
+                ("word/charts/chart1.xml", Some(chart)),
+                ("word/charts/_rels/chart1.xml.rels", Some(chart_rels)),
+                (
+                    "word/embeddings/Microsoft_Excel_Worksheet.xlsx",
+                    Some("workbook"),
+                ),
+            ],
+        );
+        let read = read_docx(&buf).unwrap();
+
+        let mut packed = Cursor::new(vec![]);
+        read.clone().build().pack(&mut packed).unwrap();
+        let packed = packed.into_inner();
+        assert!(part(&packed, "word/document.xml").contains(r#"<c:chart xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" r:id="rIdChart1" />"#));
+        let rels = part(&packed, "word/_rels/document.xml.rels");
+        assert!(rels.contains(r#"<Relationship Id="rIdChart1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart" Target="charts/chart1.xml" />"#));
+        assert!(rels.contains(r#"Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles""#));
+        assert_eq!(part(&packed, "word/charts/chart1.xml"), chart);
+        assert_eq!(
+            part(&packed, "word/charts/_rels/chart1.xml.rels"),
+            chart_rels
+        );
+        assert_eq!(
+            part(&packed, "word/embeddings/Microsoft_Excel_Worksheet.xlsx"),
+            "workbook"
+        );
+        let content_types = part(&packed, "[Content_Types].xml");
+        assert!(content_types.contains(r#"<Override PartName="/word/charts/chart1.xml" ContentType="application/vnd.openxmlformats-officedocument.drawingml.chart+xml" />"#));
+        assert!(content_types.contains(r#"<Override PartName="/word/embeddings/Microsoft_Excel_Worksheet.xlsx" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" />"#));
+
+        let reread = read_docx(&packed).unwrap();
+        assert_eq!(reread.document, read.document);
+        assert_eq!(reread.charts, read.charts);
+    }
+
+    fn part(buf: &[u8], name: &str) -> String {
+        let mut archive = zip::ZipArchive::new(Cursor::new(buf)).unwrap();
+        let mut s = String::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_string(&mut s)
+            .unwrap();
+        s
+    }
+
+    #[test]
+    fn test_read_hyperlinks() {
+        let link = Paragraph::new().add_hyperlink(
+            Hyperlink::new("https://example.com/?a=1&b=2", HyperlinkType::External)
+                .add_run(Run::new().add_text("link")),
+        );
+        let docx = Docx::new().add_table(Table::new(vec![TableRow::new(vec![
+            TableCell::new().add_paragraph(link)
+        ])]));
+        let read = read_docx(&pack(docx)).unwrap();
+        let json = serde_json::to_string(&read.document).unwrap();
+        assert!(json.contains(r#""path":"https://example.com/?a=1&amp;b=2""#));
+        let rels = part(&pack(read), "word/_rels/document.xml.rels");
+        // The relationship is written again with its target.
+        assert!(rels.contains(r#"Target="https://example.com/?a=1&amp;b=2" TargetMode="External""#));
+
+        let mut docx = Docx::new().add_paragraph(Paragraph::new().add_hyperlink(Hyperlink {
+            link: HyperlinkData::External {
+                rid: "rId9".to_owned(),
+                path: String::new(),
+            },
+            history: None,
+            children: vec![],
+        }));
+        docx.hyperlinks = vec![(
+            "rId9".to_owned(),
+            "#intro".to_owned(),
+            "Internal".to_owned(),
+        )];
+        docx.store_hyperlinks();
+        match &docx.document.children[0] {
+            DocumentChild::Paragraph(p) => assert_eq!(
+                p.children[0],
+                ParagraphChild::Hyperlink(Hyperlink::new("intro", HyperlinkType::Anchor))
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_fill_structured_data_tags() {
+        let docx = Docx::new()
+            .add_structured_data_tag(
+                StructuredDataTag::new()
+                    .tag("title")
+                    .showing_placeholder()
+                    .add_paragraph(
+                        Paragraph::new().add_run(Run::new().add_text("Enter a title").bold()),
+                    ),
+            )
+            .add_table(Table::new(vec![TableRow::new(vec![TableCell::new()
+                .add_paragraph(
+                    Paragraph::new()
+                        .add_run(Run::new().add_text("Name: "))
+                        .add_structured_data_tag(
+                            StructuredDataTag::new()
+                                .tag("name")
+                                .add_run(Run::new().add_text("Click here")),
+                        ),
+                )])]));
+        let read = read_docx(&pack(docx))
+            .unwrap()
+            .fill_structured_data_tag("title", "Report")
+            .fill_structured_data_tag("name", "Alice");
+        let xml = part(&pack(read), "word/document.xml");
+        assert!(!xml.contains("showingPlcHdr"));
+        assert!(xml.contains(r#"<w:b /><w:bCs /></w:rPr><w:t xml:space="preserve">Report</w:t>"#));
+        assert!(xml.contains(
+            r#"<w:tag w:val="name" />
+</w:sdtPr><w:sdtContent><w:r><w:rPr /><w:t xml:space="preserve">Alice</w:t></w:r></w:sdtContent>"#
+        ));
+    }
+
+    #[test]
+    fn test_read_alt_chunks() {
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Before")))
+            .add_alt_chunk("afchunk1.html", b"<html><p>Imported</p></html>".to_vec());
+        let read = read_docx(&pack(docx)).unwrap();
+        assert_eq!(
+            read.document.children[1],
+            DocumentChild::AltChunk(AltChunk::new("AltChunkId1"))
+        );
+        assert_eq!(
+            read.alt_chunks(),
+            &[AltChunkPart {
+                id: "AltChunkId1".to_owned(),
+                path: "afchunk1.html".to_owned(),
+                content_type: "text/html".to_owned(),
+                data: b"<html><p>Imported</p></html>".to_vec(),
+            }]
+        );
+
+        let buf = pack(read.replace_alt_chunk("AltChunkId1", b"<html>Replaced</html>".to_vec()));
+        assert_eq!(part(&buf, "word/afchunk1.html"), "<html>Replaced</html>");
+        assert!(part(&buf, "word/_rels/document.xml.rels").contains(r#"<Relationship Id="AltChunkId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/aFChunk" Target="afchunk1.html" />"#));
+        assert!(part(&buf, "[Content_Types].xml")
+            .contains(r#"<Override PartName="/word/afchunk1.html" ContentType="text/html" />"#));
+    }
+
+    #[test]
+    fn test_read_embedded_fonts() {
+        let key = "{00112233-4455-6677-8899-AABBCCDDEEFF}";
+        let font: Vec<u8> = (0..64).collect();
+        let mut docx = Docx::new()
+            .add_embedded_font(
+                EmbeddedFont::new("Custom Sans", EmbeddedFontStyle::Regular, "rId1").font_key(key),
+                &font,
+            )
+            .add_embedded_font(
+                EmbeddedFont::new("Custom Sans", EmbeddedFontStyle::Bold, "rId2"),
+                &font,
+            );
+        docx.settings = Settings::new().embed_true_type_fonts();
+        let buf = pack(docx);
+        assert!(part(&buf, "[Content_Types].xml").contains(
+            r#"<Override PartName="/word/fonts/font1.odttf" ContentType="application/vnd.openxmlformats-officedocument.obfuscatedFont" />"#
+        ));
+
+        let read = read_docx(&buf).unwrap();
+        assert_eq!(read.embedded_font_names(), vec!["Custom Sans"]);
+        let fonts = read.embedded_fonts();
+        assert_eq!(fonts.len(), 2);
+        assert_eq!(fonts[0].path, "fonts/font1.odttf");
+        assert_ne!(fonts[0].data, font);
+        assert_eq!(fonts[0].font_data(), font);
+        assert_eq!(fonts[1].font_data(), font);
+        assert_eq!(read.settings, Settings::new().embed_true_type_fonts());
+
+        // Written back as they were read.
+        let read = read_docx(&pack(read)).unwrap();
+        assert_eq!(read.embedded_fonts()[0].font_data(), font);
+        assert_eq!(read.embedded_fonts()[1].font_key, None);
+    }
+
+    #[test]
+    fn test_read_images() {
+        let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+        let mut buf = Vec::new();
+        img.read_to_end(&mut buf).unwrap();
+        let pic = Pic::new(&buf).size(320 * 9525, 240 * 9525);
+        let id = pic.id.clone();
+        let docx = Docx::new().add_paragraph(Paragraph::new().add_run(Run::new().add_image(pic)));
+        let read = read_docx(&pack(docx)).unwrap();
+        let images = read.images();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].id, id);
+        assert_eq!(images[0].path, format!("word/media/{}.png", id));
+        assert_eq!(images[0].content_type, "image/png");
+        assert_eq!(images[0].size, Some((320 * 9525, 240 * 9525)));
+        assert!(image::load_from_memory(&images[0].data).is_ok());
+    }
+
+    #[test]
+    fn test_read_floating_image() {
+        let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+        let mut buf = Vec::new();
+        img.read_to_end(&mut buf).unwrap();
+        let pic = Pic::new(&buf)
+            .size(320 * 9525, 240 * 9525)
+            .floating()
+            .wrap(DrawingWrapType::Tight)
+            .behind_doc(true)
+            .position_h(DrawingPosition::Align(PicAlign::Right))
+            .offset_y(9525)
+            .relative_from_h(RelativeFromHType::Margin);
+        let docx = Docx::new().add_paragraph(Paragraph::new().add_run(Run::new().add_image(pic)));
+        let read = read_docx(&pack(docx)).unwrap();
+        let read = match &read.document.children[0] {
+            DocumentChild::Paragraph(p) => match &p.children[0] {
+                ParagraphChild::Run(run) => match &run.children[0] {
+                    RunChild::Drawing(d) => match &d.data {
+                        Some(DrawingData::Pic(pic)) => pic.clone(),
+                        _ => unreachable!(),
+                    },
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+        assert_eq!(read.position_type, DrawingPositionType::Anchor);
+        assert_eq!(read.wrap, Some(DrawingWrapType::Tight));
+        assert!(read.behind_doc);
+        assert_eq!(read.position_h, DrawingPosition::Align(PicAlign::Right));
+        assert_eq!(read.position_v, DrawingPosition::Offset(9525));
+        assert_eq!(read.relative_from_h, RelativeFromHType::Margin);
+        assert_eq!(read.size, (320 * 9525, 240 * 9525));
+    }
+
+    #[test]
+    fn test_read_progress() {
+        use std::sync::{Arc, Mutex};
+
+        let buf = pack(
+            Docx::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+                .header(header("Head")),
+        );
+        let parts = Arc::new(Mutex::new(vec![]));
+        let reported = parts.clone();
+        let options = ReadOptions::new().on_progress(move |p| {
+            reported.lock().unwrap().push(p.clone());
+            true
+        });
+        assert!(read_docx_with_options(&buf, &options).is_ok());
+        let parts = parts.lock().unwrap();
+        assert_eq!(parts[0].part, "[Content_Types].xml");
+        assert!(parts.iter().any(|p| p.part == "word/document.xml"));
+        assert!(parts.iter().any(|p| p.part == "word/header1.xml"));
+        let last = parts.last().unwrap();
+        assert_eq!(
+            last.bytes_read,
+            parts.iter().map(|p| p.bytes).sum::<usize>()
+        );
+        assert!(last.bytes_read <= last.total_bytes);
+
+        // Cancelled after the document, even when reading in lenient mode.
+        let options = ReadOptions::new()
+            .lenient(true)
+            .on_progress(|p| p.part != "word/document.xml");
+        assert!(matches!(
+            read_docx_with_options(&buf, &options),
+            Err(ReaderError::Cancelled)
+        ));
+        let options = ReadOptions::new().on_progress(|p| p.part != "word/header1.xml");
+        assert!(matches!(
+            read_docx_with_options(&buf, &options),
+            Err(ReaderError::Cancelled)
+        ));
+    }
+
+    #[test]
+    fn test_read_glossary_document() {
+        let buf = pack(Docx::new().add_paragraph(Paragraph::new()));
+        let rels = part(&buf, "word/_rels/document.xml.rels").replace(
+            "</Relationships>",
+            r#"<Relationship Id="rId99" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/glossaryDocument" Target="glossary/document.xml" /></Relationships>"#,
+        );
+        let content_types = part(&buf, "[Content_Types].xml").replace(
+            "</Types>",
+            r#"<Override PartName="/word/glossary/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.glossary+xml" /><Override PartName="/word/glossary/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml" /></Types>"#,
+        );
+        let glossary = r#"<w:glossaryDocument xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:docParts><w:docPart><w:docPartPr><w:name w:val="Signature" /><w:category><w:name w:val="General" /><w:gallery w:val="quickParts" /></w:category></w:docPartPr><w:docPartBody><w:p><w:pPr><w:pStyle w:val="Closing" /></w:pPr><w:r><w:t>Best regards</w:t></w:r></w:p></w:docPartBody></w:docPart></w:docParts></w:glossaryDocument>"#;
+        let glossary_rels = r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml" /></Relationships>"#;
+        let glossary_styles = r#"<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:style w:type="paragraph" w:styleId="Closing"><w:name w:val="Closing" /></w:style></w:styles>"#;
+        let buf = replace_parts(
+            &buf,
+            &[
+                ("word/_rels/document.xml.rels", Some(&rels)),
+                ("[Content_Types].xml", Some(&content_types)),
+                ("word/glossary/document.xml", Some(glossary)),
+                ("word/glossary/_rels/document.xml.rels", Some(glossary_rels)),
+                ("word/glossary/styles.xml", Some(glossary_styles)),
+            ],
+        );
+
+        let read = read_docx(&buf).unwrap();
+        let blocks = read.building_blocks();
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].name, "Signature");
+        assert_eq!(blocks[0].gallery.as_deref(), Some("quickParts"));
+        assert_eq!(blocks[0].category.as_deref(), Some("General"));
+        let glossary_document = read.glossary_document.as_ref().unwrap();
+        assert!(glossary_document
+            .styles
+            .as_ref()
+            .unwrap()
+            .find_style_by_id("Closing")
+            .is_some());
+        assert_eq!(glossary_document.parts.len(), 3);
+
+        // Written back as they were read.
+        let buf = pack(read);
+        assert_eq!(part(&buf, "word/glossary/document.xml"), glossary);
+        assert_eq!(part(&buf, "word/glossary/styles.xml"), glossary_styles);
+        assert!(part(&buf, "word/_rels/document.xml.rels").contains(r#"<Relationship Id="rIdGlossaryDocument" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/glossaryDocument" Target="glossary/document.xml" />"#));
+        assert!(part(&buf, "[Content_Types].xml").contains(r#"<Override PartName="/word/glossary/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml" />"#));
+        assert_eq!(read_docx(&buf).unwrap().building_blocks().len(), 1);
+    }
+
+    #[test]
+    fn test_read_web_settings() {
+        let frameset = r#"<w:frameset xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><w:frame><w:sourceFileName r:id="rId1" /></w:frame></w:frameset>"#;
+        let frame_rels = r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/frame" Target="frame.docx" TargetMode="External" /></Relationships>"#;
+        let web_settings = WebSettings::new()
+            .add_div(Div::new("1").block_quote())
+            .allow_png()
+            .frameset(frameset.to_owned(), Some(frame_rels.as_bytes().to_vec()));
+        let buf = pack(Docx::new().web_settings(web_settings.clone()));
+        assert!(part(&buf, "word/webSettings.xml").contains("<w:allowPNG />"));
+        assert_eq!(part(&buf, "word/_rels/webSettings.xml.rels"), frame_rels);
+
+        let read = read_docx(&buf).unwrap();
+        assert_eq!(read.web_settings, web_settings);
+        let buf = pack(read);
+        assert!(part(&buf, "word/_rels/document.xml.rels").contains(r#"Target="webSettings.xml""#));
+
+        // Without web settings, the part is not written.
+        let read = read_docx(&pack(Docx::new())).unwrap();
+        assert_eq!(read.web_settings, WebSettings::new());
+    }
+
+    #[test]
+    fn test_read_vba_project() {
+        let buf = pack(
+            Docx::new()
+                .package_type(PackageType::MacroEnabledDocument)
+                .vba_project(VbaProject::new(b"macros".to_vec())),
+        );
+        let content_types = part(&buf, "[Content_Types].xml");
+        assert!(content_types.contains(r#"<Override PartName="/word/document.xml" ContentType="application/vnd.ms-word.document.macroEnabled.main+xml" />"#));
+        assert!(content_types.contains(r#"<Override PartName="/word/vbaProject.bin" ContentType="application/vnd.ms-office.vbaProject" />"#));
+        assert!(part(&buf, "word/_rels/document.xml.rels").contains(r#"<Relationship Id="rIdVbaProject" Type="http://schemas.microsoft.com/office/2006/relationships/vbaProject" Target="vbaProject.bin" />"#));
+
+        let content_types = content_types.replace(
+            "</Types>",
+            r#"<Override PartName="/word/vbaData.xml" ContentType="application/vnd.ms-word.vbaData+xml" /></Types>"#,
+        );
+        let vba_rels = r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.microsoft.com/office/2006/relationships/wordVbaData" Target="vbaData.xml" /></Relationships>"#;
+        let vba_data = r#"<wne:vbaSuppData xmlns:wne="http://schemas.microsoft.com/office/word/2006/wordml" />"#;
+        let buf = replace_parts(
+            &buf,
+            &[
+                ("[Content_Types].xml", Some(&content_types)),
+                ("word/_rels/vbaProject.bin.rels", Some(vba_rels)),
+                ("word/vbaData.xml", Some(vba_data)),
+            ],
+        );
+
+        let read = read_docx(&buf).unwrap();
+        assert_eq!(read.package_type, PackageType::MacroEnabledDocument);
+        let project = read.vba_project.as_ref().unwrap();
+        assert_eq!(
+            project
+                .parts
+                .iter()
+                .map(|p| (p.path.as_str(), p.content_type.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "word/vbaProject.bin",
+                    Some("application/vnd.ms-office.vbaProject")
+                ),
+                ("word/_rels/vbaProject.bin.rels", None),
+                (
+                    "word/vbaData.xml",
+                    Some("application/vnd.ms-word.vbaData+xml")
+                ),
+            ]
+        );
+
+        // Written back untouched.
+        let buf = pack(read.clone());
+        assert_eq!(part(&buf, "word/vbaProject.bin"), "macros");
+        assert_eq!(part(&buf, "word/vbaData.xml"), vba_data);
+        assert_eq!(read_docx(&buf).unwrap().vba_project, read.vba_project);
+
+        // A template keeps its content type and a document can not have macros.
+        let buf = pack(read.clone().package_type(PackageType::Template));
+        assert!(part(&buf, "[Content_Types].xml").contains("wordprocessingml.template.main+xml"));
+        let read = read_docx(&buf).unwrap();
+        assert_eq!(read.package_type, PackageType::Template);
+        assert_eq!(read.vba_project, None);
+        assert_eq!(
+            read_docx(&pack(Docx::new())).unwrap().package_type,
+            PackageType::Document
+        );
+    }
+
+    #[test]
+    fn test_read_strict() {
+        let docx = Docx::new()
+            .add_paragraph(
+                Paragraph::new()
+                    .add_run(Run::new().add_text("Hello"))
+                    .indent(Some(720), None, Some(360), None)
+                    .align(AlignmentType::Right),
+            )
+            .conformance(Conformance::Strict);
+        let buf = pack(docx);
+        assert!(part(&buf, "_rels/.rels").contains("http://purl.oclc.org/ooxml/"));
+        let read = read_docx(&buf).unwrap();
+        assert_eq!(read.conformance, Conformance::Strict);
+        let p = match &read.document.children[0] {
+            DocumentChild::Paragraph(p) => p,
+            _ => unreachable!(),
+        };
+        assert_eq!(p.raw_text(), "Hello");
+        let indent = p.property.indent.as_ref().unwrap();
+        assert_eq!((indent.start, indent.end), (Some(720), Some(360)));
+        assert_eq!(p.property.alignment, Some(Justification::new("end")));
+    }
+
+    #[test]
+    fn test_read_text_only() {
+        use std::sync::{Arc, Mutex};
+
+        let mut img = std::fs::File::open("../images/cat_min.jpg").unwrap();
+        let mut image = Vec::new();
+        img.read_to_end(&mut image).unwrap();
+        let buf = pack(
+            Docx::new()
+                .add_paragraph(
+                    Paragraph::new()
+                        .style("Heading1")
+                        .numbering(NumberingId::new(1), IndentLevel::new(0))
+                        .add_run(Run::new().add_text("Hello").add_image(Pic::new(&image))),
+                )
+                .add_style(Style::new("Heading1", StyleType::Paragraph))
+                .add_abstract_numbering(AbstractNumbering::new(1))
+                .add_numbering(Numbering::new(1, 1))
+                .header(header("Head")),
+        );
+        let parts = Arc::new(Mutex::new(vec![]));
+        let reported = parts.clone();
+        let options = ReadOptions::text_only().on_progress(move |p| {
+            reported.lock().unwrap().push(p.part.clone());
+            true
+        });
+        let (read, _) = read_docx_with_options(&buf, &options).unwrap();
+        let parts = parts.lock().unwrap();
+        assert!(parts.iter().any(|p| p == "word/document.xml"));
+        assert!(!parts.iter().any(|p| p == "word/styles.xml"
+            || p == "word/numbering.xml"
+            || p == "word/header1.xml"
+            || p.starts_with("word/media/")));
+
+        assert_eq!(read.document.children.len(), 1);
+        if let DocumentChild::Paragraph(p) = &read.document.children[0] {
+            assert_eq!(p.raw_text(), "Hello");
+        }
+        assert!(read.styles.find_style_by_id("Heading1").is_none());
+        assert!(read.numberings.numberings.is_empty());
+        assert!(read.images.is_empty());
+        assert!(read.document.section_property.get_headers().is_empty());
+
+        // Each part is skipped on its own.
+        let options = ReadOptions::new().skip_media(true);
+        let read = read_docx_with_options(&buf, &options).unwrap().0;
+        assert!(read.images.is_empty());
+        assert!(read.styles.find_style_by_id("Heading1").is_some());
+        assert!(!read.numberings.numberings.is_empty());
+        assert_eq!(read.document.section_property.get_headers().len(), 1);
+    }
+
+    #[test]
+    fn test_read_custom_xml_parts() {
+        let docx = Docx::new()
+            .add_custom_item(
+                "8B34F5A4-0F6A-4E5B-9C4D-1A2B3C4D5E6F",
+                "<invoice><no>1</no></invoice>",
+            )
+            .add_custom_xml_part(
+                "<customer><name>Alice</name></customer>",
+                CustomItemProperty::new("1C2D3E4F-5A6B-7C8D-9E0F-A1B2C3D4E5F6")
+                    .add_schema_ref("https://example.com/customer"),
+            );
+        let docx = read_docx(&pack(docx)).unwrap();
+        let parts: Vec<_> = docx
+            .custom_xml_parts()
+            .map(|(props, item)| {
+                (
+                    props.id().to_owned(),
+                    props.schema_refs().to_vec(),
+                    item.xml(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                (
+                    "8B34F5A4-0F6A-4E5B-9C4D-1A2B3C4D5E6F".to_owned(),
+                    vec![],
+                    "<invoice>\n<no>\n1</no></invoice>".to_owned()
+                ),
+                (
+                    "1C2D3E4F-5A6B-7C8D-9E0F-A1B2C3D4E5F6".to_owned(),
+                    vec!["https://example.com/customer".to_owned()],
+                    "<customer>\n<name>\nAlice</name></customer>".to_owned()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek};
+
+use zip::ZipArchive;
+
+use super::*;
+
+// A package whose relationships and content types are read when it is opened. The other
+// parts are read when they are first accessed, and the parsed ones are kept.
+//
+// Unlike `read_docx`, parts are not linked to each other: the sections of the document have
+// no headers and footers, and comments and notes are not stored into the paragraphs.
+pub struct DocxPackage<R: Read + Seek> {
+    archive: ZipArchive<R>,
+    content_types: ContentTypes,
+    rels: Rels,
+    document_path: String,
+    document_rels: ReadDocumentRels,
+    document: Option<Document>,
+    styles: Option<Styles>,
+    numberings: Option<Numberings>,
+    headers: HashMap<RId, Header>,
+    footers: HashMap<RId, Footer>,
+}
+
+impl<'a> DocxPackage<Cursor<&'a [u8]>> {
+    pub fn from_buf(buf: &'a [u8]) -> Result<Self, ReaderError> {
+        Self::open(Cursor::new(buf))
+    }
+}
+
+impl<R: Read + Seek> DocxPackage<R> {
+    pub fn open(reader: R) -> Result<Self, ReaderError> {
+        let mut archive = zip::ZipArchive::new(reader)?;
+        let content_types =
+            ContentTypes::from_xml(&read_zip(&mut archive, "[Content_Types].xml")?[..])?;
+        let rels = Rels::from_xml(&read_zip(&mut archive, "_rels/.rels")?[..])?;
+        let document_path = main_document_path(&rels);
+        let document_rels = read_document_rels(&mut archive, &document_path).unwrap_or_default();
+        Ok(DocxPackage {
+            archive,
+            content_types,
+            rels,
+            document_path,
+            document_rels,
+            document: None,
+            styles: None,
+            numberings: None,
+            headers: HashMap::new(),
+            footers: HashMap::new(),
+        })
+    }
+
+    pub fn content_types(&self) -> &ContentTypes {
+        &self.content_types
+    }
+
+    pub fn rels(&self) -> &Rels {
+        &self.rels
+    }
+
+    pub fn document_rels(&self) -> &ReadDocumentRels {
+        &self.document_rels
+    }
+
+    pub fn part_names(&self) -> Vec<String> {
+        self.archive.file_names().map(|n| n.to_owned()).collect()
+    }
+
+    // The data of a part as it is in the package, without parsing it.
+    pub fn read_part(&mut self, name: &str) -> Result<Vec<u8>, ReaderError> {
+        read_zip(&mut self.archive, name)
+    }
+
+    pub fn document(&mut self) -> Result<&Document, ReaderError> {
+        if self.document.is_none() {
+            let data = read_zip(&mut self.archive, &self.document_path)?;
+            let mut ctx = ReadContext::new(&ReadOptions::new());
+            let document = document::read_document(&data[..], &mut ctx, &self.document_path)?;
+            self.document = Some(document);
+        }
+        Ok(self.document.as_ref().expect("should have document"))
+    }
+
+    // Styles of the document, or `None` when it has no styles part.
+    pub fn styles(&mut self) -> Result<Option<&Styles>, ReaderError> {
+        if self.styles.is_none() {
+            if let Some(path) = self.target_path(STYLE_RELATIONSHIP_TYPE, None) {
+                let data = read_zip(&mut self.archive, &path)?;
+                self.styles = Some(Styles::from_xml(&data[..])?);
+            }
+        }
+        Ok(self.styles.as_ref())
+    }
+
+    pub fn numberings(&mut self) -> Result<Option<&Numberings>, ReaderError> {
+        if self.numberings.is_none() {
+            if let Some(path) = self.target_path(NUMBERING_RELATIONSHIP_TYPE, None) {
+                let data = read_zip(&mut self.archive, &path)?;
+                self.numberings = Some(Numberings::from_xml(&data[..])?);
+            }
+        }
+        Ok(self.numberings.as_ref())
+    }
+
+    // The header referred to by `rid` in the section properties.
+    pub fn header(&mut self, rid: &str) -> Result<Option<&Header>, ReaderError> {
+        if !self.headers.contains_key(rid) {
+            if let Some(path) = self.target_path(HEADER_TYPE, Some(rid)) {
+                let data = read_zip(&mut self.archive, &path)?;
+                self.headers
+                    .insert(rid.to_owned(), Header::from_xml(&data[..])?);
+            }
+        }
+        Ok(self.headers.get(rid))
+    }
+
+    pub fn footer(&mut self, rid: &str) -> Result<Option<&Footer>, ReaderError> {
+        if !self.footers.contains_key(rid) {
+            if let Some(path) = self.target_path(FOOTER_TYPE, Some(rid)) {
+                let data = read_zip(&mut self.archive, &path)?;
+                self.footers
+                    .insert(rid.to_owned(), Footer::from_xml(&data[..])?);
+            }
+        }
+        Ok(self.footers.get(rid))
+    }
+
+    // The data of the image referred to by `rid`. Media are not kept, since they may be large.
+    pub fn media(&mut self, rid: &str) -> Result<Option<Vec<u8>>, ReaderError> {
+        match self.target_path(IMAGE_TYPE, Some(rid)) {
+            Some(path) => Ok(Some(read_zip(&mut self.archive, &path)?)),
+            None => Ok(None),
+        }
+    }
+
+    // The path of the first part of the type, or of the one with the relationship id.
+    fn target_path(&self, rel_type: &str, rid: Option<&str>) -> Option<String> {
+        self.document_rels
+            .find_target_path(rel_type)?
+            .into_iter()
+            .find(|(id, ..)| rid.map_or(true, |rid| id == rid))
+            .and_then(|(_, path, _)| path.to_str().map(|p| p.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_docx_package() {
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Body")))
+            .header(
+                Header::new().add_paragraph(Paragraph::new().add_run(Run::new().add_text("Head"))),
+            )
+            .add_style(Style::new("Heading1", StyleType::Paragraph).name("Heading 1"));
+        let mut buf = Cursor::new(vec![]);
+        docx.build().pack(&mut buf).unwrap();
+        let buf = buf.into_inner();
+
+        let mut package = DocxPackage::from_buf(&buf).unwrap();
+        assert!(package
+            .part_names()
+            .contains(&"word/document.xml".to_owned()));
+        assert!(package.styles.is_none());
+        let document = package.document().unwrap();
+        let p = match &document.children[0] {
+            DocumentChild::Paragraph(p) => p.raw_text(),
+            _ => unreachable!(),
+        };
+        assert_eq!(p, "Body");
+        let rid = document
+            .section_property
+            .header_reference
+            .as_ref()
+            .map(|r| r.id.clone());
+        assert!(package
+            .styles()
+            .unwrap()
+            .unwrap()
+            .find_style_by_id("Heading1")
+            .is_some());
+        let header = package.header(&rid.unwrap()).unwrap().unwrap();
+        assert_eq!(header.children.len(), 1);
+        assert!(package.header("rIdMissing").unwrap().is_none());
+        assert!(package.media("rIdMissing").unwrap().is_none());
+    }
+}
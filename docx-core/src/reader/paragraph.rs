@@ -23,6 +23,22 @@ impl ElementReader for Paragraph {
                 Ok(XmlEvent::StartElement {
                     attributes, name, ..
                 }) => {
+                    // m:r shares the local name with w:r, so check the namespace first.
+                    if name.local_name == "oMath"
+                        && name.namespace.as_deref() == Some(MATH_NAMESPACE)
+                    {
+                        let m = OfficeMath::read(r, &attributes)?;
+                        p = p.add_math(m);
+                        continue;
+                    }
+                    if name.local_name == "oMathPara"
+                        && name.namespace.as_deref() == Some(MATH_NAMESPACE)
+                    {
+                        for m in math::read_math_paragraph(r)? {
+                            p = p.add_math(m);
+                        }
+                        continue;
+                    }
                     let e = XMLElement::from_str(&name.local_name).unwrap();
 
                     match e {
@@ -36,6 +52,11 @@ impl ElementReader for Paragraph {
                             p = p.add_hyperlink(link);
                             continue;
                         }
+                        XMLElement::StructuredDataTag => {
+                            let tag = StructuredDataTag::read(r, &attributes)?;
+                            p = p.add_structured_data_tag(tag);
+                            continue;
+                        }
                         XMLElement::Insert => {
                             let ins = Insert::read(r, &attributes)?;
                             p = p.add_insert(ins);
@@ -46,6 +67,16 @@ impl ElementReader for Paragraph {
                             p = p.add_delete(del);
                             continue;
                         }
+                        XMLElement::MoveTo => {
+                            let ins = Insert::read(r, &attributes)?.moved();
+                            p = p.add_insert(ins);
+                            continue;
+                        }
+                        XMLElement::MoveFrom => {
+                            let del = Delete::read(r, &attributes)?.moved();
+                            p = p.add_delete(del);
+                            continue;
+                        }
                         XMLElement::BookmarkStart => {
                             let s = BookmarkStart::read(r, &attributes)?;
                             p = p.add_bookmark_start(s.id, s.name);
@@ -81,12 +112,18 @@ impl ElementReader for Paragraph {
                             }
                             continue;
                         }
+                        _ if unsupported::is_preserved(&name) => {
+                            let xml = unsupported::read_unsupported(r, &name, &attributes)?;
+                            p.children.push(ParagraphChild::Unsupported(xml));
+                            continue;
+                        }
                         _ => {}
                     }
                 }
                 Ok(XmlEvent::EndElement { name, .. }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
                     if e == XMLElement::Paragraph {
+                        p.children = complex_field::read_complex_fields(p.children);
                         return Ok(p);
                     }
                 }
@@ -453,4 +490,86 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_read_move() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:p>
+        <w:moveFrom w:id="1" w:author="bokuweb" w:date="2019-11-15T14:19:04Z">
+            <w:r>
+                <w:delText>Hello</w:delText>
+            </w:r>
+        </w:moveFrom>
+        <w:moveTo w:id="2" w:author="bokuweb" w:date="2019-11-15T14:19:04Z">
+            <w:r>
+                <w:t>Hello</w:t>
+            </w:r>
+        </w:moveTo>
+    </w:p>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let p = Paragraph::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            p.children,
+            vec![
+                ParagraphChild::Delete(
+                    Delete::new()
+                        .add_run(Run::new().add_delete_text("Hello"))
+                        .author("bokuweb")
+                        .date("2019-11-15T14:19:04Z")
+                        .moved()
+                ),
+                ParagraphChild::Insert(
+                    Insert::new(Run::new().add_text("Hello"))
+                        .author("bokuweb")
+                        .date("2019-11-15T14:19:04Z")
+                        .moved()
+                ),
+            ]
+        );
+        let xml = String::from_utf8(p.build()).unwrap();
+        assert!(xml.contains(r#"<w:moveFrom w:id="#));
+        assert!(xml.contains(r#"<w:moveTo w:id="#));
+    }
+
+    #[test]
+    fn test_read_unsupported_elements() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:p>
+        <w:permStart w:id="0" w:edGrp="everyone"/>
+        <w:r>
+            <w:t>a</w:t>
+            <w:cr/>
+        </w:r>
+        <w:smartTag w:element="place">
+            <w:r>
+                <w:t>b</w:t>
+            </w:r>
+        </w:smartTag>
+    </w:p>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let p = Paragraph::read(&mut parser, &[]).unwrap();
+        let permission = r#"<w:permStart xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" w:id="0" w:edGrp="everyone" />"#;
+        assert_eq!(
+            p.children,
+            vec![
+                ParagraphChild::Unsupported(permission.to_owned()),
+                ParagraphChild::Run(Box::new(Run {
+                    children: vec![
+                        RunChild::Text(Text::new("a")),
+                        RunChild::Unsupported(
+                            r#"<w:cr xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" />"#
+                                .to_owned()
+                        ),
+                    ],
+                    ..Run::new()
+                })),
+                ParagraphChild::Run(Box::new(Run::new().add_text("b"))),
+            ]
+        );
+        assert!(std::str::from_utf8(&p.build())
+            .unwrap()
+            .contains(permission));
+    }
 }
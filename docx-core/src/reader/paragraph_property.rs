@@ -103,6 +103,9 @@ impl ElementReader for ParagraphProperty {
                                 p.keep_next = Some(true);
                             }
                         }
+                        XMLElement::Bidi => {
+                            p.bidi = Some(read_bool(&attributes));
+                        }
                         XMLElement::KeepLines => {
                             if read_bool(&attributes) {
                                 p.keep_lines = Some(true);
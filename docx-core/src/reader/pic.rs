@@ -27,6 +27,36 @@ impl ElementReader for Pic {
                                     pic = pic.id(id)
                                 }
                             }
+                            AXMLElement::Xfrm => {
+                                // 60000ths of a degree
+                                if let Some(rot) = read(&attributes, "rot") {
+                                    if let Ok(rot) = f64::from_str(&rot) {
+                                        pic = pic.rotate((rot / 60000.0) as u16);
+                                    }
+                                }
+                                if let Some(v) = read(&attributes, "flipH") {
+                                    if !is_false(&v) {
+                                        pic = pic.flip_h();
+                                    }
+                                }
+                                if let Some(v) = read(&attributes, "flipV") {
+                                    if !is_false(&v) {
+                                        pic = pic.flip_v();
+                                    }
+                                }
+                            }
+                            AXMLElement::SrcRect => {
+                                let inset = |n: &str| {
+                                    read(&attributes, n)
+                                        .and_then(|v| f64::from_str(&v).ok())
+                                        .map(|v| v as i32)
+                                };
+                                let insets = [inset("l"), inset("t"), inset("r"), inset("b")];
+                                if insets.iter().any(|v| v.is_some()) {
+                                    let [l, t, r, b] = insets.map(|v| v.unwrap_or(0));
+                                    pic = pic.crop(l, t, r, b);
+                                }
+                            }
                             AXMLElement::Off => {
                                 let mut offset_x: i32 = 0;
                                 let mut offset_y: i32 = 0;
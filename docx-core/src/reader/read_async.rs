@@ -0,0 +1,83 @@
+use std::io::Cursor;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+use super::*;
+
+// Read from an async source, such as an upload. The central directory of the archive is at its
+// end, so the package is read into memory from its start and then parsed.
+pub async fn read_docx_async<R>(reader: R) -> Result<Docx, ReaderError>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    read_docx_async_with_options(reader, &ReadOptions::new())
+        .await
+        .map(|(docx, _)| docx)
+}
+
+pub async fn read_docx_async_with_options<R>(
+    mut reader: R,
+    options: &ReadOptions,
+) -> Result<(Docx, Vec<ReadWarning>), ReaderError>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let mut buf = vec![];
+    reader.rewind().await.map_err(zip::result::ZipError::from)?;
+    reader
+        .read_to_end(&mut buf)
+        .await
+        .map_err(zip::result::ZipError::from)?;
+    read_docx_from_reader_with_options(Cursor::new(buf), options)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    fn block_on<F: std::future::Future>(f: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(f)
+    }
+
+    #[test]
+    fn test_read_docx_async() {
+        let mut buf = Cursor::new(vec![]);
+        Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+            .build()
+            .pack(&mut buf)
+            .unwrap();
+        // Read from the start even when the source has been read from.
+        let docx = block_on(read_docx_async(&mut buf)).unwrap();
+        assert_eq!(docx.document.children.len(), 1);
+
+        let docx = block_on(read_docx_async(Cursor::new(b"not a package".to_vec())));
+        assert!(docx.is_err());
+    }
+
+    #[test]
+    fn test_pack_and_read_async() {
+        let docx = block_on(async {
+            let mut buf = vec![];
+            Docx::new()
+                .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+                .pack_async(&mut buf)
+                .await
+                .unwrap();
+            read_docx_async(Cursor::new(buf)).await
+        })
+        .unwrap();
+        assert_eq!(
+            docx.document.children,
+            vec![DocumentChild::Paragraph(Box::new(
+                Paragraph::new().add_run(Run::new().add_text("Hello"))
+            ))]
+        );
+    }
+}
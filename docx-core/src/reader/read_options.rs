@@ -0,0 +1,363 @@
+use std::fmt;
+use std::io::{Read, Seek};
+use std::sync::Arc;
+
+use serde::Serialize;
+use zip::ZipArchive;
+
+use super::{read_zip, ReaderError};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReadOptions {
+    // Skip parts and elements which fail to be read, reporting them as warnings.
+    pub lenient: bool,
+    pub progress: Option<ProgressCallback>,
+    // Skip the bytes of images and embedded fonts. Drawings keep their relationship ids.
+    pub skip_media: bool,
+    pub skip_styles: bool,
+    pub skip_numberings: bool,
+    // Skip headers and footers. Sections keep their references.
+    pub skip_headers_footers: bool,
+}
+
+impl ReadOptions {
+    pub fn new() -> ReadOptions {
+        Default::default()
+    }
+
+    // Read only what is needed to extract the text of the body, notes and comments.
+    pub fn text_only() -> ReadOptions {
+        ReadOptions::new()
+            .skip_media(true)
+            .skip_styles(true)
+            .skip_numberings(true)
+            .skip_headers_footers(true)
+    }
+
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    pub fn skip_media(mut self, skip: bool) -> Self {
+        self.skip_media = skip;
+        self
+    }
+
+    pub fn skip_styles(mut self, skip: bool) -> Self {
+        self.skip_styles = skip;
+        self
+    }
+
+    pub fn skip_numberings(mut self, skip: bool) -> Self {
+        self.skip_numberings = skip;
+        self
+    }
+
+    pub fn skip_headers_footers(mut self, skip: bool) -> Self {
+        self.skip_headers_footers = skip;
+        self
+    }
+
+    // Called after each part is read out of the package. Returning `false` cancels reading,
+    // which then fails with `ReaderError::Cancelled`.
+    pub fn on_progress(
+        mut self,
+        callback: impl Fn(&ReadProgress) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(ProgressCallback(Arc::new(callback)));
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct ProgressCallback(Arc<dyn Fn(&ReadProgress) -> bool + Send + Sync>);
+
+impl fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressCallback")
+    }
+}
+
+impl PartialEq for ProgressCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+// `bytes` is the uncompressed size of the part, and `total_bytes` that of all the parts in the
+// package, so `bytes_read` may not reach it when some parts are not used.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadProgress {
+    pub part: String,
+    pub bytes: usize,
+    pub bytes_read: usize,
+    pub total_bytes: usize,
+}
+
+// Something which was skipped or repaired while reading in lenient mode. `element` is unset
+// when the whole part was skipped or repaired.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadWarning {
+    pub part: String,
+    pub element: Option<String>,
+    pub message: String,
+}
+
+pub(crate) struct ReadContext {
+    pub lenient: bool,
+    pub skip_media: bool,
+    pub skip_styles: bool,
+    pub skip_numberings: bool,
+    pub skip_headers_footers: bool,
+    pub warnings: Vec<ReadWarning>,
+    progress: Option<ProgressCallback>,
+    bytes_read: usize,
+    pub total_bytes: usize,
+    pub cancelled: bool,
+    // Given by `w:conformance` of the document.
+    pub conformance: crate::types::Conformance,
+}
+
+impl ReadContext {
+    pub(crate) fn new(options: &ReadOptions) -> ReadContext {
+        ReadContext {
+            lenient: options.lenient,
+            skip_media: options.skip_media,
+            skip_styles: options.skip_styles,
+            skip_numberings: options.skip_numberings,
+            skip_headers_footers: options.skip_headers_footers,
+            warnings: vec![],
+            progress: options.progress.clone(),
+            bytes_read: 0,
+            total_bytes: 0,
+            cancelled: false,
+            conformance: crate::types::Conformance::Transitional,
+        }
+    }
+
+    // Read a part and report the progress. Once cancelled, no more parts are read, so that
+    // the parts whose errors are ignored do not keep reading either.
+    pub(crate) fn read_zip<R: Read + Seek>(
+        &mut self,
+        archive: &mut ZipArchive<R>,
+        part: &str,
+    ) -> Result<Vec<u8>, ReaderError> {
+        if self.cancelled {
+            return Err(ReaderError::Cancelled);
+        }
+        let data = read_zip(archive, part)?;
+        if let Some(callback) = &self.progress {
+            self.bytes_read += data.len();
+            let progress = ReadProgress {
+                part: part.to_owned(),
+                bytes: data.len(),
+                bytes_read: self.bytes_read,
+                total_bytes: self.total_bytes,
+            };
+            if !(callback.0)(&progress) {
+                self.cancelled = true;
+                return Err(ReaderError::Cancelled);
+            }
+        }
+        Ok(data)
+    }
+
+    pub(crate) fn warn(&mut self, part: &str, element: Option<&str>, message: impl Into<String>) {
+        self.warnings.push(ReadWarning {
+            part: part.to_owned(),
+            element: element.map(|e| e.to_owned()),
+            message: message.into(),
+        });
+    }
+
+    // Errors are returned as is in strict mode, and recorded as warnings in lenient mode.
+    pub(crate) fn recover<T>(
+        &mut self,
+        part: &str,
+        element: Option<&str>,
+        result: Result<T, ReaderError>,
+    ) -> Result<Option<T>, ReaderError> {
+        match result {
+            Ok(v) => Ok(Some(v)),
+            Err(ReaderError::Cancelled) => Err(ReaderError::Cancelled),
+            Err(e) if self.lenient => {
+                self.warn(part, element, e.to_string());
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    // Parse a part. In lenient mode, the undeclared namespace prefixes are declared first.
+    pub(crate) fn parse<T>(
+        &mut self,
+        part: &str,
+        data: &[u8],
+        parse: impl Fn(&[u8], &mut ReadContext) -> Result<T, ReaderError>,
+    ) -> Result<Option<T>, ReaderError> {
+        if self.lenient {
+            if let Some(data) = declare_missing_namespaces(data) {
+                self.warn(part, None, "Declared missing namespaces.");
+                let result = parse(&data, self);
+                return self.recover(part, None, result);
+            }
+        }
+        let result = parse(data, self);
+        self.recover(part, None, result)
+    }
+}
+
+const KNOWN_NAMESPACES: &[(&str, &str)] = &[
+    (
+        "w",
+        "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+    ),
+    (
+        "r",
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+    ),
+    (
+        "wp",
+        "http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing",
+    ),
+    ("a", "http://schemas.openxmlformats.org/drawingml/2006/main"),
+    (
+        "pic",
+        "http://schemas.openxmlformats.org/drawingml/2006/picture",
+    ),
+    (
+        "m",
+        "http://schemas.openxmlformats.org/officeDocument/2006/math",
+    ),
+    (
+        "mc",
+        "http://schemas.openxmlformats.org/markup-compatibility/2006",
+    ),
+    ("v", "urn:schemas-microsoft-com:vml"),
+    ("o", "urn:schemas-microsoft-com:office:office"),
+    ("w10", "urn:schemas-microsoft-com:office:word"),
+    (
+        "w14",
+        "http://schemas.microsoft.com/office/word/2010/wordml",
+    ),
+    (
+        "w15",
+        "http://schemas.microsoft.com/office/word/2012/wordml",
+    ),
+    (
+        "wps",
+        "http://schemas.microsoft.com/office/word/2010/wordprocessingShape",
+    ),
+    (
+        "wpg",
+        "http://schemas.microsoft.com/office/word/2010/wordprocessingGroup",
+    ),
+    (
+        "wp14",
+        "http://schemas.microsoft.com/office/word/2010/wordprocessingDrawing",
+    ),
+];
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-' || c == '.'
+}
+
+// Prefixes of element and attribute names, such as `w` of `<w:p` and `w14:paraId=`.
+fn used_prefixes(xml: &str) -> Vec<String> {
+    let mut prefixes: Vec<String> = vec![];
+    let mut in_tag = false;
+    let mut quote: Option<char> = None;
+    let mut name_start = false;
+    let mut name = String::new();
+    for c in xml.chars() {
+        if let Some(q) = quote {
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        if !in_tag {
+            if c == '<' {
+                in_tag = true;
+                name_start = true;
+            }
+            continue;
+        }
+        if name_start && is_name_char(c) {
+            name.push(c);
+            continue;
+        }
+        if c == ':' && !name.is_empty() {
+            if name != "xmlns" && name != "xml" && !prefixes.contains(&name) {
+                prefixes.push(name.clone());
+            }
+            name_start = false;
+        }
+        name.clear();
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '>' => in_tag = false,
+            _ => name_start = c.is_whitespace() || c == '/',
+        }
+    }
+    prefixes
+}
+
+// Add the declarations of prefixes which are used but not declared to the root element.
+// Unknown prefixes are bound to placeholder URIs. Returns `None` when nothing is missing.
+pub(crate) fn declare_missing_namespaces(data: &[u8]) -> Option<Vec<u8>> {
+    let xml = std::str::from_utf8(data).ok()?;
+    let missing: Vec<String> = used_prefixes(xml)
+        .into_iter()
+        .filter(|p| !xml.contains(&format!("xmlns:{}=", p)))
+        .collect();
+    if missing.is_empty() {
+        return None;
+    }
+    // The root is the first element which is not a declaration, comment or DOCTYPE.
+    let mut root = None;
+    for (i, _) in xml.match_indices('<') {
+        if xml[i + 1..].starts_with(|c: char| c.is_alphabetic() || c == '_') {
+            root = Some(i);
+            break;
+        }
+    }
+    let root = root?;
+    let name_end = root + 1 + xml[root + 1..].find(|c: char| !is_name_char(c) && c != ':')?;
+    let mut declarations = String::new();
+    for p in missing {
+        let uri = KNOWN_NAMESPACES
+            .iter()
+            .find(|(k, _)| *k == p)
+            .map(|(_, v)| v.to_string())
+            .unwrap_or_else(|| format!("urn:docx-rs:undeclared:{}", p));
+        declarations.push_str(&format!(" xmlns:{}=\"{}\"", p, uri));
+    }
+    let mut fixed = xml.to_owned();
+    fixed.insert_str(name_end, &declarations);
+    Some(fixed.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_declare_missing_namespaces() {
+        let xml = r#"<?xml version="1.0"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:p w14:paraId="1"><x:y /></w:p></w:document>"#;
+        let fixed = declare_missing_namespaces(xml.as_bytes()).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&fixed).unwrap(),
+            r#"<?xml version="1.0"?>
+<w:document xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" xmlns:x="urn:docx-rs:undeclared:x" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:p w14:paraId="1"><x:y /></w:p></w:document>"#
+        );
+        assert_eq!(declare_missing_namespaces(&fixed), None);
+    }
+}
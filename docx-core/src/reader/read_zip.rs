@@ -1,9 +1,9 @@
-use std::io::{Cursor, Read};
+use std::io::{Read, Seek};
 
 use super::ReaderError;
 
-pub fn read_zip(
-    archive: &mut zip::read::ZipArchive<Cursor<&[u8]>>,
+pub fn read_zip<R: Read + Seek>(
+    archive: &mut zip::read::ZipArchive<R>,
     name: &str,
 ) -> Result<Vec<u8>, ReaderError> {
     let p = name.to_owned();
@@ -16,10 +16,8 @@ pub fn read_zip(
     let mut data = vec![];
     xml.read_to_end(&mut data).unwrap();
     // Remove BOM
-    if (data[0] == 0xef) && (data[1] == 0xbb) && (data[2] == 0xbf) {
-        data.remove(0);
-        data.remove(0);
-        data.remove(0);
+    if data.starts_with(&[0xef, 0xbb, 0xbf]) {
+        data.drain(..3);
     }
     Ok(data)
 }
@@ -49,13 +49,12 @@ pub fn find_rels_filename(main_path: impl AsRef<Path>) -> Result<PathBuf, Reader
     let dir = path
         .parent()
         .ok_or(ReaderError::DocumentRelsNotFoundError)?;
-    let base = path
-        .file_stem()
+    // The part name with `.rels` appended, such as `vbaProject.bin.rels`.
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
         .ok_or(ReaderError::DocumentRelsNotFoundError)?;
-    Ok(Path::new(dir)
-        .join("_rels")
-        .join(base)
-        .with_extension("xml.rels"))
+    Ok(Path::new(dir).join("_rels").join(format!("{}.rels", name)))
 }
 
 pub fn read_rels_xml<R: Read>(reader: R, dir: impl AsRef<Path>) -> Result<ReadRels, ReaderError> {
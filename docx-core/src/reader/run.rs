@@ -71,6 +71,12 @@ impl ElementReader for Run {
                                 XMLElement::Tab => {
                                     run = run.add_tab();
                                 }
+                                XMLElement::Pict => {
+                                    let xml = unsupported::read_unsupported(r, &name, &attributes)?;
+                                    if let Ok(shape) = shape::read_pict(xml) {
+                                        run.children.push(RunChild::Shape(Box::new(shape)));
+                                    }
+                                }
                                 XMLElement::Sym => {
                                     if let Some(font) = read(&attributes, "font") {
                                         if let Some(char) = read(&attributes, "char") {
@@ -97,11 +103,29 @@ impl ElementReader for Run {
                                         run = run.add_drawing(drawing);
                                     }
                                 }
+                                XMLElement::FootnoteReference => {
+                                    if let Some(id) = read(&attributes, "id") {
+                                        let f = FootnoteReference::new(usize::from_str(&id)?);
+                                        run.children.push(RunChild::FootnoteReference(f));
+                                    }
+                                }
+                                XMLElement::EndnoteReference => {
+                                    if let Some(id) = read(&attributes, "id") {
+                                        let e = EndnoteReference::new(usize::from_str(&id)?);
+                                        run.children.push(RunChild::EndnoteReference(e));
+                                    }
+                                }
                                 XMLElement::FieldChar => {
                                     if let Ok(f) = read_field_char(&attributes) {
                                         run.children.push(RunChild::FieldChar(f));
                                     }
                                 }
+                                XMLElement::FormFieldData => {
+                                    let data = FormFieldData::read(r, &attributes)?;
+                                    if let Some(RunChild::FieldChar(f)) = run.children.last_mut() {
+                                        f.form_field = Some(data);
+                                    }
+                                }
                                 XMLElement::InstrText => loop {
                                     let e = r.next();
                                     match e {
@@ -122,7 +146,13 @@ impl ElementReader for Run {
                                         _ => {}
                                     }
                                 },
-                                _ => {}
+                                _ => {
+                                    if unsupported::is_preserved_in_run(&name) {
+                                        let xml =
+                                            unsupported::read_unsupported(r, &name, &attributes)?;
+                                        run.children.push(RunChild::Unsupported(xml));
+                                    }
+                                }
                             }
                         }
                         Some("mc") => {
@@ -136,14 +166,14 @@ impl ElementReader for Run {
                         }
                         Some("v") => {
                             let e = VXMLElement::from_str(&name.local_name).unwrap();
-                            match e {
-                                // Experimental For now support only imageData in shape
-                                VXMLElement::Shape => {
-                                    if let Ok(shape) = Shape::read(r, &attributes) {
-                                        run.children.push(RunChild::Shape(Box::new(shape)));
-                                    }
+                            // Shapes out of `w:pict`, such as the preview of an embedded object.
+                            if e.is_shape() {
+                                let xml = unsupported::read_unsupported(r, &name, &attributes)?;
+                                if let Ok(shape) =
+                                    shape::read_pict(format!("<w:pict>{}</w:pict>", xml))
+                                {
+                                    run.children.push(RunChild::Shape(Box::new(shape)));
                                 }
-                                _ => {}
                             }
                         }
                         _ => {}
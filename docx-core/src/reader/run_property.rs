@@ -46,6 +46,20 @@ fn read_run_fonts(attributes: &[OwnedAttribute]) -> Result<RunFonts, ReaderError
     Ok(f)
 }
 
+fn read_color(attributes: &[OwnedAttribute]) -> Color {
+    let mut c = Color::new(read_val(attributes).unwrap_or_else(|| "auto".to_owned()));
+    if let Some(v) = read(attributes, "themeColor") {
+        c = c.theme_color(v);
+    }
+    if let Some(v) = read(attributes, "themeTint") {
+        c = c.theme_tint(v);
+    }
+    if let Some(v) = read(attributes, "themeShade") {
+        c = c.theme_shade(v);
+    }
+    c
+}
+
 impl ElementReader for RunProperty {
     fn read<R: Read>(
         r: &mut EventReader<R>,
@@ -92,7 +106,7 @@ impl ElementReader for RunProperty {
                                 rp = rp.vert_align(v)
                             }
                         }
-                        XMLElement::Color => rp = rp.color(attributes[0].value.clone()),
+                        XMLElement::Color => rp.color = Some(read_color(&attributes)),
                         XMLElement::Size => {
                             rp = rp.size(f64::from_str(&attributes[0].value)? as usize)
                         }
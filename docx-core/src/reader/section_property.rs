@@ -4,6 +4,7 @@ use std::str::FromStr;
 use xml::attribute::OwnedAttribute;
 use xml::reader::{EventReader, XmlEvent};
 
+use crate::types::PageOrientationType;
 use crate::SectionType;
 
 use super::*;
@@ -19,6 +20,11 @@ fn read_page_size(attributes: &[OwnedAttribute]) -> Result<PageSize, ReaderError
             "h" => {
                 size = size.height(value_to_dax(&a.value)? as u32);
             }
+            "orient" => {
+                if let Ok(o) = PageOrientationType::from_str(&a.value) {
+                    size = size.orient(o);
+                }
+            }
             _ => {}
         }
     }
@@ -59,6 +65,33 @@ fn read_page_margin(
     Ok(margin)
 }
 
+fn read_columns(
+    sp: &mut SectionProperty,
+    attributes: &[OwnedAttribute],
+) -> Result<(), ReaderError> {
+    if let Some(num) = read(attributes, "num") {
+        sp.columns = usize::from_str(&num)?;
+    }
+    if let Some(space) = read(attributes, "space") {
+        sp.space = value_to_dax(&space)? as usize;
+    }
+    if let Some(sep) = read(attributes, "sep") {
+        sp.column_separator = !is_false(&sep);
+    }
+    Ok(())
+}
+
+fn read_column(attributes: &[OwnedAttribute]) -> Result<Column, ReaderError> {
+    let mut c = Column::new(0);
+    if let Some(w) = read(attributes, "w") {
+        c.width = value_to_dax(&w)? as usize;
+    }
+    if let Some(space) = read(attributes, "space") {
+        c.space = value_to_dax(&space)? as usize;
+    }
+    Ok(c)
+}
+
 fn read_header_or_footer_reference(
     attributes: &[OwnedAttribute],
 ) -> Result<(String, String), ReaderError> {
@@ -160,7 +193,21 @@ impl ElementReader for SectionProperty {
                                 }
                             }
                         }
-                        XMLElement::TitlePg => sp = sp.title_pg(),
+                        XMLElement::Columns => read_columns(&mut sp, &attributes)?,
+                        XMLElement::Column => {
+                            let c = read_column(&attributes)?;
+                            sp.column_definitions.push(c);
+                        }
+                        XMLElement::TextDirection => {
+                            if let Some(val) = read_val(&attributes) {
+                                sp = sp.text_direction(val);
+                            }
+                        }
+                        XMLElement::TitlePg => {
+                            if read_bool(&attributes) {
+                                sp = sp.title_pg();
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -176,3 +223,49 @@ impl ElementReader for SectionProperty {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_section_property() {
+        let c = r#"<w:sectPr xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+  <w:headerReference w:type="first" r:id="rId7" />
+  <w:pgSz w:w="16838" w:h="11906" w:orient="landscape" />
+  <w:pgMar w:top="720" w:right="720" w:bottom="720" w:left="720" w:header="360" w:footer="360" w:gutter="0" />
+  <w:cols w:num="2" w:space="708" w:sep="1" w:equalWidth="0"><w:col w:w="4000" w:space="708" /><w:col w:w="6000" /></w:cols>
+  <w:textDirection w:val="tbRl" />
+  <w:titlePg w:val="0" />
+</w:sectPr>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        if let Ok(XmlEvent::StartElement { .. }) = parser.next() {}
+        let sp = SectionProperty::read(&mut parser, &[]).unwrap();
+        let mut expected = SectionProperty::new()
+            .page_size(
+                PageSize::new()
+                    .size(16838, 11906)
+                    .orient(PageOrientationType::Landscape),
+            )
+            .page_margin(
+                crate::types::PageMargin::new()
+                    .top(720)
+                    .right(720)
+                    .bottom(720)
+                    .left(720)
+                    .header(360)
+                    .footer(360)
+                    .gutter(0),
+            )
+            .columns(2, 708)
+            .column_separator(true)
+            .add_column(Column::new(4000).space(708))
+            .add_column(Column::new(6000))
+            .text_direction("tbRl".to_owned());
+        expected.first_header_reference = Some(HeaderReference::new("first", "rId7"));
+        assert_eq!(sp, expected);
+    }
+}
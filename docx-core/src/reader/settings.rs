@@ -4,12 +4,39 @@ use xml::reader::{EventReader, XmlEvent};
 
 use super::*;
 use crate::reader::{FromXML, ReaderError};
-use crate::types::CharacterSpacingValues;
+use crate::types::{CharacterSpacingValues, DocumentProtectionType};
+use xml::attribute::OwnedAttribute;
+
+// Legacy hashes name the algorithm by a Windows algorithm id.
+fn read_protection_hash(attrs: &[OwnedAttribute]) -> ProtectionHash {
+    let mut hash = ProtectionHash::new();
+    let legacy_name = read(attrs, "cryptAlgorithmSid").and_then(|sid| {
+        let name = match sid.as_str() {
+            "1" => "MD2",
+            "2" => "MD4",
+            "3" => "MD5",
+            "4" => "SHA-1",
+            "12" => "SHA-256",
+            "13" => "SHA-384",
+            "14" => "SHA-512",
+            _ => return None,
+        };
+        Some(name.to_owned())
+    });
+    hash.algorithm_name = read(attrs, "algorithmName").or(legacy_name);
+    hash.hash_value = read(attrs, "hashValue").or_else(|| read(attrs, "hash"));
+    hash.salt_value = read(attrs, "saltValue").or_else(|| read(attrs, "salt"));
+    hash.spin_count = read(attrs, "spinCount")
+        .or_else(|| read(attrs, "cryptSpinCount"))
+        .and_then(|v| u32::from_str(&v).ok());
+    hash
+}
 
 impl FromXML for Settings {
     fn from_xml<R: Read>(reader: R) -> Result<Self, ReaderError> {
         let mut parser = EventReader::new(reader);
         let mut settings = Self::default();
+        let mut in_compat = false;
 
         loop {
             let e = parser.next();
@@ -66,13 +93,79 @@ impl FromXML for Settings {
                                 }
                             }
                         }
+                        XMLElement::Zoom => {
+                            if let Some(percent) = read(&attributes, "percent") {
+                                if let Ok(percent) = f32::from_str(&percent) {
+                                    settings = settings.zoom(percent as usize);
+                                }
+                            }
+                        }
+                        XMLElement::TrackRevisions => {
+                            if read_bool(&attributes) {
+                                settings = settings.track_revisions();
+                            }
+                        }
+                        XMLElement::EmbedTrueTypeFonts => {
+                            if read_bool(&attributes) {
+                                settings = settings.embed_true_type_fonts();
+                            }
+                        }
+                        XMLElement::DocumentProtection => {
+                            let edit = read(&attributes, "edit")
+                                .and_then(|v| DocumentProtectionType::from_str(&v).ok())
+                                .unwrap_or(DocumentProtectionType::None);
+                            let enforcement = read(&attributes, "enforcement")
+                                .map(|v| !is_false(&v))
+                                .unwrap_or(false);
+                            settings = settings.document_protection(
+                                DocumentProtection::new(edit)
+                                    .enforcement(enforcement)
+                                    .hash(read_protection_hash(&attributes)),
+                            );
+                        }
+                        XMLElement::WriteProtection => {
+                            let mut p =
+                                WriteProtection::new().hash(read_protection_hash(&attributes));
+                            p.recommended = read(&attributes, "recommended")
+                                .map(|v| !is_false(&v))
+                                .unwrap_or(false);
+                            settings = settings.write_protection(p);
+                        }
+                        XMLElement::Compat => {
+                            settings = settings.clear_compat();
+                            in_compat = true;
+                        }
+                        XMLElement::CompatSetting => {
+                            if let (Some(name), Some(uri), Some(val)) = (
+                                read(&attributes, "name"),
+                                read(&attributes, "uri"),
+                                read(&attributes, "val"),
+                            ) {
+                                settings = settings.compat_setting(name, uri, val);
+                            }
+                        }
+                        XMLElement::RsidRoot => {
+                            if let Some(val) = read_val(&attributes) {
+                                settings = settings.rsid_root(val);
+                            }
+                        }
+                        XMLElement::Rsid => {
+                            if let Some(val) = read_val(&attributes) {
+                                settings = settings.add_rsid(val);
+                            }
+                        }
+                        _ if in_compat => {
+                            settings = settings.add_compat_option(name.local_name);
+                        }
                         _ => {}
                     }
                 }
                 Ok(XmlEvent::EndElement { name, .. }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
-                    if let XMLElement::Settings = e {
-                        break;
+                    match e {
+                        XMLElement::Settings => break,
+                        XMLElement::Compat => in_compat = false,
+                        _ => {}
                     }
                 }
                 Ok(XmlEvent::EndDocument { .. }) => break,
@@ -83,3 +176,74 @@ impl FromXML for Settings {
         Ok(settings)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_settings_from_xml() {
+        let xml = r#"<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:zoom w:percent="120"/>
+    <w:trackRevisions/>
+    <w:defaultTabStop w:val="720"/>
+    <w:characterSpacingControl w:val="doNotCompress"/>
+    <w:compat>
+        <w:useFELayout/>
+        <w:compatSetting w:name="compatibilityMode" w:uri="http://schemas.microsoft.com/office/word" w:val="14"/>
+    </w:compat>
+    <w:rsids>
+        <w:rsidRoot w:val="00A1B2C3"/>
+        <w:rsid w:val="00A1B2C3"/>
+        <w:rsid w:val="00D4E5F6"/>
+    </w:rsids>
+</w:settings>"#;
+        let s = Settings::from_xml(xml.as_bytes()).unwrap();
+        let expected = Settings::new()
+            .zoom(120)
+            .track_revisions()
+            .default_tab_stop(720)
+            .character_spacing_control(CharacterSpacingValues::DoNotCompress)
+            .clear_compat()
+            .add_compat_option("useFELayout")
+            .compat_setting(
+                "compatibilityMode",
+                "http://schemas.microsoft.com/office/word",
+                "14",
+            )
+            .rsid_root("00A1B2C3")
+            .add_rsid("00A1B2C3")
+            .add_rsid("00D4E5F6");
+        assert_eq!(s, expected);
+        // Written back unchanged.
+        assert_eq!(Settings::from_xml(&s.build()[..]).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_read_document_protection() {
+        let xml = r#"<w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:writeProtection w:recommended="true"/>
+    <w:documentProtection w:edit="comments" w:enforcement="1" w:cryptProviderType="rsaFull" w:cryptAlgorithmClass="hash" w:cryptAlgorithmType="typeAny" w:cryptAlgorithmSid="4" w:cryptSpinCount="100000" w:hash="aGFzaA==" w:salt="c2FsdA=="/>
+</w:settings>"#;
+        let s = Settings::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            s.get_document_protection(),
+            Some(
+                &DocumentProtection::new(DocumentProtectionType::Comments).hash(
+                    ProtectionHash::new()
+                        .algorithm_name("SHA-1")
+                        .hash_value("aGFzaA==")
+                        .salt_value("c2FsdA==")
+                        .spin_count(100000)
+                )
+            )
+        );
+        assert_eq!(
+            s.get_write_protection(),
+            Some(&WriteProtection::new().recommended())
+        );
+    }
+}
@@ -23,6 +23,14 @@ impl ElementReader for Shading {
                 shd = shd.color(&a.value);
             } else if local_name == "fill" {
                 shd = shd.fill(&a.value);
+            } else if local_name == "themeColor" {
+                shd = shd.theme_color(&a.value);
+            } else if local_name == "themeFill" {
+                shd = shd.theme_fill(&a.value);
+            } else if local_name == "themeFillTint" {
+                shd = shd.theme_fill_tint(&a.value);
+            } else if local_name == "themeFillShade" {
+                shd = shd.theme_fill_shade(&a.value);
             }
         }
         Ok(shd)
@@ -17,24 +17,39 @@ impl ElementReader for Shape {
         if let Some(style) = read(attrs, "style") {
             shape = shape.style(style);
         }
-
+        let mut depth = 0;
         loop {
             let e = r.next();
             match e {
                 Ok(XmlEvent::StartElement {
                     name, attributes, ..
                 }) => {
-                    if let Ok(VXMLElement::ImageData) = VXMLElement::from_str(&name.local_name) {
-                        if let Some(id) = read(&attributes, "id") {
-                            shape = shape.image_data(id);
+                    if name.prefix.as_deref() == Some("v") {
+                        let e = VXMLElement::from_str(&name.local_name).unwrap();
+                        // Grouped shapes are read with their own end elements.
+                        if e.is_shape() {
+                            shape = shape.add_child(Shape::read(r, &attributes)?);
+                            continue;
+                        }
+                        if e == VXMLElement::ImageData {
+                            if let Some(id) = read(&attributes, "id") {
+                                shape = shape.image_data(id);
+                            }
                         }
                     }
+                    // v:textbox wraps w:txbxContent.
+                    if let Ok(XMLElement::TxbxContent) = XMLElement::from_str(&name.local_name) {
+                        let content = TextBoxContent::read(r, &attributes)?;
+                        shape = shape.text_box(content);
+                        continue;
+                    }
+                    depth += 1;
                 }
-                Ok(XmlEvent::EndElement { name, .. }) => {
-                    let e = VXMLElement::from_str(&name.local_name).unwrap();
-                    if e == VXMLElement::Shape {
+                Ok(XmlEvent::EndElement { .. }) => {
+                    if depth == 0 {
                         return Ok(shape);
                     }
+                    depth -= 1;
                 }
                 Err(_) => return Err(ReaderError::XMLReadError),
                 _ => {}
@@ -42,3 +57,86 @@ impl ElementReader for Shape {
         }
     }
 }
+
+// Read the first shape of a `w:pict`, keeping the XML to write it back. Shape types and
+// pictures without shapes are kept as empty shapes.
+pub(crate) fn read_pict(xml: String) -> Result<Shape, ReaderError> {
+    let mut r = EventReader::new(xml.as_bytes());
+    let mut shape = None;
+    loop {
+        match r.next() {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                if name.prefix.as_deref() == Some("v")
+                    && VXMLElement::from_str(&name.local_name).unwrap().is_shape()
+                {
+                    shape = Some(Shape::read(&mut r, &attributes)?);
+                    break;
+                }
+            }
+            Ok(XmlEvent::EndDocument) => break,
+            Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+    let mut shape = shape.unwrap_or_default();
+    shape.raw = Some(xml);
+    Ok(shape)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_shape_text_box() {
+        let c = r#"<v:shape xmlns:v="urn:schemas-microsoft-com:vml" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" style="width:100pt">
+    <v:textbox>
+        <w:txbxContent>
+            <w:p><w:r><w:t>Legacy</w:t></w:r></w:p>
+        </w:txbxContent>
+    </v:textbox>
+</v:shape>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        // Skip the v:shape start element.
+        let _ = parser.next();
+        let _ = parser.next();
+        let shape = Shape::read(&mut parser, &[]).unwrap();
+        let content = shape.text_box.unwrap();
+        assert_eq!(content.children.len(), 1);
+        match &content.children[0] {
+            TextBoxContentChild::Paragraph(p) => assert_eq!(p.raw_text(), "Legacy"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_read_pict() {
+        let c = r#"<w:pict xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:v="urn:schemas-microsoft-com:vml" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+    <v:shapetype id="_x0000_t202" coordsize="21600,21600"><v:path gradientshapeok="t" /></v:shapetype>
+    <v:group style="width:200pt">
+        <v:rect style="width:100pt"><v:imagedata r:id="rId5" /></v:rect>
+        <v:roundrect><v:textbox><w:txbxContent><w:p><w:r><w:t>First</w:t></w:r></w:p></w:txbxContent></v:textbox></v:roundrect>
+        <v:oval><v:textbox><w:txbxContent><w:p><w:r><w:t>Second</w:t></w:r></w:p></w:txbxContent></v:textbox></v:oval>
+    </v:group>
+</w:pict>"#;
+        let shape = read_pict(c.to_owned()).unwrap();
+        assert_eq!(shape.style, Some("width:200pt".to_owned()));
+        assert_eq!(shape.children.len(), 3);
+        assert_eq!(shape.image_ids(), vec!["rId5"]);
+        let texts: Vec<String> = shape
+            .text_boxes()
+            .iter()
+            .map(|t| match &t.children[0] {
+                TextBoxContentChild::Paragraph(p) => p.raw_text(),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(texts, vec!["First", "Second"]);
+        assert_eq!(shape.build(), c.as_bytes());
+    }
+}
@@ -23,6 +23,12 @@ impl ElementReader for StructuredDataTag {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
 
                     match e {
+                        XMLElement::StructuredDataTagProperty => {
+                            if let Ok(p) = StructuredDataTagProperty::read(r, &attributes) {
+                                sdt.property = p;
+                            }
+                            continue;
+                        }
                         XMLElement::Paragraph => {
                             if let Ok(p) = Paragraph::read(r, &attributes) {
                                 sdt.children
@@ -0,0 +1,205 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+use crate::types::*;
+
+impl ElementReader for StructuredDataTagProperty {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        _attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let mut property = StructuredDataTagProperty::new();
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    match e {
+                        XMLElement::RunProperty => {
+                            if let Ok(run_pr) = RunProperty::read(r, &attributes) {
+                                property.run_property = run_pr;
+                            }
+                        }
+                        XMLElement::Alias => {
+                            if let Some(val) = read(&attributes, "val") {
+                                property = property.alias(val);
+                            }
+                        }
+                        XMLElement::Tag => {
+                            if let Some(val) = read(&attributes, "val") {
+                                property = property.tag(val);
+                            }
+                        }
+                        XMLElement::Lock => {
+                            if let Some(val) = read(&attributes, "val") {
+                                if let Ok(lock) = StructuredDataTagLockType::from_str(&val) {
+                                    property = property.lock(lock);
+                                }
+                            }
+                        }
+                        XMLElement::DocPart => {
+                            if let Some(val) = read(&attributes, "val") {
+                                property = property.placeholder(val);
+                            }
+                        }
+                        XMLElement::DataBinding => {
+                            let mut binding = DataBinding::new();
+                            if let Some(v) = read(&attributes, "xpath") {
+                                binding = binding.xpath(v);
+                            }
+                            if let Some(v) = read(&attributes, "prefixMappings") {
+                                binding = binding.prefix_mappings(v);
+                            }
+                            if let Some(v) = read(&attributes, "storeItemID") {
+                                binding = binding.store_item_id(v);
+                            }
+                            property = property.data_binding(binding);
+                        }
+                        XMLElement::ShowingPlaceholder => {
+                            property = property.showing_placeholder();
+                        }
+                        XMLElement::SdtCheckBox => {
+                            property = property
+                                .control(StructuredDataTagControl::CheckBox { checked: false });
+                        }
+                        XMLElement::Checked => {
+                            if let Some(StructuredDataTagControl::CheckBox { checked }) =
+                                &mut property.control
+                            {
+                                *checked = read(&attributes, "val")
+                                    .map(|v| !is_false(&v))
+                                    .unwrap_or(true);
+                            }
+                        }
+                        XMLElement::SdtDropDownList => {
+                            property = property
+                                .control(StructuredDataTagControl::DropDownList { items: vec![] });
+                        }
+                        XMLElement::SdtComboBox => {
+                            property = property
+                                .control(StructuredDataTagControl::ComboBox { items: vec![] });
+                        }
+                        XMLElement::SdtListItem => {
+                            let display_text = read(&attributes, "displayText");
+                            let value = read(&attributes, "value");
+                            let item = StructuredDataTagListItem::new(
+                                display_text
+                                    .clone()
+                                    .or_else(|| value.clone())
+                                    .unwrap_or_default(),
+                                value.or(display_text).unwrap_or_default(),
+                            );
+                            match &mut property.control {
+                                Some(StructuredDataTagControl::DropDownList { items })
+                                | Some(StructuredDataTagControl::ComboBox { items }) => {
+                                    items.push(item)
+                                }
+                                _ => {}
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if e == XMLElement::StructuredDataTagProperty {
+                        return Ok(property);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_inline_sdt() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:p>
+    <w:sdt>
+        <w:sdtPr>
+            <w:alias w:val="Name"/>
+            <w:tag w:val="name"/>
+            <w:showingPlcHdr/>
+        </w:sdtPr>
+        <w:sdtContent>
+            <w:r><w:t>Click here</w:t></w:r>
+        </w:sdtContent>
+    </w:sdt>
+</w:p>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let p = Paragraph::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            p.children,
+            vec![ParagraphChild::StructuredDataTag(Box::new(
+                StructuredDataTag::new()
+                    .alias("Name")
+                    .tag("name")
+                    .showing_placeholder()
+                    .add_run(Run::new().add_text("Click here"))
+            ))]
+        );
+    }
+
+    #[test]
+    fn test_read_block_sdt() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:body>
+    <w:sdt>
+        <w:sdtPr>
+            <w:tag w:val="address"/>
+            <w:lock w:val="sdtContentLocked"/>
+            <w:placeholder><w:docPart w:val="DefaultPlaceholder_1"/></w:placeholder>
+            <w:dataBinding w:prefixMappings="xmlns:ns0='urn:form'" w:xpath="/ns0:form/ns0:address" w:storeItemID="{1234}"/>
+        </w:sdtPr>
+        <w:sdtContent>
+            <w:p><w:r><w:t>Address</w:t></w:r></w:p>
+        </w:sdtContent>
+    </w:sdt>
+</w:body>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        loop {
+            if let Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) = parser.next()
+            {
+                if name.local_name == "sdt" {
+                    let sdt = StructuredDataTag::read(&mut parser, &attributes).unwrap();
+                    assert_eq!(
+                        sdt,
+                        StructuredDataTag::new()
+                            .tag("address")
+                            .lock(StructuredDataTagLockType::SdtContentLocked)
+                            .placeholder("DefaultPlaceholder_1")
+                            .data_binding(
+                                DataBinding::new()
+                                    .xpath("/ns0:form/ns0:address")
+                                    .prefix_mappings("xmlns:ns0='urn:form'")
+                                    .store_item_id("{1234}")
+                            )
+                            .add_paragraph(
+                                Paragraph::new().add_run(Run::new().add_text("Address"))
+                            )
+                    );
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -42,6 +42,12 @@ impl ElementReader for Style {
                             }
                             continue;
                         }
+                        XMLElement::Next => {
+                            if let Some(v) = read_val(&attributes) {
+                                style = style.next(v);
+                            }
+                            continue;
+                        }
                         XMLElement::Link => {
                             if let Some(v) = read_val(&attributes) {
                                 style = style.link(v);
@@ -50,7 +56,7 @@ impl ElementReader for Style {
                         }
                         // pPr
                         XMLElement::ParagraphProperty => {
-                            if let Ok(pr) = ParagraphProperty::read(r, attrs) {
+                            if let Ok(pr) = ParagraphProperty::read(r, &attributes) {
                                 style.paragraph_property = pr;
                             }
                             continue;
@@ -70,6 +76,11 @@ impl ElementReader for Style {
                                 style = style.table_cell_property(p);
                             }
                         }
+                        XMLElement::TableStyleProperty => {
+                            if let Ok(p) = TableStyleProperty::read(r, &attributes) {
+                                style = style.add_table_style_property(p);
+                            }
+                        }
                         _ => {}
                     }
                 }
@@ -83,4 +83,55 @@ mod tests {
         );
         assert_eq!(s, styles);
     }
+
+    #[test]
+    fn test_read_table_style() {
+        let xml = r#"<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+    <w:docDefaults>
+        <w:rPrDefault><w:rPr><w:sz w:val="21"/></w:rPr></w:rPrDefault>
+    </w:docDefaults>
+    <w:style w:type="table" w:styleId="Grid">
+        <w:name w:val="Grid Table"/>
+        <w:basedOn w:val="TableNormal"/>
+        <w:next w:val="Grid"/>
+        <w:rPr><w:sz w:val="18"/></w:rPr>
+        <w:tblPr><w:tblInd w:w="10" w:type="dxa"/></w:tblPr>
+        <w:tblStylePr w:type="firstRow">
+            <w:rPr><w:b/></w:rPr>
+            <w:tcPr><w:shd w:val="clear" w:color="auto" w:fill="FF0000"/></w:tcPr>
+        </w:tblStylePr>
+        <w:tblStylePr w:type="band1Horz">
+            <w:pPr><w:jc w:val="center"/></w:pPr>
+        </w:tblStylePr>
+    </w:style>
+</w:styles>"#;
+        let s = Styles::from_xml(xml.as_bytes()).unwrap();
+        let style = s.find_style_by_id("Grid").unwrap();
+        assert_eq!(style.next, Some(Next::new("Grid")));
+        assert_eq!(style.based_on, Some(BasedOn::new("TableNormal")));
+        assert_eq!(style.run_property, RunProperty::new().size(18));
+        assert_eq!(
+            style.table_property,
+            TableProperty::without_borders().indent(10)
+        );
+        assert_eq!(style.table_cell_property, TableCellProperty::new());
+        assert_eq!(
+            style.table_style_properties,
+            vec![
+                TableStyleProperty::new(TableStyleOverrideType::FirstRow)
+                    .run_property(RunProperty::new().bold())
+                    .table_cell_property(
+                        TableCellProperty::new().shading(Shading::new().fill("FF0000"))
+                    ),
+                TableStyleProperty::new(TableStyleOverrideType::Band1Horz)
+                    .paragraph_property(ParagraphProperty::new().align(AlignmentType::Center)),
+            ]
+        );
+        assert_eq!(
+            s,
+            Styles::new()
+                .doc_defaults(DocDefaults::new().size(21))
+                .add_style(style.clone())
+        );
+    }
 }
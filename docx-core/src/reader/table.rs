@@ -40,6 +40,26 @@ impl ElementReader for Table {
                         XMLElement::Justification => {
                             t = t.align(TableAlignmentType::from_str(&attributes[0].value)?);
                         }
+                        XMLElement::TableCaption => {
+                            if let Some(v) = read_val(&attributes) {
+                                t = t.caption(v);
+                            }
+                        }
+                        XMLElement::TableDescription => {
+                            if let Some(v) = read_val(&attributes) {
+                                t = t.description(v);
+                            }
+                        }
+                        XMLElement::TableLook => {
+                            if let Ok(look) = TableLook::read(r, &attributes) {
+                                t = t.look(look);
+                            }
+                        }
+                        XMLElement::TableCellSpacing => {
+                            let (w, width_type) = read_width(&attributes)?;
+                            t = t.cell_spacing(w as usize, width_type);
+                            continue;
+                        }
                         XMLElement::TableIndent => {
                             let (w, _) = read_width(&attributes)?;
                             t = t.indent(w as i32);
@@ -52,7 +72,7 @@ impl ElementReader for Table {
                         }
                         XMLElement::TableStyle => {
                             if let Some(s) = read_val(&attributes) {
-                                t = t.style(s);
+                                t.property = t.property.style(s);
                             }
                         }
                         XMLElement::TableCellMargin => {
@@ -131,4 +151,42 @@ mod tests {
                 .indent(100)
         );
     }
+
+    #[test]
+    fn test_read_table_with_look() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tbl>
+    <w:tblPr>
+        <w:tblLook w:val="04A0" w:firstRow="1" w:lastRow="1" w:noVBand="0"/>
+    </w:tblPr>
+</w:tbl>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let t = Table::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            t,
+            Table::without_borders(vec![])
+                .look(TableLook::new(true, true, true, false, false, false))
+        );
+    }
+
+    #[test]
+    fn test_read_table_with_caption() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tbl>
+    <w:tblPr>
+        <w:tblCaption w:val="Sales"/>
+        <w:tblDescription w:val="Sales by region &amp; year"/>
+    </w:tblPr>
+</w:tbl>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let t = Table::read(&mut parser, &[]).unwrap();
+        assert_eq!(
+            t,
+            Table::without_borders(vec![])
+                .caption("Sales")
+                .description("Sales by region & year")
+        );
+    }
 }
@@ -35,10 +35,12 @@ impl ElementReader for TableCell {
                             }
                             continue;
                         }
+                        // Errors are not ignored, since the rest of the nested table would be
+                        // read as the content of this cell.
                         XMLElement::Table => {
-                            if let Ok(table) = Table::read(r, &attributes) {
-                                cell = cell.add_table(table)
-                            }
+                            let table = Table::read(r, &attributes)?;
+                            cell = cell.add_table(table);
+                            continue;
                         }
                         _ => {}
                     }
@@ -163,4 +165,60 @@ mod tests {
                 .vertical_align(VAlignType::Bottom),
         );
     }
+
+    #[test]
+    fn test_read_nested_table() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tc>
+    <w:tcPr>
+        <w:tcW w:w="6425" w:type="dxa"/>
+    </w:tcPr>
+    <w:p><w:r><w:t>Before</w:t></w:r></w:p>
+    <w:tbl>
+        <w:tblPr>
+            <w:tblW w:w="3000" w:type="dxa"/>
+            <w:jc w:val="center"/>
+        </w:tblPr>
+        <w:tblGrid>
+            <w:gridCol w:w="3000"/>
+        </w:tblGrid>
+        <w:tr>
+            <w:tc>
+                <w:tcPr>
+                    <w:tcW w:w="3000" w:type="dxa"/>
+                </w:tcPr>
+                <w:tbl>
+                    <w:tblGrid><w:gridCol w:w="1000"/></w:tblGrid>
+                    <w:tr><w:tc><w:p><w:r><w:t>Deepest</w:t></w:r></w:p></w:tc></w:tr>
+                </w:tbl>
+                <w:p><w:r><w:t>Inner</w:t></w:r></w:p>
+            </w:tc>
+        </w:tr>
+    </w:tbl>
+    <w:p><w:r><w:t>After</w:t></w:r></w:p>
+</w:tc>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let cell = TableCell::read(&mut parser, &[]).unwrap();
+        assert_eq!(cell.children.len(), 3);
+        assert_eq!(
+            cell.property,
+            TableCellProperty::new().width(6425, WidthType::Dxa)
+        );
+        let table = match &cell.children[1] {
+            TableCellContent::Table(t) => t,
+            _ => panic!("should be a nested table"),
+        };
+        let TableChild::TableRow(row) = &table.rows[0];
+        let TableRowChild::TableCell(inner) = &row.cells[0];
+        assert_eq!(inner.children.len(), 2);
+        match &inner.children[0] {
+            TableCellContent::Table(t) => assert_eq!(t.grid, vec![1000]),
+            _ => panic!("should be a nested table"),
+        }
+        match &cell.children[2] {
+            TableCellContent::Paragraph(p) => assert_eq!(p.raw_text(), "After"),
+            _ => panic!("should be a paragraph"),
+        }
+    }
 }
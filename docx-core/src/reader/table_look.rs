@@ -0,0 +1,34 @@
+use std::io::Read;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::EventReader;
+
+use super::*;
+
+impl ElementReader for TableLook {
+    fn read<R: Read>(
+        _r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        // The attributes take precedence over the bitmask of older documents.
+        let mut look = attrs
+            .iter()
+            .find(|a| a.name.local_name == "val")
+            .and_then(|a| u16::from_str_radix(&a.value, 16).ok())
+            .map(TableLook::from_bitmask)
+            .unwrap_or_default();
+        for a in attrs {
+            let v = !is_false(&a.value);
+            match a.name.local_name.as_str() {
+                "firstRow" => look.first_row = v,
+                "lastRow" => look.last_row = v,
+                "firstColumn" => look.first_column = v,
+                "lastColumn" => look.last_column = v,
+                "noHBand" => look.no_h_band = v,
+                "noVBand" => look.no_v_band = v,
+                _ => {}
+            }
+        }
+        Ok(look)
+    }
+}
@@ -44,6 +44,26 @@ impl ElementReader for TableProperty {
                                 tp = tp.align(v);
                             }
                         }
+                        XMLElement::TableCaption => {
+                            if let Some(v) = read_val(&attributes) {
+                                tp = tp.caption(v);
+                            }
+                        }
+                        XMLElement::TableDescription => {
+                            if let Some(v) = read_val(&attributes) {
+                                tp = tp.description(v);
+                            }
+                        }
+                        XMLElement::TableLook => {
+                            if let Ok(look) = TableLook::read(r, &attributes) {
+                                tp = tp.set_look(look);
+                            }
+                        }
+                        XMLElement::TableCellSpacing => {
+                            if let Ok((w, width_type)) = read_width(&attributes) {
+                                tp = tp.cell_spacing(w as usize, width_type);
+                            }
+                        }
                         XMLElement::TableIndent => {
                             if let Ok((w, _)) = read_width(&attributes) {
                                 if w != 0 {
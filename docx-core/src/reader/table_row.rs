@@ -20,6 +20,7 @@ impl ElementReader for TableRow {
         let mut del = None;
         let mut ins = None;
         let mut height_rule = None;
+        let mut table_header = false;
         loop {
             let e = r.next();
             match e {
@@ -70,6 +71,9 @@ impl ElementReader for TableRow {
                                 }
                             }
                         }
+                        XMLElement::TableHeader => {
+                            table_header = read_bool(&attributes);
+                        }
                         XMLElement::Delete => {
                             if let Ok(d) = Delete::read(r, &attributes) {
                                 del = Some(d);
@@ -119,6 +123,10 @@ impl ElementReader for TableRow {
                             row = row.insert(ins);
                         }
 
+                        if table_header {
+                            row = row.header_row();
+                        }
+
                         return Ok(row);
                     }
                 }
@@ -128,3 +136,25 @@ impl ElementReader for TableRow {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_header_row() {
+        let c = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:tr>
+    <w:trPr>
+        <w:tblHeader/>
+    </w:trPr>
+</w:tr>
+</w:document>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        let row = TableRow::read(&mut parser, &[]).unwrap();
+        assert_eq!(row, TableRow::new(vec![]).header_row());
+    }
+}
@@ -0,0 +1,62 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+
+use crate::types::*;
+
+impl ElementReader for TableStyleProperty {
+    fn read<R: Read>(
+        r: &mut EventReader<R>,
+        attrs: &[OwnedAttribute],
+    ) -> Result<Self, ReaderError> {
+        let override_type = match read(attrs, "type") {
+            Some(t) => TableStyleOverrideType::from_str(&t)?,
+            None => return Err(ReaderError::XMLReadError),
+        };
+        let mut p = TableStyleProperty::new(override_type);
+        loop {
+            let e = r.next();
+            match e {
+                Ok(XmlEvent::StartElement {
+                    attributes, name, ..
+                }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    match e {
+                        XMLElement::ParagraphProperty => {
+                            if let Ok(pr) = ParagraphProperty::read(r, &attributes) {
+                                p = p.paragraph_property(pr);
+                            }
+                        }
+                        XMLElement::RunProperty => {
+                            let pr = RunProperty::read(r, &attributes)?;
+                            p = p.run_property(pr);
+                        }
+                        XMLElement::TableProperty => {
+                            if let Ok(pr) = TableProperty::read(r, &attributes) {
+                                p = p.table_property(pr);
+                            }
+                        }
+                        XMLElement::TableCellProperty => {
+                            if let Ok(pr) = TableCellProperty::read(r, &attributes) {
+                                p = p.table_cell_property(pr);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name, .. }) => {
+                    let e = XMLElement::from_str(&name.local_name).unwrap();
+                    if let XMLElement::TableStyleProperty = e {
+                        return Ok(p);
+                    }
+                }
+                Err(_) => return Err(ReaderError::XMLReadError),
+                _ => {}
+            }
+        }
+    }
+}
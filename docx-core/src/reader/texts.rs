@@ -0,0 +1,250 @@
+use std::borrow::Cow;
+use std::io::{Cursor, Read, Seek};
+
+use super::*;
+
+// Decompress the main document part, to read its texts with `read_texts`.
+pub fn read_document_xml(buf: &[u8]) -> Result<Vec<u8>, ReaderError> {
+    read_document_xml_from_reader(Cursor::new(buf))
+}
+
+pub fn read_document_xml_from_reader<R: Read + Seek>(reader: R) -> Result<Vec<u8>, ReaderError> {
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let rels = Rels::from_xml(&read_zip(&mut archive, "_rels/.rels")?[..])?;
+    read_zip(&mut archive, &main_document_path(&rels))
+}
+
+const WML_NS: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+
+// The contents of the `w:t` elements of a part, in document order and unescaped, whatever
+// prefix the WordprocessingML namespace is bound to. They are borrowed from the part buffer,
+// except for the texts which have character references or are split by CDATA sections.
+pub fn read_texts(xml: &[u8]) -> Result<Vec<Cow<'_, str>>, ReaderError> {
+    let xml = std::str::from_utf8(xml).map_err(|_| ReaderError::XMLReadError)?;
+    let mut texts = vec![];
+    // The namespaces in scope, as the depth of the element declaring them, prefix and uri.
+    let mut namespaces: Vec<(usize, &str, &str)> = vec![];
+    let mut depth = 0;
+    // The pieces of the text element being read, if any.
+    let mut text: Option<Vec<Cow<str>>> = None;
+    let mut pos = 0;
+    while let Some(i) = xml[pos..].find('<') {
+        let start = pos + i;
+        if let Some(pieces) = text.as_mut() {
+            if start > pos {
+                pieces.push(unescape(&xml[pos..start])?);
+            }
+        }
+        let tail = &xml[start..];
+        pos = start
+            + if tail.starts_with("<!--") {
+                find_end(tail, "-->")?
+            } else if let Some(data) = tail.strip_prefix("<![CDATA[") {
+                let end = data.find("]]>").ok_or(ReaderError::XMLReadError)?;
+                if let Some(pieces) = text.as_mut() {
+                    pieces.push(Cow::Borrowed(&data[..end]));
+                }
+                "<![CDATA[".len() + end + "]]>".len()
+            } else if tail.starts_with("<?") || tail.starts_with("<!") {
+                find_end(tail, ">")?
+            } else {
+                let end = tag_end(tail)?;
+                let tag = &tail[1..end - 1];
+                if let Some(name) = tag.strip_prefix('/') {
+                    if is_text(&namespaces, name.trim_end()) {
+                        if let Some(pieces) = text.take() {
+                            texts.push(join(pieces));
+                        }
+                    }
+                    end_element(&mut namespaces, &mut depth);
+                } else {
+                    let (tag, empty) = match tag.strip_suffix('/') {
+                        Some(tag) => (tag, true),
+                        None => (tag, false),
+                    };
+                    let name = tag.split(char::is_whitespace).next().unwrap_or_default();
+                    depth += 1;
+                    declare_namespaces(&mut namespaces, depth, &tag[name.len()..])?;
+                    if is_text(&namespaces, name) {
+                        if empty {
+                            texts.push(Cow::Borrowed(""));
+                        } else {
+                            text = Some(vec![]);
+                        }
+                    }
+                    if empty {
+                        end_element(&mut namespaces, &mut depth);
+                    }
+                }
+                end
+            };
+    }
+    if text.is_some() {
+        return Err(ReaderError::XMLReadError);
+    }
+    Ok(texts)
+}
+
+fn find_end(tail: &str, delimiter: &str) -> Result<usize, ReaderError> {
+    tail.find(delimiter)
+        .map(|i| i + delimiter.len())
+        .ok_or(ReaderError::XMLReadError)
+}
+
+// The end of a tag, skipping the `>` in quoted attribute values.
+fn tag_end(tail: &str) -> Result<usize, ReaderError> {
+    let mut quote = None;
+    for (i, c) in tail.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if q == c => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '>') => return Ok(i + 1),
+            _ => {}
+        }
+    }
+    Err(ReaderError::XMLReadError)
+}
+
+fn is_text(namespaces: &[(usize, &str, &str)], name: &str) -> bool {
+    let (prefix, local_name) = name.split_once(':').unwrap_or(("", name));
+    local_name == "t"
+        && namespaces
+            .iter()
+            .rev()
+            .find(|(_, p, _)| *p == prefix)
+            .map(|(_, _, uri)| *uri)
+            == Some(WML_NS)
+}
+
+// Push the `xmlns` attributes of a start tag, the default namespace with an empty prefix.
+fn declare_namespaces<'a>(
+    namespaces: &mut Vec<(usize, &'a str, &'a str)>,
+    depth: usize,
+    attributes: &'a str,
+) -> Result<(), ReaderError> {
+    let mut rest = attributes.trim_start();
+    while let Some(eq) = rest.find('=') {
+        let name = rest[..eq].trim_end();
+        let value = rest[eq + 1..].trim_start();
+        let quote = match value.chars().next() {
+            Some(c) if c == '"' || c == '\'' => c,
+            _ => return Err(ReaderError::XMLReadError),
+        };
+        let end = value[1..].find(quote).ok_or(ReaderError::XMLReadError)? + 1;
+        let prefix = if name == "xmlns" {
+            Some("")
+        } else {
+            name.strip_prefix("xmlns:")
+        };
+        if let Some(prefix) = prefix {
+            namespaces.push((depth, prefix, &value[1..end]));
+        }
+        rest = value[end + 1..].trim_start();
+    }
+    Ok(())
+}
+
+fn end_element(namespaces: &mut Vec<(usize, &str, &str)>, depth: &mut usize) {
+    while matches!(namespaces.last(), Some((d, ..)) if *d == *depth) {
+        namespaces.pop();
+    }
+    *depth = depth.saturating_sub(1);
+}
+
+fn join(mut pieces: Vec<Cow<str>>) -> Cow<str> {
+    match pieces.len() {
+        0 => Cow::Borrowed(""),
+        1 => pieces.remove(0),
+        _ => Cow::Owned(pieces.concat()),
+    }
+}
+
+fn unescape(s: &str) -> Result<Cow<str>, ReaderError> {
+    if !s.contains('&') {
+        return Ok(Cow::Borrowed(s));
+    }
+    let mut unescaped = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(i) = rest.find('&') {
+        unescaped.push_str(&rest[..i]);
+        let end = rest[i..].find(';').ok_or(ReaderError::XMLReadError)?;
+        let entity = &rest[i + 1..i + end];
+        let c = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16)
+                } else if let Some(dec) = entity.strip_prefix('#') {
+                    dec.parse()
+                } else {
+                    return Err(ReaderError::XMLReadError);
+                };
+                code.ok()
+                    .and_then(char::from_u32)
+                    .ok_or(ReaderError::XMLReadError)?
+            }
+        };
+        unescaped.push(c);
+        rest = &rest[i + end + 1..];
+    }
+    unescaped.push_str(rest);
+    Ok(Cow::Owned(unescaped))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_texts() {
+        let xml = r#"<?xml version="1.0"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">
+  <w:body>
+    <w:p w14:paraId="a>b"><w:r><w:t xml:space="preserve">Hello </w:t></w:r><w:r><w:t>a &amp; b&#x21;</w:t></w:r></w:p>
+    <!-- <w:t>comment</w:t> -->
+    <w:p><w:r><w:t/><w:tab/><w:t><![CDATA[<x>]]> y</w:t></w:r><m:oMath><m:r><m:t>x</m:t></m:r></m:oMath></w:p>
+  </w:body>
+</w:document>"#;
+        let texts = read_texts(xml.as_bytes()).unwrap();
+        assert_eq!(texts, vec!["Hello ", "a & b!", "", "<x> y"]);
+        assert!(matches!(texts[0], Cow::Borrowed(_)));
+        assert!(matches!(texts[1], Cow::Owned(_)));
+        let declared =
+            |xml: &str| format!(r#"<w:document xmlns:w="{}">{}</w:document>"#, WML_NS, xml);
+        assert!(read_texts(declared("<w:t>a &unknown; b</w:t>").as_bytes()).is_err());
+        assert!(read_texts(declared("<w:t>unclosed").as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_read_texts_with_other_prefixes() {
+        let xml = r#"<x:document xmlns:x="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <x:body>
+    <x:p><x:r><x:t>Prefixed</x:t></x:r></x:p>
+    <p xmlns="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><r><t>Default</t><t/></r></p>
+    <t>Unbound</t>
+    <x:p xmlns:w="urn:other"><w:r><w:t>Other</w:t></w:r><x:r><x:t xml:space='preserve'> Last</x:t></x:r></x:p>
+  </x:body>
+</x:document>"#;
+        let texts = read_texts(xml.as_bytes()).unwrap();
+        assert_eq!(texts, vec!["Prefixed", "Default", "", " Last"]);
+    }
+
+    #[test]
+    fn test_read_document_xml() {
+        let docx = Docx::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("Hello")))
+            .add_paragraph(Paragraph::new().add_run(Run::new().add_text("<World>")));
+        let mut buf = Cursor::new(vec![]);
+        docx.build().pack(&mut buf).unwrap();
+        let xml = read_document_xml(&buf.into_inner()).unwrap();
+        assert_eq!(read_texts(&xml).unwrap(), vec!["Hello", "<World>"]);
+    }
+}
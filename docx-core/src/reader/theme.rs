@@ -15,7 +15,6 @@ impl FromXML for Theme {
                     attributes, name, ..
                 }) => {
                     let e = AXMLElement::from_str(&name.local_name).unwrap();
-                    #[allow(clippy::single_match)]
                     match e {
                         AXMLElement::FontScheme => {
                             if let Ok(f) = FontScheme::read(&mut parser, &attributes) {
@@ -23,6 +22,12 @@ impl FromXML for Theme {
                             }
                             continue;
                         }
+                        AXMLElement::ClrScheme => {
+                            if let Ok(c) = ColorScheme::read(&mut parser, &attributes) {
+                                theme.color_scheme = c;
+                            }
+                            continue;
+                        }
                         _ => {}
                     }
                 }
@@ -34,3 +39,46 @@ impl FromXML for Theme {
         Ok(theme)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_theme() {
+        let xml = r#"<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Office Theme">
+  <a:themeElements>
+    <a:clrScheme name="Office">
+      <a:dk1><a:sysClr val="windowText" lastClr="000000"/></a:dk1>
+      <a:lt1><a:sysClr val="window" lastClr="FFFFFF"/></a:lt1>
+      <a:dk2><a:srgbClr val="44546A"/></a:dk2>
+      <a:lt2><a:srgbClr val="E7E6E6"/></a:lt2>
+      <a:accent1><a:srgbClr val="4472C4"/></a:accent1>
+      <a:accent2><a:srgbClr val="ED7D31"/></a:accent2>
+      <a:accent3><a:srgbClr val="A5A5A5"/></a:accent3>
+      <a:accent4><a:srgbClr val="FFC000"/></a:accent4>
+      <a:accent5><a:srgbClr val="5B9BD5"/></a:accent5>
+      <a:accent6><a:srgbClr val="70AD47"/></a:accent6>
+      <a:hlink><a:srgbClr val="0563C1"/></a:hlink>
+      <a:folHlink><a:srgbClr val="954F72"/></a:folHlink>
+    </a:clrScheme>
+    <a:fontScheme name="Office">
+      <a:majorFont><a:latin typeface="Calibri Light"/><a:ea typeface=""/><a:cs typeface=""/></a:majorFont>
+      <a:minorFont><a:latin typeface="Calibri"/><a:ea typeface=""/><a:cs typeface=""/></a:minorFont>
+    </a:fontScheme>
+  </a:themeElements>
+</a:theme>"#;
+        let theme = Theme::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(theme.color_scheme.name, "Office");
+        assert_eq!(theme.color("text1"), Some("000000"));
+        assert_eq!(theme.color("background1"), Some("FFFFFF"));
+        assert_eq!(theme.color("accent6"), Some("70AD47"));
+        assert_eq!(theme.color("followedHyperlink"), Some("954F72"));
+        assert_eq!(theme.font("majorHAnsi"), Some("Calibri Light"));
+        assert_eq!(theme.font("+mn-lt"), Some("Calibri"));
+        assert_eq!(theme.font("minorEastAsia"), None);
+    }
+}
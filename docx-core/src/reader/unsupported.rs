@@ -0,0 +1,171 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use xml::attribute::OwnedAttribute;
+use xml::name::OwnedName;
+use xml::reader::{EventReader, XmlEvent};
+
+use super::*;
+use crate::escape::escape;
+
+// Paragraph and body level wrappers whose children are still read as if they were not wrapped.
+const TRANSPARENT_ELEMENTS: &[&str] = &["smartTag", "customXml", "fldSimple", "dir", "bdo"];
+
+// Run children which the writer generates by itself, or whose content is read as run children.
+const GENERATED_RUN_ELEMENTS: &[&str] = &["commentReference", "object"];
+
+pub(crate) fn is_preserved(name: &OwnedName) -> bool {
+    name.prefix.as_deref() == Some("w")
+        && XMLElement::from_str(&name.local_name) == Ok(XMLElement::Unsupported)
+        && !TRANSPARENT_ELEMENTS.contains(&name.local_name.as_str())
+}
+
+pub(crate) fn is_preserved_in_run(name: &OwnedName) -> bool {
+    is_preserved(name) && !GENERATED_RUN_ELEMENTS.contains(&name.local_name.as_str())
+}
+
+fn qualified_name(name: &OwnedName) -> String {
+    match &name.prefix {
+        Some(prefix) => format!("{}:{}", prefix, name.local_name),
+        None => name.local_name.clone(),
+    }
+}
+
+struct RawXML {
+    xml: String,
+    namespaces: Vec<(String, String)>,
+    open: bool,
+}
+
+impl RawXML {
+    fn add_namespace(&mut self, name: &OwnedName) {
+        if let Some(uri) = &name.namespace {
+            let key = match name.prefix.as_deref() {
+                Some("xml") => return,
+                Some(prefix) => format!("xmlns:{}", prefix),
+                None => "xmlns".to_owned(),
+            };
+            if !self.namespaces.iter().any(|(k, _)| k == &key) {
+                self.namespaces.push((key, uri.clone()));
+            }
+        }
+    }
+
+    fn close_start(&mut self) {
+        if self.open {
+            self.xml.push('>');
+            self.open = false;
+        }
+    }
+
+    fn start(&mut self, name: &OwnedName, attrs: &[OwnedAttribute]) {
+        self.close_start();
+        self.add_namespace(name);
+        self.xml.push('<');
+        self.xml.push_str(&qualified_name(name));
+        for a in attrs {
+            self.add_namespace(&a.name);
+            self.xml.push_str(&format!(
+                " {}=\"{}\"",
+                qualified_name(&a.name),
+                escape(&a.value)
+            ));
+        }
+        self.open = true;
+    }
+
+    fn end(&mut self, name: &OwnedName) {
+        if self.open {
+            self.xml.push_str(" />");
+            self.open = false;
+        } else {
+            self.xml.push_str(&format!("</{}>", qualified_name(name)));
+        }
+    }
+
+    fn text(&mut self, t: &str) {
+        self.close_start();
+        self.xml.push_str(t);
+    }
+
+    // Declare the namespaces used in the element on its root, so that it can be written anywhere.
+    fn finish(self, root: &OwnedName) -> String {
+        let mut declarations = String::new();
+        for (k, v) in &self.namespaces {
+            declarations.push_str(&format!(" {}=\"{}\"", k, escape(v)));
+        }
+        let mut xml = self.xml;
+        xml.insert_str(1 + qualified_name(root).len(), &declarations);
+        xml
+    }
+}
+
+// Read the element which has just been started as XML, to write it back as it was.
+pub(crate) fn read_unsupported<R: Read>(
+    r: &mut EventReader<R>,
+    name: &OwnedName,
+    attrs: &[OwnedAttribute],
+) -> Result<String, ReaderError> {
+    let mut raw = RawXML {
+        xml: String::new(),
+        namespaces: vec![],
+        open: false,
+    };
+    raw.start(name, attrs);
+    let mut depth = 0;
+    loop {
+        let e = r.next();
+        match e {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                depth += 1;
+                raw.start(&name, &attributes);
+            }
+            Ok(XmlEvent::EndElement { name: end }) => {
+                raw.end(&end);
+                if depth == 0 {
+                    return Ok(raw.finish(name));
+                }
+                depth -= 1;
+            }
+            Ok(XmlEvent::Characters(c)) => raw.text(&escape(&c)),
+            Ok(XmlEvent::Whitespace(c)) => raw.text(&c),
+            Ok(XmlEvent::CData(c)) => raw.text(&format!("<![CDATA[{}]]>", c)),
+            Ok(XmlEvent::EndDocument) | Err(_) => return Err(ReaderError::XMLReadError),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_read_unsupported() {
+        let c = r#"<w:p xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml">
+  <w:permStart w:id="0" w14:edGrp="everyone"><w:x>a &amp; b</w:x><w:y/></w:permStart>
+</w:p>"#;
+        let mut parser = EventReader::new(c.as_bytes());
+        loop {
+            if let Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) = parser.next()
+            {
+                if name.local_name == "permStart" {
+                    assert!(is_preserved(&name));
+                    let xml = read_unsupported(&mut parser, &name, &attributes).unwrap();
+                    assert_eq!(
+                        xml,
+                        r#"<w:permStart xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml" w:id="0" w14:edGrp="everyone"><w:x>a &amp; b</w:x><w:y /></w:permStart>"#
+                    );
+                    break;
+                }
+            }
+        }
+    }
+}
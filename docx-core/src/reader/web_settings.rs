@@ -17,10 +17,43 @@ impl FromXML for WebSettings {
                     attributes, name, ..
                 }) => {
                     let e = XMLElement::from_str(&name.local_name).unwrap();
-                    if let XMLElement::Div = e {
-                        if let Ok(div) = Div::read(&mut parser, &attributes) {
-                            settings.divs.push(div);
+                    match e {
+                        XMLElement::Div => {
+                            if let Ok(div) = Div::read(&mut parser, &attributes) {
+                                settings.divs.push(div);
+                            }
                         }
+                        XMLElement::Frameset => {
+                            let xml =
+                                unsupported::read_unsupported(&mut parser, &name, &attributes)?;
+                            settings.frameset = Some(xml);
+                        }
+                        XMLElement::Encoding => settings.encoding = read_val(&attributes),
+                        XMLElement::OptimizeForBrowser => {
+                            settings.optimize_for_browser = read_bool(&attributes)
+                        }
+                        XMLElement::RelyOnVML => settings.rely_on_vml = read_bool(&attributes),
+                        XMLElement::AllowPNG => settings.allow_png = read_bool(&attributes),
+                        XMLElement::DoNotRelyOnCSS => {
+                            settings.do_not_rely_on_css = read_bool(&attributes)
+                        }
+                        XMLElement::DoNotSaveAsSingleFile => {
+                            settings.do_not_save_as_single_file = read_bool(&attributes)
+                        }
+                        XMLElement::DoNotOrganizeInFolder => {
+                            settings.do_not_organize_in_folder = read_bool(&attributes)
+                        }
+                        XMLElement::DoNotUseLongFileNames => {
+                            settings.do_not_use_long_file_names = read_bool(&attributes)
+                        }
+                        XMLElement::PixelsPerInch => {
+                            settings.pixels_per_inch =
+                                read_val(&attributes).and_then(|v| usize::from_str(&v).ok())
+                        }
+                        XMLElement::TargetScreenSize => {
+                            settings.target_screen_size = read_val(&attributes)
+                        }
+                        _ => {}
                     }
                 }
                 Ok(XmlEvent::EndElement { name, .. }) => {
@@ -37,3 +70,46 @@ impl FromXML for WebSettings {
         Ok(settings)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::types::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_web_settings_from_xml() {
+        let xml = r#"<w:webSettings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:divs>
+    <w:div w:id="1">
+      <w:bodyDiv w:val="1" />
+      <w:marLeft w:val="0" /><w:marRight w:val="0" /><w:marTop w:val="0" /><w:marBottom w:val="0" />
+      <w:divBdr><w:top w:val="single" w:sz="6" w:space="1" w:color="FF0000" /></w:divBdr>
+    </w:div>
+  </w:divs>
+  <w:encoding w:val="utf-8" />
+  <w:optimizeForBrowser />
+  <w:allowPNG />
+  <w:pixelsPerInch w:val="96" />
+</w:webSettings>"#;
+        let s = WebSettings::from_xml(xml.as_bytes()).unwrap();
+        assert_eq!(
+            s,
+            WebSettings::new()
+                .add_div(
+                    Div::new("1").body_div().add_border(
+                        ParagraphBorder::new(ParagraphBorderPosition::Top)
+                            .size(6)
+                            .space(1)
+                            .color("FF0000")
+                    )
+                )
+                .encoding("utf-8")
+                .optimize_for_browser()
+                .allow_png()
+                .pixels_per_inch(96)
+        );
+    }
+}
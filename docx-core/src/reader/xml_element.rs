@@ -8,6 +8,7 @@ use crate::reader::ReaderError;
 
 #[derive(PartialEq, Debug, Clone)]
 pub enum XMLElement {
+    Document,
     Body,
     Paragraph,
     ParagraphProperty,
@@ -53,6 +54,7 @@ pub enum XMLElement {
     OutlineLvl,
     Insert,
     SnapToGrid,
+    Bidi,
     KeepNext,
     KeepLines,
     PageBreakBefore,
@@ -65,6 +67,8 @@ pub enum XMLElement {
     MarginTop,
     MarginBottom,
     Delete,
+    MoveFrom,
+    MoveTo,
     DeleteText,
     BookmarkStart,
     BookmarkEnd,
@@ -75,6 +79,12 @@ pub enum XMLElement {
     CommentExtended,
     Property,
     CommentsExtended,
+    Footnotes,
+    Footnote,
+    FootnoteReference,
+    Endnotes,
+    Endnote,
+    EndnoteReference,
     VAlign,
     Shading,
     Strike,
@@ -83,6 +93,7 @@ pub enum XMLElement {
     TableProperty,
     TableRow,
     TableRowHeight,
+    TableHeader,
     HeightRule,
     TableCell,
     TableCellProperty,
@@ -94,8 +105,13 @@ pub enum XMLElement {
     TableIndent,
     TableBorders,
     TableCellMargin,
+    TableCellSpacing,
+    TableLook,
+    TableCaption,
+    TableDescription,
     TablePositionProperty,
     TableStyle,
+    TableStyleProperty,
     // Change
     TableGridChange,
     TablePropertyChange,
@@ -151,15 +167,77 @@ pub enum XMLElement {
     RunPropertyDefault,
     AdjustLineHeightInTable,
     CharacterSpacingControl,
+    Zoom,
+    TrackRevisions,
+    DocumentProtection,
+    EmbedTrueTypeFonts,
+    Font,
+    EmbedRegular,
+    EmbedBold,
+    EmbedItalic,
+    EmbedBoldItalic,
+    WriteProtection,
+    Compat,
+    CompatSetting,
+    Rsids,
+    RsidRoot,
+    Rsid,
     SectionProperty,
     PageSize,
     PageMargin,
     WebSettings,
+    Frameset,
+    BlockQuote,
+    BodyDiv,
+    DivBorder,
+    Encoding,
+    OptimizeForBrowser,
+    RelyOnVML,
+    AllowPNG,
+    DoNotRelyOnCSS,
+    DoNotSaveAsSingleFile,
+    DoNotOrganizeInFolder,
+    DoNotUseLongFileNames,
+    PixelsPerInch,
+    TargetScreenSize,
     HeaderReference,
     FooterReference,
     TitlePg,
+    Columns,
+    Column,
     EvenAndOddHeaders,
     StructuredDataTag,
+    StructuredDataTagProperty,
+    AltChunk,
+    Alias,
+    Tag,
+    Lock,
+    Placeholder,
+    DocPart,
+    DocPartPr,
+    DocPartBody,
+    Category,
+    Gallery,
+    Behavior,
+    Description,
+    Guid,
+    DataBinding,
+    ShowingPlaceholder,
+    SdtCheckBox,
+    SdtDropDownList,
+    SdtComboBox,
+    SdtListItem,
+    FormFieldData,
+    HelpText,
+    StatusText,
+    CheckBox,
+    Checked,
+    Default,
+    DropDownList,
+    DropDownResult,
+    ListEntry,
+    TextInput,
+    MaxLength,
     Type,
     PageNumType,
     FrameProperty,
@@ -198,6 +276,10 @@ pub enum WpXMLElement {
     Extent,
     EffectExtent,
     WrapNone,
+    WrapSquare,
+    WrapTight,
+    WrapThrough,
+    WrapTopAndBottom,
     DocProperty,
     Unsupported,
 }
@@ -208,12 +290,16 @@ pub enum AXMLElement {
     GraphicData,
     Xfrm,
     Blip,
+    SrcRect,
     Off,
     Ext,
     PrstGeom,
     SolidFill,
     Ln,
     FontScheme,
+    ClrScheme,
+    SrgbClr,
+    SysClr,
     MajorFont,
     MinorFont,
     Latin,
@@ -241,10 +327,36 @@ pub enum VXMLElement {
     Fill,
     TextBox,
     Shape,
+    RoundRect,
+    Oval,
+    Line,
+    PolyLine,
+    Arc,
+    Curve,
+    Image,
+    Group,
     ImageData,
     Unsupported,
 }
 
+impl VXMLElement {
+    pub fn is_shape(&self) -> bool {
+        matches!(
+            self,
+            VXMLElement::Shape
+                | VXMLElement::Rect
+                | VXMLElement::RoundRect
+                | VXMLElement::Oval
+                | VXMLElement::Line
+                | VXMLElement::PolyLine
+                | VXMLElement::Arc
+                | VXMLElement::Curve
+                | VXMLElement::Image
+                | VXMLElement::Group
+        )
+    }
+}
+
 pub enum VtXMLElement {
     Lpwstr,
     Unsupported,
@@ -260,6 +372,7 @@ impl FromStr for XMLElement {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "document" => Ok(XMLElement::Document),
             "body" => Ok(XMLElement::Body),
             "p" => Ok(XMLElement::Paragraph),
             "pPr" => Ok(XMLElement::ParagraphProperty),
@@ -297,6 +410,8 @@ impl FromStr for XMLElement {
             "jc" => Ok(XMLElement::Justification),
             "ins" => Ok(XMLElement::Insert),
             "del" => Ok(XMLElement::Delete),
+            "moveFrom" => Ok(XMLElement::MoveFrom),
+            "moveTo" => Ok(XMLElement::MoveTo),
             "delText" => Ok(XMLElement::DeleteText),
             "bookmarkStart" => Ok(XMLElement::BookmarkStart),
             "bookmarkEnd" => Ok(XMLElement::BookmarkEnd),
@@ -306,12 +421,19 @@ impl FromStr for XMLElement {
             "commentRangeEnd" => Ok(XMLElement::CommentRangeEnd),
             "commentEx" => Ok(XMLElement::CommentExtended),
             "commentsEx" => Ok(XMLElement::CommentsExtended),
+            "footnotes" => Ok(XMLElement::Footnotes),
+            "footnote" => Ok(XMLElement::Footnote),
+            "footnoteReference" => Ok(XMLElement::FootnoteReference),
+            "endnotes" => Ok(XMLElement::Endnotes),
+            "endnote" => Ok(XMLElement::Endnote),
+            "endnoteReference" => Ok(XMLElement::EndnoteReference),
             "shd" => Ok(XMLElement::Shading),
             "property" => Ok(XMLElement::Property),
             "tbl" => Ok(XMLElement::Table),
             "tblPr" => Ok(XMLElement::TableProperty),
             "tr" => Ok(XMLElement::TableRow),
             "trHeight" => Ok(XMLElement::TableRowHeight),
+            "tblHeader" => Ok(XMLElement::TableHeader),
             "hRule" => Ok(XMLElement::HeightRule),
             "tc" => Ok(XMLElement::TableCell),
             "tcPr" => Ok(XMLElement::TableCellProperty),
@@ -328,7 +450,12 @@ impl FromStr for XMLElement {
             "tblInd" => Ok(XMLElement::TableIndent),
             "tblBorders" => Ok(XMLElement::TableBorders),
             "tblCellMar" => Ok(XMLElement::TableCellMargin),
+            "tblCellSpacing" => Ok(XMLElement::TableCellSpacing),
+            "tblLook" => Ok(XMLElement::TableLook),
+            "tblCaption" => Ok(XMLElement::TableCaption),
+            "tblDescription" => Ok(XMLElement::TableDescription),
             "tblStyle" => Ok(XMLElement::TableStyle),
+            "tblStylePr" => Ok(XMLElement::TableStyleProperty),
             "tblpPr" => Ok(XMLElement::TablePositionProperty),
             "top" => Ok(XMLElement::Top),
             "right" => Ok(XMLElement::Right),
@@ -391,6 +518,21 @@ impl FromStr for XMLElement {
             "rPrDefault" => Ok(XMLElement::RunPropertyDefault),
             "adjustLineHeightInTable" => Ok(XMLElement::AdjustLineHeightInTable),
             "characterSpacingControl" => Ok(XMLElement::CharacterSpacingControl),
+            "zoom" => Ok(XMLElement::Zoom),
+            "trackRevisions" => Ok(XMLElement::TrackRevisions),
+            "documentProtection" => Ok(XMLElement::DocumentProtection),
+            "embedTrueTypeFonts" => Ok(XMLElement::EmbedTrueTypeFonts),
+            "font" => Ok(XMLElement::Font),
+            "embedRegular" => Ok(XMLElement::EmbedRegular),
+            "embedBold" => Ok(XMLElement::EmbedBold),
+            "embedItalic" => Ok(XMLElement::EmbedItalic),
+            "embedBoldItalic" => Ok(XMLElement::EmbedBoldItalic),
+            "writeProtection" => Ok(XMLElement::WriteProtection),
+            "compat" => Ok(XMLElement::Compat),
+            "compatSetting" => Ok(XMLElement::CompatSetting),
+            "rsids" => Ok(XMLElement::Rsids),
+            "rsidRoot" => Ok(XMLElement::RsidRoot),
+            "rsid" => Ok(XMLElement::Rsid),
             "defaultTabStop" => Ok(XMLElement::DefaultTabStop),
             "divId" => Ok(XMLElement::DivId),
             "div" => Ok(XMLElement::Div),
@@ -401,7 +543,22 @@ impl FromStr for XMLElement {
             "marBottom" => Ok(XMLElement::MarginBottom),
             "sym" => Ok(XMLElement::Sym),
             "webSettings" => Ok(XMLElement::WebSettings),
+            "frameset" => Ok(XMLElement::Frameset),
+            "blockQuote" => Ok(XMLElement::BlockQuote),
+            "bodyDiv" => Ok(XMLElement::BodyDiv),
+            "divBdr" => Ok(XMLElement::DivBorder),
+            "encoding" => Ok(XMLElement::Encoding),
+            "optimizeForBrowser" => Ok(XMLElement::OptimizeForBrowser),
+            "relyOnVML" => Ok(XMLElement::RelyOnVML),
+            "allowPNG" => Ok(XMLElement::AllowPNG),
+            "doNotRelyOnCSS" => Ok(XMLElement::DoNotRelyOnCSS),
+            "doNotSaveAsSingleFile" => Ok(XMLElement::DoNotSaveAsSingleFile),
+            "doNotOrganizeInFolder" => Ok(XMLElement::DoNotOrganizeInFolder),
+            "doNotUseLongFileNames" => Ok(XMLElement::DoNotUseLongFileNames),
+            "pixelsPerInch" => Ok(XMLElement::PixelsPerInch),
+            "targetScreenSz" => Ok(XMLElement::TargetScreenSize),
             "snapToGrid" => Ok(XMLElement::SnapToGrid),
+            "bidi" => Ok(XMLElement::Bidi),
             "keepNext" => Ok(XMLElement::KeepNext),
             "keepLines" => Ok(XMLElement::KeepLines),
             "pageBreakBefore" => Ok(XMLElement::PageBreakBefore),
@@ -409,8 +566,41 @@ impl FromStr for XMLElement {
             "headerReference" => Ok(XMLElement::HeaderReference),
             "footerReference" => Ok(XMLElement::FooterReference),
             "titlePg" => Ok(XMLElement::TitlePg),
+            "cols" => Ok(XMLElement::Columns),
+            "col" => Ok(XMLElement::Column),
             "evenAndOddHeaders" => Ok(XMLElement::EvenAndOddHeaders),
             "sdt" => Ok(XMLElement::StructuredDataTag),
+            "sdtPr" => Ok(XMLElement::StructuredDataTagProperty),
+            "altChunk" => Ok(XMLElement::AltChunk),
+            "alias" => Ok(XMLElement::Alias),
+            "tag" => Ok(XMLElement::Tag),
+            "lock" => Ok(XMLElement::Lock),
+            "placeholder" => Ok(XMLElement::Placeholder),
+            "docPart" => Ok(XMLElement::DocPart),
+            "docPartPr" => Ok(XMLElement::DocPartPr),
+            "docPartBody" => Ok(XMLElement::DocPartBody),
+            "category" => Ok(XMLElement::Category),
+            "gallery" => Ok(XMLElement::Gallery),
+            "behavior" => Ok(XMLElement::Behavior),
+            "description" => Ok(XMLElement::Description),
+            "guid" => Ok(XMLElement::Guid),
+            "dataBinding" => Ok(XMLElement::DataBinding),
+            "showingPlcHdr" => Ok(XMLElement::ShowingPlaceholder),
+            "checkbox" => Ok(XMLElement::SdtCheckBox),
+            "dropDownList" => Ok(XMLElement::SdtDropDownList),
+            "comboBox" => Ok(XMLElement::SdtComboBox),
+            "listItem" => Ok(XMLElement::SdtListItem),
+            "ffData" => Ok(XMLElement::FormFieldData),
+            "helpText" => Ok(XMLElement::HelpText),
+            "statusText" => Ok(XMLElement::StatusText),
+            "checkBox" => Ok(XMLElement::CheckBox),
+            "checked" => Ok(XMLElement::Checked),
+            "default" => Ok(XMLElement::Default),
+            "ddList" => Ok(XMLElement::DropDownList),
+            "result" => Ok(XMLElement::DropDownResult),
+            "listEntry" => Ok(XMLElement::ListEntry),
+            "textInput" => Ok(XMLElement::TextInput),
+            "maxLength" => Ok(XMLElement::MaxLength),
             "pgNumType" => Ok(XMLElement::PageNumType),
             "framePr" => Ok(XMLElement::FrameProperty),
             "textAlignment" => Ok(XMLElement::TextAlignment),
@@ -457,6 +647,10 @@ impl FromStr for WpXMLElement {
             "extent" => Ok(WpXMLElement::Extent),
             "effectExtent" => Ok(WpXMLElement::EffectExtent),
             "wrapNone" => Ok(WpXMLElement::WrapNone),
+            "wrapSquare" => Ok(WpXMLElement::WrapSquare),
+            "wrapTight" => Ok(WpXMLElement::WrapTight),
+            "wrapThrough" => Ok(WpXMLElement::WrapThrough),
+            "wrapTopAndBottom" => Ok(WpXMLElement::WrapTopAndBottom),
             "docPr" => Ok(WpXMLElement::DocProperty),
             _ => Ok(WpXMLElement::Unsupported),
         }
@@ -471,12 +665,16 @@ impl FromStr for AXMLElement {
             "graphicData" => Ok(AXMLElement::GraphicData),
             "xfrm" => Ok(AXMLElement::Xfrm),
             "blip" => Ok(AXMLElement::Blip),
+            "srcRect" => Ok(AXMLElement::SrcRect),
             "off" => Ok(AXMLElement::Off),
             "ext" => Ok(AXMLElement::Ext),
             "prstGeom" => Ok(AXMLElement::PrstGeom),
             "solidFill" => Ok(AXMLElement::SolidFill),
             "ln" => Ok(AXMLElement::Ln),
             "fontScheme" => Ok(AXMLElement::FontScheme),
+            "clrScheme" => Ok(AXMLElement::ClrScheme),
+            "srgbClr" => Ok(AXMLElement::SrgbClr),
+            "sysClr" => Ok(AXMLElement::SysClr),
             "majorFont" => Ok(AXMLElement::MajorFont),
             "minorFont" => Ok(AXMLElement::MinorFont),
             "latin" => Ok(AXMLElement::Latin),
@@ -512,6 +710,14 @@ impl FromStr for VXMLElement {
             "fill" => Ok(VXMLElement::Fill),
             "textbox" => Ok(VXMLElement::TextBox),
             "shape" => Ok(VXMLElement::Shape),
+            "roundrect" => Ok(VXMLElement::RoundRect),
+            "oval" => Ok(VXMLElement::Oval),
+            "line" => Ok(VXMLElement::Line),
+            "polyline" => Ok(VXMLElement::PolyLine),
+            "arc" => Ok(VXMLElement::Arc),
+            "curve" => Ok(VXMLElement::Curve),
+            "image" => Ok(VXMLElement::Image),
+            "group" => Ok(VXMLElement::Group),
             "imagedata" => Ok(VXMLElement::ImageData),
             _ => Ok(VXMLElement::Unsupported),
         }
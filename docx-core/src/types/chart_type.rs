@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+//
+// The chart elements of CT_PlotArea, such as <c:barChart>.
+//
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ChartType {
+    Area,
+    Area3D,
+    Bar,
+    Bar3D,
+    Bubble,
+    Doughnut,
+    Line,
+    Line3D,
+    OfPie,
+    Pie,
+    Pie3D,
+    Radar,
+    Scatter,
+    Stock,
+    Surface,
+    Surface3D,
+}
+
+impl fmt::Display for ChartType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ChartType::Area => write!(f, "areaChart"),
+            ChartType::Area3D => write!(f, "area3DChart"),
+            ChartType::Bar => write!(f, "barChart"),
+            ChartType::Bar3D => write!(f, "bar3DChart"),
+            ChartType::Bubble => write!(f, "bubbleChart"),
+            ChartType::Doughnut => write!(f, "doughnutChart"),
+            ChartType::Line => write!(f, "lineChart"),
+            ChartType::Line3D => write!(f, "line3DChart"),
+            ChartType::OfPie => write!(f, "ofPieChart"),
+            ChartType::Pie => write!(f, "pieChart"),
+            ChartType::Pie3D => write!(f, "pie3DChart"),
+            ChartType::Radar => write!(f, "radarChart"),
+            ChartType::Scatter => write!(f, "scatterChart"),
+            ChartType::Stock => write!(f, "stockChart"),
+            ChartType::Surface => write!(f, "surfaceChart"),
+            ChartType::Surface3D => write!(f, "surface3DChart"),
+        }
+    }
+}
+
+impl FromStr for ChartType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "areaChart" => Ok(ChartType::Area),
+            "area3DChart" => Ok(ChartType::Area3D),
+            "barChart" => Ok(ChartType::Bar),
+            "bar3DChart" => Ok(ChartType::Bar3D),
+            "bubbleChart" => Ok(ChartType::Bubble),
+            "doughnutChart" => Ok(ChartType::Doughnut),
+            "lineChart" => Ok(ChartType::Line),
+            "line3DChart" => Ok(ChartType::Line3D),
+            "ofPieChart" => Ok(ChartType::OfPie),
+            "pieChart" => Ok(ChartType::Pie),
+            "pie3DChart" => Ok(ChartType::Pie3D),
+            "radarChart" => Ok(ChartType::Radar),
+            "scatterChart" => Ok(ChartType::Scatter),
+            "stockChart" => Ok(ChartType::Stock),
+            "surfaceChart" => Ok(ChartType::Surface),
+            "surface3DChart" => Ok(ChartType::Surface3D),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+// The width of a grid column of a table in twips. A fixed column keeps its width, and an
+// auto column starts with the width and is fitted to the content by Word.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", tag = "type", content = "width")]
+pub enum ColumnSpec {
+    Fixed(usize),
+    Auto(usize),
+}
+
+impl ColumnSpec {
+    pub fn width(&self) -> usize {
+        match *self {
+            ColumnSpec::Fixed(w) | ColumnSpec::Auto(w) => w,
+        }
+    }
+
+    pub fn is_fixed(&self) -> bool {
+        matches!(self, ColumnSpec::Fixed(_))
+    }
+}
@@ -0,0 +1,45 @@
+use serde::Serialize;
+
+//
+// The version of Word whose layout is emulated, i.e. the `compatibilityMode` compat setting.
+//
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum CompatibilityMode {
+    Word2007,
+    Word2010,
+    Word2013,
+}
+
+impl fmt::Display for CompatibilityMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CompatibilityMode::Word2007 => write!(f, "12"),
+            CompatibilityMode::Word2010 => write!(f, "14"),
+            CompatibilityMode::Word2013 => write!(f, "15"),
+        }
+    }
+}
+
+impl FromStr for CompatibilityMode {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "12" => Ok(CompatibilityMode::Word2007),
+            "14" => Ok(CompatibilityMode::Word2010),
+            "15" => Ok(CompatibilityMode::Word2013),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
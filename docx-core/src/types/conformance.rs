@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+//
+// The class of ISO/IEC 29500 the package conforms to. Strict documents are written with the
+// namespaces of `http://purl.oclc.org/ooxml` and without the spellings of the transitional
+// schemas such as `w:left` of indents.
+//
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum Conformance {
+    #[default]
+    Transitional,
+    Strict,
+}
+
+impl Conformance {
+    pub fn is_strict(&self) -> bool {
+        *self == Conformance::Strict
+    }
+}
+
+impl fmt::Display for Conformance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Conformance::Transitional => write!(f, "transitional"),
+            Conformance::Strict => write!(f, "strict"),
+        }
+    }
+}
+
+impl FromStr for Conformance {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "transitional" => Ok(Conformance::Transitional),
+            "strict" => Ok(Conformance::Strict),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
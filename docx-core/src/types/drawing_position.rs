@@ -1,5 +1,6 @@
 use serde::Serialize;
 use std::fmt;
+use std::str::FromStr;
 
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
@@ -39,6 +40,20 @@ impl fmt::Display for PicAlign {
     }
 }
 
+impl FromStr for PicAlign {
+    type Err = super::errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "left" => Ok(PicAlign::Left),
+            "right" => Ok(PicAlign::Right),
+            "center" => Ok(PicAlign::Center),
+            "bottom" => Ok(PicAlign::Bottom),
+            "top" => Ok(PicAlign::Top),
+            _ => Err(super::errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, PartialEq)]
 #[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
 #[cfg_attr(feature = "wasm", ts(export))]
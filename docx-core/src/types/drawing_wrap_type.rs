@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+//
+// The wrapping elements of CT_Anchor, such as <wp:wrapSquare>.
+//
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum DrawingWrapType {
+    None,
+    Square,
+    Tight,
+    Through,
+    TopAndBottom,
+}
+
+impl fmt::Display for DrawingWrapType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DrawingWrapType::None => write!(f, "wrapNone"),
+            DrawingWrapType::Square => write!(f, "wrapSquare"),
+            DrawingWrapType::Tight => write!(f, "wrapTight"),
+            DrawingWrapType::Through => write!(f, "wrapThrough"),
+            DrawingWrapType::TopAndBottom => write!(f, "wrapTopAndBottom"),
+        }
+    }
+}
+
+impl FromStr for DrawingWrapType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "wrapNone" => Ok(DrawingWrapType::None),
+            "wrapSquare" => Ok(DrawingWrapType::Square),
+            "wrapTight" => Ok(DrawingWrapType::Tight),
+            "wrapThrough" => Ok(DrawingWrapType::Through),
+            "wrapTopAndBottom" => Ok(DrawingWrapType::TopAndBottom),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
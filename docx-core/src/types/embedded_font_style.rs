@@ -0,0 +1,48 @@
+use serde::Serialize;
+
+//
+// The style of an embedded font, i.e. <w:embedRegular>, <w:embedBold> and so on.
+//
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum EmbeddedFontStyle {
+    Regular,
+    Bold,
+    Italic,
+    BoldItalic,
+}
+
+impl fmt::Display for EmbeddedFontStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EmbeddedFontStyle::Regular => write!(f, "regular"),
+            EmbeddedFontStyle::Bold => write!(f, "bold"),
+            EmbeddedFontStyle::Italic => write!(f, "italic"),
+            EmbeddedFontStyle::BoldItalic => write!(f, "boldItalic"),
+        }
+    }
+}
+
+impl FromStr for EmbeddedFontStyle {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "regular" => Ok(EmbeddedFontStyle::Regular),
+            "bold" => Ok(EmbeddedFontStyle::Bold),
+            "italic" => Ok(EmbeddedFontStyle::Italic),
+            "boldItalic" => Ok(EmbeddedFontStyle::BoldItalic),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
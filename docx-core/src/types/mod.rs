@@ -3,8 +3,15 @@ pub mod border_position;
 pub mod border_type;
 pub mod break_type;
 pub mod character_spacing_values;
+pub mod chart_type;
+pub mod column_spec;
+pub mod compatibility_mode;
+pub mod conformance;
 pub mod doc_grid_type;
+pub mod document_protection_type;
 pub mod drawing_position;
+pub mod drawing_wrap_type;
+pub mod embedded_font_style;
 pub mod emu;
 pub mod errors;
 pub mod field_char_type;
@@ -13,19 +20,23 @@ pub mod height_rule;
 pub mod hyperlink_type;
 pub mod level_suffix_type;
 pub mod line_spacing_type;
+pub mod package_type;
 pub mod page_margin;
 pub mod page_orientation_type;
 pub mod positional_tab_alignment_type;
 pub mod positional_tab_relative_to;
+pub mod preset_shape_type;
 pub mod relative_from_type;
 pub mod section_type;
 pub mod shd_type;
 pub mod special_indent_type;
+pub mod structured_data_tag_lock_type;
 pub mod style_type;
 pub mod tab_leader_type;
 pub mod tab_value_type;
 pub mod table_alignment_type;
 pub mod table_layout_type;
+pub mod table_style_override_type;
 pub mod text_alignment_type;
 pub mod text_direction_type;
 pub mod vert_align_type;
@@ -38,8 +49,15 @@ pub use border_position::*;
 pub use border_type::*;
 pub use break_type::*;
 pub use character_spacing_values::*;
+pub use chart_type::*;
+pub use column_spec::*;
+pub use compatibility_mode::*;
+pub use conformance::*;
 pub use doc_grid_type::*;
+pub use document_protection_type::*;
 pub use drawing_position::*;
+pub use drawing_wrap_type::*;
+pub use embedded_font_style::*;
 pub use emu::*;
 pub use errors::*;
 pub use field_char_type::*;
@@ -48,19 +66,23 @@ pub use height_rule::*;
 pub use hyperlink_type::*;
 pub use level_suffix_type::*;
 pub use line_spacing_type::*;
+pub use package_type::*;
 pub use page_margin::*;
 pub use page_orientation_type::*;
 pub use positional_tab_alignment_type::*;
 pub use positional_tab_relative_to::*;
+pub use preset_shape_type::*;
 pub use relative_from_type::*;
 pub use section_type::*;
 pub use shd_type::*;
 pub use special_indent_type::*;
+pub use structured_data_tag_lock_type::*;
 pub use style_type::*;
 pub use tab_leader_type::*;
 pub use tab_value_type::*;
 pub use table_alignment_type::*;
 pub use table_layout_type::*;
+pub use table_style_override_type::*;
 pub use text_alignment_type::*;
 pub use text_direction_type::*;
 pub use vert_align_type::*;
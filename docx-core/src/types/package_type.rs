@@ -0,0 +1,105 @@
+use serde::Serialize;
+
+//
+// The flavor of the package, told apart by the content type of the Main Document part.
+//
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum PackageType {
+    // .docx
+    #[default]
+    Document,
+    // .docm
+    MacroEnabledDocument,
+    // .dotx
+    Template,
+    // .dotm
+    MacroEnabledTemplate,
+}
+
+impl PackageType {
+    pub fn from_content_type(content_type: &str) -> Option<PackageType> {
+        match content_type {
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml" => {
+                Some(PackageType::Document)
+            }
+            "application/vnd.ms-word.document.macroEnabled.main+xml" => {
+                Some(PackageType::MacroEnabledDocument)
+            }
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.template.main+xml" => {
+                Some(PackageType::Template)
+            }
+            "application/vnd.ms-word.template.macroEnabledTemplate.main+xml" => {
+                Some(PackageType::MacroEnabledTemplate)
+            }
+            _ => None,
+        }
+    }
+
+    // The content type of the Main Document part.
+    pub fn content_type(&self) -> &'static str {
+        match *self {
+            PackageType::Document => {
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"
+            }
+            PackageType::MacroEnabledDocument => {
+                "application/vnd.ms-word.document.macroEnabled.main+xml"
+            }
+            PackageType::Template => {
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.template.main+xml"
+            }
+            PackageType::MacroEnabledTemplate => {
+                "application/vnd.ms-word.template.macroEnabledTemplate.main+xml"
+            }
+        }
+    }
+
+    pub fn is_macro_enabled(&self) -> bool {
+        matches!(
+            self,
+            PackageType::MacroEnabledDocument | PackageType::MacroEnabledTemplate
+        )
+    }
+
+    pub fn is_template(&self) -> bool {
+        matches!(
+            self,
+            PackageType::Template | PackageType::MacroEnabledTemplate
+        )
+    }
+}
+
+impl fmt::Display for PackageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PackageType::Document => write!(f, "document"),
+            PackageType::MacroEnabledDocument => write!(f, "macroEnabledDocument"),
+            PackageType::Template => write!(f, "template"),
+            PackageType::MacroEnabledTemplate => write!(f, "macroEnabledTemplate"),
+        }
+    }
+}
+
+impl FromStr for PackageType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "document" => Ok(PackageType::Document),
+            "macroEnabledDocument" => Ok(PackageType::MacroEnabledDocument),
+            "template" => Ok(PackageType::Template),
+            "macroEnabledTemplate" => Ok(PackageType::MacroEnabledTemplate),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
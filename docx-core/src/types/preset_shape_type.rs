@@ -0,0 +1,59 @@
+use serde::Serialize;
+
+//
+// The preset geometries of DrawingML which can be drawn as shapes, i.e. the prst of
+// <a:prstGeom>. An arrow is a straight line with an arrowhead at its end.
+//
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum PresetShapeType {
+    Rectangle,
+    RoundedRectangle,
+    Ellipse,
+    Line,
+    Arrow,
+}
+
+impl PresetShapeType {
+    // Lines are drawn by their outline only.
+    pub fn is_line(&self) -> bool {
+        matches!(self, PresetShapeType::Line | PresetShapeType::Arrow)
+    }
+}
+
+impl fmt::Display for PresetShapeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PresetShapeType::Rectangle => write!(f, "rect"),
+            PresetShapeType::RoundedRectangle => write!(f, "roundRect"),
+            PresetShapeType::Ellipse => write!(f, "ellipse"),
+            PresetShapeType::Line => write!(f, "line"),
+            PresetShapeType::Arrow => write!(f, "straightConnector1"),
+        }
+    }
+}
+
+impl FromStr for PresetShapeType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rect" => Ok(PresetShapeType::Rectangle),
+            "roundRect" => Ok(PresetShapeType::RoundedRectangle),
+            "ellipse" => Ok(PresetShapeType::Ellipse),
+            "line" => Ok(PresetShapeType::Line),
+            "straightConnector1" => Ok(PresetShapeType::Arrow),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+//
+// The locking of a content control, i.e. the value of <w:lock>.
+//
+
+use std::fmt;
+use std::str::FromStr;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use super::errors;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[cfg_attr(feature = "wasm", derive(ts_rs::TS))]
+#[cfg_attr(feature = "wasm", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub enum StructuredDataTagLockType {
+    // The control can't be deleted.
+    SdtLocked,
+    // The content can't be edited.
+    ContentLocked,
+    Unlocked,
+    // Neither the control can be deleted nor the content edited.
+    SdtContentLocked,
+}
+
+impl fmt::Display for StructuredDataTagLockType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            StructuredDataTagLockType::SdtLocked => write!(f, "sdtLocked"),
+            StructuredDataTagLockType::ContentLocked => write!(f, "contentLocked"),
+            StructuredDataTagLockType::Unlocked => write!(f, "unlocked"),
+            StructuredDataTagLockType::SdtContentLocked => write!(f, "sdtContentLocked"),
+        }
+    }
+}
+
+impl FromStr for StructuredDataTagLockType {
+    type Err = errors::TypeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sdtLocked" => Ok(StructuredDataTagLockType::SdtLocked),
+            "contentLocked" => Ok(StructuredDataTagLockType::ContentLocked),
+            "unlocked" => Ok(StructuredDataTagLockType::Unlocked),
+            "sdtContentLocked" => Ok(StructuredDataTagLockType::SdtContentLocked),
+            _ => Err(errors::TypeError::Unsupported(s.to_string())),
+        }
+    }
+}
@@ -1,16 +1,15 @@
 use super::XMLBuilder;
+use std::io::Write;
 
-impl XMLBuilder {
+impl<W: Write> XMLBuilder<W> {
     // Build XML declaration
     // i.e. <?xml version="1.0" encoding="UTF-8"?>
     pub(crate) fn declaration(mut self, standalone: Option<bool>) -> Self {
-        self.writer
-            .write(super::XmlEvent::StartDocument {
-                version: super::XmlVersion::Version10,
-                encoding: Some("UTF-8"),
-                standalone,
-            })
-            .expect("should write to buf");
+        self.write(super::XmlEvent::StartDocument {
+            version: super::XmlVersion::Version10,
+            encoding: Some("UTF-8"),
+            standalone,
+        });
         self
     }
 }
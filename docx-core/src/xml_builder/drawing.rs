@@ -1,7 +1,8 @@
 use super::XMLBuilder;
 use super::XmlEvent;
+use std::io::Write;
 
-impl XMLBuilder {
+impl<W: Write> XMLBuilder<W> {
     open!(
         open_wp_inline,
         "wp:inline",
@@ -31,7 +32,9 @@ impl XMLBuilder {
     closed!(wp_extent, "wp:extent", "cx", "cy");
     closed!(wp_effect_extent, "wp:effectExtent", "b", "l", "r", "t");
     closed!(wp_doc_pr, "wp:docPr", "id", "name");
+    closed!(wp_doc_pr_with_descr, "wp:docPr", "id", "name", "descr");
     open!(open_wp_c_nv_graphic_frame_pr, "wp:cNvGraphicFramePr");
+    closed!(c_chart, "c:chart", "xmlns:c", "r:id");
     closed!(
         a_graphic_frame_locks,
         "a:graphicFrameLocks",
@@ -46,4 +49,10 @@ impl XMLBuilder {
     closed_with_child!(align, "wp:align");
     closed!(wrap_none, "wp:wrapNone");
     closed!(wrap_square, "wp:wrapSquare", "wrapText");
+    open!(open_wrap_tight, "wp:wrapTight", "wrapText");
+    open!(open_wrap_through, "wp:wrapThrough", "wrapText");
+    closed!(wrap_top_and_bottom, "wp:wrapTopAndBottom");
+    open!(open_wrap_polygon, "wp:wrapPolygon", "edited");
+    closed!(wrap_polygon_start, "wp:start", "x", "y");
+    closed!(wrap_polygon_line_to, "wp:lineTo", "x", "y");
 }
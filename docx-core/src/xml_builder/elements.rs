@@ -1,14 +1,16 @@
 use super::XMLBuilder;
 use super::XmlEvent;
 use crate::types::*;
+use crate::Column;
 use crate::FrameProperty;
 use crate::TablePositionProperty;
+use std::io::Write;
 
-const EXPECT_MESSAGE: &str = "should write buf";
-
-impl XMLBuilder {
+impl<W: Write> XMLBuilder<W> {
     // i.e. <w:body... >
     open!(open_body, "w:body");
+    // i.e. <w:altChunk r:id="AltChunkId1" />
+    closed!(alt_chunk, "w:altChunk", "r:id");
     // i.e. <w:basedOn ... >
     closed_with_str!(based_on, "w:basedOn");
     // i.e. <w:t ... >
@@ -18,10 +20,8 @@ impl XMLBuilder {
         } else {
             "default"
         };
-        self.writer
-            .write(XmlEvent::start_element("w:t").attr("xml:space", space))
-            .expect(EXPECT_MESSAGE);
-        self.writer.write(text).expect(EXPECT_MESSAGE);
+        self.write(XmlEvent::start_element("w:t").attr("xml:space", space));
+        self.write(text);
         self.close()
     }
 
@@ -31,9 +31,7 @@ impl XMLBuilder {
         } else {
             "false".to_string()
         };
-        self.writer
-            .write(XmlEvent::start_element("w:snapToGrid").attr("w:val", &v))
-            .expect(EXPECT_MESSAGE);
+        self.write(XmlEvent::start_element("w:snapToGrid").attr("w:val", &v));
         self.close()
     }
 
@@ -78,7 +76,7 @@ impl XMLBuilder {
         if let Some(hint) = hint {
             w = w.attr("w:hint", hint);
         }
-        self.writer.write(w).expect(EXPECT_MESSAGE);
+        self.write(w);
         self.close()
     }
 
@@ -89,10 +87,8 @@ impl XMLBuilder {
         } else {
             "default"
         };
-        self.writer
-            .write(XmlEvent::start_element("w:delText").attr("xml:space", space))
-            .expect(EXPECT_MESSAGE);
-        self.writer.write(text).expect(EXPECT_MESSAGE);
+        self.write(XmlEvent::start_element("w:delText").attr("xml:space", space));
+        self.write(text);
         self.close()
     }
 
@@ -112,7 +108,7 @@ impl XMLBuilder {
         if let Some(store_item_id) = store_item_id {
             e = e.attr("w:storeItemID", store_item_id);
         }
-        self.writer.write(e).expect(EXPECT_MESSAGE);
+        self.write(e);
         self.close()
     }
 
@@ -132,7 +128,7 @@ impl XMLBuilder {
         }
         let s = format!("{}", history);
         e = e.attr("w:history", s.as_str());
-        self.writer.write(e).expect(EXPECT_MESSAGE);
+        self.write(e);
         self
     }
 
@@ -154,6 +150,29 @@ impl XMLBuilder {
     open!(open_structured_tag_content, "w:sdtContent");
     open!(open_structured_tag_property, "w:sdtPr");
     closed_with_str!(alias, "w:alias");
+    closed_with_str!(sdt_tag, "w:tag");
+    closed_with_str!(sdt_lock, "w:lock");
+    open!(open_sdt_placeholder, "w:placeholder");
+    closed_with_str!(doc_part, "w:docPart");
+    closed!(showing_placeholder, "w:showingPlcHdr");
+    open!(open_sdt_check_box, "w14:checkbox");
+    closed_with_str!(sdt_checked, "w14:checked");
+    open!(open_drop_down_list_control, "w:dropDownList");
+    open!(open_combo_box, "w:comboBox");
+    closed!(list_item, "w:listItem", "w:displayText", "w:value");
+
+    open!(open_form_field_data, "w:ffData");
+    closed!(form_field_help_text, "w:helpText", "w:type", "w:val");
+    closed!(form_field_status_text, "w:statusText", "w:type", "w:val");
+    open!(open_check_box, "w:checkBox");
+    closed!(size_auto, "w:sizeAuto");
+    closed_with_str!(form_field_default, "w:default");
+    closed_with_str!(checked, "w:checked");
+    open!(open_drop_down_list, "w:ddList");
+    closed_with_usize!(drop_down_result, "w:result");
+    closed_with_str!(list_entry, "w:listEntry");
+    open!(open_text_input, "w:textInput");
+    closed_with_usize!(max_length, "w:maxLength");
 
     closed_paragraph_border_el!(paragraph_border_top, "w:top");
     closed_paragraph_border_el!(paragraph_border_left, "w:left");
@@ -182,6 +201,12 @@ impl XMLBuilder {
     closed_with_str!(text_alignment, "w:textAlignment");
 
     closed!(field_character, "w:fldChar", "w:fldCharType", "w:dirty");
+    open!(
+        open_field_character,
+        "w:fldChar",
+        "w:fldCharType",
+        "w:dirty"
+    );
 
     open!(open_instr_text, "w:instrText");
     open!(open_delete_instr_text, "w:delInstrText");
@@ -201,13 +226,11 @@ impl XMLBuilder {
     // Build w:style element
     // i.e. <w:style ... >
     pub(crate) fn open_style(mut self, style_type: StyleType, id: &str) -> Self {
-        self.writer
-            .write(
-                XmlEvent::start_element("w:style")
-                    .attr("w:type", &style_type.to_string())
-                    .attr("w:styleId", id),
-            )
-            .expect(EXPECT_MESSAGE);
+        self.write(
+            XmlEvent::start_element("w:style")
+                .attr("w:type", &style_type.to_string())
+                .attr("w:styleId", id),
+        );
         self
     }
     // i.e. <w:next ... >
@@ -218,6 +241,27 @@ impl XMLBuilder {
     // i.e. <w:color ... >
     closed_with_str!(color, "w:color");
 
+    pub(crate) fn color_with_theme(
+        mut self,
+        val: &str,
+        theme_color: Option<&String>,
+        theme_tint: Option<&String>,
+        theme_shade: Option<&String>,
+    ) -> Self {
+        let mut w = XmlEvent::start_element("w:color").attr("w:val", val);
+        if let Some(theme_color) = theme_color {
+            w = w.attr("w:themeColor", theme_color);
+        }
+        if let Some(theme_tint) = theme_tint {
+            w = w.attr("w:themeTint", theme_tint);
+        }
+        if let Some(theme_shade) = theme_shade {
+            w = w.attr("w:themeShade", theme_shade);
+        }
+        self.write(w);
+        self.close()
+    }
+
     // i.e. <w:highlight ... >
     closed_with_str!(highlight, "w:highlight");
 
@@ -246,24 +290,20 @@ impl XMLBuilder {
         }
 
         match special_indent {
-            Some(SpecialIndentType::FirstLine(v)) => self
-                .writer
-                .write(base.attr("w:firstLine", &format!("{}", v)))
-                .expect(EXPECT_MESSAGE),
-            Some(SpecialIndentType::Hanging(v)) => self
-                .writer
-                .write(base.attr("w:hanging", &format!("{}", v)))
-                .expect(EXPECT_MESSAGE),
-            _ => self.writer.write(base).expect(EXPECT_MESSAGE),
+            Some(SpecialIndentType::FirstLine(v)) => {
+                self.write(base.attr("w:firstLine", &format!("{}", v)))
+            }
+            Some(SpecialIndentType::Hanging(v)) => {
+                self.write(base.attr("w:hanging", &format!("{}", v)))
+            }
+            _ => self.write(base),
         };
         self.close()
     }
 
     // i.e. <w:spacing ... >
     pub(crate) fn spacing(mut self, s: i32) -> Self {
-        self.writer
-            .write(XmlEvent::start_element("w:spacing").attr("w:val", &format!("{}", s)))
-            .expect(EXPECT_MESSAGE);
+        self.write(XmlEvent::start_element("w:spacing").attr("w:val", &format!("{}", s)));
         self.close()
     }
 
@@ -317,7 +357,7 @@ impl XMLBuilder {
                 }
             }
         }
-        self.writer.write(xml_event).expect(EXPECT_MESSAGE);
+        self.write(xml_event);
         self.close()
     }
 
@@ -331,15 +371,30 @@ impl XMLBuilder {
     open!(open_table_row_property, "w:trPr");
     open!(open_table_cell, "w:tc");
     open!(open_table_cell_property, "w:tcPr");
+    open!(open_table_style_property, "w:tblStylePr", "w:type");
     open!(open_table_cell_borders, "w:tcBorders");
     open!(open_table_borders, "w:tblBorders");
     open!(open_table_cell_margins, "w:tblCellMar");
     open!(open_cell_margins, "w:tcMar");
 
     closed!(table_layout, "w:tblLayout", "w:type");
+    closed!(
+        table_look,
+        "w:tblLook",
+        "w:val",
+        "w:firstRow",
+        "w:lastRow",
+        "w:firstColumn",
+        "w:lastColumn",
+        "w:noHBand",
+        "w:noVBand"
+    );
     closed_with_str!(table_style, "w:tblStyle");
+    closed_with_str!(table_caption, "w:tblCaption");
+    closed_with_str!(table_description, "w:tblDescription");
     closed_w_with_type_el!(table_width, "w:tblW");
     closed_w_with_type_el!(table_indent, "w:tblInd");
+    closed_w_with_type_el!(table_cell_spacing, "w:tblCellSpacing");
     closed_w_with_type_el!(grid_column, "w:gridCol");
     closed_w_with_type_el!(table_cell_width, "w:tcW");
 
@@ -365,7 +420,36 @@ impl XMLBuilder {
 
     closed_border_el!(text_border, "w:bdr");
 
-    closed!(shd, "w:shd", "w:val", "w:color", "w:fill");
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn shd(
+        mut self,
+        val: &str,
+        color: &str,
+        fill: &str,
+        theme_color: Option<&String>,
+        theme_fill: Option<&String>,
+        theme_fill_tint: Option<&String>,
+        theme_fill_shade: Option<&String>,
+    ) -> Self {
+        let mut w = XmlEvent::start_element("w:shd")
+            .attr("w:val", val)
+            .attr("w:color", color)
+            .attr("w:fill", fill);
+        if let Some(theme_color) = theme_color {
+            w = w.attr("w:themeColor", theme_color);
+        }
+        if let Some(theme_fill) = theme_fill {
+            w = w.attr("w:themeFill", theme_fill);
+        }
+        if let Some(theme_fill_tint) = theme_fill_tint {
+            w = w.attr("w:themeFillTint", theme_fill_tint);
+        }
+        if let Some(theme_fill_shade) = theme_fill_shade {
+            w = w.attr("w:themeFillShade", theme_fill_shade);
+        }
+        self.write(w);
+        self.close()
+    }
 
     closed!(tab_with_pos, "w:tab", "w:val", "w:pos");
 
@@ -405,6 +489,8 @@ impl XMLBuilder {
 
     open!(open_insert, "w:ins", "w:id", "w:author", "w:date");
     open!(open_delete, "w:del", "w:id", "w:author", "w:date");
+    open!(open_move_to, "w:moveTo", "w:id", "w:author", "w:date");
+    open!(open_move_from, "w:moveFrom", "w:id", "w:author", "w:date");
     open!(
         open_paragraph_property_change,
         "w:pPrChange",
@@ -414,6 +500,7 @@ impl XMLBuilder {
     );
     // cantSplit for table row
     closed!(cant_split, "w:cantSplit");
+    closed!(table_header, "w:tblHeader");
 
     closed!(bookmark_start, "w:bookmarkStart", "w:id", "w:name");
     closed!(bookmark_end, "w:bookmarkEnd", "w:id");
@@ -454,6 +541,23 @@ impl XMLBuilder {
 
     // shape
     open!(open_wp_shape, "wps:wsp");
+    closed!(wps_c_nv_sp_pr, "wps:cNvSpPr", "txBox");
+    closed!(wps_shape_c_nv_sp_pr, "wps:cNvSpPr");
+    closed!(wps_body_pr, "wps:bodyPr");
+    closed!(a_tail_end, "a:tailEnd", "type");
+    open!(open_wps_sp_pr, "wps:spPr");
+    open!(
+        open_wps_body_pr,
+        "wps:bodyPr",
+        "wrap",
+        "lIns",
+        "tIns",
+        "rIns",
+        "bIns",
+        "anchor"
+    );
+    closed!(a_no_fill, "a:noFill");
+    closed!(a_no_autofit, "a:noAutofit");
     open!(open_wp_text_box, "wps:txbx");
     open!(open_text_box_content, "w:txbxContent");
 
@@ -474,6 +578,11 @@ impl XMLBuilder {
         "w:val"
     );
     closed!(use_fe_layout, "w:useFELayout");
+    // Other flags of `w:compat`, kept from read documents.
+    pub(crate) fn compat_option(mut self, name: &str) -> Self {
+        self.write(XmlEvent::start_element(format!("w:{}", name).as_str()));
+        self.close()
+    }
     closed!(
         compat_setting,
         "w:compatSetting",
@@ -484,6 +593,7 @@ impl XMLBuilder {
 
     closed!(keep_next, "w:keepNext");
     closed!(keep_lines, "w:keepLines");
+    closed!(bidi, "w:bidi");
     closed!(page_break_before, "w:pageBreakBefore");
     closed!(widow_control, "w:widowControl", "w:val");
 
@@ -496,6 +606,12 @@ impl XMLBuilder {
     closed_with_str!(start_override, "w:startOverride");
 
     closed!(doc_id, "w15:docId", "w15:val");
+    closed!(track_revisions, "w:trackRevisions");
+    closed!(embed_true_type_fonts, "w:embedTrueTypeFonts");
+
+    open!(open_rsids, "w:rsids");
+    closed_with_str!(rsid_root, "w:rsidRoot");
+    closed_with_str!(rsid, "w:rsid");
 
     open!(open_doc_vars, "w:docVars");
     closed!(doc_var, "w:docVar", "w:name", "w:val");
@@ -536,6 +652,7 @@ impl XMLBuilder {
         "ds:itemID"
     );
     open!(open_data_store_schema_refs, "ds:schemaRefs");
+    closed!(data_store_schema_ref, "ds:schemaRef", "ds:uri");
 
     // CommentExtended
     // w15:commentEx w15:paraId="00000001" w15:paraIdParent="57D1BD7C" w15:done="0"
@@ -546,23 +663,50 @@ impl XMLBuilder {
         parent_paragraph_id: &Option<String>,
     ) -> Self {
         if let Some(parent_paragraph_id) = parent_paragraph_id {
-            self.writer
-                .write(
-                    XmlEvent::start_element("w15:commentEx")
-                        .attr("w15:paraId", paragraph_id)
-                        .attr("w15:paraIdParent", parent_paragraph_id)
-                        .attr("w15:done", &format!("{}", done as usize)),
-                )
-                .expect(EXPECT_MESSAGE);
-            return self.close();
-        }
-        self.writer
-            .write(
+            self.write(
                 XmlEvent::start_element("w15:commentEx")
                     .attr("w15:paraId", paragraph_id)
+                    .attr("w15:paraIdParent", parent_paragraph_id)
                     .attr("w15:done", &format!("{}", done as usize)),
-            )
-            .expect(EXPECT_MESSAGE);
+            );
+            return self.close();
+        }
+        self.write(
+            XmlEvent::start_element("w15:commentEx")
+                .attr("w15:paraId", paragraph_id)
+                .attr("w15:done", &format!("{}", done as usize)),
+        );
+        self.close()
+    }
+
+    // cols with a line between the columns, or with columns of different widths.
+    pub(crate) fn columns_with_definitions(
+        mut self,
+        space: &str,
+        num: &str,
+        sep: bool,
+        columns: &[Column],
+    ) -> Self {
+        let mut w = XmlEvent::start_element("w:cols")
+            .attr("w:space", space)
+            .attr("w:num", num);
+        if sep {
+            w = w.attr("w:sep", "1");
+        }
+        if !columns.is_empty() {
+            w = w.attr("w:equalWidth", "0");
+        }
+        self.write(w);
+        for c in columns {
+            let width = format!("{}", c.width);
+            let space = format!("{}", c.space);
+            self.write(
+                XmlEvent::start_element("w:col")
+                    .attr("w:w", &width)
+                    .attr("w:space", &space),
+            );
+            self = self.close();
+        }
         self.close()
     }
 
@@ -583,7 +727,7 @@ impl XMLBuilder {
         if char_space.is_some() {
             w = w.attr("w:charSpace", &char_space_string);
         }
-        self.writer.write(w).expect(EXPECT_MESSAGE);
+        self.write(w);
 
         self.close()
     }
@@ -643,7 +787,7 @@ impl XMLBuilder {
         if prop.h.is_some() {
             w = w.attr("w:h", &h);
         }
-        self.writer.write(w).expect(EXPECT_MESSAGE);
+        self.write(w);
         self.close()
     }
 
@@ -690,7 +834,7 @@ impl XMLBuilder {
             w = w.attr("w:tblpY", &v);
         }
 
-        self.writer.write(w).expect(EXPECT_MESSAGE);
+        self.write(w);
         self.close()
     }
 
@@ -704,7 +848,7 @@ impl XMLBuilder {
         if chap_style.is_some() {
             w = w.attr("w:chapStyle", &chap_style_string);
         }
-        self.writer.write(w).expect(EXPECT_MESSAGE);
+        self.write(w);
         self.close()
     }
 
@@ -740,7 +884,7 @@ impl XMLBuilder {
         if pos.is_some() {
             t = t.attr("w:pos", &pos_string);
         }
-        self.writer.write(t).expect(EXPECT_MESSAGE);
+        self.write(t);
 
         self.close()
     }
@@ -761,7 +905,7 @@ impl XMLBuilder {
         t = t.attr("w:relativeTo", &relative_to_string);
         t = t.attr("w:leader", &leader_string);
 
-        self.writer.write(t).expect(EXPECT_MESSAGE);
+        self.write(t);
 
         self.close()
     }
@@ -769,14 +913,22 @@ impl XMLBuilder {
     // FootnoteReference
     // w:footnoteReference w:id="1"
     pub(crate) fn footnote_reference(mut self, id: usize) -> Self {
-        self.writer
-            .write(XmlEvent::start_element("w:footnoteReference").attr("w:id", &id.to_string()))
-            .expect(EXPECT_MESSAGE);
+        self.write(XmlEvent::start_element("w:footnoteReference").attr("w:id", &id.to_string()));
         self.close()
     }
 
     // Footnotes
     open!(open_footnote, "w:footnote", "w:id");
+
+    // EndnoteReference
+    // w:endnoteReference w:id="1"
+    pub(crate) fn endnote_reference(mut self, id: usize) -> Self {
+        self.write(XmlEvent::start_element("w:endnoteReference").attr("w:id", &id.to_string()));
+        self.close()
+    }
+
+    // Endnotes
+    open!(open_endnote, "w:endnote", "w:id");
 }
 
 #[cfg(test)]
@@ -1,21 +1,41 @@
 use super::XMLBuilder;
 use super::XmlEvent;
+use crate::types::EmbeddedFontStyle;
+use std::io::Write;
 
-impl XMLBuilder {
+impl<W: Write> XMLBuilder<W> {
     pub(crate) fn open_fonts(mut self) -> Self {
-        self.writer
-            .write(
-                XmlEvent::start_element("w:fonts")
-                    .attr(
-                        "xmlns:w",
-                        "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
-                    )
-                    .attr(
-                        "xmlns:r",
-                        "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
-                    ),
-            )
-            .expect("should write to buf");
+        self.write(
+            XmlEvent::start_element("w:fonts")
+                .attr(
+                    "xmlns:w",
+                    "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+                )
+                .attr(
+                    "xmlns:r",
+                    "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+                ),
+        );
         self
     }
+
+    pub(crate) fn embed_font(
+        mut self,
+        style: EmbeddedFontStyle,
+        id: &str,
+        font_key: Option<&str>,
+    ) -> Self {
+        let name = match style {
+            EmbeddedFontStyle::Regular => "w:embedRegular",
+            EmbeddedFontStyle::Bold => "w:embedBold",
+            EmbeddedFontStyle::Italic => "w:embedItalic",
+            EmbeddedFontStyle::BoldItalic => "w:embedBoldItalic",
+        };
+        let mut e = XmlEvent::start_element(name).attr("r:id", id);
+        if let Some(key) = font_key {
+            e = e.attr("w:fontKey", key);
+        }
+        self.write(e);
+        self.close()
+    }
 }
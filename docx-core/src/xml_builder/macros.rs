@@ -2,56 +2,46 @@ macro_rules! open {
     ($name: ident, $el_name: expr) => {
         #[allow(dead_code)]
         pub(crate) fn $name(mut self) -> Self {
-            self.writer
-                .write(XmlEvent::start_element($el_name))
-                .expect("should write to buf");
+            self.write(XmlEvent::start_element($el_name));
             self
         }
     };
     ($name: ident, $el_name: expr, $attr0: expr) => {
         pub(crate) fn $name(mut self, arg0: &str) -> Self {
-            self.writer
-                .write(XmlEvent::start_element($el_name).attr($attr0, arg0))
-                .expect("should write to buf");
+            self.write(XmlEvent::start_element($el_name).attr($attr0, arg0));
             self
         }
     };
     ($name: ident, $el_name: expr, $attr0: expr, $attr1: expr) => {
         pub(crate) fn $name(mut self, arg0: &str, arg1: &str) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1),
+            );
             self
         }
     };
     ($name: ident, $el_name: expr, $attr0: expr, $attr1: expr, $attr2: expr) => {
         pub(crate) fn $name(mut self, arg0: &str, arg1: &str, arg2: &str) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2),
+            );
             self
         }
     };
     ($name: ident, $el_name: expr, $attr0: expr, $attr1: expr, $attr2: expr, $attr3: expr) => {
         pub(crate) fn $name(mut self, arg0: &str, arg1: &str, arg2: &str, arg3: &str) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3),
+            );
             self
         }
     };
@@ -64,16 +54,14 @@ macro_rules! open {
             arg3: &str,
             arg4: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4),
+            );
             self
         }
     };
@@ -87,17 +75,15 @@ macro_rules! open {
             arg4: &str,
             arg5: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4)
-                        .attr($attr5, arg5),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4)
+                    .attr($attr5, arg5),
+            );
             self
         }
     };
@@ -112,18 +98,16 @@ macro_rules! open {
             arg5: &str,
             arg6: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4)
-                        .attr($attr5, arg5)
-                        .attr($attr6, arg6),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4)
+                    .attr($attr5, arg5)
+                    .attr($attr6, arg6),
+            );
             self
         }
     };
@@ -139,19 +123,17 @@ macro_rules! open {
             arg6: &str,
             arg7: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4)
-                        .attr($attr5, arg5)
-                        .attr($attr6, arg6)
-                        .attr($attr7, arg7),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4)
+                    .attr($attr5, arg5)
+                    .attr($attr6, arg6)
+                    .attr($attr7, arg7),
+            );
             self
         }
     };
@@ -168,20 +150,18 @@ macro_rules! open {
             arg7: &str,
             arg8: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4)
-                        .attr($attr5, arg5)
-                        .attr($attr6, arg6)
-                        .attr($attr7, arg7)
-                        .attr($attr8, arg8),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4)
+                    .attr($attr5, arg5)
+                    .attr($attr6, arg6)
+                    .attr($attr7, arg7)
+                    .attr($attr8, arg8),
+            );
             self
         }
     };
@@ -200,21 +180,19 @@ macro_rules! open {
             arg8: &str,
             arg9: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4)
-                        .attr($attr5, arg5)
-                        .attr($attr6, arg6)
-                        .attr($attr7, arg7)
-                        .attr($attr8, arg8)
-                        .attr($attr9, arg9),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4)
+                    .attr($attr5, arg5)
+                    .attr($attr6, arg6)
+                    .attr($attr7, arg7)
+                    .attr($attr8, arg8)
+                    .attr($attr9, arg9),
+            );
             self
         }
     };
@@ -233,22 +211,20 @@ macro_rules! open {
             arg9: &str,
             arg10: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4)
-                        .attr($attr5, arg5)
-                        .attr($attr6, arg6)
-                        .attr($attr7, arg7)
-                        .attr($attr8, arg8)
-                        .attr($attr9, arg9)
-                        .attr($attr10, arg10),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4)
+                    .attr($attr5, arg5)
+                    .attr($attr6, arg6)
+                    .attr($attr7, arg7)
+                    .attr($attr8, arg8)
+                    .attr($attr9, arg9)
+                    .attr($attr10, arg10),
+            );
             self
         }
     };
@@ -258,46 +234,38 @@ macro_rules! closed_with_child {
     ($name: ident, $el_name: expr) => {
         #[allow(dead_code)]
         pub(crate) fn $name(mut self, child: &str) -> Self {
-            self.writer
-                .write(XmlEvent::start_element($el_name))
-                .expect("should write to buf");
-            self.writer.write(child).expect("should write to buf");
+            self.write(XmlEvent::start_element($el_name));
+            self.write(child);
             self.close()
         }
     };
     ($name: ident, $el_name: expr, $attr0: expr) => {
         pub(crate) fn $name(mut self, arg0: &str, child: &str) -> Self {
-            self.writer
-                .write(XmlEvent::start_element($el_name).attr($attr0, arg0))
-                .expect("should write to buf");
-            self.writer.write(child).expect("should write to buf");
+            self.write(XmlEvent::start_element($el_name).attr($attr0, arg0));
+            self.write(child);
             self.close()
         }
     };
     ($name: ident, $el_name: expr, $attr0: expr, $attr1: expr) => {
         pub(crate) fn $name(mut self, arg0: &str, arg1: &str, child: &str) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1),
-                )
-                .expect("should write to buf");
-            self.writer.write(child).expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1),
+            );
+            self.write(child);
             self.close()
         }
     };
     ($name: ident, $el_name: expr, $attr0: expr, $attr1: expr, $attr2: expr) => {
         pub(crate) fn $name(mut self, arg0: &str, arg1: &str, arg2: &str, child: &str) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2),
-                )
-                .expect("should write to buf");
-            self.writer.write(child).expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2),
+            );
+            self.write(child);
             self.close()
         }
     };
@@ -307,9 +275,7 @@ macro_rules! closed {
     ($name: ident, $el_name: expr) => {
         #[allow(clippy::wrong_self_convention)]
         pub(crate) fn $name(mut self) -> Self {
-            self.writer
-                .write(XmlEvent::start_element($el_name))
-                .expect("should write to buf");
+            self.write(XmlEvent::start_element($el_name));
             self.close()
         }
     };
@@ -317,9 +283,7 @@ macro_rules! closed {
         #[allow(clippy::wrong_self_convention)]
 
         pub(crate) fn $name(mut self, arg0: &str) -> Self {
-            self.writer
-                .write(XmlEvent::start_element($el_name).attr($attr0, arg0))
-                .expect("should write to buf");
+            self.write(XmlEvent::start_element($el_name).attr($attr0, arg0));
             self.close()
         }
     };
@@ -327,40 +291,34 @@ macro_rules! closed {
         #[allow(dead_code)]
         #[allow(clippy::wrong_self_convention)]
         pub(crate) fn $name(mut self, arg0: &str, arg1: &str) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1),
+            );
             self.close()
         }
     };
     ($name: ident, $el_name: expr, $attr0: expr, $attr1: expr, $attr2: expr) => {
         pub(crate) fn $name(mut self, arg0: &str, arg1: &str, arg2: &str) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2),
+            );
             self.close()
         }
     };
     ($name: ident, $el_name: expr, $attr0: expr, $attr1: expr, $attr2: expr, $attr3: expr) => {
         pub(crate) fn $name(mut self, arg0: &str, arg1: &str, arg2: &str, arg3: &str) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3),
+            );
             self.close()
         }
     };
@@ -373,16 +331,14 @@ macro_rules! closed {
             arg3: &str,
             arg4: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4),
+            );
             self.close()
         }
     };
@@ -396,17 +352,15 @@ macro_rules! closed {
             arg4: &str,
             arg5: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4)
-                        .attr($attr5, arg5),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4)
+                    .attr($attr5, arg5),
+            );
             self.close()
         }
     };
@@ -422,18 +376,16 @@ macro_rules! closed {
             arg5: &str,
             arg6: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4)
-                        .attr($attr5, arg5)
-                        .attr($attr6, arg6),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4)
+                    .attr($attr5, arg5)
+                    .attr($attr6, arg6),
+            );
             self.close()
         }
     };
@@ -450,19 +402,17 @@ macro_rules! closed {
             arg6: &str,
             arg7: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4)
-                        .attr($attr5, arg5)
-                        .attr($attr6, arg6)
-                        .attr($attr7, arg7),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4)
+                    .attr($attr5, arg5)
+                    .attr($attr6, arg6)
+                    .attr($attr7, arg7),
+            );
             self.close()
         }
     };
@@ -480,20 +430,18 @@ macro_rules! closed {
             arg7: &str,
             arg8: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr($attr0, arg0)
-                        .attr($attr1, arg1)
-                        .attr($attr2, arg2)
-                        .attr($attr3, arg3)
-                        .attr($attr4, arg4)
-                        .attr($attr5, arg5)
-                        .attr($attr6, arg6)
-                        .attr($attr7, arg7)
-                        .attr($attr8, arg8),
-                )
-                .expect("should write to buf");
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr($attr0, arg0)
+                    .attr($attr1, arg1)
+                    .attr($attr2, arg2)
+                    .attr($attr3, arg3)
+                    .attr($attr4, arg4)
+                    .attr($attr5, arg5)
+                    .attr($attr6, arg6)
+                    .attr($attr7, arg7)
+                    .attr($attr8, arg8),
+            );
             self.close()
         }
     };
@@ -503,9 +451,7 @@ macro_rules! closed_with_str {
     ($name: ident, $el_name: expr) => {
         #[allow(dead_code)]
         pub(crate) fn $name(mut self, val: &str) -> Self {
-            self.writer
-                .write(XmlEvent::start_element($el_name).attr("w:val", val))
-                .expect("should write to buf");
+            self.write(XmlEvent::start_element($el_name).attr("w:val", val));
             self.close()
         }
     };
@@ -514,9 +460,7 @@ macro_rules! closed_with_str {
 macro_rules! closed_with_usize {
     ($name: ident, $el_name: expr) => {
         pub(crate) fn $name(mut self, val: usize) -> Self {
-            self.writer
-                .write(XmlEvent::start_element($el_name).attr("w:val", &format!("{}", val)))
-                .expect("should write to buf");
+            self.write(XmlEvent::start_element($el_name).attr("w:val", &format!("{}", val)));
             self.close()
         }
     };
@@ -525,9 +469,7 @@ macro_rules! closed_with_usize {
 macro_rules! closed_with_isize {
     ($name: ident, $el_name: expr) => {
         pub(crate) fn $name(mut self, val: isize) -> Self {
-            self.writer
-                .write(XmlEvent::start_element($el_name).attr("w:val", &format!("{}", val)))
-                .expect("should write to buf");
+            self.write(XmlEvent::start_element($el_name).attr("w:val", &format!("{}", val)));
             self.close()
         }
     };
@@ -536,13 +478,11 @@ macro_rules! closed_with_isize {
 macro_rules! closed_w_with_type_el {
     ($name: ident, $el_name: expr) => {
         pub(crate) fn $name(mut self, w: i32, t: WidthType) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr("w:w", &format!("{}", w))
-                        .attr("w:type", &t.to_string()),
-                )
-                .expect(EXPECT_MESSAGE);
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr("w:w", &format!("{}", w))
+                    .attr("w:type", &t.to_string()),
+            );
             self.close()
         }
     };
@@ -557,15 +497,13 @@ macro_rules! closed_border_el {
             space: usize,
             color: &str,
         ) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr("w:val", &val.to_string())
-                        .attr("w:sz", &format!("{}", size))
-                        .attr("w:space", &format!("{}", space))
-                        .attr("w:color", color),
-                )
-                .expect(EXPECT_MESSAGE);
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr("w:val", &val.to_string())
+                    .attr("w:sz", &format!("{}", size))
+                    .attr("w:space", &format!("{}", space))
+                    .attr("w:color", color),
+            );
             self.close()
         }
     };
@@ -574,15 +512,13 @@ macro_rules! closed_border_el {
 macro_rules! closed_paragraph_border_el {
     ($name: ident, $ el_name: expr) => {
         pub(crate) fn $name<'a>(mut self, val: &str, space: &str, size: &str, color: &str) -> Self {
-            self.writer
-                .write(
-                    XmlEvent::start_element($el_name)
-                        .attr("w:val", val)
-                        .attr("w:space", space)
-                        .attr("w:sz", size)
-                        .attr("w:color", color),
-                )
-                .expect(EXPECT_MESSAGE);
+            self.write(
+                XmlEvent::start_element($el_name)
+                    .attr("w:val", val)
+                    .attr("w:space", space)
+                    .attr("w:sz", size)
+                    .attr("w:color", color),
+            );
             self.close()
         }
     };
@@ -0,0 +1,40 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+use std::io::Write;
+
+// Office Math Markup Language (22.1 Math)
+impl<W: Write> XMLBuilder<W> {
+    open!(open_math, "m:oMath", "xmlns:m");
+    open!(open_math_paragraph, "m:oMathPara", "xmlns:m");
+    open!(open_display_math, "m:oMath");
+    open!(open_math_run, "m:r");
+    open!(open_math_fraction, "m:f");
+    open!(open_math_numerator, "m:num");
+    open!(open_math_denominator, "m:den");
+    open!(open_math_super_script, "m:sSup");
+    open!(open_math_sub_script, "m:sSub");
+    open!(open_math_sub_super_script, "m:sSubSup");
+    open!(open_math_sup, "m:sup");
+    open!(open_math_sub, "m:sub");
+    open!(open_math_base, "m:e");
+    open!(open_math_radical, "m:rad");
+    open!(open_math_radical_property, "m:radPr");
+    open!(open_math_degree, "m:deg");
+    open!(open_math_nary, "m:nary");
+    open!(open_math_nary_property, "m:naryPr");
+    open!(open_math_delimiter, "m:d");
+    open!(open_math_delimiter_property, "m:dPr");
+    open!(open_math_matrix, "m:m");
+    open!(open_math_matrix_row, "m:mr");
+    closed!(math_deg_hide, "m:degHide", "m:val");
+    closed!(math_chr, "m:chr", "m:val");
+    closed!(math_beg_chr, "m:begChr", "m:val");
+    closed!(math_end_chr, "m:endChr", "m:val");
+    closed!(math_sep_chr, "m:sepChr", "m:val");
+
+    pub(crate) fn math_text(mut self, text: &str) -> Self {
+        self.write(XmlEvent::start_element("m:t").attr("xml:space", "preserve"));
+        self.write(text);
+        self.close()
+    }
+}
@@ -6,13 +6,16 @@ mod comments_extended;
 mod core_properties;
 mod custom_properties;
 mod declaration;
+mod digital_signature;
 mod document;
 mod drawing;
 mod elements;
+mod endnotes;
 mod fonts;
 mod footer;
 mod footnotes;
 mod header;
+mod math;
 mod numbering;
 mod pic;
 mod properties;
@@ -20,60 +23,85 @@ mod relationship;
 mod settings;
 
 mod styles;
+mod theme;
+mod web_settings;
 
 use crate::BuildXML;
 
+use std::io::{self, Write};
 use std::str;
 use xml::common::XmlVersion;
-use xml::writer::{EmitterConfig, EventWriter, XmlEvent};
+use xml::writer::{EmitterConfig, Error as EmitterError, EventWriter, XmlEvent};
 
 pub use elements::*;
 
-pub struct XMLBuilder {
-    writer: EventWriter<Vec<u8>>,
+// Children are written into the same writer as their parents, so an element is streamed
+// without building its children into buffers of their own.
+pub struct XMLBuilder<W: Write = Vec<u8>> {
+    writer: EventWriter<W>,
+    // The first error of the writer. Later writes are skipped and it is returned by
+    // `into_inner`.
+    error: Option<io::Error>,
 }
 
 impl XMLBuilder {
     pub(crate) fn new() -> XMLBuilder {
-        let buf = Vec::new();
+        XMLBuilder::from_writer(Vec::new())
+    }
+
+    pub(crate) fn build(self) -> Vec<u8> {
+        self.into_inner().expect("should write to buf")
+    }
+}
+
+impl<W: Write> XMLBuilder<W> {
+    pub(crate) fn from_writer(w: W) -> XMLBuilder<W> {
         let mut config = EmitterConfig::new()
             .write_document_declaration(false)
             .perform_indent(true);
         config.perform_escaping = false;
-        let writer = config.create_writer(buf);
-        XMLBuilder { writer }
+        let writer = config.create_writer(w);
+        XMLBuilder {
+            writer,
+            error: None,
+        }
+    }
+
+    fn write<'a>(&mut self, e: impl Into<XmlEvent<'a>>) {
+        if self.error.is_some() {
+            return;
+        }
+        match self.writer.write(e) {
+            Ok(()) => {}
+            Err(EmitterError::Io(e)) => self.error = Some(e),
+            Err(e) => panic!("should write xml: {}", e),
+        }
     }
 
     // Build types element
     // i.e. <Types xmlns="http://...">
     pub(crate) fn open_types(mut self, uri: &str) -> Self {
-        self.writer
-            .write(XmlEvent::start_element("Types").attr("xmlns", uri))
-            .expect("should write to buf");
+        self.write(XmlEvent::start_element("Types").attr("xmlns", uri));
         self
     }
 
     // Build Override element
     // i.e. <Override PartName="/_rels/.rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
     pub(crate) fn add_override(mut self, name: &str, content_type: &str) -> Self {
-        self.writer
-            .write(
-                XmlEvent::start_element("Override")
-                    .attr("PartName", name)
-                    .attr("ContentType", content_type),
-            )
-            .expect("should write to buf");
+        self.write(
+            XmlEvent::start_element("Override")
+                .attr("PartName", name)
+                .attr("ContentType", content_type),
+        );
         self.close()
     }
 
     pub(crate) fn add_default(mut self, name: &str, extension: &str) -> Self {
-        self.writer
-            .write(
-                XmlEvent::start_element("Default")
-                    .attr("ContentType", extension)
-                    .attr("Extension", name),
-            )
-            .expect("should write to buf");
+        self.write(
+            XmlEvent::start_element("Default")
+                .attr("ContentType", extension)
+                .attr("Extension", name),
+        );
         self.close()
     }
 
@@ -81,15 +109,20 @@ impl XMLBuilder {
     where
         T: BuildXML,
     {
-        let buf = child.build();
-        let text = str::from_utf8(&buf).unwrap();
-        self.writer.write(text).expect("should write to buf");
+        // Writing no text ends the open start tag, so the child can be written after it.
+        self.write("");
+        if self.error.is_none() {
+            // As a trait object, the writers of nested children are not wrapped in one more
+            // reference at each level.
+            let w: &mut dyn Write = self.writer.inner_mut();
+            self.error = child.build_to(w).err();
+        }
         self
     }
 
     pub(crate) fn add_bytes(mut self, child: &[u8]) -> Self {
         let text = str::from_utf8(child).unwrap();
-        self.writer.write(text).expect("should write to buf");
+        self.write(text);
         self
     }
 
@@ -115,21 +148,22 @@ impl XMLBuilder {
 
     // Close tag
     pub(crate) fn close(mut self) -> Self {
-        self.writer
-            .write(XmlEvent::end_element())
-            .expect("should end");
+        self.write(XmlEvent::end_element());
         self
     }
 
     // Write plain text
     #[allow(dead_code)]
     pub(crate) fn plain_text(mut self, t: &str) -> Self {
-        self.writer.write(t).unwrap();
+        self.write(t);
         self
     }
 
-    pub(crate) fn build(self) -> Vec<u8> {
-        self.writer.into_inner()
+    pub(crate) fn into_inner(self) -> io::Result<W> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.writer.into_inner()),
+        }
     }
 }
 
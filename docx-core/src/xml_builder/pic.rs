@@ -1,11 +1,11 @@
 use super::XMLBuilder;
 use super::XmlEvent;
+use std::io::Write;
 
-impl XMLBuilder {
+impl<W: Write> XMLBuilder<W> {
     open!(open_pic, "pic:pic", "xmlns:pic");
     open!(open_blip_fill, "pic:blipFill");
     closed!(a_blip, "a:blip", "r:embed");
-    closed!(a_src_rect, "a:srcRect");
     open!(open_a_stretch, "a:stretch");
     closed!(a_fill_rect, "a:fillRect");
 
@@ -26,4 +26,36 @@ impl XMLBuilder {
     closed!(a_ext, "a:ext", "cx", "cy");
     open!(open_a_prst_geom, "a:prstGeom", "prst");
     closed!(a_av_lst, "a:avLst");
+
+    // The insets are written only when the picture is cropped.
+    pub(crate) fn a_src_rect(mut self, crop: Option<(i32, i32, i32, i32)>) -> Self {
+        let mut e = XmlEvent::start_element("a:srcRect");
+        let insets = crop.map(|(l, t, r, b)| {
+            [
+                ("l", l.to_string()),
+                ("t", t.to_string()),
+                ("r", r.to_string()),
+                ("b", b.to_string()),
+            ]
+        });
+        if let Some(insets) = &insets {
+            for (name, v) in insets.iter().filter(|(_, v)| v != "0") {
+                e = e.attr(*name, v);
+            }
+        }
+        self.write(e);
+        self.close()
+    }
+
+    pub(crate) fn open_a_xfrm_with_flip(mut self, rot: &str, flip_h: bool, flip_v: bool) -> Self {
+        let mut e = XmlEvent::start_element("a:xfrm").attr("rot", rot);
+        if flip_h {
+            e = e.attr("flipH", "1");
+        }
+        if flip_v {
+            e = e.attr("flipV", "1");
+        }
+        self.write(e);
+        self
+    }
 }
@@ -1,7 +1,8 @@
 use super::XMLBuilder;
 use super::XmlEvent;
+use std::io::Write;
 
-impl XMLBuilder {
+impl<W: Write> XMLBuilder<W> {
     // Build Properties element
     // i.e. <Properties xmlns:vt="http://schemas.openxmlformats.org/package/2006/relationships">
     open!(open_properties, "Properties", "xmlns", "xmlns:vt");
@@ -14,6 +15,8 @@ impl XMLBuilder {
     closed_with_child!(characters, "Characters");
     closed_with_child!(characters_with_spaces, "CharactersWithSpaces");
     closed_with_child!(paragraphs, "Paragraphs");
+    closed_with_child!(company, "Company");
+    closed_with_child!(app_version, "AppVersion");
 }
 
 #[cfg(test)]
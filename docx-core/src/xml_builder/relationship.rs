@@ -1,7 +1,8 @@
 use super::XMLBuilder;
 use super::XmlEvent;
+use std::io::Write;
 
-impl XMLBuilder {
+impl<W: Write> XMLBuilder<W> {
     // Build RelationShips element
     // i.e. <Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
     open!(open_relationships, "Relationships", "xmlns");
@@ -1,25 +1,88 @@
 use super::XMLBuilder;
 use super::XmlEvent;
+use crate::ProtectionHash;
+use std::io::Write;
 
-impl XMLBuilder {
+impl<W: Write> XMLBuilder<W> {
     pub(crate) fn open_settings(mut self) -> Self {
-        self.writer
-            .write(
-                XmlEvent::start_element("w:settings")
-                    .attr(
-                        "xmlns:w",
-                        "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
-                    )
-                    .attr(
-                        "xmlns:w14",
-                        "http://schemas.microsoft.com/office/word/2010/wordml",
-                    )
-                    .attr(
-                        "xmlns:w15",
-                        "http://schemas.microsoft.com/office/word/2012/wordml",
-                    ),
-            )
-            .expect("should write to buf");
+        self.write(
+            XmlEvent::start_element("w:settings")
+                .attr(
+                    "xmlns:w",
+                    "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+                )
+                .attr(
+                    "xmlns:w14",
+                    "http://schemas.microsoft.com/office/word/2010/wordml",
+                )
+                .attr(
+                    "xmlns:w15",
+                    "http://schemas.microsoft.com/office/word/2012/wordml",
+                ),
+        );
         self
     }
+
+    // Both the transitional attributes, which Word reads, and those of ISO/IEC 29500.
+    fn protection_hash<'a>(
+        mut e: xml::writer::events::StartElementBuilder<'a>,
+        hash: &'a ProtectionHash,
+        spin_count: &'a str,
+    ) -> xml::writer::events::StartElementBuilder<'a> {
+        if let Some(ref v) = hash.hash_value {
+            e = e
+                .attr("w:cryptProviderType", "rsaAES")
+                .attr("w:cryptAlgorithmClass", "hash")
+                .attr("w:cryptAlgorithmType", "typeAny");
+            if let Some(sid) = hash.algorithm_sid() {
+                e = e.attr("w:cryptAlgorithmSid", sid);
+            }
+            if hash.spin_count.is_some() {
+                e = e.attr("w:cryptSpinCount", spin_count);
+            }
+            e = e.attr("w:hash", v);
+            if let Some(ref v) = hash.salt_value {
+                e = e.attr("w:salt", v);
+            }
+        }
+        if let Some(ref v) = hash.algorithm_name {
+            e = e.attr("w:algorithmName", v);
+        }
+        if let Some(ref v) = hash.hash_value {
+            e = e.attr("w:hashValue", v);
+        }
+        if let Some(ref v) = hash.salt_value {
+            e = e.attr("w:saltValue", v);
+        }
+        if hash.spin_count.is_some() {
+            e = e.attr("w:spinCount", spin_count);
+        }
+        e
+    }
+
+    pub(crate) fn document_protection(
+        mut self,
+        edit: &str,
+        enforcement: bool,
+        hash: &ProtectionHash,
+    ) -> Self {
+        let spin_count = format!("{}", hash.spin_count.unwrap_or_default());
+        let e = XmlEvent::start_element("w:documentProtection")
+            .attr("w:edit", edit)
+            .attr("w:enforcement", if enforcement { "1" } else { "0" });
+        let e = Self::protection_hash(e, hash, &spin_count);
+        self.write(e);
+        self.close()
+    }
+
+    pub(crate) fn write_protection(mut self, recommended: bool, hash: &ProtectionHash) -> Self {
+        let spin_count = format!("{}", hash.spin_count.unwrap_or_default());
+        let mut e = XmlEvent::start_element("w:writeProtection");
+        if recommended {
+            e = e.attr("w:recommended", "1");
+        }
+        let e = Self::protection_hash(e, hash, &spin_count);
+        self.write(e);
+        self.close()
+    }
 }
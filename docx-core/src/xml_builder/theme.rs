@@ -0,0 +1,47 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+use std::io::Write;
+
+impl<W: Write> XMLBuilder<W> {
+    pub(crate) fn open_theme(mut self, name: &str) -> Self {
+        self.write(
+            XmlEvent::start_element("a:theme")
+                .attr(
+                    "xmlns:a",
+                    "http://schemas.openxmlformats.org/drawingml/2006/main",
+                )
+                .attr("name", name),
+        );
+        self
+    }
+
+    open!(open_theme_elements, "a:themeElements");
+    open!(open_color_scheme, "a:clrScheme", "name");
+    closed!(a_srgb_color, "a:srgbClr", "val");
+    closed!(a_scheme_color, "a:schemeClr", "val");
+    open!(open_font_scheme, "a:fontScheme", "name");
+    open!(open_major_font, "a:majorFont");
+    open!(open_minor_font, "a:minorFont");
+    closed!(a_latin, "a:latin", "typeface");
+    closed!(a_ea, "a:ea", "typeface");
+    closed!(a_cs, "a:cs", "typeface");
+    closed!(a_font, "a:font", "script", "typeface");
+    open!(open_format_scheme, "a:fmtScheme", "name");
+    open!(open_fill_style_list, "a:fillStyleLst");
+    open!(open_line_style_list, "a:lnStyleLst");
+    open!(open_effect_style_list, "a:effectStyleLst");
+    open!(open_background_fill_style_list, "a:bgFillStyleLst");
+    open!(open_a_solid_fill, "a:solidFill");
+    open!(open_a_line, "a:ln", "w");
+    open!(open_effect_style, "a:effectStyle");
+    closed!(a_effect_list, "a:effectLst");
+    closed!(a_object_defaults, "a:objectDefaults");
+    closed!(a_extra_color_scheme_list, "a:extraClrSchemeLst");
+
+    // The slots of `a:clrScheme`, such as `a:accent1`.
+    pub(crate) fn open_color_slot(mut self, slot: &str) -> Self {
+        let name = format!("a:{}", slot);
+        self.write(XmlEvent::start_element(name.as_str()));
+        self
+    }
+}
@@ -0,0 +1,42 @@
+use super::XMLBuilder;
+use super::XmlEvent;
+use std::io::Write;
+
+impl<W: Write> XMLBuilder<W> {
+    pub(crate) fn open_web_settings(mut self) -> Self {
+        self.write(
+            XmlEvent::start_element("w:webSettings")
+                .attr(
+                    "xmlns:w",
+                    "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+                )
+                .attr(
+                    "xmlns:r",
+                    "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+                ),
+        );
+        self
+    }
+
+    open!(open_divs, "w:divs");
+    open!(open_div, "w:div", "w:id");
+    open!(open_div_border, "w:divBdr");
+    open!(open_divs_child, "w:divsChild");
+    closed!(block_quote, "w:blockQuote");
+    closed!(body_div, "w:bodyDiv");
+    closed_with_str!(div_margin_left, "w:marLeft");
+    closed_with_str!(div_margin_right, "w:marRight");
+    closed_with_str!(div_margin_top, "w:marTop");
+    closed_with_str!(div_margin_bottom, "w:marBottom");
+
+    closed_with_str!(encoding, "w:encoding");
+    closed!(optimize_for_browser, "w:optimizeForBrowser");
+    closed!(rely_on_vml, "w:relyOnVML");
+    closed!(allow_png, "w:allowPNG");
+    closed!(do_not_rely_on_css, "w:doNotRelyOnCSS");
+    closed!(do_not_save_as_single_file, "w:doNotSaveAsSingleFile");
+    closed!(do_not_organize_in_folder, "w:doNotOrganizeInFolder");
+    closed!(do_not_use_long_file_names, "w:doNotUseLongFileNames");
+    closed_with_str!(pixels_per_inch, "w:pixelsPerInch");
+    closed_with_str!(target_screen_size, "w:targetScreenSz");
+}
@@ -1,115 +1,185 @@
-use crate::XMLDocx;
+mod stream_writer;
+
+pub(crate) use stream_writer::*;
+
+use crate::{PackOptions, XMLDocx};
 
 use std::io::prelude::*;
 use std::io::Seek;
-use zip::write::FileOptions;
+use zip::result::ZipResult;
+use zip::ZipWriter;
+
+pub(crate) enum Entry {
+    Directory(String),
+    File(String, Vec<u8>),
+}
+
+fn file(path: impl Into<String>, data: Vec<u8>) -> Entry {
+    Entry::File(path.into(), data)
+}
+
+fn directory(path: &str) -> Entry {
+    Entry::Directory(path.to_owned())
+}
+
+pub(crate) fn start_file<W>(
+    zip: &mut ZipWriter<W>,
+    path: impl Into<String>,
+    options: &PackOptions,
+) -> ZipResult<()>
+where
+    W: Write + Seek,
+{
+    let path = path.into();
+    let file_options = options.file_options(&path);
+    zip.start_file(path, file_options)
+}
+
+pub fn zip<W>(w: W, xml: XMLDocx, options: &PackOptions) -> ZipResult<W>
+where
+    W: Write + Seek,
+{
+    zip_entries(w, entries(xml), options)
+}
 
-pub fn zip<W>(w: W, xml: XMLDocx) -> zip::result::ZipResult<()>
+pub(crate) fn zip_entries<W>(w: W, entries: Vec<Entry>, options: &PackOptions) -> ZipResult<W>
 where
     W: Write + Seek,
 {
     let mut zip = zip::ZipWriter::new(w);
+    for entry in entries {
+        match entry {
+            Entry::Directory(path) => zip.add_directory(path, Default::default())?,
+            Entry::File(path, data) => {
+                start_file(&mut zip, path, options)?;
+                zip.write_all(&data)?;
+            }
+        }
+    }
+    zip.finish()
+}
 
-    zip.add_directory("word/", Default::default())?;
-    zip.add_directory("word/_rels", Default::default())?;
-    zip.add_directory("_rels/", Default::default())?;
-    zip.add_directory("docProps/", Default::default())?;
-
-    let options = FileOptions::default()
-        .compression_method(zip::CompressionMethod::Stored)
-        .unix_permissions(0o755);
-
-    zip.start_file("[Content_Types].xml", options)?;
-    zip.write_all(&xml.content_type)?;
-    zip.start_file("_rels/.rels", options)?;
-    zip.write_all(&xml.rels)?;
-    zip.start_file("docProps/app.xml", options)?;
-    zip.write_all(&xml.doc_props.app)?;
-    zip.start_file("docProps/core.xml", options)?;
-    zip.write_all(&xml.doc_props.core)?;
-    zip.start_file("docProps/custom.xml", options)?;
-    zip.write_all(&xml.doc_props.custom)?;
-    zip.start_file("word/_rels/document.xml.rels", options)?;
-    zip.write_all(&xml.document_rels)?;
-    zip.start_file("word/document.xml", options)?;
-    zip.write_all(&xml.document)?;
-    zip.start_file("word/styles.xml", options)?;
-    zip.write_all(&xml.styles)?;
-    zip.start_file("word/settings.xml", options)?;
-    zip.write_all(&xml.settings)?;
-    zip.start_file("word/fontTable.xml", options)?;
-    zip.write_all(&xml.font_table)?;
-    zip.start_file("word/comments.xml", options)?;
-    zip.write_all(&xml.comments)?;
-    zip.start_file("word/numbering.xml", options)?;
-    zip.write_all(&xml.numberings)?;
-    zip.start_file("word/commentsExtended.xml", options)?;
-    zip.write_all(&xml.comments_extended)?;
-    zip.start_file("word/footnotes.xml", options)?;
-    zip.write_all(&xml.footnotes)?;
-
-    for (i, h) in xml.headers.iter().enumerate() {
-        zip.start_file(format!("word/header{}.xml", i + 1), options)?;
-        zip.write_all(h)?;
-
-        if let Some(rels) = xml.header_rels.get(i) {
-            zip.start_file(format!("word/_rels/header{}.xml.rels", i + 1), options)?;
-            zip.write_all(rels)?;
+// The directories and files of the package, in the order they are written.
+pub(crate) fn entries(xml: XMLDocx) -> Vec<Entry> {
+    let mut entries = vec![
+        directory("word/"),
+        directory("word/_rels"),
+        directory("_rels/"),
+        directory("docProps/"),
+        file("[Content_Types].xml", xml.content_type),
+        file("_rels/.rels", xml.rels),
+        file("docProps/app.xml", xml.doc_props.app),
+        file("docProps/core.xml", xml.doc_props.core),
+        file("docProps/custom.xml", xml.doc_props.custom),
+        file("word/_rels/document.xml.rels", xml.document_rels),
+        file("word/document.xml", xml.document),
+        file("word/styles.xml", xml.styles),
+        file("word/settings.xml", xml.settings),
+        file("word/fontTable.xml", xml.font_table),
+    ];
+    if let Some(rels) = xml.font_table_rels {
+        entries.push(file("word/_rels/fontTable.xml.rels", rels));
+    }
+    if let Some(web_settings) = xml.web_settings {
+        entries.push(file("word/webSettings.xml", web_settings));
+        if let Some(rels) = xml.web_settings_rels {
+            entries.push(file("word/_rels/webSettings.xml.rels", rels));
         }
     }
+    if let Some(theme) = xml.theme {
+        entries.push(directory("word/theme/"));
+        entries.push(file("word/theme/theme1.xml", theme));
+    }
+    for (path, data) in xml.fonts {
+        entries.push(file(format!("word/{}", path), data));
+    }
+    entries.push(file("word/comments.xml", xml.comments));
+    entries.push(file("word/numbering.xml", xml.numberings));
+    entries.push(file("word/commentsExtended.xml", xml.comments_extended));
+    entries.push(file("word/footnotes.xml", xml.footnotes));
+    if let Some(endnotes) = xml.endnotes {
+        entries.push(file("word/endnotes.xml", endnotes));
+    }
 
-    for (i, h) in xml.footers.iter().enumerate() {
-        zip.start_file(format!("word/footer{}.xml", i + 1), options)?;
-        zip.write_all(h)?;
+    let mut header_rels = xml.header_rels.into_iter();
+    for (i, h) in xml.headers.into_iter().enumerate() {
+        entries.push(file(format!("word/header{}.xml", i + 1), h));
+        if let Some(rels) = header_rels.next() {
+            entries.push(file(format!("word/_rels/header{}.xml.rels", i + 1), rels));
+        }
+    }
 
-        if let Some(rels) = xml.footer_rels.get(i) {
-            zip.start_file(format!("word/_rels/footer{}.xml.rels", i + 1), options)?;
-            zip.write_all(rels)?;
+    let mut footer_rels = xml.footer_rels.into_iter();
+    for (i, f) in xml.footers.into_iter().enumerate() {
+        entries.push(file(format!("word/footer{}.xml", i + 1), f));
+        if let Some(rels) = footer_rels.next() {
+            entries.push(file(format!("word/_rels/footer{}.xml.rels", i + 1), rels));
         }
     }
 
+    for (path, data) in xml.alt_chunks {
+        entries.push(file(format!("word/{}", path), data));
+    }
+
+    for (path, data) in xml.custom_parts {
+        entries.push(file(path, data));
+    }
+
+    for (path, data) in xml.charts {
+        entries.push(file(path, data));
+    }
+
+    for (path, data) in xml.glossary {
+        entries.push(file(path, data));
+    }
+
+    for (path, data) in xml.vba_project {
+        entries.push(file(path, data));
+    }
+
     if !xml.media.is_empty() {
-        zip.add_directory("word/media/", Default::default())?;
-        for m in xml.media {
+        entries.push(directory("word/media/"));
+        for (id, data) in xml.media {
             // For now only png supported
-            zip.start_file(format!("word/media/{}.png", m.0), options)?;
-            zip.write_all(&m.1)?;
+            entries.push(file(format!("word/media/{}.png", id), data));
         }
     }
 
     // For now support only taskpanes
     if let Some(taskpanes) = xml.taskpanes {
-        zip.add_directory("word/webextensions/", Default::default())?;
-        zip.start_file("word/webextensions/taskpanes.xml", options)?;
-        zip.write_all(&taskpanes)?;
-
-        zip.add_directory("word/webextensions/_rels", Default::default())?;
-        zip.start_file("word/webextensions/_rels/taskpanes.xml.rels", options)?;
-        zip.write_all(&xml.taskpanes_rels)?;
-
-        for (i, ext) in xml.web_extensions.iter().enumerate() {
-            zip.start_file(
+        entries.push(directory("word/webextensions/"));
+        entries.push(file("word/webextensions/taskpanes.xml", taskpanes));
+        entries.push(directory("word/webextensions/_rels"));
+        entries.push(file(
+            "word/webextensions/_rels/taskpanes.xml.rels",
+            xml.taskpanes_rels,
+        ));
+        for (i, ext) in xml.web_extensions.into_iter().enumerate() {
+            entries.push(file(
                 format!("word/webextensions/webextension{}.xml", i + 1),
-                options,
-            )?;
-            zip.write_all(ext)?;
+                ext,
+            ));
         }
     }
 
     if !xml.custom_items.is_empty() {
-        zip.add_directory("customXml/_rels", Default::default())?;
+        entries.push(directory("customXml/_rels"));
     }
 
+    let mut custom_item_rels = xml.custom_item_rels.into_iter();
+    let mut custom_item_props = xml.custom_item_props.into_iter();
     for (i, item) in xml.custom_items.into_iter().enumerate() {
         let n = i + 1;
-        zip.start_file(format!("customXml/_rels/item{}.xml.rels", n), options)?;
-        zip.write_all(&xml.custom_item_rels[i])?;
-        zip.start_file(format!("customXml/item{}.xml", n), options)?;
-        zip.write_all(&item)?;
-        zip.start_file(format!("customXml/itemProps{}.xml", n), options)?;
-        zip.write_all(&xml.custom_item_props[i])?;
+        entries.push(file(
+            format!("customXml/_rels/item{}.xml.rels", n),
+            custom_item_rels.next().unwrap_or_default(),
+        ));
+        entries.push(file(format!("customXml/item{}.xml", n), item));
+        entries.push(file(
+            format!("customXml/itemProps{}.xml", n),
+            custom_item_props.next().unwrap_or_default(),
+        ));
     }
 
-    zip.finish()?;
-    Ok(())
+    entries
 }
@@ -0,0 +1,98 @@
+use std::io::{self, Seek, SeekFrom, Write};
+
+// Lets the zip writer, which seeks back to complete the local header of each file, write into
+// a sink which can not seek. The zip writer only seeks back into the header of the file it is
+// writing, so everything before the position of a backward seek is final and written out.
+// Bytes are only written out on a backward seek, so a file stays buffered until the seek into
+// the header of the next one: up to two files, the previous and the current, are held in
+// memory, and the central directory with the last file until `finish`.
+pub(crate) struct StreamWriter<W: Write> {
+    inner: W,
+    // The bytes written out to `inner`.
+    flushed: u64,
+    buf: Vec<u8>,
+    pos: u64,
+}
+
+impl<W: Write> StreamWriter<W> {
+    pub(crate) fn new(inner: W) -> StreamWriter<W> {
+        StreamWriter {
+            inner,
+            flushed: 0,
+            buf: vec![],
+            pos: 0,
+        }
+    }
+
+    fn end(&self) -> u64 {
+        self.flushed + self.buf.len() as u64
+    }
+
+    pub(crate) fn finish(mut self) -> io::Result<W> {
+        self.inner.write_all(&self.buf)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for StreamWriter<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        let start = (self.pos - self.flushed) as usize;
+        let overlap = data.len().min(self.buf.len() - start);
+        self.buf[start..start + overlap].copy_from_slice(&data[..overlap]);
+        self.buf.extend_from_slice(&data[overlap..]);
+        self.pos += data.len() as u64;
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Seek for StreamWriter<W> {
+    fn seek(&mut self, from: SeekFrom) -> io::Result<u64> {
+        let pos = match from {
+            SeekFrom::Start(p) => Some(p),
+            SeekFrom::Current(d) => self.pos.checked_add_signed(d),
+            SeekFrom::End(d) => self.end().checked_add_signed(d),
+        };
+        let pos = match pos {
+            Some(p) if p >= self.flushed && p <= self.end() => p,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "can not seek to a position which was written out",
+                ))
+            }
+        };
+        if pos < self.pos {
+            let n = (pos - self.flushed) as usize;
+            self.inner.write_all(&self.buf[..n])?;
+            self.buf.drain(..n);
+            self.flushed = pos;
+        }
+        self.pos = pos;
+        Ok(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[cfg(test)]
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_stream_writer() {
+        let mut w = StreamWriter::new(vec![]);
+        w.write_all(b"header____data").unwrap();
+        w.seek(SeekFrom::Start(6)).unwrap();
+        w.write_all(b"1234").unwrap();
+        w.seek(SeekFrom::End(0)).unwrap();
+        w.write_all(b"!").unwrap();
+        assert!(w.seek(SeekFrom::Start(2)).is_err());
+        assert_eq!(w.finish().unwrap(), b"header1234data!".to_vec());
+    }
+}
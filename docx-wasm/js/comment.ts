@@ -7,6 +7,7 @@ export class Comment {
   _date: string;
   children: (Paragraph | Table)[] = [];
   _parentCommentId: number;
+  _done: boolean = false;
 
   constructor(id: number) {
     this.id = id;
@@ -31,4 +32,9 @@ export class Comment {
     this._parentCommentId = id;
     return this;
   }
+
+  done() {
+    this._done = true;
+    return this;
+  }
 }
@@ -6,6 +6,9 @@ export class Image {
   _offsetX = 0;
   _offsetY = 0;
   rot = 0;
+  _flipH = false;
+  _flipV = false;
+  _crop: [number, number, number, number] | null = null;
 
   constructor(data: Uint8Array) {
     this.data = data;
@@ -22,6 +25,22 @@ export class Image {
     return this;
   };
 
+  flipH = () => {
+    this._flipH = true;
+    return this;
+  };
+
+  flipV = () => {
+    this._flipV = true;
+    return this;
+  };
+
+  // unit is 1/1000 of a percent
+  crop = (left: number, top: number, right: number, bottom: number) => {
+    this._crop = [left, top, right, bottom];
+    return this;
+  };
+
   floating = () => {
     this._floating = true;
     return this;
@@ -0,0 +1 @@
+export interface ColorScheme { name: string, dk1: string, lt1: string, dk2: string, lt2: string, accent1: string, accent2: string, accent3: string, accent4: string, accent5: string, accent6: string, hlink: string, folHlink: string, }
\ No newline at end of file
@@ -0,0 +1 @@
+export type Conformance = "transitional" | "strict";
\ No newline at end of file
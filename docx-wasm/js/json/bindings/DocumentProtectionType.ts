@@ -0,0 +1 @@
+export type DocumentProtectionType = "none" | "readOnly" | "comments" | "trackedChanges" | "forms";
\ No newline at end of file
@@ -0,0 +1 @@
+export type DrawingWrapType = "none" | "square" | "tight" | "through" | "topAndBottom";
\ No newline at end of file
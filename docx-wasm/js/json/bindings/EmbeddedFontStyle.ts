@@ -0,0 +1 @@
+export type EmbeddedFontStyle = "regular" | "bold" | "italic" | "boldItalic";
\ No newline at end of file
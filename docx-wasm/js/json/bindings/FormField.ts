@@ -0,0 +1,2 @@
+
+export type FormField = { type: "checkBox", checked: boolean, } | { type: "dropDown", entries: Array<string>, selected: number, } | { type: "textInput", default: string | null, maxLength: number | null, };
\ No newline at end of file
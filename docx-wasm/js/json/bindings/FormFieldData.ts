@@ -0,0 +1,3 @@
+import type { FormField } from "./FormField";
+
+export interface FormFieldData { name: string | null, helpText?: string, statusText?: string, field: FormField, }
\ No newline at end of file
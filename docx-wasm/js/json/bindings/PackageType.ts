@@ -0,0 +1 @@
+export type PackageType = "document" | "macroEnabledDocument" | "template" | "macroEnabledTemplate";
\ No newline at end of file
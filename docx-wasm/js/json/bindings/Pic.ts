@@ -1,6 +1,7 @@
 import type { DrawingPosition } from "./DrawingPosition";
 import type { DrawingPositionType } from "./DrawingPositionType";
+import type { DrawingWrapType } from "./DrawingWrapType";
 import type { RelativeFromHType } from "./RelativeFromHType";
 import type { RelativeFromVType } from "./RelativeFromVType";
 
-export interface Pic { id: string, image: Array<number>, size: [number, number], positionType: DrawingPositionType, simplePos: boolean, simplePosX: number, simplePosY: number, layoutInCell: boolean, relativeHeight: number, allowOverlap: boolean, positionH: DrawingPosition, positionV: DrawingPosition, relativeFromH: RelativeFromHType, relativeFromV: RelativeFromVType, distT: number, distB: number, distL: number, distR: number, rot: number, }
\ No newline at end of file
+export interface Pic { id: string, image: Array<number>, size: [number, number], positionType: DrawingPositionType, simplePos: boolean, simplePosX: number, simplePosY: number, layoutInCell: boolean, relativeHeight: number, allowOverlap: boolean, behindDoc: boolean, wrap?: DrawingWrapType, positionH: DrawingPosition, positionV: DrawingPosition, relativeFromH: RelativeFromHType, relativeFromV: RelativeFromVType, distT: number, distB: number, distL: number, distR: number, rot: number, flipH: boolean, flipV: boolean, crop?: [number, number, number, number], name?: string, description?: string, }
\ No newline at end of file
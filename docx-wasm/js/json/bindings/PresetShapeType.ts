@@ -0,0 +1 @@
+export type PresetShapeType = "rectangle" | "roundedRectangle" | "ellipse" | "line" | "arrow";
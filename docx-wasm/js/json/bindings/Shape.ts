@@ -1,3 +1,3 @@
 import type { ImageData } from "./ImageData";
 
-export interface Shape { style?: string, imageData?: ImageData, }
\ No newline at end of file
+export interface Shape { style?: string, imageData?: ImageData, children?: Array<Shape>, }
\ No newline at end of file
@@ -0,0 +1,3 @@
+import type { StructuredDataTagListItem } from "./StructuredDataTagListItem";
+
+export type StructuredDataTagControl = { type: "checkBox", checked: boolean, } | { type: "dropDownList", items: Array<StructuredDataTagListItem>, } | { type: "comboBox", items: Array<StructuredDataTagListItem>, };
\ No newline at end of file
@@ -0,0 +1,2 @@
+
+export interface StructuredDataTagListItem { displayText: string, value: string, }
\ No newline at end of file
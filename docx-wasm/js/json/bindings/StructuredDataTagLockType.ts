@@ -0,0 +1 @@
+export type StructuredDataTagLockType = "sdtLocked" | "contentLocked" | "unlocked" | "sdtContentLocked";
\ No newline at end of file
@@ -1,3 +1,4 @@
+import type { ColorScheme } from "./ColorScheme";
 import type { FontScheme } from "./FontScheme";
 
-export interface Theme { fontSchema: FontScheme, }
\ No newline at end of file
+export interface Theme { fontSchema: FontScheme, colorScheme: ColorScheme, }
\ No newline at end of file
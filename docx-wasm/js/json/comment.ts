@@ -8,6 +8,7 @@ export type CommentJSON = {
   date: string;
   children: CommentChildJSON[];
   parentCommentId: number | null;
+  done: boolean;
 };
 
 export type CommentRangeStartJSON = {
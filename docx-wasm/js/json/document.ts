@@ -3,6 +3,7 @@ import { TableJSON } from "./table";
 import { SectionPropertyJSON } from "./section-property";
 import { CommentRangeStartJSON, CommentRangeEndJSON } from "..";
 import { StructuredTagJSON } from "./structured-data-tag";
+import { UnsupportedJSON } from "./run";
 
 export type DocumentChildJSON =
   | ParagraphJSON
@@ -11,7 +12,39 @@ export type DocumentChildJSON =
   | CommentRangeEndJSON
   | BookmarkStartJSON
   | BookmarkEndJSON
-  | StructuredTagJSON;
+  | StructuredTagJSON
+  | AltChunkJSON
+  | UnsupportedJSON;
+
+export type AltChunkJSON = {
+  type: "altChunk";
+  data: {
+    id: string;
+  };
+};
+
+// The content of the part is not included.
+export type AltChunkPartJSON = {
+  id: string;
+  path: string;
+  contentType: string;
+};
+
+export type BuildingBlockJSON = {
+  name: string;
+  gallery: string | null;
+  category: string | null;
+  description: string | null;
+  guid: string | null;
+  behaviors?: string[];
+  children: DocumentChildJSON[];
+};
+
+// The contents of the parts are not included.
+export type GlossaryPartJSON = {
+  path: string;
+  contentType: string | null;
+};
 
 export type DocumentJSON = {
   children: DocumentChildJSON[];
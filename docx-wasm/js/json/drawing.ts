@@ -1,5 +1,6 @@
 import { TextBoxContentJSON } from "./textbox-content";
 import { Pic as InnerPic } from "./bindings/Pic";
+import { PresetShapeType } from "./bindings/PresetShapeType";
 import { ParagraphJSON, TableJSON } from "..";
 
 export interface Pic extends Omit<InnerPic, "image"> {
@@ -18,9 +19,57 @@ export type DrawingJSON = {
         data: {
           children: (ParagraphJSON | TableJSON)[];
         };
+      }
+    | {
+        type: "chart";
+        data: ChartJSON;
+      }
+    | {
+        type: "shape";
+        data: {
+          preset: PresetShapeType;
+          size: [number, number];
+          rotation: number;
+          fill?: string;
+          outline?: [string, number];
+        };
       };
 };
 
+export type ChartType =
+  | "area"
+  | "area3D"
+  | "bar"
+  | "bar3D"
+  | "bubble"
+  | "doughnut"
+  | "line"
+  | "line3D"
+  | "ofPie"
+  | "pie"
+  | "pie3D"
+  | "radar"
+  | "scatter"
+  | "stock"
+  | "surface"
+  | "surface3D";
+
+export type ChartSeriesJSON = {
+  name: string | null;
+  categories: string[];
+  values: (number | null)[];
+};
+
+export type ChartJSON = {
+  id: string;
+  size: [number, number];
+  name?: string;
+  description?: string;
+  chartType: ChartType | null;
+  title: string | null;
+  series: ChartSeriesJSON[];
+};
+
 export type WpAnchorJSON = {
   type: "anchor";
   data: {
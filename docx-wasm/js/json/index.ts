@@ -1,11 +1,22 @@
 import { StylesJSON } from "./styles";
-import { DocumentJSON } from "./document";
+import {
+  DocumentJSON,
+  AltChunkPartJSON,
+  BuildingBlockJSON,
+  GlossaryPartJSON,
+} from "./document";
 import { NumberingsJSON } from "./numbering";
 import { CommentJSON } from "./comment";
 import { WebSettingsJSON } from "./web-settings";
+import { ParagraphJSON } from "./paragraph";
+import { TableJSON } from "./table";
 
 import { Theme as ThemeJSON } from "./bindings/Theme";
 import { CharacterSpacingValues } from "../settings";
+import { DocumentProtectionType } from "./bindings/DocumentProtectionType";
+import { EmbeddedFontStyle } from "./bindings/EmbeddedFontStyle";
+import { PackageType } from "./bindings/PackageType";
+import { Conformance } from "./bindings/Conformance";
 
 export type DocxJSON = {
   contentType: {
@@ -17,17 +28,31 @@ export type DocxJSON = {
   documentRels: {
     hasComments: boolean;
     hasNumberings: boolean;
+    hasFootnotes: boolean;
+    hasEndnotes: boolean;
   };
   docProps: {
-    app: {};
+    app: {
+      template: string | null;
+      totalTime: number | null;
+      pages: number | null;
+      words: number | null;
+      characters: number | null;
+      application: string | null;
+      paragraphs: number | null;
+      company: string | null;
+      charactersWithSpaces: number | null;
+      appVersion: string | null;
+    };
     core: {
       config: {
+        created: string | null;
         creator: string | null;
         description: string | null;
         language: string | null;
         lastModifiedBy: string | null;
         modified: string | null;
-        revision: string | null;
+        revision: number | null;
         subject: string | null;
         title: string | null;
       };
@@ -46,11 +71,52 @@ export type DocxJSON = {
   numberings: NumberingsJSON;
   settings: SettingsJSON;
   webSettings: WebSettingsJSON;
-  fontTable: {};
+  fontTable: {
+    embeddedFonts?: EmbeddedFontJSON[];
+  };
   themes: ThemeJSON[];
   //(id, path, base64 encoded original image data, base64 encoded png image data)
   images: [string, string, string, string][];
   hyperlinks: [string, string, string][];
+  footnotes: {
+    footnotes: NoteJSON[];
+  };
+  endnotes: {
+    endnotes: NoteJSON[];
+  };
+  altChunks?: AltChunkPartJSON[];
+  charts?: {
+    id: string;
+    parts: ChartPartJSON[];
+  }[];
+  glossaryDocument?: {
+    buildingBlocks: BuildingBlockJSON[];
+    styles: StylesJSON | null;
+    numberings: NumberingsJSON | null;
+    parts: GlossaryPartJSON[];
+  };
+  packageType: PackageType;
+  conformance: Conformance;
+  vbaProject?: {
+    parts: VbaProjectPartJSON[];
+  };
+};
+
+export type ChartPartJSON = {
+  path: string;
+  contentType: string | null;
+};
+
+export type VbaProjectPartJSON = {
+  path: string;
+  contentType: string | null;
+};
+
+export type NoteChildJSON = ParagraphJSON | TableJSON;
+
+export type NoteJSON = {
+  id: number;
+  content: NoteChildJSON[];
 };
 
 export type SettingsJSON = {
@@ -62,6 +128,36 @@ export type SettingsJSON = {
   evenAndOddHeaders: boolean;
   zoom: number;
   docVars: { name: string; val: string }[];
+  trackRevisions: boolean;
+  compatOptions: string[];
+  compatSettings: { name: string; uri: string; val: string }[];
+  rsidRoot?: string;
+  rsids: string[];
+  embedTrueTypeFonts?: boolean;
+  documentProtection?: {
+    edit: DocumentProtectionType;
+    enforcement: boolean;
+    hash: ProtectionHashJSON;
+  };
+  writeProtection?: {
+    recommended: boolean;
+    hash: ProtectionHashJSON;
+  };
+};
+
+export type EmbeddedFontJSON = {
+  fontName: string;
+  style: EmbeddedFontStyle;
+  id: string;
+  fontKey: string | null;
+  path: string;
+};
+
+export type ProtectionHashJSON = {
+  algorithmName: string | null;
+  hashValue: string | null;
+  saltValue: string | null;
+  spinCount: number | null;
 };
 
 export * from "../settings";
@@ -1,4 +1,4 @@
-import { RunJSON, RunPropertyJSON } from "./run";
+import { RunJSON, RunPropertyJSON, UnsupportedJSON } from "./run";
 import { IndentJSON } from "./indent";
 import {
   CommentRangeEndJSON,
@@ -20,7 +20,10 @@ export type ParagraphChildJSON =
   | CommentRangeStartJSON
   | CommentRangeEndJSON
   | BookmarkStartJSON
-  | BookmarkEndJSON;
+  | BookmarkEndJSON
+  | OfficeMathJSON
+  | ComplexFieldJSON
+  | UnsupportedJSON;
 
 export type HyperlinkChildJSON =
   | RunJSON
@@ -65,6 +68,7 @@ export type ParagraphPropertyJSON = {
   divId?: string | null;
   keepNext?: boolean;
   keepLines?: boolean;
+  bidi?: boolean;
   snapToGrid?: boolean;
   pageBreakBefore?: boolean;
   widowControl?: boolean;
@@ -97,6 +101,7 @@ export type InsertJSONData = {
   )[];
   author: string;
   date: string;
+  moved?: boolean;
 };
 
 export type InsertJSON = {
@@ -108,6 +113,7 @@ export type DeleteJSONData = {
   children: DeleteChildJSON[];
   author: string;
   date: string;
+  moved?: boolean;
 };
 
 export type DeleteJSON = {
@@ -121,6 +127,7 @@ export type HyperlinkJSON = {
     | {
         type: "external";
         rid: string;
+        path?: string;
         children: HyperlinkChildJSON[];
         history: number | null;
       }
@@ -150,3 +157,49 @@ export type BookmarkEndJSON = {
     id: number;
   };
 };
+
+export type MathNodeJSON =
+  | { type: "run"; text: string }
+  | { type: "fraction"; numerator: MathNodeJSON[]; denominator: MathNodeJSON[] }
+  | { type: "superScript"; base: MathNodeJSON[]; sup: MathNodeJSON[] }
+  | { type: "subScript"; base: MathNodeJSON[]; sub: MathNodeJSON[] }
+  | {
+      type: "subSuperScript";
+      base: MathNodeJSON[];
+      sub: MathNodeJSON[];
+      sup: MathNodeJSON[];
+    }
+  | { type: "radical"; degree: MathNodeJSON[]; base: MathNodeJSON[] }
+  | {
+      type: "nary";
+      operator: string;
+      sub: MathNodeJSON[];
+      sup: MathNodeJSON[];
+      base: MathNodeJSON[];
+    }
+  | {
+      type: "delimiter";
+      begin: string;
+      end: string;
+      separator: string;
+      elements: MathNodeJSON[][];
+    }
+  | { type: "matrix"; rows: MathNodeJSON[][][] };
+
+export type OfficeMathJSON = {
+  type: "officeMath";
+  data: {
+    children: MathNodeJSON[];
+    display?: boolean;
+  };
+};
+
+export type ComplexFieldJSON = {
+  type: "complexField";
+  data: {
+    instruction: string;
+    cachedResult: ParagraphChildJSON[];
+    runProperty: RunPropertyJSON;
+    dirty: boolean;
+  };
+};
@@ -65,7 +65,10 @@ export type RunChildJSON =
   | CommentRangeStartJSON
   | CommentRangeEndJSON
   | FieldCharJSON
-  | InstrTextStringJSON;
+  | InstrTextStringJSON
+  | FootnoteReferenceJSON
+  | EndnoteReferenceJSON
+  | UnsupportedJSON;
 
 export type TextJSON = {
   type: "text";
@@ -132,3 +135,23 @@ export type InstrTextStringJSON = {
   type: "instrTextString";
   data: string;
 };
+
+export type FootnoteReferenceJSON = {
+  type: "footnoteReference";
+  data: {
+    id: number;
+  };
+};
+
+export type EndnoteReferenceJSON = {
+  type: "endnoteReference";
+  data: {
+    id: number;
+  };
+};
+
+// Raw XML of an element which the reader does not support, written back as is.
+export type UnsupportedJSON = {
+  type: "unsupported";
+  data: string;
+};
@@ -23,6 +23,7 @@ export type SectionPropertyJSON = {
   pageSize: {
     w: number;
     h: number;
+    orient?: "landscape" | "portrait" | null;
   };
   pageMargin: {
     top: number;
@@ -34,6 +35,10 @@ export type SectionPropertyJSON = {
     gutter: number;
   };
   columns: number;
+  space: number;
+  columnDefinitions?: { width: number; space: number }[];
+  columnSeparator?: boolean;
+  textDirection: string;
   docGrid?: DocGridJSON;
   sectionType?: SectionType;
   titlePg?: boolean;
@@ -2,4 +2,8 @@ export type ShadingJSON = {
   shdType: string;
   color: string;
   fill: string;
+  themeColor?: string;
+  themeFill?: string;
+  themeFillTint?: string;
+  themeFillShade?: string;
 };
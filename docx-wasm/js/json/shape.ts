@@ -1,6 +1,7 @@
 import { Shape } from "./bindings/Shape";
+import { TextBoxContentJSON } from "./textbox-content";
 
 export type ShapeJSON = {
   type: "shape";
-  data: Shape;
+  data: Shape & { textBox?: TextBoxContentJSON };
 };
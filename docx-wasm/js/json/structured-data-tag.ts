@@ -6,11 +6,26 @@ import {
   ParagraphJSON,
   TableJSON,
 } from "..";
+import { StructuredDataTagControl } from "./bindings/StructuredDataTagControl";
+import { StructuredDataTagLockType } from "./bindings/StructuredDataTagLockType";
 
 export type StructuredTagJSON = {
   type: "structuredDataTag";
   data: {
     children: StructuredDataTagChildJSON[];
+    property?: {
+      dataBinding: {
+        xpath: string | null;
+        prefix_mappings: string | null;
+        store_item_id: string | null;
+      } | null;
+      alias: string | null;
+      tag?: string;
+      lock?: StructuredDataTagLockType;
+      placeholder?: string;
+      showingPlaceholder: boolean;
+      control?: StructuredDataTagControl;
+    };
   };
 };
 
@@ -3,6 +3,15 @@ import { ParagraphPropertyJSON } from "./paragraph";
 import { TablePropertyJSON } from "./table";
 import { TableCellPropertyJSON } from "..";
 import { StyleType } from "../style";
+import { TableStyleOverrideType } from "./bindings/TableStyleOverrideType";
+
+export type TableStylePropertyJSON = {
+  overrideType: TableStyleOverrideType;
+  runProperty: RunPropertyJSON;
+  paragraphProperty: ParagraphPropertyJSON;
+  tableProperty: TablePropertyJSON | null;
+  tableCellProperty: TableCellPropertyJSON | null;
+};
 
 export type StyleJSON = {
   styleId: string;
@@ -12,6 +21,7 @@ export type StyleJSON = {
   paragraphProperty: ParagraphPropertyJSON;
   tableProperty: TablePropertyJSON;
   tableCellProperty: TableCellPropertyJSON;
+  tableStyleProperties?: TableStylePropertyJSON[];
   basedOn: string | null;
   link?: string | null | undefined;
   next?: string | null;
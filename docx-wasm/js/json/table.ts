@@ -45,6 +45,7 @@ export type TableRowPropertyJSON = {
   widthBefore: number | null;
   del?: DeleteJSONData;
   ins?: InsertJSONData;
+  tableHeader?: "tblHeader";
 };
 
 export type TableCellJSON = {
@@ -1,12 +1,36 @@
+import { BorderType } from "./bindings/BorderType";
+
 export type WebSettingsJSON = {
+  frameset?: string;
   divs: DivJSON[];
+  encoding?: string;
+  optimizeForBrowser?: boolean;
+  relyOnVml?: boolean;
+  allowPng?: boolean;
+  doNotRelyOnCss?: boolean;
+  doNotSaveAsSingleFile?: boolean;
+  doNotOrganizeInFolder?: boolean;
+  doNotUseLongFileNames?: boolean;
+  pixelsPerInch?: number;
+  targetScreenSize?: string;
 };
 
 export type DivJSON = {
   id: string;
+  blockQuote?: boolean;
+  bodyDiv?: boolean;
   marginLeft: number;
   marginRight: number;
   marginTop: number;
   marginBottom: number;
+  borders?: DivBorderJSON[];
   divsChild: DivJSON[];
 };
+
+export type DivBorderJSON = {
+  position: "top" | "left" | "bottom" | "right";
+  val: BorderType;
+  size: number;
+  space: number;
+  color: string;
+};
@@ -315,6 +315,15 @@ export class Run {
         if (child.rot != null) {
           pic = pic.rotate(child.rot);
         }
+        if (child._flipH) {
+          pic = pic.flip_h();
+        }
+        if (child._flipV) {
+          pic = pic.flip_v();
+        }
+        if (child._crop) {
+          pic = pic.crop(...child._crop);
+        }
         run = run.add_image(pic);
       }
     });
@@ -7,6 +7,7 @@ export class TableRow {
   hasNumberings = false;
   height: number | null = null;
   hRule: HeightRule | null = null;
+  isHeader = false;
   del: { author: string; date: string } | null = null;
   ins: { author: string; date: string } | null = null;
 
@@ -28,6 +29,11 @@ export class TableRow {
     return this;
   }
 
+  headerRow() {
+    this.isHeader = true;
+    return this;
+  }
+
   delete(author: string, date: string) {
     this.del = { author, date };
     return this;
@@ -201,6 +201,10 @@ export class Table {
         row = row.row_height(r.height);
       }
 
+      if (r.isHeader) {
+        row = row.header_row();
+      }
+
       if (r.del) {
         row = row.delete(r.del.author, r.del.date);
       }
@@ -38,6 +38,11 @@ impl Comment {
         self
     }
 
+    pub fn done(mut self) -> Comment {
+        self.0 = self.0.done();
+        self
+    }
+
     pub fn id(&self) -> usize {
         self.0.id
     }
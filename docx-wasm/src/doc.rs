@@ -1,5 +1,5 @@
 use super::*;
-use docx_rs::CharacterSpacingValues;
+use docx_rs::{CharacterSpacingValues, CompatibilityMode};
 use wasm_bindgen::prelude::*;
 
 extern crate console_error_panic_hook;
@@ -93,6 +93,16 @@ impl Docx {
         self
     }
 
+    pub fn compatibility_mode(mut self, mode: CompatibilityMode) -> Self {
+        self.0 = self.0.compatibility_mode(mode);
+        self
+    }
+
+    pub fn remove_compat_option(mut self, name: &str) -> Self {
+        self.0.settings = self.0.settings.remove_compat_option(name);
+        self
+    }
+
     pub fn header(mut self, header: Header) -> Self {
         self.0 = self.0.header(header.take());
         self
@@ -23,6 +23,22 @@ impl Pic {
         self
     }
 
+    pub fn flip_h(mut self) -> Pic {
+        self.0 = self.0.flip_h();
+        self
+    }
+
+    pub fn flip_v(mut self) -> Pic {
+        self.0 = self.0.flip_v();
+        self
+    }
+
+    // unit is 1/1000 of a percent
+    pub fn crop(mut self, left: i32, top: i32, right: i32, bottom: i32) -> Pic {
+        self.0 = self.0.crop(left, top, right, bottom);
+        self
+    }
+
     pub fn floating(mut self) -> Pic {
         self.0 = self.0.floating();
         self
@@ -39,6 +39,52 @@ impl Table {
         self
     }
 
+    pub fn cell_spacing(mut self, v: usize, t: docx_rs::WidthType) -> Table {
+        self.0 = self.0.cell_spacing(v, t);
+        self
+    }
+
+    pub fn look(
+        mut self,
+        first_row: bool,
+        last_row: bool,
+        first_column: bool,
+        last_column: bool,
+        no_h_band: bool,
+        no_v_band: bool,
+    ) -> Table {
+        self.0 = self.0.look(docx_rs::TableLook::new(
+            first_row,
+            last_row,
+            first_column,
+            last_column,
+            no_h_band,
+            no_v_band,
+        ));
+        self
+    }
+
+    pub fn caption(mut self, v: &str) -> Table {
+        self.0 = self.0.caption(v);
+        self
+    }
+
+    pub fn description(mut self, v: &str) -> Table {
+        self.0 = self.0.description(v);
+        self
+    }
+
+    pub fn merge_cells(
+        mut self,
+        start_row: usize,
+        start_col: usize,
+        end_row: usize,
+        end_col: usize,
+    ) -> Table {
+        self.0 = self.0.merge_cells(start_row, start_col, end_row, end_col);
+        self
+    }
+
     pub fn align(mut self, v: docx_rs::TableAlignmentType) -> Table {
         self.0 = self.0.align(v);
         self
@@ -35,6 +35,11 @@ impl TableRow {
         self
     }
 
+    pub fn header_row(mut self) -> TableRow {
+        self.0 = self.0.header_row();
+        self
+    }
+
     pub fn delete(mut self, author: &str, date: &str) -> Self {
         self.0 = self
             .0